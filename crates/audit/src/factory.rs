@@ -16,17 +16,25 @@ use crate::AuditEntry;
 use async_trait::async_trait;
 use hashbrown::HashSet;
 use rumqttc::QoS;
-use rustfs_config::audit::{AUDIT_MQTT_KEYS, AUDIT_WEBHOOK_KEYS, ENV_AUDIT_MQTT_KEYS, ENV_AUDIT_WEBHOOK_KEYS};
+#[cfg(feature = "kafka")]
+use rustfs_config::audit::{AUDIT_KAFKA_KEYS, ENV_AUDIT_KAFKA_KEYS};
+use rustfs_config::audit::{
+    AUDIT_MQTT_KEYS, AUDIT_NATS_KEYS, AUDIT_WEBHOOK_KEYS, ENV_AUDIT_MQTT_KEYS, ENV_AUDIT_NATS_KEYS, ENV_AUDIT_WEBHOOK_KEYS,
+};
 use rustfs_config::{
     AUDIT_DEFAULT_DIR, DEFAULT_LIMIT, MQTT_BROKER, MQTT_KEEP_ALIVE_INTERVAL, MQTT_PASSWORD, MQTT_QOS, MQTT_QUEUE_DIR,
-    MQTT_QUEUE_LIMIT, MQTT_RECONNECT_INTERVAL, MQTT_TOPIC, MQTT_USERNAME, WEBHOOK_AUTH_TOKEN, WEBHOOK_CLIENT_CERT,
-    WEBHOOK_CLIENT_KEY, WEBHOOK_ENDPOINT, WEBHOOK_QUEUE_DIR, WEBHOOK_QUEUE_LIMIT,
+    MQTT_QUEUE_LIMIT, MQTT_RECONNECT_INTERVAL, MQTT_TOPIC, MQTT_USERNAME, NATS_ADDRESS, NATS_CLIENT_TLS_CERT,
+    NATS_CLIENT_TLS_KEY, NATS_JETSTREAM, NATS_NKEY_SEED, NATS_PASSWORD, NATS_QUEUE_DIR, NATS_QUEUE_LIMIT, NATS_SUBJECT, NATS_TLS,
+    NATS_TLS_CA, NATS_TOKEN, NATS_USER_CREDENTIALS, NATS_USERNAME, WEBHOOK_AUTH_TOKEN, WEBHOOK_CLIENT_CERT, WEBHOOK_CLIENT_KEY,
+    WEBHOOK_ENDPOINT, WEBHOOK_QUEUE_DIR, WEBHOOK_QUEUE_LIMIT,
 };
 use rustfs_ecstore::config::KVS;
+#[cfg(feature = "kafka")]
+use rustfs_targets::target::kafka::KafkaArgs;
 use rustfs_targets::{
     Target,
     error::TargetError,
-    target::{mqtt::MQTTArgs, webhook::WebhookArgs},
+    target::{mqtt::MQTTArgs, nats::NATSArgs, parse_optional_bool, webhook::WebhookArgs},
 };
 use std::time::Duration;
 use tracing::{debug, warn};
@@ -222,3 +230,94 @@ impl TargetFactory for MQTTTargetFactory {
         ENV_AUDIT_MQTT_KEYS.iter().map(|s| s.to_string()).collect()
     }
 }
+
+/// Factory for creating Kafka targets
+#[cfg(feature = "kafka")]
+pub struct KafkaTargetFactory;
+
+#[cfg(feature = "kafka")]
+fn kafka_args(config: &KVS) -> Result<KafkaArgs, TargetError> {
+    KafkaArgs::from_config(|key| config.lookup(key), AUDIT_DEFAULT_DIR, rustfs_targets::target::TargetType::AuditLog)
+}
+
+#[cfg(feature = "kafka")]
+#[async_trait]
+impl TargetFactory for KafkaTargetFactory {
+    async fn create_target(&self, id: String, config: &KVS) -> Result<Box<dyn Target<AuditEntry> + Send + Sync>, TargetError> {
+        let args = kafka_args(config)?;
+        let target = rustfs_targets::target::kafka::KafkaTarget::new(id, args)?;
+        Ok(Box::new(target))
+    }
+
+    fn validate_config(&self, _id: &str, config: &KVS) -> Result<(), TargetError> {
+        kafka_args(config)?.validate()
+    }
+
+    fn get_valid_fields(&self) -> HashSet<String> {
+        AUDIT_KAFKA_KEYS.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn get_valid_env_fields(&self) -> HashSet<String> {
+        ENV_AUDIT_KAFKA_KEYS.iter().map(|s| s.to_string()).collect()
+    }
+}
+
+/// Factory for creating NATS targets
+pub struct NATSTargetFactory;
+
+fn nats_args(config: &KVS) -> Result<NATSArgs, TargetError> {
+    let address = config
+        .lookup(NATS_ADDRESS)
+        .ok_or_else(|| TargetError::Configuration("Missing NATS address".to_string()))?
+        .split(',')
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty())
+        .collect::<Vec<_>>();
+
+    let subject = config
+        .lookup(NATS_SUBJECT)
+        .ok_or_else(|| TargetError::Configuration("Missing NATS subject".to_string()))?;
+
+    Ok(NATSArgs {
+        enable: true, // Assumed enabled.
+        address,
+        subject,
+        username: config.lookup(NATS_USERNAME).unwrap_or_default(),
+        password: config.lookup(NATS_PASSWORD).unwrap_or_default(),
+        token: config.lookup(NATS_TOKEN).unwrap_or_default(),
+        nkey_seed: config.lookup(NATS_NKEY_SEED).unwrap_or_default(),
+        user_credentials: config.lookup(NATS_USER_CREDENTIALS).unwrap_or_default(),
+        tls: parse_optional_bool(config.lookup(NATS_TLS))?,
+        tls_ca: config.lookup(NATS_TLS_CA).unwrap_or_default(),
+        client_tls_cert: config.lookup(NATS_CLIENT_TLS_CERT).unwrap_or_default(),
+        client_tls_key: config.lookup(NATS_CLIENT_TLS_KEY).unwrap_or_default(),
+        jetstream: parse_optional_bool(config.lookup(NATS_JETSTREAM))?,
+        queue_dir: config.lookup(NATS_QUEUE_DIR).unwrap_or(AUDIT_DEFAULT_DIR.to_string()),
+        queue_limit: config
+            .lookup(NATS_QUEUE_LIMIT)
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_LIMIT),
+        target_type: rustfs_targets::target::TargetType::AuditLog,
+    })
+}
+
+#[async_trait]
+impl TargetFactory for NATSTargetFactory {
+    async fn create_target(&self, id: String, config: &KVS) -> Result<Box<dyn Target<AuditEntry> + Send + Sync>, TargetError> {
+        let args = nats_args(config)?;
+        let target = rustfs_targets::target::nats::NATSTarget::new(id, args)?;
+        Ok(Box::new(target))
+    }
+
+    fn validate_config(&self, _id: &str, config: &KVS) -> Result<(), TargetError> {
+        nats_args(config)?.validate()
+    }
+
+    fn get_valid_fields(&self) -> HashSet<String> {
+        AUDIT_NATS_KEYS.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn get_valid_env_fields(&self) -> HashSet<String> {
+        ENV_AUDIT_NATS_KEYS.iter().map(|s| s.to_string()).collect()
+    }
+}
@@ -19,6 +19,8 @@ pub mod globals;
 pub mod heal_channel;
 pub mod last_minute;
 pub mod metrics;
+pub mod service_signal;
+pub mod trace_channel;
 mod readiness;
 
 pub use globals::*;
@@ -40,6 +40,7 @@ pub enum IlmAction {
     DeleteRestoredVersionAction,
     DeleteAllVersionsAction,
     DelMarkerDeleteAllVersionsAction,
+    AbortMultipartUploadAction,
     ActionCount,
 }
 
@@ -77,6 +78,7 @@ impl IlmAction {
             Self::DeleteRestoredVersionAction => "delete_restored_version",
             Self::DeleteAllVersionsAction => "delete_all_versions",
             Self::DelMarkerDeleteAllVersionsAction => "del_marker_delete_all_versions",
+            Self::AbortMultipartUploadAction => "abort_multipart_upload",
             Self::ActionCount => "action_count",
         }
     }
@@ -92,7 +94,8 @@ impl IlmAction {
             6 => Some(Self::DeleteRestoredVersionAction),
             7 => Some(Self::DeleteAllVersionsAction),
             8 => Some(Self::DelMarkerDeleteAllVersionsAction),
-            9 => Some(Self::ActionCount),
+            9 => Some(Self::AbortMultipartUploadAction),
+            10 => Some(Self::ActionCount),
             _ => None,
         }
     }
@@ -0,0 +1,115 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Node-local service control: restart/stop requests and S3 API freezing.
+//!
+//! Signals arrive through the admin service API, either directly on the node
+//! that received the request or through the `SignalService` peer RPC.
+
+use std::sync::LazyLock;
+use tokio::sync::{broadcast, watch};
+
+/// Service signals exchanged between peers; the numeric values are part of the peer RPC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u64)]
+pub enum ServiceSignal {
+    Restart = 0,
+    Stop = 1,
+    ReloadDynamic = 2,
+    Freeze = 3,
+    Unfreeze = 4,
+}
+
+impl TryFrom<u64> for ServiceSignal {
+    type Error = String;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Restart),
+            1 => Ok(Self::Stop),
+            2 => Ok(Self::ReloadDynamic),
+            3 => Ok(Self::Freeze),
+            4 => Ok(Self::Unfreeze),
+            _ => Err(format!("unknown service signal: {value}")),
+        }
+    }
+}
+
+static GLOBAL_SERVICE_SIGNAL: LazyLock<broadcast::Sender<ServiceSignal>> = LazyLock::new(|| broadcast::channel(16).0);
+
+/// Number of outstanding freeze requests; S3 calls are held while it is non-zero
+static GLOBAL_SERVICE_FREEZE: LazyLock<watch::Sender<usize>> = LazyLock::new(|| watch::channel(0).0);
+
+/// Deliver a restart or stop request to the process main loop.
+///
+/// Returns `false` when nothing is listening for service signals.
+pub fn send_service_signal(signal: ServiceSignal) -> bool {
+    GLOBAL_SERVICE_SIGNAL.send(signal).is_ok()
+}
+
+/// Subscribe to restart and stop requests.
+pub fn subscribe_service_signal() -> broadcast::Receiver<ServiceSignal> {
+    GLOBAL_SERVICE_SIGNAL.subscribe()
+}
+
+/// Hold new S3 API calls until [`unfreeze_services`] is called.
+pub fn freeze_services() {
+    GLOBAL_SERVICE_FREEZE.send_modify(|count| *count += 1);
+}
+
+/// Release one outstanding freeze; calls resume once no freeze is left.
+pub fn unfreeze_services() {
+    GLOBAL_SERVICE_FREEZE.send_modify(|count| *count = count.saturating_sub(1));
+}
+
+pub fn is_services_frozen() -> bool {
+    *GLOBAL_SERVICE_FREEZE.borrow() > 0
+}
+
+/// Wait until the S3 API is no longer frozen.
+pub async fn wait_for_unfreeze() {
+    let mut rx = GLOBAL_SERVICE_FREEZE.subscribe();
+    // The sender lives in a static and is never dropped.
+    let _ = rx.wait_for(|count| *count == 0).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_service_signal_from_u64() {
+        assert_eq!(ServiceSignal::try_from(ServiceSignal::Freeze as u64), Ok(ServiceSignal::Freeze));
+        assert_eq!(ServiceSignal::try_from(0), Ok(ServiceSignal::Restart));
+        assert!(ServiceSignal::try_from(42).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_freeze_and_unfreeze() {
+        freeze_services();
+        freeze_services();
+        assert!(is_services_frozen());
+
+        let waiter = tokio::spawn(wait_for_unfreeze());
+        unfreeze_services();
+        assert!(is_services_frozen());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!waiter.is_finished());
+
+        unfreeze_services();
+        tokio::time::timeout(Duration::from_secs(1), waiter).await.unwrap().unwrap();
+        assert!(!is_services_frozen());
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Node-local publish/subscribe channel for admin trace records.
+//!
+//! Producers (HTTP layer, internal RPC, storage, scanner, heal) call
+//! [`is_tracing`] before building a [`TraceInfo`] so that tracing costs
+//! nothing while no `admin trace` client is connected.
+
+use crate::GLOBAL_LOCAL_NODE_NAME;
+use rustfs_madmin::trace::{TraceInfo, TraceType};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use tokio::sync::broadcast;
+use tracing::warn;
+
+/// Number of records buffered per subscriber before the slowest one starts losing records
+const TRACE_CHANNEL_CAPACITY: usize = 10000;
+
+/// Number of distinct trace type bits tracked for subscriber accounting
+const TRACE_TYPE_BITS: usize = u64::BITS as usize;
+
+struct TraceChannel {
+    tx: broadcast::Sender<Arc<TraceInfo>>,
+    /// Active subscribers per trace type bit
+    subscribers: [AtomicUsize; TRACE_TYPE_BITS],
+}
+
+static GLOBAL_TRACE_CHANNEL: OnceLock<TraceChannel> = OnceLock::new();
+
+fn trace_channel() -> &'static TraceChannel {
+    GLOBAL_TRACE_CHANNEL.get_or_init(|| {
+        let (tx, _rx) = broadcast::channel(TRACE_CHANNEL_CAPACITY);
+        TraceChannel {
+            tx,
+            subscribers: std::array::from_fn(|_| AtomicUsize::new(0)),
+        }
+    })
+}
+
+fn for_each_bit(mask: u64, mut f: impl FnMut(usize)) {
+    (0..TRACE_TYPE_BITS).filter(|bit| mask & (1 << bit) != 0).for_each(&mut f);
+}
+
+/// Reports whether any subscriber is interested in one of the given trace types.
+pub fn is_tracing(trace_type: TraceType) -> bool {
+    let Some(channel) = GLOBAL_TRACE_CHANNEL.get() else {
+        return false;
+    };
+
+    let mut found = false;
+    for_each_bit(trace_type.mask(), |bit| {
+        found |= channel.subscribers[bit].load(Ordering::Relaxed) > 0;
+    });
+    found
+}
+
+/// Publish a trace record to all local subscribers.
+///
+/// The node name is filled in when the producer left it empty.
+pub fn publish_trace(mut info: TraceInfo) {
+    if !is_tracing(TraceType::new(info.trace_type)) {
+        return;
+    }
+
+    if info.node_name.is_empty()
+        && let Ok(name) = GLOBAL_LOCAL_NODE_NAME.try_read()
+    {
+        info.node_name = name.clone();
+    }
+
+    // Sending only fails when every receiver is gone, which is fine for tracing.
+    let _ = trace_channel().tx.send(Arc::new(info));
+}
+
+/// Subscribe to trace records of the given types.
+///
+/// The subscription is accounted for until the returned value is dropped.
+pub fn subscribe_trace(trace_type: TraceType) -> TraceSubscription {
+    let channel = trace_channel();
+    for_each_bit(trace_type.mask(), |bit| {
+        channel.subscribers[bit].fetch_add(1, Ordering::Relaxed);
+    });
+
+    TraceSubscription {
+        rx: channel.tx.subscribe(),
+        trace_type,
+    }
+}
+
+/// A live trace subscription
+pub struct TraceSubscription {
+    rx: broadcast::Receiver<Arc<TraceInfo>>,
+    trace_type: TraceType,
+}
+
+impl TraceSubscription {
+    /// Receive the next record matching the subscribed types.
+    ///
+    /// Records are dropped when the subscriber falls behind; `None` is
+    /// returned once the channel is closed.
+    pub async fn recv(&mut self) -> Option<Arc<TraceInfo>> {
+        loop {
+            match self.rx.recv().await {
+                Ok(info) if self.trace_type.overlaps(&TraceType::new(info.trace_type)) => return Some(info),
+                Ok(_) => continue,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("trace subscriber is falling behind, dropped {} records", skipped);
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+impl Drop for TraceSubscription {
+    fn drop(&mut self) {
+        let channel = trace_channel();
+        for_each_bit(self.trace_type.mask(), |bit| {
+            channel.subscribers[bit].fetch_sub(1, Ordering::Relaxed);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_subscribe_and_publish() {
+        assert!(!is_tracing(TraceType::SCANNER));
+
+        let mut sub = subscribe_trace(TraceType::SCANNER);
+        assert!(is_tracing(TraceType::SCANNER));
+        assert!(!is_tracing(TraceType::BOOTSTRAP));
+
+        publish_trace(TraceInfo::new(TraceType::BOOTSTRAP, "bootstrap.Ignored", ""));
+        publish_trace(TraceInfo::new(TraceType::SCANNER, "scanner.ScanObject", "bucket/object"));
+
+        let info = sub.recv().await.unwrap();
+        assert_eq!(info.func_name, "scanner.ScanObject");
+
+        drop(sub);
+        assert!(!is_tracing(TraceType::SCANNER));
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Environment variable name that specifies how often buffered server access log lines are written to the target buckets.
+/// - Purpose: Bound the delay between a request and its access log record becoming visible in the target bucket.
+/// - Unit: seconds (u64).
+/// - Valid values: any positive integer.
+/// - Semantics: Each flush writes one log object per target bucket and prefix that received records since the last flush.
+/// - Example: `export RUSTFS_ACCESS_LOG_FLUSH_INTERVAL_SECS=60`
+/// - Note: Shorter intervals produce more, smaller log objects.
+pub const ENV_ACCESS_LOG_FLUSH_INTERVAL_SECS: &str = "RUSTFS_ACCESS_LOG_FLUSH_INTERVAL_SECS";
+
+/// Default server access log flush interval in seconds.
+/// - Value: 300 seconds.
+/// - Rationale: Matches the best-effort delivery delay of S3 server access logging.
+pub const DEFAULT_ACCESS_LOG_FLUSH_INTERVAL_SECS: u64 = 300;
+
+/// Environment variable name that specifies how many access log lines are buffered for one target before it is flushed early.
+/// - Purpose: Cap the memory held by busy source buckets between two periodic flushes.
+/// - Unit: number of log lines (usize).
+/// - Example: `export RUSTFS_ACCESS_LOG_MAX_BATCH=5000`
+pub const ENV_ACCESS_LOG_MAX_BATCH: &str = "RUSTFS_ACCESS_LOG_MAX_BATCH";
+
+/// Default number of access log lines buffered per target before an early flush.
+pub const DEFAULT_ACCESS_LOG_MAX_BATCH: usize = 1000;
@@ -0,0 +1,25 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/// Environment variable name that specifies how many objects an inventory data file holds before a new file is started.
+/// - Purpose: Keep the individual CSV and Parquet files of large inventory reports at a size downstream tooling can process.
+/// - Unit: number of objects (usize).
+/// - Valid values: any positive integer.
+/// - Semantics: Every data file of a report is listed in its `manifest.json`.
+/// - Example: `export RUSTFS_INVENTORY_MAX_ROWS_PER_FILE=500000`
+/// - Note: Rows are buffered in memory until a file is written, so larger values use more memory during report generation.
+pub const ENV_INVENTORY_MAX_ROWS_PER_FILE: &str = "RUSTFS_INVENTORY_MAX_ROWS_PER_FILE";
+
+/// Default number of objects per inventory data file.
+/// - Value: 1,000,000 objects.
+pub const DEFAULT_INVENTORY_MAX_ROWS_PER_FILE: usize = 1_000_000;
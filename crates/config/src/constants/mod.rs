@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub(crate) mod access_log;
 pub(crate) mod app;
 pub(crate) mod body_limits;
 pub(crate) mod compress;
 pub(crate) mod console;
 pub(crate) mod env;
 pub(crate) mod heal;
+pub(crate) mod inventory;
 pub(crate) mod object;
 pub(crate) mod profiler;
 pub(crate) mod protocols;
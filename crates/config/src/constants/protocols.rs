@@ -45,3 +45,27 @@ pub const ENV_FTPS_CERTS_DIR: &str = "RUSTFS_FTPS_CERTS_DIR";
 pub const ENV_FTPS_CA_FILE: &str = "RUSTFS_FTPS_CA_FILE";
 pub const ENV_FTPS_PASSIVE_PORTS: &str = "RUSTFS_FTPS_PASSIVE_PORTS";
 pub const ENV_FTPS_EXTERNAL_IP: &str = "RUSTFS_FTPS_EXTERNAL_IP";
+
+/// Default SFTP server bind address (SSH File Transfer Protocol)
+pub const DEFAULT_SFTP_ADDRESS: &str = "0.0.0.0:8023";
+
+/// Default SFTP idle session timeout in seconds
+pub const DEFAULT_SFTP_IDLE_TIMEOUT: u64 = 600;
+
+pub const ENV_SFTP_ENABLE: &str = "RUSTFS_SFTP_ENABLE";
+pub const ENV_SFTP_ADDRESS: &str = "RUSTFS_SFTP_ADDRESS";
+pub const ENV_SFTP_HOST_KEY: &str = "RUSTFS_SFTP_HOST_KEY";
+pub const ENV_SFTP_IDLE_TIMEOUT: &str = "RUSTFS_SFTP_IDLE_TIMEOUT";
+pub const ENV_SFTP_PASSWORD_AUTH: &str = "RUSTFS_SFTP_PASSWORD_AUTH";
+
+/// Default WebDAV server bind address
+pub const DEFAULT_WEBDAV_ADDRESS: &str = "0.0.0.0:8024";
+
+/// Default maximum WebDAV lock timeout in seconds
+pub const DEFAULT_WEBDAV_MAX_LOCK_TIMEOUT: u64 = 3600;
+
+pub const ENV_WEBDAV_ENABLE: &str = "RUSTFS_WEBDAV_ENABLE";
+pub const ENV_WEBDAV_ADDRESS: &str = "RUSTFS_WEBDAV_ADDRESS";
+pub const ENV_WEBDAV_TLS_ENABLED: &str = "RUSTFS_WEBDAV_TLS_ENABLED";
+pub const ENV_WEBDAV_CERTS_DIR: &str = "RUSTFS_WEBDAV_CERTS_DIR";
+pub const ENV_WEBDAV_MAX_LOCK_TIMEOUT: &str = "RUSTFS_WEBDAV_MAX_LOCK_TIMEOUT";
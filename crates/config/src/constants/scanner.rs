@@ -26,3 +26,15 @@ pub const ENV_DATA_SCANNER_START_DELAY_SECS: &str = "RUSTFS_DATA_SCANNER_START_D
 /// - Rationale: This default interval provides a reasonable balance between scanning responsiveness and system load for most deployments.
 /// - Adjustments: Users may modify this value via the `RUSTFS_DATA_SCANNER_START_DELAY_SECS` environment variable based on their specific scanning requirements and system performance.
 pub const DEFAULT_DATA_SCANNER_START_DELAY_SECS: u64 = 60;
+
+/// Environment variable name that specifies how old a multipart upload without a recorded owner
+/// must be before the scanner removes it.
+/// - Unit: seconds (u64).
+/// - Semantics: Uploads started before the owning bucket and object were recorded cannot be matched
+///   against a bucket's AbortIncompleteMultipartUpload rule, so they are removed by upload path once stale.
+/// - Example: `export RUSTFS_STALE_UPLOADS_EXPIRY_SECS=86400`
+pub const ENV_STALE_UPLOADS_EXPIRY_SECS: &str = "RUSTFS_STALE_UPLOADS_EXPIRY_SECS";
+
+/// Default age in seconds after which a multipart upload without a recorded owner is removed.
+/// - Value: 24 hours.
+pub const DEFAULT_STALE_UPLOADS_EXPIRY_SECS: u64 = 24 * 60 * 60;
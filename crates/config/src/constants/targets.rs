@@ -32,3 +32,42 @@ pub const MQTT_RECONNECT_INTERVAL: &str = "reconnect_interval";
 pub const MQTT_KEEP_ALIVE_INTERVAL: &str = "keep_alive_interval";
 pub const MQTT_QUEUE_DIR: &str = "queue_dir";
 pub const MQTT_QUEUE_LIMIT: &str = "queue_limit";
+
+pub const KAFKA_BROKERS: &str = "brokers";
+pub const KAFKA_TOPIC: &str = "topic";
+pub const KAFKA_KEY_TEMPLATE: &str = "key_template";
+pub const KAFKA_ACKS: &str = "acks";
+pub const KAFKA_BATCH_SIZE: &str = "batch_size";
+pub const KAFKA_BATCH_COMMIT_TIMEOUT: &str = "batch_commit_timeout";
+pub const KAFKA_SASL: &str = "sasl";
+pub const KAFKA_SASL_USERNAME: &str = "sasl_username";
+pub const KAFKA_SASL_PASSWORD: &str = "sasl_password";
+pub const KAFKA_SASL_MECHANISM: &str = "sasl_mechanism";
+pub const KAFKA_TLS: &str = "tls";
+pub const KAFKA_TLS_SKIP_VERIFY: &str = "tls_skip_verify";
+pub const KAFKA_TLS_CA: &str = "tls_ca";
+pub const KAFKA_CLIENT_TLS_CERT: &str = "client_tls_cert";
+pub const KAFKA_CLIENT_TLS_KEY: &str = "client_tls_key";
+pub const KAFKA_QUEUE_DIR: &str = "queue_dir";
+pub const KAFKA_QUEUE_LIMIT: &str = "queue_limit";
+
+/// Default Kafka message key, `{bucket}` and `{object}` are replaced per event
+pub const DEFAULT_KAFKA_KEY_TEMPLATE: &str = "{bucket}/{object}";
+pub const DEFAULT_KAFKA_ACKS: &str = "all";
+pub const DEFAULT_KAFKA_BATCH_SIZE: u64 = 100;
+pub const DEFAULT_KAFKA_BATCH_COMMIT_TIMEOUT: &str = "10ms";
+
+pub const NATS_ADDRESS: &str = "address";
+pub const NATS_SUBJECT: &str = "subject";
+pub const NATS_USERNAME: &str = "username";
+pub const NATS_PASSWORD: &str = "password";
+pub const NATS_TOKEN: &str = "token";
+pub const NATS_NKEY_SEED: &str = "nkey_seed";
+pub const NATS_USER_CREDENTIALS: &str = "user_credentials";
+pub const NATS_TLS: &str = "tls";
+pub const NATS_TLS_CA: &str = "tls_ca";
+pub const NATS_CLIENT_TLS_CERT: &str = "client_tls_cert";
+pub const NATS_CLIENT_TLS_KEY: &str = "client_tls_key";
+pub const NATS_JETSTREAM: &str = "jetstream";
+pub const NATS_QUEUE_DIR: &str = "queue_dir";
+pub const NATS_QUEUE_LIMIT: &str = "queue_limit";
@@ -84,3 +84,16 @@ pub const ENV_SERVER_MTLS_ENABLE: &str = "RUSTFS_SERVER_MTLS_ENABLE";
 /// By default, RustFS server mTLS is disabled.
 /// To change this behavior, set the environment variable RUSTFS_SERVER_MTLS_ENABLE=1
 pub const DEFAULT_SERVER_MTLS_ENABLE: bool = false;
+
+/// RUSTFS_IDENTITY_TLS_ENABLE
+/// Environment variable to enable STS AssumeRoleWithCertificate
+/// When set to "1", the server requests (but does not require) client certificates signed by the client CA
+/// and accepts them as identities for AssumeRoleWithCertificate.
+/// By default, this is disabled.
+/// To enable, set the environment variable RUSTFS_IDENTITY_TLS_ENABLE=1
+pub const ENV_IDENTITY_TLS_ENABLE: &str = "RUSTFS_IDENTITY_TLS_ENABLE";
+
+/// Default value for enabling STS AssumeRoleWithCertificate
+/// By default, certificate identities are disabled.
+/// To change this behavior, set the environment variable RUSTFS_IDENTITY_TLS_ENABLE=1
+pub const DEFAULT_IDENTITY_TLS_ENABLE: bool = false;
@@ -0,0 +1,49 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//ldap identity provider env vars
+pub const ENV_IDENTITY_LDAP_SERVER_ADDR: &str = "RUSTFS_IDENTITY_LDAP_SERVER_ADDR";
+pub const ENV_IDENTITY_LDAP_LOOKUP_BIND_DN: &str = "RUSTFS_IDENTITY_LDAP_LOOKUP_BIND_DN";
+pub const ENV_IDENTITY_LDAP_LOOKUP_BIND_PASSWORD: &str = "RUSTFS_IDENTITY_LDAP_LOOKUP_BIND_PASSWORD";
+pub const ENV_IDENTITY_LDAP_USER_DN_SEARCH_BASE_DN: &str = "RUSTFS_IDENTITY_LDAP_USER_DN_SEARCH_BASE_DN";
+pub const ENV_IDENTITY_LDAP_USER_DN_SEARCH_FILTER: &str = "RUSTFS_IDENTITY_LDAP_USER_DN_SEARCH_FILTER";
+pub const ENV_IDENTITY_LDAP_GROUP_SEARCH_BASE_DN: &str = "RUSTFS_IDENTITY_LDAP_GROUP_SEARCH_BASE_DN";
+pub const ENV_IDENTITY_LDAP_GROUP_SEARCH_FILTER: &str = "RUSTFS_IDENTITY_LDAP_GROUP_SEARCH_FILTER";
+pub const ENV_IDENTITY_LDAP_SERVER_STARTTLS: &str = "RUSTFS_IDENTITY_LDAP_SERVER_STARTTLS";
+pub const ENV_IDENTITY_LDAP_SERVER_INSECURE: &str = "RUSTFS_IDENTITY_LDAP_SERVER_INSECURE";
+pub const ENV_IDENTITY_LDAP_TLS_SKIP_VERIFY: &str = "RUSTFS_IDENTITY_LDAP_TLS_SKIP_VERIFY";
+
+pub const ENV_IDENTITY_LDAP_KEYS: &[&str] = &[
+    ENV_IDENTITY_LDAP_SERVER_ADDR,
+    ENV_IDENTITY_LDAP_LOOKUP_BIND_DN,
+    ENV_IDENTITY_LDAP_LOOKUP_BIND_PASSWORD,
+    ENV_IDENTITY_LDAP_USER_DN_SEARCH_BASE_DN,
+    ENV_IDENTITY_LDAP_USER_DN_SEARCH_FILTER,
+    ENV_IDENTITY_LDAP_GROUP_SEARCH_BASE_DN,
+    ENV_IDENTITY_LDAP_GROUP_SEARCH_FILTER,
+    ENV_IDENTITY_LDAP_SERVER_STARTTLS,
+    ENV_IDENTITY_LDAP_SERVER_INSECURE,
+    ENV_IDENTITY_LDAP_TLS_SKIP_VERIFY,
+];
+
+pub const IDENTITY_LDAP_SUB_SYS: &str = "identity_ldap";
+
+/// Port used for ldaps:// connections when the server address has none
+pub const DEFAULT_IDENTITY_LDAP_TLS_PORT: u16 = 636;
+
+/// Port used for ldap:// (plain or StartTLS) connections when the server address has none
+pub const DEFAULT_IDENTITY_LDAP_PORT: u16 = 389;
+
+/// Seconds allowed for connecting to the LDAP server
+pub const DEFAULT_IDENTITY_LDAP_CONNECT_TIMEOUT: u64 = 5;
@@ -15,6 +15,8 @@
 #[cfg(feature = "constants")]
 pub mod constants;
 #[cfg(feature = "constants")]
+pub use constants::access_log::*;
+#[cfg(feature = "constants")]
 pub use constants::app::*;
 #[cfg(feature = "constants")]
 pub use constants::body_limits::*;
@@ -27,6 +29,8 @@ pub use constants::env::*;
 #[cfg(feature = "constants")]
 pub use constants::heal::*;
 #[cfg(feature = "constants")]
+pub use constants::inventory::*;
+#[cfg(feature = "constants")]
 pub use constants::object::*;
 #[cfg(feature = "constants")]
 pub use constants::profiler::*;
@@ -46,9 +50,13 @@ pub use constants::targets::*;
 pub use constants::tls::*;
 #[cfg(feature = "audit")]
 pub mod audit;
+#[cfg(feature = "ldap")]
+pub mod ldap;
 #[cfg(feature = "notify")]
 pub mod notify;
 #[cfg(feature = "observability")]
 pub mod observability;
+#[cfg(feature = "oidc")]
+pub mod oidc;
 #[cfg(feature = "opa")]
 pub mod opa;
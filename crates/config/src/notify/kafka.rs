@@ -0,0 +1,77 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A list of all valid configuration keys for a Kafka target.
+pub const NOTIFY_KAFKA_KEYS: &[&str] = &[
+    crate::ENABLE_KEY,
+    crate::KAFKA_BROKERS,
+    crate::KAFKA_TOPIC,
+    crate::KAFKA_KEY_TEMPLATE,
+    crate::KAFKA_ACKS,
+    crate::KAFKA_BATCH_SIZE,
+    crate::KAFKA_BATCH_COMMIT_TIMEOUT,
+    crate::KAFKA_SASL,
+    crate::KAFKA_SASL_USERNAME,
+    crate::KAFKA_SASL_PASSWORD,
+    crate::KAFKA_SASL_MECHANISM,
+    crate::KAFKA_TLS,
+    crate::KAFKA_TLS_SKIP_VERIFY,
+    crate::KAFKA_TLS_CA,
+    crate::KAFKA_CLIENT_TLS_CERT,
+    crate::KAFKA_CLIENT_TLS_KEY,
+    crate::KAFKA_QUEUE_DIR,
+    crate::KAFKA_QUEUE_LIMIT,
+    crate::COMMENT_KEY,
+];
+
+// Kafka Environment Variables
+pub const ENV_NOTIFY_KAFKA_ENABLE: &str = "RUSTFS_NOTIFY_KAFKA_ENABLE";
+pub const ENV_NOTIFY_KAFKA_BROKERS: &str = "RUSTFS_NOTIFY_KAFKA_BROKERS";
+pub const ENV_NOTIFY_KAFKA_TOPIC: &str = "RUSTFS_NOTIFY_KAFKA_TOPIC";
+pub const ENV_NOTIFY_KAFKA_KEY_TEMPLATE: &str = "RUSTFS_NOTIFY_KAFKA_KEY_TEMPLATE";
+pub const ENV_NOTIFY_KAFKA_ACKS: &str = "RUSTFS_NOTIFY_KAFKA_ACKS";
+pub const ENV_NOTIFY_KAFKA_BATCH_SIZE: &str = "RUSTFS_NOTIFY_KAFKA_BATCH_SIZE";
+pub const ENV_NOTIFY_KAFKA_BATCH_COMMIT_TIMEOUT: &str = "RUSTFS_NOTIFY_KAFKA_BATCH_COMMIT_TIMEOUT";
+pub const ENV_NOTIFY_KAFKA_SASL: &str = "RUSTFS_NOTIFY_KAFKA_SASL";
+pub const ENV_NOTIFY_KAFKA_SASL_USERNAME: &str = "RUSTFS_NOTIFY_KAFKA_SASL_USERNAME";
+pub const ENV_NOTIFY_KAFKA_SASL_PASSWORD: &str = "RUSTFS_NOTIFY_KAFKA_SASL_PASSWORD";
+pub const ENV_NOTIFY_KAFKA_SASL_MECHANISM: &str = "RUSTFS_NOTIFY_KAFKA_SASL_MECHANISM";
+pub const ENV_NOTIFY_KAFKA_TLS: &str = "RUSTFS_NOTIFY_KAFKA_TLS";
+pub const ENV_NOTIFY_KAFKA_TLS_SKIP_VERIFY: &str = "RUSTFS_NOTIFY_KAFKA_TLS_SKIP_VERIFY";
+pub const ENV_NOTIFY_KAFKA_TLS_CA: &str = "RUSTFS_NOTIFY_KAFKA_TLS_CA";
+pub const ENV_NOTIFY_KAFKA_CLIENT_TLS_CERT: &str = "RUSTFS_NOTIFY_KAFKA_CLIENT_TLS_CERT";
+pub const ENV_NOTIFY_KAFKA_CLIENT_TLS_KEY: &str = "RUSTFS_NOTIFY_KAFKA_CLIENT_TLS_KEY";
+pub const ENV_NOTIFY_KAFKA_QUEUE_DIR: &str = "RUSTFS_NOTIFY_KAFKA_QUEUE_DIR";
+pub const ENV_NOTIFY_KAFKA_QUEUE_LIMIT: &str = "RUSTFS_NOTIFY_KAFKA_QUEUE_LIMIT";
+
+pub const ENV_NOTIFY_KAFKA_KEYS: &[&str; 18] = &[
+    ENV_NOTIFY_KAFKA_ENABLE,
+    ENV_NOTIFY_KAFKA_BROKERS,
+    ENV_NOTIFY_KAFKA_TOPIC,
+    ENV_NOTIFY_KAFKA_KEY_TEMPLATE,
+    ENV_NOTIFY_KAFKA_ACKS,
+    ENV_NOTIFY_KAFKA_BATCH_SIZE,
+    ENV_NOTIFY_KAFKA_BATCH_COMMIT_TIMEOUT,
+    ENV_NOTIFY_KAFKA_SASL,
+    ENV_NOTIFY_KAFKA_SASL_USERNAME,
+    ENV_NOTIFY_KAFKA_SASL_PASSWORD,
+    ENV_NOTIFY_KAFKA_SASL_MECHANISM,
+    ENV_NOTIFY_KAFKA_TLS,
+    ENV_NOTIFY_KAFKA_TLS_SKIP_VERIFY,
+    ENV_NOTIFY_KAFKA_TLS_CA,
+    ENV_NOTIFY_KAFKA_CLIENT_TLS_CERT,
+    ENV_NOTIFY_KAFKA_CLIENT_TLS_KEY,
+    ENV_NOTIFY_KAFKA_QUEUE_DIR,
+    ENV_NOTIFY_KAFKA_QUEUE_LIMIT,
+];
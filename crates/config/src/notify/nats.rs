@@ -0,0 +1,68 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A list of all valid configuration keys for a NATS target.
+pub const NOTIFY_NATS_KEYS: &[&str] = &[
+    crate::ENABLE_KEY,
+    crate::NATS_ADDRESS,
+    crate::NATS_SUBJECT,
+    crate::NATS_USERNAME,
+    crate::NATS_PASSWORD,
+    crate::NATS_TOKEN,
+    crate::NATS_NKEY_SEED,
+    crate::NATS_USER_CREDENTIALS,
+    crate::NATS_TLS,
+    crate::NATS_TLS_CA,
+    crate::NATS_CLIENT_TLS_CERT,
+    crate::NATS_CLIENT_TLS_KEY,
+    crate::NATS_JETSTREAM,
+    crate::NATS_QUEUE_DIR,
+    crate::NATS_QUEUE_LIMIT,
+    crate::COMMENT_KEY,
+];
+
+// NATS Environment Variables
+pub const ENV_NOTIFY_NATS_ENABLE: &str = "RUSTFS_NOTIFY_NATS_ENABLE";
+pub const ENV_NOTIFY_NATS_ADDRESS: &str = "RUSTFS_NOTIFY_NATS_ADDRESS";
+pub const ENV_NOTIFY_NATS_SUBJECT: &str = "RUSTFS_NOTIFY_NATS_SUBJECT";
+pub const ENV_NOTIFY_NATS_USERNAME: &str = "RUSTFS_NOTIFY_NATS_USERNAME";
+pub const ENV_NOTIFY_NATS_PASSWORD: &str = "RUSTFS_NOTIFY_NATS_PASSWORD";
+pub const ENV_NOTIFY_NATS_TOKEN: &str = "RUSTFS_NOTIFY_NATS_TOKEN";
+pub const ENV_NOTIFY_NATS_NKEY_SEED: &str = "RUSTFS_NOTIFY_NATS_NKEY_SEED";
+pub const ENV_NOTIFY_NATS_USER_CREDENTIALS: &str = "RUSTFS_NOTIFY_NATS_USER_CREDENTIALS";
+pub const ENV_NOTIFY_NATS_TLS: &str = "RUSTFS_NOTIFY_NATS_TLS";
+pub const ENV_NOTIFY_NATS_TLS_CA: &str = "RUSTFS_NOTIFY_NATS_TLS_CA";
+pub const ENV_NOTIFY_NATS_CLIENT_TLS_CERT: &str = "RUSTFS_NOTIFY_NATS_CLIENT_TLS_CERT";
+pub const ENV_NOTIFY_NATS_CLIENT_TLS_KEY: &str = "RUSTFS_NOTIFY_NATS_CLIENT_TLS_KEY";
+pub const ENV_NOTIFY_NATS_JETSTREAM: &str = "RUSTFS_NOTIFY_NATS_JETSTREAM";
+pub const ENV_NOTIFY_NATS_QUEUE_DIR: &str = "RUSTFS_NOTIFY_NATS_QUEUE_DIR";
+pub const ENV_NOTIFY_NATS_QUEUE_LIMIT: &str = "RUSTFS_NOTIFY_NATS_QUEUE_LIMIT";
+
+pub const ENV_NOTIFY_NATS_KEYS: &[&str; 15] = &[
+    ENV_NOTIFY_NATS_ENABLE,
+    ENV_NOTIFY_NATS_ADDRESS,
+    ENV_NOTIFY_NATS_SUBJECT,
+    ENV_NOTIFY_NATS_USERNAME,
+    ENV_NOTIFY_NATS_PASSWORD,
+    ENV_NOTIFY_NATS_TOKEN,
+    ENV_NOTIFY_NATS_NKEY_SEED,
+    ENV_NOTIFY_NATS_USER_CREDENTIALS,
+    ENV_NOTIFY_NATS_TLS,
+    ENV_NOTIFY_NATS_TLS_CA,
+    ENV_NOTIFY_NATS_CLIENT_TLS_CERT,
+    ENV_NOTIFY_NATS_CLIENT_TLS_KEY,
+    ENV_NOTIFY_NATS_JETSTREAM,
+    ENV_NOTIFY_NATS_QUEUE_DIR,
+    ENV_NOTIFY_NATS_QUEUE_LIMIT,
+];
@@ -0,0 +1,41 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//openid identity provider env vars
+//
+// Each variable may carry a `_<NAME>` suffix to configure additional providers,
+// e.g. RUSTFS_IDENTITY_OPENID_CONFIG_URL_KEYCLOAK.
+pub const ENV_IDENTITY_OPENID_CONFIG_URL: &str = "RUSTFS_IDENTITY_OPENID_CONFIG_URL";
+pub const ENV_IDENTITY_OPENID_CLIENT_ID: &str = "RUSTFS_IDENTITY_OPENID_CLIENT_ID";
+pub const ENV_IDENTITY_OPENID_CLAIM_NAME: &str = "RUSTFS_IDENTITY_OPENID_CLAIM_NAME";
+pub const ENV_IDENTITY_OPENID_CLAIM_PREFIX: &str = "RUSTFS_IDENTITY_OPENID_CLAIM_PREFIX";
+pub const ENV_IDENTITY_OPENID_ROLE_POLICY: &str = "RUSTFS_IDENTITY_OPENID_ROLE_POLICY";
+pub const ENV_IDENTITY_OPENID_JWKS_REFRESH_INTERVAL: &str = "RUSTFS_IDENTITY_OPENID_JWKS_REFRESH_INTERVAL";
+
+pub const ENV_IDENTITY_OPENID_KEYS: &[&str] = &[
+    ENV_IDENTITY_OPENID_CONFIG_URL,
+    ENV_IDENTITY_OPENID_CLIENT_ID,
+    ENV_IDENTITY_OPENID_CLAIM_NAME,
+    ENV_IDENTITY_OPENID_CLAIM_PREFIX,
+    ENV_IDENTITY_OPENID_ROLE_POLICY,
+    ENV_IDENTITY_OPENID_JWKS_REFRESH_INTERVAL,
+];
+
+pub const IDENTITY_OPENID_SUB_SYS: &str = "identity_openid";
+
+/// Claim holding the policies of the authenticated identity
+pub const DEFAULT_IDENTITY_OPENID_CLAIM_NAME: &str = "policy";
+
+/// Seconds between JWKS refreshes
+pub const DEFAULT_IDENTITY_OPENID_JWKS_REFRESH_INTERVAL: u64 = 3600;
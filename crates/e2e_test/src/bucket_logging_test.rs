@@ -0,0 +1,121 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verifies that PutBucketLogging requires write access to the log target.
+
+use crate::bucket_policy_check_test::{create_user, create_user_client};
+use crate::common::{RustFSTestEnvironment, init_logging};
+use aws_sdk_s3::Client;
+use aws_sdk_s3::types::{BucketLoggingStatus, LoggingEnabled};
+use serial_test::serial;
+use tracing::info;
+
+async fn put_bucket_policy(
+    client: &Client,
+    bucket: &str,
+    user: &str,
+    actions: &[&str],
+    resource: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let policy_json = serde_json::json!({
+        "Version": "2012-10-17",
+        "Statement": [
+            {
+                "Effect": "Allow",
+                "Principal": { "AWS": [user] },
+                "Action": actions,
+                "Resource": [format!("arn:aws:s3:::{resource}")]
+            }
+        ]
+    })
+    .to_string();
+
+    client.put_bucket_policy().bucket(bucket).policy(&policy_json).send().await?;
+    Ok(())
+}
+
+fn logging_status(
+    target_bucket: &str,
+    target_prefix: &str,
+) -> Result<BucketLoggingStatus, Box<dyn std::error::Error + Send + Sync>> {
+    Ok(BucketLoggingStatus::builder()
+        .logging_enabled(
+            LoggingEnabled::builder()
+                .target_bucket(target_bucket)
+                .target_prefix(target_prefix)
+                .build()?,
+        )
+        .build())
+}
+
+#[tokio::test]
+#[serial]
+async fn test_put_bucket_logging_requires_target_write_access() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    init_logging();
+    info!("Starting test_put_bucket_logging_requires_target_write_access...");
+
+    let mut env = RustFSTestEnvironment::new().await?;
+    env.start_rustfs_server(vec![]).await?;
+
+    let admin_client = env.create_s3_client();
+    let source_bucket = "logging-source";
+    let target_bucket = "logging-target";
+    let user_access = "logginguser";
+    let user_secret = "loggingpassword";
+
+    admin_client.create_bucket().bucket(source_bucket).send().await?;
+    admin_client.create_bucket().bucket(target_bucket).send().await?;
+    create_user(&env, user_access, user_secret).await?;
+    let user_client = create_user_client(&env, user_access, user_secret);
+
+    // The user may configure logging on the source bucket only
+    put_bucket_policy(&admin_client, source_bucket, user_access, &["s3:PutBucketLogging"], source_bucket).await?;
+
+    let result = user_client
+        .put_bucket_logging()
+        .bucket(source_bucket)
+        .bucket_logging_status(logging_status(target_bucket, "access/")?)
+        .send()
+        .await;
+    assert!(result.is_err(), "logging into a bucket the user cannot write to must be denied");
+
+    // Allow writes under the target prefix
+    put_bucket_policy(
+        &admin_client,
+        target_bucket,
+        user_access,
+        &["s3:PutObject"],
+        &format!("{target_bucket}/access/*"),
+    )
+    .await?;
+
+    user_client
+        .put_bucket_logging()
+        .bucket(source_bucket)
+        .bucket_logging_status(logging_status(target_bucket, "access/")?)
+        .send()
+        .await
+        .map_err(|e| format!("PutBucketLogging failed: {}", e))?;
+
+    let result = user_client
+        .put_bucket_logging()
+        .bucket(source_bucket)
+        .bucket_logging_status(logging_status(target_bucket, "other/")?)
+        .send()
+        .await;
+    assert!(result.is_err(), "logging outside the writable prefix must be denied");
+
+    info!("Test Passed!");
+    Ok(())
+}
@@ -21,7 +21,7 @@ use aws_sdk_s3::{Client, Config};
 use serial_test::serial;
 use tracing::info;
 
-async fn create_user(
+pub(crate) async fn create_user(
     env: &RustFSTestEnvironment,
     username: &str,
     password: &str,
@@ -37,7 +37,7 @@ async fn create_user(
     Ok(())
 }
 
-fn create_user_client(env: &RustFSTestEnvironment, access_key: &str, secret_key: &str) -> Client {
+pub(crate) fn create_user_client(env: &RustFSTestEnvironment, access_key: &str, secret_key: &str) -> Client {
     let credentials = Credentials::new(access_key, secret_key, None, None, "test-user");
     let config = Config::builder()
         .credentials_provider(credentials)
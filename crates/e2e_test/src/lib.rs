@@ -36,6 +36,14 @@ mod quota_test;
 #[cfg(test)]
 mod bucket_policy_check_test;
 
+// Server access logging tests
+#[cfg(test)]
+mod bucket_logging_test;
+
+// Object ACL authorization tests
+#[cfg(test)]
+mod object_acl_test;
+
 // Special characters in path test modules
 #[cfg(test)]
 mod special_chars_test;
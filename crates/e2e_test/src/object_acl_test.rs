@@ -0,0 +1,113 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verifies that object writes carrying ACL headers also require s3:PutObjectAcl.
+
+use crate::bucket_policy_check_test::{create_user, create_user_client};
+use crate::common::{RustFSTestEnvironment, init_logging};
+use aws_sdk_s3::Client;
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::types::ObjectCannedAcl;
+use serial_test::serial;
+use tracing::info;
+
+async fn put_bucket_policy(
+    client: &Client,
+    bucket: &str,
+    user: &str,
+    actions: &[&str],
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let policy_json = serde_json::json!({
+        "Version": "2012-10-17",
+        "Statement": [
+            {
+                "Effect": "Allow",
+                "Principal": { "AWS": [user] },
+                "Action": actions,
+                "Resource": [format!("arn:aws:s3:::{bucket}/*")]
+            }
+        ]
+    })
+    .to_string();
+
+    client.put_bucket_policy().bucket(bucket).policy(&policy_json).send().await?;
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn test_put_object_with_acl_requires_put_object_acl() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    init_logging();
+    info!("Starting test_put_object_with_acl_requires_put_object_acl...");
+
+    let mut env = RustFSTestEnvironment::new().await?;
+    env.start_rustfs_server(vec![]).await?;
+
+    let admin_client = env.create_s3_client();
+    let bucket_name = "object-acl-write-test";
+    let user_access = "acluser";
+    let user_secret = "aclpassword";
+
+    admin_client.create_bucket().bucket(bucket_name).send().await?;
+    create_user(&env, user_access, user_secret).await?;
+    let user_client = create_user_client(&env, user_access, user_secret);
+
+    put_bucket_policy(&admin_client, bucket_name, user_access, &["s3:PutObject"]).await?;
+
+    user_client
+        .put_object()
+        .bucket(bucket_name)
+        .key("plain.txt")
+        .body(ByteStream::from_static(b"plain"))
+        .send()
+        .await
+        .map_err(|e| format!("PutObject without ACL headers failed: {}", e))?;
+
+    let result = user_client
+        .put_object()
+        .bucket(bucket_name)
+        .key("with-acl.txt")
+        .acl(ObjectCannedAcl::Private)
+        .body(ByteStream::from_static(b"with acl"))
+        .send()
+        .await;
+    assert!(result.is_err(), "PutObject with an ACL header must be denied without s3:PutObjectAcl");
+
+    let result = user_client
+        .create_multipart_upload()
+        .bucket(bucket_name)
+        .key("multipart.txt")
+        .acl(ObjectCannedAcl::Private)
+        .send()
+        .await;
+    assert!(
+        result.is_err(),
+        "CreateMultipartUpload with an ACL header must be denied without s3:PutObjectAcl"
+    );
+
+    put_bucket_policy(&admin_client, bucket_name, user_access, &["s3:PutObject", "s3:PutObjectAcl"]).await?;
+
+    user_client
+        .put_object()
+        .bucket(bucket_name)
+        .key("with-acl.txt")
+        .acl(ObjectCannedAcl::Private)
+        .body(ByteStream::from_static(b"with acl"))
+        .send()
+        .await
+        .map_err(|e| format!("PutObject with s3:PutObjectAcl failed: {}", e))?;
+
+    info!("Test Passed!");
+    Ok(())
+}
@@ -24,9 +24,14 @@ use crate::{
 };
 
 use crate::data_usage::load_data_usage_cache;
-use rustfs_common::{GLOBAL_LOCAL_NODE_NAME, heal_channel::DriveState};
+use rustfs_common::{
+    GLOBAL_LOCAL_NODE_NAME,
+    heal_channel::DriveState,
+    metrics::{Metric, global_metrics},
+};
 use rustfs_madmin::{
     BackendDisks, Disk, ErasureSetInfo, ITEM_INITIALIZING, ITEM_OFFLINE, ITEM_ONLINE, InfoMessage, ServerProperties,
+    heal_commands::BgHealState,
 };
 use rustfs_protos::{
     models::{PingBody, PingBodyBuilder},
@@ -198,6 +203,29 @@ pub async fn get_local_server_property() -> ServerProperties {
     props
 }
 
+/// Collect the background healing state of the drives attached to this node.
+///
+/// Returns `None` until the object layer is initialized.
+pub async fn get_local_background_heal_state() -> Option<BgHealState> {
+    let store = new_object_layer_fn()?;
+    let storage_info = store.local_storage_info().await;
+
+    let mut state = BgHealState {
+        scanned_items_count: global_metrics().lifetime(Metric::ScanObject),
+        ..Default::default()
+    };
+    for disk in storage_info.disks {
+        if disk.state != DriveState::Ok.to_string() && disk.state != DriveState::Unformatted.to_string() {
+            state.offline_endpoints.push(disk.endpoint.clone());
+        }
+        if disk.healing {
+            state.heal_disks.push(disk.endpoint);
+        }
+    }
+
+    Some(state)
+}
+
 pub async fn get_server_info(get_pools: bool) -> InfoMessage {
     let nowt: OffsetDateTime = OffsetDateTime::now_utc();
 
@@ -0,0 +1,346 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::metadata_sys;
+use super::public_access::{ALL_USERS_GROUP, AUTHENTICATED_USERS_GROUP};
+use rustfs_policy::policy::action::{Action, S3Action};
+use s3s::dto::{Grant, Grantee, Permission, Type};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Grantee group used by S3 server access logging
+pub const LOG_DELIVERY_GROUP: &str = "http://acs.amazonaws.com/groups/s3/LogDelivery";
+
+/// Object metadata key holding the JSON encoded ACL of an object version
+pub const OBJECT_ACL_METADATA_KEY: &str = "x-rustfs-internal-acl";
+
+const ERR_EMAIL_GRANTEE: &str = "Grants by email address are not supported";
+const ERR_INVALID_GRANTEE: &str = "Invalid grantee, it must be an id or a uri";
+const ERR_INVALID_PERMISSION: &str = "Invalid grant permission";
+const ERR_INVALID_CANNED_ACL: &str = "Invalid canned ACL";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AclPermission {
+    #[serde(rename = "FULL_CONTROL")]
+    FullControl,
+    #[serde(rename = "READ")]
+    Read,
+    #[serde(rename = "WRITE")]
+    Write,
+    #[serde(rename = "READ_ACP")]
+    ReadAcp,
+    #[serde(rename = "WRITE_ACP")]
+    WriteAcp,
+}
+
+impl AclPermission {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AclPermission::FullControl => Permission::FULL_CONTROL,
+            AclPermission::Read => Permission::READ,
+            AclPermission::Write => Permission::WRITE,
+            AclPermission::ReadAcp => Permission::READ_ACP,
+            AclPermission::WriteAcp => Permission::WRITE_ACP,
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            Permission::FULL_CONTROL => Some(AclPermission::FullControl),
+            Permission::READ => Some(AclPermission::Read),
+            Permission::WRITE => Some(AclPermission::Write),
+            Permission::READ_ACP => Some(AclPermission::ReadAcp),
+            Permission::WRITE_ACP => Some(AclPermission::WriteAcp),
+            _ => None,
+        }
+    }
+
+    /// The ACL permission an action needs on a bucket, object permissions are checked with [`AclPermission::for_object`]
+    pub fn for_bucket(action: &Action) -> Option<Self> {
+        match action {
+            Action::S3Action(
+                S3Action::ListBucketAction | S3Action::ListBucketVersionsAction | S3Action::ListBucketMultipartUploadsAction,
+            ) => Some(AclPermission::Read),
+            Action::S3Action(S3Action::PutObjectAction | S3Action::DeleteObjectAction) => Some(AclPermission::Write),
+            Action::S3Action(S3Action::GetBucketAclAction) => Some(AclPermission::ReadAcp),
+            Action::S3Action(S3Action::PutBucketAclAction) => Some(AclPermission::WriteAcp),
+            _ => None,
+        }
+    }
+
+    pub fn for_object(action: &Action) -> Option<Self> {
+        match action {
+            Action::S3Action(S3Action::GetObjectAction | S3Action::GetObjectVersionAction) => Some(AclPermission::Read),
+            Action::S3Action(S3Action::GetObjectAclAction) => Some(AclPermission::ReadAcp),
+            Action::S3Action(S3Action::PutObjectAclAction) => Some(AclPermission::WriteAcp),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum AclGrantee {
+    /// A user, identified by its canonical id or its access key
+    CanonicalUser(String),
+    /// A predefined group such as AllUsers, identified by its uri
+    Group(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AclGrant {
+    pub grantee: AclGrantee,
+    pub permission: AclPermission,
+}
+
+/// A bucket or object ACL in the form it is persisted
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessControlList {
+    pub grants: Vec<AclGrant>,
+}
+
+impl AccessControlList {
+    /// Expands a canned ACL into grants, `owner_id` receives FULL_CONTROL
+    pub fn from_canned(acl: &str, owner_id: &str) -> Result<Self, std::io::Error> {
+        let mut grants = vec![AclGrant {
+            grantee: AclGrantee::CanonicalUser(owner_id.to_string()),
+            permission: AclPermission::FullControl,
+        }];
+        let group = |uri: &str, permission| AclGrant {
+            grantee: AclGrantee::Group(uri.to_string()),
+            permission,
+        };
+
+        match acl {
+            // A deployment has a single owner, so the bucket owner variants match `private`
+            "private" | "bucket-owner-read" | "bucket-owner-full-control" | "aws-exec-read" => {}
+            "public-read" => grants.push(group(ALL_USERS_GROUP, AclPermission::Read)),
+            "public-read-write" => {
+                grants.push(group(ALL_USERS_GROUP, AclPermission::Read));
+                grants.push(group(ALL_USERS_GROUP, AclPermission::Write));
+            }
+            "authenticated-read" => grants.push(group(AUTHENTICATED_USERS_GROUP, AclPermission::Read)),
+            "log-delivery-write" => {
+                grants.push(group(LOG_DELIVERY_GROUP, AclPermission::Write));
+                grants.push(group(LOG_DELIVERY_GROUP, AclPermission::ReadAcp));
+            }
+            _ => return Err(std::io::Error::other(ERR_INVALID_CANNED_ACL)),
+        }
+
+        Ok(Self { grants })
+    }
+
+    /// Converts the grants of an `AccessControlPolicy` body
+    pub fn from_grants(grants: &[Grant]) -> Result<Self, std::io::Error> {
+        let grants = grants
+            .iter()
+            .map(|grant| {
+                let permission = grant
+                    .permission
+                    .as_ref()
+                    .and_then(|p| AclPermission::parse(p.as_str()))
+                    .ok_or_else(|| std::io::Error::other(ERR_INVALID_PERMISSION))?;
+                let grantee = grant
+                    .grantee
+                    .as_ref()
+                    .ok_or_else(|| std::io::Error::other(ERR_INVALID_GRANTEE))?;
+                let grantee = match grantee.type_.as_str() {
+                    Type::CANONICAL_USER => AclGrantee::CanonicalUser(grantee.id.clone().unwrap_or_default()),
+                    Type::GROUP => AclGrantee::Group(grantee.uri.clone().unwrap_or_default()),
+                    Type::AMAZON_CUSTOMER_BY_EMAIL => return Err(std::io::Error::other(ERR_EMAIL_GRANTEE)),
+                    _ => return Err(std::io::Error::other(ERR_INVALID_GRANTEE)),
+                };
+                validate_grantee(&grantee)?;
+                Ok(AclGrant { grantee, permission })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { grants })
+    }
+
+    /// Parses the `x-amz-grant-*` headers, each a comma separated list of `id="..."` or `uri="..."` grantees
+    pub fn from_grant_headers(headers: &[(AclPermission, Option<&str>)]) -> Result<Option<Self>, std::io::Error> {
+        let mut grants = Vec::new();
+        for (permission, value) in headers {
+            for grantee in value
+                .iter()
+                .flat_map(|v| v.split(','))
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+            {
+                let (kind, value) = grantee
+                    .split_once('=')
+                    .ok_or_else(|| std::io::Error::other(ERR_INVALID_GRANTEE))?;
+                let value = value.trim().trim_matches('"').to_string();
+                let grantee = match kind.trim() {
+                    "id" => AclGrantee::CanonicalUser(value),
+                    "uri" => AclGrantee::Group(value),
+                    "emailAddress" => return Err(std::io::Error::other(ERR_EMAIL_GRANTEE)),
+                    _ => return Err(std::io::Error::other(ERR_INVALID_GRANTEE)),
+                };
+                validate_grantee(&grantee)?;
+                grants.push(AclGrant {
+                    grantee,
+                    permission: *permission,
+                });
+            }
+        }
+
+        Ok(if grants.is_empty() { None } else { Some(Self { grants }) })
+    }
+
+    pub fn to_grants(&self) -> Vec<Grant> {
+        self.grants
+            .iter()
+            .map(|grant| {
+                let (type_, id, uri) = match &grant.grantee {
+                    AclGrantee::CanonicalUser(id) => (Type::CANONICAL_USER, Some(id.clone()), None),
+                    AclGrantee::Group(uri) => (Type::GROUP, None, Some(uri.clone())),
+                };
+                Grant {
+                    grantee: Some(Grantee {
+                        type_: Type::from_static(type_),
+                        display_name: None,
+                        email_address: None,
+                        id,
+                        uri,
+                    }),
+                    permission: Some(Permission::from_static(grant.permission.as_str())),
+                }
+            })
+            .collect()
+    }
+
+    /// Whether any grant goes to the AllUsers or AuthenticatedUsers groups
+    pub fn is_public(&self) -> bool {
+        self.grants.iter().any(|g| is_public_grantee(&g.grantee))
+    }
+
+    /// Whether the grants give `permission` to the requester, `account` is `None` for anonymous requests.
+    /// `ignore_public` drops the AllUsers and AuthenticatedUsers grants, as IgnorePublicAcls does.
+    pub fn is_allowed(&self, account: Option<&str>, permission: AclPermission, ignore_public: bool) -> bool {
+        self.grants.iter().any(|grant| {
+            if grant.permission != permission && grant.permission != AclPermission::FullControl {
+                return false;
+            }
+            if ignore_public && is_public_grantee(&grant.grantee) {
+                return false;
+            }
+            match &grant.grantee {
+                AclGrantee::Group(uri) => uri == ALL_USERS_GROUP || (uri == AUTHENTICATED_USERS_GROUP && account.is_some()),
+                AclGrantee::CanonicalUser(id) => account.is_some_and(|a| a == id),
+            }
+        })
+    }
+
+    /// Reads the ACL stored in the metadata of an object version
+    pub fn from_object_metadata(user_defined: &HashMap<String, String>) -> Option<Self> {
+        user_defined
+            .get(OBJECT_ACL_METADATA_KEY)
+            .and_then(|v| serde_json::from_str(v).ok())
+    }
+
+    pub fn to_object_metadata(&self) -> HashMap<String, String> {
+        HashMap::from([(OBJECT_ACL_METADATA_KEY.to_string(), serde_json::to_string(self).unwrap_or_default())])
+    }
+}
+
+fn validate_grantee(grantee: &AclGrantee) -> Result<(), std::io::Error> {
+    match grantee {
+        AclGrantee::CanonicalUser(id) if !id.is_empty() => Ok(()),
+        AclGrantee::Group(uri) if uri == ALL_USERS_GROUP || uri == AUTHENTICATED_USERS_GROUP || uri == LOG_DELIVERY_GROUP => {
+            Ok(())
+        }
+        _ => Err(std::io::Error::other(ERR_INVALID_GRANTEE)),
+    }
+}
+
+fn is_public_grantee(grantee: &AclGrantee) -> bool {
+    matches!(grantee, AclGrantee::Group(uri) if uri == ALL_USERS_GROUP || uri == AUTHENTICATED_USERS_GROUP)
+}
+
+/// The ACL of the bucket, `None` when only the owner has access
+pub async fn get_bucket_acl(bucket: &str) -> Option<AccessControlList> {
+    metadata_sys::get_acl_config(bucket).await.ok().map(|(acl, _)| acl)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OWNER: &str = "owner";
+
+    #[test]
+    fn test_from_canned() {
+        let acl = AccessControlList::from_canned("public-read", OWNER).unwrap();
+        assert!(acl.is_public());
+        assert!(acl.is_allowed(None, AclPermission::Read, false));
+        assert!(!acl.is_allowed(None, AclPermission::Read, true));
+        assert!(!acl.is_allowed(None, AclPermission::Write, false));
+        assert!(acl.is_allowed(Some(OWNER), AclPermission::WriteAcp, false));
+
+        let acl = AccessControlList::from_canned("authenticated-read", OWNER).unwrap();
+        assert!(!acl.is_allowed(None, AclPermission::Read, false));
+        assert!(acl.is_allowed(Some("alice"), AclPermission::Read, false));
+
+        let acl = AccessControlList::from_canned("bucket-owner-full-control", OWNER).unwrap();
+        assert!(!acl.is_public());
+        assert!(!acl.is_allowed(Some("alice"), AclPermission::Read, false));
+
+        assert!(AccessControlList::from_canned("everyone", OWNER).is_err());
+    }
+
+    #[test]
+    fn test_grants_round_trip() {
+        let acl = AccessControlList::from_grant_headers(&[
+            (
+                AclPermission::Read,
+                Some(r#"uri="http://acs.amazonaws.com/groups/global/AllUsers", id="alice""#),
+            ),
+            (AclPermission::FullControl, Some(r#"id="owner""#)),
+            (AclPermission::Write, None),
+        ])
+        .unwrap()
+        .unwrap();
+        assert_eq!(acl.grants.len(), 3);
+        assert!(acl.is_allowed(Some("alice"), AclPermission::Read, true));
+        assert!(!acl.is_allowed(Some("alice"), AclPermission::ReadAcp, false));
+
+        assert_eq!(AccessControlList::from_grants(&acl.to_grants()).unwrap(), acl);
+        assert_eq!(AccessControlList::from_object_metadata(&acl.to_object_metadata()), Some(acl));
+
+        assert!(
+            AccessControlList::from_grant_headers(&[(AclPermission::Read, None)])
+                .unwrap()
+                .is_none()
+        );
+        assert!(AccessControlList::from_grant_headers(&[(AclPermission::Read, Some(r#"emailAddress="a@b.c""#))]).is_err());
+        assert!(
+            AccessControlList::from_grant_headers(&[(AclPermission::Read, Some(r#"uri="http://example.com/group""#))]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_permission_for_action() {
+        assert_eq!(
+            AclPermission::for_bucket(&Action::S3Action(S3Action::PutObjectAction)),
+            Some(AclPermission::Write)
+        );
+        assert_eq!(
+            AclPermission::for_object(&Action::S3Action(S3Action::GetObjectAction)),
+            Some(AclPermission::Read)
+        );
+        assert_eq!(AclPermission::for_object(&Action::S3Action(S3Action::PutObjectAction)), None);
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::metadata_sys;
+use super::utils::{deserialize, serialize};
+use crate::error::Error;
+use s3s::dto::{InventoryConfiguration, InventoryFormat, InventoryFrequency, InventoryIncludedObjectVersions};
+use time::{Duration, OffsetDateTime};
+
+/// Prefix of the bucket ARN an inventory destination must use
+pub const INVENTORY_DESTINATION_ARN_PREFIX: &str = "arn:aws:s3:::";
+
+/// Optional fields an inventory report can include, in the order they are written after the fixed columns
+pub const INVENTORY_OPTIONAL_FIELDS: [&str; 11] = [
+    "Size",
+    "LastModifiedDate",
+    "ETag",
+    "StorageClass",
+    "IsMultipartUploaded",
+    "ReplicationStatus",
+    "EncryptionStatus",
+    "ObjectLockRetainUntilDate",
+    "ObjectLockMode",
+    "ObjectLockLegalHoldStatus",
+    "Tags",
+];
+
+/// Configurations returned per ListBucketInventoryConfigurations page
+pub const MAX_INVENTORY_LIST_SIZE: usize = 100;
+
+const MAX_INVENTORY_CONFIGS: usize = 1000;
+
+const ERR_INVENTORY_ID: &str = "Inventory configuration Id must not be empty";
+const ERR_INVENTORY_DESTINATION: &str = "Inventory destination bucket must be an ARN of the form arn:aws:s3:::bucket";
+const ERR_INVENTORY_FORMAT: &str = "Inventory format must be CSV, ORC or Parquet";
+const ERR_INVENTORY_FREQUENCY: &str = "Inventory frequency must be Daily or Weekly";
+const ERR_INVENTORY_VERSIONS: &str = "Inventory IncludedObjectVersions must be All or Current";
+const ERR_INVENTORY_TOO_MANY: &str = "A bucket can have at most 1000 inventory configurations";
+
+pub trait InventoryApi {
+    fn validate(&self) -> Result<(), std::io::Error>;
+    /// Name of the bucket the reports are written to
+    fn destination_bucket(&self) -> &str;
+    fn includes_all_versions(&self) -> bool;
+    fn has_optional_field(&self, field: &str) -> bool;
+    /// Whether a report is due, given when the previous one was generated
+    fn is_due(&self, last_run: Option<OffsetDateTime>, now: OffsetDateTime) -> bool;
+}
+
+impl InventoryApi for InventoryConfiguration {
+    fn validate(&self) -> Result<(), std::io::Error> {
+        if self.id.is_empty() {
+            return Err(std::io::Error::other(ERR_INVENTORY_ID));
+        }
+
+        let destination = &self.destination.s3_bucket_destination;
+        match destination.bucket.strip_prefix(INVENTORY_DESTINATION_ARN_PREFIX) {
+            Some(bucket) if !bucket.is_empty() && !bucket.contains('/') => {}
+            _ => return Err(std::io::Error::other(ERR_INVENTORY_DESTINATION)),
+        }
+
+        match destination.format.as_str() {
+            InventoryFormat::CSV | InventoryFormat::ORC | InventoryFormat::PARQUET => {}
+            _ => return Err(std::io::Error::other(ERR_INVENTORY_FORMAT)),
+        }
+
+        match self.schedule.frequency.as_str() {
+            InventoryFrequency::DAILY | InventoryFrequency::WEEKLY => {}
+            _ => return Err(std::io::Error::other(ERR_INVENTORY_FREQUENCY)),
+        }
+
+        match self.included_object_versions.as_str() {
+            InventoryIncludedObjectVersions::ALL | InventoryIncludedObjectVersions::CURRENT => {}
+            _ => return Err(std::io::Error::other(ERR_INVENTORY_VERSIONS)),
+        }
+
+        for field in self.optional_fields.iter().flatten() {
+            if !INVENTORY_OPTIONAL_FIELDS.contains(&field.as_str()) {
+                return Err(std::io::Error::other(format!("Unsupported inventory optional field: {}", field.as_str())));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn destination_bucket(&self) -> &str {
+        let bucket = &self.destination.s3_bucket_destination.bucket;
+        bucket.strip_prefix(INVENTORY_DESTINATION_ARN_PREFIX).unwrap_or(bucket)
+    }
+
+    fn includes_all_versions(&self) -> bool {
+        self.included_object_versions.as_str() == InventoryIncludedObjectVersions::ALL
+    }
+
+    fn has_optional_field(&self, field: &str) -> bool {
+        self.optional_fields.iter().flatten().any(|f| f.as_str() == field)
+    }
+
+    fn is_due(&self, last_run: Option<OffsetDateTime>, now: OffsetDateTime) -> bool {
+        let Some(last_run) = last_run else {
+            return true;
+        };
+
+        let interval = if self.schedule.frequency.as_str() == InventoryFrequency::WEEKLY {
+            Duration::weeks(1)
+        } else {
+            Duration::days(1)
+        };
+        now - last_run >= interval
+    }
+}
+
+/// The bucket's inventory configurations, empty when none are configured
+pub async fn get_inventory_configs(bucket: &str) -> Result<Vec<InventoryConfiguration>, Error> {
+    match metadata_sys::get_inventory_config(bucket).await {
+        Ok((configs, _)) => Ok(configs),
+        Err(Error::ConfigNotFound) => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Encodes a bucket's inventory configurations as a JSON array of their XML documents
+pub fn marshal_inventory_configs(configs: &[InventoryConfiguration]) -> Result<Vec<u8>, Error> {
+    if configs.len() > MAX_INVENTORY_CONFIGS {
+        return Err(Error::other(ERR_INVENTORY_TOO_MANY));
+    }
+
+    let docs = configs
+        .iter()
+        .map(|config| -> Result<String, Error> { Ok(String::from_utf8(serialize(config)?)?) })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(serde_json::to_vec(&docs)?)
+}
+
+pub fn unmarshal_inventory_configs(data: &[u8]) -> Result<Vec<InventoryConfiguration>, Error> {
+    let docs: Vec<String> = serde_json::from_slice(data)?;
+    docs.iter()
+        .map(|doc| -> Result<InventoryConfiguration, Error> { Ok(deserialize(doc.as_bytes())?) })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s3s::dto::{InventoryDestination, InventoryOptionalField, InventoryS3BucketDestination, InventorySchedule};
+
+    fn config(id: &str, bucket: &str, format: &'static str, frequency: &'static str) -> InventoryConfiguration {
+        InventoryConfiguration {
+            destination: InventoryDestination {
+                s3_bucket_destination: InventoryS3BucketDestination {
+                    account_id: None,
+                    bucket: bucket.to_string(),
+                    encryption: None,
+                    format: InventoryFormat::from_static(format),
+                    prefix: Some("reports".to_string()),
+                },
+            },
+            filter: None,
+            id: id.to_string(),
+            included_object_versions: InventoryIncludedObjectVersions::from_static(InventoryIncludedObjectVersions::CURRENT),
+            is_enabled: true,
+            optional_fields: Some(vec![
+                InventoryOptionalField::from_static("Size"),
+                InventoryOptionalField::from_static("ETag"),
+            ]),
+            schedule: InventorySchedule {
+                frequency: InventoryFrequency::from_static(frequency),
+            },
+        }
+    }
+
+    #[test]
+    fn test_validate_inventory_config() {
+        let valid = config("daily", "arn:aws:s3:::reports", InventoryFormat::CSV, InventoryFrequency::DAILY);
+        assert!(valid.validate().is_ok());
+        assert_eq!(valid.destination_bucket(), "reports");
+        assert!(valid.has_optional_field("ETag"));
+        assert!(!valid.has_optional_field("Tags"));
+        assert!(!valid.includes_all_versions());
+
+        assert!(
+            config("", "arn:aws:s3:::reports", InventoryFormat::CSV, InventoryFrequency::DAILY)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            config("id", "reports", InventoryFormat::CSV, InventoryFrequency::DAILY)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            config("id", "arn:aws:s3:::reports", InventoryFormat::ORC, InventoryFrequency::DAILY)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            config("id", "arn:aws:s3:::reports", InventoryFormat::PARQUET, "Hourly")
+                .validate()
+                .is_err()
+        );
+
+        let mut unknown_field = valid.clone();
+        unknown_field.optional_fields = Some(vec![InventoryOptionalField::from_static("Owner")]);
+        assert!(unknown_field.validate().is_err());
+    }
+
+    #[test]
+    fn test_inventory_is_due() {
+        let now = OffsetDateTime::now_utc();
+        let daily = config("daily", "arn:aws:s3:::reports", InventoryFormat::CSV, InventoryFrequency::DAILY);
+        let weekly = config("weekly", "arn:aws:s3:::reports", InventoryFormat::CSV, InventoryFrequency::WEEKLY);
+
+        assert!(daily.is_due(None, now));
+        assert!(daily.is_due(Some(now - Duration::days(1)), now));
+        assert!(!daily.is_due(Some(now - Duration::hours(23)), now));
+        assert!(!weekly.is_due(Some(now - Duration::days(6)), now));
+        assert!(weekly.is_due(Some(now - Duration::days(7)), now));
+    }
+
+    #[test]
+    fn test_marshal_inventory_configs() {
+        let configs = vec![
+            config("daily", "arn:aws:s3:::reports", InventoryFormat::CSV, InventoryFrequency::DAILY),
+            config("weekly", "arn:aws:s3:::reports", InventoryFormat::PARQUET, InventoryFrequency::WEEKLY),
+        ];
+
+        let data = marshal_inventory_configs(&configs).unwrap();
+        let decoded = unmarshal_inventory_configs(&data).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].id, "daily");
+        assert_eq!(decoded[1].destination.s3_bucket_destination.format.as_str(), InventoryFormat::PARQUET);
+        assert_eq!(decoded[1].schedule.frequency.as_str(), InventoryFrequency::WEEKLY);
+    }
+}
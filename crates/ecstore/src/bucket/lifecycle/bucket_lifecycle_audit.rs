@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
+use time::format_description::well_known::Rfc3339;
+
 use crate::bucket::lifecycle::lifecycle;
 
 #[derive(Debug, Clone, Default)]
@@ -30,6 +34,24 @@ pub enum LcEventSrc {
     S3CompleteMultipartUpload,
 }
 
+impl LcEventSrc {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Heal => "Heal",
+            Self::Scanner => "Scanner",
+            Self::Decom => "Decom",
+            Self::Rebal => "Rebal",
+            Self::S3HeadObject => "S3HeadObject",
+            Self::S3GetObject => "S3GetObject",
+            Self::S3ListObjects => "S3ListObjects",
+            Self::S3PutObject => "S3PutObject",
+            Self::S3CopyObject => "S3CopyObject",
+            Self::S3CompleteMultipartUpload => "S3CompleteMultipartUpload",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct LcAuditEvent {
     pub event: lifecycle::Event,
@@ -40,4 +62,58 @@ impl LcAuditEvent {
     pub fn new(event: lifecycle::Event, source: LcEventSrc) -> Self {
         Self { event, source }
     }
+
+    /// Tags attached to the audit entry describing the lifecycle action taken.
+    pub fn tags(&self) -> HashMap<String, String> {
+        let event = &self.event;
+        let mut tags = HashMap::new();
+        if !matches!(self.source, LcEventSrc::None) {
+            tags.insert("ilm-src".to_string(), self.source.as_str().to_string());
+        }
+        tags.insert("ilm-action".to_string(), event.action.to_string());
+        tags.insert("ilm-rule-id".to_string(), event.rule_id.clone());
+        if let Some(due) = event.due
+            && due.unix_timestamp() != 0
+            && let Ok(due) = due.format(&Rfc3339)
+        {
+            tags.insert("ilm-due".to_string(), due);
+        }
+        if !event.storage_class.is_empty() {
+            tags.insert("ilm-tier".to_string(), event.storage_class.clone());
+        }
+        if event.newer_noncurrent_versions > 0 {
+            tags.insert("ilm-newer-noncurrent-versions".to_string(), event.newer_noncurrent_versions.to_string());
+        }
+        if event.noncurrent_days > 0 {
+            tags.insert("ilm-noncurrent-days".to_string(), event.noncurrent_days.to_string());
+        }
+        tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bucket::lifecycle::lifecycle::{Event, IlmAction};
+    use time::macros::datetime;
+
+    #[test]
+    fn test_lc_audit_event_tags() {
+        let event = Event {
+            action: IlmAction::AbortMultipartUploadAction,
+            rule_id: "abort-stale".to_string(),
+            due: Some(datetime!(2025-01-02 00:00:00 UTC)),
+            ..Default::default()
+        };
+        let tags = LcAuditEvent::new(event, LcEventSrc::Scanner).tags();
+        assert_eq!(tags.get("ilm-src").map(String::as_str), Some("Scanner"));
+        assert_eq!(tags.get("ilm-action").map(String::as_str), Some("AbortMultipartUploadAction"));
+        assert_eq!(tags.get("ilm-rule-id").map(String::as_str), Some("abort-stale"));
+        assert_eq!(tags.get("ilm-due").map(String::as_str), Some("2025-01-02T00:00:00Z"));
+        assert!(!tags.contains_key("ilm-tier"));
+
+        let tags = LcAuditEvent::new(Event::default(), LcEventSrc::None).tags();
+        assert!(!tags.contains_key("ilm-src"));
+        assert!(!tags.contains_key("ilm-due"));
+    }
 }
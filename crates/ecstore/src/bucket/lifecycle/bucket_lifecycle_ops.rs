@@ -34,7 +34,7 @@ use crate::global::GLOBAL_LocalNodeName;
 use crate::global::{GLOBAL_LifecycleSys, GLOBAL_TierConfigMgr, get_global_deployment_id};
 use crate::store::ECStore;
 use crate::store_api::StorageAPI;
-use crate::store_api::{GetObjectReader, HTTPRangeSpec, ObjectInfo, ObjectOptions, ObjectToDelete};
+use crate::store_api::{GetObjectReader, HTTPRangeSpec, MultipartInfo, ObjectInfo, ObjectOptions, ObjectToDelete};
 use crate::tier::warm_backend::WarmBackendGetOpts;
 use async_channel::{Receiver as A_Receiver, Sender as A_Sender, bounded};
 use bytes::BytesMut;
@@ -1163,6 +1163,27 @@ pub async fn apply_expiry_on_non_transitioned_objects(
     true
 }
 
+pub async fn apply_abort_incomplete_multipart_upload(
+    api: Arc<ECStore>,
+    upload: &MultipartInfo,
+    lc_event: &lifecycle::Event,
+) -> bool {
+    let time_ilm = Metrics::time_ilm(lc_event.action);
+    if let Err(err) = api
+        .abort_multipart_upload(&upload.bucket, &upload.object, &upload.upload_id, &ObjectOptions::default())
+        .await
+    {
+        error!(
+            "abort_multipart_upload {}/{} upload {} error: {:?}",
+            upload.bucket, upload.object, upload.upload_id, err
+        );
+        return false;
+    }
+    time_ilm(1)();
+
+    true
+}
+
 pub async fn apply_expiry_rule(event: &lifecycle::Event, src: &LcEventSrc, oi: &ObjectInfo) -> bool {
     let mut expiry_state = GLOBAL_ExpiryState.write().await;
     expiry_state.enqueue_by_days(oi, event, src).await;
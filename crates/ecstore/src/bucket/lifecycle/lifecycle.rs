@@ -33,7 +33,7 @@ use time::{self, Duration, OffsetDateTime};
 use tracing::info;
 use uuid::Uuid;
 
-use crate::bucket::lifecycle::rule::TransitionOps;
+use crate::bucket::lifecycle::rule::{LifecycleRuleExt, TransitionOps};
 use crate::store_api::ObjectInfo;
 
 pub const TRANSITION_COMPLETE: &str = "complete";
@@ -113,11 +113,12 @@ impl RuleValidate for LifecycleRule {
     }*/
 
     fn validate(&self) -> Result<(), std::io::Error> {
+        self.validate_prefix_and_filter()?;
+        self.validate_abort_incomplete_multipart_upload()?;
         /*self.validate_id()?;
         self.validate_status()?;
         self.validate_expiration()?;
         self.validate_noncurrent_expiration()?;
-        self.validate_prefix_and_filter()?;
         self.validate_transition()?;
         self.validate_noncurrent_transition()?;
         if (!self.Filter.Tag.IsEmpty() || len(self.Filter.And.Tags) != 0) && !self.delmarker_expiration.Empty() {
@@ -139,8 +140,14 @@ pub trait Lifecycle {
     async fn filter_rules(&self, obj: &ObjectOpts) -> Option<Vec<LifecycleRule>>;
     async fn eval(&self, obj: &ObjectOpts) -> Event;
     async fn eval_inner(&self, obj: &ObjectOpts, now: OffsetDateTime, newer_noncurrent_versions: usize) -> Event;
-    //fn set_prediction_headers(&self, w: http.ResponseWriter, obj: ObjectOpts);
+    /// Earliest upcoming expiration of `obj`, whether or not it is due yet.
+    async fn predict_expiration(&self, obj: &ObjectOpts) -> Option<Event>;
+    /// Earliest upcoming transition of `obj`, whether or not it is due yet.
+    async fn predict_transition(&self, obj: &ObjectOpts) -> Option<Event>;
     async fn noncurrent_versions_expiration_limit(self: Arc<Self>, obj: &ObjectOpts) -> Event;
+    fn has_abort_incomplete_multipart_upload(&self) -> bool;
+    /// Evaluates AbortIncompleteMultipartUpload rules for an upload of `object` initiated at `initiated`.
+    fn eval_incomplete_multipart_upload(&self, object: &str, initiated: OffsetDateTime, now: OffsetDateTime) -> Event;
 }
 
 #[async_trait::async_trait]
@@ -172,7 +179,7 @@ impl Lifecycle for BucketLifecycleConfiguration {
                 continue;
             }
 
-            let rule_prefix = &rule.prefix.clone().unwrap_or_default();
+            let rule_prefix = rule.prefix();
             if prefix.len() > 0 && rule_prefix.len() > 0 && !prefix.starts_with(rule_prefix) && !rule_prefix.starts_with(&prefix)
             {
                 continue;
@@ -223,6 +230,49 @@ impl Lifecycle for BucketLifecycleConfiguration {
         false
     }
 
+    fn has_abort_incomplete_multipart_upload(&self) -> bool {
+        self.rules.iter().any(|rule| {
+            rule.status.as_str() != ExpirationStatus::DISABLED
+                && rule
+                    .abort_incomplete_multipart_upload
+                    .as_ref()
+                    .is_some_and(|abort| abort.days_after_initiation.is_some())
+        })
+    }
+
+    fn eval_incomplete_multipart_upload(&self, object: &str, initiated: OffsetDateTime, now: OffsetDateTime) -> Event {
+        let mut event = Event::default();
+        for rule in self.rules.iter() {
+            if rule.status.as_str() == ExpirationStatus::DISABLED || !object.starts_with(rule.prefix()) {
+                continue;
+            }
+            let Some(days) = rule
+                .abort_incomplete_multipart_upload
+                .as_ref()
+                .and_then(|abort| abort.days_after_initiation)
+            else {
+                continue;
+            };
+            if days <= 0 {
+                continue;
+            }
+            let due = expected_expiry_time(initiated, days);
+            if now.unix_timestamp() < due.unix_timestamp() {
+                continue;
+            }
+            // Report the earliest due rule when several apply
+            if event.action == IlmAction::NoneAction || event.due.is_some_and(|d| due < d) {
+                event = Event {
+                    action: IlmAction::AbortMultipartUploadAction,
+                    rule_id: rule.id.clone().unwrap_or_default(),
+                    due: Some(due),
+                    ..Default::default()
+                };
+            }
+        }
+        event
+    }
+
     async fn validate(&self, lr: &ObjectLockConfiguration) -> Result<(), std::io::Error> {
         if self.rules.len() > 1000 {
             return Err(std::io::Error::other(ERR_LIFECYCLE_TOO_MANY_RULES));
@@ -266,16 +316,7 @@ impl Lifecycle for BucketLifecycleConfiguration {
             if rule.status.as_str() == ExpirationStatus::DISABLED {
                 continue;
             }
-            if let Some(prefix) = rule.prefix.clone() {
-                if !obj.name.starts_with(prefix.as_str()) {
-                    continue;
-                }
-            }
-            /*if !rule.filter.test_tags(obj.user_tags) {
-                continue;
-            }*/
-            //if !obj.delete_marker && !rule.filter.BySize(obj.size) {
-            if !obj.delete_marker && false {
+            if !rule.matches(&obj.name, &obj.user_tags, obj.size as i64, obj.delete_marker) {
                 continue;
             }
             rules.push(rule.clone());
@@ -564,6 +605,90 @@ impl Lifecycle for BucketLifecycleConfiguration {
         Event::default()
     }
 
+    async fn predict_expiration(&self, obj: &ObjectOpts) -> Option<Event> {
+        let mod_time = obj.mod_time?;
+        if obj.delete_marker {
+            return None;
+        }
+        let current = obj.is_latest || obj.version_id.is_none_or(|v| v.is_nil());
+
+        let mut next: Option<Event> = None;
+        for rule in self.filter_rules(obj).await.unwrap_or_default() {
+            let (action, due) = if current {
+                let Some(expiration) = rule.expiration.as_ref() else {
+                    continue;
+                };
+                let due = match (&expiration.date, expiration.days) {
+                    (Some(date), _) => OffsetDateTime::from(date.clone()),
+                    (None, Some(days)) => expected_expiry_time(mod_time, days),
+                    (None, None) => continue,
+                };
+                (IlmAction::DeleteAction, due)
+            } else {
+                let Some(days) = rule
+                    .noncurrent_version_expiration
+                    .as_ref()
+                    .and_then(|expiration| expiration.noncurrent_days)
+                    .filter(|days| *days > 0)
+                else {
+                    continue;
+                };
+                let Some(successor_mod_time) = obj.successor_mod_time else {
+                    continue;
+                };
+                (IlmAction::DeleteVersionAction, expected_expiry_time(successor_mod_time, days))
+            };
+
+            if next.as_ref().is_none_or(|event| event.due.is_some_and(|d| due < d)) {
+                next = Some(Event {
+                    action,
+                    rule_id: rule.id.clone().unwrap_or_default(),
+                    due: Some(due),
+                    ..Default::default()
+                });
+            }
+        }
+        next
+    }
+
+    async fn predict_transition(&self, obj: &ObjectOpts) -> Option<Event> {
+        if obj.mod_time.is_none() || obj.delete_marker || obj.transition_status == TRANSITION_COMPLETE {
+            return None;
+        }
+
+        let mut next: Option<Event> = None;
+        for rule in self.filter_rules(obj).await.unwrap_or_default() {
+            let (action, due, storage_class) = if obj.is_latest {
+                let Some(transition) = rule.transitions.as_ref().and_then(|t| t.first()) else {
+                    continue;
+                };
+                let Some(due) = transition.next_due(obj) else {
+                    continue;
+                };
+                (IlmAction::TransitionAction, due, transition.storage_class.clone())
+            } else {
+                let Some(transition) = rule.noncurrent_version_transitions.as_ref().and_then(|t| t.first()) else {
+                    continue;
+                };
+                let Some(due) = transition.next_due(obj) else {
+                    continue;
+                };
+                (IlmAction::TransitionVersionAction, due, transition.storage_class.clone())
+            };
+
+            if next.as_ref().is_none_or(|event| event.due.is_some_and(|d| due < d)) {
+                next = Some(Event {
+                    action,
+                    rule_id: rule.id.clone().unwrap_or_default(),
+                    due: Some(due),
+                    storage_class: storage_class.map(|sc| sc.as_str().to_string()).unwrap_or_default(),
+                    ..Default::default()
+                });
+            }
+        }
+        next
+    }
+
     async fn noncurrent_versions_expiration_limit(self: Arc<Self>, obj: &ObjectOpts) -> Event {
         if let Some(filter_rules) = self.filter_rules(obj).await {
             for rule in filter_rules.iter() {
@@ -637,7 +762,7 @@ impl LifecycleCalculate for NoncurrentVersionTransition {
 #[async_trait::async_trait]
 impl LifecycleCalculate for Transition {
     fn next_due(&self, obj: &ObjectOpts) -> Option<OffsetDateTime> {
-        if !obj.is_latest || self.days.is_none() {
+        if !obj.is_latest || (self.days.is_none() && self.date.is_none()) {
             return None;
         }
 
@@ -770,3 +895,35 @@ impl Default for TransitionOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bucket::utils::deserialize;
+
+    #[test]
+    fn test_eval_incomplete_multipart_upload() {
+        let xml = r#"<LifecycleConfiguration><Rule><ID>short</ID><Status>Enabled</Status><Filter><Prefix>tmp/</Prefix></Filter><AbortIncompleteMultipartUpload><DaysAfterInitiation>1</DaysAfterInitiation></AbortIncompleteMultipartUpload></Rule><Rule><ID>long</ID><Status>Enabled</Status><Filter><Prefix></Prefix></Filter><AbortIncompleteMultipartUpload><DaysAfterInitiation>7</DaysAfterInitiation></AbortIncompleteMultipartUpload></Rule><Rule><ID>disabled</ID><Status>Disabled</Status><Filter><Prefix>data/</Prefix></Filter><AbortIncompleteMultipartUpload><DaysAfterInitiation>1</DaysAfterInitiation></AbortIncompleteMultipartUpload></Rule></LifecycleConfiguration>"#;
+        let config: BucketLifecycleConfiguration = deserialize(xml.as_bytes()).unwrap();
+        assert!(config.has_abort_incomplete_multipart_upload());
+
+        let initiated = datetime!(2024-01-01 12:00 UTC);
+        let event = config.eval_incomplete_multipart_upload("tmp/a", initiated, initiated + Duration::days(3));
+        assert_eq!(event.action, IlmAction::AbortMultipartUploadAction);
+        assert_eq!(event.rule_id, "short");
+
+        let event = config.eval_incomplete_multipart_upload("data/a", initiated, initiated + Duration::days(3));
+        assert_eq!(event.action, IlmAction::NoneAction);
+
+        // The earliest due rule wins once several apply
+        let event = config.eval_incomplete_multipart_upload("tmp/a", initiated, initiated + Duration::days(10));
+        assert_eq!(event.rule_id, "short");
+        let event = config.eval_incomplete_multipart_upload("data/a", initiated, initiated + Duration::days(10));
+        assert_eq!(event.action, IlmAction::AbortMultipartUploadAction);
+        assert_eq!(event.rule_id, "long");
+
+        let xml = r#"<LifecycleConfiguration><Rule><ID>expire</ID><Status>Enabled</Status><Filter><Prefix></Prefix></Filter><Expiration><Days>1</Days></Expiration></Rule></LifecycleConfiguration>"#;
+        let config: BucketLifecycleConfiguration = deserialize(xml.as_bytes()).unwrap();
+        assert!(!config.has_abort_incomplete_multipart_upload());
+    }
+}
@@ -18,27 +18,203 @@
 #![allow(unused_must_use)]
 #![allow(clippy::all)]
 
-use s3s::dto::{LifecycleRuleFilter, Transition};
+use crate::bucket::tagging::decode_tags_to_map;
+use s3s::dto::{LifecycleRule, LifecycleRuleAndOperator, LifecycleRuleFilter, Tag, Transition};
+use std::collections::HashSet;
 
 const _ERR_TRANSITION_INVALID_DAYS: &str = "Days must be 0 or greater when used with Transition";
 const _ERR_TRANSITION_INVALID_DATE: &str = "Date must be provided in ISO 8601 format";
 const ERR_TRANSITION_INVALID: &str =
     "Exactly one of Days (0 or greater) or Date (positive ISO 8601 format) should be present in Transition.";
 const _ERR_TRANSITION_DATE_NOT_MIDNIGHT: &str = "'Date' must be at midnight GMT";
+const ERR_XML_NOT_WELL_FORMED: &str = "The XML you provided was not well-formed or did not validate against our published schema";
+const ERR_INVALID_FILTER: &str = "Filter must have exactly one of Prefix, Tag, ObjectSizeGreaterThan, ObjectSizeLessThan or And";
+const ERR_INVALID_TAG_KEY: &str = "The TagKey you have provided is invalid";
+const ERR_INVALID_TAG_VALUE: &str = "The TagValue you have provided is invalid";
+const ERR_DUPLICATE_TAG_KEY: &str = "Duplicate Tag Keys are not allowed";
+const ERR_INVALID_OBJECT_SIZE: &str = "ObjectSizeGreaterThan and ObjectSizeLessThan must not be negative";
+const ERR_INVALID_OBJECT_SIZE_RANGE: &str = "ObjectSizeGreaterThan must be less than ObjectSizeLessThan";
+const ERR_ABORT_MPU_WITH_FILTER: &str = "AbortIncompleteMultipartUpload cannot be specified with Tags or object size filters";
+const ERR_INVALID_DAYS_AFTER_INITIATION: &str =
+    "DaysAfterInitiation for AbortIncompleteMultipartUpload action must be a positive integer";
+
+const MAX_TAG_KEY_LENGTH: usize = 128;
+const MAX_TAG_VALUE_LENGTH: usize = 256;
 
 pub trait Filter {
     fn test_tags(&self, user_tags: &str) -> bool;
     fn by_size(&self, sz: i64) -> bool;
+    fn validate(&self) -> Result<(), std::io::Error>;
 }
 
 impl Filter for LifecycleRuleFilter {
     fn test_tags(&self, user_tags: &str) -> bool {
-        true
+        let filter_tags: Vec<&Tag> = match &self.and {
+            Some(and) => and.tags.iter().flatten().collect(),
+            None => self.tag.iter().collect(),
+        };
+        if filter_tags.is_empty() {
+            return true;
+        }
+
+        let object_tags = decode_tags_to_map(user_tags);
+        filter_tags.iter().all(|tag| match (&tag.key, &tag.value) {
+            (Some(key), value) => object_tags.get(key).map(String::as_str) == Some(value.as_deref().unwrap_or_default()),
+            (None, _) => false,
+        })
     }
 
     fn by_size(&self, sz: i64) -> bool {
+        let (greater_than, less_than) = match &self.and {
+            Some(and) => (and.object_size_greater_than, and.object_size_less_than),
+            None => (self.object_size_greater_than, self.object_size_less_than),
+        };
+        if let Some(gt) = greater_than
+            && gt > 0
+            && sz <= gt
+        {
+            return false;
+        }
+        if let Some(lt) = less_than
+            && lt > 0
+            && sz >= lt
+        {
+            return false;
+        }
         true
     }
+
+    fn validate(&self) -> Result<(), std::io::Error> {
+        let predicates = [
+            self.prefix.is_some(),
+            self.tag.is_some(),
+            self.object_size_greater_than.is_some(),
+            self.object_size_less_than.is_some(),
+            self.and.is_some(),
+        ];
+        if predicates.iter().filter(|set| **set).count() > 1 {
+            return Err(std::io::Error::other(ERR_INVALID_FILTER));
+        }
+
+        if let Some(tag) = &self.tag {
+            validate_tag(tag)?;
+        }
+        validate_size_range(self.object_size_greater_than, self.object_size_less_than)?;
+
+        if let Some(and) = &self.and {
+            validate_and(and)?;
+        }
+        Ok(())
+    }
+}
+
+fn validate_tag(tag: &Tag) -> Result<(), std::io::Error> {
+    let key = tag.key.as_deref().unwrap_or_default();
+    if key.is_empty() || key.len() > MAX_TAG_KEY_LENGTH {
+        return Err(std::io::Error::other(ERR_INVALID_TAG_KEY));
+    }
+    if tag.value.as_deref().unwrap_or_default().len() > MAX_TAG_VALUE_LENGTH {
+        return Err(std::io::Error::other(ERR_INVALID_TAG_VALUE));
+    }
+    Ok(())
+}
+
+fn validate_size_range(greater_than: Option<i64>, less_than: Option<i64>) -> Result<(), std::io::Error> {
+    if greater_than.is_some_and(|gt| gt < 0) || less_than.is_some_and(|lt| lt < 0) {
+        return Err(std::io::Error::other(ERR_INVALID_OBJECT_SIZE));
+    }
+    if let (Some(gt), Some(lt)) = (greater_than, less_than)
+        && lt > 0
+        && gt >= lt
+    {
+        return Err(std::io::Error::other(ERR_INVALID_OBJECT_SIZE_RANGE));
+    }
+    Ok(())
+}
+
+fn validate_and(and: &LifecycleRuleAndOperator) -> Result<(), std::io::Error> {
+    let tags = and.tags.as_deref().unwrap_or_default();
+    if and.prefix.is_none() && tags.is_empty() && and.object_size_greater_than.is_none() && and.object_size_less_than.is_none() {
+        return Err(std::io::Error::other(ERR_XML_NOT_WELL_FORMED));
+    }
+
+    let mut keys = HashSet::new();
+    for tag in tags {
+        validate_tag(tag)?;
+        if !keys.insert(tag.key.as_deref().unwrap_or_default()) {
+            return Err(std::io::Error::other(ERR_DUPLICATE_TAG_KEY));
+        }
+    }
+    validate_size_range(and.object_size_greater_than, and.object_size_less_than)
+}
+
+pub trait LifecycleRuleExt {
+    /// Prefix the rule is scoped to, from the legacy `Prefix`, `Filter.Prefix` or `Filter.And.Prefix`
+    fn prefix(&self) -> &str;
+    /// Whether the rule applies to an object with the given name, tags and size
+    fn matches(&self, name: &str, user_tags: &str, size: i64, delete_marker: bool) -> bool;
+    fn validate_prefix_and_filter(&self) -> Result<(), std::io::Error>;
+    /// Uploads carry no tags or size, so only prefix-scoped rules may abort them
+    fn validate_abort_incomplete_multipart_upload(&self) -> Result<(), std::io::Error>;
+}
+
+impl LifecycleRuleExt for LifecycleRule {
+    fn prefix(&self) -> &str {
+        if let Some(prefix) = &self.prefix {
+            return prefix;
+        }
+        match &self.filter {
+            Some(filter) => match (&filter.prefix, &filter.and) {
+                (Some(prefix), _) => prefix,
+                (None, Some(and)) => and.prefix.as_deref().unwrap_or(""),
+                (None, None) => "",
+            },
+            None => "",
+        }
+    }
+
+    fn matches(&self, name: &str, user_tags: &str, size: i64, delete_marker: bool) -> bool {
+        if !name.starts_with(self.prefix()) {
+            return false;
+        }
+        let Some(filter) = &self.filter else {
+            return true;
+        };
+        // Delete markers carry no tags, so a tag-filtered rule never matches them, while their
+        // zero size must not keep size-filtered rules from expiring them
+        Filter::test_tags(filter, user_tags) && (delete_marker || Filter::by_size(filter, size))
+    }
+
+    fn validate_prefix_and_filter(&self) -> Result<(), std::io::Error> {
+        if self.prefix.is_some() && self.filter.is_some() {
+            return Err(std::io::Error::other(ERR_XML_NOT_WELL_FORMED));
+        }
+        match &self.filter {
+            Some(filter) => Filter::validate(filter),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_abort_incomplete_multipart_upload(&self) -> Result<(), std::io::Error> {
+        let Some(abort) = &self.abort_incomplete_multipart_upload else {
+            return Ok(());
+        };
+        if abort.days_after_initiation.is_none_or(|days| days <= 0) {
+            return Err(std::io::Error::other(ERR_INVALID_DAYS_AFTER_INITIATION));
+        }
+        let Some(filter) = &self.filter else {
+            return Ok(());
+        };
+        let and = filter.and.as_ref();
+        let has_tags = filter.tag.is_some() || and.is_some_and(|and| and.tags.as_ref().is_some_and(|tags| !tags.is_empty()));
+        let has_size = filter.object_size_greater_than.is_some()
+            || filter.object_size_less_than.is_some()
+            || and.is_some_and(|and| and.object_size_greater_than.is_some() || and.object_size_less_than.is_some());
+        if has_tags || has_size {
+            return Err(std::io::Error::other(ERR_ABORT_MPU_WITH_FILTER));
+        }
+        Ok(())
+    }
 }
 
 pub trait TransitionOps {
@@ -61,9 +237,147 @@ impl TransitionOps for Transition {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::bucket::utils::deserialize;
+    use s3s::dto::BucketLifecycleConfiguration;
 
     #[tokio::test]
     async fn test_rule() {
         //assert!(skip_access_checks(p.to_str().unwrap()));
     }
+
+    fn tag(key: &str, value: &str) -> Tag {
+        Tag {
+            key: Some(key.to_string()),
+            value: Some(value.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_filter_tags() {
+        let filter = LifecycleRuleFilter {
+            tag: Some(tag("env", "tmp")),
+            ..Default::default()
+        };
+        assert!(Filter::test_tags(&filter, "env=tmp&team=a"));
+        assert!(!Filter::test_tags(&filter, "env=prod"));
+        assert!(!Filter::test_tags(&filter, ""));
+
+        let filter = LifecycleRuleFilter {
+            and: Some(LifecycleRuleAndOperator {
+                prefix: Some("tmp/".to_string()),
+                tags: Some(vec![tag("env", "tmp"), tag("team", "a")]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(Filter::test_tags(&filter, "team=a&env=tmp"));
+        assert!(!Filter::test_tags(&filter, "env=tmp"));
+        assert!(Filter::test_tags(&LifecycleRuleFilter::default(), ""));
+    }
+
+    #[test]
+    fn test_filter_by_size() {
+        let filter = LifecycleRuleFilter {
+            object_size_greater_than: Some(100),
+            ..Default::default()
+        };
+        assert!(Filter::by_size(&filter, 101));
+        assert!(!Filter::by_size(&filter, 100));
+
+        let filter = LifecycleRuleFilter {
+            and: Some(LifecycleRuleAndOperator {
+                object_size_greater_than: Some(100),
+                object_size_less_than: Some(200),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(Filter::by_size(&filter, 150));
+        assert!(!Filter::by_size(&filter, 50));
+        assert!(!Filter::by_size(&filter, 200));
+    }
+
+    #[test]
+    fn test_rule_matches_delete_marker() {
+        let xml = r#"<LifecycleConfiguration><Rule><ID>tagged</ID><Status>Enabled</Status><Filter><And><Prefix>logs/</Prefix><Tag><Key>env</Key><Value>tmp</Value></Tag></And></Filter><Expiration><Days>1</Days></Expiration></Rule><Rule><ID>sized</ID><Status>Enabled</Status><Filter><ObjectSizeGreaterThan>100</ObjectSizeGreaterThan></Filter><Expiration><Days>1</Days></Expiration></Rule></LifecycleConfiguration>"#;
+        let config: BucketLifecycleConfiguration = deserialize(xml.as_bytes()).unwrap();
+        let (tagged, sized) = (&config.rules[0], &config.rules[1]);
+
+        assert!(tagged.matches("logs/a", "env=tmp", 0, false));
+        assert!(!tagged.matches("logs/a", "", 0, true));
+        assert!(!tagged.matches("data/a", "env=tmp", 0, false));
+
+        assert!(sized.matches("logs/a", "", 0, true));
+        assert!(!sized.matches("logs/a", "", 50, false));
+        assert!(sized.matches("logs/a", "", 150, false));
+    }
+
+    #[test]
+    fn test_validate_abort_incomplete_multipart_upload() {
+        let rule = |filter: &str, days: i32| -> LifecycleRule {
+            let xml = format!(
+                "<LifecycleConfiguration><Rule><ID>abort</ID><Status>Enabled</Status><Filter>{filter}</Filter><AbortIncompleteMultipartUpload><DaysAfterInitiation>{days}</DaysAfterInitiation></AbortIncompleteMultipartUpload></Rule></LifecycleConfiguration>"
+            );
+            let config: BucketLifecycleConfiguration = deserialize(xml.as_bytes()).unwrap();
+            config.rules.into_iter().next().unwrap()
+        };
+
+        assert!(
+            rule("<Prefix>tmp/</Prefix>", 1)
+                .validate_abort_incomplete_multipart_upload()
+                .is_ok()
+        );
+        assert!(
+            rule("<Prefix>tmp/</Prefix>", 0)
+                .validate_abort_incomplete_multipart_upload()
+                .is_err()
+        );
+        assert!(
+            rule("<Tag><Key>env</Key><Value>tmp</Value></Tag>", 1)
+                .validate_abort_incomplete_multipart_upload()
+                .is_err()
+        );
+        assert!(
+            rule("<ObjectSizeGreaterThan>100</ObjectSizeGreaterThan>", 1)
+                .validate_abort_incomplete_multipart_upload()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_filter_validate() {
+        assert!(Filter::validate(&LifecycleRuleFilter::default()).is_ok());
+
+        let filter = LifecycleRuleFilter {
+            prefix: Some("tmp/".to_string()),
+            tag: Some(tag("env", "tmp")),
+            ..Default::default()
+        };
+        assert!(Filter::validate(&filter).is_err());
+
+        let filter = LifecycleRuleFilter {
+            and: Some(LifecycleRuleAndOperator {
+                tags: Some(vec![tag("env", "tmp"), tag("env", "prod")]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(Filter::validate(&filter).is_err());
+
+        let filter = LifecycleRuleFilter {
+            and: Some(LifecycleRuleAndOperator {
+                object_size_greater_than: Some(200),
+                object_size_less_than: Some(100),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(Filter::validate(&filter).is_err());
+
+        let filter = LifecycleRuleFilter {
+            and: Some(LifecycleRuleAndOperator::default()),
+            ..Default::default()
+        };
+        assert!(Filter::validate(&filter).is_err());
+    }
 }
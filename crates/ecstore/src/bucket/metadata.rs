@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::acl::AccessControlList;
+use super::inventory::unmarshal_inventory_configs;
 use super::object_lock::ObjectLockApi;
 use super::versioning::VersioningApi;
 use super::{quota::BucketQuota, target::BucketTargets};
@@ -25,8 +27,9 @@ use byteorder::{BigEndian, ByteOrder, LittleEndian};
 use rmp_serde::Serializer as rmpSerializer;
 use rustfs_policy::policy::BucketPolicy;
 use s3s::dto::{
-    BucketLifecycleConfiguration, CORSConfiguration, NotificationConfiguration, ObjectLockConfiguration,
-    ReplicationConfiguration, ServerSideEncryptionConfiguration, Tagging, VersioningConfiguration,
+    BucketLifecycleConfiguration, BucketLoggingStatus, CORSConfiguration, InventoryConfiguration, NotificationConfiguration,
+    ObjectLockConfiguration, OwnershipControls, PublicAccessBlockConfiguration, ReplicationConfiguration,
+    ServerSideEncryptionConfiguration, Tagging, VersioningConfiguration, WebsiteConfiguration,
 };
 use serde::Serializer;
 use serde::{Deserialize, Serialize};
@@ -50,6 +53,12 @@ pub const BUCKET_VERSIONING_CONFIG: &str = "versioning.xml";
 pub const BUCKET_REPLICATION_CONFIG: &str = "replication.xml";
 pub const BUCKET_TARGETS_FILE: &str = "bucket-targets.json";
 pub const BUCKET_CORS_CONFIG: &str = "cors.xml";
+pub const BUCKET_WEBSITE_CONFIG: &str = "website.xml";
+pub const BUCKET_LOGGING_CONFIG: &str = "logging.xml";
+pub const BUCKET_PUBLIC_ACCESS_BLOCK_CONFIG: &str = "public-access-block.xml";
+pub const BUCKET_OWNERSHIP_CONTROLS_CONFIG: &str = "ownership-controls.xml";
+pub const BUCKET_ACL_CONFIG: &str = "acl.json";
+pub const BUCKET_INVENTORY_CONFIG: &str = "inventory.json";
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "PascalCase", default)]
@@ -69,6 +78,12 @@ pub struct BucketMetadata {
     pub bucket_targets_config_json: Vec<u8>,
     pub bucket_targets_config_meta_json: Vec<u8>,
     pub cors_config_xml: Vec<u8>,
+    pub website_config_xml: Vec<u8>,
+    pub logging_config_xml: Vec<u8>,
+    pub public_access_block_config_xml: Vec<u8>,
+    pub ownership_controls_config_xml: Vec<u8>,
+    pub acl_config_json: Vec<u8>,
+    pub inventory_config_json: Vec<u8>,
 
     pub policy_config_updated_at: OffsetDateTime,
     pub object_lock_config_updated_at: OffsetDateTime,
@@ -82,6 +97,12 @@ pub struct BucketMetadata {
     pub bucket_targets_config_updated_at: OffsetDateTime,
     pub bucket_targets_config_meta_updated_at: OffsetDateTime,
     pub cors_config_updated_at: OffsetDateTime,
+    pub website_config_updated_at: OffsetDateTime,
+    pub logging_config_updated_at: OffsetDateTime,
+    pub public_access_block_config_updated_at: OffsetDateTime,
+    pub ownership_controls_config_updated_at: OffsetDateTime,
+    pub acl_config_updated_at: OffsetDateTime,
+    pub inventory_config_updated_at: OffsetDateTime,
 
     #[serde(skip)]
     pub new_field_updated_at: OffsetDateTime,
@@ -110,6 +131,18 @@ pub struct BucketMetadata {
     pub bucket_target_config_meta: Option<HashMap<String, String>>,
     #[serde(skip)]
     pub cors_config: Option<CORSConfiguration>,
+    #[serde(skip)]
+    pub website_config: Option<WebsiteConfiguration>,
+    #[serde(skip)]
+    pub logging_config: Option<BucketLoggingStatus>,
+    #[serde(skip)]
+    pub public_access_block_config: Option<PublicAccessBlockConfiguration>,
+    #[serde(skip)]
+    pub ownership_controls_config: Option<OwnershipControls>,
+    #[serde(skip)]
+    pub acl_config: Option<AccessControlList>,
+    #[serde(skip)]
+    pub inventory_config: Option<Vec<InventoryConfiguration>>,
 }
 
 impl Default for BucketMetadata {
@@ -130,6 +163,12 @@ impl Default for BucketMetadata {
             bucket_targets_config_json: Default::default(),
             bucket_targets_config_meta_json: Default::default(),
             cors_config_xml: Default::default(),
+            website_config_xml: Default::default(),
+            logging_config_xml: Default::default(),
+            public_access_block_config_xml: Default::default(),
+            ownership_controls_config_xml: Default::default(),
+            acl_config_json: Default::default(),
+            inventory_config_json: Default::default(),
             policy_config_updated_at: OffsetDateTime::UNIX_EPOCH,
             object_lock_config_updated_at: OffsetDateTime::UNIX_EPOCH,
             encryption_config_updated_at: OffsetDateTime::UNIX_EPOCH,
@@ -142,6 +181,12 @@ impl Default for BucketMetadata {
             bucket_targets_config_updated_at: OffsetDateTime::UNIX_EPOCH,
             bucket_targets_config_meta_updated_at: OffsetDateTime::UNIX_EPOCH,
             cors_config_updated_at: OffsetDateTime::UNIX_EPOCH,
+            website_config_updated_at: OffsetDateTime::UNIX_EPOCH,
+            logging_config_updated_at: OffsetDateTime::UNIX_EPOCH,
+            public_access_block_config_updated_at: OffsetDateTime::UNIX_EPOCH,
+            ownership_controls_config_updated_at: OffsetDateTime::UNIX_EPOCH,
+            acl_config_updated_at: OffsetDateTime::UNIX_EPOCH,
+            inventory_config_updated_at: OffsetDateTime::UNIX_EPOCH,
             new_field_updated_at: OffsetDateTime::UNIX_EPOCH,
             policy_config: Default::default(),
             notification_config: Default::default(),
@@ -155,6 +200,12 @@ impl Default for BucketMetadata {
             bucket_target_config: Default::default(),
             bucket_target_config_meta: Default::default(),
             cors_config: Default::default(),
+            website_config: Default::default(),
+            logging_config: Default::default(),
+            public_access_block_config: Default::default(),
+            ownership_controls_config: Default::default(),
+            acl_config: Default::default(),
+            inventory_config: Default::default(),
         }
     }
 }
@@ -307,6 +358,30 @@ impl BucketMetadata {
                 self.cors_config_xml = data;
                 self.cors_config_updated_at = updated;
             }
+            BUCKET_WEBSITE_CONFIG => {
+                self.website_config_xml = data;
+                self.website_config_updated_at = updated;
+            }
+            BUCKET_LOGGING_CONFIG => {
+                self.logging_config_xml = data;
+                self.logging_config_updated_at = updated;
+            }
+            BUCKET_PUBLIC_ACCESS_BLOCK_CONFIG => {
+                self.public_access_block_config_xml = data;
+                self.public_access_block_config_updated_at = updated;
+            }
+            BUCKET_OWNERSHIP_CONTROLS_CONFIG => {
+                self.ownership_controls_config_xml = data;
+                self.ownership_controls_config_updated_at = updated;
+            }
+            BUCKET_ACL_CONFIG => {
+                self.acl_config_json = data;
+                self.acl_config_updated_at = updated;
+            }
+            BUCKET_INVENTORY_CONFIG => {
+                self.inventory_config_json = data;
+                self.inventory_config_updated_at = updated;
+            }
             _ => return Err(Error::other(format!("config file not found : {config_file}"))),
         }
 
@@ -380,6 +455,25 @@ impl BucketMetadata {
         if !self.cors_config_xml.is_empty() {
             self.cors_config = Some(deserialize::<CORSConfiguration>(&self.cors_config_xml)?);
         }
+        if !self.website_config_xml.is_empty() {
+            self.website_config = Some(deserialize::<WebsiteConfiguration>(&self.website_config_xml)?);
+        }
+        if !self.logging_config_xml.is_empty() {
+            self.logging_config = Some(deserialize::<BucketLoggingStatus>(&self.logging_config_xml)?);
+        }
+        if !self.public_access_block_config_xml.is_empty() {
+            self.public_access_block_config =
+                Some(deserialize::<PublicAccessBlockConfiguration>(&self.public_access_block_config_xml)?);
+        }
+        if !self.ownership_controls_config_xml.is_empty() {
+            self.ownership_controls_config = Some(deserialize::<OwnershipControls>(&self.ownership_controls_config_xml)?);
+        }
+        if !self.acl_config_json.is_empty() {
+            self.acl_config = Some(serde_json::from_slice(&self.acl_config_json)?);
+        }
+        if !self.inventory_config_json.is_empty() {
+            self.inventory_config = Some(unmarshal_inventory_configs(&self.inventory_config_json)?);
+        }
 
         Ok(())
     }
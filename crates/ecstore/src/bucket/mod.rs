@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod acl;
 pub mod bucket_target_sys;
 pub mod error;
+pub mod inventory;
 pub mod lifecycle;
 pub mod metadata;
 pub mod metadata_sys;
 pub mod object_lock;
 pub mod policy_sys;
+pub mod public_access;
 pub mod quota;
 pub mod replication;
 pub mod tagging;
@@ -26,3 +29,4 @@ pub mod target;
 pub mod utils;
 pub mod versioning;
 pub mod versioning_sys;
+pub mod website;
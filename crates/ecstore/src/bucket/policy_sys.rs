@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::metadata_sys::get_bucket_metadata_sys;
+use super::public_access::{PublicAccessBlockApi, get_public_access_block};
 use crate::error::{Result, StorageError};
 use rustfs_policy::policy::{BucketPolicy, BucketPolicyArgs};
 use tracing::info;
@@ -22,7 +23,16 @@ pub struct PolicySys {}
 impl PolicySys {
     pub async fn is_allowed(args: &BucketPolicyArgs<'_>) -> bool {
         match Self::get(args.bucket).await {
-            Ok(cfg) => return cfg.is_allowed(args).await,
+            Ok(cfg) => {
+                // RestrictPublicBuckets keeps anonymous callers out of buckets whose policy is public
+                if args.account.is_empty()
+                    && cfg.is_public()
+                    && get_public_access_block(args.bucket).await.restricts_public_buckets()
+                {
+                    return args.is_owner;
+                }
+                return cfg.is_allowed(args).await;
+            }
             Err(err) => {
                 if err != StorageError::ConfigNotFound {
                     info!("config get err {:?}", err);
@@ -0,0 +1,168 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::metadata_sys;
+use s3s::dto::{Grant, ObjectOwnership, OwnershipControls, PublicAccessBlockConfiguration};
+
+/// Grantee group granting access to anyone
+pub const ALL_USERS_GROUP: &str = "http://acs.amazonaws.com/groups/global/AllUsers";
+/// Grantee group granting access to any authenticated caller
+pub const AUTHENTICATED_USERS_GROUP: &str = "http://acs.amazonaws.com/groups/global/AuthenticatedUsers";
+
+const ERR_OWNERSHIP_RULES: &str = "OwnershipControls must contain exactly one rule";
+const ERR_OWNERSHIP_VALUE: &str = "Invalid ObjectOwnership, it must be BucketOwnerEnforced, BucketOwnerPreferred or ObjectWriter";
+
+pub trait PublicAccessBlockApi {
+    fn blocks_public_acls(&self) -> bool;
+    fn ignores_public_acls(&self) -> bool;
+    fn blocks_public_policy(&self) -> bool;
+    fn restricts_public_buckets(&self) -> bool;
+}
+
+impl PublicAccessBlockApi for PublicAccessBlockConfiguration {
+    fn blocks_public_acls(&self) -> bool {
+        self.block_public_acls.unwrap_or_default()
+    }
+
+    fn ignores_public_acls(&self) -> bool {
+        self.ignore_public_acls.unwrap_or_default()
+    }
+
+    fn blocks_public_policy(&self) -> bool {
+        self.block_public_policy.unwrap_or_default()
+    }
+
+    fn restricts_public_buckets(&self) -> bool {
+        self.restrict_public_buckets.unwrap_or_default()
+    }
+}
+
+pub trait OwnershipControlsApi {
+    fn validate(&self) -> Result<(), std::io::Error>;
+    /// `BucketOwnerEnforced` turns ACLs off for the bucket and its objects
+    fn acls_disabled(&self) -> bool;
+}
+
+impl OwnershipControlsApi for OwnershipControls {
+    fn validate(&self) -> Result<(), std::io::Error> {
+        let [rule] = self.rules.as_slice() else {
+            return Err(std::io::Error::other(ERR_OWNERSHIP_RULES));
+        };
+
+        match rule.object_ownership.as_str() {
+            ObjectOwnership::BUCKET_OWNER_ENFORCED | ObjectOwnership::BUCKET_OWNER_PREFERRED | ObjectOwnership::OBJECT_WRITER => {
+                Ok(())
+            }
+            _ => Err(std::io::Error::other(ERR_OWNERSHIP_VALUE)),
+        }
+    }
+
+    fn acls_disabled(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|r| r.object_ownership.as_str() == ObjectOwnership::BUCKET_OWNER_ENFORCED)
+    }
+}
+
+/// The bucket's Public Access Block, all settings off when none is configured
+pub async fn get_public_access_block(bucket: &str) -> PublicAccessBlockConfiguration {
+    metadata_sys::get_public_access_block_config(bucket)
+        .await
+        .map(|(config, _)| config)
+        .unwrap_or_default()
+}
+
+/// Whether ACLs are disabled through `BucketOwnerEnforced` object ownership
+pub async fn bucket_acls_disabled(bucket: &str) -> bool {
+    metadata_sys::get_ownership_controls_config(bucket)
+        .await
+        .is_ok_and(|(controls, _)| controls.acls_disabled())
+}
+
+/// Canned ACLs that grant access outside the bucket owner
+pub fn is_public_canned_acl(acl: &str) -> bool {
+    matches!(acl, "public-read" | "public-read-write" | "authenticated-read")
+}
+
+/// Grants to the AllUsers or AuthenticatedUsers groups
+pub fn is_public_grant(grant: &Grant) -> bool {
+    grant
+        .grantee
+        .as_ref()
+        .and_then(|g| g.uri.as_deref())
+        .is_some_and(|uri| uri == ALL_USERS_GROUP || uri == AUTHENTICATED_USERS_GROUP)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s3s::dto::{Grantee, OwnershipControlsRule, Type};
+
+    fn controls(values: &[&'static str]) -> OwnershipControls {
+        OwnershipControls {
+            rules: values
+                .iter()
+                .map(|&v| OwnershipControlsRule {
+                    object_ownership: ObjectOwnership::from_static(v),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_ownership_controls() {
+        assert!(controls(&[ObjectOwnership::BUCKET_OWNER_ENFORCED]).validate().is_ok());
+        assert!(controls(&[ObjectOwnership::OBJECT_WRITER]).validate().is_ok());
+        assert!(controls(&[]).validate().is_err());
+        assert!(controls(&["Everyone"]).validate().is_err());
+        assert!(
+            controls(&[ObjectOwnership::OBJECT_WRITER, ObjectOwnership::BUCKET_OWNER_PREFERRED])
+                .validate()
+                .is_err()
+        );
+
+        assert!(controls(&[ObjectOwnership::BUCKET_OWNER_ENFORCED]).acls_disabled());
+        assert!(!controls(&[ObjectOwnership::BUCKET_OWNER_PREFERRED]).acls_disabled());
+    }
+
+    #[test]
+    fn test_public_acls() {
+        assert!(is_public_canned_acl("public-read"));
+        assert!(is_public_canned_acl("authenticated-read"));
+        assert!(!is_public_canned_acl("private"));
+        assert!(!is_public_canned_acl("bucket-owner-full-control"));
+
+        let grant = |uri: Option<&str>| Grant {
+            grantee: Some(Grantee {
+                type_: Type::from_static(Type::GROUP),
+                display_name: None,
+                email_address: None,
+                id: None,
+                uri: uri.map(str::to_string),
+            }),
+            permission: None,
+        };
+        assert!(is_public_grant(&grant(Some(ALL_USERS_GROUP))));
+        assert!(is_public_grant(&grant(Some(AUTHENTICATED_USERS_GROUP))));
+        assert!(!is_public_grant(&grant(Some("http://acs.amazonaws.com/groups/s3/LogDelivery"))));
+        assert!(!is_public_grant(&grant(None)));
+
+        let config = PublicAccessBlockConfiguration {
+            block_public_acls: Some(true),
+            ..Default::default()
+        };
+        assert!(config.blocks_public_acls());
+        assert!(!config.restricts_public_buckets());
+    }
+}
@@ -23,4 +23,5 @@ pub use config::*;
 pub use datatypes::*;
 pub use replication_pool::*;
 pub use replication_resyncer::*;
+pub use replication_state::{BucketReplicationStats, BucketStats, SRMetricsSummary};
 pub use rule::*;
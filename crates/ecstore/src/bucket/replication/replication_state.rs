@@ -665,6 +665,33 @@ pub struct SRMetricsSummary {
     pub replica_count: i64,
 }
 
+impl SRMetricsSummary {
+    /// Combine the summaries reported by two nodes into a cluster-wide one
+    pub fn merge(&self, other: &SRMetricsSummary) -> Self {
+        let mut metrics = self.metrics.clone();
+        for (name, value) in &other.metrics {
+            *metrics.entry(name.clone()).or_default() += value;
+        }
+
+        let mut proxied = self.proxied.clone();
+        proxied.add(&other.proxied);
+
+        Self {
+            uptime: self.uptime.max(other.uptime),
+            queued: self.queued.merge(&other.queued),
+            active_workers: ActiveWorkerStat {
+                curr: self.active_workers.curr + other.active_workers.curr,
+                max: self.active_workers.max + other.active_workers.max,
+                avg: self.active_workers.avg + other.active_workers.avg,
+            },
+            metrics,
+            proxied,
+            replica_size: self.replica_size + other.replica_size,
+            replica_count: self.replica_count + other.replica_count,
+        }
+    }
+}
+
 /// Active worker statistics
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ActiveWorkerStat {
@@ -1128,6 +1155,37 @@ impl ReplicationStats {
         let p_cache = self.p_cache.lock().await;
         p_cache.get_bucket_stats(bucket)
     }
+
+    /// Get this node's replication statistics for a bucket, as served to peers
+    pub async fn get_local_bucket_stats(&self, bucket: &str) -> BucketStats {
+        let mut replication_stats = self.get(bucket).await;
+        let q_stat = self.q_cache.lock().await.get_bucket_stats(bucket);
+        replication_stats.q_stat = q_stat.clone();
+
+        BucketStats {
+            uptime: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs() as i64,
+            replication_stats,
+            queue_stats: QueueStats {
+                nodes: vec![QueueNode { q_stats: q_stat }],
+            },
+            proxy_stats: self.get_proxy_stats(bucket).await,
+        }
+    }
+
+    /// Get this node's replication statistics for every bucket it has seen
+    pub async fn get_all_local_bucket_stats(&self) -> HashMap<String, BucketStats> {
+        let buckets: Vec<String> = self.cache.read().await.keys().cloned().collect();
+
+        let mut result = HashMap::with_capacity(buckets.len());
+        for bucket in buckets {
+            let stats = self.get_local_bucket_stats(&bucket).await;
+            result.insert(bucket, stats);
+        }
+        result
+    }
 }
 
 impl Default for ReplicationStats {
@@ -1212,4 +1270,32 @@ mod tests {
         assert_eq!(stats_map["replica_size"], 0);
         assert_eq!(stats_map["replica_count"], 0);
     }
+
+    #[test]
+    fn test_sr_metrics_summary_merge() {
+        let node = |replica_count: i64, workers: i32| SRMetricsSummary {
+            uptime: replica_count,
+            active_workers: ActiveWorkerStat {
+                curr: workers,
+                max: workers,
+                avg: workers as f64,
+            },
+            metrics: HashMap::from([("replica_count".to_string(), replica_count)]),
+            proxied: ProxyMetric {
+                get_total: 1,
+                ..Default::default()
+            },
+            replica_size: replica_count * 1024,
+            replica_count,
+            ..Default::default()
+        };
+
+        let merged = node(2, 1).merge(&node(3, 4));
+        assert_eq!(merged.uptime, 3);
+        assert_eq!(merged.replica_count, 5);
+        assert_eq!(merged.replica_size, 5 * 1024);
+        assert_eq!(merged.metrics["replica_count"], 5);
+        assert_eq!(merged.active_workers.curr, 5);
+        assert_eq!(merged.proxied.get_total, 2);
+    }
 }
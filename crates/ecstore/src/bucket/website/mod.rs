@@ -0,0 +1,338 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use s3s::dto::{Redirect, RoutingRule, WebsiteConfiguration};
+
+const ERR_MISSING_INDEX_DOCUMENT: &str = "A value for IndexDocument Suffix must be provided if RedirectAllRequestsTo is empty";
+const ERR_INVALID_INDEX_DOCUMENT: &str = "The IndexDocument Suffix is not well formed";
+const ERR_REDIRECT_ALL_WITH_OTHERS: &str = "RedirectAllRequestsTo cannot be provided in conjunction with other Routing Rules";
+const ERR_INVALID_REDIRECT_HOST: &str = "RedirectAllRequestsTo HostName must be provided";
+const ERR_EMPTY_REDIRECT: &str = "A RoutingRule Redirect must specify at least one redirect field";
+const ERR_BOTH_REPLACE_KEY: &str = "You can only define ReplaceKeyPrefix or ReplaceKey but not both";
+const ERR_INVALID_REDIRECT_CODE: &str = "The provided HTTP redirect code is not valid, it should be a 3XX code";
+const ERR_INVALID_ERROR_CODE: &str = "The provided HTTP error code is not valid, it should be a 4XX or 5XX code";
+
+/// The status of a redirect when the rule does not set `HttpRedirectCode`
+pub const DEFAULT_REDIRECT_CODE: u16 = 301;
+
+/// A redirect resolved from the website configuration for one request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebsiteRedirect {
+    /// `http` or `https`, `None` keeps the protocol of the request
+    pub protocol: Option<String>,
+    /// `None` keeps the host of the request
+    pub host: Option<String>,
+    /// The object key to redirect to, `None` keeps the requested path
+    pub key: Option<String>,
+    pub status: u16,
+}
+
+pub trait WebsiteApi {
+    /// Checks the configuration the way PutBucketWebsite does
+    fn validate(&self) -> Result<(), std::io::Error>;
+    /// The redirect for every request when `RedirectAllRequestsTo` is set
+    fn redirect_all(&self) -> Option<WebsiteRedirect>;
+    /// The object key served for a request key, folder keys get the index document appended
+    fn object_key(&self, key: &str) -> Option<String>;
+    /// The first routing rule redirect matching the key, and the error status when the read failed
+    fn routing_redirect(&self, key: &str, error_code: Option<u16>) -> Option<WebsiteRedirect>;
+}
+
+impl WebsiteApi for WebsiteConfiguration {
+    fn validate(&self) -> Result<(), std::io::Error> {
+        if let Some(redirect_all) = &self.redirect_all_requests_to {
+            if self.index_document.is_some() || self.error_document.is_some() || self.routing_rules.is_some() {
+                return Err(std::io::Error::other(ERR_REDIRECT_ALL_WITH_OTHERS));
+            }
+            if redirect_all.host_name.is_empty() {
+                return Err(std::io::Error::other(ERR_INVALID_REDIRECT_HOST));
+            }
+            return Ok(());
+        }
+
+        let Some(index) = &self.index_document else {
+            return Err(std::io::Error::other(ERR_MISSING_INDEX_DOCUMENT));
+        };
+        if index.suffix.is_empty() || index.suffix.contains('/') {
+            return Err(std::io::Error::other(ERR_INVALID_INDEX_DOCUMENT));
+        }
+
+        for rule in self.routing_rules.iter().flatten() {
+            validate_rule(rule)?;
+        }
+
+        Ok(())
+    }
+
+    fn redirect_all(&self) -> Option<WebsiteRedirect> {
+        self.redirect_all_requests_to.as_ref().map(|to| WebsiteRedirect {
+            protocol: to.protocol.as_ref().map(|p| p.as_str().to_string()),
+            host: Some(to.host_name.clone()),
+            key: None,
+            status: DEFAULT_REDIRECT_CODE,
+        })
+    }
+
+    fn object_key(&self, key: &str) -> Option<String> {
+        let suffix = &self.index_document.as_ref()?.suffix;
+        if key.is_empty() || key.ends_with('/') {
+            Some(format!("{key}{suffix}"))
+        } else {
+            Some(key.to_string())
+        }
+    }
+
+    fn routing_redirect(&self, key: &str, error_code: Option<u16>) -> Option<WebsiteRedirect> {
+        self.routing_rules
+            .iter()
+            .flatten()
+            .find(|rule| rule_matches(rule, key, error_code))
+            .map(|rule| resolve_redirect(rule, key))
+    }
+}
+
+fn validate_rule(rule: &RoutingRule) -> Result<(), std::io::Error> {
+    let redirect = &rule.redirect;
+    if redirect.host_name.is_none()
+        && redirect.http_redirect_code.is_none()
+        && redirect.protocol.is_none()
+        && redirect.replace_key_prefix_with.is_none()
+        && redirect.replace_key_with.is_none()
+    {
+        return Err(std::io::Error::other(ERR_EMPTY_REDIRECT));
+    }
+    if redirect.replace_key_prefix_with.is_some() && redirect.replace_key_with.is_some() {
+        return Err(std::io::Error::other(ERR_BOTH_REPLACE_KEY));
+    }
+    if let Some(code) = &redirect.http_redirect_code
+        && !code.parse::<u16>().is_ok_and(|c| (300..400).contains(&c))
+    {
+        return Err(std::io::Error::other(ERR_INVALID_REDIRECT_CODE));
+    }
+    if let Some(code) = rule
+        .condition
+        .as_ref()
+        .and_then(|c| c.http_error_code_returned_equals.as_ref())
+        && !code.parse::<u16>().is_ok_and(|c| (400..600).contains(&c))
+    {
+        return Err(std::io::Error::other(ERR_INVALID_ERROR_CODE));
+    }
+    Ok(())
+}
+
+/// Rules with an error code condition only apply once the read has failed with that code,
+/// all other rules apply before the object is read
+fn rule_matches(rule: &RoutingRule, key: &str, error_code: Option<u16>) -> bool {
+    let Some(condition) = &rule.condition else {
+        return error_code.is_none();
+    };
+
+    let code_matches = match (&condition.http_error_code_returned_equals, error_code) {
+        (Some(expected), Some(actual)) => expected.parse::<u16>().is_ok_and(|c| c == actual),
+        (None, None) => true,
+        _ => false,
+    };
+
+    code_matches
+        && condition
+            .key_prefix_equals
+            .as_deref()
+            .is_none_or(|prefix| key.starts_with(prefix))
+}
+
+fn resolve_redirect(rule: &RoutingRule, key: &str) -> WebsiteRedirect {
+    let Redirect {
+        host_name,
+        http_redirect_code,
+        protocol,
+        replace_key_prefix_with,
+        replace_key_with,
+    } = &rule.redirect;
+
+    let key = if let Some(replace) = replace_key_with {
+        Some(replace.clone())
+    } else if let Some(replace) = replace_key_prefix_with {
+        let prefix = rule
+            .condition
+            .as_ref()
+            .and_then(|c| c.key_prefix_equals.as_deref())
+            .unwrap_or_default();
+        Some(format!("{replace}{}", key.strip_prefix(prefix).unwrap_or(key)))
+    } else {
+        None
+    };
+
+    WebsiteRedirect {
+        protocol: protocol.as_ref().map(|p| p.as_str().to_string()),
+        host: host_name.clone(),
+        key,
+        status: http_redirect_code
+            .as_deref()
+            .and_then(|c| c.parse().ok())
+            .unwrap_or(DEFAULT_REDIRECT_CODE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s3s::dto::{Condition, ErrorDocument, IndexDocument, Protocol, RedirectAllRequestsTo};
+
+    fn rule(prefix: Option<&str>, error_code: Option<&str>, redirect: Redirect) -> RoutingRule {
+        RoutingRule {
+            condition: Some(Condition {
+                http_error_code_returned_equals: error_code.map(str::to_string),
+                key_prefix_equals: prefix.map(str::to_string),
+            }),
+            redirect,
+        }
+    }
+
+    fn website(rules: Vec<RoutingRule>) -> WebsiteConfiguration {
+        WebsiteConfiguration {
+            error_document: Some(ErrorDocument {
+                key: "error.html".to_string(),
+            }),
+            index_document: Some(IndexDocument {
+                suffix: "index.html".to_string(),
+            }),
+            redirect_all_requests_to: None,
+            routing_rules: if rules.is_empty() { None } else { Some(rules) },
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(website(vec![]).validate().is_ok());
+        assert!(
+            WebsiteConfiguration {
+                index_document: None,
+                ..website(vec![])
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            WebsiteConfiguration {
+                index_document: Some(IndexDocument {
+                    suffix: "docs/index.html".to_string()
+                }),
+                ..website(vec![])
+            }
+            .validate()
+            .is_err()
+        );
+
+        let redirect_all = Some(RedirectAllRequestsTo {
+            host_name: "example.com".to_string(),
+            protocol: Some(Protocol::from_static(Protocol::HTTPS)),
+        });
+        assert!(
+            WebsiteConfiguration {
+                error_document: None,
+                index_document: None,
+                redirect_all_requests_to: redirect_all.clone(),
+                routing_rules: None,
+            }
+            .validate()
+            .is_ok()
+        );
+        assert!(
+            WebsiteConfiguration {
+                redirect_all_requests_to: redirect_all,
+                ..website(vec![])
+            }
+            .validate()
+            .is_err()
+        );
+
+        let empty = rule(Some("docs/"), None, Redirect::default());
+        assert!(website(vec![empty]).validate().is_err());
+
+        let both = rule(
+            Some("docs/"),
+            None,
+            Redirect {
+                replace_key_prefix_with: Some("documents/".to_string()),
+                replace_key_with: Some("index.html".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(website(vec![both]).validate().is_err());
+
+        let bad_code = rule(
+            None,
+            Some("200"),
+            Redirect {
+                http_redirect_code: Some("302".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(website(vec![bad_code]).validate().is_err());
+    }
+
+    #[test]
+    fn test_object_key() {
+        let config = website(vec![]);
+        assert_eq!(config.object_key(""), Some("index.html".to_string()));
+        assert_eq!(config.object_key("docs/"), Some("docs/index.html".to_string()));
+        assert_eq!(config.object_key("docs/page.html"), Some("docs/page.html".to_string()));
+    }
+
+    #[test]
+    fn test_routing_redirect() {
+        let config = website(vec![
+            rule(
+                Some("docs/"),
+                None,
+                Redirect {
+                    replace_key_prefix_with: Some("documents/".to_string()),
+                    ..Default::default()
+                },
+            ),
+            rule(
+                None,
+                Some("404"),
+                Redirect {
+                    host_name: Some("fallback.example.com".to_string()),
+                    http_redirect_code: Some("302".to_string()),
+                    protocol: Some(Protocol::from_static(Protocol::HTTPS)),
+                    replace_key_with: Some("missing.html".to_string()),
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        assert_eq!(
+            config.routing_redirect("docs/a.html", None),
+            Some(WebsiteRedirect {
+                protocol: None,
+                host: None,
+                key: Some("documents/a.html".to_string()),
+                status: DEFAULT_REDIRECT_CODE,
+            })
+        );
+        assert_eq!(config.routing_redirect("images/a.png", None), None);
+        assert_eq!(
+            config.routing_redirect("images/a.png", Some(404)),
+            Some(WebsiteRedirect {
+                protocol: Some("https".to_string()),
+                host: Some("fallback.example.com".to_string()),
+                key: Some("missing.html".to_string()),
+                status: 302,
+            })
+        );
+        assert_eq!(config.routing_redirect("images/a.png", Some(403)), None);
+    }
+}
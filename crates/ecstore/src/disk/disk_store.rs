@@ -19,15 +19,19 @@ use crate::disk::{
     local::{LocalDisk, ScanGuard},
 };
 use bytes::Bytes;
+use rustfs_common::trace_channel::{is_tracing, publish_trace};
 use rustfs_filemeta::{FileInfo, ObjectPartInfo, RawFileInfo};
+use rustfs_madmin::trace::{TraceInfo, TraceType};
+use rustfs_utils::path::path_join_buf;
 use rustfs_utils::string::parse_bool_with_default;
 use std::{
+    collections::HashMap,
     path::PathBuf,
     sync::{
         Arc,
         atomic::{AtomicI64, AtomicU32, Ordering},
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::{sync::RwLock, time};
 use tokio_util::sync::CancellationToken;
@@ -422,6 +426,35 @@ impl LocalDiskWrapper {
         *self.disk_id.read().await
     }
 
+    /// Track disk health for an operation and publish it to `admin trace` storage subscribers.
+    async fn track_disk_op<T, F, Fut>(
+        &self,
+        func_name: &'static str,
+        volume: &str,
+        path: &str,
+        operation: F,
+        timeout_duration: Duration,
+    ) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        if !is_tracing(TraceType::STORAGE) {
+            return self.track_disk_health(operation, timeout_duration).await;
+        }
+
+        let start = Instant::now();
+        let result = self.track_disk_health(operation, timeout_duration).await;
+
+        let mut info = TraceInfo::new(TraceType::STORAGE, func_name, path_join_buf(&[volume, path]));
+        info.duration = start.elapsed();
+        info.error = result.as_ref().err().map(|err| err.to_string());
+        info.custom = Some(HashMap::from([("disk".to_string(), self.to_string())]));
+        publish_trace(info);
+
+        result
+    }
+
     /// Track disk health for an operation.
     /// This method should wrap disk operations to ensure health checking.
     pub async fn track_disk_health<T, F, Fut>(&self, operation: F, timeout_duration: Duration) -> Result<T>
@@ -479,8 +512,14 @@ impl LocalDiskWrapper {
 #[async_trait::async_trait]
 impl DiskAPI for LocalDiskWrapper {
     async fn read_metadata(&self, volume: &str, path: &str) -> Result<Bytes> {
-        self.track_disk_health(|| async { self.disk.read_metadata(volume, path).await }, Duration::ZERO)
-            .await
+        self.track_disk_op(
+            "storage.ReadMetadata",
+            volume,
+            path,
+            || async { self.disk.read_metadata(volume, path).await },
+            Duration::ZERO,
+        )
+        .await
     }
 
     fn start_scan(&self) -> ScanGuard {
@@ -604,7 +643,10 @@ impl DiskAPI for LocalDiskWrapper {
         force_del_marker: bool,
         opts: DeleteOptions,
     ) -> Result<()> {
-        self.track_disk_health(
+        self.track_disk_op(
+            "storage.DeleteVersion",
+            volume,
+            path,
             || async { self.disk.delete_version(volume, path, fi, force_del_marker, opts).await },
             get_max_timeout_duration(),
         )
@@ -644,12 +686,21 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn delete_paths(&self, volume: &str, paths: &[String]) -> Result<()> {
-        self.track_disk_health(|| async { self.disk.delete_paths(volume, paths).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_op(
+            "storage.DeletePaths",
+            volume,
+            "",
+            || async { self.disk.delete_paths(volume, paths).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn write_metadata(&self, org_volume: &str, volume: &str, path: &str, fi: FileInfo) -> Result<()> {
-        self.track_disk_health(
+        self.track_disk_op(
+            "storage.WriteMetadata",
+            volume,
+            path,
             || async { self.disk.write_metadata(org_volume, volume, path, fi).await },
             get_max_timeout_duration(),
         )
@@ -657,7 +708,10 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn update_metadata(&self, volume: &str, path: &str, fi: FileInfo, opts: &UpdateMetadataOpts) -> Result<()> {
-        self.track_disk_health(
+        self.track_disk_op(
+            "storage.UpdateMetadata",
+            volume,
+            path,
             || async { self.disk.update_metadata(volume, path, fi, opts).await },
             get_max_timeout_duration(),
         )
@@ -672,7 +726,10 @@ impl DiskAPI for LocalDiskWrapper {
         version_id: &str,
         opts: &ReadOptions,
     ) -> Result<FileInfo> {
-        self.track_disk_health(
+        self.track_disk_op(
+            "storage.ReadVersion",
+            volume,
+            path,
             || async { self.disk.read_version(org_volume, volume, path, version_id, opts).await },
             get_max_timeout_duration(),
         )
@@ -680,8 +737,14 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn read_xl(&self, volume: &str, path: &str, read_data: bool) -> Result<RawFileInfo> {
-        self.track_disk_health(|| async { self.disk.read_xl(volume, path, read_data).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_op(
+            "storage.ReadXL",
+            volume,
+            path,
+            || async { self.disk.read_xl(volume, path, read_data).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn rename_data(
@@ -692,7 +755,10 @@ impl DiskAPI for LocalDiskWrapper {
         dst_volume: &str,
         dst_path: &str,
     ) -> Result<RenameDataResp> {
-        self.track_disk_health(
+        self.track_disk_op(
+            "storage.RenameData",
+            src_volume,
+            src_path,
             || async { self.disk.rename_data(src_volume, src_path, fi, dst_volume, dst_path).await },
             get_max_timeout_duration(),
         )
@@ -700,7 +766,10 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn list_dir(&self, origvolume: &str, volume: &str, dir_path: &str, count: i32) -> Result<Vec<String>> {
-        self.track_disk_health(
+        self.track_disk_op(
+            "storage.ListDir",
+            volume,
+            dir_path,
             || async { self.disk.list_dir(origvolume, volume, dir_path, count).await },
             get_max_timeout_duration(),
         )
@@ -708,12 +777,21 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn read_file(&self, volume: &str, path: &str) -> Result<crate::disk::FileReader> {
-        self.track_disk_health(|| async { self.disk.read_file(volume, path).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_op(
+            "storage.ReadFile",
+            volume,
+            path,
+            || async { self.disk.read_file(volume, path).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn read_file_stream(&self, volume: &str, path: &str, offset: usize, length: usize) -> Result<crate::disk::FileReader> {
-        self.track_disk_health(
+        self.track_disk_op(
+            "storage.ReadFileStream",
+            volume,
+            path,
             || async { self.disk.read_file_stream(volume, path, offset, length).await },
             get_max_timeout_duration(),
         )
@@ -721,12 +799,21 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn append_file(&self, volume: &str, path: &str) -> Result<crate::disk::FileWriter> {
-        self.track_disk_health(|| async { self.disk.append_file(volume, path).await }, Duration::ZERO)
-            .await
+        self.track_disk_op(
+            "storage.AppendFile",
+            volume,
+            path,
+            || async { self.disk.append_file(volume, path).await },
+            Duration::ZERO,
+        )
+        .await
     }
 
     async fn create_file(&self, origvolume: &str, volume: &str, path: &str, file_size: i64) -> Result<crate::disk::FileWriter> {
-        self.track_disk_health(
+        self.track_disk_op(
+            "storage.CreateFile",
+            volume,
+            path,
             || async { self.disk.create_file(origvolume, volume, path, file_size).await },
             Duration::ZERO,
         )
@@ -734,7 +821,10 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn rename_file(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str) -> Result<()> {
-        self.track_disk_health(
+        self.track_disk_op(
+            "storage.RenameFile",
+            src_volume,
+            src_path,
             || async { self.disk.rename_file(src_volume, src_path, dst_volume, dst_path).await },
             get_max_timeout_duration(),
         )
@@ -742,7 +832,10 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn rename_part(&self, src_volume: &str, src_path: &str, dst_volume: &str, dst_path: &str, meta: Bytes) -> Result<()> {
-        self.track_disk_health(
+        self.track_disk_op(
+            "storage.RenamePart",
+            src_volume,
+            src_path,
             || async { self.disk.rename_part(src_volume, src_path, dst_volume, dst_path, meta).await },
             get_max_timeout_duration(),
         )
@@ -750,23 +843,47 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn delete(&self, volume: &str, path: &str, opt: DeleteOptions) -> Result<()> {
-        self.track_disk_health(|| async { self.disk.delete(volume, path, opt).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_op(
+            "storage.Delete",
+            volume,
+            path,
+            || async { self.disk.delete(volume, path, opt).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn verify_file(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
-        self.track_disk_health(|| async { self.disk.verify_file(volume, path, fi).await }, Duration::ZERO)
-            .await
+        self.track_disk_op(
+            "storage.VerifyFile",
+            volume,
+            path,
+            || async { self.disk.verify_file(volume, path, fi).await },
+            Duration::ZERO,
+        )
+        .await
     }
 
     async fn check_parts(&self, volume: &str, path: &str, fi: &FileInfo) -> Result<CheckPartsResp> {
-        self.track_disk_health(|| async { self.disk.check_parts(volume, path, fi).await }, Duration::ZERO)
-            .await
+        self.track_disk_op(
+            "storage.CheckParts",
+            volume,
+            path,
+            || async { self.disk.check_parts(volume, path, fi).await },
+            Duration::ZERO,
+        )
+        .await
     }
 
     async fn read_parts(&self, bucket: &str, paths: &[String]) -> Result<Vec<ObjectPartInfo>> {
-        self.track_disk_health(|| async { self.disk.read_parts(bucket, paths).await }, Duration::ZERO)
-            .await
+        self.track_disk_op(
+            "storage.ReadParts",
+            bucket,
+            "",
+            || async { self.disk.read_parts(bucket, paths).await },
+            Duration::ZERO,
+        )
+        .await
     }
 
     async fn read_multiple(&self, req: ReadMultipleReq) -> Result<Vec<ReadMultipleResp>> {
@@ -775,12 +892,24 @@ impl DiskAPI for LocalDiskWrapper {
     }
 
     async fn write_all(&self, volume: &str, path: &str, data: Bytes) -> Result<()> {
-        self.track_disk_health(|| async { self.disk.write_all(volume, path, data).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_op(
+            "storage.WriteAll",
+            volume,
+            path,
+            || async { self.disk.write_all(volume, path, data).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 
     async fn read_all(&self, volume: &str, path: &str) -> Result<Bytes> {
-        self.track_disk_health(|| async { self.disk.read_all(volume, path).await }, get_max_timeout_duration())
-            .await
+        self.track_disk_op(
+            "storage.ReadAll",
+            volume,
+            path,
+            || async { self.disk.read_all(volume, path).await },
+            get_max_timeout_duration(),
+        )
+        .await
     }
 }
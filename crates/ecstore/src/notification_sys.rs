@@ -14,6 +14,7 @@
 
 use crate::StorageAPI;
 use crate::admin_server_info::get_commit_id;
+use crate::bucket::replication::{BucketStats, SRMetricsSummary};
 use crate::error::{Error, Result};
 use crate::global::{GLOBAL_BOOT_TIME, get_global_endpoints};
 use crate::metrics_realtime::{CollectMetricsOpts, MetricType};
@@ -21,10 +22,12 @@ use crate::rpc::PeerRestClient;
 use crate::{endpoints::EndpointServerPools, new_object_layer_fn};
 use futures::future::join_all;
 use lazy_static::lazy_static;
+use rustfs_madmin::heal_commands::BgHealState;
 use rustfs_madmin::health::{Cpus, MemInfo, OsInfo, Partitions, ProcInfo, SysConfig, SysErrors, SysService};
 use rustfs_madmin::metrics::RealtimeMetrics;
 use rustfs_madmin::net::NetInfo;
 use rustfs_madmin::{ItemState, ServerProperties};
+use std::collections::HashMap;
 use std::collections::hash_map::DefaultHasher;
 use std::future::Future;
 use std::hash::{Hash, Hasher};
@@ -520,6 +523,79 @@ impl NotificationSys {
         join_all(futures).await
     }
 
+    /// Collect the profiles started by `start_profiling` from every peer, keyed by host.
+    pub async fn download_profile_data(&self) -> HashMap<String, HashMap<String, Vec<u8>>> {
+        let mut futures = Vec::with_capacity(self.peer_clients.len());
+        for client in self.peer_clients.iter().flatten() {
+            futures.push(async move {
+                match client.download_profile_data().await {
+                    Ok(data) => Some((client.host.to_string(), data)),
+                    Err(err) => {
+                        warn!("download profile data from {} failed: {}", client.host, err);
+                        None
+                    }
+                }
+            });
+        }
+        join_all(futures).await.into_iter().flatten().collect()
+    }
+
+    pub async fn get_cluster_bucket_stats(&self, bucket: &str) -> Vec<BucketStats> {
+        let mut futures = Vec::with_capacity(self.peer_clients.len());
+        for client in self.peer_clients.iter().cloned() {
+            futures.push(async move {
+                if let Some(client) = client {
+                    client.get_bucket_stats(bucket).await.unwrap_or_default()
+                } else {
+                    BucketStats::default()
+                }
+            });
+        }
+        join_all(futures).await
+    }
+
+    pub async fn get_cluster_all_bucket_stats(&self) -> Vec<HashMap<String, BucketStats>> {
+        let mut futures = Vec::with_capacity(self.peer_clients.len());
+        for client in self.peer_clients.iter().cloned() {
+            futures.push(async move {
+                if let Some(client) = client {
+                    client.get_all_bucket_stats().await.unwrap_or_default()
+                } else {
+                    HashMap::new()
+                }
+            });
+        }
+        join_all(futures).await
+    }
+
+    pub async fn get_cluster_site_metrics(&self) -> Vec<SRMetricsSummary> {
+        let mut futures = Vec::with_capacity(self.peer_clients.len());
+        for client in self.peer_clients.iter().cloned() {
+            futures.push(async move {
+                if let Some(client) = client {
+                    client.get_sr_metrics().await.unwrap_or_default()
+                } else {
+                    SRMetricsSummary::default()
+                }
+            });
+        }
+        join_all(futures).await
+    }
+
+    pub async fn background_heal_status(&self) -> Vec<BgHealState> {
+        let mut futures = Vec::with_capacity(self.peer_clients.len());
+        for client in self.peer_clients.iter().cloned() {
+            futures.push(async move {
+                if let Some(client) = client {
+                    client.background_heal_status().await.unwrap_or_default()
+                } else {
+                    BgHealState::default()
+                }
+            });
+        }
+        join_all(futures).await
+    }
+
     pub async fn get_cpus(&self) -> Vec<Cpus> {
         let mut futures = Vec::with_capacity(self.peer_clients.len());
         for client in self.peer_clients.iter().cloned() {
@@ -713,6 +789,33 @@ impl NotificationSys {
         }
         join_all(futures).await
     }
+
+    pub async fn signal_service(&self, sig: u64, sub_sys: &str, dry_run: bool) -> Vec<NotificationPeerErr> {
+        let mut futures = Vec::with_capacity(self.peer_clients.len());
+        for client in self.peer_clients.iter() {
+            let sub_sys = sub_sys.to_string();
+            futures.push(async move {
+                if let Some(client) = client {
+                    match client.signal_service(sig, &sub_sys, dry_run, SystemTime::now()).await {
+                        Ok(_) => NotificationPeerErr {
+                            host: client.host.to_string(),
+                            err: None,
+                        },
+                        Err(e) => NotificationPeerErr {
+                            host: client.host.to_string(),
+                            err: Some(e),
+                        },
+                    }
+                } else {
+                    NotificationPeerErr {
+                        host: "".to_string(),
+                        err: Some(Error::other("peer is not reachable")),
+                    }
+                }
+            });
+        }
+        join_all(futures).await
+    }
 }
 
 async fn call_peer_with_timeout<F, Fut>(
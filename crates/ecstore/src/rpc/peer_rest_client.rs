@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::bucket::replication::{BucketStats, SRMetricsSummary};
 use crate::error::{Error, Result};
 use crate::rpc::client::{TonicInterceptor, gen_tonic_signature_interceptor, node_service_time_out_client};
 use crate::{
@@ -19,22 +20,27 @@ use crate::{
     global::is_dist_erasure,
     metrics_realtime::{CollectMetricsOpts, MetricType},
 };
+use futures::stream::{BoxStream, StreamExt};
 use rmp_serde::{Deserializer, Serializer};
 use rustfs_madmin::{
     ServerProperties,
+    heal_commands::BgHealState,
     health::{Cpus, MemInfo, OsInfo, Partitions, ProcInfo, SysConfig, SysErrors, SysService},
     metrics::RealtimeMetrics,
     net::NetInfo,
+    service_commands::ServiceTraceOpts,
+    trace::TraceInfo,
 };
 use rustfs_protos::evict_failed_connection;
 use rustfs_protos::proto_gen::node_service::{
-    DeleteBucketMetadataRequest, DeletePolicyRequest, DeleteServiceAccountRequest, DeleteUserRequest, GetCpusRequest,
+    BackgroundHealStatusRequest, DeleteBucketMetadataRequest, DeletePolicyRequest, DeleteServiceAccountRequest,
+    DeleteUserRequest, DownloadProfileDataRequest, GetAllBucketStatsRequest, GetBucketStatsDataRequest, GetCpusRequest,
     GetMemInfoRequest, GetMetricsRequest, GetNetInfoRequest, GetOsInfoRequest, GetPartitionsRequest, GetProcInfoRequest,
-    GetSeLinuxInfoRequest, GetSysConfigRequest, GetSysErrorsRequest, LoadBucketMetadataRequest, LoadGroupRequest,
-    LoadPolicyMappingRequest, LoadPolicyRequest, LoadRebalanceMetaRequest, LoadServiceAccountRequest,
+    GetSeLinuxInfoRequest, GetSrMetricsDataRequest, GetSysConfigRequest, GetSysErrorsRequest, LoadBucketMetadataRequest,
+    LoadGroupRequest, LoadPolicyMappingRequest, LoadPolicyRequest, LoadRebalanceMetaRequest, LoadServiceAccountRequest,
     LoadTransitionTierConfigRequest, LoadUserRequest, LocalStorageInfoRequest, Mss, ReloadPoolMetaRequest,
     ReloadSiteReplicationConfigRequest, ServerInfoRequest, SignalServiceRequest, StartProfilingRequest, StopRebalanceRequest,
-    node_service_client::NodeServiceClient,
+    TraceRequest, node_service_client::NodeServiceClient,
 };
 use rustfs_utils::XHost;
 use serde::{Deserialize, Serialize as _};
@@ -368,20 +374,97 @@ impl PeerRestClient {
         Ok(())
     }
 
-    pub async fn download_profile_data(&self) -> Result<()> {
-        todo!()
+    pub async fn download_profile_data(&self) -> Result<HashMap<String, Vec<u8>>> {
+        let mut client = self.get_client().await?;
+        let request = Request::new(DownloadProfileDataRequest {});
+
+        let response = client.download_profile_data(request).await?.into_inner();
+        if !response.success {
+            if let Some(msg) = response.error_info {
+                return Err(Error::other(msg));
+            }
+            return Err(Error::other(""));
+        }
+
+        Ok(response.data.into_iter().map(|(k, v)| (k, v.to_vec())).collect())
     }
 
-    pub async fn get_bucket_stats(&self) -> Result<()> {
-        todo!()
+    pub async fn get_bucket_stats(&self, bucket: &str) -> Result<BucketStats> {
+        let mut client = self.get_client().await?;
+        let request = Request::new(GetBucketStatsDataRequest {
+            bucket: bucket.to_string(),
+        });
+
+        let response = client.get_bucket_stats(request).await?.into_inner();
+        if !response.success {
+            if let Some(msg) = response.error_info {
+                return Err(Error::other(msg));
+            }
+            return Err(Error::other(""));
+        }
+        let data = response.bucket_stats;
+
+        let mut buf = Deserializer::new(Cursor::new(data));
+        let bucket_stats: BucketStats = Deserialize::deserialize(&mut buf)?;
+
+        Ok(bucket_stats)
     }
 
-    pub async fn get_sr_metrics(&self) -> Result<()> {
-        todo!()
+    pub async fn get_sr_metrics(&self) -> Result<SRMetricsSummary> {
+        let mut client = self.get_client().await?;
+        let request = Request::new(GetSrMetricsDataRequest {});
+
+        let response = client.get_sr_metrics(request).await?.into_inner();
+        if !response.success {
+            if let Some(msg) = response.error_info {
+                return Err(Error::other(msg));
+            }
+            return Err(Error::other(""));
+        }
+        let data = response.sr_metrics_summary;
+
+        let mut buf = Deserializer::new(Cursor::new(data));
+        let sr_metrics: SRMetricsSummary = Deserialize::deserialize(&mut buf)?;
+
+        Ok(sr_metrics)
     }
 
-    pub async fn get_all_bucket_stats(&self) -> Result<()> {
-        todo!()
+    pub async fn get_all_bucket_stats(&self) -> Result<HashMap<String, BucketStats>> {
+        let mut client = self.get_client().await?;
+        let request = Request::new(GetAllBucketStatsRequest {});
+
+        let response = client.get_all_bucket_stats(request).await?.into_inner();
+        if !response.success {
+            if let Some(msg) = response.error_info {
+                return Err(Error::other(msg));
+            }
+            return Err(Error::other(""));
+        }
+        let data = response.bucket_stats_map;
+
+        let mut buf = Deserializer::new(Cursor::new(data));
+        let bucket_stats_map: HashMap<String, BucketStats> = Deserialize::deserialize(&mut buf)?;
+
+        Ok(bucket_stats_map)
+    }
+
+    pub async fn background_heal_status(&self) -> Result<BgHealState> {
+        let mut client = self.get_client().await?;
+        let request = Request::new(BackgroundHealStatusRequest {});
+
+        let response = client.background_heal_status(request).await?.into_inner();
+        if !response.success {
+            if let Some(msg) = response.error_info {
+                return Err(Error::other(msg));
+            }
+            return Err(Error::other(""));
+        }
+        let data = response.bg_heal_state;
+
+        let mut buf = Deserializer::new(Cursor::new(data));
+        let bg_heal_state: BgHealState = Deserialize::deserialize(&mut buf)?;
+
+        Ok(bg_heal_state)
     }
 
     pub async fn load_bucket_metadata(&self, bucket: &str) -> Result<()> {
@@ -601,16 +684,6 @@ impl PeerRestClient {
         Ok(())
     }
 
-    pub async fn get_metacache_listing(&self) -> Result<()> {
-        let _client = self.get_client().await?;
-        todo!()
-    }
-
-    pub async fn update_metacache_listing(&self) -> Result<()> {
-        let _client = self.get_client().await?;
-        todo!()
-    }
-
     pub async fn reload_pool_meta(&self) -> Result<()> {
         let mut client = self.get_client().await?;
         let request = Request::new(ReloadPoolMetaRequest {});
@@ -672,4 +745,35 @@ impl PeerRestClient {
 
         Ok(())
     }
+
+    /// Subscribe to trace records produced on this peer.
+    ///
+    /// The returned stream yields records until the peer closes the stream or
+    /// the caller drops it.
+    pub async fn trace(&self, opts: &ServiceTraceOpts) -> Result<BoxStream<'static, Result<TraceInfo>>> {
+        let result = self.trace_inner(opts).await;
+        if result.is_err() {
+            // Evict stale connection on any error for cluster recovery
+            self.evict_connection().await;
+        }
+        result
+    }
+
+    async fn trace_inner(&self, opts: &ServiceTraceOpts) -> Result<BoxStream<'static, Result<TraceInfo>>> {
+        let mut client = self.get_client().await?;
+        let request = Request::new(TraceRequest {
+            opts: serde_json::to_vec(opts)?.into(),
+        });
+
+        let stream = client.trace(request).await?.into_inner();
+        Ok(stream
+            .map(|resp| {
+                let resp = resp?;
+                if !resp.success {
+                    return Err(Error::other(resp.error_info.unwrap_or_default()));
+                }
+                Ok(serde_json::from_slice::<TraceInfo>(&resp.trace_info)?)
+            })
+            .boxed())
+    }
 }
@@ -68,6 +68,7 @@ use md5::{Digest as Md5Digest, Md5};
 use rand::{Rng, seq::SliceRandom};
 use regex::Regex;
 use rustfs_common::heal_channel::{DriveState, HealChannelPriority, HealItemType, HealOpts, HealScanMode, send_heal_disk};
+use rustfs_common::trace_channel::{is_tracing, publish_trace};
 use rustfs_config::MI_B;
 use rustfs_filemeta::{
     FileInfo, FileMeta, FileMetaShallowVersion, MetaCacheEntries, MetaCacheEntry, MetadataResolutionParams, ObjectPartInfo,
@@ -78,10 +79,11 @@ use rustfs_lock::fast_lock::types::LockResult;
 use rustfs_lock::local_lock::LocalLock;
 use rustfs_lock::{FastLockGuard, NamespaceLock, NamespaceLockGuard, NamespaceLockWrapper, ObjectKey};
 use rustfs_madmin::heal_commands::{HealDriveInfo, HealResultItem};
+use rustfs_madmin::trace::{TraceInfo, TraceType};
 use rustfs_rio::{EtagResolvable, HashReader, HashReaderMut, TryGetIndex as _, WarpReader};
 use rustfs_utils::http::RUSTFS_BUCKET_REPLICATION_SSEC_CHECKSUM;
-use rustfs_utils::http::headers::AMZ_STORAGE_CLASS;
 use rustfs_utils::http::headers::{AMZ_OBJECT_TAGGING, RESERVED_METADATA_PREFIX, RESERVED_METADATA_PREFIX_LOWER};
+use rustfs_utils::http::headers::{AMZ_STORAGE_CLASS, RUSTFS_MULTIPART_BUCKET, RUSTFS_MULTIPART_OBJECT};
 use rustfs_utils::{
     HashAlgorithm,
     crypto::hex,
@@ -1048,6 +1050,78 @@ impl SetDisks {
         format!("{}/{}", Self::get_multipart_sha_dir(bucket, object), upload_uuid)
     }
 
+    /// Lists every in-progress multipart upload stored on this set, across all buckets.
+    ///
+    /// The owning bucket and object are taken from the metadata written at initiation; uploads
+    /// that predate it fall back to an empty bucket and their upload path as the object.
+    pub async fn list_all_multipart_uploads(&self) -> Result<Vec<MultipartInfo>> {
+        let disks = {
+            let disks = self.get_online_local_disks().await;
+            if disks.is_empty() {
+                self.get_online_disks().await
+            } else {
+                disks
+            }
+        };
+
+        let Some(disk) = disks.into_iter().flatten().next() else {
+            return Ok(Vec::new());
+        };
+
+        let sha_dirs = match disk.list_dir("", RUSTFS_META_MULTIPART_BUCKET, "", -1).await {
+            Ok(dirs) => dirs,
+            Err(err) if err == DiskError::FileNotFound || err == DiskError::VolumeNotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let deployment_id = get_global_deployment_id().unwrap_or_default();
+        let mut uploads = Vec::new();
+
+        for sha_dir in sha_dirs {
+            let sha_dir = sha_dir.trim_end_matches(SLASH_SEPARATOR);
+            let upload_uuids = match disk.list_dir("", RUSTFS_META_MULTIPART_BUCKET, sha_dir, -1).await {
+                Ok(ids) => ids,
+                Err(err) => {
+                    debug!("list_all_multipart_uploads: list {} failed: {:?}", sha_dir, err);
+                    continue;
+                }
+            };
+
+            for upload_uuid in upload_uuids {
+                let upload_uuid = upload_uuid.trim_end_matches(SLASH_SEPARATOR);
+                let upload_path = format!("{sha_dir}/{upload_uuid}");
+                let fi = match disk
+                    .read_version("", RUSTFS_META_MULTIPART_BUCKET, &upload_path, "", &ReadOptions::default())
+                    .await
+                {
+                    Ok(fi) => fi,
+                    Err(err) => {
+                        debug!("list_all_multipart_uploads: read {} failed: {:?}", upload_path, err);
+                        continue;
+                    }
+                };
+
+                let (bucket, object) = match (fi.metadata.get(RUSTFS_MULTIPART_BUCKET), fi.metadata.get(RUSTFS_MULTIPART_OBJECT))
+                {
+                    (Some(bucket), Some(object)) => (bucket.clone(), object.clone()),
+                    _ => (String::new(), upload_path.clone()),
+                };
+
+                uploads.push(MultipartInfo {
+                    bucket,
+                    object,
+                    upload_id: base64_simd::URL_SAFE_NO_PAD.encode_to_string(format!("{deployment_id}.{upload_uuid}").as_bytes()),
+                    initiated: fi.mod_time,
+                    user_defined: fi.metadata.clone(),
+                });
+            }
+        }
+
+        uploads.sort_by(|a, b| a.initiated.cmp(&b.initiated));
+
+        Ok(uploads)
+    }
+
     fn get_multipart_sha_dir(bucket: &str, object: &str) -> String {
         let path = format!("{bucket}/{object}");
         let mut hasher = Sha256::new();
@@ -2659,6 +2733,70 @@ impl SetDisks {
         Ok((new_disks, new_infos, healing))
     }
 
+    /// Lock and heal an object, retrying with a deep scan when a normal scan finds bitrot.
+    async fn heal_object_with_retry(
+        &self,
+        bucket: &str,
+        object: &str,
+        version_id: &str,
+        opts: &HealOpts,
+    ) -> Result<(HealResultItem, Option<Error>)> {
+        let _write_lock_guard = if !opts.no_lock {
+            let ns_lock = self.new_ns_lock(bucket, object).await?;
+            Some(ns_lock.get_write_lock(get_lock_acquire_timeout()).await.map_err(|e| {
+                StorageError::other(format!(
+                    "Failed to acquire write lock: {}",
+                    self.format_lock_error_from_error(bucket, object, "write", &e)
+                ))
+            })?)
+        } else {
+            None
+        };
+
+        if has_suffix(object, SLASH_SEPARATOR) {
+            let (result, err) = self.heal_object_dir_locked(bucket, object, opts.dry_run, opts.remove).await?;
+            return Ok((result, err.map(|e| e.into())));
+        }
+
+        let disks = self.disks.read().await;
+
+        let disks = disks.clone();
+        let (_, errs) = Self::read_all_fileinfo(&disks, "", bucket, object, version_id, false, false).await?;
+        if DiskError::is_all_not_found(&errs) {
+            warn!(
+                "heal_object failed, all obj part not found, bucket: {}, obj: {}, version_id: {}",
+                bucket, object, version_id
+            );
+            let err = if !version_id.is_empty() {
+                Error::FileVersionNotFound
+            } else {
+                Error::FileNotFound
+            };
+            return Ok((
+                self.default_heal_result(FileInfo::default(), &errs, bucket, object, version_id)
+                    .await,
+                Some(err),
+            ));
+        }
+
+        // Heal the object.
+        let (result, err) = self.heal_object(bucket, object, version_id, opts).await?;
+        if let Some(err) = err.as_ref() {
+            match err {
+                &DiskError::FileCorrupt if opts.scan_mode != HealScanMode::Deep => {
+                    // Instead of returning an error when a bitrot error is detected
+                    // during a normal heal scan, heal again with bitrot flag enabled.
+                    let mut opts = *opts;
+                    opts.scan_mode = HealScanMode::Deep;
+                    let (result, err) = self.heal_object(bucket, object, version_id, &opts).await?;
+                    return Ok((result, err.map(|e| e.into())));
+                }
+                _ => {}
+            }
+        }
+        Ok((result, err.map(|e| e.into())))
+    }
+
     #[tracing::instrument(skip(self, opts), fields(bucket = %bucket, object = %object, version_id = %version_id))]
     async fn heal_object(
         &self,
@@ -4776,6 +4914,9 @@ impl StorageAPI for SetDisks {
 
         let obj_info = ObjectInfo::from_file_info(&fi, bucket, object, opts.versioned || opts.version_suspended);
 
+        // Checked under the write lock so a concurrent overwrite cannot slip in between
+        opts.precondition_check(&obj_info)?;
+
         for (k, v) in obj_info.user_defined {
             fi.metadata.insert(k, v);
         }
@@ -5656,6 +5797,10 @@ impl StorageAPI for SetDisks {
             );
         }
 
+        // Record the owning bucket/object so uploads can be enumerated without knowing the key.
+        user_defined.insert(RUSTFS_MULTIPART_BUCKET.to_string(), bucket.to_owned());
+        user_defined.insert(RUSTFS_MULTIPART_OBJECT.to_string(), object.to_owned());
+
         let (shuffle_disks, mut parts_metadatas) = Self::shuffle_disks_and_parts_metadata(&disks, &parts_metadata, &fi);
 
         let mod_time = opts.mod_time.unwrap_or(OffsetDateTime::now_utc());
@@ -6005,6 +6150,8 @@ impl StorageAPI for SetDisks {
 
         fi.metadata.remove(rustfs_rio::RUSTFS_MULTIPART_CHECKSUM);
         fi.metadata.remove(rustfs_rio::RUSTFS_MULTIPART_CHECKSUM_TYPE);
+        fi.metadata.remove(RUSTFS_MULTIPART_BUCKET);
+        fi.metadata.remove(RUSTFS_MULTIPART_OBJECT);
 
         fi.size = object_size as i64;
         fi.mod_time = opts.mod_time;
@@ -6174,60 +6321,23 @@ impl StorageAPI for SetDisks {
         version_id: &str,
         opts: &HealOpts,
     ) -> Result<(HealResultItem, Option<Error>)> {
-        let _write_lock_guard = if !opts.no_lock {
-            let ns_lock = self.new_ns_lock(bucket, object).await?;
-            Some(ns_lock.get_write_lock(get_lock_acquire_timeout()).await.map_err(|e| {
-                StorageError::other(format!(
-                    "Failed to acquire write lock: {}",
-                    self.format_lock_error_from_error(bucket, object, "write", &e)
-                ))
-            })?)
-        } else {
-            None
-        };
-
-        if has_suffix(object, SLASH_SEPARATOR) {
-            let (result, err) = self.heal_object_dir_locked(bucket, object, opts.dry_run, opts.remove).await?;
-            return Ok((result, err.map(|e| e.into())));
-        }
-
-        let disks = self.disks.read().await;
-
-        let disks = disks.clone();
-        let (_, errs) = Self::read_all_fileinfo(&disks, "", bucket, object, version_id, false, false).await?;
-        if DiskError::is_all_not_found(&errs) {
-            warn!(
-                "heal_object failed, all obj part not found, bucket: {}, obj: {}, version_id: {}",
-                bucket, object, version_id
-            );
-            let err = if !version_id.is_empty() {
-                Error::FileVersionNotFound
-            } else {
-                Error::FileNotFound
-            };
-            return Ok((
-                self.default_heal_result(FileInfo::default(), &errs, bucket, object, version_id)
-                    .await,
-                Some(err),
-            ));
-        }
-
-        // Heal the object.
-        let (result, err) = self.heal_object(bucket, object, version_id, opts).await?;
-        if let Some(err) = err.as_ref() {
-            match err {
-                &DiskError::FileCorrupt if opts.scan_mode != HealScanMode::Deep => {
-                    // Instead of returning an error when a bitrot error is detected
-                    // during a normal heal scan, heal again with bitrot flag enabled.
-                    let mut opts = *opts;
-                    opts.scan_mode = HealScanMode::Deep;
-                    let (result, err) = self.heal_object(bucket, object, version_id, &opts).await?;
-                    return Ok((result, err.map(|e| e.into())));
+        let start = Instant::now();
+        let result = self.heal_object_with_retry(bucket, object, version_id, opts).await;
+
+        if is_tracing(TraceType::HEALING) {
+            let mut info = TraceInfo::new(TraceType::HEALING, "heal.HealObject", path_join_buf(&[bucket, object]));
+            info.duration = start.elapsed();
+            match &result {
+                Ok((item, err)) => {
+                    info.error = err.as_ref().map(|err| err.to_string());
+                    info.heal_result = Some(item.clone());
                 }
-                _ => {}
+                Err(err) => info.error = Some(err.to_string()),
             }
+            publish_trace(info);
         }
-        Ok((result, err.map(|e| e.into())))
+
+        result
     }
 
     #[tracing::instrument(skip(self))]
@@ -52,7 +52,7 @@ use crate::store_api::{
 use crate::store_init::{check_disk_fatal_errs, ec_drives_no_config};
 use crate::{
     bucket::{lifecycle::bucket_lifecycle_ops::TransitionState, metadata::BucketMetadata},
-    disk::{BUCKET_META_PREFIX, DiskOption, DiskStore, RUSTFS_META_BUCKET, new_disk},
+    disk::{BUCKET_META_PREFIX, DiskOption, DiskStore, RUSTFS_META_BUCKET, RUSTFS_META_MULTIPART_BUCKET, new_disk},
     endpoints::EndpointServerPools,
     rpc::S3PeerSys,
     sets::Sets,
@@ -1015,6 +1015,34 @@ impl ECStore {
         Ok(())
     }
 
+    /// Lists every in-progress multipart upload across all pools, skipping suspended pools.
+    pub async fn list_all_multipart_uploads(&self) -> Result<Vec<MultipartInfo>> {
+        let mut uploads = Vec::new();
+
+        for pool in self.pools.iter() {
+            if self.is_suspended(pool.pool_idx).await {
+                continue;
+            }
+
+            for set in pool.disk_set.iter() {
+                uploads.extend(set.list_all_multipart_uploads().await?);
+            }
+        }
+
+        Ok(uploads)
+    }
+
+    /// Removes a multipart upload by its upload path, for uploads whose bucket and object are unknown.
+    pub async fn delete_multipart_upload_path(&self, upload_path: &str) -> Result<()> {
+        for pool in self.pools.iter() {
+            for set in pool.disk_set.iter() {
+                set.delete_all(RUSTFS_META_MULTIPART_BUCKET, upload_path).await?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Disk information deduplication function
     ///
     /// Use multiple field combinations to ensure uniqueness:
@@ -293,6 +293,7 @@ impl ErasureSetHealer {
                         warn!("Failed to check existence of {}/{}: {}, marking as failed", bucket, object, e);
                         *failed_objects += 1;
                         checkpoint_manager.add_failed_object(object.clone()).await?;
+                        self.record_object(false, *successful_objects, *failed_objects).await;
                         global_obj_idx += 1;
                         *current_object_index = global_obj_idx;
                         continue;
@@ -306,6 +307,7 @@ impl ErasureSetHealer {
                     );
                     checkpoint_manager.add_processed_object(object.clone()).await?;
                     *successful_objects += 1; // Treat as successful - object is gone as intended
+                    self.record_object(true, *successful_objects, *failed_objects).await;
                     global_obj_idx += 1;
                     *current_object_index = global_obj_idx;
                     continue;
@@ -319,23 +321,27 @@ impl ErasureSetHealer {
                     ..Default::default()
                 };
 
-                match self.storage.heal_object(bucket, &object, None, &heal_opts).await {
+                let healed = match self.storage.heal_object(bucket, &object, None, &heal_opts).await {
                     Ok((_result, None)) => {
                         *successful_objects += 1;
                         checkpoint_manager.add_processed_object(object.clone()).await?;
                         info!("Successfully healed object {}/{}", bucket, object);
+                        true
                     }
                     Ok((_, Some(err))) => {
                         *failed_objects += 1;
                         checkpoint_manager.add_failed_object(object.clone()).await?;
                         warn!("Failed to heal object {}/{}: {}", bucket, object, err);
+                        false
                     }
                     Err(err) => {
                         *failed_objects += 1;
                         checkpoint_manager.add_failed_object(object.clone()).await?;
                         warn!("Error healing object {}/{}: {}", bucket, object, err);
+                        false
                     }
-                }
+                };
+                self.record_object(healed, *successful_objects, *failed_objects).await;
 
                 *processed_objects += 1;
                 global_obj_idx += 1;
@@ -376,10 +382,21 @@ impl ErasureSetHealer {
         progress.objects_scanned = state.total_objects;
         progress.objects_healed = state.successful_objects;
         progress.objects_failed = state.failed_objects;
+        // objects healed before a restart count towards the items of the resumed heal
+        progress.items_healed += state.successful_objects;
+        progress.items_failed += state.failed_objects;
         progress.bytes_processed = 0; // set to 0 for now, can be extended later
         progress.set_current_object(state.current_object.clone());
     }
 
+    /// publish the outcome of one object heal to the task progress
+    async fn record_object(&self, healed: bool, successful_objects: u64, failed_objects: u64) {
+        let mut progress = self.progress.write().await;
+        progress.objects_healed = successful_objects;
+        progress.objects_failed = failed_objects;
+        progress.record_item(healed);
+    }
+
     /// heal all buckets concurrently
     #[allow(dead_code)]
     async fn heal_buckets_concurrently(&self, buckets: &[String]) -> Vec<Result<()>> {
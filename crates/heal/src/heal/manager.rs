@@ -238,6 +238,31 @@ pub struct HealState {
     pub active_heal_count: usize,
 }
 
+/// Local drive found unformatted by the auto disk scanner and queued for an erasure set heal
+#[derive(Debug, Clone)]
+pub struct HealingDrive {
+    /// Drive endpoint
+    pub endpoint: String,
+    /// Pool index of the drive
+    pub pool_index: i32,
+    /// Set index of the drive
+    pub set_index: i32,
+    /// Disk index of the drive within its set
+    pub disk_index: i32,
+    /// Erasure set heal that resyncs the drive
+    pub set_disk_id: String,
+    /// When the drive was first detected
+    pub started: SystemTime,
+}
+
+/// Healing drive together with the progress of the heal covering it
+#[derive(Debug, Clone)]
+pub struct HealingDriveStatus {
+    pub drive: HealingDrive,
+    /// Progress of the erasure set heal, `None` while the heal is still queued
+    pub progress: Option<HealProgress>,
+}
+
 /// Heal manager
 pub struct HealManager {
     /// Heal config
@@ -254,6 +279,8 @@ pub struct HealManager {
     cancel_token: CancellationToken,
     /// Statistics
     statistics: Arc<RwLock<HealStatistics>>,
+    /// Local drives being resynced, keyed by endpoint
+    healing_drives: Arc<RwLock<HashMap<String, HealingDrive>>>,
 }
 
 impl HealManager {
@@ -268,6 +295,7 @@ impl HealManager {
             storage,
             cancel_token: CancellationToken::new(),
             statistics: Arc::new(RwLock::new(HealStatistics::new())),
+            healing_drives: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -433,6 +461,32 @@ impl HealManager {
         queue.len()
     }
 
+    /// Get the local drives being healed and the progress of their erasure set heals
+    pub async fn get_healing_drives(&self) -> Vec<HealingDriveStatus> {
+        let drives: Vec<HealingDrive> = self.healing_drives.read().await.values().cloned().collect();
+        let set_tasks: Vec<(String, Arc<HealTask>)> = self
+            .active_heals
+            .lock()
+            .await
+            .values()
+            .filter_map(|task| match &task.heal_type {
+                HealType::ErasureSet { set_disk_id, .. } => Some((set_disk_id.clone(), task.clone())),
+                _ => None,
+            })
+            .collect();
+
+        let mut result = Vec::with_capacity(drives.len());
+        for drive in drives {
+            let progress = match set_tasks.iter().find(|(id, _)| *id == drive.set_disk_id) {
+                Some((_, task)) => Some(task.get_progress().await),
+                None => None,
+            };
+            result.push(HealingDriveStatus { drive, progress });
+        }
+        result.sort_by(|a, b| a.drive.endpoint.cmp(&b.drive.endpoint));
+        result
+    }
+
     /// Start scheduler
     async fn start_scheduler(&self) -> Result<()> {
         let config = self.config.clone();
@@ -441,6 +495,7 @@ impl HealManager {
         let cancel_token = self.cancel_token.clone();
         let statistics = self.statistics.clone();
         let storage = self.storage.clone();
+        let healing_drives = self.healing_drives.clone();
 
         tokio::spawn(async move {
             let mut interval = interval(config.read().await.heal_interval);
@@ -452,7 +507,7 @@ impl HealManager {
                         break;
                     }
                     _ = interval.tick() => {
-                        Self::process_heal_queue(&heal_queue, &active_heals, &config, &statistics, &storage).await;
+                        Self::process_heal_queue(&heal_queue, &active_heals, &config, &statistics, &storage, &healing_drives).await;
                     }
                 }
             }
@@ -468,6 +523,7 @@ impl HealManager {
         let active_heals = self.active_heals.clone();
         let cancel_token = self.cancel_token.clone();
         let storage = self.storage.clone();
+        let healing_drives = self.healing_drives.clone();
         let mut duration = {
             let config = config.read().await;
             config.heal_interval
@@ -530,6 +586,18 @@ impl HealManager {
                                 warn!("start_auto_disk_scanner: Skipping endpoint {} without valid pool/set index", ep);
                                 continue;
                             };
+                            healing_drives
+                                .write()
+                                .await
+                                .entry(ep.to_string())
+                                .or_insert_with(|| HealingDrive {
+                                    endpoint: ep.to_string(),
+                                    pool_index: ep.pool_idx,
+                                    set_index: ep.set_idx,
+                                    disk_index: ep.disk_idx,
+                                    set_disk_id: set_disk_id.clone(),
+                                    started: SystemTime::now(),
+                                });
                             // skip if already queued or healing
                             // Use consistent lock order: queue first, then active_heals to avoid deadlock
                             let mut skip = false;
@@ -585,6 +653,7 @@ impl HealManager {
         config: &Arc<RwLock<HealConfig>>,
         statistics: &Arc<RwLock<HealStatistics>>,
         storage: &Arc<dyn HealStorageAPI>,
+        healing_drives: &Arc<RwLock<HashMap<String, HealingDrive>>>,
     ) {
         let config = config.read().await;
         let mut active_heals_guard = active_heals.lock().await;
@@ -623,6 +692,7 @@ impl HealManager {
                 active_heals_guard.insert(task_id.clone(), task.clone());
                 let active_heals_clone = active_heals.clone();
                 let statistics_clone = statistics.clone();
+                let healing_drives_clone = healing_drives.clone();
 
                 // start heal task
                 tokio::spawn(async move {
@@ -649,6 +719,14 @@ impl HealManager {
                             }
                         }
                         stats.update_running_tasks(active_heals_guard.len() as u64);
+
+                        // The scanner re-detects drives that are still unformatted
+                        if let HealType::ErasureSet { set_disk_id, .. } = &completed_task.heal_type {
+                            healing_drives_clone
+                                .write()
+                                .await
+                                .retain(|_, drive| &drive.set_disk_id != set_disk_id);
+                        }
                     }
                 });
             } else {
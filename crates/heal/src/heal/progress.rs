@@ -23,6 +23,10 @@ pub struct HealProgress {
     pub objects_healed: u64,
     /// Objects failed
     pub objects_failed: u64,
+    /// Buckets and objects healed
+    pub items_healed: u64,
+    /// Buckets and objects that failed to heal
+    pub items_failed: u64,
     /// Bytes processed
     pub bytes_processed: u64,
     /// Current object
@@ -60,6 +64,16 @@ impl HealProgress {
         }
     }
 
+    /// Count one bucket or object whose heal finished
+    pub fn record_item(&mut self, healed: bool) {
+        if healed {
+            self.items_healed += 1;
+        } else {
+            self.items_failed += 1;
+        }
+        self.last_update_time = Some(SystemTime::now());
+    }
+
     pub fn set_current_object(&mut self, object: Option<String>) {
         self.current_object = object;
         self.last_update_time = Some(SystemTime::now());
@@ -70,6 +84,22 @@ impl HealProgress {
             || self.objects_scanned > 0 && self.objects_healed + self.objects_failed >= self.objects_scanned
     }
 
+    /// Estimate when the heal will finish from the rate observed so far
+    pub fn estimate_completion(&self) -> Option<SystemTime> {
+        if self.estimated_completion_time.is_some() {
+            return self.estimated_completion_time;
+        }
+        let done = self.objects_healed + self.objects_failed;
+        if done == 0 || self.objects_scanned <= done {
+            return None;
+        }
+        let start = self.start_time?;
+        let now = self.last_update_time.unwrap_or_else(SystemTime::now);
+        let elapsed = now.duration_since(start).ok()?;
+        let remaining = self.objects_scanned - done;
+        Some(now + elapsed.mul_f64(remaining as f64 / done as f64))
+    }
+
     pub fn get_success_rate(&self) -> f64 {
         let total = self.objects_healed + self.objects_failed;
         if total > 0 {
@@ -150,6 +180,7 @@ impl HealStatistics {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_heal_progress_new() {
@@ -180,6 +211,37 @@ mod tests {
         assert!(progress.last_update_time.is_some());
     }
 
+    #[test]
+    fn test_heal_progress_estimate_completion() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let progress = HealProgress {
+            objects_scanned: 100,
+            objects_healed: 20,
+            objects_failed: 5,
+            start_time: Some(start),
+            last_update_time: Some(start + Duration::from_secs(25)),
+            ..Default::default()
+        };
+
+        // 25 objects took 25s, the remaining 75 should take 75s more
+        assert_eq!(progress.estimate_completion(), Some(start + Duration::from_secs(100)));
+        assert_eq!(HealProgress::new().estimate_completion(), None);
+    }
+
+    #[test]
+    fn test_heal_progress_record_item() {
+        let mut progress = HealProgress::new();
+        progress.update_progress(3, 4, 0, 0);
+        progress.record_item(true);
+        progress.record_item(true);
+        progress.record_item(false);
+
+        // items count finished buckets and objects, independent of the step counters above
+        assert_eq!(progress.items_healed, 2);
+        assert_eq!(progress.items_failed, 1);
+        assert_eq!(progress.objects_healed, 4);
+    }
+
     #[test]
     fn test_heal_progress_update_progress_zero_total() {
         let mut progress = HealProgress::new();
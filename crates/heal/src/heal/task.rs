@@ -943,13 +943,15 @@ impl HealTask {
             // Check control flags before starting each bucket heal
             self.check_control_flags().await?;
             // heal_bucket internally uses await_with_control for timeout/cancellation handling
-            if let Err(err) = self.heal_bucket(bucket).await {
+            let result = self.heal_bucket(bucket).await;
+            if let Err(err) = &result {
                 // Check if error is due to cancellation or timeout
                 if matches!(err, Error::TaskCancelled | Error::TaskTimeout) {
-                    return Err(err);
+                    return result;
                 }
                 info!("Bucket heal failed: {}", err.to_string());
             }
+            self.progress.write().await.record_item(result.is_ok());
         }
 
         // Step 3: Create erasure set healer with resume support
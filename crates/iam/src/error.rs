@@ -112,6 +112,9 @@ pub enum Error {
 
     #[error("system already initialized")]
     IamSysAlreadyInitialized,
+
+    #[error("openid: {0}")]
+    OpenIdError(String),
 }
 
 impl PartialEq for Error {
@@ -166,6 +169,7 @@ impl Clone for Error {
             Error::ConfigNotFound => Error::ConfigNotFound,
             Error::Io(e) => Error::Io(std::io::Error::new(e.kind(), e.to_string())),
             Error::IamSysAlreadyInitialized => Error::IamSysAlreadyInitialized,
+            Error::OpenIdError(s) => Error::OpenIdError(s.clone()),
         }
     }
 }
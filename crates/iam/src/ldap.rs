@@ -0,0 +1,389 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! LDAP / Active Directory identity provider for STS LDAP identity.
+
+use crate::error::{Error, Result};
+use ldap3::{Ldap, LdapConnAsync, LdapConnSettings, Scope, SearchEntry, ldap_escape};
+use rustfs_config::ldap::*;
+use std::collections::HashMap;
+use std::env;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+/// Claim holding the DN of the LDAP user on issued credentials
+pub const LDAP_USER_CLAIM: &str = "ldapUser";
+
+/// Claim holding the login name of the LDAP user on issued credentials
+pub const LDAP_USERNAME_CLAIM: &str = "ldapUsername";
+
+/// How the connection to the LDAP server is secured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LdapTransport {
+    /// ldaps://
+    Tls,
+    /// ldap:// upgraded with StartTLS
+    StartTls,
+    /// ldap:// without TLS, for testing only
+    Insecure,
+}
+
+/// LDAP identity provider configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdapConfig {
+    /// Server address (`host` or `host:port`)
+    pub server_addr: String,
+    pub transport: LdapTransport,
+    pub tls_skip_verify: bool,
+    /// Service account used to look up users and groups
+    pub lookup_bind_dn: String,
+    pub lookup_bind_password: String,
+    /// Base DN for user lookups
+    pub user_dn_search_base_dn: String,
+    /// User lookup filter; `%s` is replaced by the login name, e.g. `(sAMAccountName=%s)`
+    pub user_dn_search_filter: String,
+    /// Base DN for group lookups (group lookup is disabled when empty)
+    pub group_search_base_dn: String,
+    /// Group lookup filter; `%s` is replaced by the login name and `%d` by the user DN,
+    /// e.g. `(&(objectclass=group)(member=%d))`
+    pub group_search_filter: String,
+}
+
+impl LdapConfig {
+    /// Server URL derived from the address and transport
+    pub fn url(&self) -> String {
+        let (scheme, default_port) = match self.transport {
+            LdapTransport::Tls => ("ldaps", DEFAULT_IDENTITY_LDAP_TLS_PORT),
+            LdapTransport::StartTls | LdapTransport::Insecure => ("ldap", DEFAULT_IDENTITY_LDAP_PORT),
+        };
+        if self
+            .server_addr
+            .rsplit_once(':')
+            .is_some_and(|(_, port)| port.parse::<u16>().is_ok())
+        {
+            format!("{scheme}://{}", self.server_addr)
+        } else {
+            format!("{scheme}://{}:{default_port}", self.server_addr)
+        }
+    }
+
+    fn user_filter(&self, username: &str) -> String {
+        self.user_dn_search_filter.replace("%s", &ldap_escape(username))
+    }
+
+    fn group_filter(&self, username: &str, user_dn: &str) -> String {
+        self.group_search_filter
+            .replace("%s", &ldap_escape(username))
+            .replace("%d", &ldap_escape(user_dn))
+    }
+}
+
+/// Read the LDAP configuration from the environment; `None` when LDAP is not configured
+pub fn lookup_config() -> Result<Option<LdapConfig>> {
+    lookup_config_from(env::vars().collect())
+}
+
+fn lookup_config_from(vars: HashMap<String, String>) -> Result<Option<LdapConfig>> {
+    let get = |cfg: &str| vars.get(cfg).map(|v| v.trim().to_string()).unwrap_or_default();
+    let get_bool = |cfg: &str| matches!(get(cfg).to_lowercase().as_str(), "on" | "true" | "1" | "yes");
+
+    let server_addr = get(ENV_IDENTITY_LDAP_SERVER_ADDR);
+    if server_addr.is_empty() {
+        return Ok(None);
+    }
+
+    let transport = match (get_bool(ENV_IDENTITY_LDAP_SERVER_INSECURE), get_bool(ENV_IDENTITY_LDAP_SERVER_STARTTLS)) {
+        (true, true) => {
+            return Err(Error::other(format!(
+                "{ENV_IDENTITY_LDAP_SERVER_INSECURE} and {ENV_IDENTITY_LDAP_SERVER_STARTTLS} cannot both be enabled"
+            )));
+        }
+        (true, false) => LdapTransport::Insecure,
+        (false, true) => LdapTransport::StartTls,
+        (false, false) => LdapTransport::Tls,
+    };
+
+    let config = LdapConfig {
+        server_addr,
+        transport,
+        tls_skip_verify: get_bool(ENV_IDENTITY_LDAP_TLS_SKIP_VERIFY),
+        lookup_bind_dn: get(ENV_IDENTITY_LDAP_LOOKUP_BIND_DN),
+        lookup_bind_password: vars.get(ENV_IDENTITY_LDAP_LOOKUP_BIND_PASSWORD).cloned().unwrap_or_default(),
+        user_dn_search_base_dn: get(ENV_IDENTITY_LDAP_USER_DN_SEARCH_BASE_DN),
+        user_dn_search_filter: get(ENV_IDENTITY_LDAP_USER_DN_SEARCH_FILTER),
+        group_search_base_dn: get(ENV_IDENTITY_LDAP_GROUP_SEARCH_BASE_DN),
+        group_search_filter: get(ENV_IDENTITY_LDAP_GROUP_SEARCH_FILTER),
+    };
+
+    for (name, value) in [
+        (ENV_IDENTITY_LDAP_LOOKUP_BIND_DN, &config.lookup_bind_dn),
+        (ENV_IDENTITY_LDAP_USER_DN_SEARCH_BASE_DN, &config.user_dn_search_base_dn),
+        (ENV_IDENTITY_LDAP_USER_DN_SEARCH_FILTER, &config.user_dn_search_filter),
+    ] {
+        if value.is_empty() {
+            return Err(Error::other(format!("{name} is required when LDAP is enabled")));
+        }
+    }
+    if !config.user_dn_search_filter.contains("%s") {
+        return Err(Error::other(format!("{ENV_IDENTITY_LDAP_USER_DN_SEARCH_FILTER} must contain %s")));
+    }
+    if config.group_search_base_dn.is_empty() != config.group_search_filter.is_empty() {
+        return Err(Error::other(format!(
+            "{ENV_IDENTITY_LDAP_GROUP_SEARCH_BASE_DN} and {ENV_IDENTITY_LDAP_GROUP_SEARCH_FILTER} must be set together"
+        )));
+    }
+
+    Ok(Some(config))
+}
+
+/// Normalize a DN so mappings match regardless of case and spacing
+pub fn normalize_dn(dn: &str) -> String {
+    dn.split(',')
+        .map(|rdn| match rdn.split_once('=') {
+            Some((attr, value)) => format!("{}={}", attr.trim(), value.trim()),
+            None => rdn.trim().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+        .to_lowercase()
+}
+
+/// Whether a policy mapping name is an LDAP DN rather than an IAM user or group
+pub fn is_dn(name: &str) -> bool {
+    name.contains('=')
+}
+
+/// Identity established by an LDAP bind
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdapIdentity {
+    /// Login name
+    pub username: String,
+    /// Normalized user DN
+    pub user_dn: String,
+    /// Normalized DNs of the groups the user belongs to
+    pub groups: Vec<String>,
+}
+
+fn ldap_err(op: &str) -> impl Fn(ldap3::LdapError) -> Error + '_ {
+    move |e| Error::other(format!("ldap {op} failed: {e}"))
+}
+
+/// LDAP identity provider
+#[derive(Default)]
+pub struct LdapSys {
+    config: Option<LdapConfig>,
+}
+
+impl LdapSys {
+    pub fn new(config: Option<LdapConfig>) -> Self {
+        Self { config }
+    }
+
+    /// Load the configuration from the environment; a broken configuration disables LDAP
+    pub fn from_env() -> Self {
+        match lookup_config() {
+            Ok(config) => {
+                if let Some(config) = &config {
+                    info!("LDAP identity enabled with server {}", config.url());
+                }
+                Self::new(config)
+            }
+            Err(e) => {
+                error!("Error loading LDAP configuration err:{}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.is_some()
+    }
+
+    async fn connect(config: &LdapConfig) -> Result<Ldap> {
+        let settings = LdapConnSettings::new()
+            .set_conn_timeout(Duration::from_secs(DEFAULT_IDENTITY_LDAP_CONNECT_TIMEOUT))
+            .set_starttls(config.transport == LdapTransport::StartTls)
+            .set_no_tls_verify(config.tls_skip_verify);
+
+        let (conn, ldap) = LdapConnAsync::with_settings(settings, &config.url())
+            .await
+            .map_err(ldap_err("connect"))?;
+        tokio::spawn(async move {
+            if let Err(e) = conn.drive().await {
+                warn!("ldap connection error: {}", e);
+            }
+        });
+
+        Ok(ldap)
+    }
+
+    async fn lookup_bind(ldap: &mut Ldap, config: &LdapConfig) -> Result<()> {
+        ldap.simple_bind(&config.lookup_bind_dn, &config.lookup_bind_password)
+            .await
+            .and_then(|r| r.success())
+            .map_err(ldap_err("lookup bind"))?;
+        Ok(())
+    }
+
+    async fn search_dns(ldap: &mut Ldap, base: &str, filter: &str) -> Result<Vec<String>> {
+        let (entries, _) = ldap
+            .search(base, Scope::Subtree, filter, vec!["dn"])
+            .await
+            .and_then(|r| r.success())
+            .map_err(ldap_err("search"))?;
+        Ok(entries.into_iter().map(|e| SearchEntry::construct(e).dn).collect())
+    }
+
+    /// Verify the user's password and resolve their DN and groups
+    pub async fn authenticate(&self, username: &str, password: &str) -> Result<LdapIdentity> {
+        let Some(config) = &self.config else {
+            return Err(Error::other("LDAP is not configured"));
+        };
+        // An empty password would turn the bind into an unauthenticated bind that always succeeds
+        if username.is_empty() || password.is_empty() {
+            return Err(Error::InvalidArgument);
+        }
+
+        let mut ldap = Self::connect(config).await?;
+        let result = async {
+            Self::lookup_bind(&mut ldap, config).await?;
+
+            let user_dns = Self::search_dns(&mut ldap, &config.user_dn_search_base_dn, &config.user_filter(username)).await?;
+            let user_dn = match user_dns.as_slice() {
+                [dn] => dn.clone(),
+                [] => return Err(Error::NoSuchUser(username.to_string())),
+                _ => return Err(Error::other(format!("ldap user lookup for {username} matched multiple entries"))),
+            };
+
+            ldap.simple_bind(&user_dn, password)
+                .await
+                .and_then(|r| r.success())
+                .map_err(|_| Error::InvalidToken)?;
+
+            let mut groups = Vec::new();
+            if !config.group_search_base_dn.is_empty() {
+                Self::lookup_bind(&mut ldap, config).await?;
+                groups = Self::search_dns(&mut ldap, &config.group_search_base_dn, &config.group_filter(username, &user_dn))
+                    .await?
+                    .iter()
+                    .map(|dn| normalize_dn(dn))
+                    .collect();
+                groups.sort();
+                groups.dedup();
+            }
+
+            Ok(LdapIdentity {
+                username: username.to_string(),
+                user_dn: normalize_dn(&user_dn),
+                groups,
+            })
+        }
+        .await;
+
+        let _ = ldap.unbind().await;
+        result
+    }
+
+    /// Check that a DN exists in the directory, for validating policy mappings
+    pub async fn lookup_dn(&self, dn: &str) -> Result<bool> {
+        let Some(config) = &self.config else {
+            return Err(Error::other("LDAP is not configured"));
+        };
+
+        let mut ldap = Self::connect(config).await?;
+        let result = async {
+            Self::lookup_bind(&mut ldap, config).await?;
+            match ldap.search(dn, Scope::Base, "(objectClass=*)", vec!["dn"]).await {
+                Ok(r) => match r.success() {
+                    Ok((entries, _)) => Ok(!entries.is_empty()),
+                    // noSuchObject
+                    Err(ldap3::LdapError::LdapResult { result }) if result.rc == 32 => Ok(false),
+                    Err(e) => Err(ldap_err("search")(e)),
+                },
+                Err(e) => Err(ldap_err("search")(e)),
+            }
+        }
+        .await;
+
+        let _ = ldap.unbind().await;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    fn base_vars() -> Vec<(&'static str, &'static str)> {
+        vec![
+            (ENV_IDENTITY_LDAP_SERVER_ADDR, "ad.example.com"),
+            (ENV_IDENTITY_LDAP_LOOKUP_BIND_DN, "cn=svc-rustfs,ou=services,dc=example,dc=com"),
+            (ENV_IDENTITY_LDAP_LOOKUP_BIND_PASSWORD, "secret"),
+            (ENV_IDENTITY_LDAP_USER_DN_SEARCH_BASE_DN, "ou=people,dc=example,dc=com"),
+            (ENV_IDENTITY_LDAP_USER_DN_SEARCH_FILTER, "(sAMAccountName=%s)"),
+        ]
+    }
+
+    #[test]
+    fn test_lookup_config() {
+        assert!(lookup_config_from(HashMap::new()).unwrap().is_none());
+
+        let config = lookup_config_from(vars(&base_vars())).unwrap().unwrap();
+        assert_eq!(config.transport, LdapTransport::Tls);
+        assert_eq!(config.url(), "ldaps://ad.example.com:636");
+
+        let mut pairs = base_vars();
+        pairs.push((ENV_IDENTITY_LDAP_SERVER_STARTTLS, "on"));
+        pairs[0] = (ENV_IDENTITY_LDAP_SERVER_ADDR, "ad.example.com:3268");
+        let config = lookup_config_from(vars(&pairs)).unwrap().unwrap();
+        assert_eq!(config.transport, LdapTransport::StartTls);
+        assert_eq!(config.url(), "ldap://ad.example.com:3268");
+
+        let mut pairs = base_vars();
+        pairs.push((ENV_IDENTITY_LDAP_GROUP_SEARCH_BASE_DN, "ou=groups,dc=example,dc=com"));
+        assert!(lookup_config_from(vars(&pairs)).is_err());
+
+        let mut pairs = base_vars();
+        pairs.retain(|(k, _)| *k != ENV_IDENTITY_LDAP_LOOKUP_BIND_DN);
+        assert!(lookup_config_from(vars(&pairs)).is_err());
+    }
+
+    #[test]
+    fn test_filters_escape_input() {
+        let mut pairs = base_vars();
+        pairs.push((ENV_IDENTITY_LDAP_GROUP_SEARCH_BASE_DN, "ou=groups,dc=example,dc=com"));
+        pairs.push((ENV_IDENTITY_LDAP_GROUP_SEARCH_FILTER, "(&(objectclass=group)(member=%d))"));
+        let config = lookup_config_from(vars(&pairs)).unwrap().unwrap();
+
+        assert_eq!(config.user_filter("alice*)(cn=*"), "(sAMAccountName=alice\\2a\\29\\28cn=\\2a)");
+        assert_eq!(
+            config.group_filter("alice", "cn=Alice (Ops),ou=people,dc=example,dc=com"),
+            "(&(objectclass=group)(member=cn=Alice \\28Ops\\29,ou=people,dc=example,dc=com))"
+        );
+    }
+
+    #[test]
+    fn test_normalize_dn() {
+        assert_eq!(
+            normalize_dn("CN=Data Science, OU=Groups ,DC=Example,DC=com"),
+            "cn=data science,ou=groups,dc=example,dc=com"
+        );
+        assert!(is_dn("cn=data,dc=example,dc=com"));
+        assert!(!is_dn("readers"));
+    }
+}
@@ -22,7 +22,9 @@ use tracing::{error, info, instrument};
 
 pub mod cache;
 pub mod error;
+pub mod ldap;
 pub mod manager;
+pub mod oidc;
 pub mod store;
 pub mod sys;
 pub mod utils;
@@ -756,6 +756,55 @@ where
         Ok(policies)
     }
 
+    /// Policies mapped to an LDAP user DN and the DNs of its groups.
+    ///
+    /// LDAP identities have no IAM user or group records, so the mappings are read directly:
+    /// user DNs are stored as STS mappings and group DNs as group mappings.
+    pub async fn policy_db_get_ldap(&self, user_dn: &str, groups: &Option<Vec<String>>) -> Result<Vec<String>> {
+        if user_dn.is_empty() {
+            return Err(Error::InvalidArgument);
+        }
+
+        let mut policies = self.ldap_mapped_policy(user_dn, false).await?.to_slice();
+        if let Some(groups) = groups {
+            for group in groups.iter() {
+                policies.extend(self.ldap_mapped_policy(group, true).await?.to_slice());
+            }
+        }
+
+        let mut seen = HashSet::new();
+        policies.retain(|p| seen.insert(p.clone()));
+        Ok(policies)
+    }
+
+    /// Policy mapping of a single LDAP user DN or group DN
+    pub async fn ldap_mapped_policy(&self, name: &str, is_group: bool) -> Result<MappedPolicy> {
+        let (cache, user_type) = if is_group {
+            (&self.cache.group_policies, UserType::Reg)
+        } else {
+            (&self.cache.sts_policies, UserType::Sts)
+        };
+
+        if let Some(p) = cache.load().get(name) {
+            return Ok(p.clone());
+        }
+
+        let mut m = HashMap::new();
+        if let Err(err) = self.api.load_mapped_policy(name, user_type, is_group, &mut m).await
+            && !is_err_no_such_policy(&err)
+        {
+            return Err(err);
+        }
+
+        match m.get(name) {
+            Some(p) => {
+                Cache::add_or_update(cache, name, p, OffsetDateTime::now_utc());
+                Ok(p.clone())
+            }
+            None => Ok(MappedPolicy::default()),
+        }
+    }
+
     async fn policy_db_get_internal(
         &self,
         name: &str,
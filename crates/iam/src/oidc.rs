@@ -0,0 +1,498 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! OpenID Connect identity providers for STS web identity and client grants.
+
+use crate::error::{Error, Result};
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header};
+use rustfs_config::oidc::*;
+use rustfs_policy::arn::ARN;
+use rustfs_policy::policy::get_policies_from_claims;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::env;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+
+/// Prefix of the parent user recorded on credentials issued for an OpenID identity
+pub const OPENID_PARENT_PREFIX: &str = "openid:";
+
+/// Minimum time between JWKS refreshes triggered by an unknown key id
+const JWKS_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Configuration of one OpenID provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenIdProviderConfig {
+    /// Provider name, taken from the env var suffix ("default" without one)
+    pub name: String,
+    /// Discovery document URL (`.../.well-known/openid-configuration`)
+    pub config_url: String,
+    /// Accepted audiences
+    pub client_ids: Vec<String>,
+    /// Claim holding the policies of the identity
+    pub claim_name: String,
+    /// Prefix applied to the policy claim name
+    pub claim_prefix: String,
+    /// Policies granted to every identity of the provider, exposed as a role ARN
+    pub role_policy: Option<String>,
+    /// How long fetched signing keys are trusted before being refreshed
+    pub jwks_refresh_interval: Duration,
+}
+
+impl OpenIdProviderConfig {
+    /// Name of the claim policies are read from
+    pub fn policy_claim(&self) -> String {
+        format!("{}{}", self.claim_prefix, self.claim_name)
+    }
+
+    /// Role ARN of a provider configured with a role policy
+    pub fn role_arn(&self, region: &str) -> Option<ARN> {
+        self.role_policy.as_ref()?;
+        ARN::new_iam_role_arn(&format!("openid-{}", self.name), region).ok()
+    }
+}
+
+/// Read the OpenID provider configuration from the environment
+pub fn lookup_config() -> Result<Vec<OpenIdProviderConfig>> {
+    lookup_config_from(env::vars().collect())
+}
+
+fn lookup_config_from(vars: HashMap<String, String>) -> Result<Vec<OpenIdProviderConfig>> {
+    let mut providers = Vec::new();
+
+    for (key, config_url) in vars.iter() {
+        let Some(suffix) = key.strip_prefix(ENV_IDENTITY_OPENID_CONFIG_URL) else {
+            continue;
+        };
+        let name = match suffix {
+            "" => "default".to_string(),
+            s if s.len() > 1 && s.starts_with('_') => s[1..].to_lowercase(),
+            _ => continue,
+        };
+        if config_url.is_empty() {
+            continue;
+        }
+
+        let get = |cfg: &str| {
+            vars.get(&format!("{cfg}{suffix}"))
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+        };
+
+        let client_ids: Vec<String> = get(ENV_IDENTITY_OPENID_CLIENT_ID)
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+            .collect();
+        if client_ids.is_empty() {
+            return Err(Error::OpenIdError(format!(
+                "{ENV_IDENTITY_OPENID_CLIENT_ID}{suffix} is required for openid provider {name}"
+            )));
+        }
+
+        let jwks_refresh_interval =
+            match get(ENV_IDENTITY_OPENID_JWKS_REFRESH_INTERVAL) {
+                Some(v) => Duration::from_secs(v.parse::<u64>().map_err(|_| {
+                    Error::OpenIdError(format!("invalid {ENV_IDENTITY_OPENID_JWKS_REFRESH_INTERVAL}{suffix}: {v}"))
+                })?),
+                None => Duration::from_secs(DEFAULT_IDENTITY_OPENID_JWKS_REFRESH_INTERVAL),
+            };
+
+        let role_policy = get(ENV_IDENTITY_OPENID_ROLE_POLICY).map(str::to_string);
+        let claim_name = get(ENV_IDENTITY_OPENID_CLAIM_NAME).unwrap_or(DEFAULT_IDENTITY_OPENID_CLAIM_NAME);
+        if role_policy.is_some() && get(ENV_IDENTITY_OPENID_CLAIM_NAME).is_some() {
+            return Err(Error::OpenIdError(format!(
+                "openid provider {name} cannot use both a role policy and a policy claim"
+            )));
+        }
+
+        providers.push(OpenIdProviderConfig {
+            name,
+            config_url: config_url.clone(),
+            client_ids,
+            claim_name: claim_name.to_string(),
+            claim_prefix: get(ENV_IDENTITY_OPENID_CLAIM_PREFIX).unwrap_or_default().to_string(),
+            role_policy,
+            jwks_refresh_interval,
+        });
+    }
+
+    providers.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(providers)
+}
+
+/// Identity established from a validated token
+#[derive(Debug, Clone)]
+pub struct OpenIdIdentity {
+    /// Provider that issued the token
+    pub provider: String,
+    /// Token issuer
+    pub issuer: String,
+    /// Token subject
+    pub subject: String,
+    /// Audience the token was accepted for
+    pub audience: String,
+    /// Token expiry (unix seconds)
+    pub expires_at: Option<i64>,
+    /// Policies from the policy claim; empty when a role ARN applies
+    pub policies: Vec<String>,
+    /// Role ARN of the provider when it grants a role policy
+    pub role_arn: Option<String>,
+    /// All claims of the token
+    pub claims: HashMap<String, Value>,
+}
+
+impl OpenIdIdentity {
+    /// Parent user of temporary credentials issued to this identity
+    pub fn parent_user(&self) -> String {
+        format!("{OPENID_PARENT_PREFIX}{}:{}", self.provider, self.subject)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DiscoveryDocument {
+    issuer: String,
+    jwks_uri: String,
+}
+
+struct ProviderKeys {
+    issuer: String,
+    jwks: JwkSet,
+    fetched_at: Instant,
+}
+
+fn find_jwk<'a>(jwks: &'a JwkSet, kid: Option<&str>) -> Option<&'a Jwk> {
+    match kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+}
+
+/// Whether the token audience or authorized party is one of the client ids
+fn audience_matches(claims: &HashMap<String, Value>, client_ids: &[String]) -> Option<String> {
+    let mut candidates: Vec<&str> = match claims.get("aud") {
+        Some(Value::String(aud)) => vec![aud.as_str()],
+        Some(Value::Array(auds)) => auds.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if let Some(azp) = claims.get("azp").and_then(Value::as_str) {
+        candidates.push(azp);
+    }
+    candidates
+        .into_iter()
+        .find(|aud| client_ids.iter().any(|id| id.as_str() == *aud))
+        .map(str::to_string)
+}
+
+/// An OpenID provider with its cached signing keys
+pub struct OpenIdProvider {
+    config: OpenIdProviderConfig,
+    role_arn: Option<ARN>,
+    client: reqwest::Client,
+    keys: RwLock<Option<ProviderKeys>>,
+}
+
+impl OpenIdProvider {
+    pub fn new(config: OpenIdProviderConfig, region: &str) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            role_arn: config.role_arn(region),
+            config,
+            client,
+            keys: RwLock::new(None),
+        }
+    }
+
+    pub fn config(&self) -> &OpenIdProviderConfig {
+        &self.config
+    }
+
+    async fn fetch_keys(&self) -> Result<ProviderKeys> {
+        let fetch_err = |e: reqwest::Error| Error::OpenIdError(format!("provider {}: {e}", self.config.name));
+
+        let discovery: DiscoveryDocument = self
+            .client
+            .get(&self.config.config_url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(fetch_err)?
+            .json()
+            .await
+            .map_err(fetch_err)?;
+
+        let jwks: JwkSet = self
+            .client
+            .get(&discovery.jwks_uri)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(fetch_err)?
+            .json()
+            .await
+            .map_err(fetch_err)?;
+
+        info!("openid provider {}: loaded {} signing keys", self.config.name, jwks.keys.len());
+        Ok(ProviderKeys {
+            issuer: discovery.issuer,
+            jwks,
+            fetched_at: Instant::now(),
+        })
+    }
+
+    /// Issuer and decoding key for a key id, refreshing the JWKS when it is stale or the key was rotated
+    async fn decoding_key(&self, kid: Option<&str>) -> Result<(String, DecodingKey)> {
+        {
+            let keys = self.keys.read().await;
+            if let Some(k) = keys.as_ref()
+                && k.fetched_at.elapsed() < self.config.jwks_refresh_interval
+                && let Some(jwk) = find_jwk(&k.jwks, kid)
+            {
+                return Ok((k.issuer.clone(), DecodingKey::from_jwk(jwk).map_err(Error::JWTError)?));
+            }
+        }
+
+        let mut keys = self.keys.write().await;
+        let refresh = match keys.as_ref() {
+            None => true,
+            Some(k) => {
+                k.fetched_at.elapsed() >= self.config.jwks_refresh_interval
+                    || (find_jwk(&k.jwks, kid).is_none() && k.fetched_at.elapsed() >= JWKS_MIN_REFRESH_INTERVAL)
+            }
+        };
+        if refresh {
+            match self.fetch_keys().await {
+                Ok(k) => *keys = Some(k),
+                Err(e) if keys.is_some() => warn!("{e}, keeping previously fetched signing keys"),
+                Err(e) => return Err(e),
+            }
+        }
+
+        let Some(k) = keys.as_ref() else {
+            return Err(Error::OpenIdError(format!("provider {}: no signing keys", self.config.name)));
+        };
+        let jwk = find_jwk(&k.jwks, kid)
+            .ok_or_else(|| Error::OpenIdError(format!("provider {}: unknown signing key {kid:?}", self.config.name)))?;
+        Ok((k.issuer.clone(), DecodingKey::from_jwk(jwk).map_err(Error::JWTError)?))
+    }
+
+    /// Verify the token signature, issuer, expiry and audience
+    pub async fn validate(&self, token: &str) -> Result<OpenIdIdentity> {
+        let header = decode_header(token).map_err(Error::JWTError)?;
+        if !matches!(
+            header.alg,
+            Algorithm::RS256
+                | Algorithm::RS384
+                | Algorithm::RS512
+                | Algorithm::PS256
+                | Algorithm::PS384
+                | Algorithm::PS512
+                | Algorithm::ES256
+                | Algorithm::ES384
+                | Algorithm::EdDSA
+        ) {
+            return Err(Error::OpenIdError(format!("unsupported token algorithm {:?}", header.alg)));
+        }
+
+        let (issuer, key) = self.decoding_key(header.kid.as_deref()).await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&issuer]);
+        validation.set_required_spec_claims(&["exp", "iss", "sub"]);
+        // Keycloak access tokens carry the client id in azp, so the audience is checked below
+        validation.validate_aud = false;
+
+        let claims = decode::<HashMap<String, Value>>(token, &key, &validation)
+            .map_err(Error::JWTError)?
+            .claims;
+
+        let audience = audience_matches(&claims, &self.config.client_ids)
+            .ok_or_else(|| Error::OpenIdError(format!("token audience is not accepted by provider {}", self.config.name)))?;
+
+        let (policies, role_arn) = match &self.role_arn {
+            Some(arn) => (Vec::new(), Some(arn.to_string())),
+            None => {
+                let (policies, _) = get_policies_from_claims(&claims, &self.config.policy_claim());
+                if policies.is_empty() {
+                    return Err(Error::OpenIdError(format!(
+                        "token has no policies in claim {}",
+                        self.config.policy_claim()
+                    )));
+                }
+                let mut policies: Vec<String> = policies.into_iter().collect();
+                policies.sort();
+                (policies, None)
+            }
+        };
+
+        Ok(OpenIdIdentity {
+            provider: self.config.name.clone(),
+            issuer,
+            subject: claims.get("sub").and_then(Value::as_str).unwrap_or_default().to_string(),
+            audience,
+            expires_at: claims.get("exp").and_then(Value::as_i64),
+            policies,
+            role_arn,
+            claims,
+        })
+    }
+}
+
+/// Configured OpenID providers
+#[derive(Default)]
+pub struct OpenIdSys {
+    providers: Vec<OpenIdProvider>,
+}
+
+impl OpenIdSys {
+    pub fn new(configs: Vec<OpenIdProviderConfig>, region: &str) -> Self {
+        Self {
+            providers: configs.into_iter().map(|c| OpenIdProvider::new(c, region)).collect(),
+        }
+    }
+
+    /// Load providers from the environment; a broken configuration disables OpenID
+    pub fn from_env(region: &str) -> Self {
+        match lookup_config() {
+            Ok(configs) => {
+                if !configs.is_empty() {
+                    info!("OpenID identity enabled with {} provider(s)", configs.len());
+                }
+                Self::new(configs, region)
+            }
+            Err(e) => {
+                error!("Error loading OpenID configuration err:{}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.providers.is_empty()
+    }
+
+    /// Role ARNs and the policies they grant
+    pub fn role_policies(&self) -> Vec<(ARN, String)> {
+        self.providers
+            .iter()
+            .filter_map(|p| Some((p.role_arn.clone()?, p.config.role_policy.clone()?)))
+            .collect()
+    }
+
+    /// Validate a token against the provider of `role_arn`, or against the claim based providers
+    pub async fn authenticate(&self, token: &str, role_arn: Option<&str>) -> Result<OpenIdIdentity> {
+        if let Some(role_arn) = role_arn {
+            let arn = ARN::parse(role_arn).map_err(|_| Error::OpenIdError(format!("invalid role ARN {role_arn}")))?;
+            let provider = self
+                .providers
+                .iter()
+                .find(|p| p.role_arn.as_ref() == Some(&arn))
+                .ok_or_else(|| Error::OpenIdError(format!("role ARN {role_arn} is not configured")))?;
+            return provider.validate(token).await;
+        }
+
+        let mut last_err = Error::OpenIdError("no openid provider without a role policy is configured".to_string());
+        for provider in self.providers.iter().filter(|p| p.role_arn.is_none()) {
+            match provider.validate(token).await {
+                Ok(identity) => return Ok(identity),
+                Err(e) => last_err = e,
+            }
+        }
+        Err(last_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_lookup_config_multiple_providers() {
+        let providers = lookup_config_from(vars(&[
+            (ENV_IDENTITY_OPENID_CONFIG_URL, "https://sso.example.com/.well-known/openid-configuration"),
+            (ENV_IDENTITY_OPENID_CLIENT_ID, "rustfs, console"),
+            (
+                "RUSTFS_IDENTITY_OPENID_CONFIG_URL_KEYCLOAK",
+                "https://kc.example.com/.well-known/openid-configuration",
+            ),
+            ("RUSTFS_IDENTITY_OPENID_CLIENT_ID_KEYCLOAK", "rustfs"),
+            ("RUSTFS_IDENTITY_OPENID_ROLE_POLICY_KEYCLOAK", "readonly"),
+        ]))
+        .expect("valid configuration");
+
+        assert_eq!(providers.len(), 2);
+        assert_eq!(providers[0].name, "default");
+        assert_eq!(providers[0].client_ids, vec!["rustfs".to_string(), "console".to_string()]);
+        assert_eq!(providers[0].policy_claim(), DEFAULT_IDENTITY_OPENID_CLAIM_NAME);
+        assert!(providers[0].role_arn("").is_none());
+
+        assert_eq!(providers[1].name, "keycloak");
+        assert_eq!(providers[1].role_policy.as_deref(), Some("readonly"));
+        assert_eq!(
+            providers[1].role_arn("us-east-1").map(|a| a.to_string()).as_deref(),
+            Some("arn:rustfs:iam:us-east-1::role/openid-keycloak")
+        );
+    }
+
+    #[test]
+    fn test_lookup_config_rejects_incomplete_provider() {
+        assert!(lookup_config_from(vars(&[(ENV_IDENTITY_OPENID_CONFIG_URL, "https://sso.example.com")])).is_err());
+        assert!(
+            lookup_config_from(vars(&[
+                (ENV_IDENTITY_OPENID_CONFIG_URL, "https://sso.example.com"),
+                (ENV_IDENTITY_OPENID_CLIENT_ID, "rustfs"),
+                (ENV_IDENTITY_OPENID_CLAIM_NAME, "groups"),
+                (ENV_IDENTITY_OPENID_ROLE_POLICY, "readonly"),
+            ]))
+            .is_err()
+        );
+        assert!(lookup_config_from(HashMap::new()).expect("empty configuration").is_empty());
+    }
+
+    #[test]
+    fn test_audience_matches() {
+        let client_ids = vec!["rustfs".to_string()];
+        let claims = |v: Value| serde_json::from_value::<HashMap<String, Value>>(v).expect("claims");
+
+        assert_eq!(
+            audience_matches(&claims(json!({"aud": "rustfs"})), &client_ids).as_deref(),
+            Some("rustfs")
+        );
+        assert_eq!(
+            audience_matches(&claims(json!({"aud": ["account", "rustfs"]})), &client_ids).as_deref(),
+            Some("rustfs")
+        );
+        assert_eq!(
+            audience_matches(&claims(json!({"aud": "account", "azp": "rustfs"})), &client_ids).as_deref(),
+            Some("rustfs")
+        );
+        assert!(audience_matches(&claims(json!({"aud": "other"})), &client_ids).is_none());
+    }
+}
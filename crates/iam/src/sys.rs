@@ -16,9 +16,11 @@ use crate::error::Error as IamError;
 use crate::error::is_err_no_such_account;
 use crate::error::is_err_no_such_temp_account;
 use crate::error::{Error, Result};
+use crate::ldap::{LDAP_USER_CLAIM, LdapSys};
 use crate::manager::IamCache;
 use crate::manager::extract_jwt_claims;
 use crate::manager::get_default_policyes;
+use crate::oidc::{OPENID_PARENT_PREFIX, OpenIdSys};
 use crate::store::GroupInfo;
 use crate::store::MappedPolicy;
 use crate::store::Store;
@@ -54,6 +56,9 @@ pub const POLICYNAME: &str = "policy";
 pub const SESSION_POLICY_NAME: &str = "sessionPolicy";
 pub const SESSION_POLICY_NAME_EXTRACTED: &str = "sessionPolicy-extracted";
 
+/// Parent user prefix of credentials issued by AssumeRoleWithCertificate
+pub const TLS_PARENT_PREFIX: &str = "tls:";
+
 static POLICY_PLUGIN_CLIENT: OnceLock<Arc<RwLock<Option<rustfs_policy::policy::opa::AuthZPlugin>>>> = OnceLock::new();
 
 fn get_policy_plugin_client() -> Arc<RwLock<Option<rustfs_policy::policy::opa::AuthZPlugin>>> {
@@ -63,6 +68,8 @@ fn get_policy_plugin_client() -> Arc<RwLock<Option<rustfs_policy::policy::opa::A
 pub struct IamSys<T> {
     store: Arc<IamCache<T>>,
     roles_map: HashMap<ARN, String>,
+    openid: OpenIdSys,
+    ldap: LdapSys,
 }
 
 impl<T: Store> IamSys<T> {
@@ -88,12 +95,28 @@ impl<T: Store> IamSys<T> {
             };
         });
 
+        let region = rustfs_ecstore::global::get_global_region().unwrap_or_default();
+        let openid = OpenIdSys::from_env(&region);
+        let roles_map = openid.role_policies().into_iter().collect();
+
         Self {
             store,
-            roles_map: HashMap::new(),
+            roles_map,
+            openid,
+            ldap: LdapSys::from_env(),
         }
     }
 
+    /// OpenID providers accepted for STS web identity and client grants
+    pub fn openid(&self) -> &OpenIdSys {
+        &self.openid
+    }
+
+    /// LDAP directory accepted for STS LDAP identity
+    pub fn ldap(&self) -> &LdapSys {
+        &self.ldap
+    }
+
     /// Check if the IamSys has a watcher configured
     ///
     /// # Returns
@@ -743,16 +766,41 @@ impl<T: Store> IamSys<T> {
         self.store.policy_db_get(name, groups).await
     }
 
-    pub async fn is_allowed_sts(&self, args: &Args<'_>, parent_user: &str) -> bool {
-        let is_owner = matches!(get_global_action_cred(), Some(cred) if cred.access_key == parent_user);
+    /// Policies mapped to an LDAP user DN and its group DNs
+    pub async fn policy_db_get_ldap(&self, user_dn: &str, groups: &Option<Vec<String>>) -> Result<Vec<String>> {
+        self.store.policy_db_get_ldap(user_dn, groups).await
+    }
+
+    /// Policies mapped directly to a single LDAP user DN or group DN
+    pub async fn ldap_mapped_policy(&self, dn: &str, is_group: bool) -> Result<Vec<String>> {
+        Ok(self.store.ldap_mapped_policy(dn, is_group).await?.to_slice())
+    }
+
+    /// Policies that apply to a temporary credential, with the groups they were resolved for and where
+    /// those groups came from; `None` when they cannot be resolved
+    async fn sts_policies(
+        &self,
+        args: &Args<'_>,
+        parent_user: &str,
+        is_owner: bool,
+    ) -> Option<(Option<Vec<String>>, &'static str, Vec<String>)> {
         let role_arn = args.get_role_arn();
 
-        let (effective_groups, groups_source, policies) = if is_owner {
+        let resolved = if is_owner {
             (None, "owner", Vec::new())
         } else if let Some(arn_str) = role_arn {
-            let Ok(arn) = ARN::parse(arn_str) else { return false };
+            let arn = ARN::parse(arn_str).ok()?;
             let p = MappedPolicy::new(self.roles_map.get(&arn).map_or_else(String::default, |v| v.clone()).as_str()).to_slice();
             (None, "role", p)
+        } else if parent_user.starts_with(OPENID_PARENT_PREFIX) || parent_user.starts_with(TLS_PARENT_PREFIX) {
+            // OpenID and certificate identities have no IAM user; their policies were taken from the
+            // validated token or certificate
+            let (p, _) = args.get_policies(POLICYNAME);
+            (None, "identity_claim", p.into_iter().collect())
+        } else if args.claims.contains_key(LDAP_USER_CLAIM) {
+            // LDAP identities are mapped by DN; group membership was resolved from the directory at login
+            let p = self.policy_db_get_ldap(parent_user, &args.groups).await.ok()?;
+            (args.groups.clone(), "ldap", p)
         } else {
             let (effective_groups, groups_source) = match args.groups.as_ref() {
                 Some(g) if !g.is_empty() => (args.groups.clone(), "args"),
@@ -767,10 +815,19 @@ impl<T: Store> IamSys<T> {
                     }
                 },
             };
-            let Ok(p) = self.policy_db_get(parent_user, &effective_groups).await else { return false };
+            let p = self.policy_db_get(parent_user, &effective_groups).await.ok()?;
             (effective_groups, groups_source, p)
         };
 
+        Some(resolved)
+    }
+
+    pub async fn is_allowed_sts(&self, args: &Args<'_>, parent_user: &str) -> bool {
+        let is_owner = matches!(get_global_action_cred(), Some(cred) if cred.access_key == parent_user);
+        let Some((effective_groups, groups_source, policies)) = self.sts_policies(args, parent_user, is_owner).await else {
+            return false;
+        };
+
         if !is_owner && policies.is_empty() {
             return false;
         }
@@ -805,6 +862,18 @@ impl<T: Store> IamSys<T> {
         is_owner || combined_policy.is_allowed(args).await
     }
 
+    /// Policies that apply to a service account, resolved from its role or its parent user
+    async fn service_account_policies(&self, args: &Args<'_>, parent_user: &str, is_owner: bool) -> Option<Vec<String>> {
+        if is_owner {
+            Some(Vec::new())
+        } else if let Some(arn_str) = args.get_role_arn() {
+            let arn = ARN::parse(arn_str).ok()?;
+            Some(MappedPolicy::new(self.roles_map.get(&arn).map_or_else(String::default, |v| v.clone()).as_str()).to_slice())
+        } else {
+            self.policy_db_get(parent_user, args.groups).await.ok()
+        }
+    }
+
     pub async fn is_allowed_service_account(&self, args: &Args<'_>, parent_user: &str) -> bool {
         let Some(p) = args.claims.get("parent") else {
             return false;
@@ -816,18 +885,8 @@ impl<T: Store> IamSys<T> {
 
         let is_owner = matches!(get_global_action_cred(), Some(cred) if cred.access_key == parent_user);
 
-        let role_arn = args.get_role_arn();
-
-        let svc_policies = {
-            if is_owner {
-                Vec::new()
-            } else if role_arn.is_some() {
-                let Ok(arn) = ARN::parse(role_arn.unwrap_or_default()) else { return false };
-                MappedPolicy::new(self.roles_map.get(&arn).map_or_else(String::default, |v| v.clone()).as_str()).to_slice()
-            } else {
-                let Ok(p) = self.policy_db_get(parent_user, args.groups).await else { return false };
-                p
-            }
+        let Some(svc_policies) = self.service_account_policies(args, parent_user, is_owner).await else {
+            return false;
         };
 
         if !is_owner && svc_policies.is_empty() {
@@ -904,6 +963,37 @@ impl<T: Store> IamSys<T> {
         self.get_combined_policy(&policies).await.is_allowed(args).await
     }
 
+    /// Whether a Deny statement in the identity's own policies matches the request. Callers that can grant
+    /// access outside IAM, such as bucket ACLs, must honour such a Deny even though `is_allowed` returned false.
+    pub async fn is_denied(&self, args: &Args<'_>) -> bool {
+        if args.is_owner {
+            return false;
+        }
+
+        let Ok((is_temp, temp_parent)) = self.is_temp_user(args.account).await else { return false };
+        let Ok((is_svc, svc_parent)) = self.is_service_account(args.account).await else { return false };
+
+        let mut deny_args = args.clone();
+        let policies = if is_temp {
+            let is_owner = matches!(get_global_action_cred(), Some(cred) if cred.access_key == temp_parent);
+            self.sts_policies(args, &temp_parent, is_owner).await.map(|(_, _, p)| p)
+        } else if is_svc {
+            let is_owner = matches!(get_global_action_cred(), Some(cred) if cred.access_key == svc_parent);
+            deny_args.account = &svc_parent;
+            self.service_account_policies(args, &svc_parent, is_owner).await
+        } else {
+            self.policy_db_get(args.account, args.groups).await.ok()
+        };
+
+        let Some(policies) = policies.filter(|p| !p.is_empty()) else {
+            return false;
+        };
+
+        // With is_owner set, Policy::is_allowed only returns false when a Deny statement matches
+        deny_args.is_owner = true;
+        !self.get_combined_policy(&policies).await.is_allowed(&deny_args).await
+    }
+
     /// Check if the underlying store is ready
     pub fn is_ready(&self) -> bool {
         self.store.is_ready()
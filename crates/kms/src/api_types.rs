@@ -62,6 +62,8 @@ pub struct ConfigureVaultKmsRequest {
     pub key_path_prefix: Option<String>,
     /// Skip TLS verification (insecure, for development only)
     pub skip_tls_verify: Option<bool>,
+    /// Keep master keys in the Transit engine instead of KV (optional, defaults to false)
+    pub use_transit: Option<bool>,
     /// Default master key ID for auto-encryption
     pub default_key_id: Option<String>,
     /// Operation timeout in seconds
@@ -197,6 +199,22 @@ pub enum BackendSummary {
         /// Key path prefix
         key_path_prefix: String,
     },
+    /// KMIP backend summary
+    Kmip {
+        /// KMIP server endpoint
+        endpoint: String,
+        /// KMIP protocol version
+        protocol_version: String,
+    },
+    /// PKCS#11 backend summary
+    Pkcs11 {
+        /// PKCS#11 module path
+        module_path: PathBuf,
+        /// Token label (if configured)
+        token_label: Option<String>,
+        /// Slot ID (if configured)
+        slot_id: Option<u64>,
+    },
 }
 
 impl From<&KmsConfig> for KmsConfigSummary {
@@ -217,7 +235,7 @@ impl From<&KmsConfig> for KmsConfigSummary {
                 has_master_key: local_config.master_key.is_some(),
                 file_permissions: local_config.file_permissions,
             },
-            BackendConfig::Vault(vault_config) => BackendSummary::Vault {
+            BackendConfig::Vault(vault_config) | BackendConfig::VaultTransit(vault_config) => BackendSummary::Vault {
                 address: vault_config.address.clone(),
                 auth_method_type: match &vault_config.auth_method {
                     VaultAuthMethod::Token { .. } => "token".to_string(),
@@ -228,6 +246,18 @@ impl From<&KmsConfig> for KmsConfigSummary {
                 kv_mount: vault_config.kv_mount.clone(),
                 key_path_prefix: vault_config.key_path_prefix.clone(),
             },
+            BackendConfig::Kmip(kmip_config) => {
+                let (major, minor) = kmip_config.protocol_version.as_tuple();
+                BackendSummary::Kmip {
+                    endpoint: kmip_config.endpoint.clone(),
+                    protocol_version: format!("{major}.{minor}"),
+                }
+            }
+            BackendConfig::Pkcs11(pkcs11_config) => BackendSummary::Pkcs11 {
+                module_path: pkcs11_config.module_path.clone(),
+                token_label: pkcs11_config.token_label.clone(),
+                slot_id: pkcs11_config.slot_id,
+            },
         };
 
         Self {
@@ -268,27 +298,34 @@ impl ConfigureLocalKmsRequest {
 impl ConfigureVaultKmsRequest {
     /// Convert to KmsConfig
     pub fn to_kms_config(&self) -> KmsConfig {
+        let vault_config = Box::new(VaultConfig {
+            address: self.address.clone(),
+            auth_method: self.auth_method.clone(),
+            namespace: self.namespace.clone(),
+            mount_path: self.mount_path.clone().unwrap_or_else(|| "transit".to_string()),
+            kv_mount: self.kv_mount.clone().unwrap_or_else(|| "secret".to_string()),
+            key_path_prefix: self.key_path_prefix.clone().unwrap_or_else(|| "rustfs/kms/keys".to_string()),
+            tls: if self.skip_tls_verify.unwrap_or(false) {
+                Some(TlsConfig {
+                    ca_cert_path: None,
+                    client_cert_path: None,
+                    client_key_path: None,
+                    skip_verify: true,
+                })
+            } else {
+                None
+            },
+        });
+        let (backend, backend_config) = if self.use_transit.unwrap_or(false) {
+            (KmsBackend::VaultTransit, BackendConfig::VaultTransit(vault_config))
+        } else {
+            (KmsBackend::Vault, BackendConfig::Vault(vault_config))
+        };
+
         KmsConfig {
-            backend: KmsBackend::Vault,
+            backend,
             default_key_id: self.default_key_id.clone(),
-            backend_config: BackendConfig::Vault(Box::new(VaultConfig {
-                address: self.address.clone(),
-                auth_method: self.auth_method.clone(),
-                namespace: self.namespace.clone(),
-                mount_path: self.mount_path.clone().unwrap_or_else(|| "transit".to_string()),
-                kv_mount: self.kv_mount.clone().unwrap_or_else(|| "secret".to_string()),
-                key_path_prefix: self.key_path_prefix.clone().unwrap_or_else(|| "rustfs/kms/keys".to_string()),
-                tls: if self.skip_tls_verify.unwrap_or(false) {
-                    Some(TlsConfig {
-                        ca_cert_path: None,
-                        client_cert_path: None,
-                        client_key_path: None,
-                        skip_verify: true,
-                    })
-                } else {
-                    None
-                },
-            })),
+            backend_config,
             timeout: Duration::from_secs(self.timeout_seconds.unwrap_or(30)),
             retry_attempts: self.retry_attempts.unwrap_or(3),
             enable_cache: self.enable_cache.unwrap_or(true),
@@ -0,0 +1,1035 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shared implementation for backends whose master keys live in an external key device
+//!
+//! KMIP servers and PKCS#11 HSMs hold non-extractable AES master keys and only
+//! wrap or unwrap data keys on request. The rest of the key state (the device
+//! object of every key version, status, description and tags) contains no key
+//! material and is kept in a [`KeyStateStore`] shared by all nodes. Each node
+//! caches that state and reloads it when the device rejects a cached object or
+//! a requested version is unknown, so rotations and status changes made through
+//! other nodes are picked up.
+
+use crate::backends::{BackendInfo, KmsBackend, KmsClient};
+use crate::encryption::{DataKeyEnvelope, generate_key_material};
+use crate::error::{KmsError, Result};
+use crate::types::*;
+use async_trait::async_trait;
+use jiff::Zoned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+use zeroize::Zeroize;
+
+/// A key device holding non-extractable AES-256 master keys
+#[async_trait]
+pub trait KeyDevice: Send + Sync {
+    /// Create a new key, returning its device object identifier
+    async fn create_key(&self, label: &str) -> Result<String>;
+
+    /// Create a replacement for a key, returning the identifier of the new object
+    async fn rekey(&self, object_id: &str, label: &str) -> Result<String>;
+
+    /// Encrypt with AES-GCM, returning the ciphertext (with tag) and the IV
+    async fn wrap(&self, object_id: &str, plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>)>;
+
+    /// Decrypt an AES-GCM ciphertext produced by `wrap`
+    async fn unwrap(&self, object_id: &str, ciphertext: &[u8], iv: &[u8]) -> Result<Vec<u8>>;
+
+    /// Stop a key from encrypting; data wrapped under it can still be unwrapped
+    async fn revoke_key(&self, object_id: &str) -> Result<()>;
+
+    /// Revoke and destroy a key
+    async fn destroy_key(&self, object_id: &str) -> Result<()>;
+
+    /// Check that the device is reachable
+    async fn health_check(&self) -> Result<()>;
+
+    /// Describe the device
+    fn backend_info(&self) -> BackendInfo;
+}
+
+/// Storage for key state shared by every node using the same key device
+#[async_trait]
+pub trait KeyStateStore: Send + Sync {
+    /// Load the state of a key, if it exists
+    async fn load(&self, key_id: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Store the state of a key
+    async fn store(&self, key_id: &str, data: Vec<u8>) -> Result<()>;
+
+    /// Remove the state of a key
+    async fn remove(&self, key_id: &str) -> Result<()>;
+
+    /// List the IDs of all stored keys
+    async fn list(&self) -> Result<Vec<String>>;
+}
+
+static KEY_STATE_STORE: OnceLock<Arc<dyn KeyStateStore>> = OnceLock::new();
+
+/// Installs the store the KMIP and PKCS#11 backends keep key state in
+pub fn set_key_state_store(store: Arc<dyn KeyStateStore>) {
+    if KEY_STATE_STORE.set(store).is_err() {
+        warn!("set_key_state_store: key state store already set");
+    }
+}
+
+/// The installed key state store
+pub(crate) fn key_state_store() -> Result<Arc<dyn KeyStateStore>> {
+    KEY_STATE_STORE
+        .get()
+        .cloned()
+        .ok_or_else(|| KmsError::configuration_error("No key state store is available for the key device backend"))
+}
+
+/// Key state kept in the key state store
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct HsmKeyData {
+    /// Key algorithm
+    algorithm: String,
+    /// Key usage type
+    usage: KeyUsage,
+    /// Key creation timestamp
+    created_at: Zoned,
+    /// Last rotation timestamp
+    rotated_at: Option<Zoned>,
+    /// Key status
+    status: KeyStatus,
+    /// Device object of each key version, oldest first
+    versions: Vec<String>,
+    /// Whether the latest version was revoked on the device by disabling the key or scheduling its deletion
+    revoked: bool,
+    /// Key description
+    description: Option<String>,
+    /// Key metadata
+    metadata: HashMap<String, String>,
+    /// Key tags
+    tags: HashMap<String, String>,
+}
+
+impl HsmKeyData {
+    fn new(algorithm: &str, object_id: String) -> Self {
+        Self {
+            algorithm: algorithm.to_string(),
+            usage: KeyUsage::EncryptDecrypt,
+            created_at: Zoned::now(),
+            rotated_at: None,
+            status: KeyStatus::Active,
+            versions: vec![object_id],
+            revoked: false,
+            description: None,
+            metadata: HashMap::new(),
+            tags: HashMap::new(),
+        }
+    }
+
+    /// Latest key version
+    fn version(&self) -> u32 {
+        self.versions.len() as u32
+    }
+
+    /// Device object holding a key version
+    fn object_id(&self, version: u32) -> Option<&str> {
+        let index = usize::try_from(version).ok()?.checked_sub(1)?;
+        self.versions.get(index).map(String::as_str)
+    }
+
+    /// Device object holding the latest key version
+    fn latest_object_id(&self) -> Result<&str> {
+        self.versions
+            .last()
+            .map(String::as_str)
+            .ok_or_else(|| KmsError::internal_error("Key state has no versions"))
+    }
+
+    fn master_key_info(&self, key_id: &str) -> MasterKeyInfo {
+        MasterKeyInfo {
+            key_id: key_id.to_string(),
+            version: self.version(),
+            algorithm: self.algorithm.clone(),
+            usage: self.usage.clone(),
+            status: self.status.clone(),
+            description: self.description.clone(),
+            metadata: self.metadata.clone(),
+            created_at: self.created_at.clone(),
+            rotated_at: self.rotated_at.clone(),
+            created_by: None,
+        }
+    }
+}
+
+/// Label of the device object holding a key version
+fn object_label(key_id: &str, version: u32) -> String {
+    format!("rustfs-kms:{key_id}:v{version}")
+}
+
+/// Reject key IDs that cannot be used as a store entry name
+fn validate_key_id(key_id: &str) -> Result<()> {
+    let valid = !key_id.is_empty()
+        && !key_id.starts_with('.')
+        && key_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        return Err(KmsError::invalid_parameter(format!("Invalid key ID: {key_id}")));
+    }
+    Ok(())
+}
+
+/// KMS client for a key device
+pub struct HsmKmsClient<D: KeyDevice> {
+    device: D,
+    store: Arc<dyn KeyStateStore>,
+    /// Key state last loaded from or written to the store
+    cache: RwLock<HashMap<String, HsmKeyData>>,
+}
+
+impl<D: KeyDevice> HsmKmsClient<D> {
+    /// Create a new client keeping key state in a store
+    pub fn new(device: D, store: Arc<dyn KeyStateStore>) -> Self {
+        Self {
+            device,
+            store,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn cached(&self, key_id: &str) -> Option<HsmKeyData> {
+        self.cache.read().unwrap_or_else(|e| e.into_inner()).get(key_id).cloned()
+    }
+
+    fn update_cache(&self, key_id: &str, key_data: Option<&HsmKeyData>) {
+        let mut cache = self.cache.write().unwrap_or_else(|e| e.into_inner());
+        match key_data {
+            Some(key_data) => cache.insert(key_id.to_string(), key_data.clone()),
+            None => cache.remove(key_id),
+        };
+    }
+
+    /// Store key state
+    async fn store_key_data(&self, key_id: &str, key_data: &HsmKeyData) -> Result<()> {
+        validate_key_id(key_id)?;
+        self.store.store(key_id, serde_json::to_vec(key_data)?).await?;
+        self.update_cache(key_id, Some(key_data));
+
+        debug!("Stored key {} state", key_id);
+        Ok(())
+    }
+
+    /// Load key state from the store, bypassing the cache
+    async fn load_key_data(&self, key_id: &str) -> Result<HsmKeyData> {
+        validate_key_id(key_id)?;
+        let Some(content) = self.store.load(key_id).await? else {
+            self.update_cache(key_id, None);
+            return Err(KmsError::key_not_found(key_id));
+        };
+
+        let key_data: HsmKeyData = serde_json::from_slice(&content)?;
+        self.update_cache(key_id, Some(&key_data));
+        Ok(key_data)
+    }
+
+    /// Load key state, from the cache when possible
+    async fn get_key_data(&self, key_id: &str) -> Result<HsmKeyData> {
+        match self.cached(key_id) {
+            Some(key_data) => Ok(key_data),
+            None => self.load_key_data(key_id).await,
+        }
+    }
+
+    /// Load key state and ensure the key may be used for new encryptions
+    async fn get_active_key_data(&self, key_id: &str) -> Result<HsmKeyData> {
+        let mut key_data = self.get_key_data(key_id).await?;
+        if key_data.status != KeyStatus::Active {
+            // The key may have been enabled through another node
+            key_data = self.load_key_data(key_id).await?;
+        }
+        if key_data.status != KeyStatus::Active {
+            return Err(KmsError::invalid_key_state(format!("Key {key_id} is not active: {:?}", key_data.status)));
+        }
+        Ok(key_data)
+    }
+
+    async fn store_key_metadata(&self, key_id: &str, request: &CreateKeyRequest) -> Result<()> {
+        let mut key_data = self.load_key_data(key_id).await?;
+        key_data.usage = request.key_usage.clone();
+        key_data.description = request.description.clone();
+        key_data.tags = request.tags.clone();
+        self.store_key_data(key_id, &key_data).await
+    }
+
+    /// List all key IDs, sorted
+    async fn list_key_ids(&self) -> Result<Vec<String>> {
+        let mut key_ids = self.store.list().await?;
+        key_ids.retain(|key_id| validate_key_id(key_id).is_ok());
+        key_ids.sort();
+        Ok(key_ids)
+    }
+
+    /// Destroy every version of a key on the device and remove its state
+    async fn delete_key(&self, key_id: &str) -> Result<()> {
+        let key_data = self.load_key_data(key_id).await?;
+        for object_id in &key_data.versions {
+            self.device.destroy_key(object_id).await?;
+        }
+        self.store.remove(key_id).await?;
+        self.update_cache(key_id, None);
+
+        debug!("Permanently deleted key {} ({} versions)", key_id, key_data.version());
+        Ok(())
+    }
+
+    /// Revoke the latest version on the device so no node can encrypt with the key
+    async fn revoke(&self, key_id: &str, status: KeyStatus) -> Result<()> {
+        let mut key_data = self.load_key_data(key_id).await?;
+        if !key_data.revoked {
+            self.device.revoke_key(key_data.latest_object_id()?).await?;
+            key_data.revoked = true;
+        }
+        key_data.status = status;
+        self.store_key_data(key_id, &key_data).await
+    }
+
+    /// Make a key usable again; a revoked latest version is replaced since revocation is permanent
+    async fn reinstate(&self, key_id: &str) -> Result<()> {
+        let mut key_data = self.load_key_data(key_id).await?;
+        if key_data.revoked {
+            self.add_version(key_id, &mut key_data).await?;
+        }
+        key_data.status = KeyStatus::Active;
+        self.store_key_data(key_id, &key_data).await
+    }
+
+    /// Replace the latest version on the device with a new one
+    async fn add_version(&self, key_id: &str, key_data: &mut HsmKeyData) -> Result<()> {
+        let version = key_data.version() + 1;
+
+        // Previous versions stay on the device so existing data keys remain decryptable
+        let object_id = self
+            .device
+            .rekey(key_data.latest_object_id()?, &object_label(key_id, version))
+            .await?;
+        key_data.versions.push(object_id);
+        key_data.rotated_at = Some(Zoned::now());
+        key_data.revoked = false;
+        Ok(())
+    }
+
+    /// Wrap plaintext under the latest version of an active key, returning the key state used
+    async fn seal(&self, key_id: &str, plaintext: &[u8]) -> Result<(HsmKeyData, Vec<u8>, Vec<u8>)> {
+        let key_data = self.get_active_key_data(key_id).await?;
+        let object_id = key_data.latest_object_id()?.to_string();
+        let err = match self.device.wrap(&object_id, plaintext).await {
+            Ok((encrypted_key, nonce)) => return Ok((key_data, encrypted_key, nonce)),
+            Err(e) => e,
+        };
+
+        // The key may have been rotated, disabled or deleted through another node
+        let current = self.load_key_data(key_id).await?;
+        if current == key_data {
+            return Err(err);
+        }
+        if current.status != KeyStatus::Active {
+            return Err(KmsError::invalid_key_state(format!("Key {key_id} is not active: {:?}", current.status)));
+        }
+        let (encrypted_key, nonce) = self.device.wrap(current.latest_object_id()?, plaintext).await?;
+        Ok((current, encrypted_key, nonce))
+    }
+
+    /// Unwrap an envelope with the key version it was sealed under
+    async fn open(&self, envelope: &DataKeyEnvelope) -> Result<Vec<u8>> {
+        let key_id = &envelope.master_key_id;
+        let version = envelope.master_key_version.unwrap_or(1);
+
+        let mut key_data = self.get_key_data(key_id).await?;
+        if key_data.object_id(version).is_none() {
+            // A version added through another node
+            key_data = self.load_key_data(key_id).await?;
+        }
+        let object_id = key_data
+            .object_id(version)
+            .ok_or_else(|| KmsError::key_not_found(format!("{key_id} (version {version})")))?;
+        self.device.unwrap(object_id, &envelope.encrypted_key, &envelope.nonce).await
+    }
+}
+
+/// Envelope for a data key wrapped under the latest version of a key
+fn envelope(
+    key_id: &str,
+    key_data: &HsmKeyData,
+    key_spec: &str,
+    encrypted_key: Vec<u8>,
+    nonce: Vec<u8>,
+    encryption_context: &HashMap<String, String>,
+) -> DataKeyEnvelope {
+    DataKeyEnvelope {
+        key_id: uuid::Uuid::new_v4().to_string(),
+        master_key_id: key_id.to_string(),
+        master_key_version: Some(key_data.version()),
+        key_spec: key_spec.to_string(),
+        encrypted_key,
+        nonce,
+        encryption_context: encryption_context.clone(),
+        created_at: Zoned::now(),
+    }
+}
+
+#[async_trait]
+impl<D: KeyDevice> KmsClient for HsmKmsClient<D> {
+    async fn generate_data_key(&self, request: &GenerateKeyRequest, _context: Option<&OperationContext>) -> Result<DataKeyInfo> {
+        debug!("Generating data key for master key: {}", request.master_key_id);
+
+        let plaintext_key = generate_key_material(&request.key_spec)?;
+        let (key_data, encrypted_key, nonce) = self.seal(&request.master_key_id, &plaintext_key).await?;
+        let envelope = envelope(
+            &request.master_key_id,
+            &key_data,
+            &request.key_spec,
+            encrypted_key,
+            nonce,
+            &request.encryption_context,
+        );
+
+        info!("Generated data key for master key: {}", request.master_key_id);
+        Ok(DataKeyInfo::new(
+            envelope.key_id.clone(),
+            key_data.version(),
+            Some(plaintext_key),
+            serde_json::to_vec(&envelope)?,
+            request.key_spec.clone(),
+        ))
+    }
+
+    async fn encrypt(&self, request: &EncryptRequest, _context: Option<&OperationContext>) -> Result<EncryptResponse> {
+        debug!("Encrypting data with key: {}", request.key_id);
+
+        let (key_data, encrypted_key, nonce) = self.seal(&request.key_id, &request.plaintext).await?;
+        let envelope = envelope(
+            &request.key_id,
+            &key_data,
+            &key_data.algorithm,
+            encrypted_key,
+            nonce,
+            &request.encryption_context,
+        );
+
+        Ok(EncryptResponse {
+            ciphertext: serde_json::to_vec(&envelope)?,
+            key_id: request.key_id.clone(),
+            key_version: key_data.version(),
+            algorithm: key_data.algorithm,
+        })
+    }
+
+    async fn decrypt(&self, request: &DecryptRequest, _context: Option<&OperationContext>) -> Result<Vec<u8>> {
+        debug!("Decrypting data");
+
+        let envelope: DataKeyEnvelope = serde_json::from_slice(&request.ciphertext)
+            .map_err(|e| KmsError::cryptographic_error("parse", format!("Failed to parse data key envelope: {e}")))?;
+
+        // Same context rules as the Vault backends
+        for (key, expected_value) in &envelope.encryption_context {
+            if let Some(actual_value) = request.encryption_context.get(key) {
+                if actual_value != expected_value {
+                    return Err(KmsError::context_mismatch(format!(
+                        "Context mismatch for key '{key}': expected '{expected_value}', got '{actual_value}'"
+                    )));
+                }
+            } else if !request.encryption_context.is_empty() {
+                return Err(KmsError::context_mismatch(format!("Missing context key '{key}'")));
+            }
+        }
+
+        self.open(&envelope).await
+    }
+
+    async fn create_key(&self, key_id: &str, algorithm: &str, _context: Option<&OperationContext>) -> Result<MasterKeyInfo> {
+        debug!("Creating master key: {} with algorithm: {}", key_id, algorithm);
+
+        if algorithm != "AES_256" {
+            return Err(KmsError::unsupported_algorithm(algorithm));
+        }
+        match self.load_key_data(key_id).await {
+            Ok(_) => return Err(KmsError::key_already_exists(key_id)),
+            Err(KmsError::KeyNotFound { .. }) => {}
+            Err(e) => return Err(e),
+        }
+
+        let object_id = self.device.create_key(&object_label(key_id, 1)).await?;
+
+        let key_data = HsmKeyData::new(algorithm, object_id);
+        self.store_key_data(key_id, &key_data).await?;
+
+        info!("Successfully created master key: {}", key_id);
+        Ok(key_data.master_key_info(key_id))
+    }
+
+    async fn describe_key(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<KeyInfo> {
+        let key_data = self.load_key_data(key_id).await?;
+        let version = key_data.version();
+
+        Ok(KeyInfo {
+            key_id: key_id.to_string(),
+            description: key_data.description,
+            algorithm: key_data.algorithm,
+            usage: key_data.usage,
+            status: key_data.status,
+            version,
+            metadata: key_data.metadata,
+            tags: key_data.tags,
+            created_at: key_data.created_at,
+            rotated_at: key_data.rotated_at,
+            created_by: None,
+        })
+    }
+
+    async fn list_keys(&self, request: &ListKeysRequest, _context: Option<&OperationContext>) -> Result<ListKeysResponse> {
+        let all_keys = self.list_key_ids().await?;
+        let limit = request.limit.unwrap_or(100) as usize;
+
+        let start_idx = request
+            .marker
+            .as_ref()
+            .and_then(|m| all_keys.iter().position(|k| k == m))
+            .map(|idx| idx + 1)
+            .unwrap_or(0);
+        let end_idx = std::cmp::min(start_idx + limit, all_keys.len());
+
+        let mut key_infos = Vec::new();
+        for key_id in &all_keys[start_idx..end_idx] {
+            if let Ok(key_info) = self.describe_key(key_id, None).await {
+                key_infos.push(key_info);
+            }
+        }
+
+        let truncated = end_idx < all_keys.len();
+        Ok(ListKeysResponse {
+            keys: key_infos,
+            next_marker: if truncated {
+                Some(all_keys[end_idx - 1].clone())
+            } else {
+                None
+            },
+            truncated,
+        })
+    }
+
+    async fn enable_key(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<()> {
+        self.reinstate(key_id).await
+    }
+
+    async fn disable_key(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<()> {
+        self.revoke(key_id, KeyStatus::Disabled).await
+    }
+
+    async fn schedule_key_deletion(
+        &self,
+        key_id: &str,
+        _pending_window_days: u32,
+        _context: Option<&OperationContext>,
+    ) -> Result<()> {
+        self.revoke(key_id, KeyStatus::PendingDeletion).await
+    }
+
+    async fn cancel_key_deletion(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<()> {
+        self.reinstate(key_id).await
+    }
+
+    async fn rotate_key(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<MasterKeyInfo> {
+        debug!("Rotating key: {}", key_id);
+
+        let mut key_data = self.load_key_data(key_id).await?;
+        self.add_version(key_id, &mut key_data).await?;
+        self.store_key_data(key_id, &key_data).await?;
+
+        info!("Successfully rotated key: {} (version {})", key_id, key_data.version());
+        Ok(key_data.master_key_info(key_id))
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.device
+            .health_check()
+            .await
+            .inspect_err(|e| warn!("Key device health check failed: {}", e))
+    }
+
+    fn backend_info(&self) -> BackendInfo {
+        self.device.backend_info()
+    }
+}
+
+/// HsmKmsBackend wraps HsmKmsClient and implements the KmsBackend trait
+pub struct HsmKmsBackend<D: KeyDevice> {
+    client: HsmKmsClient<D>,
+    /// Origin reported in key metadata (e.g. "KMIP", "PKCS11")
+    origin: &'static str,
+}
+
+impl<D: KeyDevice> HsmKmsBackend<D> {
+    /// Create a backend around a key device
+    pub fn with_device(device: D, store: Arc<dyn KeyStateStore>, origin: &'static str) -> Self {
+        Self {
+            client: HsmKmsClient::new(device, store),
+            origin,
+        }
+    }
+
+    fn key_metadata(&self, key_id: String, key_data: HsmKeyData, deletion_date: Option<Zoned>) -> KeyMetadata {
+        KeyMetadata {
+            key_id,
+            key_state: match key_data.status {
+                KeyStatus::Active => KeyState::Enabled,
+                KeyStatus::Disabled => KeyState::Disabled,
+                KeyStatus::PendingDeletion => KeyState::PendingDeletion,
+                KeyStatus::Deleted => KeyState::Unavailable,
+            },
+            key_usage: key_data.usage,
+            description: key_data.description,
+            creation_date: key_data.created_at,
+            deletion_date,
+            origin: self.origin.to_string(),
+            key_manager: self.origin.to_string(),
+            tags: key_data.tags,
+        }
+    }
+}
+
+#[async_trait]
+impl<D: KeyDevice> KmsBackend for HsmKmsBackend<D> {
+    async fn create_key(&self, request: CreateKeyRequest) -> Result<CreateKeyResponse> {
+        let key_id = request.key_name.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+        self.client.create_key(&key_id, "AES_256", None).await?;
+        self.client.store_key_metadata(&key_id, &request).await?;
+
+        let key_data = self.client.get_key_data(&key_id).await?;
+        Ok(CreateKeyResponse {
+            key_metadata: self.key_metadata(key_id.clone(), key_data, None),
+            key_id,
+        })
+    }
+
+    async fn encrypt(&self, request: EncryptRequest) -> Result<EncryptResponse> {
+        self.client.encrypt(&request, None).await
+    }
+
+    async fn decrypt(&self, request: DecryptRequest) -> Result<DecryptResponse> {
+        let envelope: DataKeyEnvelope = serde_json::from_slice(&request.ciphertext)
+            .map_err(|e| KmsError::cryptographic_error("parse", format!("Failed to parse data key envelope: {e}")))?;
+        let plaintext = self.client.decrypt(&request, None).await?;
+
+        Ok(DecryptResponse {
+            plaintext,
+            key_id: envelope.master_key_id,
+            encryption_algorithm: Some("AES-256-GCM".to_string()),
+        })
+    }
+
+    async fn generate_data_key(&self, request: GenerateDataKeyRequest) -> Result<GenerateDataKeyResponse> {
+        let generate_request = GenerateKeyRequest {
+            master_key_id: request.key_id.clone(),
+            key_spec: request.key_spec.as_str().to_string(),
+            key_length: Some(request.key_spec.key_size() as u32),
+            encryption_context: request.encryption_context,
+            grant_tokens: Vec::new(),
+        };
+
+        let data_key = self.client.generate_data_key(&generate_request, None).await?;
+
+        Ok(GenerateDataKeyResponse {
+            key_id: request.key_id,
+            plaintext_key: data_key.plaintext.clone().unwrap_or_default(),
+            ciphertext_blob: data_key.ciphertext.clone(),
+        })
+    }
+
+    async fn describe_key(&self, request: DescribeKeyRequest) -> Result<DescribeKeyResponse> {
+        let key_data = self.client.load_key_data(&request.key_id).await?;
+        Ok(DescribeKeyResponse {
+            key_metadata: self.key_metadata(request.key_id, key_data, None),
+        })
+    }
+
+    async fn list_keys(&self, request: ListKeysRequest) -> Result<ListKeysResponse> {
+        self.client.list_keys(&request, None).await
+    }
+
+    async fn delete_key(&self, request: DeleteKeyRequest) -> Result<DeleteKeyResponse> {
+        let key_id = &request.key_id;
+        let key_data = self.client.load_key_data(key_id).await?;
+
+        if request.force_immediate.unwrap_or(false) {
+            if key_data.status == KeyStatus::PendingDeletion {
+                // Destroys the key on the device; data wrapped under it can no longer be decrypted
+                self.client.delete_key(key_id).await?;
+                return Ok(DeleteKeyResponse {
+                    key_id: key_id.clone(),
+                    deletion_date: None,
+                    key_metadata: self.key_metadata(key_id.clone(), key_data, None),
+                });
+            }
+
+            self.client.schedule_key_deletion(key_id, 0, None).await?;
+            let key_data = self.client.get_key_data(key_id).await?;
+            return Ok(DeleteKeyResponse {
+                key_id: key_id.clone(),
+                deletion_date: None,
+                key_metadata: self.key_metadata(key_id.clone(), key_data, Some(Zoned::now())),
+            });
+        }
+
+        let days = request.pending_window_in_days.unwrap_or(30);
+        if !(7..=30).contains(&days) {
+            return Err(KmsError::invalid_parameter("pending_window_in_days must be between 7 and 30".to_string()));
+        }
+
+        self.client.schedule_key_deletion(key_id, days, None).await?;
+        let key_data = self.client.get_key_data(key_id).await?;
+        let deletion_date = Zoned::now() + Duration::from_secs(days as u64 * 86400);
+
+        Ok(DeleteKeyResponse {
+            key_id: key_id.clone(),
+            deletion_date: Some(deletion_date.to_string()),
+            key_metadata: self.key_metadata(key_id.clone(), key_data, Some(deletion_date)),
+        })
+    }
+
+    async fn cancel_key_deletion(&self, request: CancelKeyDeletionRequest) -> Result<CancelKeyDeletionResponse> {
+        let key_id = &request.key_id;
+        let key_data = self.client.load_key_data(key_id).await?;
+
+        if key_data.status != KeyStatus::PendingDeletion {
+            return Err(KmsError::invalid_key_state(format!("Key {key_id} is not pending deletion")));
+        }
+
+        self.client.cancel_key_deletion(key_id, None).await?;
+        let key_data = self.client.get_key_data(key_id).await?;
+
+        Ok(CancelKeyDeletionResponse {
+            key_id: key_id.clone(),
+            key_metadata: self.key_metadata(key_id.clone(), key_data, None),
+        })
+    }
+
+    async fn rotate_key(&self, request: RotateKeyRequest) -> Result<RotateKeyResponse> {
+        let master_key = self.client.rotate_key(&request.key_id, None).await?;
+
+        Ok(RotateKeyResponse {
+            key_id: master_key.key_id,
+            key_version: master_key.version,
+        })
+    }
+
+    async fn rewrap_data_key(&self, request: RewrapDataKeyRequest) -> Result<RewrapDataKeyResponse> {
+        let mut envelope: DataKeyEnvelope = serde_json::from_slice(&request.ciphertext_blob)
+            .map_err(|e| KmsError::cryptographic_error("parse", format!("Failed to parse data key envelope: {e}")))?;
+        let key_data = self.client.load_key_data(&envelope.master_key_id).await?;
+
+        if envelope.master_key_version == Some(key_data.version()) {
+            return Ok(RewrapDataKeyResponse {
+                key_id: envelope.master_key_id,
+                key_version: key_data.version(),
+                ciphertext_blob: request.ciphertext_blob,
+                rewrapped: false,
+            });
+        }
+
+        let mut plaintext = self.client.open(&envelope).await?;
+        let sealed = self.client.seal(&envelope.master_key_id, &plaintext).await;
+        plaintext.zeroize();
+        let (key_data, encrypted_key, nonce) = sealed?;
+
+        envelope.encrypted_key = encrypted_key;
+        envelope.nonce = nonce;
+        envelope.master_key_version = Some(key_data.version());
+
+        Ok(RewrapDataKeyResponse {
+            ciphertext_blob: serde_json::to_vec(&envelope)?,
+            key_id: envelope.master_key_id,
+            key_version: key_data.version(),
+            rewrapped: true,
+        })
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.client.health_check().await.map(|_| true)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::encryption::{AesDekCrypto, DekCrypto};
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    /// In-memory stand-in for a KMIP server or HSM; clones share the same keys
+    #[derive(Clone, Default)]
+    struct MemoryDevice {
+        keys: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+        revoked: Arc<Mutex<HashSet<String>>>,
+    }
+
+    impl MemoryDevice {
+        fn key(&self, object_id: &str) -> Result<Vec<u8>> {
+            let keys = self.keys.lock().expect("device lock");
+            keys.get(object_id).cloned().ok_or_else(|| KmsError::key_not_found(object_id))
+        }
+    }
+
+    #[async_trait]
+    impl KeyDevice for MemoryDevice {
+        async fn create_key(&self, _label: &str) -> Result<String> {
+            let key = generate_key_material("AES_256")?;
+            let object_id = uuid::Uuid::new_v4().to_string();
+            self.keys.lock().expect("device lock").insert(object_id.clone(), key);
+            Ok(object_id)
+        }
+
+        async fn rekey(&self, object_id: &str, label: &str) -> Result<String> {
+            self.key(object_id)?;
+            self.create_key(label).await
+        }
+
+        async fn wrap(&self, object_id: &str, plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+            if self.revoked.lock().expect("device lock").contains(object_id) {
+                return Err(KmsError::backend_error(format!("Key {object_id} is revoked")));
+            }
+            AesDekCrypto::new().encrypt(&self.key(object_id)?, plaintext).await
+        }
+
+        async fn unwrap(&self, object_id: &str, ciphertext: &[u8], iv: &[u8]) -> Result<Vec<u8>> {
+            AesDekCrypto::new().decrypt(&self.key(object_id)?, ciphertext, iv).await
+        }
+
+        async fn revoke_key(&self, object_id: &str) -> Result<()> {
+            self.revoked.lock().expect("device lock").insert(object_id.to_string());
+            Ok(())
+        }
+
+        async fn destroy_key(&self, object_id: &str) -> Result<()> {
+            self.keys.lock().expect("device lock").remove(object_id);
+            Ok(())
+        }
+
+        async fn health_check(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn backend_info(&self) -> BackendInfo {
+            BackendInfo::new("memory".to_string(), "0.1.0".to_string(), "memory".to_string(), true)
+        }
+    }
+
+    /// In-memory stand-in for the cluster key state store
+    #[derive(Default)]
+    pub(crate) struct MemoryStore {
+        entries: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl KeyStateStore for MemoryStore {
+        async fn load(&self, key_id: &str) -> Result<Option<Vec<u8>>> {
+            Ok(self.entries.lock().expect("store lock").get(key_id).cloned())
+        }
+
+        async fn store(&self, key_id: &str, data: Vec<u8>) -> Result<()> {
+            self.entries.lock().expect("store lock").insert(key_id.to_string(), data);
+            Ok(())
+        }
+
+        async fn remove(&self, key_id: &str) -> Result<()> {
+            self.entries.lock().expect("store lock").remove(key_id);
+            Ok(())
+        }
+
+        async fn list(&self) -> Result<Vec<String>> {
+            Ok(self.entries.lock().expect("store lock").keys().cloned().collect())
+        }
+    }
+
+    /// Two nodes sharing a device and a key state store
+    fn two_nodes() -> (HsmKmsBackend<MemoryDevice>, HsmKmsBackend<MemoryDevice>) {
+        let device = MemoryDevice::default();
+        let store: Arc<dyn KeyStateStore> = Arc::new(MemoryStore::default());
+        (
+            HsmKmsBackend::with_device(device.clone(), store.clone(), "TEST"),
+            HsmKmsBackend::with_device(device, store, "TEST"),
+        )
+    }
+
+    async fn generate(backend: &HsmKmsBackend<MemoryDevice>, key_id: &str) -> Result<GenerateDataKeyResponse> {
+        backend
+            .generate_data_key(GenerateDataKeyRequest {
+                key_id: key_id.to_string(),
+                key_spec: KeySpec::Aes256,
+                encryption_context: HashMap::new(),
+            })
+            .await
+    }
+
+    async fn decrypt(backend: &HsmKmsBackend<MemoryDevice>, ciphertext: Vec<u8>) -> Result<Vec<u8>> {
+        backend
+            .decrypt(DecryptRequest {
+                ciphertext,
+                encryption_context: HashMap::new(),
+                grant_tokens: Vec::new(),
+            })
+            .await
+            .map(|response| response.plaintext)
+    }
+
+    #[tokio::test]
+    async fn test_rotation_and_rewrap() {
+        let backend = HsmKmsBackend::with_device(MemoryDevice::default(), Arc::new(MemoryStore::default()), "TEST");
+
+        let key_id = backend
+            .create_key(CreateKeyRequest {
+                key_name: Some("master".to_string()),
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create key")
+            .key_id;
+
+        let data_key = generate(&backend, &key_id).await.expect("Failed to generate data key");
+        assert_eq!(DataKeyEnvelope::version_of(&data_key.ciphertext_blob), Some(1));
+
+        let rotated = backend
+            .rotate_key(RotateKeyRequest { key_id: key_id.clone() })
+            .await
+            .expect("Failed to rotate key");
+        assert_eq!(rotated.key_version, 2);
+
+        // Data keys sealed under version 1 still decrypt after rotation
+        let decrypted = decrypt(&backend, data_key.ciphertext_blob.clone())
+            .await
+            .expect("Failed to decrypt with previous version");
+        assert_eq!(decrypted, data_key.plaintext_key);
+
+        let rewrapped = backend
+            .rewrap_data_key(RewrapDataKeyRequest {
+                ciphertext_blob: data_key.ciphertext_blob,
+            })
+            .await
+            .expect("Failed to rewrap data key");
+        assert!(rewrapped.rewrapped);
+        assert_eq!(rewrapped.key_version, 2);
+
+        let decrypted = decrypt(&backend, rewrapped.ciphertext_blob.clone())
+            .await
+            .expect("Failed to decrypt rewrapped data key");
+        assert_eq!(decrypted, data_key.plaintext_key);
+
+        let again = backend
+            .rewrap_data_key(RewrapDataKeyRequest {
+                ciphertext_blob: rewrapped.ciphertext_blob,
+            })
+            .await
+            .expect("Failed to rewrap data key");
+        assert!(!again.rewrapped);
+    }
+
+    #[tokio::test]
+    async fn test_versions_resolve_across_nodes() {
+        let (node_a, node_b) = two_nodes();
+
+        let key_id = node_a
+            .create_key(CreateKeyRequest {
+                key_name: Some("shared".to_string()),
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create key")
+            .key_id;
+
+        // Node B caches version 1 before node A rotates
+        let data_key = generate(&node_b, &key_id).await.expect("Failed to generate data key");
+        assert_eq!(DataKeyEnvelope::version_of(&data_key.ciphertext_blob), Some(1));
+        node_a
+            .rotate_key(RotateKeyRequest { key_id: key_id.clone() })
+            .await
+            .expect("Failed to rotate key");
+
+        let data_key = generate(&node_a, &key_id).await.expect("Failed to generate data key");
+        assert_eq!(DataKeyEnvelope::version_of(&data_key.ciphertext_blob), Some(2));
+        let decrypted = decrypt(&node_b, data_key.ciphertext_blob)
+            .await
+            .expect("Failed to decrypt a newer version on the other node");
+        assert_eq!(decrypted, data_key.plaintext_key);
+
+        let listed = node_b
+            .list_keys(ListKeysRequest::default())
+            .await
+            .expect("Failed to list keys on the other node");
+        assert_eq!(listed.keys.len(), 1);
+        assert_eq!(listed.keys[0].version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_status_changes_apply_across_nodes() {
+        let (node_a, node_b) = two_nodes();
+
+        let key_id = node_a
+            .create_key(CreateKeyRequest {
+                key_name: Some("shared".to_string()),
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create key")
+            .key_id;
+        let data_key = generate(&node_b, &key_id).await.expect("Failed to generate data key");
+
+        // Disabling revokes the key on the device, so node B's cached state cannot keep encrypting
+        node_a.client.disable_key(&key_id, None).await.expect("Failed to disable key");
+        assert!(matches!(generate(&node_b, &key_id).await, Err(KmsError::InvalidOperation { .. })));
+        let decrypted = decrypt(&node_b, data_key.ciphertext_blob.clone())
+            .await
+            .expect("Revoked versions still decrypt");
+        assert_eq!(decrypted, data_key.plaintext_key);
+
+        // Enabling replaces the revoked version
+        node_a.client.enable_key(&key_id, None).await.expect("Failed to enable key");
+        let data_key = generate(&node_b, &key_id).await.expect("Failed to generate data key");
+        assert_eq!(DataKeyEnvelope::version_of(&data_key.ciphertext_blob), Some(2));
+
+        node_a
+            .delete_key(DeleteKeyRequest {
+                key_id: key_id.clone(),
+                pending_window_in_days: Some(7),
+                force_immediate: None,
+            })
+            .await
+            .expect("Failed to schedule key deletion");
+        assert!(matches!(generate(&node_b, &key_id).await, Err(KmsError::InvalidOperation { .. })));
+        node_b
+            .cancel_key_deletion(CancelKeyDeletionRequest { key_id: key_id.clone() })
+            .await
+            .expect("Failed to cancel key deletion on the other node");
+        let data_key = generate(&node_a, &key_id).await.expect("Failed to generate data key");
+        assert_eq!(DataKeyEnvelope::version_of(&data_key.ciphertext_blob), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_path_like_key_ids() {
+        let client = HsmKmsClient::new(MemoryDevice::default(), Arc::new(MemoryStore::default()));
+
+        assert!(client.create_key("../escape", "AES_256", None).await.is_err());
+        assert!(client.create_key(".hidden", "AES_256", None).await.is_err());
+        assert!(client.create_key("valid-key_1.0", "AES_256", None).await.is_ok());
+    }
+}
@@ -0,0 +1,668 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! KMIP KMS backend implementation
+//!
+//! Talks KMIP 1.4 or 2.0 over mutual TLS. Master keys are AES-256 symmetric
+//! keys created on the KMIP server and used through its Encrypt/Decrypt
+//! operations (AES-GCM); key material is never retrieved. Rotation uses ReKey,
+//! and disabling a key or scheduling its deletion revokes it on the server.
+
+pub(crate) mod ttlv;
+
+use crate::backends::BackendInfo;
+use crate::backends::hsm::{HsmKmsBackend, KeyDevice, key_state_store};
+use crate::config::{KmipConfig, KmipProtocolVersion, KmsConfig};
+use crate::error::{KmsError, Result};
+use async_trait::async_trait;
+use rand::RngExt;
+use rustls::RootCertStore;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, ServerName};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+use tracing::{debug, info};
+use ttlv::{HEADER_LEN, Ttlv, tag};
+
+/// Default KMIP port
+const KMIP_PORT: u16 = 5696;
+/// Largest response accepted from the server
+const MAX_RESPONSE_LEN: usize = 16 * 1024 * 1024;
+/// AES-GCM IV length
+const GCM_IV_LEN: usize = 12;
+/// AES-GCM tag length
+const GCM_TAG_LEN: usize = 16;
+
+/// KMIP operations
+mod operation {
+    pub const CREATE: u32 = 0x01;
+    pub const REKEY: u32 = 0x04;
+    pub const ACTIVATE: u32 = 0x12;
+    pub const REVOKE: u32 = 0x13;
+    pub const DESTROY: u32 = 0x14;
+    pub const QUERY: u32 = 0x18;
+    pub const ENCRYPT: u32 = 0x1F;
+    pub const DECRYPT: u32 = 0x20;
+}
+
+/// KMIP enumeration values
+mod value {
+    pub const OBJECT_TYPE_SYMMETRIC_KEY: u32 = 0x02;
+    pub const ALGORITHM_AES: u32 = 0x03;
+    pub const BLOCK_CIPHER_MODE_GCM: u32 = 0x09;
+    pub const USAGE_MASK_ENCRYPT_DECRYPT: i32 = 0x04 | 0x08;
+    pub const NAME_TYPE_TEXT: u32 = 0x01;
+    pub const RESULT_STATUS_SUCCESS: u32 = 0x00;
+    pub const RESULT_REASON_ITEM_NOT_FOUND: u32 = 0x01;
+    pub const REVOCATION_CESSATION_OF_OPERATION: u32 = 0x06;
+    pub const QUERY_OPERATIONS: u32 = 0x01;
+}
+
+/// Failure of a KMIP request
+#[derive(Debug)]
+enum KmipError {
+    /// The request could not be sent or the response could not be read
+    Transport(KmsError),
+    /// The server processed the request and reported a failure
+    Failed { reason: Option<u32>, message: String },
+}
+
+impl KmipError {
+    fn is_reason(&self, expected: u32) -> bool {
+        matches!(self, Self::Failed { reason: Some(reason), .. } if *reason == expected)
+    }
+
+    fn into_kms_error(self, op: &str, object_id: &str) -> KmsError {
+        match self {
+            Self::Transport(e) => e,
+            Self::Failed {
+                reason: Some(value::RESULT_REASON_ITEM_NOT_FOUND),
+                ..
+            } => KmsError::key_not_found(object_id),
+            Self::Failed { reason, message } => {
+                KmsError::backend_error(format!("KMIP {op} failed for object {object_id} (reason {reason:?}): {message}"))
+            }
+        }
+    }
+}
+
+impl From<KmsError> for KmipError {
+    fn from(e: KmsError) -> Self {
+        Self::Transport(e)
+    }
+}
+
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>> {
+    let pem = std::fs::read(path)?;
+    let certs = rustls_pemfile::certs(&mut pem.as_slice())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|e| KmsError::configuration_error(format!("Invalid certificate file {}: {e}", path.display())))?;
+    if certs.is_empty() {
+        return Err(KmsError::configuration_error(format!("No certificate found in {}", path.display())));
+    }
+    Ok(certs)
+}
+
+fn load_private_key(path: &Path) -> Result<PrivateKeyDer<'static>> {
+    let pem = std::fs::read(path)?;
+    rustls_pemfile::private_key(&mut pem.as_slice())
+        .map_err(|e| KmsError::configuration_error(format!("Invalid private key file {}: {e}", path.display())))?
+        .ok_or_else(|| KmsError::configuration_error(format!("No private key found in {}", path.display())))
+}
+
+/// Minimal KMIP client sending one request per TLS connection
+pub struct KmipClient {
+    endpoint: String,
+    server_name: ServerName<'static>,
+    connector: TlsConnector,
+    protocol_version: KmipProtocolVersion,
+    timeout: Duration,
+}
+
+impl KmipClient {
+    /// Create a new KMIP client
+    pub fn new(config: &KmipConfig, timeout: Duration) -> Result<Self> {
+        let endpoint = if config
+            .endpoint
+            .rsplit_once(':')
+            .is_some_and(|(_, port)| port.parse::<u16>().is_ok())
+        {
+            config.endpoint.clone()
+        } else {
+            format!("{}:{KMIP_PORT}", config.endpoint)
+        };
+        let host = config.server_name.clone().unwrap_or_else(|| {
+            endpoint
+                .rsplit_once(':')
+                .map(|(host, _)| host)
+                .unwrap_or_default()
+                .to_string()
+        });
+        let server_name = ServerName::try_from(host.trim_matches(['[', ']']).to_string())
+            .map_err(|e| KmsError::configuration_error(format!("Invalid KMIP server name {host}: {e}")))?;
+
+        let mut roots = RootCertStore::empty();
+        for cert in load_certs(&config.ca_cert_path)? {
+            roots
+                .add(cert)
+                .map_err(|e| KmsError::configuration_error(format!("Invalid KMIP CA certificate: {e}")))?;
+        }
+
+        let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+        let tls_config = rustls::ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .map_err(|e| KmsError::configuration_error(format!("Failed to configure TLS: {e}")))?
+            .with_root_certificates(roots)
+            .with_client_auth_cert(load_certs(&config.client_cert_path)?, load_private_key(&config.client_key_path)?)
+            .map_err(|e| KmsError::configuration_error(format!("Invalid KMIP client certificate: {e}")))?;
+
+        Ok(Self {
+            endpoint,
+            server_name,
+            connector: TlsConnector::from(Arc::new(tls_config)),
+            protocol_version: config.protocol_version,
+            timeout,
+        })
+    }
+
+    /// Build a request message with a single batch item
+    fn request_message(&self, operation: u32, payload: Vec<Ttlv>) -> Ttlv {
+        let (major, minor) = self.protocol_version.as_tuple();
+        Ttlv::structure(
+            tag::REQUEST_MESSAGE,
+            vec![
+                Ttlv::structure(
+                    tag::REQUEST_HEADER,
+                    vec![
+                        Ttlv::structure(
+                            tag::PROTOCOL_VERSION,
+                            vec![
+                                Ttlv::integer(tag::PROTOCOL_VERSION_MAJOR, major),
+                                Ttlv::integer(tag::PROTOCOL_VERSION_MINOR, minor),
+                            ],
+                        ),
+                        Ttlv::integer(tag::BATCH_COUNT, 1),
+                    ],
+                ),
+                Ttlv::structure(
+                    tag::BATCH_ITEM,
+                    vec![
+                        Ttlv::enumeration(tag::OPERATION, operation),
+                        Ttlv::structure(tag::REQUEST_PAYLOAD, payload),
+                    ],
+                ),
+            ],
+        )
+    }
+
+    /// Send a request and read the raw response message
+    async fn exchange(&self, request: &[u8]) -> Result<Vec<u8>> {
+        let stream = TcpStream::connect(&self.endpoint)
+            .await
+            .map_err(|e| KmsError::backend_error(format!("Failed to connect to KMIP server {}: {e}", self.endpoint)))?;
+        let mut stream = self
+            .connector
+            .connect(self.server_name.clone(), stream)
+            .await
+            .map_err(|e| KmsError::backend_error(format!("KMIP TLS handshake with {} failed: {e}", self.endpoint)))?;
+
+        stream.write_all(request).await?;
+        stream.flush().await?;
+
+        let mut header = [0u8; HEADER_LEN];
+        stream.read_exact(&mut header).await?;
+        let len = Ttlv::encoded_len(&header);
+        if len > MAX_RESPONSE_LEN {
+            return Err(KmsError::backend_error(format!("KMIP response too large: {len} bytes")));
+        }
+
+        let mut response = vec![0u8; len];
+        response[..HEADER_LEN].copy_from_slice(&header);
+        stream.read_exact(&mut response[HEADER_LEN..]).await?;
+        Ok(response)
+    }
+
+    /// Run an operation, returning the response payload
+    async fn call(&self, operation: u32, payload: Vec<Ttlv>) -> std::result::Result<Ttlv, KmipError> {
+        let request = self.request_message(operation, payload).to_bytes();
+        let response = tokio::time::timeout(self.timeout, self.exchange(&request))
+            .await
+            .map_err(|_| KmsError::backend_error(format!("KMIP request to {} timed out", self.endpoint)))??;
+
+        let (message, _) = Ttlv::decode(&response)?;
+        let item = message.require(tag::BATCH_ITEM)?;
+        let status = item.require(tag::RESULT_STATUS)?.as_enumeration();
+
+        if status != Some(value::RESULT_STATUS_SUCCESS) {
+            return Err(KmipError::Failed {
+                reason: item.child(tag::RESULT_REASON).and_then(Ttlv::as_enumeration),
+                message: item
+                    .child(tag::RESULT_MESSAGE)
+                    .and_then(Ttlv::as_text)
+                    .unwrap_or("no result message")
+                    .to_string(),
+            });
+        }
+
+        Ok(item
+            .child(tag::RESPONSE_PAYLOAD)
+            .cloned()
+            .unwrap_or_else(|| Ttlv::structure(tag::RESPONSE_PAYLOAD, Vec::new())))
+    }
+
+    /// Unique identifier returned in a response payload
+    fn unique_identifier(payload: &Ttlv) -> Result<String> {
+        payload
+            .require(tag::UNIQUE_IDENTIFIER)?
+            .as_text()
+            .map(str::to_string)
+            .ok_or_else(|| KmsError::backend_error("KMIP unique identifier is not a text string"))
+    }
+
+    fn name(tag: u32, label: &str) -> Ttlv {
+        Ttlv::structure(
+            tag,
+            vec![
+                Ttlv::text(tag::NAME_VALUE, label),
+                Ttlv::enumeration(tag::NAME_TYPE, value::NAME_TYPE_TEXT),
+            ],
+        )
+    }
+
+    /// A KMIP 1.x attribute
+    fn attribute(attribute_name: &str, attribute_value: Ttlv) -> Ttlv {
+        Ttlv::structure(tag::ATTRIBUTE, vec![Ttlv::text(tag::ATTRIBUTE_NAME, attribute_name), attribute_value])
+    }
+
+    /// Attributes of a new AES-256 encrypt/decrypt key, in the layout of the negotiated protocol version
+    fn key_attributes(&self, label: &str) -> Ttlv {
+        match self.protocol_version {
+            KmipProtocolVersion::V1_4 => Ttlv::structure(
+                tag::TEMPLATE_ATTRIBUTE,
+                vec![
+                    Self::attribute("Cryptographic Algorithm", Ttlv::enumeration(tag::ATTRIBUTE_VALUE, value::ALGORITHM_AES)),
+                    Self::attribute("Cryptographic Length", Ttlv::integer(tag::ATTRIBUTE_VALUE, 256)),
+                    Self::attribute(
+                        "Cryptographic Usage Mask",
+                        Ttlv::integer(tag::ATTRIBUTE_VALUE, value::USAGE_MASK_ENCRYPT_DECRYPT),
+                    ),
+                    Self::attribute("Name", Self::name(tag::ATTRIBUTE_VALUE, label)),
+                ],
+            ),
+            KmipProtocolVersion::V2_0 => Ttlv::structure(
+                tag::ATTRIBUTES,
+                vec![
+                    Ttlv::enumeration(tag::CRYPTOGRAPHIC_ALGORITHM, value::ALGORITHM_AES),
+                    Ttlv::integer(tag::CRYPTOGRAPHIC_LENGTH, 256),
+                    Ttlv::integer(tag::CRYPTOGRAPHIC_USAGE_MASK, value::USAGE_MASK_ENCRYPT_DECRYPT),
+                    Self::name(tag::NAME, label),
+                ],
+            ),
+        }
+    }
+
+    fn gcm_parameters() -> Ttlv {
+        Ttlv::structure(
+            tag::CRYPTOGRAPHIC_PARAMETERS,
+            vec![
+                Ttlv::enumeration(tag::BLOCK_CIPHER_MODE, value::BLOCK_CIPHER_MODE_GCM),
+                Ttlv::enumeration(tag::CRYPTOGRAPHIC_ALGORITHM, value::ALGORITHM_AES),
+            ],
+        )
+    }
+
+    /// Create and activate an AES-256 key
+    pub async fn create(&self, label: &str) -> Result<String> {
+        let payload = self
+            .call(
+                operation::CREATE,
+                vec![
+                    Ttlv::enumeration(tag::OBJECT_TYPE, value::OBJECT_TYPE_SYMMETRIC_KEY),
+                    self.key_attributes(label),
+                ],
+            )
+            .await
+            .map_err(|e| e.into_kms_error("Create", label))?;
+        let object_id = Self::unique_identifier(&payload)?;
+
+        // Keys are created pre-active and cannot be used until activated
+        self.activate(&object_id).await?;
+
+        Ok(object_id)
+    }
+
+    /// Activate a key, making it usable for encryption
+    async fn activate(&self, object_id: &str) -> Result<()> {
+        self.call(operation::ACTIVATE, vec![Ttlv::text(tag::UNIQUE_IDENTIFIER, object_id)])
+            .await
+            .map(|_| ())
+            .map_err(|e| e.into_kms_error("Activate", object_id))
+    }
+
+    /// Replace a key with a new one, returning the replacement's identifier
+    pub async fn rekey(&self, object_id: &str) -> Result<String> {
+        let payload = self
+            .call(operation::REKEY, vec![Ttlv::text(tag::UNIQUE_IDENTIFIER, object_id)])
+            .await
+            .map_err(|e| e.into_kms_error("ReKey", object_id))?;
+        let replacement = Self::unique_identifier(&payload)?;
+
+        // Without an offset the replacement is normally active at once; a server that
+        // schedules activation instead leaves it pre-active
+        if let Err(e) = self.activate(&replacement).await {
+            debug!("Activate after ReKey of {} returned: {}", object_id, e);
+        }
+        Ok(replacement)
+    }
+
+    /// Encrypt with AES-GCM, returning the ciphertext with the tag appended
+    pub async fn encrypt(&self, object_id: &str, plaintext: &[u8], iv: &[u8]) -> Result<Vec<u8>> {
+        let payload = self
+            .call(
+                operation::ENCRYPT,
+                vec![
+                    Ttlv::text(tag::UNIQUE_IDENTIFIER, object_id),
+                    Self::gcm_parameters(),
+                    Ttlv::bytes(tag::DATA, plaintext),
+                    Ttlv::bytes(tag::IV_COUNTER_NONCE, iv),
+                ],
+            )
+            .await
+            .map_err(|e| e.into_kms_error("Encrypt", object_id))?;
+
+        let mut ciphertext = payload
+            .require(tag::DATA)?
+            .as_bytes()
+            .ok_or_else(|| KmsError::backend_error("KMIP Encrypt returned no data"))?
+            .to_vec();
+        let auth_tag = payload
+            .require(tag::AUTHENTICATED_ENCRYPTION_TAG)?
+            .as_bytes()
+            .ok_or_else(|| KmsError::backend_error("KMIP Encrypt returned no authentication tag"))?;
+        ciphertext.extend_from_slice(auth_tag);
+        Ok(ciphertext)
+    }
+
+    /// Decrypt an AES-GCM ciphertext with the tag appended
+    pub async fn decrypt(&self, object_id: &str, ciphertext: &[u8], iv: &[u8]) -> Result<Vec<u8>> {
+        if ciphertext.len() < GCM_TAG_LEN {
+            return Err(KmsError::cryptographic_error("decrypt", "Ciphertext too short"));
+        }
+        let (data, auth_tag) = ciphertext.split_at(ciphertext.len() - GCM_TAG_LEN);
+
+        let payload = self
+            .call(
+                operation::DECRYPT,
+                vec![
+                    Ttlv::text(tag::UNIQUE_IDENTIFIER, object_id),
+                    Self::gcm_parameters(),
+                    Ttlv::bytes(tag::DATA, data),
+                    Ttlv::bytes(tag::IV_COUNTER_NONCE, iv),
+                    Ttlv::bytes(tag::AUTHENTICATED_ENCRYPTION_TAG, auth_tag),
+                ],
+            )
+            .await
+            .map_err(|e| match e {
+                KmipError::Failed { message, .. } => KmsError::cryptographic_error("decrypt", message),
+                e => e.into_kms_error("Decrypt", object_id),
+            })?;
+
+        payload
+            .require(tag::DATA)?
+            .as_bytes()
+            .map(<[u8]>::to_vec)
+            .ok_or_else(|| KmsError::backend_error("KMIP Decrypt returned no data"))
+    }
+
+    async fn revoke_request(&self, object_id: &str) -> std::result::Result<Ttlv, KmipError> {
+        self.call(
+            operation::REVOKE,
+            vec![
+                Ttlv::text(tag::UNIQUE_IDENTIFIER, object_id),
+                Ttlv::structure(
+                    tag::REVOCATION_REASON,
+                    vec![Ttlv::enumeration(
+                        tag::REVOCATION_REASON_CODE,
+                        value::REVOCATION_CESSATION_OF_OPERATION,
+                    )],
+                ),
+            ],
+        )
+        .await
+    }
+
+    /// Revoke a key so it can only decrypt; keys that are already gone are ignored
+    pub async fn revoke(&self, object_id: &str) -> Result<()> {
+        match self.revoke_request(object_id).await {
+            Err(e) if !e.is_reason(value::RESULT_REASON_ITEM_NOT_FOUND) => Err(e.into_kms_error("Revoke", object_id)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Revoke and destroy a key; keys that are already gone are ignored
+    pub async fn destroy(&self, object_id: &str) -> Result<()> {
+        match self.revoke_request(object_id).await {
+            Err(e) if e.is_reason(value::RESULT_REASON_ITEM_NOT_FOUND) => return Ok(()),
+            Err(KmipError::Transport(e)) => return Err(e),
+            // Keys revoked when they were disabled may refuse a second revocation
+            _ => {}
+        }
+
+        match self
+            .call(operation::DESTROY, vec![Ttlv::text(tag::UNIQUE_IDENTIFIER, object_id)])
+            .await
+        {
+            Err(e) if !e.is_reason(value::RESULT_REASON_ITEM_NOT_FOUND) => Err(e.into_kms_error("Destroy", object_id)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Query the operations supported by the server
+    pub async fn query(&self) -> Result<()> {
+        self.call(operation::QUERY, vec![Ttlv::enumeration(tag::QUERY_FUNCTION, value::QUERY_OPERATIONS)])
+            .await
+            .map(|_| ())
+            .map_err(|e| e.into_kms_error("Query", "-"))
+    }
+}
+
+/// KMIP server as a key device
+pub struct KmipDevice {
+    client: KmipClient,
+    config: KmipConfig,
+}
+
+#[async_trait]
+impl KeyDevice for KmipDevice {
+    async fn create_key(&self, label: &str) -> Result<String> {
+        self.client.create(label).await
+    }
+
+    async fn rekey(&self, object_id: &str, _label: &str) -> Result<String> {
+        // The server moves the key's name to the replacement, which is found through the key state instead
+        self.client.rekey(object_id).await
+    }
+
+    async fn wrap(&self, object_id: &str, plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+        let mut iv = vec![0u8; GCM_IV_LEN];
+        rand::rng().fill(&mut iv[..]);
+        let ciphertext = self.client.encrypt(object_id, plaintext, &iv).await?;
+        Ok((ciphertext, iv))
+    }
+
+    async fn unwrap(&self, object_id: &str, ciphertext: &[u8], iv: &[u8]) -> Result<Vec<u8>> {
+        self.client.decrypt(object_id, ciphertext, iv).await
+    }
+
+    async fn revoke_key(&self, object_id: &str) -> Result<()> {
+        self.client.revoke(object_id).await
+    }
+
+    async fn destroy_key(&self, object_id: &str) -> Result<()> {
+        self.client.destroy(object_id).await
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.client.query().await
+    }
+
+    fn backend_info(&self) -> BackendInfo {
+        let (major, minor) = self.config.protocol_version.as_tuple();
+        BackendInfo::new("kmip".to_string(), "0.1.0".to_string(), self.client.endpoint.clone(), true)
+            .with_metadata("protocol_version".to_string(), format!("{major}.{minor}"))
+    }
+}
+
+/// KMIP backend
+pub type KmipKmsBackend = HsmKmsBackend<KmipDevice>;
+
+impl HsmKmsBackend<KmipDevice> {
+    /// Create a new KMIP backend
+    pub async fn new(config: KmsConfig) -> Result<Self> {
+        let kmip_config = match &config.backend_config {
+            crate::config::BackendConfig::Kmip(kmip_config) => (**kmip_config).clone(),
+            _ => return Err(KmsError::configuration_error("Expected KMIP backend configuration")),
+        };
+
+        let client = KmipClient::new(&kmip_config, config.timeout)?;
+        info!("Using KMIP server at {} for master keys", client.endpoint);
+
+        let device = KmipDevice {
+            client,
+            config: kmip_config,
+        };
+        Ok(Self::with_device(device, key_state_store()?, "KMIP"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::KmsBackend;
+    use crate::types::*;
+    use std::collections::HashMap;
+
+    fn test_client(protocol_version: KmipProtocolVersion) -> KmipClient {
+        // The TLS connector is never used by these tests
+        let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+        let tls_config = rustls::ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .expect("TLS versions")
+            .with_root_certificates(RootCertStore::empty())
+            .with_no_client_auth();
+
+        KmipClient {
+            endpoint: format!("localhost:{KMIP_PORT}"),
+            server_name: ServerName::try_from("localhost").expect("server name"),
+            connector: TlsConnector::from(Arc::new(tls_config)),
+            protocol_version,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    #[test]
+    fn test_request_message_layout() {
+        let client = test_client(KmipProtocolVersion::V2_0);
+        let message = client.request_message(operation::QUERY, Vec::new());
+
+        let header = message.require(tag::REQUEST_HEADER).expect("header");
+        let version = header.require(tag::PROTOCOL_VERSION).expect("version");
+        assert_eq!(
+            version.require(tag::PROTOCOL_VERSION_MAJOR).expect("major").value,
+            ttlv::TtlvValue::Integer(2)
+        );
+        assert_eq!(
+            version.require(tag::PROTOCOL_VERSION_MINOR).expect("minor").value,
+            ttlv::TtlvValue::Integer(0)
+        );
+
+        let item = message.require(tag::BATCH_ITEM).expect("batch item");
+        assert_eq!(item.require(tag::OPERATION).expect("operation").as_enumeration(), Some(operation::QUERY));
+    }
+
+    #[test]
+    fn test_key_attributes_follow_protocol_version() {
+        let attributes = test_client(KmipProtocolVersion::V1_4).key_attributes("rustfs-kms:k:v1");
+        assert_eq!(attributes.tag, tag::TEMPLATE_ATTRIBUTE);
+
+        let attributes = test_client(KmipProtocolVersion::V2_0).key_attributes("rustfs-kms:k:v1");
+        assert_eq!(attributes.tag, tag::ATTRIBUTES);
+        let name = attributes.require(tag::NAME).expect("name");
+        assert_eq!(name.require(tag::NAME_VALUE).expect("name value").as_text(), Some("rustfs-kms:k:v1"));
+    }
+
+    /// Runs against a KMIP server such as PyKMIP, configured through RUSTFS_KMS_KMIP_* variables
+    #[tokio::test]
+    #[ignore] // Requires a running KMIP server
+    async fn test_kmip_backend_integration() {
+        let kmip_config = KmipConfig::from_env().expect("KMIP test configuration");
+        let config = KmsConfig {
+            backend: crate::config::KmsBackend::Kmip,
+            backend_config: crate::config::BackendConfig::Kmip(Box::new(kmip_config)),
+            ..Default::default()
+        };
+        crate::backends::hsm::set_key_state_store(Arc::new(crate::backends::hsm::tests::MemoryStore::default()));
+        let backend = KmipKmsBackend::new(config).await.expect("Failed to create KMIP backend");
+        assert!(backend.health_check().await.expect("Health check failed"));
+
+        let key_id = backend
+            .create_key(CreateKeyRequest::default())
+            .await
+            .expect("Failed to create key")
+            .key_id;
+        let data_key = backend
+            .generate_data_key(GenerateDataKeyRequest {
+                key_id: key_id.clone(),
+                key_spec: KeySpec::Aes256,
+                encryption_context: HashMap::new(),
+            })
+            .await
+            .expect("Failed to generate data key");
+
+        backend
+            .rotate_key(RotateKeyRequest { key_id: key_id.clone() })
+            .await
+            .expect("Failed to rotate key");
+
+        let decrypted = backend
+            .decrypt(DecryptRequest {
+                ciphertext: data_key.ciphertext_blob,
+                encryption_context: HashMap::new(),
+                grant_tokens: Vec::new(),
+            })
+            .await
+            .expect("Failed to decrypt data key");
+        assert_eq!(decrypted.plaintext, data_key.plaintext_key);
+
+        backend
+            .delete_key(DeleteKeyRequest {
+                key_id: key_id.clone(),
+                pending_window_in_days: None,
+                force_immediate: Some(true),
+            })
+            .await
+            .expect("Failed to schedule key deletion");
+        backend
+            .delete_key(DeleteKeyRequest {
+                key_id,
+                pending_window_in_days: None,
+                force_immediate: Some(true),
+            })
+            .await
+            .expect("Failed to destroy key");
+    }
+}
@@ -0,0 +1,364 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! KMIP Tag-Type-Length-Value encoding
+//!
+//! Every item is a 3-byte tag, a 1-byte type and a 4-byte big-endian length,
+//! followed by the value padded to a multiple of 8 bytes.
+
+use crate::error::{KmsError, Result};
+
+/// KMIP tags used by the client
+pub mod tag {
+    pub const ATTRIBUTE: u32 = 0x42_0008;
+    pub const ATTRIBUTE_NAME: u32 = 0x42_000A;
+    pub const ATTRIBUTE_VALUE: u32 = 0x42_000B;
+    pub const BATCH_COUNT: u32 = 0x42_000D;
+    pub const BATCH_ITEM: u32 = 0x42_000F;
+    pub const BLOCK_CIPHER_MODE: u32 = 0x42_0011;
+    pub const CRYPTOGRAPHIC_ALGORITHM: u32 = 0x42_0028;
+    pub const CRYPTOGRAPHIC_LENGTH: u32 = 0x42_002A;
+    pub const CRYPTOGRAPHIC_PARAMETERS: u32 = 0x42_002B;
+    pub const CRYPTOGRAPHIC_USAGE_MASK: u32 = 0x42_002C;
+    pub const IV_COUNTER_NONCE: u32 = 0x42_003D;
+    pub const NAME: u32 = 0x42_0053;
+    pub const NAME_TYPE: u32 = 0x42_0054;
+    pub const NAME_VALUE: u32 = 0x42_0055;
+    pub const OBJECT_TYPE: u32 = 0x42_0057;
+    pub const OPERATION: u32 = 0x42_005C;
+    pub const PROTOCOL_VERSION: u32 = 0x42_0069;
+    pub const PROTOCOL_VERSION_MAJOR: u32 = 0x42_006A;
+    pub const PROTOCOL_VERSION_MINOR: u32 = 0x42_006B;
+    pub const QUERY_FUNCTION: u32 = 0x42_0074;
+    pub const REQUEST_HEADER: u32 = 0x42_0077;
+    pub const REQUEST_MESSAGE: u32 = 0x42_0078;
+    pub const REQUEST_PAYLOAD: u32 = 0x42_0079;
+    pub const RESPONSE_PAYLOAD: u32 = 0x42_007C;
+    pub const RESULT_MESSAGE: u32 = 0x42_007D;
+    pub const RESULT_REASON: u32 = 0x42_007E;
+    pub const RESULT_STATUS: u32 = 0x42_007F;
+    pub const REVOCATION_REASON: u32 = 0x42_0081;
+    pub const REVOCATION_REASON_CODE: u32 = 0x42_0082;
+    pub const TEMPLATE_ATTRIBUTE: u32 = 0x42_0091;
+    pub const UNIQUE_IDENTIFIER: u32 = 0x42_0094;
+    pub const DATA: u32 = 0x42_00C2;
+    pub const AUTHENTICATED_ENCRYPTION_TAG: u32 = 0x42_00FF;
+    pub const ATTRIBUTES: u32 = 0x42_0125;
+}
+
+const TYPE_STRUCTURE: u8 = 0x01;
+const TYPE_INTEGER: u8 = 0x02;
+const TYPE_LONG_INTEGER: u8 = 0x03;
+const TYPE_BIG_INTEGER: u8 = 0x04;
+const TYPE_ENUMERATION: u8 = 0x05;
+const TYPE_BOOLEAN: u8 = 0x06;
+const TYPE_TEXT_STRING: u8 = 0x07;
+const TYPE_BYTE_STRING: u8 = 0x08;
+const TYPE_DATE_TIME: u8 = 0x09;
+const TYPE_INTERVAL: u8 = 0x0A;
+
+/// Size of an item header (tag, type and length)
+pub const HEADER_LEN: usize = 8;
+
+/// Value of a TTLV item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TtlvValue {
+    Structure(Vec<Ttlv>),
+    Integer(i32),
+    LongInteger(i64),
+    BigInteger(Vec<u8>),
+    Enumeration(u32),
+    Boolean(bool),
+    TextString(String),
+    ByteString(Vec<u8>),
+    DateTime(i64),
+    Interval(u32),
+}
+
+/// A TTLV item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ttlv {
+    pub tag: u32,
+    pub value: TtlvValue,
+}
+
+fn padded(len: usize) -> usize {
+    len.div_ceil(8) * 8
+}
+
+fn malformed(message: impl Into<String>) -> KmsError {
+    KmsError::backend_error(format!("Malformed KMIP message: {}", message.into()))
+}
+
+impl Ttlv {
+    pub fn structure(tag: u32, children: Vec<Ttlv>) -> Self {
+        Self {
+            tag,
+            value: TtlvValue::Structure(children),
+        }
+    }
+
+    pub fn integer(tag: u32, value: i32) -> Self {
+        Self {
+            tag,
+            value: TtlvValue::Integer(value),
+        }
+    }
+
+    pub fn enumeration(tag: u32, value: u32) -> Self {
+        Self {
+            tag,
+            value: TtlvValue::Enumeration(value),
+        }
+    }
+
+    pub fn text(tag: u32, value: impl Into<String>) -> Self {
+        Self {
+            tag,
+            value: TtlvValue::TextString(value.into()),
+        }
+    }
+
+    pub fn bytes(tag: u32, value: impl Into<Vec<u8>>) -> Self {
+        Self {
+            tag,
+            value: TtlvValue::ByteString(value.into()),
+        }
+    }
+
+    /// Encode the item, including its header
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.encode(&mut buf);
+        buf
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.tag.to_be_bytes()[1..]);
+
+        let (item_type, value) = match &self.value {
+            TtlvValue::Structure(children) => {
+                let mut value = Vec::new();
+                for child in children {
+                    child.encode(&mut value);
+                }
+                (TYPE_STRUCTURE, value)
+            }
+            TtlvValue::Integer(v) => (TYPE_INTEGER, v.to_be_bytes().to_vec()),
+            TtlvValue::LongInteger(v) => (TYPE_LONG_INTEGER, v.to_be_bytes().to_vec()),
+            TtlvValue::BigInteger(v) => (TYPE_BIG_INTEGER, v.clone()),
+            TtlvValue::Enumeration(v) => (TYPE_ENUMERATION, v.to_be_bytes().to_vec()),
+            TtlvValue::Boolean(v) => (TYPE_BOOLEAN, u64::from(*v).to_be_bytes().to_vec()),
+            TtlvValue::TextString(v) => (TYPE_TEXT_STRING, v.as_bytes().to_vec()),
+            TtlvValue::ByteString(v) => (TYPE_BYTE_STRING, v.clone()),
+            TtlvValue::DateTime(v) => (TYPE_DATE_TIME, v.to_be_bytes().to_vec()),
+            TtlvValue::Interval(v) => (TYPE_INTERVAL, v.to_be_bytes().to_vec()),
+        };
+
+        buf.push(item_type);
+        buf.extend_from_slice(&(value.len() as u32).to_be_bytes());
+        buf.extend_from_slice(&value);
+        buf.resize(buf.len() + padded(value.len()) - value.len(), 0);
+    }
+
+    /// Total encoded length of the item starting with `header`
+    pub fn encoded_len(header: &[u8; HEADER_LEN]) -> usize {
+        let len = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        HEADER_LEN + padded(len)
+    }
+
+    /// Decode a single item from the start of `buf`, returning it and the bytes consumed
+    pub fn decode(buf: &[u8]) -> Result<(Self, usize)> {
+        if buf.len() < HEADER_LEN {
+            return Err(malformed("truncated item header"));
+        }
+
+        let tag = u32::from_be_bytes([0, buf[0], buf[1], buf[2]]);
+        let item_type = buf[3];
+        let len = u32::from_be_bytes([buf[4], buf[5], buf[6], buf[7]]) as usize;
+        let total = HEADER_LEN + padded(len);
+        if buf.len() < HEADER_LEN + len {
+            return Err(malformed(format!("item {tag:#08x} exceeds message length")));
+        }
+        let raw = &buf[HEADER_LEN..HEADER_LEN + len];
+
+        let fixed = |size: usize| -> Result<&[u8]> {
+            if len != size {
+                return Err(malformed(format!("item {tag:#08x} has invalid length {len}")));
+            }
+            Ok(raw)
+        };
+
+        let value = match item_type {
+            TYPE_STRUCTURE => {
+                let mut children = Vec::new();
+                let mut offset = 0;
+                while offset < len {
+                    let (child, used) = Self::decode(&raw[offset..])?;
+                    children.push(child);
+                    offset += used;
+                }
+                TtlvValue::Structure(children)
+            }
+            TYPE_INTEGER => TtlvValue::Integer(i32::from_be_bytes(fixed(4)?.try_into().expect("length checked"))),
+            TYPE_LONG_INTEGER => TtlvValue::LongInteger(i64::from_be_bytes(fixed(8)?.try_into().expect("length checked"))),
+            TYPE_BIG_INTEGER => TtlvValue::BigInteger(raw.to_vec()),
+            TYPE_ENUMERATION => TtlvValue::Enumeration(u32::from_be_bytes(fixed(4)?.try_into().expect("length checked"))),
+            TYPE_BOOLEAN => TtlvValue::Boolean(u64::from_be_bytes(fixed(8)?.try_into().expect("length checked")) != 0),
+            TYPE_TEXT_STRING => TtlvValue::TextString(
+                String::from_utf8(raw.to_vec()).map_err(|_| malformed(format!("item {tag:#08x} is not valid UTF-8")))?,
+            ),
+            TYPE_BYTE_STRING => TtlvValue::ByteString(raw.to_vec()),
+            TYPE_DATE_TIME => TtlvValue::DateTime(i64::from_be_bytes(fixed(8)?.try_into().expect("length checked"))),
+            TYPE_INTERVAL => TtlvValue::Interval(u32::from_be_bytes(fixed(4)?.try_into().expect("length checked"))),
+            other => return Err(malformed(format!("unknown item type {other:#04x}"))),
+        };
+
+        // The final item of a message may omit its trailing padding
+        Ok((Self { tag, value }, total.min(buf.len())))
+    }
+
+    /// First child with the given tag
+    pub fn child(&self, tag: u32) -> Option<&Ttlv> {
+        match &self.value {
+            TtlvValue::Structure(children) => children.iter().find(|child| child.tag == tag),
+            _ => None,
+        }
+    }
+
+    /// First child with the given tag, or an error naming the missing field
+    pub fn require(&self, tag: u32) -> Result<&Ttlv> {
+        self.child(tag).ok_or_else(|| malformed(format!("missing field {tag:#08x}")))
+    }
+
+    pub fn as_enumeration(&self) -> Option<u32> {
+        match self.value {
+            TtlvValue::Enumeration(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match &self.value {
+            TtlvValue::TextString(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match &self.value {
+            TtlvValue::ByteString(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_matches_specification_examples() {
+        // Examples from the KMIP 1.4 specification, section 9.1.2
+        assert_eq!(
+            Ttlv::integer(0x42_0020, 8).to_bytes(),
+            [0x42, 0x00, 0x20, 0x02, 0, 0, 0, 4, 0, 0, 0, 8, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            Ttlv::enumeration(0x42_0020, 255).to_bytes(),
+            [0x42, 0x00, 0x20, 0x05, 0, 0, 0, 4, 0, 0, 0, 0xFF, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            Ttlv::text(0x42_0020, "Hello World").to_bytes(),
+            [
+                0x42, 0x00, 0x20, 0x07, 0, 0, 0, 0x0B, b'H', b'e', b'l', b'l', b'o', b' ', b'W', b'o', b'r', b'l', b'd', 0, 0, 0,
+                0, 0
+            ]
+        );
+        assert_eq!(
+            Ttlv::bytes(0x42_0020, vec![1, 2, 3]).to_bytes(),
+            [0x42, 0x00, 0x20, 0x08, 0, 0, 0, 3, 1, 2, 3, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            Ttlv::structure(0x42_0020, vec![Ttlv::enumeration(0x42_0004, 254), Ttlv::integer(0x42_0005, 255)]).to_bytes(),
+            [
+                0x42, 0x00, 0x20, 0x01, 0, 0, 0, 0x20, 0x42, 0x00, 0x04, 0x05, 0, 0, 0, 4, 0, 0, 0, 0xFE, 0, 0, 0, 0, 0x42, 0x00,
+                0x05, 0x02, 0, 0, 0, 4, 0, 0, 0, 0xFF, 0, 0, 0, 0
+            ]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let message = Ttlv::structure(
+            tag::REQUEST_MESSAGE,
+            vec![
+                Ttlv::structure(
+                    tag::REQUEST_HEADER,
+                    vec![
+                        Ttlv::structure(
+                            tag::PROTOCOL_VERSION,
+                            vec![
+                                Ttlv::integer(tag::PROTOCOL_VERSION_MAJOR, 1),
+                                Ttlv::integer(tag::PROTOCOL_VERSION_MINOR, 4),
+                            ],
+                        ),
+                        Ttlv::integer(tag::BATCH_COUNT, 1),
+                    ],
+                ),
+                Ttlv::structure(
+                    tag::BATCH_ITEM,
+                    vec![
+                        Ttlv::enumeration(tag::OPERATION, 0x1F),
+                        Ttlv::structure(
+                            tag::REQUEST_PAYLOAD,
+                            vec![
+                                Ttlv::text(tag::UNIQUE_IDENTIFIER, "42"),
+                                Ttlv::bytes(tag::DATA, vec![7; 33]),
+                                Ttlv {
+                                    tag: tag::DATA,
+                                    value: TtlvValue::Boolean(true),
+                                },
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        );
+
+        let encoded = message.to_bytes();
+        assert_eq!(encoded.len() % 8, 0);
+
+        let header: [u8; HEADER_LEN] = encoded[..HEADER_LEN].try_into().expect("header");
+        assert_eq!(Ttlv::encoded_len(&header), encoded.len());
+
+        let (decoded, used) = Ttlv::decode(&encoded).expect("decode");
+        assert_eq!(used, encoded.len());
+        assert_eq!(decoded, message);
+
+        let payload = decoded
+            .require(tag::BATCH_ITEM)
+            .and_then(|item| item.require(tag::REQUEST_PAYLOAD))
+            .expect("payload");
+        assert_eq!(payload.require(tag::UNIQUE_IDENTIFIER).expect("uid").as_text(), Some("42"));
+        assert_eq!(payload.require(tag::DATA).expect("data").as_bytes(), Some(&[7u8; 33][..]));
+    }
+
+    #[test]
+    fn test_decode_rejects_truncated_input() {
+        let encoded = Ttlv::text(tag::UNIQUE_IDENTIFIER, "0123456789").to_bytes();
+        assert!(Ttlv::decode(&encoded[..12]).is_err());
+        assert!(Ttlv::decode(&encoded[..4]).is_err());
+    }
+}
@@ -35,6 +35,7 @@ use std::time::Duration;
 use tokio::fs;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
+use zeroize::Zeroize;
 
 /// Local KMS client that stores keys in local files
 pub struct LocalKmsClient {
@@ -64,6 +65,36 @@ struct StoredMasterKey {
     encrypted_key_material: String,
     /// Nonce used for encryption
     nonce: Vec<u8>,
+    /// Superseded key versions, oldest first, kept so that DEKs wrapped under them stay decryptable
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    previous_versions: Vec<StoredKeyVersion>,
+}
+
+/// Key material of a previous master key version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredKeyVersion {
+    version: u32,
+    /// Encrypted key material (base64)
+    encrypted_key_material: String,
+    /// Nonce used for encryption
+    nonce: Vec<u8>,
+}
+
+impl StoredMasterKey {
+    fn master_key_info(&self) -> MasterKeyInfo {
+        MasterKeyInfo {
+            key_id: self.key_id.clone(),
+            version: self.version,
+            algorithm: self.algorithm.clone(),
+            usage: self.usage.clone(),
+            status: self.status.clone(),
+            description: self.description.clone(),
+            metadata: self.metadata.clone(),
+            created_at: self.created_at.clone(),
+            rotated_at: self.rotated_at.clone(),
+            created_by: self.created_by.clone(),
+        }
+    }
 }
 
 impl LocalKmsClient {
@@ -120,58 +151,41 @@ impl LocalKmsClient {
         let content = fs::read(&key_path).await?;
         let stored_key: StoredMasterKey = serde_json::from_slice(&content)?;
 
-        // Decrypt key material if master cipher is available
-        let key_material = if let Some(ref cipher) = self.master_cipher {
-            if stored_key.nonce.len() != 12 {
+        let key_material = self.unseal_key_material(&stored_key.encrypted_key_material, &stored_key.nonce)?;
+
+        Ok((stored_key, key_material))
+    }
+
+    /// Decrypt stored key material if master cipher is available
+    fn unseal_key_material(&self, encrypted_key_material: &str, nonce: &[u8]) -> Result<Vec<u8>> {
+        if let Some(ref cipher) = self.master_cipher {
+            if nonce.len() != 12 {
                 return Err(KmsError::cryptographic_error("nonce", "Invalid nonce length"));
             }
 
             let mut nonce_array = [0u8; 12];
-            nonce_array.copy_from_slice(&stored_key.nonce);
+            nonce_array.copy_from_slice(nonce);
             let nonce = Nonce::from(nonce_array);
 
             // Decode base64 string to bytes
             let encrypted_bytes = BASE64
-                .decode(&stored_key.encrypted_key_material)
+                .decode(encrypted_key_material)
                 .map_err(|e| KmsError::cryptographic_error("base64_decode", e.to_string()))?;
 
             cipher
                 .decrypt(&nonce, encrypted_bytes.as_ref())
-                .map_err(|e| KmsError::cryptographic_error("decrypt", e.to_string()))?
+                .map_err(|e| KmsError::cryptographic_error("decrypt", e.to_string()))
         } else {
             // Decode base64 string to bytes when no encryption
             BASE64
-                .decode(&stored_key.encrypted_key_material)
-                .map_err(|e| KmsError::cryptographic_error("base64_decode", e.to_string()))?
-        };
-
-        Ok((stored_key, key_material))
-    }
-
-    /// Load a master key from disk
-    async fn load_master_key(&self, key_id: &str) -> Result<MasterKeyInfo> {
-        let (stored_key, _key_material) = self.decode_stored_key(key_id).await?;
-
-        Ok(MasterKeyInfo {
-            key_id: stored_key.key_id,
-            version: stored_key.version,
-            algorithm: stored_key.algorithm,
-            usage: stored_key.usage,
-            status: stored_key.status,
-            description: stored_key.description,
-            metadata: stored_key.metadata,
-            created_at: stored_key.created_at,
-            rotated_at: stored_key.rotated_at,
-            created_by: stored_key.created_by,
-        })
+                .decode(encrypted_key_material)
+                .map_err(|e| KmsError::cryptographic_error("base64_decode", e.to_string()))
+        }
     }
 
-    /// Save a master key to disk
-    async fn save_master_key(&self, master_key: &MasterKeyInfo, key_material: &[u8]) -> Result<()> {
-        let key_path = self.master_key_path(&master_key.key_id);
-
-        // Encrypt key material if master cipher is available
-        let (encrypted_key_material, nonce) = if let Some(ref cipher) = self.master_cipher {
+    /// Encrypt key material for storage if master cipher is available
+    fn seal_key_material(&self, key_material: &[u8]) -> Result<(String, Vec<u8>)> {
+        if let Some(ref cipher) = self.master_cipher {
             let mut nonce_bytes = [0u8; 12];
             rand::rng().fill(&mut nonce_bytes[..]);
             let nonce = Nonce::from(nonce_bytes);
@@ -180,11 +194,22 @@ impl LocalKmsClient {
                 .encrypt(&nonce, key_material)
                 .map_err(|e| KmsError::cryptographic_error("encrypt", e.to_string()))?;
             // Encode encrypted bytes to base64 string
-            (BASE64.encode(&encrypted), nonce.to_vec())
+            Ok((BASE64.encode(&encrypted), nonce.to_vec()))
         } else {
             // Encode key material to base64 string when no encryption
-            (BASE64.encode(key_material), Vec::new())
-        };
+            Ok((BASE64.encode(key_material), Vec::new()))
+        }
+    }
+
+    /// Load a master key from disk
+    async fn load_master_key(&self, key_id: &str) -> Result<MasterKeyInfo> {
+        let (stored_key, _key_material) = self.decode_stored_key(key_id).await?;
+        Ok(stored_key.master_key_info())
+    }
+
+    /// Save a new master key to disk
+    async fn save_master_key(&self, master_key: &MasterKeyInfo, key_material: &[u8]) -> Result<()> {
+        let (encrypted_key_material, nonce) = self.seal_key_material(key_material)?;
 
         let stored_key = StoredMasterKey {
             key_id: master_key.key_id.clone(),
@@ -199,9 +224,28 @@ impl LocalKmsClient {
             created_by: master_key.created_by.clone(),
             encrypted_key_material,
             nonce,
+            previous_versions: Vec::new(),
         };
 
-        let content = serde_json::to_vec_pretty(&stored_key)?;
+        self.write_stored_key(&stored_key).await
+    }
+
+    /// Update the metadata of an existing master key, preserving all key material versions
+    async fn update_master_key(&self, master_key: &MasterKeyInfo) -> Result<()> {
+        let (mut stored_key, _key_material) = self.decode_stored_key(&master_key.key_id).await?;
+
+        stored_key.usage = master_key.usage.clone();
+        stored_key.status = master_key.status.clone();
+        stored_key.description = master_key.description.clone();
+        stored_key.metadata = master_key.metadata.clone();
+
+        self.write_stored_key(&stored_key).await
+    }
+
+    /// Write a stored key to disk
+    async fn write_stored_key(&self, stored_key: &StoredMasterKey) -> Result<()> {
+        let key_path = self.master_key_path(&stored_key.key_id);
+        let content = serde_json::to_vec_pretty(stored_key)?;
 
         // Write to temporary file first, then rename for atomicity
         let temp_path = key_path.with_extension("tmp");
@@ -217,28 +261,62 @@ impl LocalKmsClient {
 
         fs::rename(&temp_path, &key_path).await?;
 
-        info!("Saved master key {} to {:?}", master_key.key_id, key_path);
+        info!("Saved master key {} to {:?}", stored_key.key_id, key_path);
         Ok(())
     }
 
-    /// Get the actual key material for a master key
-    async fn get_key_material(&self, key_id: &str) -> Result<Vec<u8>> {
-        let (_stored_key, key_material) = self.decode_stored_key(key_id).await?;
-        Ok(key_material)
+    /// Get the key material of a master key version, newest first
+    ///
+    /// With `version` set only that version is returned; otherwise all versions are returned
+    /// so that envelopes written before versioning can still be opened.
+    async fn get_key_materials(&self, key_id: &str, version: Option<u32>) -> Result<Vec<(u32, Vec<u8>)>> {
+        let (stored_key, key_material) = self.decode_stored_key(key_id).await?;
+
+        match version {
+            Some(version) if version != stored_key.version => {
+                let previous = stored_key
+                    .previous_versions
+                    .iter()
+                    .find(|previous| previous.version == version)
+                    .ok_or_else(|| KmsError::key_not_found(format!("{key_id} (version {version})")))?;
+                let material = self.unseal_key_material(&previous.encrypted_key_material, &previous.nonce)?;
+                Ok(vec![(version, material)])
+            }
+            Some(_) => Ok(vec![(stored_key.version, key_material)]),
+            None => {
+                let mut materials = vec![(stored_key.version, key_material)];
+                for previous in stored_key.previous_versions.iter().rev() {
+                    let material = self.unseal_key_material(&previous.encrypted_key_material, &previous.nonce)?;
+                    materials.push((previous.version, material));
+                }
+                Ok(materials)
+            }
+        }
     }
 
-    /// Encrypt data using a master key
-    async fn encrypt_with_master_key(&self, key_id: &str, plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
-        // Load the actual master key material
-        let key_material = self.get_key_material(key_id).await?;
-        self.dek_crypto.encrypt(&key_material, plaintext).await
+    /// Encrypt data using the latest version of a master key, returning the version used
+    async fn encrypt_with_master_key(&self, key_id: &str, plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>, u32)> {
+        let (stored_key, key_material) = self.decode_stored_key(key_id).await?;
+        let (ciphertext, nonce) = self.dek_crypto.encrypt(&key_material, plaintext).await?;
+        Ok((ciphertext, nonce, stored_key.version))
     }
 
-    /// Decrypt data using a master key
-    async fn decrypt_with_master_key(&self, key_id: &str, ciphertext: &[u8], nonce: &[u8]) -> Result<Vec<u8>> {
-        // Load the actual master key material
-        let key_material = self.get_key_material(key_id).await?;
-        self.dek_crypto.decrypt(&key_material, ciphertext, nonce).await
+    /// Decrypt data using the given (or, if unknown, any) version of a master key
+    async fn decrypt_with_master_key(
+        &self,
+        key_id: &str,
+        version: Option<u32>,
+        ciphertext: &[u8],
+        nonce: &[u8],
+    ) -> Result<Vec<u8>> {
+        let mut last_err = None;
+        for (_version, key_material) in self.get_key_materials(key_id, version).await? {
+            match self.dek_crypto.decrypt(&key_material, ciphertext, nonce).await {
+                Ok(plaintext) => return Ok(plaintext),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| KmsError::key_not_found(key_id)))
     }
 }
 
@@ -258,12 +336,13 @@ impl KmsClient for LocalKmsClient {
         rand::rng().fill(&mut plaintext_key[..]);
 
         // Encrypt the data key with the master key
-        let (encrypted_key, nonce) = self.encrypt_with_master_key(&request.master_key_id, &plaintext_key).await?;
+        let (encrypted_key, nonce, version) = self.encrypt_with_master_key(&request.master_key_id, &plaintext_key).await?;
 
         // Create data key envelope with master key version for rotation support
         let envelope = DataKeyEnvelope {
             key_id: uuid::Uuid::new_v4().to_string(),
             master_key_id: request.master_key_id.clone(),
+            master_key_version: Some(version),
             key_spec: request.key_spec.clone(),
             encrypted_key: encrypted_key.clone(),
             nonce,
@@ -274,9 +353,9 @@ impl KmsClient for LocalKmsClient {
         // Serialize the envelope as the ciphertext
         let ciphertext = serde_json::to_vec(&envelope)?;
 
-        let data_key = DataKeyInfo::new(envelope.key_id, 1, Some(plaintext_key), ciphertext, request.key_spec.clone());
+        let data_key = DataKeyInfo::new(envelope.key_id, version, Some(plaintext_key), ciphertext, request.key_spec.clone());
 
-        info!("Generated data key for master key: {}", request.master_key_id);
+        info!("Generated data key for master key: {} (version {})", request.master_key_id, version);
         Ok(data_key)
     }
 
@@ -292,12 +371,12 @@ impl KmsClient for LocalKmsClient {
             )));
         }
 
-        let (ciphertext, _nonce) = self.encrypt_with_master_key(&request.key_id, &request.plaintext).await?;
+        let (ciphertext, _nonce, key_version) = self.encrypt_with_master_key(&request.key_id, &request.plaintext).await?;
 
         Ok(EncryptResponse {
             ciphertext,
             key_id: request.key_id.clone(),
-            key_version: key_info.version,
+            key_version,
             algorithm: key_info.algorithm,
         })
     }
@@ -329,7 +408,12 @@ impl KmsClient for LocalKmsClient {
 
         // Decrypt the data key
         let plaintext = self
-            .decrypt_with_master_key(&envelope.master_key_id, &envelope.encrypted_key, &envelope.nonce)
+            .decrypt_with_master_key(
+                &envelope.master_key_id,
+                envelope.master_key_version,
+                &envelope.encrypted_key,
+                &envelope.nonce,
+            )
             .await?;
 
         info!("Successfully decrypted data");
@@ -442,10 +526,7 @@ impl KmsClient for LocalKmsClient {
         let mut master_key = self.load_master_key(key_id).await?;
         master_key.status = KeyStatus::Active;
 
-        // For simplicity, we'll regenerate key material
-        // In a real implementation, we'd preserve the original key material
-        let key_material = generate_key_material(&master_key.algorithm)?;
-        self.save_master_key(&master_key, &key_material).await?;
+        self.update_master_key(&master_key).await?;
 
         // Update cache
         let mut cache = self.key_cache.write().await;
@@ -461,8 +542,7 @@ impl KmsClient for LocalKmsClient {
         let mut master_key = self.load_master_key(key_id).await?;
         master_key.status = KeyStatus::Disabled;
 
-        let key_material = generate_key_material(&master_key.algorithm)?;
-        self.save_master_key(&master_key, &key_material).await?;
+        self.update_master_key(&master_key).await?;
 
         // Update cache
         let mut cache = self.key_cache.write().await;
@@ -483,8 +563,7 @@ impl KmsClient for LocalKmsClient {
         let mut master_key = self.load_master_key(key_id).await?;
         master_key.status = KeyStatus::PendingDeletion;
 
-        let key_material = generate_key_material(&master_key.algorithm)?;
-        self.save_master_key(&master_key, &key_material).await?;
+        self.update_master_key(&master_key).await?;
 
         // Update cache
         let mut cache = self.key_cache.write().await;
@@ -500,8 +579,7 @@ impl KmsClient for LocalKmsClient {
         let mut master_key = self.load_master_key(key_id).await?;
         master_key.status = KeyStatus::Active;
 
-        let key_material = generate_key_material(&master_key.algorithm)?;
-        self.save_master_key(&master_key, &key_material).await?;
+        self.update_master_key(&master_key).await?;
 
         // Update cache
         let mut cache = self.key_cache.write().await;
@@ -514,19 +592,31 @@ impl KmsClient for LocalKmsClient {
     async fn rotate_key(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<MasterKeyInfo> {
         debug!("Rotating key: {}", key_id);
 
-        let mut master_key = self.load_master_key(key_id).await?;
-        master_key.version += 1;
-        master_key.rotated_at = Some(Zoned::now());
+        let (mut stored_key, _key_material) = self.decode_stored_key(key_id).await?;
 
-        // Generate new key material
-        let key_material = generate_key_material(&master_key.algorithm)?;
-        self.save_master_key(&master_key, &key_material).await?;
+        // Keep the current material so DEKs wrapped under it remain decryptable
+        stored_key.previous_versions.push(StoredKeyVersion {
+            version: stored_key.version,
+            encrypted_key_material: stored_key.encrypted_key_material.clone(),
+            nonce: stored_key.nonce.clone(),
+        });
+
+        // Generate new key material for the next version
+        let key_material = generate_key_material(&stored_key.algorithm)?;
+        let (encrypted_key_material, nonce) = self.seal_key_material(&key_material)?;
+        stored_key.encrypted_key_material = encrypted_key_material;
+        stored_key.nonce = nonce;
+        stored_key.version += 1;
+        stored_key.rotated_at = Some(Zoned::now());
+        self.write_stored_key(&stored_key).await?;
+
+        let master_key = stored_key.master_key_info();
 
         // Update cache
         let mut cache = self.key_cache.write().await;
         cache.insert(key_id.to_string(), master_key.clone());
 
-        info!("Rotated key: {}", key_id);
+        info!("Rotated key: {} to version {}", key_id, master_key.version);
         Ok(master_key)
     }
 
@@ -748,15 +838,8 @@ impl KmsBackend for LocalKmsBackend {
             (Some(deletion_date.to_string()), Some(deletion_date))
         };
 
-        // Save the updated key to disk - preserve existing key material!
-        // Load and decode the stored key to get the existing key material
-        let (_stored_key, existing_key_material) = self
-            .client
-            .decode_stored_key(key_id)
-            .await
-            .map_err(|e| KmsError::internal_error(format!("Failed to decode key: {e}")))?;
-
-        self.client.save_master_key(&master_key, &existing_key_material).await?;
+        // Save the updated key to disk, preserving all key material versions
+        self.client.update_master_key(&master_key).await?;
 
         // Update cache
         let mut cache = self.client.key_cache.write().await;
@@ -799,15 +882,8 @@ impl KmsBackend for LocalKmsBackend {
         // Cancel the deletion by resetting the state
         master_key.status = KeyStatus::Active;
 
-        // Save the updated key to disk - this is the missing critical step!
-        // Preserve existing key material instead of generating new one
-        let (_stored_key, existing_key_material) = self
-            .client
-            .decode_stored_key(key_id)
-            .await
-            .map_err(|e| KmsError::internal_error(format!("Failed to decode key: {e}")))?;
-
-        self.client.save_master_key(&master_key, &existing_key_material).await?;
+        // Save the updated key to disk, preserving all key material versions
+        self.client.update_master_key(&master_key).await?;
 
         // Update cache
         let mut cache = self.client.key_cache.write().await;
@@ -832,6 +908,53 @@ impl KmsBackend for LocalKmsBackend {
         })
     }
 
+    async fn rotate_key(&self, request: RotateKeyRequest) -> Result<RotateKeyResponse> {
+        let master_key = self.client.rotate_key(&request.key_id, None).await?;
+
+        Ok(RotateKeyResponse {
+            key_id: master_key.key_id,
+            key_version: master_key.version,
+        })
+    }
+
+    async fn rewrap_data_key(&self, request: RewrapDataKeyRequest) -> Result<RewrapDataKeyResponse> {
+        let mut envelope: DataKeyEnvelope = serde_json::from_slice(&request.ciphertext_blob)?;
+        let latest = self.client.describe_key(&envelope.master_key_id, None).await?.version;
+
+        if envelope.master_key_version == Some(latest) {
+            return Ok(RewrapDataKeyResponse {
+                key_id: envelope.master_key_id,
+                key_version: latest,
+                ciphertext_blob: request.ciphertext_blob,
+                rewrapped: false,
+            });
+        }
+
+        let mut plaintext = self
+            .client
+            .decrypt_with_master_key(
+                &envelope.master_key_id,
+                envelope.master_key_version,
+                &envelope.encrypted_key,
+                &envelope.nonce,
+            )
+            .await?;
+        let wrapped = self.client.encrypt_with_master_key(&envelope.master_key_id, &plaintext).await;
+        plaintext.zeroize();
+        let (encrypted_key, nonce, version) = wrapped?;
+
+        envelope.encrypted_key = encrypted_key;
+        envelope.nonce = nonce;
+        envelope.master_key_version = Some(version);
+
+        Ok(RewrapDataKeyResponse {
+            ciphertext_blob: serde_json::to_vec(&envelope)?,
+            key_id: envelope.master_key_id,
+            key_version: version,
+            rewrapped: true,
+        })
+    }
+
     async fn health_check(&self) -> Result<bool> {
         self.client.health_check().await.map(|_| true)
     }
@@ -943,4 +1066,96 @@ mod tests {
         // Note: Direct decryption of encrypt() results is not implemented in this simple version
         // In a real implementation, encrypt() would create a different envelope format
     }
+
+    #[tokio::test]
+    async fn test_rotation_keeps_previous_versions() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config = KmsConfig {
+            backend_config: crate::config::BackendConfig::Local(LocalConfig {
+                key_dir: temp_dir.path().to_path_buf(),
+                master_key: Some("test-master-key".to_string()),
+                file_permissions: Some(0o600),
+            }),
+            ..Default::default()
+        };
+        let backend = LocalKmsBackend::new(config).await.expect("Failed to create backend");
+
+        let key_id = "rotating-key";
+        backend
+            .create_key(CreateKeyRequest {
+                key_name: Some(key_id.to_string()),
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create key");
+
+        let generate = || GenerateDataKeyRequest {
+            key_id: key_id.to_string(),
+            key_spec: KeySpec::Aes256,
+            encryption_context: HashMap::new(),
+        };
+        let decrypt = |ciphertext: Vec<u8>| DecryptRequest {
+            ciphertext,
+            encryption_context: HashMap::new(),
+            grant_tokens: Vec::new(),
+        };
+
+        let old_key = backend
+            .generate_data_key(generate())
+            .await
+            .expect("Failed to generate data key");
+        assert_eq!(DataKeyEnvelope::version_of(&old_key.ciphertext_blob), Some(1));
+
+        // Disabling and re-enabling must not replace key material
+        backend.client.disable_key(key_id, None).await.expect("Failed to disable key");
+        backend.client.enable_key(key_id, None).await.expect("Failed to enable key");
+
+        let rotated = backend
+            .rotate_key(RotateKeyRequest {
+                key_id: key_id.to_string(),
+            })
+            .await
+            .expect("Failed to rotate key");
+        assert_eq!(rotated.key_version, 2);
+
+        // DEKs wrapped under version 1 stay decryptable
+        let plaintext = backend
+            .decrypt(decrypt(old_key.ciphertext_blob.clone()))
+            .await
+            .expect("Failed to decrypt old data key");
+        assert_eq!(plaintext.plaintext, old_key.plaintext_key);
+
+        // New DEKs are wrapped under version 2
+        let new_key = backend
+            .generate_data_key(generate())
+            .await
+            .expect("Failed to generate data key");
+        assert_eq!(DataKeyEnvelope::version_of(&new_key.ciphertext_blob), Some(2));
+
+        // Re-wrap moves the old DEK to version 2 without changing it
+        let rewrapped = backend
+            .rewrap_data_key(RewrapDataKeyRequest {
+                ciphertext_blob: old_key.ciphertext_blob.clone(),
+            })
+            .await
+            .expect("Failed to re-wrap data key");
+        assert!(rewrapped.rewrapped);
+        assert_eq!(rewrapped.key_version, 2);
+        assert_eq!(DataKeyEnvelope::version_of(&rewrapped.ciphertext_blob), Some(2));
+        let plaintext = backend
+            .decrypt(decrypt(rewrapped.ciphertext_blob.clone()))
+            .await
+            .expect("Failed to decrypt re-wrapped data key");
+        assert_eq!(plaintext.plaintext, old_key.plaintext_key);
+
+        // Re-wrapping again is a no-op
+        let unchanged = backend
+            .rewrap_data_key(RewrapDataKeyRequest {
+                ciphertext_blob: rewrapped.ciphertext_blob.clone(),
+            })
+            .await
+            .expect("Failed to re-wrap data key");
+        assert!(!unchanged.rewrapped);
+        assert_eq!(unchanged.ciphertext_blob, rewrapped.ciphertext_blob);
+    }
 }
@@ -19,8 +19,12 @@ use crate::types::*;
 use async_trait::async_trait;
 use std::collections::HashMap;
 
+pub mod hsm;
+pub mod kmip;
 pub mod local;
+pub mod pkcs11;
 pub mod vault;
+pub mod vault_transit;
 
 /// Abstract KMS client interface that all backends must implement
 #[async_trait]
@@ -179,6 +183,12 @@ pub trait KmsBackend: Send + Sync {
     /// Cancel key deletion
     async fn cancel_key_deletion(&self, request: CancelKeyDeletionRequest) -> Result<CancelKeyDeletionResponse>;
 
+    /// Rotate a master key, keeping previous versions for decryption
+    async fn rotate_key(&self, request: RotateKeyRequest) -> Result<RotateKeyResponse>;
+
+    /// Re-wrap a sealed data key under the latest master key version
+    async fn rewrap_data_key(&self, request: RewrapDataKeyRequest) -> Result<RewrapDataKeyResponse>;
+
     /// Health check
     async fn health_check(&self) -> Result<bool>;
 }
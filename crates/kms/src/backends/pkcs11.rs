@@ -0,0 +1,354 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! PKCS#11 KMS backend implementation
+//!
+//! Master keys are non-extractable AES-256 token objects on an HSM reached
+//! through its PKCS#11 module. Data keys are wrapped with CKM_AES_GCM on the
+//! token. Objects are identified by a random CKA_ID, hex-encoded, and carry
+//! the CKA_LABEL of their key version. Revoking a key clears its CKA_ENCRYPT
+//! so it can only unwrap existing data keys.
+
+use crate::backends::BackendInfo;
+use crate::backends::hsm::{HsmKmsBackend, KeyDevice, key_state_store};
+use crate::config::{KmsConfig, Pkcs11Config};
+use crate::error::{KmsError, Result};
+use async_trait::async_trait;
+use cryptoki::context::{CInitializeArgs, Pkcs11};
+use cryptoki::mechanism::Mechanism;
+use cryptoki::mechanism::aead::GcmParams;
+use cryptoki::object::{Attribute, KeyType, ObjectClass, ObjectHandle};
+use cryptoki::session::{Session, UserType};
+use cryptoki::types::AuthPin;
+use rand::RngExt;
+use std::sync::{Arc, Mutex};
+use tracing::info;
+
+/// AES-GCM IV length
+const GCM_IV_LEN: usize = 12;
+/// AES-GCM tag length in bits
+const GCM_TAG_BITS: u64 = 128;
+/// Length of the random CKA_ID given to each key
+const OBJECT_ID_LEN: usize = 16;
+
+fn pkcs11_error(op: &str, e: cryptoki::error::Error) -> KmsError {
+    KmsError::backend_error(format!("PKCS#11 {op} failed: {e}"))
+}
+
+fn encode_object_id(id: &[u8]) -> String {
+    id.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn decode_object_id(object_id: &str) -> Result<Vec<u8>> {
+    if object_id.len() % 2 != 0 {
+        return Err(KmsError::invalid_parameter(format!("Invalid PKCS#11 object id: {object_id}")));
+    }
+    (0..object_id.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&object_id[i..i + 2], 16)
+                .map_err(|_| KmsError::invalid_parameter(format!("Invalid PKCS#11 object id: {object_id}")))
+        })
+        .collect()
+}
+
+/// PKCS#11 token as a key device
+///
+/// A single logged-in read/write session is shared by all operations, which
+/// run on the blocking thread pool.
+pub struct Pkcs11Device {
+    session: Arc<Mutex<Session>>,
+    config: Pkcs11Config,
+    // Dropped after the session so the module stays initialized while it is open
+    _context: Pkcs11,
+}
+
+impl Pkcs11Device {
+    /// Load the PKCS#11 module and log in to the configured token
+    pub fn open(config: &Pkcs11Config) -> Result<Self> {
+        let context = Pkcs11::new(&config.module_path).map_err(|e| {
+            KmsError::configuration_error(format!("Failed to load PKCS#11 module {}: {e}", config.module_path.display()))
+        })?;
+        context
+            .initialize(CInitializeArgs::OsThreads)
+            .map_err(|e| pkcs11_error("C_Initialize", e))?;
+
+        let slots = context.get_slots_with_token().map_err(|e| pkcs11_error("C_GetSlotList", e))?;
+        let mut selected = None;
+        for slot in slots {
+            let matches = match (&config.token_label, config.slot_id) {
+                (_, Some(slot_id)) => slot.id() == slot_id,
+                (Some(label), None) => {
+                    let info = context.get_token_info(slot).map_err(|e| pkcs11_error("C_GetTokenInfo", e))?;
+                    info.label().trim_end() == label
+                }
+                (None, None) => false,
+            };
+            if matches {
+                selected = Some(slot);
+                break;
+            }
+        }
+        let slot = selected.ok_or_else(|| {
+            KmsError::configuration_error(format!(
+                "No PKCS#11 token found for label {:?} / slot {:?}",
+                config.token_label, config.slot_id
+            ))
+        })?;
+
+        let session = context.open_rw_session(slot).map_err(|e| pkcs11_error("C_OpenSession", e))?;
+        session
+            .login(UserType::User, Some(&AuthPin::new(config.pin.clone())))
+            .map_err(|e| pkcs11_error("C_Login", e))?;
+
+        info!("Logged in to PKCS#11 token in slot {} via {}", slot.id(), config.module_path.display());
+        Ok(Self {
+            session: Arc::new(Mutex::new(session)),
+            config: config.clone(),
+            _context: context,
+        })
+    }
+
+    /// Run a blocking operation against the session
+    async fn with_session<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Session) -> Result<T> + Send + 'static,
+    {
+        let session = Arc::clone(&self.session);
+        tokio::task::spawn_blocking(move || {
+            let session = session
+                .lock()
+                .map_err(|_| KmsError::backend_error("PKCS#11 session lock poisoned"))?;
+            f(&session)
+        })
+        .await
+        .map_err(|e| KmsError::backend_error(format!("PKCS#11 task failed: {e}")))?
+    }
+
+    fn find_object(session: &Session, object_id: &str) -> Result<ObjectHandle> {
+        let id = decode_object_id(object_id)?;
+        session
+            .find_objects(&[Attribute::Class(ObjectClass::SECRET_KEY), Attribute::Id(id)])
+            .map_err(|e| pkcs11_error("C_FindObjects", e))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| KmsError::key_not_found(object_id))
+    }
+}
+
+#[async_trait]
+impl KeyDevice for Pkcs11Device {
+    async fn create_key(&self, label: &str) -> Result<String> {
+        let label = label.as_bytes().to_vec();
+        self.with_session(move |session| {
+            let mut id = vec![0u8; OBJECT_ID_LEN];
+            rand::rng().fill(&mut id[..]);
+
+            let template = [
+                Attribute::Class(ObjectClass::SECRET_KEY),
+                Attribute::KeyType(KeyType::AES),
+                Attribute::ValueLen(32.into()),
+                Attribute::Token(true),
+                Attribute::Private(true),
+                Attribute::Sensitive(true),
+                Attribute::Extractable(false),
+                Attribute::Encrypt(true),
+                Attribute::Decrypt(true),
+                Attribute::Label(label),
+                Attribute::Id(id.clone()),
+            ];
+            session
+                .generate_key(&Mechanism::AesKeyGen, &template)
+                .map_err(|e| pkcs11_error("C_GenerateKey", e))?;
+            Ok(encode_object_id(&id))
+        })
+        .await
+    }
+
+    async fn rekey(&self, _object_id: &str, label: &str) -> Result<String> {
+        self.create_key(label).await
+    }
+
+    async fn wrap(&self, object_id: &str, plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+        let object_id = object_id.to_string();
+        let plaintext = zeroize::Zeroizing::new(plaintext.to_vec());
+        self.with_session(move |session| {
+            let key = Self::find_object(session, &object_id)?;
+            let mut iv = vec![0u8; GCM_IV_LEN];
+            rand::rng().fill(&mut iv[..]);
+
+            let mechanism = Mechanism::AesGcm(GcmParams::new(&iv, &[], GCM_TAG_BITS.into()));
+            let ciphertext = session
+                .encrypt(&mechanism, key, &plaintext)
+                .map_err(|e| pkcs11_error("C_Encrypt", e))?;
+            Ok((ciphertext, iv))
+        })
+        .await
+    }
+
+    async fn unwrap(&self, object_id: &str, ciphertext: &[u8], iv: &[u8]) -> Result<Vec<u8>> {
+        let object_id = object_id.to_string();
+        let ciphertext = ciphertext.to_vec();
+        let iv = iv.to_vec();
+        self.with_session(move |session| {
+            let key = Self::find_object(session, &object_id)?;
+            let mechanism = Mechanism::AesGcm(GcmParams::new(&iv, &[], GCM_TAG_BITS.into()));
+            session
+                .decrypt(&mechanism, key, &ciphertext)
+                .map_err(|e| KmsError::cryptographic_error("decrypt", e.to_string()))
+        })
+        .await
+    }
+
+    async fn revoke_key(&self, object_id: &str) -> Result<()> {
+        let object_id = object_id.to_string();
+        self.with_session(move |session| match Self::find_object(session, &object_id) {
+            Ok(key) => session
+                .update_attributes(key, &[Attribute::Encrypt(false)])
+                .map_err(|e| pkcs11_error("C_SetAttributeValue", e)),
+            Err(KmsError::KeyNotFound { .. }) => Ok(()),
+            Err(e) => Err(e),
+        })
+        .await
+    }
+
+    async fn destroy_key(&self, object_id: &str) -> Result<()> {
+        let object_id = object_id.to_string();
+        self.with_session(move |session| match Self::find_object(session, &object_id) {
+            Ok(key) => session.destroy_object(key).map_err(|e| pkcs11_error("C_DestroyObject", e)),
+            Err(KmsError::KeyNotFound { .. }) => Ok(()),
+            Err(e) => Err(e),
+        })
+        .await
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.with_session(|session| {
+            session
+                .get_session_info()
+                .map(|_| ())
+                .map_err(|e| pkcs11_error("C_GetSessionInfo", e))
+        })
+        .await
+    }
+
+    fn backend_info(&self) -> BackendInfo {
+        let mut info = BackendInfo::new(
+            "pkcs11".to_string(),
+            "0.1.0".to_string(),
+            self.config.module_path.display().to_string(),
+            true,
+        );
+        if let Some(label) = &self.config.token_label {
+            info = info.with_metadata("token_label".to_string(), label.clone());
+        }
+        if let Some(slot_id) = self.config.slot_id {
+            info = info.with_metadata("slot_id".to_string(), slot_id.to_string());
+        }
+        info
+    }
+}
+
+/// PKCS#11 backend
+pub type Pkcs11KmsBackend = HsmKmsBackend<Pkcs11Device>;
+
+impl HsmKmsBackend<Pkcs11Device> {
+    /// Create a new PKCS#11 backend
+    pub async fn new(config: KmsConfig) -> Result<Self> {
+        let pkcs11_config = match &config.backend_config {
+            crate::config::BackendConfig::Pkcs11(pkcs11_config) => (**pkcs11_config).clone(),
+            _ => return Err(KmsError::configuration_error("Expected PKCS#11 backend configuration")),
+        };
+
+        let store = key_state_store()?;
+        let device = tokio::task::spawn_blocking(move || Pkcs11Device::open(&pkcs11_config))
+            .await
+            .map_err(|e| KmsError::backend_error(format!("PKCS#11 task failed: {e}")))??;
+        Ok(Self::with_device(device, store, "PKCS11"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::KmsBackend;
+    use crate::types::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_object_id_encoding() {
+        let id = [0x00, 0x7f, 0xa5, 0xff];
+        assert_eq!(encode_object_id(&id), "007fa5ff");
+        assert_eq!(decode_object_id("007fa5ff").expect("valid id"), id);
+        assert!(decode_object_id("abc").is_err());
+        assert!(decode_object_id("zz").is_err());
+    }
+
+    /// Runs against SoftHSM or another PKCS#11 token configured through RUSTFS_KMS_PKCS11_* variables
+    #[tokio::test]
+    #[ignore] // Requires an initialized PKCS#11 token
+    async fn test_pkcs11_backend_integration() {
+        let pkcs11_config = Pkcs11Config::from_env().expect("PKCS#11 test configuration");
+
+        let config = KmsConfig {
+            backend: crate::config::KmsBackend::Pkcs11,
+            backend_config: crate::config::BackendConfig::Pkcs11(Box::new(pkcs11_config)),
+            ..Default::default()
+        };
+        crate::backends::hsm::set_key_state_store(Arc::new(crate::backends::hsm::tests::MemoryStore::default()));
+        let backend = Pkcs11KmsBackend::new(config).await.expect("Failed to create PKCS#11 backend");
+        assert!(backend.health_check().await.expect("Health check failed"));
+
+        let key_id = backend
+            .create_key(CreateKeyRequest::default())
+            .await
+            .expect("Failed to create key")
+            .key_id;
+        let data_key = backend
+            .generate_data_key(GenerateDataKeyRequest {
+                key_id: key_id.clone(),
+                key_spec: KeySpec::Aes256,
+                encryption_context: HashMap::new(),
+            })
+            .await
+            .expect("Failed to generate data key");
+
+        backend
+            .rotate_key(RotateKeyRequest { key_id: key_id.clone() })
+            .await
+            .expect("Failed to rotate key");
+
+        let decrypted = backend
+            .decrypt(DecryptRequest {
+                ciphertext: data_key.ciphertext_blob,
+                encryption_context: HashMap::new(),
+                grant_tokens: Vec::new(),
+            })
+            .await
+            .expect("Failed to decrypt data key");
+        assert_eq!(decrypted.plaintext, data_key.plaintext_key);
+
+        for _ in 0..2 {
+            backend
+                .delete_key(DeleteKeyRequest {
+                    key_id: key_id.clone(),
+                    pending_window_in_days: None,
+                    force_immediate: Some(true),
+                })
+                .await
+                .expect("Failed to delete key");
+        }
+    }
+}
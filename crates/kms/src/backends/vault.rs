@@ -30,6 +30,7 @@ use vaultrs::{
     client::{VaultClient, VaultClientSettingsBuilder},
     kv2,
 };
+use zeroize::Zeroize;
 
 /// Vault KMS client implementation
 pub struct VaultKmsClient {
@@ -64,39 +65,54 @@ struct VaultKeyData {
     tags: HashMap<String, String>,
     /// Encrypted key material (base64 encoded)
     encrypted_key_material: String,
+    /// Superseded key versions, oldest first, kept so that DEKs wrapped under them stay decryptable
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    previous_versions: Vec<VaultKeyVersion>,
 }
 
-impl VaultKmsClient {
-    /// Create a new Vault KMS client
-    pub async fn new(config: VaultConfig) -> Result<Self> {
-        // Create client settings
-        let mut settings_builder = VaultClientSettingsBuilder::default();
-        settings_builder.address(&config.address);
-
-        // Set authentication token based on method
-        let token = match &config.auth_method {
-            crate::config::VaultAuthMethod::Token { token } => token.clone(),
-            crate::config::VaultAuthMethod::AppRole { .. } => {
-                // For AppRole authentication, we would need to first authenticate
-                // and get a token. For simplicity, we'll require a token for now.
-                return Err(KmsError::backend_error(
-                    "AppRole authentication not yet implemented. Please use token authentication.",
-                ));
-            }
-        };
-
-        settings_builder.token(&token);
+/// Key material of a previous master key version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct VaultKeyVersion {
+    version: u32,
+    /// Encrypted key material (base64 encoded)
+    encrypted_key_material: String,
+}
 
-        if let Some(namespace) = &config.namespace {
-            settings_builder.namespace(Some(namespace.clone()));
+/// Build an authenticated Vault client from the backend configuration
+pub(crate) fn build_vault_client(config: &VaultConfig) -> Result<VaultClient> {
+    // Create client settings
+    let mut settings_builder = VaultClientSettingsBuilder::default();
+    settings_builder.address(&config.address);
+
+    // Set authentication token based on method
+    let token = match &config.auth_method {
+        crate::config::VaultAuthMethod::Token { token } => token.clone(),
+        crate::config::VaultAuthMethod::AppRole { .. } => {
+            // For AppRole authentication, we would need to first authenticate
+            // and get a token. For simplicity, we'll require a token for now.
+            return Err(KmsError::backend_error(
+                "AppRole authentication not yet implemented. Please use token authentication.",
+            ));
         }
+    };
+
+    settings_builder.token(&token);
+
+    if let Some(namespace) = &config.namespace {
+        settings_builder.namespace(Some(namespace.clone()));
+    }
 
-        let settings = settings_builder
-            .build()
-            .map_err(|e| KmsError::backend_error(format!("Failed to build Vault client settings: {e}")))?;
+    let settings = settings_builder
+        .build()
+        .map_err(|e| KmsError::backend_error(format!("Failed to build Vault client settings: {e}")))?;
 
-        let client =
-            VaultClient::new(settings).map_err(|e| KmsError::backend_error(format!("Failed to create Vault client: {e}")))?;
+    VaultClient::new(settings).map_err(|e| KmsError::backend_error(format!("Failed to create Vault client: {e}")))
+}
+
+impl VaultKmsClient {
+    /// Create a new Vault KMS client
+    pub async fn new(config: VaultConfig) -> Result<Self> {
+        let client = build_vault_client(&config)?;
 
         info!("Successfully connected to Vault at {}", config.address);
 
@@ -174,18 +190,52 @@ impl VaultKmsClient {
         Ok(key_material)
     }
 
-    /// Encrypt data using a master key
-    async fn encrypt_with_master_key(&self, key_id: &str, plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+    /// Encrypt data using the latest version of a master key, returning the version used
+    async fn encrypt_with_master_key(&self, key_id: &str, plaintext: &[u8]) -> Result<(Vec<u8>, Vec<u8>, u32)> {
         // Load the actual master key material
         let key_material = self.get_key_material(key_id).await?;
-        self.dek_crypto.encrypt(&key_material, plaintext).await
+        let version = self.get_key_data(key_id).await?.version;
+        let (ciphertext, nonce) = self.dek_crypto.encrypt(&key_material, plaintext).await?;
+        Ok((ciphertext, nonce, version))
     }
 
-    /// Decrypt data using a master key
-    async fn decrypt_with_master_key(&self, key_id: &str, ciphertext: &[u8], nonce: &[u8]) -> Result<Vec<u8>> {
+    /// Decrypt data using the given (or, if unknown, any) version of a master key
+    async fn decrypt_with_master_key(
+        &self,
+        key_id: &str,
+        version: Option<u32>,
+        ciphertext: &[u8],
+        nonce: &[u8],
+    ) -> Result<Vec<u8>> {
+        let key_data = self.get_key_data(key_id).await?;
+
+        if let Some(version) = version
+            && version != key_data.version
+        {
+            let previous = key_data
+                .previous_versions
+                .iter()
+                .find(|previous| previous.version == version)
+                .ok_or_else(|| KmsError::key_not_found(format!("{key_id} (version {version})")))?;
+            let key_material = self.decrypt_key_material(&previous.encrypted_key_material).await?;
+            return self.dek_crypto.decrypt(&key_material, ciphertext, nonce).await;
+        }
+
         // Load the actual master key material
         let key_material = self.get_key_material(key_id).await?;
-        self.dek_crypto.decrypt(&key_material, ciphertext, nonce).await
+        let result = self.dek_crypto.decrypt(&key_material, ciphertext, nonce).await;
+        if result.is_ok() || version.is_some() {
+            return result;
+        }
+
+        // Envelopes written before versioning do not record the version, try older material too
+        for previous in key_data.previous_versions.iter().rev() {
+            let key_material = self.decrypt_key_material(&previous.encrypted_key_material).await?;
+            if let Ok(plaintext) = self.dek_crypto.decrypt(&key_material, ciphertext, nonce).await {
+                return Ok(plaintext);
+            }
+        }
+        result
     }
 
     /// Store key data in Vault
@@ -226,6 +276,7 @@ impl VaultKmsClient {
             metadata: existing_key_data.metadata.clone(),
             tags: request.tags.clone(),
             encrypted_key_material: existing_key_data.encrypted_key_material.clone(), // Preserve the key material
+            previous_versions: existing_key_data.previous_versions.clone(),
         };
 
         debug!(
@@ -298,12 +349,13 @@ impl KmsClient for VaultKmsClient {
         let plaintext_key = generate_key_material(&request.key_spec)?;
 
         // Encrypt the data key with the master key
-        let (encrypted_key, nonce) = self.encrypt_with_master_key(&request.master_key_id, &plaintext_key).await?;
+        let (encrypted_key, nonce, version) = self.encrypt_with_master_key(&request.master_key_id, &plaintext_key).await?;
 
         // Create data key envelope with master key version for rotation support
         let envelope = DataKeyEnvelope {
             key_id: uuid::Uuid::new_v4().to_string(),
             master_key_id: request.master_key_id.clone(),
+            master_key_version: Some(version),
             key_spec: request.key_spec.clone(),
             encrypted_key: encrypted_key.clone(),
             nonce,
@@ -314,7 +366,7 @@ impl KmsClient for VaultKmsClient {
         // Serialize the envelope as the ciphertext
         let ciphertext = serde_json::to_vec(&envelope)?;
 
-        let data_key = DataKeyInfo::new(envelope.key_id, 1, Some(plaintext_key), ciphertext, request.key_spec.clone());
+        let data_key = DataKeyInfo::new(envelope.key_id, version, Some(plaintext_key), ciphertext, request.key_spec.clone());
 
         info!("Generated data key for master key: {}", request.master_key_id);
         Ok(data_key)
@@ -370,7 +422,12 @@ impl KmsClient for VaultKmsClient {
 
         // Decrypt the data key
         let plaintext = self
-            .decrypt_with_master_key(&envelope.master_key_id, &envelope.encrypted_key, &envelope.nonce)
+            .decrypt_with_master_key(
+                &envelope.master_key_id,
+                envelope.master_key_version,
+                &envelope.encrypted_key,
+                &envelope.nonce,
+            )
             .await?;
 
         info!("Successfully decrypted data");
@@ -400,6 +457,7 @@ impl KmsClient for VaultKmsClient {
             metadata: HashMap::new(),
             tags: HashMap::new(),
             encrypted_key_material: encrypted_material,
+            previous_versions: Vec::new(),
         };
 
         // Store in Vault
@@ -532,6 +590,12 @@ impl KmsClient for VaultKmsClient {
         debug!("Rotating key: {}", key_id);
 
         let mut key_data = self.get_key_data(key_id).await?;
+
+        // Keep the current material so DEKs wrapped under it remain decryptable
+        key_data.previous_versions.push(VaultKeyVersion {
+            version: key_data.version,
+            encrypted_key_material: key_data.encrypted_key_material.clone(),
+        });
         key_data.version += 1;
 
         // Generate new key material
@@ -814,6 +878,54 @@ impl KmsBackend for VaultKmsBackend {
         })
     }
 
+    async fn rotate_key(&self, request: RotateKeyRequest) -> Result<RotateKeyResponse> {
+        let master_key = self.client.rotate_key(&request.key_id, None).await?;
+
+        Ok(RotateKeyResponse {
+            key_id: master_key.key_id,
+            key_version: master_key.version,
+        })
+    }
+
+    async fn rewrap_data_key(&self, request: RewrapDataKeyRequest) -> Result<RewrapDataKeyResponse> {
+        let mut envelope: DataKeyEnvelope = serde_json::from_slice(&request.ciphertext_blob)
+            .map_err(|e| KmsError::cryptographic_error("parse", format!("Failed to parse data key envelope: {e}")))?;
+        let latest = self.client.get_key_data(&envelope.master_key_id).await?.version;
+
+        if envelope.master_key_version == Some(latest) {
+            return Ok(RewrapDataKeyResponse {
+                key_id: envelope.master_key_id,
+                key_version: latest,
+                ciphertext_blob: request.ciphertext_blob,
+                rewrapped: false,
+            });
+        }
+
+        let mut plaintext = self
+            .client
+            .decrypt_with_master_key(
+                &envelope.master_key_id,
+                envelope.master_key_version,
+                &envelope.encrypted_key,
+                &envelope.nonce,
+            )
+            .await?;
+        let wrapped = self.client.encrypt_with_master_key(&envelope.master_key_id, &plaintext).await;
+        plaintext.zeroize();
+        let (encrypted_key, nonce, version) = wrapped?;
+
+        envelope.encrypted_key = encrypted_key;
+        envelope.nonce = nonce;
+        envelope.master_key_version = Some(version);
+
+        Ok(RewrapDataKeyResponse {
+            ciphertext_blob: serde_json::to_vec(&envelope)?,
+            key_id: envelope.master_key_id,
+            key_version: version,
+            rewrapped: true,
+        })
+    }
+
     async fn health_check(&self) -> Result<bool> {
         self.client.health_check().await.map(|_| true)
     }
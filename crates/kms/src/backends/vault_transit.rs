@@ -0,0 +1,742 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Vault Transit KMS backend implementation
+//!
+//! Unlike the KV-based Vault backend, master key material is created and kept
+//! inside the Transit secrets engine. Data keys are generated, wrapped and
+//! unwrapped by Transit, so RustFS only ever sees plaintext data keys and
+//! Transit ciphertexts. The KV engine is still used for key metadata (status,
+//! description, tags), but never for key material.
+
+use crate::backends::vault::build_vault_client;
+use crate::backends::{BackendInfo, KmsBackend, KmsClient};
+use crate::config::{KmsConfig, VaultConfig};
+use crate::encryption::DataKeyEnvelope;
+use crate::error::{KmsError, Result};
+use crate::types::*;
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose};
+use jiff::Zoned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+use vaultrs::{
+    api::transit::requests::{
+        CreateKeyRequest as TransitCreateKeyRequest, DataKeyType, GenerateDataKeyRequest as TransitDataKeyRequest, KeyType,
+        UpdateKeyConfigurationRequest,
+    },
+    client::VaultClient,
+    error::ClientError,
+    kv2, transit,
+};
+
+/// Key metadata stored in the KV engine. Key material lives in Transit only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TransitKeyData {
+    /// Key algorithm
+    algorithm: String,
+    /// Key usage type
+    usage: KeyUsage,
+    /// Key creation timestamp
+    created_at: Zoned,
+    /// Last rotation timestamp
+    rotated_at: Option<Zoned>,
+    /// Key status
+    status: KeyStatus,
+    /// Latest Transit key version
+    version: u32,
+    /// Key description
+    description: Option<String>,
+    /// Key metadata
+    metadata: HashMap<String, String>,
+    /// Key tags
+    tags: HashMap<String, String>,
+}
+
+/// Extract the key version from a Transit ciphertext (`vault:v<N>:<base64>`)
+fn transit_key_version(ciphertext: &str) -> Option<u32> {
+    let mut parts = ciphertext.splitn(3, ':');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some("vault"), Some(version), Some(_)) => version.strip_prefix('v')?.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Map a Transit error for a named key to a KMS error
+fn map_transit_error(key_id: &str, op: &str, e: ClientError) -> KmsError {
+    match e {
+        ClientError::APIError { code: 404, .. } => KmsError::key_not_found(key_id),
+        ClientError::APIError { code: 400, .. } => KmsError::cryptographic_error(op, e.to_string()),
+        _ => KmsError::backend_error(format!("Vault Transit {op} failed for key {key_id}: {e}")),
+    }
+}
+
+/// Vault Transit KMS client implementation
+pub struct VaultTransitKmsClient {
+    client: VaultClient,
+    config: VaultConfig,
+    /// Mount path for the Transit engine
+    transit_mount: String,
+    /// Mount path for the KV engine holding key metadata
+    kv_mount: String,
+    /// Path prefix for key metadata
+    key_path_prefix: String,
+}
+
+impl VaultTransitKmsClient {
+    /// Create a new Vault Transit KMS client
+    pub async fn new(config: VaultConfig) -> Result<Self> {
+        let client = build_vault_client(&config)?;
+
+        info!(
+            "Successfully connected to Vault Transit at {} (mount {})",
+            config.address, config.mount_path
+        );
+
+        Ok(Self {
+            client,
+            transit_mount: config.mount_path.clone(),
+            kv_mount: config.kv_mount.clone(),
+            key_path_prefix: config.key_path_prefix.clone(),
+            config,
+        })
+    }
+
+    /// Get the full KV path for a key's metadata
+    fn key_path(&self, key_id: &str) -> String {
+        format!("{}/{}", self.key_path_prefix, key_id)
+    }
+
+    /// Store key metadata in the KV engine
+    async fn store_key_data(&self, key_id: &str, key_data: &TransitKeyData) -> Result<()> {
+        let path = self.key_path(key_id);
+
+        kv2::set(&self.client, &self.kv_mount, &path, key_data)
+            .await
+            .map_err(|e| KmsError::backend_error(format!("Failed to store key metadata in Vault: {e}")))?;
+
+        debug!("Stored key {} metadata in Vault at path {}", key_id, path);
+        Ok(())
+    }
+
+    /// Retrieve key metadata from the KV engine
+    async fn get_key_data(&self, key_id: &str) -> Result<TransitKeyData> {
+        let path = self.key_path(key_id);
+
+        kv2::read(&self.client, &self.kv_mount, &path).await.map_err(|e| match e {
+            ClientError::ResponseWrapError => KmsError::key_not_found(key_id),
+            ClientError::APIError { code: 404, .. } => KmsError::key_not_found(key_id),
+            _ => KmsError::backend_error(format!("Failed to read key metadata from Vault: {e}")),
+        })
+    }
+
+    /// Load key metadata and ensure the key may be used for new encryptions
+    async fn get_active_key_data(&self, key_id: &str) -> Result<TransitKeyData> {
+        let key_data = self.get_key_data(key_id).await?;
+        if key_data.status != KeyStatus::Active {
+            return Err(KmsError::invalid_key_state(format!("Key {key_id} is not active: {:?}", key_data.status)));
+        }
+        Ok(key_data)
+    }
+
+    async fn store_key_metadata(&self, key_id: &str, request: &CreateKeyRequest) -> Result<()> {
+        let mut key_data = self.get_key_data(key_id).await?;
+        key_data.usage = request.key_usage.clone();
+        key_data.description = request.description.clone();
+        key_data.tags = request.tags.clone();
+        self.store_key_data(key_id, &key_data).await
+    }
+
+    /// List all keys with metadata in the KV engine
+    async fn list_vault_keys(&self) -> Result<Vec<String>> {
+        match kv2::list(&self.client, &self.kv_mount, &self.key_path_prefix).await {
+            Ok(keys) => Ok(keys),
+            Err(ClientError::ResponseWrapError) | Err(ClientError::APIError { code: 404, .. }) => Ok(Vec::new()),
+            Err(e) => Err(KmsError::backend_error(format!("Failed to list keys in Vault: {e}"))),
+        }
+    }
+
+    /// Permanently delete a key from Transit and its metadata from KV
+    async fn delete_key(&self, key_id: &str) -> Result<()> {
+        // Transit refuses to delete keys unless deletion is explicitly allowed
+        transit::key::update(
+            &self.client,
+            &self.transit_mount,
+            key_id,
+            Some(UpdateKeyConfigurationRequest::builder().deletion_allowed(true)),
+        )
+        .await
+        .map_err(|e| map_transit_error(key_id, "update", e))?;
+
+        transit::key::delete(&self.client, &self.transit_mount, key_id)
+            .await
+            .map_err(|e| map_transit_error(key_id, "delete", e))?;
+
+        kv2::delete_metadata(&self.client, &self.kv_mount, &self.key_path(key_id))
+            .await
+            .map_err(|e| match e {
+                ClientError::APIError { code: 404, .. } => KmsError::key_not_found(key_id),
+                _ => KmsError::backend_error(format!("Failed to delete key metadata from Vault: {e}")),
+            })?;
+
+        debug!("Permanently deleted Transit key {}", key_id);
+        Ok(())
+    }
+
+    /// Wrap plaintext with a Transit key, returning the Transit ciphertext
+    async fn transit_encrypt(&self, key_id: &str, plaintext: &[u8]) -> Result<String> {
+        let encoded = general_purpose::STANDARD.encode(plaintext);
+        let response = transit::data::encrypt(&self.client, &self.transit_mount, key_id, &encoded, None)
+            .await
+            .map_err(|e| map_transit_error(key_id, "encrypt", e))?;
+        Ok(response.ciphertext)
+    }
+
+    /// Unwrap a Transit ciphertext with a Transit key
+    async fn transit_decrypt(&self, key_id: &str, ciphertext: &str) -> Result<Vec<u8>> {
+        let response = transit::data::decrypt(&self.client, &self.transit_mount, key_id, ciphertext, None)
+            .await
+            .map_err(|e| map_transit_error(key_id, "decrypt", e))?;
+        general_purpose::STANDARD
+            .decode(response.plaintext)
+            .map_err(|e| KmsError::cryptographic_error("decrypt", e.to_string()))
+    }
+
+    /// Build a serialized envelope around a Transit ciphertext
+    fn seal_envelope(
+        master_key_id: &str,
+        key_spec: &str,
+        ciphertext: &str,
+        encryption_context: &HashMap<String, String>,
+    ) -> Result<(String, Vec<u8>)> {
+        let envelope = DataKeyEnvelope {
+            key_id: uuid::Uuid::new_v4().to_string(),
+            master_key_id: master_key_id.to_string(),
+            master_key_version: transit_key_version(ciphertext),
+            key_spec: key_spec.to_string(),
+            encrypted_key: ciphertext.as_bytes().to_vec(),
+            // Transit ciphertexts are self-describing, no nonce is needed
+            nonce: Vec::new(),
+            encryption_context: encryption_context.clone(),
+            created_at: Zoned::now(),
+        };
+        Ok((envelope.key_id.clone(), serde_json::to_vec(&envelope)?))
+    }
+}
+
+#[async_trait]
+impl KmsClient for VaultTransitKmsClient {
+    async fn generate_data_key(&self, request: &GenerateKeyRequest, _context: Option<&OperationContext>) -> Result<DataKeyInfo> {
+        debug!("Generating data key via Transit for master key: {}", request.master_key_id);
+
+        self.get_active_key_data(&request.master_key_id).await?;
+
+        let bits: u16 = match request.key_spec.as_str() {
+            "AES_256" => 256,
+            "AES_128" => 128,
+            other => return Err(KmsError::unsupported_algorithm(other)),
+        };
+
+        let response = transit::generate::data_key(
+            &self.client,
+            &self.transit_mount,
+            &request.master_key_id,
+            DataKeyType::Plaintext,
+            Some(TransitDataKeyRequest::builder().bits(bits)),
+        )
+        .await
+        .map_err(|e| map_transit_error(&request.master_key_id, "datakey", e))?;
+
+        let plaintext = response
+            .plaintext
+            .ok_or_else(|| KmsError::backend_error("Vault Transit did not return a plaintext data key"))?;
+        let plaintext_key = general_purpose::STANDARD
+            .decode(plaintext)
+            .map_err(|e| KmsError::cryptographic_error("datakey", e.to_string()))?;
+
+        let version = transit_key_version(&response.ciphertext).unwrap_or(1);
+        let (data_key_id, ciphertext) = Self::seal_envelope(
+            &request.master_key_id,
+            &request.key_spec,
+            &response.ciphertext,
+            &request.encryption_context,
+        )?;
+
+        info!("Generated data key via Transit for master key: {}", request.master_key_id);
+        Ok(DataKeyInfo::new(
+            data_key_id,
+            version,
+            Some(plaintext_key),
+            ciphertext,
+            request.key_spec.clone(),
+        ))
+    }
+
+    async fn encrypt(&self, request: &EncryptRequest, _context: Option<&OperationContext>) -> Result<EncryptResponse> {
+        debug!("Encrypting data via Transit with key: {}", request.key_id);
+
+        let key_data = self.get_active_key_data(&request.key_id).await?;
+        let transit_ciphertext = self.transit_encrypt(&request.key_id, &request.plaintext).await?;
+        let key_version = transit_key_version(&transit_ciphertext).unwrap_or(key_data.version);
+        let (_, ciphertext) =
+            Self::seal_envelope(&request.key_id, &key_data.algorithm, &transit_ciphertext, &request.encryption_context)?;
+
+        Ok(EncryptResponse {
+            ciphertext,
+            key_id: request.key_id.clone(),
+            key_version,
+            algorithm: key_data.algorithm,
+        })
+    }
+
+    async fn decrypt(&self, request: &DecryptRequest, _context: Option<&OperationContext>) -> Result<Vec<u8>> {
+        debug!("Decrypting data via Transit");
+
+        let envelope: DataKeyEnvelope = serde_json::from_slice(&request.ciphertext)
+            .map_err(|e| KmsError::cryptographic_error("parse", format!("Failed to parse data key envelope: {e}")))?;
+
+        // Same context rules as the KV-based Vault backend
+        for (key, expected_value) in &envelope.encryption_context {
+            if let Some(actual_value) = request.encryption_context.get(key) {
+                if actual_value != expected_value {
+                    return Err(KmsError::context_mismatch(format!(
+                        "Context mismatch for key '{key}': expected '{expected_value}', got '{actual_value}'"
+                    )));
+                }
+            } else if !request.encryption_context.is_empty() {
+                return Err(KmsError::context_mismatch(format!("Missing context key '{key}'")));
+            }
+        }
+
+        let transit_ciphertext = std::str::from_utf8(&envelope.encrypted_key)
+            .map_err(|e| KmsError::cryptographic_error("decrypt", format!("Invalid Transit ciphertext: {e}")))?;
+
+        let plaintext = self.transit_decrypt(&envelope.master_key_id, transit_ciphertext).await?;
+
+        info!("Successfully decrypted data via Transit");
+        Ok(plaintext)
+    }
+
+    async fn create_key(&self, key_id: &str, algorithm: &str, _context: Option<&OperationContext>) -> Result<MasterKeyInfo> {
+        debug!("Creating Transit master key: {} with algorithm: {}", key_id, algorithm);
+
+        if self.get_key_data(key_id).await.is_ok() {
+            return Err(KmsError::key_already_exists(key_id));
+        }
+
+        let key_type = match algorithm {
+            "AES_256" => KeyType::Aes256Gcm96,
+            "AES_128" => KeyType::Aes128Gcm96,
+            other => return Err(KmsError::unsupported_algorithm(other)),
+        };
+
+        transit::key::create(
+            &self.client,
+            &self.transit_mount,
+            key_id,
+            Some(TransitCreateKeyRequest::builder().key_type(key_type).exportable(false)),
+        )
+        .await
+        .map_err(|e| map_transit_error(key_id, "create", e))?;
+
+        let key_data = TransitKeyData {
+            algorithm: algorithm.to_string(),
+            usage: KeyUsage::EncryptDecrypt,
+            created_at: Zoned::now(),
+            rotated_at: None,
+            status: KeyStatus::Active,
+            version: 1,
+            description: None,
+            metadata: HashMap::new(),
+            tags: HashMap::new(),
+        };
+        self.store_key_data(key_id, &key_data).await?;
+
+        info!("Successfully created Transit master key: {}", key_id);
+        Ok(MasterKeyInfo {
+            key_id: key_id.to_string(),
+            version: key_data.version,
+            algorithm: key_data.algorithm,
+            usage: key_data.usage,
+            status: key_data.status,
+            description: None,
+            metadata: key_data.metadata,
+            created_at: key_data.created_at,
+            rotated_at: None,
+            created_by: None,
+        })
+    }
+
+    async fn describe_key(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<KeyInfo> {
+        let key_data = self.get_key_data(key_id).await?;
+
+        Ok(KeyInfo {
+            key_id: key_id.to_string(),
+            description: key_data.description,
+            algorithm: key_data.algorithm,
+            usage: key_data.usage,
+            status: key_data.status,
+            version: key_data.version,
+            metadata: key_data.metadata,
+            tags: key_data.tags,
+            created_at: key_data.created_at,
+            rotated_at: key_data.rotated_at,
+            created_by: None,
+        })
+    }
+
+    async fn list_keys(&self, request: &ListKeysRequest, _context: Option<&OperationContext>) -> Result<ListKeysResponse> {
+        let all_keys = self.list_vault_keys().await?;
+        let limit = request.limit.unwrap_or(100) as usize;
+
+        let start_idx = request
+            .marker
+            .as_ref()
+            .and_then(|m| all_keys.iter().position(|k| k == m))
+            .map(|idx| idx + 1)
+            .unwrap_or(0);
+        let end_idx = std::cmp::min(start_idx + limit, all_keys.len());
+
+        let mut key_infos = Vec::new();
+        for key_id in &all_keys[start_idx..end_idx] {
+            if let Ok(key_info) = self.describe_key(key_id, None).await {
+                key_infos.push(key_info);
+            }
+        }
+
+        let truncated = end_idx < all_keys.len();
+        Ok(ListKeysResponse {
+            keys: key_infos,
+            next_marker: if truncated {
+                Some(all_keys[end_idx - 1].clone())
+            } else {
+                None
+            },
+            truncated,
+        })
+    }
+
+    async fn enable_key(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<()> {
+        let mut key_data = self.get_key_data(key_id).await?;
+        key_data.status = KeyStatus::Active;
+        self.store_key_data(key_id, &key_data).await
+    }
+
+    async fn disable_key(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<()> {
+        let mut key_data = self.get_key_data(key_id).await?;
+        key_data.status = KeyStatus::Disabled;
+        self.store_key_data(key_id, &key_data).await
+    }
+
+    async fn schedule_key_deletion(
+        &self,
+        key_id: &str,
+        _pending_window_days: u32,
+        _context: Option<&OperationContext>,
+    ) -> Result<()> {
+        let mut key_data = self.get_key_data(key_id).await?;
+        key_data.status = KeyStatus::PendingDeletion;
+        self.store_key_data(key_id, &key_data).await
+    }
+
+    async fn cancel_key_deletion(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<()> {
+        let mut key_data = self.get_key_data(key_id).await?;
+        key_data.status = KeyStatus::Active;
+        self.store_key_data(key_id, &key_data).await
+    }
+
+    async fn rotate_key(&self, key_id: &str, _context: Option<&OperationContext>) -> Result<MasterKeyInfo> {
+        debug!("Rotating Transit key: {}", key_id);
+
+        let mut key_data = self.get_key_data(key_id).await?;
+
+        // Transit keeps every previous version available for decryption
+        transit::key::rotate(&self.client, &self.transit_mount, key_id)
+            .await
+            .map_err(|e| map_transit_error(key_id, "rotate", e))?;
+
+        let latest = transit::key::read(&self.client, &self.transit_mount, key_id)
+            .await
+            .map_err(|e| map_transit_error(key_id, "read", e))?;
+
+        key_data.version = u32::try_from(latest.latest_version).unwrap_or(key_data.version + 1);
+        key_data.rotated_at = Some(Zoned::now());
+        self.store_key_data(key_id, &key_data).await?;
+
+        info!("Successfully rotated Transit key: {} (version {})", key_id, key_data.version);
+        Ok(MasterKeyInfo {
+            key_id: key_id.to_string(),
+            version: key_data.version,
+            algorithm: key_data.algorithm,
+            usage: key_data.usage,
+            status: key_data.status,
+            description: key_data.description,
+            metadata: key_data.metadata,
+            created_at: key_data.created_at,
+            rotated_at: key_data.rotated_at,
+            created_by: None,
+        })
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        match self.list_vault_keys().await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                warn!("Vault Transit health check failed: {}", e);
+                Err(e)
+            }
+        }
+    }
+
+    fn backend_info(&self) -> BackendInfo {
+        BackendInfo::new("vault-transit".to_string(), "0.1.0".to_string(), self.config.address.clone(), true)
+            .with_metadata("transit_mount".to_string(), self.transit_mount.clone())
+            .with_metadata("kv_mount".to_string(), self.kv_mount.clone())
+            .with_metadata("key_prefix".to_string(), self.key_path_prefix.clone())
+    }
+}
+
+/// VaultTransitKmsBackend wraps VaultTransitKmsClient and implements the KmsBackend trait
+pub struct VaultTransitKmsBackend {
+    client: VaultTransitKmsClient,
+}
+
+impl VaultTransitKmsBackend {
+    /// Create a new VaultTransitKmsBackend
+    pub async fn new(config: KmsConfig) -> Result<Self> {
+        let vault_config = match &config.backend_config {
+            crate::config::BackendConfig::VaultTransit(vault_config) => (**vault_config).clone(),
+            _ => return Err(KmsError::configuration_error("Expected Vault Transit backend configuration")),
+        };
+
+        let client = VaultTransitKmsClient::new(vault_config).await?;
+        Ok(Self { client })
+    }
+
+    fn key_metadata(key_id: String, key_data: TransitKeyData, deletion_date: Option<Zoned>) -> KeyMetadata {
+        KeyMetadata {
+            key_id,
+            key_state: match key_data.status {
+                KeyStatus::Active => KeyState::Enabled,
+                KeyStatus::Disabled => KeyState::Disabled,
+                KeyStatus::PendingDeletion => KeyState::PendingDeletion,
+                KeyStatus::Deleted => KeyState::Unavailable,
+            },
+            key_usage: key_data.usage,
+            description: key_data.description,
+            creation_date: key_data.created_at,
+            deletion_date,
+            origin: "VAULT".to_string(),
+            key_manager: "VAULT".to_string(),
+            tags: key_data.tags,
+        }
+    }
+}
+
+#[async_trait]
+impl KmsBackend for VaultTransitKmsBackend {
+    async fn create_key(&self, request: CreateKeyRequest) -> Result<CreateKeyResponse> {
+        let key_id = request.key_name.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+        self.client.create_key(&key_id, "AES_256", None).await?;
+        self.client.store_key_metadata(&key_id, &request).await?;
+
+        let key_data = self.client.get_key_data(&key_id).await?;
+        Ok(CreateKeyResponse {
+            key_metadata: Self::key_metadata(key_id.clone(), key_data, None),
+            key_id,
+        })
+    }
+
+    async fn encrypt(&self, request: EncryptRequest) -> Result<EncryptResponse> {
+        self.client.encrypt(&request, None).await
+    }
+
+    async fn decrypt(&self, request: DecryptRequest) -> Result<DecryptResponse> {
+        let envelope: DataKeyEnvelope = serde_json::from_slice(&request.ciphertext)
+            .map_err(|e| KmsError::cryptographic_error("parse", format!("Failed to parse data key envelope: {e}")))?;
+        let plaintext = self.client.decrypt(&request, None).await?;
+
+        Ok(DecryptResponse {
+            plaintext,
+            key_id: envelope.master_key_id,
+            encryption_algorithm: Some("AES-256-GCM".to_string()),
+        })
+    }
+
+    async fn generate_data_key(&self, request: GenerateDataKeyRequest) -> Result<GenerateDataKeyResponse> {
+        let generate_request = GenerateKeyRequest {
+            master_key_id: request.key_id.clone(),
+            key_spec: request.key_spec.as_str().to_string(),
+            key_length: Some(request.key_spec.key_size() as u32),
+            encryption_context: request.encryption_context,
+            grant_tokens: Vec::new(),
+        };
+
+        let data_key = self.client.generate_data_key(&generate_request, None).await?;
+
+        Ok(GenerateDataKeyResponse {
+            key_id: request.key_id,
+            plaintext_key: data_key.plaintext.clone().unwrap_or_default(),
+            ciphertext_blob: data_key.ciphertext.clone(),
+        })
+    }
+
+    async fn describe_key(&self, request: DescribeKeyRequest) -> Result<DescribeKeyResponse> {
+        let key_data = self.client.get_key_data(&request.key_id).await?;
+        Ok(DescribeKeyResponse {
+            key_metadata: Self::key_metadata(request.key_id, key_data, None),
+        })
+    }
+
+    async fn list_keys(&self, request: ListKeysRequest) -> Result<ListKeysResponse> {
+        self.client.list_keys(&request, None).await
+    }
+
+    async fn delete_key(&self, request: DeleteKeyRequest) -> Result<DeleteKeyResponse> {
+        let key_id = &request.key_id;
+        let key_data = self.client.get_key_data(key_id).await?;
+
+        if request.force_immediate.unwrap_or(false) {
+            if key_data.status == KeyStatus::PendingDeletion {
+                // Destroys the Transit key; data wrapped under it can no longer be decrypted
+                self.client.delete_key(key_id).await?;
+                return Ok(DeleteKeyResponse {
+                    key_id: key_id.clone(),
+                    deletion_date: None,
+                    key_metadata: Self::key_metadata(key_id.clone(), key_data, None),
+                });
+            }
+
+            self.client.schedule_key_deletion(key_id, 0, None).await?;
+            let key_data = self.client.get_key_data(key_id).await?;
+            return Ok(DeleteKeyResponse {
+                key_id: key_id.clone(),
+                deletion_date: None,
+                key_metadata: Self::key_metadata(key_id.clone(), key_data, Some(Zoned::now())),
+            });
+        }
+
+        let days = request.pending_window_in_days.unwrap_or(30);
+        if !(7..=30).contains(&days) {
+            return Err(KmsError::invalid_parameter("pending_window_in_days must be between 7 and 30".to_string()));
+        }
+
+        self.client.schedule_key_deletion(key_id, days, None).await?;
+        let key_data = self.client.get_key_data(key_id).await?;
+        let deletion_date = Zoned::now() + Duration::from_secs(days as u64 * 86400);
+
+        Ok(DeleteKeyResponse {
+            key_id: key_id.clone(),
+            deletion_date: Some(deletion_date.to_string()),
+            key_metadata: Self::key_metadata(key_id.clone(), key_data, Some(deletion_date)),
+        })
+    }
+
+    async fn cancel_key_deletion(&self, request: CancelKeyDeletionRequest) -> Result<CancelKeyDeletionResponse> {
+        let key_id = &request.key_id;
+        let key_data = self.client.get_key_data(key_id).await?;
+
+        if key_data.status != KeyStatus::PendingDeletion {
+            return Err(KmsError::invalid_key_state(format!("Key {key_id} is not pending deletion")));
+        }
+
+        self.client.cancel_key_deletion(key_id, None).await?;
+        let key_data = self.client.get_key_data(key_id).await?;
+
+        Ok(CancelKeyDeletionResponse {
+            key_id: key_id.clone(),
+            key_metadata: Self::key_metadata(key_id.clone(), key_data, None),
+        })
+    }
+
+    async fn rotate_key(&self, request: RotateKeyRequest) -> Result<RotateKeyResponse> {
+        let master_key = self.client.rotate_key(&request.key_id, None).await?;
+
+        Ok(RotateKeyResponse {
+            key_id: master_key.key_id,
+            key_version: master_key.version,
+        })
+    }
+
+    async fn rewrap_data_key(&self, request: RewrapDataKeyRequest) -> Result<RewrapDataKeyResponse> {
+        let mut envelope: DataKeyEnvelope = serde_json::from_slice(&request.ciphertext_blob)
+            .map_err(|e| KmsError::cryptographic_error("parse", format!("Failed to parse data key envelope: {e}")))?;
+        let master_key_id = envelope.master_key_id.clone();
+        let latest = self.client.get_key_data(&master_key_id).await?.version;
+
+        if envelope.master_key_version == Some(latest) {
+            return Ok(RewrapDataKeyResponse {
+                key_id: master_key_id,
+                key_version: latest,
+                ciphertext_blob: request.ciphertext_blob,
+                rewrapped: false,
+            });
+        }
+
+        let transit_ciphertext = std::str::from_utf8(&envelope.encrypted_key)
+            .map_err(|e| KmsError::cryptographic_error("rewrap", format!("Invalid Transit ciphertext: {e}")))?;
+
+        // Transit re-encrypts under the latest key version without exposing the plaintext
+        let response =
+            transit::data::rewrap(&self.client.client, &self.client.transit_mount, &master_key_id, transit_ciphertext, None)
+                .await
+                .map_err(|e| map_transit_error(&master_key_id, "rewrap", e))?;
+
+        let version = transit_key_version(&response.ciphertext).unwrap_or(latest);
+        envelope.encrypted_key = response.ciphertext.into_bytes();
+        envelope.master_key_version = Some(version);
+
+        Ok(RewrapDataKeyResponse {
+            ciphertext_blob: serde_json::to_vec(&envelope)?,
+            key_id: master_key_id,
+            key_version: version,
+            rewrapped: true,
+        })
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        self.client.health_check().await.map(|_| true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transit_key_version() {
+        assert_eq!(transit_key_version("vault:v1:AbCd=="), Some(1));
+        assert_eq!(transit_key_version("vault:v12:AbCd:=="), Some(12));
+        assert_eq!(transit_key_version("vault:1:AbCd=="), None);
+        assert_eq!(transit_key_version("v1:AbCd=="), None);
+        assert_eq!(transit_key_version(""), None);
+    }
+
+    #[test]
+    fn test_seal_envelope_keeps_transit_ciphertext() {
+        let context = HashMap::from([("bucket".to_string(), "b".to_string())]);
+        let (key_id, sealed) =
+            VaultTransitKmsClient::seal_envelope("master", "AES_256", "vault:v2:abc", &context).expect("seal envelope");
+
+        let envelope: DataKeyEnvelope = serde_json::from_slice(&sealed).expect("parse envelope");
+        assert_eq!(envelope.key_id, key_id);
+        assert_eq!(envelope.master_key_id, "master");
+        assert_eq!(envelope.encrypted_key, b"vault:v2:abc");
+        assert!(envelope.nonce.is_empty());
+        assert_eq!(envelope.encryption_context, context);
+    }
+}
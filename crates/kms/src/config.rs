@@ -25,6 +25,12 @@ use url::Url;
 pub enum KmsBackend {
     /// Vault backend (recommended for production)
     Vault,
+    /// Vault Transit backend, master keys never leave Vault
+    VaultTransit,
+    /// KMIP 1.4/2.0 server, master keys never leave the key server
+    Kmip,
+    /// PKCS#11 hardware security module, master keys never leave the HSM
+    Pkcs11,
     /// Local file-based backend for development and testing only
     #[default]
     Local,
@@ -70,6 +76,12 @@ pub enum BackendConfig {
     Local(LocalConfig),
     /// Vault backend configuration
     Vault(Box<VaultConfig>),
+    /// Vault Transit backend configuration
+    VaultTransit(Box<VaultConfig>),
+    /// KMIP backend configuration
+    Kmip(Box<KmipConfig>),
+    /// PKCS#11 backend configuration
+    Pkcs11(Box<Pkcs11Config>),
 }
 
 impl Default for BackendConfig {
@@ -156,6 +168,111 @@ pub struct TlsConfig {
     pub skip_verify: bool,
 }
 
+/// KMIP protocol versions supported by the KMIP backend
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum KmipProtocolVersion {
+    /// KMIP 1.4
+    #[default]
+    V1_4,
+    /// KMIP 2.0
+    V2_0,
+}
+
+impl KmipProtocolVersion {
+    /// Major and minor protocol version numbers
+    pub fn as_tuple(&self) -> (i32, i32) {
+        match self {
+            Self::V1_4 => (1, 4),
+            Self::V2_0 => (2, 0),
+        }
+    }
+}
+
+impl std::str::FromStr for KmipProtocolVersion {
+    type Err = KmsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1.4" => Ok(Self::V1_4),
+            "2.0" => Ok(Self::V2_0),
+            _ => Err(KmsError::configuration_error(format!("Unsupported KMIP protocol version: {s}"))),
+        }
+    }
+}
+
+/// KMIP backend configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KmipConfig {
+    /// KMIP server address (host:port, usually port 5696)
+    pub endpoint: String,
+    /// TLS server name, defaults to the endpoint host
+    pub server_name: Option<String>,
+    /// KMIP protocol version
+    pub protocol_version: KmipProtocolVersion,
+    /// Path to the CA certificate used to verify the server
+    pub ca_cert_path: PathBuf,
+    /// Path to the client certificate used for mutual TLS
+    pub client_cert_path: PathBuf,
+    /// Path to the client private key used for mutual TLS
+    pub client_key_path: PathBuf,
+}
+
+impl KmipConfig {
+    /// Load KMIP configuration from `RUSTFS_KMS_KMIP_*` environment variables
+    pub fn from_env() -> Result<Self> {
+        let require = |name: &str| {
+            std::env::var(name).map_err(|_| KmsError::configuration_error(format!("{name} is required for the KMIP backend")))
+        };
+
+        Ok(Self {
+            endpoint: require("RUSTFS_KMS_KMIP_ENDPOINT")?,
+            server_name: std::env::var("RUSTFS_KMS_KMIP_SERVER_NAME").ok(),
+            protocol_version: match std::env::var("RUSTFS_KMS_KMIP_PROTOCOL_VERSION") {
+                Ok(version) => version.parse()?,
+                Err(_) => KmipProtocolVersion::default(),
+            },
+            ca_cert_path: PathBuf::from(require("RUSTFS_KMS_KMIP_CA_CERT")?),
+            client_cert_path: PathBuf::from(require("RUSTFS_KMS_KMIP_CLIENT_CERT")?),
+            client_key_path: PathBuf::from(require("RUSTFS_KMS_KMIP_CLIENT_KEY")?),
+        })
+    }
+}
+
+/// PKCS#11 backend configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pkcs11Config {
+    /// Path to the PKCS#11 module (e.g. libsofthsm2.so)
+    pub module_path: PathBuf,
+    /// Label of the token holding the master keys
+    pub token_label: Option<String>,
+    /// Slot holding the master keys, used when no token label is given
+    pub slot_id: Option<u64>,
+    /// User PIN for the token
+    pub pin: String,
+}
+
+impl Pkcs11Config {
+    /// Load PKCS#11 configuration from `RUSTFS_KMS_PKCS11_*` environment variables
+    pub fn from_env() -> Result<Self> {
+        let require = |name: &str| {
+            std::env::var(name).map_err(|_| KmsError::configuration_error(format!("{name} is required for the PKCS#11 backend")))
+        };
+
+        Ok(Self {
+            module_path: PathBuf::from(require("RUSTFS_KMS_PKCS11_MODULE")?),
+            token_label: std::env::var("RUSTFS_KMS_PKCS11_TOKEN_LABEL").ok(),
+            slot_id: match std::env::var("RUSTFS_KMS_PKCS11_SLOT_ID") {
+                Ok(slot) => Some(
+                    slot.parse()
+                        .map_err(|_| KmsError::configuration_error("Invalid PKCS#11 slot ID"))?,
+                ),
+                Err(_) => None,
+            },
+            pin: require("RUSTFS_KMS_PKCS11_PIN")?,
+        })
+    }
+}
+
 /// Cache configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheConfig {
@@ -216,6 +333,19 @@ impl KmsConfig {
         }
     }
 
+    /// Create a new KMS configuration for the Vault Transit backend with token authentication
+    pub fn vault_transit(address: Url, token: String) -> Self {
+        Self {
+            backend: KmsBackend::VaultTransit,
+            backend_config: BackendConfig::VaultTransit(Box::new(VaultConfig {
+                address: address.to_string(),
+                auth_method: VaultAuthMethod::Token { token },
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
     /// Get the local configuration if backend is Local
     pub fn local_config(&self) -> Option<&LocalConfig> {
         match &self.backend_config {
@@ -224,10 +354,40 @@ impl KmsConfig {
         }
     }
 
-    /// Get the Vault configuration if backend is Vault
+    /// Create a new KMS configuration for a KMIP server using mutual TLS
+    pub fn kmip(endpoint: String, ca_cert_path: PathBuf, client_cert_path: PathBuf, client_key_path: PathBuf) -> Self {
+        Self {
+            backend: KmsBackend::Kmip,
+            backend_config: BackendConfig::Kmip(Box::new(KmipConfig {
+                endpoint,
+                server_name: None,
+                protocol_version: KmipProtocolVersion::default(),
+                ca_cert_path,
+                client_cert_path,
+                client_key_path,
+            })),
+            ..Default::default()
+        }
+    }
+
+    /// Create a new KMS configuration for a PKCS#11 token
+    pub fn pkcs11(module_path: PathBuf, token_label: String, pin: String) -> Self {
+        Self {
+            backend: KmsBackend::Pkcs11,
+            backend_config: BackendConfig::Pkcs11(Box::new(Pkcs11Config {
+                module_path,
+                token_label: Some(token_label),
+                slot_id: None,
+                pin,
+            })),
+            ..Default::default()
+        }
+    }
+
+    /// Get the Vault configuration if backend is Vault or Vault Transit
     pub fn vault_config(&self) -> Option<&VaultConfig> {
         match &self.backend_config {
-            BackendConfig::Vault(config) => Some(config),
+            BackendConfig::Vault(config) | BackendConfig::VaultTransit(config) => Some(config),
             _ => None,
         }
     }
@@ -269,7 +429,7 @@ impl KmsConfig {
                     return Err(KmsError::configuration_error("Local key directory must be an absolute path"));
                 }
             }
-            BackendConfig::Vault(config) => {
+            BackendConfig::Vault(config) | BackendConfig::VaultTransit(config) => {
                 if !config.address.starts_with("http://") && !config.address.starts_with("https://") {
                     return Err(KmsError::configuration_error("Vault address must use http or https scheme"));
                 }
@@ -289,6 +449,20 @@ impl KmsConfig {
                     }
                 }
             }
+            BackendConfig::Kmip(config) => {
+                if config.endpoint.is_empty() {
+                    return Err(KmsError::configuration_error("KMIP endpoint cannot be empty"));
+                }
+            }
+            BackendConfig::Pkcs11(config) => {
+                if config.module_path.as_os_str().is_empty() {
+                    return Err(KmsError::configuration_error("PKCS#11 module path cannot be empty"));
+                }
+
+                if config.token_label.is_none() && config.slot_id.is_none() {
+                    return Err(KmsError::configuration_error("PKCS#11 token label or slot ID is required"));
+                }
+            }
         }
 
         // Validate cache configuration
@@ -308,6 +482,9 @@ impl KmsConfig {
             config.backend = match backend_type.to_lowercase().as_str() {
                 "local" => KmsBackend::Local,
                 "vault" => KmsBackend::Vault,
+                "vault-transit" | "vault_transit" => KmsBackend::VaultTransit,
+                "kmip" => KmsBackend::Kmip,
+                "pkcs11" => KmsBackend::Pkcs11,
                 _ => return Err(KmsError::configuration_error(format!("Unknown KMS backend: {backend_type}"))),
             };
         }
@@ -349,11 +526,11 @@ impl KmsConfig {
                     file_permissions: Some(0o600),
                 });
             }
-            KmsBackend::Vault => {
+            KmsBackend::Vault | KmsBackend::VaultTransit => {
                 let address = std::env::var("RUSTFS_KMS_VAULT_ADDRESS").unwrap_or_else(|_| "http://localhost:8200".to_string());
                 let token = std::env::var("RUSTFS_KMS_VAULT_TOKEN").unwrap_or_else(|_| "dev-token".to_string());
 
-                config.backend_config = BackendConfig::Vault(Box::new(VaultConfig {
+                let vault_config = Box::new(VaultConfig {
                     address,
                     auth_method: VaultAuthMethod::Token { token },
                     namespace: std::env::var("RUSTFS_KMS_VAULT_NAMESPACE").ok(),
@@ -362,7 +539,19 @@ impl KmsConfig {
                     key_path_prefix: std::env::var("RUSTFS_KMS_VAULT_KEY_PREFIX")
                         .unwrap_or_else(|_| "rustfs/kms/keys".to_string()),
                     tls: None,
-                }));
+                });
+
+                config.backend_config = if config.backend == KmsBackend::VaultTransit {
+                    BackendConfig::VaultTransit(vault_config)
+                } else {
+                    BackendConfig::Vault(vault_config)
+                };
+            }
+            KmsBackend::Kmip => {
+                config.backend_config = BackendConfig::Kmip(Box::new(KmipConfig::from_env()?));
+            }
+            KmsBackend::Pkcs11 => {
+                config.backend_config = BackendConfig::Pkcs11(Box::new(Pkcs11Config::from_env()?));
             }
         }
 
@@ -407,6 +596,45 @@ mod tests {
         assert_eq!(vault_config.address, address.as_str());
     }
 
+    #[test]
+    fn test_vault_transit_config() {
+        let address = Url::parse("https://vault.example.com:8200").expect("Valid URL");
+        let config = KmsConfig::vault_transit(address.clone(), "test-token".to_string());
+
+        assert_eq!(config.backend, KmsBackend::VaultTransit);
+        assert!(config.validate().is_ok());
+        assert!(matches!(config.backend_config, BackendConfig::VaultTransit(_)));
+
+        let vault_config = config.vault_config().expect("Should have vault config");
+        assert_eq!(vault_config.address, address.as_str());
+        assert_eq!(vault_config.mount_path, "transit");
+    }
+
+    #[test]
+    fn test_hsm_configs() {
+        let config = KmsConfig::kmip(
+            "kmip.example.com:5696".to_string(),
+            PathBuf::from("/etc/rustfs/kmip/ca.pem"),
+            PathBuf::from("/etc/rustfs/kmip/client.pem"),
+            PathBuf::from("/etc/rustfs/kmip/client.key"),
+        );
+        assert_eq!(config.backend, KmsBackend::Kmip);
+        assert!(config.validate().is_ok());
+
+        let mut config =
+            KmsConfig::pkcs11(PathBuf::from("/usr/lib/softhsm/libsofthsm2.so"), "rustfs".to_string(), "1234".to_string());
+        assert_eq!(config.backend, KmsBackend::Pkcs11);
+        assert!(config.validate().is_ok());
+
+        if let BackendConfig::Pkcs11(pkcs11) = &mut config.backend_config {
+            pkcs11.token_label = None;
+        }
+        assert!(config.validate().is_err());
+
+        assert_eq!("2.0".parse::<KmipProtocolVersion>().expect("valid version"), KmipProtocolVersion::V2_0);
+        assert!("1.0".parse::<KmipProtocolVersion>().is_err());
+    }
+
     #[test]
     fn test_config_validation() {
         let mut config = KmsConfig::default();
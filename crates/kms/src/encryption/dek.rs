@@ -36,6 +36,9 @@ use std::collections::HashMap;
 pub struct DataKeyEnvelope {
     pub key_id: String,
     pub master_key_id: String,
+    /// Master key version the DEK was wrapped with (absent in envelopes written before versioning)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub master_key_version: Option<u32>,
     pub key_spec: String,
     pub encrypted_key: Vec<u8>,
     pub nonce: Vec<u8>,
@@ -43,6 +46,15 @@ pub struct DataKeyEnvelope {
     pub created_at: Zoned,
 }
 
+impl DataKeyEnvelope {
+    /// Master key version recorded in a serialized envelope, if any
+    pub fn version_of(ciphertext: &[u8]) -> Option<u32> {
+        serde_json::from_slice::<DataKeyEnvelope>(ciphertext)
+            .ok()
+            .and_then(|envelope| envelope.master_key_version)
+    }
+}
+
 /// Trait for encrypting and decrypting data encryption keys (DEK)
 ///
 /// This trait abstracts the encryption operations used to protect
@@ -272,6 +284,7 @@ mod tests {
         let envelope = DataKeyEnvelope {
             key_id: "test-key-id".to_string(),
             master_key_id: "master-key-id".to_string(),
+            master_key_version: Some(2),
             key_spec: "AES_256".to_string(),
             encrypted_key: vec![1, 2, 3, 4],
             nonce: vec![5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
@@ -292,6 +305,8 @@ mod tests {
         assert_eq!(deserialized.key_id, envelope.key_id);
         assert_eq!(deserialized.master_key_id, envelope.master_key_id);
         assert_eq!(deserialized.encrypted_key, envelope.encrypted_key);
+        assert_eq!(deserialized.master_key_version, Some(2));
+        assert_eq!(DataKeyEnvelope::version_of(&serialized), Some(2));
     }
 
     #[tokio::test]
@@ -310,5 +325,6 @@ mod tests {
         let deserialized: DataKeyEnvelope = serde_json::from_str(envelope_json).expect("Should deserialize current format");
         assert_eq!(deserialized.key_id, "test-key-id");
         assert_eq!(deserialized.master_key_id, "master-key-id");
+        assert_eq!(deserialized.master_key_version, None);
     }
 }
@@ -21,7 +21,8 @@ use crate::error::Result;
 use crate::types::{
     CancelKeyDeletionRequest, CancelKeyDeletionResponse, CreateKeyRequest, CreateKeyResponse, DecryptRequest, DecryptResponse,
     DeleteKeyRequest, DeleteKeyResponse, DescribeKeyRequest, DescribeKeyResponse, EncryptRequest, EncryptResponse,
-    GenerateDataKeyRequest, GenerateDataKeyResponse, ListKeysRequest, ListKeysResponse,
+    GenerateDataKeyRequest, GenerateDataKeyResponse, ListKeysRequest, ListKeysResponse, RewrapDataKeyRequest,
+    RewrapDataKeyResponse, RotateKeyRequest, RotateKeyResponse,
 };
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -175,6 +176,25 @@ impl KmsManager {
         Ok(response)
     }
 
+    /// Rotate a master key to a new version
+    pub async fn rotate_key(&self, request: RotateKeyRequest) -> Result<RotateKeyResponse> {
+        let response = self.backend.rotate_key(request).await?;
+
+        // Cached data keys are wrapped under the previous version, drop them
+        if self.config.enable_cache {
+            let mut cache = self.cache.write().await;
+            cache.remove_key_metadata(&response.key_id).await;
+            cache.remove_data_key(&response.key_id).await;
+        }
+
+        Ok(response)
+    }
+
+    /// Re-wrap a sealed data key under the latest master key version
+    pub async fn rewrap_data_key(&self, request: RewrapDataKeyRequest) -> Result<RewrapDataKeyResponse> {
+        self.backend.rewrap_data_key(request).await
+    }
+
     /// Perform health check on the KMS backend
     pub async fn health_check(&self) -> Result<bool> {
         self.backend.health_check().await
@@ -14,6 +14,7 @@
 
 //! Object encryption service for S3-compatible encryption
 
+use crate::encryption::DataKeyEnvelope;
 use crate::encryption::ciphers::{create_cipher, generate_iv};
 use crate::error::{KmsError, Result};
 use crate::manager::KmsManager;
@@ -146,6 +147,42 @@ impl ObjectEncryptionService {
         self.kms_manager.clear_cache().await
     }
 
+    /// Rotate a master key (delegates to KMS manager)
+    ///
+    /// # Arguments
+    /// * `request` - RotateKeyRequest with the key to rotate
+    ///
+    /// # Returns
+    /// RotateKeyResponse with the new key version
+    ///
+    pub async fn rotate_key(&self, request: RotateKeyRequest) -> Result<RotateKeyResponse> {
+        self.kms_manager.rotate_key(request).await
+    }
+
+    /// Re-wrap a sealed data key under the latest master key version (delegates to KMS manager)
+    ///
+    /// # Arguments
+    /// * `request` - RewrapDataKeyRequest with the sealed data key
+    ///
+    /// # Returns
+    /// RewrapDataKeyResponse with the (possibly unchanged) sealed data key
+    ///
+    pub async fn rewrap_data_key(&self, request: RewrapDataKeyRequest) -> Result<RewrapDataKeyResponse> {
+        self.kms_manager.rewrap_data_key(request).await
+    }
+
+    /// Master key version a sealed data key was wrapped under
+    ///
+    /// # Arguments
+    /// * `encrypted_key` - Sealed data key blob
+    ///
+    /// # Returns
+    /// Recorded version, or 1 for blobs written before versioning
+    ///
+    pub fn data_key_version(encrypted_key: &[u8]) -> u32 {
+        DataKeyEnvelope::version_of(encrypted_key).unwrap_or(1)
+    }
+
     /// Get backend health status
     ///
     /// # Returns
@@ -338,7 +375,7 @@ impl ObjectEncryptionService {
         let metadata = EncryptionMetadata {
             algorithm: algorithm.as_str().to_string(),
             key_id: actual_key_id.to_string(),
-            key_version: 1, // Default to version 1 for now
+            key_version: Self::data_key_version(&data_key.ciphertext_blob),
             iv,
             tag: Some(tag),
             encryption_context: context,
@@ -629,6 +666,10 @@ impl ObjectEncryptionService {
             base64::engine::general_purpose::STANDARD.encode(&metadata.encrypted_data_key),
         );
 
+        if metadata.key_id != "sse-c" {
+            headers.insert("x-rustfs-encryption-key-version".to_string(), metadata.key_version.to_string());
+        }
+
         headers.insert(
             "x-rustfs-encryption-context".to_string(),
             serde_json::to_string(&metadata.encryption_context).unwrap_or_default(),
@@ -684,6 +725,11 @@ impl ObjectEncryptionService {
             Vec::new() // Empty for SSE-C
         };
 
+        let key_version = headers
+            .get("x-rustfs-encryption-key-version")
+            .and_then(|version| version.parse().ok())
+            .unwrap_or(1);
+
         let encryption_context = if let Some(context_str) = headers.get("x-rustfs-encryption-context") {
             serde_json::from_str(context_str)
                 .map_err(|e| KmsError::validation_error(format!("Invalid encryption context: {e}")))?
@@ -694,7 +740,7 @@ impl ObjectEncryptionService {
         Ok(EncryptionMetadata {
             algorithm,
             key_id,
-            key_version: 1, // Default for parsing
+            key_version,
             iv,
             tag,
             encryption_context,
@@ -808,7 +854,7 @@ mod tests {
         let metadata = EncryptionMetadata {
             algorithm: "AES256".to_string(),
             key_id: "test-key".to_string(),
-            key_version: 1,
+            key_version: 3,
             iv: vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
             tag: Some(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]),
             encryption_context: HashMap::from([("bucket".to_string(), "test-bucket".to_string())]),
@@ -826,6 +872,7 @@ mod tests {
         let parsed_metadata = service.headers_to_metadata(&headers).expect("Failed to parse headers");
         assert_eq!(parsed_metadata.algorithm, metadata.algorithm);
         assert_eq!(parsed_metadata.key_id, metadata.key_id);
+        assert_eq!(parsed_metadata.key_version, metadata.key_version);
         assert_eq!(parsed_metadata.iv, metadata.iv);
         assert_eq!(parsed_metadata.tag, metadata.tag);
     }
@@ -324,6 +324,21 @@ impl KmsServiceManager {
                 let backend = crate::backends::vault::VaultKmsBackend::new(config.clone()).await?;
                 Arc::new(backend) as Arc<dyn KmsBackend>
             }
+            BackendConfig::VaultTransit(_) => {
+                info!("Creating Vault Transit KMS backend for version {}", version);
+                let backend = crate::backends::vault_transit::VaultTransitKmsBackend::new(config.clone()).await?;
+                Arc::new(backend) as Arc<dyn KmsBackend>
+            }
+            BackendConfig::Kmip(_) => {
+                info!("Creating KMIP KMS backend for version {}", version);
+                let backend = crate::backends::kmip::KmipKmsBackend::new(config.clone()).await?;
+                Arc::new(backend) as Arc<dyn KmsBackend>
+            }
+            BackendConfig::Pkcs11(_) => {
+                info!("Creating PKCS#11 KMS backend for version {}", version);
+                let backend = crate::backends::pkcs11::Pkcs11KmsBackend::new(config.clone()).await?;
+                Arc::new(backend) as Arc<dyn KmsBackend>
+            }
         };
 
         // Create KMS manager
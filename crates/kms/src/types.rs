@@ -912,6 +912,42 @@ pub struct CancelKeyDeletionResponse {
     pub key_metadata: KeyMetadata,
 }
 
+/// Request to rotate a master key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotateKeyRequest {
+    /// Key ID to rotate
+    pub key_id: String,
+}
+
+/// Response from rotate key operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotateKeyResponse {
+    /// Key ID
+    pub key_id: String,
+    /// New latest key version, used to wrap new data keys
+    pub key_version: u32,
+}
+
+/// Request to re-wrap a sealed data key under the latest master key version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewrapDataKeyRequest {
+    /// Sealed data key as returned by generate data key
+    pub ciphertext_blob: Vec<u8>,
+}
+
+/// Response from re-wrap data key operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewrapDataKeyResponse {
+    /// Master key ID
+    pub key_id: String,
+    /// Master key version the data key is now wrapped with
+    pub key_version: u32,
+    /// Sealed data key (unchanged if it was already on the latest version)
+    pub ciphertext_blob: Vec<u8>,
+    /// Whether the data key was re-wrapped
+    pub rewrapped: bool,
+}
+
 // SECURITY: Implement Drop to automatically zero sensitive data when DataKey is dropped
 impl Drop for DataKeyInfo {
     fn drop(&mut self) {
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::HealingDisk;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 pub type HealItemType = String;
@@ -58,3 +60,108 @@ pub struct HealResultItem {
     #[serde(rename = "objectSize")]
     pub object_size: usize,
 }
+
+/// Background healing state reported by a single node, or merged across the cluster.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BgHealState {
+    #[serde(rename = "offline_nodes")]
+    pub offline_endpoints: Vec<String>,
+    pub scanned_items_count: u64,
+    pub heal_disks: Vec<String>,
+    /// When the scanner started its current bitrot scan.
+    pub bitrot_start_time: Option<DateTime<Utc>>,
+    /// Scanner cycle the current bitrot scan started at.
+    pub bitrot_start_cycle: u64,
+    /// Scan mode the scanner currently heals with.
+    pub current_scan_mode: String,
+    /// Heal requests waiting in the heal manager queue.
+    pub heal_queue_length: u64,
+    /// Heal tasks currently running.
+    pub heal_active_tasks: u64,
+    /// Resync progress of drives being healed.
+    pub healing_disks: Vec<HealingDisk>,
+}
+
+impl BgHealState {
+    /// Fold another node's state into this one.
+    pub fn merge(&mut self, other: BgHealState) {
+        self.offline_endpoints.extend(other.offline_endpoints);
+        self.scanned_items_count += other.scanned_items_count;
+        self.heal_disks.extend(other.heal_disks);
+        self.offline_endpoints.sort();
+        self.offline_endpoints.dedup();
+        self.heal_disks.sort();
+        self.heal_disks.dedup();
+
+        // The scanner state is cluster wide, keep the most recent one.
+        if other.bitrot_start_time > self.bitrot_start_time {
+            self.bitrot_start_time = other.bitrot_start_time;
+            self.bitrot_start_cycle = other.bitrot_start_cycle;
+        }
+        if self.current_scan_mode.is_empty() {
+            self.current_scan_mode = other.current_scan_mode;
+        }
+
+        self.heal_queue_length += other.heal_queue_length;
+        self.heal_active_tasks += other.heal_active_tasks;
+        self.healing_disks.extend(other.healing_disks);
+        self.healing_disks.sort_by(|a, b| a.endpoint.cmp(&b.endpoint));
+        self.healing_disks.dedup_by(|a, b| a.endpoint == b.endpoint);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bg_heal_state_merge_dedups_endpoints() {
+        let mut state = BgHealState {
+            offline_endpoints: vec!["http://node2:9000/data1".to_string()],
+            scanned_items_count: 10,
+            heal_disks: vec!["http://node1:9000/data1".to_string()],
+            ..Default::default()
+        };
+        state.merge(BgHealState {
+            offline_endpoints: vec!["http://node2:9000/data1".to_string()],
+            scanned_items_count: 5,
+            heal_disks: vec!["http://node3:9000/data2".to_string()],
+            ..Default::default()
+        });
+
+        assert_eq!(state.scanned_items_count, 15);
+        assert_eq!(state.offline_endpoints, vec!["http://node2:9000/data1".to_string()]);
+        assert_eq!(state.heal_disks.len(), 2);
+    }
+
+    #[test]
+    fn test_bg_heal_state_merge_sums_heal_manager_counters() {
+        let mut state = BgHealState {
+            heal_queue_length: 3,
+            heal_active_tasks: 1,
+            healing_disks: vec![HealingDisk {
+                endpoint: "http://node1:9000/data1".to_string(),
+                objects_healed: 10,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        state.merge(BgHealState {
+            current_scan_mode: "normal".to_string(),
+            heal_queue_length: 2,
+            heal_active_tasks: 1,
+            healing_disks: vec![HealingDisk {
+                endpoint: "http://node2:9000/data3".to_string(),
+                objects_healed: 5,
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(state.current_scan_mode, "normal");
+        assert_eq!(state.heal_queue_length, 5);
+        assert_eq!(state.heal_active_tasks, 2);
+        assert_eq!(state.healing_disks.len(), 2);
+        assert_eq!(state.healing_disks[1].endpoint, "http://node2:9000/data3");
+    }
+}
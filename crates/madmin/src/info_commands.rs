@@ -122,6 +122,8 @@ pub struct HealingDisk {
     pub queue_buckets: Vec<String>,
     pub healed_buckets: Vec<String>,
     pub finished: bool,
+    /// Estimated time the resync completes, when it can be projected.
+    pub eta: Option<SystemTime>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -549,6 +551,7 @@ mod tests {
             queue_buckets: vec!["bucket1".to_string(), "bucket2".to_string()],
             healed_buckets: vec!["bucket3".to_string()],
             finished: false,
+            eta: None,
         };
 
         assert_eq!(healing_disk.id, "heal-001");
@@ -12,14 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, str::FromStr, time::Duration};
 
 use hyper::Uri;
+use serde::{Deserialize, Serialize};
 
-use crate::{trace::TraceType, utils::parse_duration};
+use crate::{
+    trace::{TraceInfo, TraceType},
+    utils::parse_duration,
+};
 
-#[derive(Debug, Default)]
-#[allow(dead_code)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ServiceTraceOpts {
     s3: bool,
     internal: bool,
@@ -39,11 +42,16 @@ pub struct ServiceTraceOpts {
     ilm: bool,
     only_errors: bool,
     threshold: Duration,
+    /// Only trace requests answered with this HTTP status code
+    status_code: Option<i32>,
+    /// Only trace calls to this API, e.g. `GetObject` or `s3.GetObject`
+    api_name: Option<String>,
+    /// Only trace calls on paths below this bucket/prefix
+    path_prefix: Option<String>,
 }
 
-#[allow(dead_code)]
 impl ServiceTraceOpts {
-    fn trace_types(&self) -> TraceType {
+    pub fn trace_types(&self) -> TraceType {
         let mut tt = TraceType::default();
         tt.set_if(self.s3, &TraceType::S3);
         tt.set_if(self.internal, &TraceType::INTERNAL);
@@ -73,47 +81,203 @@ impl ServiceTraceOpts {
     }
 
     pub fn parse_params(&mut self, uri: &Uri) -> Result<(), String> {
-        let query_pairs: HashMap<_, _> = uri
-            .query()
-            .unwrap_or("")
-            .split('&')
-            .filter_map(|pair| {
-                let mut split = pair.split('=');
-                let key = split.next()?.to_string();
-                let value = split.next().map(|v| v.to_string()).unwrap_or_else(|| "false".to_string());
-                Some((key, value))
-            })
+        let query_pairs: HashMap<String, String> = form_urlencoded::parse(uri.query().unwrap_or("").as_bytes())
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
             .collect();
+        let flag = |key: &str| query_pairs.get(key).is_some_and(|v| v == "true");
 
-        self.s3 = query_pairs.get("s3").is_some_and(|v| v == "true");
-        self.os = query_pairs.get("os").is_some_and(|v| v == "true");
-        self.scanner = query_pairs.get("scanner").is_some_and(|v| v == "true");
-        self.decommission = query_pairs.get("decommission").is_some_and(|v| v == "true");
-        self.healing = query_pairs.get("healing").is_some_and(|v| v == "true");
-        self.batch_replication = query_pairs.get("batch-replication").is_some_and(|v| v == "true");
-        self.batch_key_rotation = query_pairs.get("batch-keyrotation").is_some_and(|v| v == "true");
-        self.batch_expire = query_pairs.get("batch-expire").is_some_and(|v| v == "true");
-        if query_pairs.get("all").is_some_and(|v| v == "true") {
-            self.s3 = true;
-            self.internal = true;
-            self.storage = true;
-            self.os = true;
-        }
-
-        self.rebalance = query_pairs.get("rebalance").is_some_and(|v| v == "true");
-        self.storage = query_pairs.get("storage").is_some_and(|v| v == "true");
-        self.internal = query_pairs.get("internal").is_some_and(|v| v == "true");
-        self.only_errors = query_pairs.get("err").is_some_and(|v| v == "true");
-        self.replication_resync = query_pairs.get("replication-resync").is_some_and(|v| v == "true");
-        self.bootstrap = query_pairs.get("bootstrap").is_some_and(|v| v == "true");
-        self.ftp = query_pairs.get("ftp").is_some_and(|v| v == "true");
-        self.ilm = query_pairs.get("ilm").is_some_and(|v| v == "true");
+        let all = flag("all");
+        self.s3 = all || flag("s3");
+        self.internal = all || flag("internal");
+        self.storage = all || flag("storage");
+        self.os = all || flag("os");
+        self.scanner = flag("scanner");
+        self.decommission = flag("decommission");
+        self.healing = flag("healing");
+        self.batch_replication = flag("batch-replication");
+        self.batch_key_rotation = flag("batch-keyrotation");
+        self.batch_expire = flag("batch-expire");
+        self.rebalance = flag("rebalance");
+        self.only_errors = flag("err");
+        self.replication_resync = flag("replication-resync");
+        self.bootstrap = flag("bootstrap");
+        self.ftp = flag("ftp");
+        self.ilm = flag("ilm");
 
         if let Some(threshold) = query_pairs.get("threshold") {
             let duration = parse_duration(threshold)?;
             self.threshold = duration;
         }
 
+        if let Some(status_code) = query_pairs.get("status-code").filter(|v| !v.is_empty()) {
+            let code = status_code
+                .parse::<i32>()
+                .map_err(|e| format!("invalid status-code '{status_code}': {e}"))?;
+            self.status_code = Some(code);
+        }
+        self.api_name = query_pairs.get("api").filter(|v| !v.is_empty()).cloned();
+        self.path_prefix = query_pairs
+            .get("prefix")
+            .map(|v| v.trim_start_matches('/').to_string())
+            .filter(|v| !v.is_empty());
+
         Ok(())
     }
+
+    /// Reports whether a trace record passes the type mask and all filters
+    pub fn matches(&self, info: &TraceInfo) -> bool {
+        if !self.trace_types().overlaps(&TraceType::new(info.trace_type)) {
+            return false;
+        }
+
+        if self.only_errors && info.error.is_none() && !info.status_code().is_some_and(|code| code >= 400) {
+            return false;
+        }
+
+        if !self.threshold.is_zero() && info.duration < self.threshold {
+            return false;
+        }
+
+        if let Some(code) = self.status_code
+            && info.status_code() != Some(code)
+        {
+            return false;
+        }
+
+        if let Some(api) = &self.api_name {
+            let short_name = info
+                .func_name
+                .rsplit_once('.')
+                .map_or(info.func_name.as_str(), |(_, name)| name);
+            if !info.func_name.eq_ignore_ascii_case(api) && !short_name.eq_ignore_ascii_case(api) {
+                return false;
+            }
+        }
+
+        if let Some(prefix) = &self.path_prefix
+            && !info.path.trim_start_matches('/').starts_with(prefix.as_str())
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// Actions accepted by the admin service API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceAction {
+    Restart,
+    Stop,
+    Freeze,
+    Unfreeze,
+}
+
+impl ServiceAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServiceAction::Restart => "restart",
+            ServiceAction::Stop => "stop",
+            ServiceAction::Freeze => "freeze",
+            ServiceAction::Unfreeze => "unfreeze",
+        }
+    }
+}
+
+impl FromStr for ServiceAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "restart" => Ok(ServiceAction::Restart),
+            "stop" => Ok(ServiceAction::Stop),
+            "freeze" => Ok(ServiceAction::Freeze),
+            "unfreeze" => Ok(ServiceAction::Unfreeze),
+            _ => Err(format!("unknown service action: {s}")),
+        }
+    }
+}
+
+/// Outcome of a service action on a single node
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ServicePeerResult {
+    pub host: String,
+    #[serde(rename = "err", skip_serializing_if = "Option::is_none")]
+    pub err: Option<String>,
+}
+
+/// Response of the admin service API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceActionResult {
+    pub action: ServiceAction,
+    #[serde(rename = "dryRun")]
+    pub dry_run: bool,
+    pub results: Vec<ServicePeerResult>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opts(query: &str) -> ServiceTraceOpts {
+        let uri: Uri = format!("/rustfs/admin/v3/trace?{query}").parse().unwrap();
+        let mut opts = ServiceTraceOpts::default();
+        opts.parse_params(&uri).unwrap();
+        opts
+    }
+
+    #[test]
+    fn test_parse_params_all() {
+        let tt = opts("all=true").trace_types();
+        assert!(tt.contains(&TraceType::S3));
+        assert!(tt.contains(&TraceType::INTERNAL));
+        assert!(tt.contains(&TraceType::STORAGE));
+        assert!(tt.contains(&TraceType::OS));
+        assert!(!tt.contains(&TraceType::SCANNER));
+    }
+
+    #[test]
+    fn test_matches_filters() {
+        let opts = opts("s3=true&threshold=100ms&api=GetObject&prefix=%2Fbucket%2Fdir");
+
+        let mut info = TraceInfo::new(TraceType::S3, "s3.GetObject", "/bucket/dir/object");
+        info.duration = Duration::from_millis(150);
+        assert!(opts.matches(&info));
+
+        info.duration = Duration::from_millis(50);
+        assert!(!opts.matches(&info));
+
+        info.duration = Duration::from_millis(150);
+        info.path = "/other/object".to_string();
+        assert!(!opts.matches(&info));
+
+        let storage = TraceInfo::new(TraceType::STORAGE, "storage.ReadAll", "/bucket/dir/object");
+        assert!(!opts.matches(&storage));
+    }
+
+    #[test]
+    fn test_matches_errors_only() {
+        let opts = opts("s3=true&err=true");
+
+        let info = TraceInfo::new(TraceType::S3, "s3.PutObject", "/bucket/key");
+        assert!(!opts.matches(&info));
+
+        let mut failed = info.clone();
+        failed.error = Some("access denied".to_string());
+        assert!(opts.matches(&failed));
+    }
+
+    #[test]
+    fn test_service_action_round_trip() {
+        for action in [
+            ServiceAction::Restart,
+            ServiceAction::Stop,
+            ServiceAction::Freeze,
+            ServiceAction::Unfreeze,
+        ] {
+            assert_eq!(action.as_str().parse::<ServiceAction>(), Ok(action));
+        }
+        assert!("reboot".parse::<ServiceAction>().is_err());
+    }
 }
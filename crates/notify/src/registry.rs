@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use crate::Event;
-use crate::factory::{MQTTTargetFactory, TargetFactory, WebhookTargetFactory};
+#[cfg(feature = "kafka")]
+use crate::factory::KafkaTargetFactory;
+use crate::factory::{MQTTTargetFactory, NATSTargetFactory, TargetFactory, WebhookTargetFactory};
 use futures::stream::{FuturesUnordered, StreamExt};
 use hashbrown::{HashMap, HashSet};
 use rustfs_config::{DEFAULT_DELIMITER, ENABLE_KEY, ENV_PREFIX, EnableState, notify::NOTIFY_ROUTE_PREFIX};
@@ -44,6 +46,9 @@ impl TargetRegistry {
         // Register built-in factories
         registry.register(ChannelTargetType::Webhook.as_str(), Box::new(WebhookTargetFactory));
         registry.register(ChannelTargetType::Mqtt.as_str(), Box::new(MQTTTargetFactory));
+        #[cfg(feature = "kafka")]
+        registry.register(ChannelTargetType::Kafka.as_str(), Box::new(KafkaTargetFactory));
+        registry.register(ChannelTargetType::Nats.as_str(), Box::new(NATSTargetFactory));
 
         registry
     }
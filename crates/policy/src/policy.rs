@@ -32,7 +32,7 @@ pub use effect::Effect;
 pub use function::Functions;
 pub use id::ID;
 pub use policy::*;
-pub use principal::Principal;
+pub use principal::{PRINCIPAL_ARN_KEY, Principal, principal_arns};
 pub use resource::ResourceSet;
 pub use statement::Statement;
 
@@ -54,6 +54,12 @@ pub enum Error {
     #[error("'Resource' and 'NotResource' cannot both be specified in the same statement")]
     BothResourceAndNotResource,
 
+    #[error("'Principal' is empty")]
+    NonPrincipal,
+
+    #[error("'Principal' and 'NotPrincipal' cannot both be specified in the same statement")]
+    BothPrincipalAndNotPrincipal,
+
     #[error("invalid key name: '{0}'")]
     InvalidKeyName(String),
 
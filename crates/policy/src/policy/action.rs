@@ -220,6 +220,8 @@ pub enum S3Action {
     DeleteBucketPolicyAction,
     #[strum(serialize = "s3:DeleteBucketCors")]
     DeleteBucketCorsAction,
+    #[strum(serialize = "s3:DeleteBucketWebsite")]
+    DeleteBucketWebsiteAction,
     #[strum(serialize = "s3:DeleteObject")]
     DeleteObjectAction,
     #[strum(serialize = "s3:GetBucketLocation")]
@@ -228,10 +230,24 @@ pub enum S3Action {
     GetBucketNotificationAction,
     #[strum(serialize = "s3:GetBucketPolicy")]
     GetBucketPolicyAction,
+    #[strum(serialize = "s3:GetBucketAcl")]
+    GetBucketAclAction,
     #[strum(serialize = "s3:GetBucketCors")]
     GetBucketCorsAction,
+    #[strum(serialize = "s3:GetBucketWebsite")]
+    GetBucketWebsiteAction,
+    #[strum(serialize = "s3:GetBucketLogging")]
+    GetBucketLoggingAction,
+    #[strum(serialize = "s3:GetBucketPublicAccessBlock")]
+    GetBucketPublicAccessBlockAction,
+    #[strum(serialize = "s3:GetBucketOwnershipControls")]
+    GetBucketOwnershipControlsAction,
+    #[strum(serialize = "s3:GetInventoryConfiguration")]
+    GetInventoryConfigurationAction,
     #[strum(serialize = "s3:GetObject")]
     GetObjectAction,
+    #[strum(serialize = "s3:GetObjectAcl")]
+    GetObjectAclAction,
     #[strum(serialize = "s3:GetObjectAttributes")]
     GetObjectAttributesAction,
     #[strum(serialize = "s3:HeadBucket")]
@@ -260,10 +276,24 @@ pub enum S3Action {
     PutBucketNotificationAction,
     #[strum(serialize = "s3:PutBucketPolicy")]
     PutBucketPolicyAction,
+    #[strum(serialize = "s3:PutBucketAcl")]
+    PutBucketAclAction,
     #[strum(serialize = "s3:PutBucketCors")]
     PutBucketCorsAction,
+    #[strum(serialize = "s3:PutBucketWebsite")]
+    PutBucketWebsiteAction,
+    #[strum(serialize = "s3:PutBucketLogging")]
+    PutBucketLoggingAction,
+    #[strum(serialize = "s3:PutBucketPublicAccessBlock")]
+    PutBucketPublicAccessBlockAction,
+    #[strum(serialize = "s3:PutBucketOwnershipControls")]
+    PutBucketOwnershipControlsAction,
+    #[strum(serialize = "s3:PutInventoryConfiguration")]
+    PutInventoryConfigurationAction,
     #[strum(serialize = "s3:PutObject")]
     PutObjectAction,
+    #[strum(serialize = "s3:PutObjectAcl")]
+    PutObjectAclAction,
     #[strum(serialize = "s3:DeleteObjectVersion")]
     DeleteObjectVersionAction,
     #[strum(serialize = "s3:DeleteObjectVersionTagging")]
@@ -92,7 +92,7 @@ impl Serialize for Functions {
         }
 
         for conditions in self.for_normal.iter() {
-            se.serialize_key(conditions.to_key())?;
+            se.serialize_key(conditions.to_key().as_ref())?;
             conditions.serialize_map(&mut se)?;
         }
 
@@ -20,24 +20,19 @@ use std::{borrow::Cow, collections::HashMap, net::IpAddr};
 pub type AddrFunc = InnerFunc<AddrFuncValue>;
 
 impl AddrFunc {
-    pub(crate) fn evaluate(&self, values: &HashMap<String, Vec<String>>) -> bool {
-        for inner in self.0.iter() {
-            let rvalues = values.get(inner.key.name().as_str()).map(|t| t.iter()).unwrap_or_default();
-
-            for r in rvalues {
-                let Ok(ip) = r.parse::<IpAddr>() else {
-                    return false;
-                };
-
-                for ip_net in inner.values.0.iter() {
-                    if ip_net.contains(ip) {
-                        return true;
-                    }
-                }
+    pub(crate) fn evaluate(&self, for_all: bool, negate: bool, if_exists: bool, values: &HashMap<String, Vec<String>>) -> bool {
+        self.0.iter().all(|inner| {
+            if if_exists && inner.request_values(values).is_none() {
+                return true;
             }
-        }
 
-        false
+            let matched = inner.match_values(for_all, values, |v| {
+                v.parse::<IpAddr>()
+                    .is_ok_and(|ip| inner.values.0.iter().any(|ip_net| ip_net.contains(ip)))
+            });
+
+            matched ^ negate
+        })
     }
 }
 
@@ -20,13 +20,62 @@ use super::func::InnerFunc;
 
 pub type BinaryFunc = InnerFunc<BinaryFuncValue>;
 
-// todo implement it
+/// Base64 encoded policy value of `BinaryEquals`
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
-#[serde(transparent)]
-pub struct BinaryFuncValue(String);
+#[serde(try_from = "String", into = "String")]
+pub struct BinaryFuncValue {
+    encoded: String,
+    decoded: Vec<u8>,
+}
+
+impl TryFrom<String> for BinaryFuncValue {
+    type Error = String;
+
+    fn try_from(encoded: String) -> Result<Self, Self::Error> {
+        let decoded = base64_simd::STANDARD
+            .decode_to_vec(encoded.as_bytes())
+            .map_err(|e| format!("invalid base64 value `{encoded}`: {e}"))?;
+        Ok(Self { encoded, decoded })
+    }
+}
+
+impl From<BinaryFuncValue> for String {
+    fn from(value: BinaryFuncValue) -> Self {
+        value.encoded
+    }
+}
 
 impl BinaryFunc {
-    pub fn evaluate(&self, _values: &HashMap<String, Vec<String>>) -> bool {
-        todo!()
+    /// Compare the raw request values with the decoded policy value
+    pub fn evaluate(&self, for_all: bool, if_exists: bool, values: &HashMap<String, Vec<String>>) -> bool {
+        self.0.iter().all(|inner| {
+            if if_exists && inner.request_values(values).is_none() {
+                return true;
+            }
+
+            inner.match_values(for_all, values, |v| v.as_bytes() == inner.values.decoded.as_slice())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BinaryFunc;
+    use std::collections::HashMap;
+    use test_case::test_case;
+
+    #[test_case(r#"{"s3:x-amz-copy-source":"bXlidWNrZXQvbXlvYmplY3Q="}"#, "mybucket/myobject" => true; "1")]
+    #[test_case(r#"{"s3:x-amz-copy-source":"bXlidWNrZXQvbXlvYmplY3Q="}"#, "mybucket/other" => false; "2")]
+    fn test_evaluate(input: &str, value: &str) -> bool {
+        let func: BinaryFunc = serde_json::from_str(input).unwrap();
+        assert_eq!(serde_json::to_string(&func).unwrap(), input);
+
+        let values = HashMap::from([("x-amz-copy-source".to_string(), vec![value.to_string()])]);
+        func.evaluate(false, false, &values)
+    }
+
+    #[test]
+    fn test_deser_invalid_base64() {
+        assert!(serde_json::from_str::<BinaryFunc>(r#"{"s3:x-amz-copy-source":"not base64!"}"#).is_err());
     }
 }
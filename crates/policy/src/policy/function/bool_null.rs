@@ -19,17 +19,15 @@ use std::{collections::HashMap, fmt};
 
 pub type BoolFunc = InnerFunc<BoolFuncValue>;
 impl BoolFunc {
-    pub fn evaluate_bool(&self, values: &HashMap<String, Vec<String>>) -> bool {
-        for inner in self.0.iter() {
-            if !match values.get(inner.key.name().as_str()).and_then(|x| x.first()) {
-                Some(x) => inner.values.0.to_string().as_str() == x,
-                None => false,
-            } {
-                return false;
+    pub fn evaluate_bool(&self, for_all: bool, if_exists: bool, values: &HashMap<String, Vec<String>>) -> bool {
+        self.0.iter().all(|inner| {
+            if if_exists && inner.request_values(values).is_none() {
+                return true;
             }
-        }
 
-        true
+            let expected = inner.values.0.to_string();
+            inner.match_values(for_all, values, |v| v.eq_ignore_ascii_case(&expected))
+        })
     }
 
     pub fn evaluate_null(&self, values: &HashMap<String, Vec<String>>) -> bool {
@@ -16,11 +16,15 @@ use crate::policy::variables::PolicyVariableResolver;
 use serde::Deserialize;
 use serde::de::{Error, MapAccess};
 use serde::ser::SerializeMap;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use time::OffsetDateTime;
 
 use super::{addr::AddrFunc, binary::BinaryFunc, bool_null::BoolFunc, date::DateFunc, number::NumberFunc, string::StringFunc};
 
+/// Suffix turning any operator except `Null` into one that also matches when the key is absent
+const IF_EXISTS_SUFFIX: &str = "IfExists";
+
 #[derive(Clone, Deserialize, Debug)]
 pub enum Condition {
     StringEquals(StringFunc),
@@ -29,6 +33,10 @@ pub enum Condition {
     StringNotEqualsIgnoreCase(StringFunc),
     StringLike(StringFunc),
     StringNotLike(StringFunc),
+    ArnEquals(StringFunc),
+    ArnLike(StringFunc),
+    ArnNotEquals(StringFunc),
+    ArnNotLike(StringFunc),
     BinaryEquals(BinaryFunc),
     IpAddress(AddrFunc),
     NotIpAddress(AddrFunc),
@@ -39,7 +47,6 @@ pub enum Condition {
     NumericLessThan(NumberFunc),
     NumericLessThanEquals(NumberFunc),
     NumericGreaterThan(NumberFunc),
-    NumericGreaterThanIfExists(NumberFunc),
     NumericGreaterThanEquals(NumberFunc),
     DateEquals(DateFunc),
     DateNotEquals(DateFunc),
@@ -47,10 +54,20 @@ pub enum Condition {
     DateLessThanEquals(DateFunc),
     DateGreaterThan(DateFunc),
     DateGreaterThanEquals(DateFunc),
+    /// `<Operator>IfExists`: keys absent from the request satisfy the wrapped operator
+    IfExists(Box<Condition>),
 }
 
 impl Condition {
     pub fn from_deserializer<'a, D: MapAccess<'a>>(key: &str, d: &mut D) -> Result<Self, D::Error> {
+        if let Some(operator) = key.strip_suffix(IF_EXISTS_SUFFIX) {
+            if operator == "Null" || operator.ends_with(IF_EXISTS_SUFFIX) {
+                return Err(Error::custom(format!("invalid condition operator: {key}")));
+            }
+
+            return Ok(Self::IfExists(Box::new(Self::from_deserializer(operator, d)?)));
+        }
+
         Ok(match key {
             "StringEquals" => Self::StringEquals(d.next_value()?),
             "StringNotEquals" => Self::StringNotEquals(d.next_value()?),
@@ -58,6 +75,10 @@ impl Condition {
             "StringNotEqualsIgnoreCase" => Self::StringNotEqualsIgnoreCase(d.next_value()?),
             "StringLike" => Self::StringLike(d.next_value()?),
             "StringNotLike" => Self::StringNotLike(d.next_value()?),
+            "ArnEquals" => Self::ArnEquals(d.next_value()?),
+            "ArnLike" => Self::ArnLike(d.next_value()?),
+            "ArnNotEquals" => Self::ArnNotEquals(d.next_value()?),
+            "ArnNotLike" => Self::ArnNotLike(d.next_value()?),
             "BinaryEquals" => Self::BinaryEquals(d.next_value()?),
             "IpAddress" => Self::IpAddress(d.next_value()?),
             "NotIpAddress" => Self::NotIpAddress(d.next_value()?),
@@ -66,11 +87,12 @@ impl Condition {
             "NumericEquals" => Self::NumericEquals(d.next_value()?),
             "NumericNotEquals" => Self::NumericNotEquals(d.next_value()?),
             "NumericLessThan" => Self::NumericLessThan(d.next_value()?),
+            "NumericLessThanEquals" => Self::NumericLessThanEquals(d.next_value()?),
             "NumericGreaterThan" => Self::NumericGreaterThan(d.next_value()?),
-            "NumericGreaterThanIfExists" => Self::NumericGreaterThanIfExists(d.next_value()?),
             "NumericGreaterThanEquals" => Self::NumericGreaterThanEquals(d.next_value()?),
             "DateEquals" => Self::DateEquals(d.next_value()?),
             "DateNotEquals" => Self::DateNotEquals(d.next_value()?),
+            "DateLessThan" => Self::DateLessThan(d.next_value()?),
             "DateLessThanEquals" => Self::DateLessThanEquals(d.next_value()?),
             "DateGreaterThan" => Self::DateGreaterThan(d.next_value()?),
             "DateGreaterThanEquals" => Self::DateGreaterThanEquals(d.next_value()?),
@@ -78,14 +100,18 @@ impl Condition {
         })
     }
 
-    pub fn to_key(&self) -> &'static str {
-        match self {
+    pub fn to_key(&self) -> Cow<'static, str> {
+        Cow::Borrowed(match self {
             Condition::StringEquals(_) => "StringEquals",
             Condition::StringNotEquals(_) => "StringNotEquals",
             Condition::StringEqualsIgnoreCase(_) => "StringEqualsIgnoreCase",
             Condition::StringNotEqualsIgnoreCase(_) => "StringNotEqualsIgnoreCase",
             Condition::StringLike(_) => "StringLike",
             Condition::StringNotLike(_) => "StringNotLike",
+            Condition::ArnEquals(_) => "ArnEquals",
+            Condition::ArnLike(_) => "ArnLike",
+            Condition::ArnNotEquals(_) => "ArnNotEquals",
+            Condition::ArnNotLike(_) => "ArnNotLike",
             Condition::BinaryEquals(_) => "BinaryEquals",
             Condition::IpAddress(_) => "IpAddress",
             Condition::NotIpAddress(_) => "NotIpAddress",
@@ -96,7 +122,6 @@ impl Condition {
             Condition::NumericLessThan(_) => "NumericLessThan",
             Condition::NumericLessThanEquals(_) => "NumericLessThanEquals",
             Condition::NumericGreaterThan(_) => "NumericGreaterThan",
-            Condition::NumericGreaterThanIfExists(_) => "NumericGreaterThanIfExists",
             Condition::NumericGreaterThanEquals(_) => "NumericGreaterThanEquals",
             Condition::DateEquals(_) => "DateEquals",
             Condition::DateNotEquals(_) => "DateNotEquals",
@@ -104,7 +129,8 @@ impl Condition {
             Condition::DateLessThanEquals(_) => "DateLessThanEquals",
             Condition::DateGreaterThan(_) => "DateGreaterThan",
             Condition::DateGreaterThanEquals(_) => "DateGreaterThanEquals",
-        }
+            Condition::IfExists(c) => return Cow::Owned(format!("{}{IF_EXISTS_SUFFIX}", c.to_key())),
+        })
     }
 
     pub async fn evaluate_with_resolver(
@@ -115,40 +141,58 @@ impl Condition {
     ) -> bool {
         use Condition::*;
 
-        let r = match self {
-            StringEquals(s) => s.evaluate_with_resolver(for_all, false, false, false, values, resolver).await,
-            StringNotEquals(s) => s.evaluate_with_resolver(for_all, false, false, true, values, resolver).await,
-            StringEqualsIgnoreCase(s) => s.evaluate_with_resolver(for_all, true, false, false, values, resolver).await,
-            StringNotEqualsIgnoreCase(s) => s.evaluate_with_resolver(for_all, true, false, true, values, resolver).await,
-            StringLike(s) => s.evaluate_with_resolver(for_all, false, true, false, values, resolver).await,
-            StringNotLike(s) => s.evaluate_with_resolver(for_all, false, true, true, values, resolver).await,
-            BinaryEquals(s) => s.evaluate(values),
-            IpAddress(s) => s.evaluate(values),
-            NotIpAddress(s) => s.evaluate(values),
-            Null(s) => s.evaluate_null(values),
-            Bool(s) => s.evaluate_bool(values),
-            NumericEquals(s) => s.evaluate(i64::eq, false, values),
-            NumericNotEquals(s) => s.evaluate(i64::ne, false, values),
-            NumericLessThan(s) => s.evaluate(i64::lt, false, values),
-            NumericLessThanEquals(s) => s.evaluate(i64::le, false, values),
-            NumericGreaterThan(s) => s.evaluate(i64::gt, false, values),
-            NumericGreaterThanIfExists(s) => s.evaluate(i64::ge, true, values),
-            NumericGreaterThanEquals(s) => s.evaluate(i64::ge, false, values),
-            DateEquals(s) => s.evaluate(OffsetDateTime::eq, values),
-            DateNotEquals(s) => s.evaluate(OffsetDateTime::ne, values),
-            DateLessThan(s) => s.evaluate(OffsetDateTime::lt, values),
-            DateLessThanEquals(s) => s.evaluate(OffsetDateTime::le, values),
-            DateGreaterThan(s) => s.evaluate(OffsetDateTime::gt, values),
-            DateGreaterThanEquals(s) => s.evaluate(OffsetDateTime::ge, values),
+        let (condition, if_exists) = match self {
+            IfExists(c) => (c.as_ref(), true),
+            c => (c, false),
         };
 
-        if self.is_negate() { !r } else { r }
-    }
-
-    #[inline]
-    pub fn is_negate(&self) -> bool {
-        use Condition::*;
-        matches!(self, StringNotEquals(_) | StringNotEqualsIgnoreCase(_) | NotIpAddress(_))
+        match condition {
+            StringEquals(s) => {
+                s.evaluate_with_resolver(for_all, false, false, false, if_exists, values, resolver)
+                    .await
+            }
+            StringNotEquals(s) => {
+                s.evaluate_with_resolver(for_all, false, false, true, if_exists, values, resolver)
+                    .await
+            }
+            StringEqualsIgnoreCase(s) => {
+                s.evaluate_with_resolver(for_all, true, false, false, if_exists, values, resolver)
+                    .await
+            }
+            StringNotEqualsIgnoreCase(s) => {
+                s.evaluate_with_resolver(for_all, true, false, true, if_exists, values, resolver)
+                    .await
+            }
+            StringLike(s) => {
+                s.evaluate_with_resolver(for_all, false, true, false, if_exists, values, resolver)
+                    .await
+            }
+            StringNotLike(s) => {
+                s.evaluate_with_resolver(for_all, false, true, true, if_exists, values, resolver)
+                    .await
+            }
+            ArnEquals(s) | ArnLike(s) => s.evaluate_arn(for_all, false, if_exists, values, resolver).await,
+            ArnNotEquals(s) | ArnNotLike(s) => s.evaluate_arn(for_all, true, if_exists, values, resolver).await,
+            BinaryEquals(s) => s.evaluate(for_all, if_exists, values),
+            IpAddress(s) => s.evaluate(for_all, false, if_exists, values),
+            NotIpAddress(s) => s.evaluate(for_all, true, if_exists, values),
+            Null(s) => s.evaluate_null(values),
+            Bool(s) => s.evaluate_bool(for_all, if_exists, values),
+            NumericEquals(s) => s.evaluate(i64::eq, for_all, if_exists, values),
+            NumericNotEquals(s) => s.evaluate(i64::ne, for_all, if_exists, values),
+            NumericLessThan(s) => s.evaluate(i64::lt, for_all, if_exists, values),
+            NumericLessThanEquals(s) => s.evaluate(i64::le, for_all, if_exists, values),
+            NumericGreaterThan(s) => s.evaluate(i64::gt, for_all, if_exists, values),
+            NumericGreaterThanEquals(s) => s.evaluate(i64::ge, for_all, if_exists, values),
+            DateEquals(s) => s.evaluate(OffsetDateTime::eq, for_all, if_exists, values),
+            DateNotEquals(s) => s.evaluate(OffsetDateTime::ne, for_all, if_exists, values),
+            DateLessThan(s) => s.evaluate(OffsetDateTime::lt, for_all, if_exists, values),
+            DateLessThanEquals(s) => s.evaluate(OffsetDateTime::le, for_all, if_exists, values),
+            DateGreaterThan(s) => s.evaluate(OffsetDateTime::gt, for_all, if_exists, values),
+            DateGreaterThanEquals(s) => s.evaluate(OffsetDateTime::ge, for_all, if_exists, values),
+            // Nested `IfExists` is rejected while parsing
+            IfExists(_) => false,
+        }
     }
 
     pub fn serialize_map<T: SerializeMap>(&self, se: &mut T) -> Result<(), T::Error> {
@@ -159,6 +203,10 @@ impl Condition {
             Condition::StringNotEqualsIgnoreCase(s) => se.serialize_value(s),
             Condition::StringLike(s) => se.serialize_value(s),
             Condition::StringNotLike(s) => se.serialize_value(s),
+            Condition::ArnEquals(s) => se.serialize_value(s),
+            Condition::ArnLike(s) => se.serialize_value(s),
+            Condition::ArnNotEquals(s) => se.serialize_value(s),
+            Condition::ArnNotLike(s) => se.serialize_value(s),
             Condition::BinaryEquals(s) => se.serialize_value(s),
             Condition::IpAddress(s) => se.serialize_value(s),
             Condition::NotIpAddress(s) => se.serialize_value(s),
@@ -169,7 +217,6 @@ impl Condition {
             Condition::NumericLessThan(s) => se.serialize_value(s),
             Condition::NumericLessThanEquals(s) => se.serialize_value(s),
             Condition::NumericGreaterThan(s) => se.serialize_value(s),
-            Condition::NumericGreaterThanIfExists(s) => se.serialize_value(s),
             Condition::NumericGreaterThanEquals(s) => se.serialize_value(s),
             Condition::DateEquals(s) => se.serialize_value(s),
             Condition::DateNotEquals(s) => se.serialize_value(s),
@@ -177,6 +224,7 @@ impl Condition {
             Condition::DateLessThanEquals(s) => se.serialize_value(s),
             Condition::DateGreaterThan(s) => se.serialize_value(s),
             Condition::DateGreaterThanEquals(s) => se.serialize_value(s),
+            Condition::IfExists(c) => c.serialize_map(se),
         }
     }
 }
@@ -190,6 +238,10 @@ impl PartialEq for Condition {
             (Self::StringNotEqualsIgnoreCase(l0), Self::StringNotEqualsIgnoreCase(r0)) => l0 == r0,
             (Self::StringLike(l0), Self::StringLike(r0)) => l0 == r0,
             (Self::StringNotLike(l0), Self::StringNotLike(r0)) => l0 == r0,
+            (Self::ArnEquals(l0), Self::ArnEquals(r0)) => l0 == r0,
+            (Self::ArnLike(l0), Self::ArnLike(r0)) => l0 == r0,
+            (Self::ArnNotEquals(l0), Self::ArnNotEquals(r0)) => l0 == r0,
+            (Self::ArnNotLike(l0), Self::ArnNotLike(r0)) => l0 == r0,
             (Self::BinaryEquals(l0), Self::BinaryEquals(r0)) => l0 == r0,
             (Self::IpAddress(l0), Self::IpAddress(r0)) => l0 == r0,
             (Self::NotIpAddress(l0), Self::NotIpAddress(r0)) => l0 == r0,
@@ -200,7 +252,6 @@ impl PartialEq for Condition {
             (Self::NumericLessThan(l0), Self::NumericLessThan(r0)) => l0 == r0,
             (Self::NumericLessThanEquals(l0), Self::NumericLessThanEquals(r0)) => l0 == r0,
             (Self::NumericGreaterThan(l0), Self::NumericGreaterThan(r0)) => l0 == r0,
-            (Self::NumericGreaterThanIfExists(l0), Self::NumericGreaterThanIfExists(r0)) => l0 == r0,
             (Self::NumericGreaterThanEquals(l0), Self::NumericGreaterThanEquals(r0)) => l0 == r0,
             (Self::DateEquals(l0), Self::DateEquals(r0)) => l0 == r0,
             (Self::DateNotEquals(l0), Self::DateNotEquals(r0)) => l0 == r0,
@@ -208,7 +259,95 @@ impl PartialEq for Condition {
             (Self::DateLessThanEquals(l0), Self::DateLessThanEquals(r0)) => l0 == r0,
             (Self::DateGreaterThan(l0), Self::DateGreaterThan(r0)) => l0 == r0,
             (Self::DateGreaterThanEquals(l0), Self::DateGreaterThanEquals(r0)) => l0 == r0,
+            (Self::IfExists(l0), Self::IfExists(r0)) => l0 == r0,
             _ => false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::policy::Functions;
+    use std::collections::HashMap;
+    use test_case::test_case;
+
+    fn eval(conditions: &str, values: &[(&str, &[&str])]) -> bool {
+        let functions: Functions = serde_json::from_str(conditions).unwrap();
+        let values: HashMap<String, Vec<String>> = values
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.iter().map(ToString::to_string).collect()))
+            .collect();
+        pollster::block_on(functions.evaluate(&values))
+    }
+
+    #[test_case(r#"{"ArnLike":{"aws:SourceArn":"arn:aws:sns:*:123456789012:*"}}"#)]
+    #[test_case(r#"{"ArnNotEquals":{"aws:PrincipalArn":"arn:aws:iam::123456789012:user/a"}}"#)]
+    #[test_case(r#"{"StringEqualsIfExists":{"aws:username":"alice"}}"#)]
+    #[test_case(r#"{"NumericGreaterThanIfExists":{"s3:max-keys":"10"}}"#)]
+    #[test_case(r#"{"ForAllValues:ArnLikeIfExists":{"aws:SourceArn":"arn:aws:s3:::bucket"}}"#)]
+    #[test_case(r#"{"ForAnyValue:StringLike":{"aws:groups":"eng-*"}}"#)]
+    #[test_case(r#"{"BinaryEquals":{"s3:x-amz-copy-source":"bXlidWNrZXQvbXlvYmplY3Q="}}"#)]
+    #[test_case(r#"{"DateLessThan":{"aws:CurrentTime":"2030-01-01T00:00:00Z"}}"#)]
+    #[test_case(r#"{"NumericLessThanEquals":{"s3:max-keys":"10"}}"#)]
+    fn test_round_trip(input: &str) {
+        let functions: Functions = serde_json::from_str(input).unwrap();
+        assert_eq!(serde_json::to_string(&functions).unwrap(), input);
+    }
+
+    #[test_case(r#"{"NullIfExists":{"aws:username":"true"}}"#)]
+    #[test_case(r#"{"StringEqualsIfExistsIfExists":{"aws:username":"alice"}}"#)]
+    #[test_case(r#"{"ForSomeValues:StringEquals":{"aws:username":"alice"}}"#)]
+    fn test_deser_failed(input: &str) {
+        assert!(serde_json::from_str::<Functions>(input).is_err());
+    }
+
+    #[test_case(r#"{"StringNotEquals":{"aws:username":"alice"}}"#, &[("username", &["alice"])] => false; "not equals match")]
+    #[test_case(r#"{"StringNotEquals":{"aws:username":"alice"}}"#, &[("username", &["bob"])] => true; "not equals mismatch")]
+    #[test_case(r#"{"StringNotEquals":{"aws:username":"alice"}}"#, &[] => true; "not equals absent")]
+    #[test_case(r#"{"StringEqualsIfExists":{"aws:username":"alice"}}"#, &[] => true; "if exists absent")]
+    #[test_case(r#"{"StringEqualsIfExists":{"aws:username":"alice"}}"#, &[("username", &["bob"])] => false; "if exists present")]
+    #[test_case(r#"{"NumericGreaterThanIfExists":{"s3:max-keys":"10"}}"#, &[("max-keys", &["10"])] => false; "numeric if exists strict")]
+    #[test_case(r#"{"NumericGreaterThanIfExists":{"s3:max-keys":"10"}}"#, &[("max-keys", &["11"])] => true; "numeric if exists greater")]
+    #[test_case(r#"{"NumericGreaterThanIfExists":{"s3:max-keys":"10"}}"#, &[] => true; "numeric if exists absent")]
+    #[test_case(r#"{"NumericLessThanEquals":{"s3:max-keys":"10"}}"#, &[("max-keys", &["10"])] => true; "numeric less than equals")]
+    #[test_case(r#"{"DateLessThan":{"aws:CurrentTime":"2030-01-01T00:00:00Z"}}"#, &[("CurrentTime", &["2026-01-01T00:00:00Z"])] => true; "date before")]
+    #[test_case(r#"{"DateLessThan":{"aws:CurrentTime":"2030-01-01T00:00:00Z"}}"#, &[("CurrentTime", &["2031-01-01T00:00:00Z"])] => false; "date after")]
+    #[test_case(r#"{"DateGreaterThan":{"aws:EpochTime":"2020-01-01T00:00:00Z"}}"#, &[("EpochTime", &["1700000000"])] => true; "date epoch")]
+    #[test_case(r#"{"DateGreaterThanIfExists":{"aws:CurrentTime":"2020-01-01T00:00:00Z"}}"#, &[] => true; "date if exists absent")]
+    #[test_case(r#"{"NotIpAddress":{"aws:SourceIp":"10.0.0.0/8"}}"#, &[("SourceIp", &["10.1.2.3"])] => false; "not ip inside")]
+    #[test_case(r#"{"NotIpAddress":{"aws:SourceIp":"10.0.0.0/8"}}"#, &[("SourceIp", &["192.168.1.1"])] => true; "not ip outside")]
+    #[test_case(r#"{"IpAddressIfExists":{"aws:SourceIp":"10.0.0.0/8"}}"#, &[] => true; "ip if exists absent")]
+    #[test_case(r#"{"BoolIfExists":{"aws:SecureTransport":"true"}}"#, &[("SecureTransport", &["false"])] => false; "bool if exists present")]
+    #[test_case(r#"{"BoolIfExists":{"aws:SecureTransport":"true"}}"#, &[] => true; "bool if exists absent")]
+    #[test_case(r#"{"BinaryEquals":{"s3:x-amz-copy-source":"bXlidWNrZXQvbXlvYmplY3Q="}}"#, &[("x-amz-copy-source", &["mybucket/myobject"])] => true; "binary equals")]
+    fn test_evaluate(conditions: &str, values: &[(&str, &[&str])]) -> bool {
+        eval(conditions, values)
+    }
+
+    #[test_case(r#"{"ArnEquals":{"aws:SourceArn":"arn:aws:sns:us-east-1:123456789012:topic"}}"#, &["arn:aws:sns:us-east-1:123456789012:topic"] => true; "arn equals")]
+    #[test_case(r#"{"ArnEquals":{"aws:SourceArn":"arn:aws:sns:us-east-1:123456789012:topic"}}"#, &["arn:aws:sns:us-east-1:123456789012:other"] => false; "arn equals mismatch")]
+    #[test_case(r#"{"ArnLike":{"aws:SourceArn":"arn:aws:sns:*:123456789012:*"}}"#, &["arn:aws:sns:eu-west-1:123456789012:topic"] => true; "arn like")]
+    #[test_case(r#"{"ArnLike":{"aws:SourceArn":"arn:aws:sns:*:123456789012:*"}}"#, &["arn:aws:sqs:eu-west-1:123456789012:queue"] => false; "arn like service")]
+    #[test_case(r#"{"ArnLike":{"aws:SourceArn":"arn:aws:s3:::bucket/*"}}"#, &["arn:aws:s3:::bucket/a:b/c"] => true; "arn like resource with colon")]
+    #[test_case(r#"{"ArnLike":{"aws:SourceArn":"arn:aws:*"}}"#, &["arn:aws:s3:::bucket"] => false; "arn like needs six components")]
+    #[test_case(r#"{"ArnLike":{"aws:SourceArn":"*:*:*:*:*:*"}}"#, &["notarn:aws:s3:::bucket"] => false; "arn like needs arn prefix")]
+    #[test_case(r#"{"ArnNotLike":{"aws:SourceArn":"arn:aws:sns:*:123456789012:*"}}"#, &["arn:aws:sns:eu-west-1:999999999999:topic"] => true; "arn not like")]
+    #[test_case(r#"{"ArnNotEquals":{"aws:SourceArn":"arn:aws:sns:us-east-1:123456789012:topic"}}"#, &["arn:aws:sns:us-east-1:123456789012:topic"] => false; "arn not equals")]
+    fn test_evaluate_arn(conditions: &str, arns: &[&str]) -> bool {
+        eval(conditions, &[("SourceArn", arns)])
+    }
+
+    #[test_case(r#"{"ForAllValues:StringEquals":{"aws:groups":["eng","ops"]}}"#, &["eng", "ops"] => true; "all values subset")]
+    #[test_case(r#"{"ForAllValues:StringEquals":{"aws:groups":["eng","ops"]}}"#, &["eng", "sales"] => false; "all values extra")]
+    #[test_case(r#"{"ForAllValues:StringEquals":{"aws:groups":["eng","ops"]}}"#, &[] => true; "all values absent")]
+    #[test_case(r#"{"ForAnyValue:StringEquals":{"aws:groups":["eng","ops"]}}"#, &["sales", "ops"] => true; "any value overlap")]
+    #[test_case(r#"{"ForAnyValue:StringEquals":{"aws:groups":["eng","ops"]}}"#, &["sales"] => false; "any value disjoint")]
+    #[test_case(r#"{"ForAnyValue:StringEquals":{"aws:groups":["eng","ops"]}}"#, &[] => false; "any value absent")]
+    #[test_case(r#"{"ForAllValues:ArnLike":{"aws:groups":"arn:aws:iam::*:group/eng-*"}}"#, &["arn:aws:iam::1:group/eng-a", "arn:aws:iam::1:group/ops"] => false; "all values arn")]
+    #[test_case(r#"{"ForAnyValue:ArnLike":{"aws:groups":"arn:aws:iam::*:group/eng-*"}}"#, &["arn:aws:iam::1:group/eng-a", "arn:aws:iam::1:group/ops"] => true; "any value arn")]
+    #[test_case(r#"{"ForAllValues:NumericLessThan":{"aws:groups":"10"}}"#, &["1", "9"] => true; "all values numeric")]
+    #[test_case(r#"{"ForAllValues:NumericLessThan":{"aws:groups":"10"}}"#, &["1", "10"] => false; "all values numeric bound")]
+    fn test_evaluate_qualifiers(conditions: &str, groups: &[&str]) -> bool {
+        eval(conditions, &[("groups", groups)])
+    }
+}
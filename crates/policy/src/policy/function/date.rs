@@ -20,26 +20,33 @@ use time::{OffsetDateTime, format_description::well_known::Rfc3339};
 pub type DateFunc = InnerFunc<DateFuncValue>;
 
 impl DateFunc {
-    pub fn evaluate(&self, op: impl Fn(&OffsetDateTime, &OffsetDateTime) -> bool, values: &HashMap<String, Vec<String>>) -> bool {
-        for inner in self.0.iter() {
-            let v = match values.get(inner.key.name().as_str()).and_then(|x| x.first()) {
-                Some(x) => x,
-                None => return false,
-            };
-
-            let Ok(rv) = OffsetDateTime::parse(v, &Rfc3339) else {
-                return false;
-            };
-
-            if !op(&inner.values.0, &rv) {
-                return false;
+    /// Compare request dates against the policy date with `op(request, policy)`
+    pub fn evaluate(
+        &self,
+        op: impl Fn(&OffsetDateTime, &OffsetDateTime) -> bool,
+        for_all: bool,
+        if_exists: bool,
+        values: &HashMap<String, Vec<String>>,
+    ) -> bool {
+        self.0.iter().all(|inner| {
+            if if_exists && inner.request_values(values).is_none() {
+                return true;
             }
-        }
 
-        true
+            inner.match_values(for_all, values, |v| parse_date(v).is_some_and(|rv| op(&rv, &inner.values.0)))
+        })
     }
 }
 
+/// Request dates are RFC 3339 strings, or epoch seconds as for `aws:EpochTime`
+fn parse_date(v: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(v, &Rfc3339).ok().or_else(|| {
+        v.parse::<i64>()
+            .ok()
+            .and_then(|secs| OffsetDateTime::from_unix_timestamp(secs).ok())
+    })
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct DateFuncValue(OffsetDateTime);
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
 use serde::{
@@ -30,6 +31,30 @@ pub struct FuncKeyValue<T> {
     pub values: T,
 }
 
+impl<T> FuncKeyValue<T> {
+    /// Request values of this key, `None` when the request does not carry it
+    pub(crate) fn request_values<'a>(&self, values: &'a HashMap<String, Vec<String>>) -> Option<&'a Vec<String>> {
+        values.get(self.key.name().as_str()).filter(|v| !v.is_empty())
+    }
+
+    /// Match the request values of this key one by one.
+    ///
+    /// With `for_all` (`ForAllValues:`) every value must match, which holds trivially for an absent key;
+    /// otherwise at least one value must match.
+    pub(crate) fn match_values(
+        &self,
+        for_all: bool,
+        values: &HashMap<String, Vec<String>>,
+        matches: impl Fn(&str) -> bool,
+    ) -> bool {
+        match self.request_values(values) {
+            Some(rvalues) if for_all => rvalues.iter().all(|v| matches(v)),
+            Some(rvalues) => rvalues.iter().any(|v| matches(v)),
+            None => for_all,
+        }
+    }
+}
+
 impl<T: Clone> Clone for FuncKeyValue<T> {
     fn clone(&self) -> Self {
         Self {
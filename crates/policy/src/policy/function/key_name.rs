@@ -318,6 +318,12 @@ pub enum AwsKeyName {
 
     #[strum(serialize = "aws:groups")]
     AWSGroups,
+
+    #[strum(serialize = "aws:SourceArn")]
+    AWSSourceArn,
+
+    #[strum(serialize = "aws:PrincipalArn")]
+    AWSPrincipalArn,
 }
 
 #[cfg(test)]
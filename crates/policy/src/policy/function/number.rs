@@ -26,23 +26,20 @@ pub type NumberFunc = InnerFunc<NumberFuncValue>;
 pub struct NumberFuncValue(i64);
 
 impl NumberFunc {
-    pub fn evaluate(&self, op: impl Fn(&i64, &i64) -> bool, if_exists: bool, values: &HashMap<String, Vec<String>>) -> bool {
-        for inner in self.0.iter() {
-            let v = match values.get(inner.key.name().as_str()).and_then(|x| x.first()) {
-                Some(x) => x,
-                None => return if_exists,
-            };
-
-            let Ok(rv) = v.parse::<i64>() else {
-                return false;
-            };
-
-            if !op(&rv, &inner.values.0) {
-                return false;
+    pub fn evaluate(
+        &self,
+        op: impl Fn(&i64, &i64) -> bool,
+        for_all: bool,
+        if_exists: bool,
+        values: &HashMap<String, Vec<String>>,
+    ) -> bool {
+        self.0.iter().all(|inner| {
+            if if_exists && inner.request_values(values).is_none() {
+                return true;
             }
-        }
 
-        true
+            inner.match_values(for_all, values, |v| v.parse::<i64>().is_ok_and(|rv| op(&rv, &inner.values.0)))
+        })
     }
 }
 
@@ -37,10 +37,15 @@ impl StringFunc {
         ignore_case: bool,
         like: bool,
         negate: bool,
+        if_exists: bool,
         values: &HashMap<String, Vec<String>>,
         resolver: Option<&dyn PolicyVariableResolver>,
     ) -> bool {
         for inner in self.0.iter() {
+            if if_exists && inner.request_values(values).is_none() {
+                continue;
+            }
+
             let result = if like {
                 inner.eval_like(for_all, values, resolver).await ^ negate
             } else {
@@ -54,9 +59,75 @@ impl StringFunc {
 
         true
     }
+
+    /// `ArnEquals`/`ArnLike` and their negations; both match the six colon separated ARN components
+    /// one by one with wildcards
+    pub(crate) async fn evaluate_arn(
+        &self,
+        for_all: bool,
+        negate: bool,
+        if_exists: bool,
+        values: &HashMap<String, Vec<String>>,
+        resolver: Option<&dyn PolicyVariableResolver>,
+    ) -> bool {
+        for inner in self.0.iter() {
+            if if_exists && inner.request_values(values).is_none() {
+                continue;
+            }
+
+            let patterns = inner.policy_values(values, resolver).await;
+            let matched = inner.match_values(for_all, values, |v| patterns.iter().any(|p| arn_match(p, v)));
+            if !(matched ^ negate) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+fn arn_match(pattern: &str, arn: &str) -> bool {
+    let pattern: Vec<&str> = pattern.splitn(6, ':').collect();
+    let arn: Vec<&str> = arn.splitn(6, ':').collect();
+
+    pattern.len() == 6
+        && arn.len() == 6
+        && arn[0] == "arn"
+        && pattern.iter().zip(arn.iter()).all(|(p, a)| wildcard::is_match(p, a))
 }
 
 impl FuncKeyValue<StringFuncValue> {
+    /// Policy values with policy variables and common condition keys substituted
+    async fn policy_values(
+        &self,
+        values: &HashMap<String, Vec<String>>,
+        resolver: Option<&dyn PolicyVariableResolver>,
+    ) -> Vec<String> {
+        let resolved_values: Vec<Vec<String>> = future::join_all(self.values.0.iter().map(|c| async {
+            if let Some(res) = resolver {
+                super::super::variables::resolve_aws_variables(c, res).await
+            } else {
+                vec![c.to_string()]
+            }
+        }))
+        .await;
+
+        resolved_values
+            .into_iter()
+            .flatten()
+            .map(|c| {
+                for key in KeyName::COMMON_KEYS {
+                    match values.get(key.name()).and_then(|x| x.first()) {
+                        Some(v) if !v.is_empty() => return c.replace(&key.var_name(), v),
+                        _ => continue,
+                    };
+                }
+
+                c
+            })
+            .collect()
+    }
+
     async fn eval(
         &self,
         for_all: bool,
@@ -122,35 +193,9 @@ impl FuncKeyValue<StringFuncValue> {
         resolver: Option<&dyn PolicyVariableResolver>,
     ) -> bool {
         if let Some(rvalues) = values.get(self.key.name().as_str()) {
+            let patterns = self.policy_values(values, resolver).await;
             for v in rvalues.iter() {
-                let resolved_futures: Vec<_> = self
-                    .values
-                    .0
-                    .iter()
-                    .map(|c| async {
-                        if let Some(res) = resolver {
-                            super::super::variables::resolve_aws_variables(c, res).await
-                        } else {
-                            vec![c.to_string()]
-                        }
-                    })
-                    .collect();
-                let resolved_values = future::join_all(resolved_futures).await;
-                let matched = resolved_values
-                    .into_iter()
-                    .flatten()
-                    .map(|resolved_c| {
-                        let mut c = Cow::from(resolved_c);
-                        for key in KeyName::COMMON_KEYS {
-                            match values.get(key.name()).and_then(|x| x.first()) {
-                                Some(v) if !v.is_empty() => return Cow::Owned(c.to_mut().replace(&key.var_name(), v)),
-                                _ => continue,
-                            };
-                        }
-
-                        c
-                    })
-                    .any(|x| wildcard::is_match(x, v));
+                let matched = patterns.iter().any(|x| wildcard::is_match(x, v));
 
                 if for_all {
                     if !matched {
@@ -216,6 +216,11 @@ impl BucketPolicy {
 
         false
     }
+
+    /// Whether any statement grants public access, see [`BPStatement::is_public`]
+    pub fn is_public(&self) -> bool {
+        self.statements.iter().any(BPStatement::is_public)
+    }
 }
 
 impl Validator for BucketPolicy {
@@ -473,6 +478,7 @@ pub mod default {
 mod test {
     use super::*;
     use crate::error::Result;
+    use test_case::test_case;
 
     #[tokio::test]
     async fn test_parse_policy() -> Result<()> {
@@ -1074,6 +1080,7 @@ mod test {
                 sid: ID::default(), // Empty Sid
                 effect: Effect::Allow,
                 principal,
+                not_principal: Principal::default(), // Empty NotPrincipal
                 actions: ActionSet::default(),
                 not_actions: ActionSet::default(), // Empty NotAction
                 resources: ResourceSet::default(),
@@ -1100,6 +1107,10 @@ mod test {
 
         let statement = &parsed["Statement"][0];
         assert!(!statement.as_object().unwrap().contains_key("Sid"), "Empty Sid should be omitted");
+        assert!(
+            !statement.as_object().unwrap().contains_key("NotPrincipal"),
+            "Empty NotPrincipal should be omitted"
+        );
         assert!(
             !statement.as_object().unwrap().contains_key("NotAction"),
             "Empty NotAction should be omitted"
@@ -1160,4 +1171,83 @@ mod test {
         assert_eq!(arr.len(), 1);
         assert_eq!(arr[0].as_str().unwrap(), "s3:ListBucket");
     }
+
+    const DENY_EXCEPT_BACKUP: &str = r#"
+{
+  "Version": "2012-10-17",
+  "Statement": [
+    {
+      "Effect": "Allow",
+      "Principal": {"AWS": "*"},
+      "Action": ["s3:GetObject"],
+      "Resource": ["arn:aws:s3:::backups/*"]
+    },
+    {
+      "Effect": "Deny",
+      "NotPrincipal": {"AWS": ["arn:aws:iam::123456789012:role/backup", "arn:aws:sts::123456789012:assumed-role/backup/*"]},
+      "Action": ["s3:GetObject"],
+      "Resource": ["arn:aws:s3:::backups/*"]
+    }
+  ]
+}
+"#;
+
+    #[test_case(&[] => false; "anonymous")]
+    #[test_case(&["arn:aws:iam:::user/alice"] => false; "other user")]
+    #[test_case(&["arn:aws:iam:::role/backup", "arn:aws:sts:::assumed-role/backup/nightly"] => true; "backup session")]
+    fn test_bucket_policy_not_principal(principal_arns: &[&str]) -> bool {
+        use crate::policy::action::S3Action;
+
+        let policy: BucketPolicy = serde_json::from_str(DENY_EXCEPT_BACKUP).unwrap();
+        policy.is_valid().unwrap();
+
+        let mut conditions = HashMap::new();
+        if !principal_arns.is_empty() {
+            conditions.insert(
+                crate::policy::PRINCIPAL_ARN_KEY.to_string(),
+                principal_arns.iter().map(ToString::to_string).collect(),
+            );
+        }
+
+        pollster::block_on(policy.is_allowed(&BucketPolicyArgs {
+            account: "",
+            groups: &None,
+            action: Action::S3Action(S3Action::GetObjectAction),
+            bucket: "backups",
+            conditions: &conditions,
+            is_owner: false,
+            object: "db.dump",
+        }))
+    }
+
+    #[test]
+    fn test_bucket_policy_not_principal_round_trip() {
+        let policy: BucketPolicy = serde_json::from_str(DENY_EXCEPT_BACKUP).unwrap();
+        let json: serde_json::Value = serde_json::to_value(&policy).unwrap();
+
+        assert!(json["Statement"][1].get("Principal").is_none());
+        assert_eq!(json["Statement"][1]["NotPrincipal"]["AWS"].as_array().map(Vec::len), Some(2));
+    }
+
+    #[test_case(r#"{"Effect":"Allow","Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"]}"#; "no principal")]
+    #[test_case(r#"{"Effect":"Allow","Principal":"*","NotPrincipal":{"AWS":"arn:aws:iam:::user/a"},"Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"]}"#; "both principals")]
+    fn test_bucket_policy_principal_invalid(statement: &str) {
+        let policy: BucketPolicy =
+            serde_json::from_str(&format!(r#"{{"Version":"2012-10-17","Statement":[{statement}]}}"#)).unwrap();
+        assert!(policy.is_valid().is_err());
+    }
+
+    #[test_case(r#"{"Effect":"Allow","Principal":"*","Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"]}"# => true; "anyone")]
+    #[test_case(r#"{"Effect":"Allow","Principal":{"AWS":["*"]},"Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"]}"# => true; "aws wildcard")]
+    #[test_case(r#"{"Effect":"Allow","NotPrincipal":{"AWS":"arn:aws:iam:::user/a"},"Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"]}"# => true; "not principal")]
+    #[test_case(r#"{"Effect":"Deny","Principal":"*","Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"]}"# => false; "deny")]
+    #[test_case(r#"{"Effect":"Allow","Principal":{"AWS":"arn:aws:iam:::user/a"},"Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"]}"# => false; "named user")]
+    #[test_case(r#"{"Effect":"Allow","Principal":"*","Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"],"Condition":{"IpAddress":{"aws:SourceIp":"10.0.0.0/8"}}}"# => false; "source ip")]
+    #[test_case(r#"{"Effect":"Allow","Principal":"*","Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"],"Condition":{"IpAddress":{"aws:SourceIp":"0.0.0.0/0"}}}"# => true; "any source ip")]
+    #[test_case(r#"{"Effect":"Allow","Principal":"*","Action":["s3:GetObject"],"Resource":["arn:aws:s3:::b/*"],"Condition":{"NotIpAddress":{"aws:SourceIp":"10.0.0.0/8"}}}"# => true; "not source ip")]
+    fn test_bucket_policy_is_public(statement: &str) -> bool {
+        let policy: BucketPolicy =
+            serde_json::from_str(&format!(r#"{{"Version":"2012-10-17","Statement":[{statement}]}}"#)).unwrap();
+        policy.is_public()
+    }
 }
@@ -14,9 +14,16 @@
 
 use super::{Validator, utils::wildcard};
 use crate::error::Error;
+use rustfs_credentials::Credentials;
 use serde::Serialize;
+use serde_json::Value;
 use std::collections::HashSet;
 
+/// Condition key (`aws:PrincipalArn`) carrying the ARNs the request principal is known by
+pub const PRINCIPAL_ARN_KEY: &str = "PrincipalArn";
+
+const ARN_PREFIX: &str = "arn:";
+
 /// Principal that serializes AWS field as single string when containing only "*",
 /// or as an array otherwise (matching AWS S3 API format).
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -97,14 +104,93 @@ impl<'de> serde::Deserialize<'de> for Principal {
 }
 
 impl Principal {
+    pub fn is_empty(&self) -> bool {
+        self.aws.is_empty()
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        self.aws.contains("*")
+    }
+
     pub fn is_match(&self, parincipal: &str) -> bool {
         for pattern in self.aws.iter() {
-            if wildcard::is_simple_match(pattern, parincipal) {
+            let matched = if pattern.starts_with(ARN_PREFIX) && parincipal.starts_with(ARN_PREFIX) {
+                arn_match(pattern, parincipal)
+            } else {
+                wildcard::is_simple_match(pattern, parincipal)
+            };
+
+            if matched {
                 return true;
             }
         }
         false
     }
+
+    /// Match the request account, or any of the principal ARNs resolved for it
+    pub fn is_match_any(&self, account: &str, principal_arns: Option<&Vec<String>>) -> bool {
+        self.is_match(account) || principal_arns.is_some_and(|arns| arns.iter().any(|arn| self.is_match(arn)))
+    }
+}
+
+/// Compare two principal ARNs component by component. The account id is ignored: a deployment
+/// is a single account, so `arn:aws:iam::123456789012:user/alice` and `arn:aws:iam:::user/alice` are the same user.
+fn arn_match(pattern: &str, arn: &str) -> bool {
+    let pattern: Vec<&str> = pattern.splitn(6, ':').collect();
+    let arn: Vec<&str> = arn.splitn(6, ':').collect();
+
+    pattern.len() == 6
+        && arn.len() == 6
+        && pattern
+            .iter()
+            .zip(arn.iter())
+            .enumerate()
+            .all(|(i, (p, a))| i == 4 || wildcard::is_simple_match(p, a))
+}
+
+/// ARNs the holder of `cred` is known by in bucket policies.
+///
+/// Service accounts act as their parent user. STS sessions are known by their role, or the parent
+/// user for `AssumeRole`, and by their `assumed-role` session ARN. `is_root` tells whether the user
+/// behind the credential is the root account.
+pub fn principal_arns(cred: &Credentials, is_root: bool) -> Vec<String> {
+    if cred.access_key.is_empty() {
+        return Vec::new();
+    }
+
+    let user = if cred.is_temp() || cred.is_service_account() {
+        cred.parent_user.as_str()
+    } else {
+        cred.access_key.as_str()
+    };
+
+    let user_arn = if is_root {
+        "arn:aws:iam:::root".to_string()
+    } else {
+        format!("arn:aws:iam:::user/{user}")
+    };
+
+    if !cred.is_temp() || cred.is_service_account() {
+        return vec![user_arn];
+    }
+
+    let claim = |name: &str| {
+        cred.claims
+            .as_ref()
+            .and_then(|claims| claims.get(name))
+            .and_then(Value::as_str)
+            .filter(|v| !v.is_empty())
+    };
+
+    let session = claim("roleSessionName").unwrap_or(cred.access_key.as_str());
+
+    match claim("roleArn").and_then(|arn| arn.split_once(":role/")) {
+        Some((_, role)) => vec![
+            format!("arn:aws:iam:::role/{role}"),
+            format!("arn:aws:sts:::assumed-role/{role}/{session}"),
+        ],
+        None => vec![user_arn, format!("arn:aws:sts:::assumed-role/{user}/{session}")],
+    }
 }
 
 impl Validator for Principal {
@@ -141,6 +227,59 @@ mod test {
         assert!(result);
     }
 
+    #[test_case("*", "arn:aws:iam:::user/alice" => true; "wildcard")]
+    #[test_case("alice", "alice" => true; "access key")]
+    #[test_case("arn:aws:iam::123456789012:user/alice", "arn:aws:iam:::user/alice" => true; "account ignored")]
+    #[test_case("arn:aws:iam::123456789012:user/alice", "arn:aws:iam:::user/bob" => false; "other user")]
+    #[test_case("arn:aws:iam::*:user/team-*", "arn:aws:iam:::user/team-a" => true; "user wildcard")]
+    #[test_case("arn:aws:iam::123456789012:role/backup", "arn:aws:sts:::assumed-role/backup/s1" => false; "role is not session")]
+    #[test_case("arn:aws:sts::123456789012:assumed-role/backup/*", "arn:aws:sts:::assumed-role/backup/s1" => true; "session wildcard")]
+    #[test_case("arn:aws:iam::123456789012:user/alice", "alice" => false; "arn against access key")]
+    fn test_principal_is_match(pattern: &str, principal: &str) -> bool {
+        let principal_json = format!(r#"{{"AWS": "{pattern}"}}"#);
+        serde_json::from_str::<Principal>(&principal_json)
+            .unwrap()
+            .is_match(principal)
+    }
+
+    fn cred(access_key: &str, parent_user: &str, claims: &[(&str, &str)]) -> Credentials {
+        Credentials {
+            access_key: access_key.to_string(),
+            session_token: if claims.is_empty() {
+                String::new()
+            } else {
+                "token".to_string()
+            },
+            parent_user: parent_user.to_string(),
+            claims: (!claims.is_empty()).then(|| {
+                claims
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), Value::String(v.to_string())))
+                    .collect()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_principal_arns() {
+        assert!(principal_arns(&Credentials::default(), false).is_empty());
+        assert_eq!(principal_arns(&cred("alice", "", &[]), false), vec!["arn:aws:iam:::user/alice"]);
+        assert_eq!(principal_arns(&cred("rustfsadmin", "", &[]), true), vec!["arn:aws:iam:::root"]);
+        assert_eq!(
+            principal_arns(&cred("svc", "alice", &[("sa-policy", "inherited-policy")]), false),
+            vec!["arn:aws:iam:::user/alice"]
+        );
+        assert_eq!(
+            principal_arns(&cred("tmp", "alice", &[("parent", "alice"), ("roleSessionName", "nightly")]), false),
+            vec!["arn:aws:iam:::user/alice", "arn:aws:sts:::assumed-role/alice/nightly"]
+        );
+        assert_eq!(
+            principal_arns(&cred("tmp", "openid:sub", &[("roleArn", "arn:rustfs:iam:us-east-1::role/backup")]), false),
+            vec!["arn:aws:iam:::role/backup", "arn:aws:sts:::assumed-role/backup/tmp"]
+        );
+    }
+
     #[test]
     fn test_principal_serialize_single_element() {
         // Single element should serialize as string (AWS format)
@@ -13,13 +13,17 @@
 // limitations under the License.
 
 use super::{
-    ActionSet, Args, BucketPolicyArgs, Effect, Error as IamError, Functions, ID, Principal, ResourceSet, Validator,
+    ActionSet, Args, BucketPolicyArgs, Effect, Error as IamError, Functions, ID, PRINCIPAL_ARN_KEY, Principal, ResourceSet,
+    Validator,
     action::Action,
     variables::{VariableContext, VariableResolver},
 };
 use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
 
+/// Condition keys that pin a statement to known callers, so it no longer counts as public
+const RESTRICTING_CONDITION_KEYS: [&str; 4] = ["aws:SourceIp", "aws:SourceArn", "aws:PrincipalArn", "aws:userid"];
+
 #[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub struct Statement {
     #[serde(rename = "Sid", default)]
@@ -188,8 +192,10 @@ pub struct BPStatement {
     pub sid: ID,
     #[serde(rename = "Effect")]
     pub effect: Effect,
-    #[serde(rename = "Principal")]
+    #[serde(rename = "Principal", default, skip_serializing_if = "Principal::is_empty")]
     pub principal: Principal,
+    #[serde(rename = "NotPrincipal", default, skip_serializing_if = "Principal::is_empty")]
+    pub not_principal: Principal,
     #[serde(rename = "Action")]
     pub actions: ActionSet,
     #[serde(rename = "NotAction", default, skip_serializing_if = "ActionSet::is_empty")]
@@ -205,7 +211,12 @@ pub struct BPStatement {
 impl BPStatement {
     pub async fn is_allowed(&self, args: &BucketPolicyArgs<'_>) -> bool {
         let check = 'c: {
-            if !self.principal.is_match(args.account) {
+            let principal_arns = args.conditions.get(PRINCIPAL_ARN_KEY);
+            if !self.principal.is_empty() && !self.principal.is_match_any(args.account, principal_arns) {
+                break 'c false;
+            }
+
+            if !self.not_principal.is_empty() && self.not_principal.is_match_any(args.account, principal_arns) {
                 break 'c false;
             }
 
@@ -237,6 +248,37 @@ impl BPStatement {
 
         self.effect.is_allowed(check)
     }
+
+    /// Whether the statement allows anyone in, following the S3 definition of a public policy:
+    /// an Allow for every principal (`*` or a `NotPrincipal`) that no condition narrows to fixed
+    /// source addresses, ARNs or users.
+    pub fn is_public(&self) -> bool {
+        if !matches!(self.effect, Effect::Allow) || !(self.principal.is_wildcard() || !self.not_principal.is_empty()) {
+            return false;
+        }
+
+        let Ok(serde_json::Value::Object(operators)) = serde_json::to_value(&self.conditions) else {
+            return true;
+        };
+
+        !operators.iter().any(|(operator, keys)| {
+            let name = operator.rsplit(':').next().unwrap_or(operator);
+            if name.contains("Not") || name == "Null" {
+                return false;
+            }
+
+            keys.as_object().is_some_and(|keys| {
+                keys.iter().any(|(key, values)| {
+                    let fixed = |v: &serde_json::Value| v.as_str().is_some_and(|v| !matches!(v, "*" | "0.0.0.0/0" | "::/0"));
+                    RESTRICTING_CONDITION_KEYS.iter().any(|k| k.eq_ignore_ascii_case(key))
+                        && match values {
+                            serde_json::Value::Array(values) => !values.is_empty() && values.iter().all(fixed),
+                            value => fixed(value),
+                        }
+                })
+            })
+        })
+    }
 }
 
 impl Validator for BPStatement {
@@ -246,7 +288,13 @@ impl Validator for BPStatement {
         // check sid
         self.sid.is_valid()?;
 
-        self.principal.is_valid()?;
+        if self.principal.is_empty() && self.not_principal.is_empty() {
+            return Err(IamError::NonPrincipal.into());
+        }
+
+        if !self.principal.is_empty() && !self.not_principal.is_empty() {
+            return Err(IamError::BothPrincipalAndNotPrincipal.into());
+        }
 
         if self.actions.is_empty() && self.not_actions.is_empty() {
             return Err(IamError::NonAction.into());
@@ -40,6 +40,16 @@ pub trait StorageBackend: Send + Sync {
     /// Put object content with metadata
     async fn put_object(&self, input: PutObjectInput, access_key: &str, secret_key: &str)
     -> Result<PutObjectOutput, Self::Error>;
+    /// Copy an object to a new bucket/key
+    async fn copy_object(
+        &self,
+        src_bucket: &str,
+        src_key: &str,
+        dst_bucket: &str,
+        dst_key: &str,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<CopyObjectOutput, Self::Error>;
     /// Delete an object
     async fn delete_object(
         &self,
@@ -71,4 +81,38 @@ pub trait StorageBackend: Send + Sync {
     async fn create_bucket(&self, bucket: &str, access_key: &str, secret_key: &str) -> Result<CreateBucketOutput, Self::Error>;
     /// Delete a bucket (must be empty)
     async fn delete_bucket(&self, bucket: &str, access_key: &str, secret_key: &str) -> Result<DeleteBucketOutput, Self::Error>;
+    /// Start a multipart upload
+    async fn create_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<CreateMultipartUploadOutput, Self::Error>;
+    /// Upload one part of a multipart upload
+    async fn upload_part(
+        &self,
+        input: UploadPartInput,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<UploadPartOutput, Self::Error>;
+    /// Assemble the uploaded parts into the object
+    async fn complete_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        upload_id: &str,
+        parts: Vec<CompletedPart>,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<CompleteMultipartUploadOutput, Self::Error>;
+    /// Abort a multipart upload and discard its parts
+    async fn abort_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        upload_id: &str,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<AbortMultipartUploadOutput, Self::Error>;
 }
@@ -155,6 +155,68 @@ pub fn is_operation_supported(protocol: super::session::Protocol, action: &S3Act
             S3Action::ListBuckets => true, // LIST at root level
             S3Action::HeadBucket => true,  // Can check if directory exists
         },
+        super::session::Protocol::Sftp => match action {
+            // Bucket operations
+            S3Action::CreateBucket => true, // MKDIR at root level
+            S3Action::DeleteBucket => true, // RMDIR at root level
+
+            // Object operations
+            S3Action::GetObject => true,    // OPEN for read + READ
+            S3Action::PutObject => true,    // OPEN for write + WRITE, MKDIR below a bucket
+            S3Action::DeleteObject => true, // REMOVE, RMDIR below a bucket
+            S3Action::HeadObject => true,   // STAT / LSTAT / FSTAT
+
+            // Multipart operations
+            S3Action::CreateMultipartUpload => false,
+            S3Action::UploadPart => false,
+            S3Action::CompleteMultipartUpload => false,
+            S3Action::AbortMultipartUpload => false,
+            S3Action::ListMultipartUploads => false,
+            S3Action::ListParts => false,
+
+            // ACL operations
+            S3Action::GetBucketAcl => false,
+            S3Action::PutBucketAcl => false,
+            S3Action::GetObjectAcl => false,
+            S3Action::PutObjectAcl => false,
+
+            // Other operations
+            S3Action::CopyObject => false, // RENAME is not atomic on S3
+            S3Action::ListBucket => true,  // OPENDIR + READDIR
+            S3Action::ListBuckets => true, // READDIR at root level
+            S3Action::HeadBucket => true,  // STAT on a bucket directory
+        },
+        super::session::Protocol::WebDav => match action {
+            // Bucket operations
+            S3Action::CreateBucket => true, // MKCOL at root level
+            S3Action::DeleteBucket => true, // DELETE of a top-level collection
+
+            // Object operations
+            S3Action::GetObject => true,    // GET
+            S3Action::PutObject => true,    // PUT, MKCOL below a bucket
+            S3Action::DeleteObject => true, // DELETE, source side of MOVE
+            S3Action::HeadObject => true,   // HEAD / PROPFIND
+
+            // Multipart operations
+            S3Action::CreateMultipartUpload => false,
+            S3Action::UploadPart => false,
+            S3Action::CompleteMultipartUpload => false,
+            S3Action::AbortMultipartUpload => false,
+            S3Action::ListMultipartUploads => false,
+            S3Action::ListParts => false,
+
+            // ACL operations
+            S3Action::GetBucketAcl => false,
+            S3Action::PutBucketAcl => false,
+            S3Action::GetObjectAcl => false,
+            S3Action::PutObjectAcl => false,
+
+            // Other operations
+            S3Action::CopyObject => true,  // COPY and MOVE
+            S3Action::ListBucket => true,  // PROPFIND with Depth: 1
+            S3Action::ListBuckets => true, // PROPFIND on the root collection
+            S3Action::HeadBucket => true,  // PROPFIND on a bucket collection
+        },
     }
 }
 
@@ -20,6 +20,8 @@ use std::sync::Arc;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Protocol {
     Ftps,
+    Sftp,
+    WebDav,
 }
 
 /// Protocol principal representing an authenticated user
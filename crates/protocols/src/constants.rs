@@ -46,6 +46,36 @@ pub mod ftps {
     pub const PASSIVE_PORTS_PART_COUNT: usize = 2;
 }
 
+/// SFTP constants
+#[cfg(feature = "sftp")]
+pub mod sftp {
+    /// SSH subsystem name requested by SFTP clients
+    pub const SUBSYSTEM_NAME: &str = "sftp";
+    /// Upper bound for a single SSH_FXP_READ response
+    pub const MAX_READ_LEN: u32 = 256 * 1024;
+    /// Number of keys requested per ListObjectsV2 page when reading a directory
+    pub const LIST_PAGE_SIZE: i32 = 1000;
+    /// Owner name reported in directory listings
+    pub const OWNER_NAME: &str = "rustfs";
+    /// Size of the multipart upload parts a written file is sent in, and so the most buffered per open file
+    pub const UPLOAD_PART_SIZE: usize = 16 * 1024 * 1024;
+    /// Largest file that can be written, bounded by the number of parts of a multipart upload
+    pub const MAX_FILE_SIZE: u64 = UPLOAD_PART_SIZE as u64 * 10_000;
+}
+
+/// WebDAV constants
+#[cfg(feature = "webdav")]
+pub mod webdav {
+    /// Authentication realm announced in WWW-Authenticate challenges
+    pub const AUTH_REALM: &str = "RustFS";
+    /// HTTP authentication scheme accepted by the WebDAV server
+    pub const BASIC_AUTH_SCHEME: &str = "Basic ";
+    /// Number of keys requested per ListObjectsV2 page when reading a collection
+    pub const LIST_PAGE_SIZE: i32 = 1000;
+    /// Largest file that can be written, files are uploaded with a single PutObject
+    pub const MAX_FILE_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+}
+
 /// Default configuration values
 pub mod defaults {
     /// Default protocol addresses
@@ -0,0 +1,72 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::net::SocketAddr;
+use std::time::Duration;
+use thiserror::Error;
+
+/// SFTP server initialization error
+#[derive(Debug, Error)]
+pub enum SftpInitError {
+    #[error("failed to bind address {0}")]
+    Bind(#[from] std::io::Error),
+    #[error("SSH error: {0}")]
+    Ssh(#[from] russh::Error),
+    #[error("failed to load host key: {0}")]
+    HostKey(String),
+    #[error("invalid SFTP configuration: {0}")]
+    InvalidConfig(String),
+}
+
+/// SFTP server configuration
+#[derive(Debug, Clone)]
+pub struct SftpConfig {
+    /// Server bind address
+    pub bind_addr: SocketAddr,
+    /// OpenSSH private host key file path (an ephemeral key is generated when unset)
+    pub host_key_file: Option<String>,
+    /// Whether password (access key / secret key) authentication is accepted
+    pub password_auth: bool,
+    /// Idle time after which a session is disconnected
+    pub idle_timeout: Duration,
+}
+
+impl SftpConfig {
+    /// Validates the configuration
+    pub async fn validate(&self) -> Result<(), SftpInitError> {
+        if let Some(path) = &self.host_key_file
+            && !tokio::fs::try_exists(path).await.unwrap_or(false)
+        {
+            return Err(SftpInitError::InvalidConfig(format!("Host key file not found: {}", path)));
+        }
+
+        if self.idle_timeout.is_zero() {
+            return Err(SftpInitError::InvalidConfig("Idle timeout must be greater than zero".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for SftpConfig {
+    fn default() -> Self {
+        Self {
+            bind_addr: rustfs_config::DEFAULT_SFTP_ADDRESS.parse().unwrap(),
+            host_key_file: None,
+            password_auth: true,
+            idle_timeout: Duration::from_secs(rustfs_config::DEFAULT_SFTP_IDLE_TIMEOUT),
+        }
+    }
+}
@@ -0,0 +1,886 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::client::s3::StorageBackend as S3StorageBackend;
+use crate::common::gateway::{S3Action, authorize_operation};
+use crate::common::session::SessionContext;
+use crate::constants::paths::{DIR_MODE, DIR_PERMISSIONS, FILE_MODE, FILE_PERMISSIONS, PATH_SEPARATOR, ROOT_PATH};
+use crate::constants::sftp::{LIST_PAGE_SIZE, MAX_FILE_SIZE, MAX_READ_LEN, OWNER_NAME, UPLOAD_PART_SIZE};
+use futures_util::{StreamExt, stream};
+use russh_sftp::protocol::{Attrs, Data, File, FileAttributes, Handle, Name, OpenFlags, Status, StatusCode, Version};
+use rustfs_utils::path;
+use s3s::dto::*;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use tracing::{debug, error};
+
+/// State behind an SFTP handle
+#[derive(Debug)]
+enum OpenHandle {
+    /// Directory opened with OPENDIR; `bucket` is `None` for the root listing
+    Dir {
+        bucket: Option<String>,
+        prefix: Option<String>,
+        /// Where the next READDIR continues the listing
+        continuation_token: Option<String>,
+        exhausted: bool,
+    },
+    /// Object opened for reading
+    Read { bucket: String, key: String, size: u64 },
+    /// Object opened for writing
+    Write(WriteHandle),
+}
+
+/// Object being written. Data is buffered until a part is full; a file larger than one part is
+/// sent as a multipart upload completed on CLOSE, a smaller one is uploaded whole on CLOSE.
+#[derive(Debug)]
+struct WriteHandle {
+    bucket: String,
+    key: String,
+    /// Bytes of the file already sent as parts
+    uploaded: u64,
+    /// Data following the uploaded parts
+    buffer: Vec<u8>,
+    upload_id: Option<String>,
+    parts: Vec<CompletedPart>,
+}
+
+impl WriteHandle {
+    fn new(bucket: String, key: String) -> Self {
+        Self {
+            bucket,
+            key,
+            uploaded: 0,
+            buffer: Vec::new(),
+            upload_id: None,
+            parts: Vec::new(),
+        }
+    }
+
+    fn size(&self) -> u64 {
+        self.uploaded + self.buffer.len() as u64
+    }
+
+    /// Copy data written at `offset` into the buffer
+    fn write_at(&mut self, offset: u64, data: &[u8]) -> Result<(), StatusCode> {
+        let end = offset
+            .checked_add(data.len() as u64)
+            .filter(|end| *end <= MAX_FILE_SIZE)
+            .ok_or(StatusCode::Failure)?;
+
+        // Uploaded parts cannot be rewritten, and a write far past the buffered data would need a large zero fill
+        if offset < self.uploaded || end - self.uploaded > 2 * UPLOAD_PART_SIZE as u64 {
+            return Err(StatusCode::OpUnsupported);
+        }
+
+        let start = (offset - self.uploaded) as usize;
+        let end = (end - self.uploaded) as usize;
+        if self.buffer.len() < end {
+            self.buffer.resize(end, 0);
+        }
+        self.buffer[start..end].copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Take the next full part off the buffer
+    fn take_part(&mut self) -> Option<Vec<u8>> {
+        if self.buffer.len() < UPLOAD_PART_SIZE {
+            return None;
+        }
+        let rest = self.buffer.split_off(UPLOAD_PART_SIZE);
+        self.uploaded += UPLOAD_PART_SIZE as u64;
+        Some(std::mem::replace(&mut self.buffer, rest))
+    }
+}
+
+/// SFTP subsystem driver translating SFTP requests into S3 operations
+pub struct SftpDriver<S> {
+    /// Storage backend for S3 operations
+    storage: S,
+    /// Session context of the authenticated user
+    session_context: SessionContext,
+    /// Open file and directory handles
+    handles: HashMap<String, OpenHandle>,
+    /// Counter used to generate handle identifiers
+    next_handle: u64,
+}
+
+impl<S> Debug for SftpDriver<S>
+where
+    S: S3StorageBackend + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SftpDriver")
+            .field("storage", &"StorageBackend")
+            .field("user", &self.session_context.access_key())
+            .field("handles", &self.handles.len())
+            .finish()
+    }
+}
+
+fn ok_status(id: u32) -> Status {
+    Status {
+        id,
+        status_code: StatusCode::Ok,
+        error_message: "Ok".to_string(),
+        language_tag: "en-US".to_string(),
+    }
+}
+
+fn to_unix_secs(ts: Option<Timestamp>) -> Option<u32> {
+    ts.map(|dt| {
+        let offset_dt: time::OffsetDateTime = dt.into();
+        offset_dt.unix_timestamp().clamp(0, u32::MAX as i64) as u32
+    })
+}
+
+fn dir_attrs(mtime: Option<u32>) -> FileAttributes {
+    FileAttributes {
+        size: Some(0),
+        uid: Some(0),
+        user: Some(OWNER_NAME.to_string()),
+        gid: Some(0),
+        group: Some(OWNER_NAME.to_string()),
+        permissions: Some(DIR_MODE | DIR_PERMISSIONS),
+        atime: mtime,
+        mtime,
+    }
+}
+
+fn file_attrs(size: u64, mtime: Option<u32>) -> FileAttributes {
+    FileAttributes {
+        size: Some(size),
+        uid: Some(0),
+        user: Some(OWNER_NAME.to_string()),
+        gid: Some(0),
+        group: Some(OWNER_NAME.to_string()),
+        permissions: Some(FILE_MODE | FILE_PERMISSIONS),
+        atime: mtime,
+        mtime,
+    }
+}
+
+fn now_secs() -> Option<u32> {
+    Some(time::OffsetDateTime::now_utc().unix_timestamp().clamp(0, u32::MAX as i64) as u32)
+}
+
+/// Directory prefix for an object key, always terminated by a separator
+fn dir_prefix(key: &str) -> String {
+    if key.ends_with(PATH_SEPARATOR) {
+        key.to_string()
+    } else {
+        format!("{}{}", key, PATH_SEPARATOR)
+    }
+}
+
+impl<S> SftpDriver<S>
+where
+    S: S3StorageBackend + Debug,
+{
+    /// Create a new SFTP driver for an authenticated session
+    pub fn new(storage: S, session_context: SessionContext) -> Self {
+        Self {
+            storage,
+            session_context,
+            handles: HashMap::new(),
+            next_handle: 0,
+        }
+    }
+
+    fn access_key(&self) -> &str {
+        &self.session_context.principal.user_identity.credentials.access_key
+    }
+
+    fn secret_key(&self) -> &str {
+        &self.session_context.principal.user_identity.credentials.secret_key
+    }
+
+    fn parse_s3_path(&self, path: &str) -> (String, Option<String>) {
+        let cleaned_path = path::clean(path);
+        let (bucket, object) = path::path_to_bucket_object(&cleaned_path);
+        let key = if object.is_empty() { None } else { Some(object) };
+
+        (bucket, key)
+    }
+
+    fn insert_handle(&mut self, handle: OpenHandle) -> String {
+        self.next_handle += 1;
+        let id = format!("{:016x}", self.next_handle);
+        self.handles.insert(id.clone(), handle);
+        id
+    }
+
+    async fn authorize(&self, action: S3Action, bucket: &str, object: Option<&str>) -> Result<(), StatusCode> {
+        authorize_operation(&self.session_context, &action, bucket, object)
+            .await
+            .map_err(|_| StatusCode::PermissionDenied)
+    }
+
+    /// Resolve the attributes of a path: the root, a bucket, an object or a common prefix
+    async fn path_attrs(&self, path_str: &str) -> Result<FileAttributes, StatusCode> {
+        let (bucket, key) = self.parse_s3_path(path_str);
+        if bucket.is_empty() {
+            return Ok(dir_attrs(now_secs()));
+        }
+
+        let Some(key) = key else {
+            self.authorize(S3Action::HeadBucket, &bucket, None).await?;
+            return match self.storage.head_bucket(&bucket, self.access_key(), self.secret_key()).await {
+                Ok(_) => Ok(dir_attrs(now_secs())),
+                Err(e) => {
+                    debug!("SFTP stat on bucket '{}' failed: {}", bucket, e);
+                    Err(StatusCode::NoSuchFile)
+                }
+            };
+        };
+
+        self.authorize(S3Action::HeadObject, &bucket, Some(&key)).await?;
+        if !key.ends_with(PATH_SEPARATOR)
+            && let Ok(output) = self
+                .storage
+                .head_object(&bucket, &key, self.access_key(), self.secret_key())
+                .await
+        {
+            let size = output.content_length.unwrap_or(0).max(0) as u64;
+            return Ok(file_attrs(size, to_unix_secs(output.last_modified)));
+        }
+
+        // Not an object: treat it as a directory if anything exists below the prefix
+        if self.prefix_has_entries(&bucket, &dir_prefix(&key)).await? {
+            Ok(dir_attrs(now_secs()))
+        } else {
+            Err(StatusCode::NoSuchFile)
+        }
+    }
+
+    async fn prefix_has_entries(&self, bucket: &str, prefix: &str) -> Result<bool, StatusCode> {
+        let input = ListObjectsV2Input::builder()
+            .bucket(bucket.to_string())
+            .prefix(Some(prefix.to_string()))
+            .delimiter(Some(PATH_SEPARATOR.to_string()))
+            .max_keys(Some(1))
+            .build()
+            .map_err(|_| StatusCode::Failure)?;
+
+        match self
+            .storage
+            .list_objects_v2(input, self.access_key(), self.secret_key())
+            .await
+        {
+            Ok(output) => Ok(output.key_count.unwrap_or(0) > 0
+                || output.contents.is_some_and(|c| !c.is_empty())
+                || output.common_prefixes.is_some_and(|p| !p.is_empty())),
+            Err(e) => {
+                debug!("SFTP prefix probe '{}/{}' failed: {}", bucket, prefix, e);
+                Ok(false)
+            }
+        }
+    }
+
+    async fn list_root(&self) -> Result<Vec<File>, StatusCode> {
+        self.authorize(S3Action::ListBuckets, "", None).await?;
+
+        let output = self
+            .storage
+            .list_buckets(self.access_key(), self.secret_key())
+            .await
+            .map_err(|e| {
+                error!("SFTP list buckets failed: {}", e);
+                StatusCode::Failure
+            })?;
+
+        Ok(output
+            .buckets
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|bucket| {
+                let name = bucket.name?;
+                Some(File::new(name, dir_attrs(to_unix_secs(bucket.creation_date))))
+            })
+            .collect())
+    }
+
+    /// List one page of the entries directly below a prefix, with the token of the next page
+    async fn list_page(
+        &self,
+        bucket: &str,
+        prefix: Option<&str>,
+        continuation_token: Option<String>,
+    ) -> Result<(Vec<File>, Option<String>), StatusCode> {
+        let prefix = prefix.map(dir_prefix);
+        let input = ListObjectsV2Input::builder()
+            .bucket(bucket.to_string())
+            .prefix(prefix.clone())
+            .delimiter(Some(PATH_SEPARATOR.to_string()))
+            .max_keys(Some(LIST_PAGE_SIZE))
+            .continuation_token(continuation_token)
+            .build()
+            .map_err(|_| StatusCode::Failure)?;
+
+        let output = self
+            .storage
+            .list_objects_v2(input, self.access_key(), self.secret_key())
+            .await
+            .map_err(|e| {
+                error!("SFTP list '{}/{}' failed: {}", bucket, prefix.as_deref().unwrap_or_default(), e);
+                StatusCode::Failure
+            })?;
+
+        let strip = |full: &str| -> String {
+            let rel = prefix.as_deref().and_then(|p| full.strip_prefix(p)).unwrap_or(full);
+            rel.trim_end_matches(PATH_SEPARATOR).to_string()
+        };
+
+        let mut files = Vec::new();
+        for common_prefix in output.common_prefixes.unwrap_or_default() {
+            if let Some(p) = common_prefix.prefix {
+                let name = strip(&p);
+                if !name.is_empty() {
+                    files.push(File::new(name, dir_attrs(now_secs())));
+                }
+            }
+        }
+
+        for object in output.contents.unwrap_or_default() {
+            if let Some(key) = object.key {
+                let name = strip(&key);
+                // Skip the directory marker object of the listed prefix itself
+                if name.is_empty() || key.ends_with(PATH_SEPARATOR) {
+                    continue;
+                }
+                let size = object.size.unwrap_or(0).max(0) as u64;
+                files.push(File::new(name, file_attrs(size, to_unix_secs(object.last_modified))));
+            }
+        }
+
+        let next_token = match output.next_continuation_token {
+            Some(token) if output.is_truncated.unwrap_or(false) => Some(token),
+            _ => None,
+        };
+        Ok((files, next_token))
+    }
+
+    async fn upload(&self, bucket: String, key: String, buffer: Vec<u8>) -> Result<(), StatusCode> {
+        let file_size = buffer.len();
+
+        let mut put_builder = PutObjectInput::builder();
+        put_builder.set_bucket(bucket.clone());
+        put_builder.set_key(key.clone());
+        put_builder.set_content_length(Some(file_size as i64));
+
+        let data_bytes = bytes::Bytes::from(buffer);
+        let body = stream::once(async move { Ok::<bytes::Bytes, std::io::Error>(data_bytes) });
+        put_builder.set_body(Some(StreamingBlob::wrap(body)));
+        let put_input = put_builder.build().map_err(|_| StatusCode::Failure)?;
+
+        match self.storage.put_object(put_input, self.access_key(), self.secret_key()).await {
+            Ok(_) => {
+                debug!("SFTP uploaded {} bytes to '{}/{}'", file_size, bucket, key);
+                Ok(())
+            }
+            Err(e) => {
+                error!("SFTP upload to '{}/{}' failed: {:?}", bucket, key, e);
+                Err(StatusCode::Failure)
+            }
+        }
+    }
+
+    /// Send a full part of a file being written, starting its multipart upload with the first part
+    async fn upload_part(&self, file: &mut WriteHandle, data: Vec<u8>) -> Result<(), StatusCode> {
+        let upload_id = match &file.upload_id {
+            Some(upload_id) => upload_id.clone(),
+            None => {
+                let output = self
+                    .storage
+                    .create_multipart_upload(&file.bucket, &file.key, self.access_key(), self.secret_key())
+                    .await
+                    .map_err(|e| {
+                        error!("SFTP create multipart upload for '{}/{}' failed: {}", file.bucket, file.key, e);
+                        StatusCode::Failure
+                    })?;
+                let upload_id = output.upload_id.ok_or(StatusCode::Failure)?;
+                file.upload_id = Some(upload_id.clone());
+                upload_id
+            }
+        };
+
+        let part_number = file.parts.len() as i32 + 1;
+        let part_size = data.len();
+
+        let mut part_builder = UploadPartInput::builder();
+        part_builder.set_bucket(file.bucket.clone());
+        part_builder.set_key(file.key.clone());
+        part_builder.set_upload_id(upload_id);
+        part_builder.set_part_number(part_number);
+        part_builder.set_content_length(Some(part_size as i64));
+
+        let data_bytes = bytes::Bytes::from(data);
+        let body = stream::once(async move { Ok::<bytes::Bytes, std::io::Error>(data_bytes) });
+        part_builder.set_body(Some(StreamingBlob::wrap(body)));
+        let part_input = part_builder.build().map_err(|_| StatusCode::Failure)?;
+
+        let output = self
+            .storage
+            .upload_part(part_input, self.access_key(), self.secret_key())
+            .await
+            .map_err(|e| {
+                error!("SFTP upload of part {} to '{}/{}' failed: {}", part_number, file.bucket, file.key, e);
+                StatusCode::Failure
+            })?;
+
+        debug!(
+            "SFTP uploaded part {} of {} bytes to '{}/{}'",
+            part_number, part_size, file.bucket, file.key
+        );
+        file.parts.push(CompletedPart {
+            e_tag: output.e_tag,
+            part_number: Some(part_number),
+            ..Default::default()
+        });
+        Ok(())
+    }
+
+    /// Upload what is left of a written file and complete it
+    async fn finish_write(&self, mut file: WriteHandle) -> Result<(), StatusCode> {
+        let Some(upload_id) = file.upload_id.clone() else {
+            return self.upload(file.bucket, file.key, file.buffer).await;
+        };
+        let size = file.size();
+
+        let result = async {
+            if !file.buffer.is_empty() {
+                let data = std::mem::take(&mut file.buffer);
+                self.upload_part(&mut file, data).await?;
+            }
+            self.storage
+                .complete_multipart_upload(
+                    &file.bucket,
+                    &file.key,
+                    &upload_id,
+                    std::mem::take(&mut file.parts),
+                    self.access_key(),
+                    self.secret_key(),
+                )
+                .await
+                .map_err(|e| {
+                    error!("SFTP complete multipart upload to '{}/{}' failed: {}", file.bucket, file.key, e);
+                    StatusCode::Failure
+                })
+        }
+        .await;
+
+        match result {
+            Ok(_) => {
+                debug!("SFTP uploaded {} bytes to '{}/{}'", size, file.bucket, file.key);
+                Ok(())
+            }
+            Err(e) => {
+                self.abort_write(&file).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Discard the parts already sent for a file whose write failed
+    async fn abort_write(&self, file: &WriteHandle) {
+        if let Some(upload_id) = &file.upload_id
+            && let Err(e) = self
+                .storage
+                .abort_multipart_upload(&file.bucket, &file.key, upload_id, self.access_key(), self.secret_key())
+                .await
+        {
+            error!("SFTP abort multipart upload to '{}/{}' failed: {}", file.bucket, file.key, e);
+        }
+    }
+}
+
+impl<S> russh_sftp::server::Handler for SftpDriver<S>
+where
+    S: S3StorageBackend + Debug + Send + Sync + 'static,
+{
+    type Error = StatusCode;
+
+    fn unimplemented(&self) -> Self::Error {
+        StatusCode::OpUnsupported
+    }
+
+    async fn init(&mut self, version: u32, _extensions: HashMap<String, String>) -> Result<Version, Self::Error> {
+        debug!("SFTP session for '{}' negotiated version {}", self.session_context.access_key(), version);
+        Ok(Version::new())
+    }
+
+    async fn realpath(&mut self, id: u32, path: String) -> Result<Name, Self::Error> {
+        let cleaned = path::clean(&format!("{}{}", ROOT_PATH, path.trim_start_matches(ROOT_PATH)));
+        Ok(Name {
+            id,
+            files: vec![File::dummy(cleaned)],
+        })
+    }
+
+    async fn stat(&mut self, id: u32, path: String) -> Result<Attrs, Self::Error> {
+        let attrs = self.path_attrs(&path).await?;
+        Ok(Attrs { id, attrs })
+    }
+
+    async fn lstat(&mut self, id: u32, path: String) -> Result<Attrs, Self::Error> {
+        let attrs = self.path_attrs(&path).await?;
+        Ok(Attrs { id, attrs })
+    }
+
+    async fn fstat(&mut self, id: u32, handle: String) -> Result<Attrs, Self::Error> {
+        let attrs = match self.handles.get(&handle).ok_or(StatusCode::Failure)? {
+            OpenHandle::Dir { .. } => dir_attrs(now_secs()),
+            OpenHandle::Read { size, .. } => file_attrs(*size, None),
+            OpenHandle::Write(file) => file_attrs(file.size(), now_secs()),
+        };
+        Ok(Attrs { id, attrs })
+    }
+
+    async fn setstat(&mut self, id: u32, path: String, _attrs: FileAttributes) -> Result<Status, Self::Error> {
+        // Object metadata is immutable; accept so that clients preserving times don't abort
+        debug!("SFTP setstat ignored for '{}'", path);
+        Ok(ok_status(id))
+    }
+
+    async fn fsetstat(&mut self, id: u32, _handle: String, _attrs: FileAttributes) -> Result<Status, Self::Error> {
+        Ok(ok_status(id))
+    }
+
+    async fn opendir(&mut self, id: u32, path: String) -> Result<Handle, Self::Error> {
+        let (bucket, prefix) = self.parse_s3_path(&path);
+
+        let handle = if bucket.is_empty() {
+            self.authorize(S3Action::ListBuckets, "", None).await?;
+            OpenHandle::Dir {
+                bucket: None,
+                prefix: None,
+                continuation_token: None,
+                exhausted: false,
+            }
+        } else {
+            self.authorize(S3Action::ListBucket, &bucket, prefix.as_deref()).await?;
+            if self
+                .storage
+                .head_bucket(&bucket, self.access_key(), self.secret_key())
+                .await
+                .is_err()
+            {
+                return Err(StatusCode::NoSuchFile);
+            }
+            OpenHandle::Dir {
+                bucket: Some(bucket),
+                prefix,
+                continuation_token: None,
+                exhausted: false,
+            }
+        };
+
+        Ok(Handle {
+            id,
+            handle: self.insert_handle(handle),
+        })
+    }
+
+    async fn readdir(&mut self, id: u32, handle: String) -> Result<Name, Self::Error> {
+        let (bucket, prefix, token) = match self.handles.get(&handle) {
+            Some(OpenHandle::Dir { exhausted: true, .. }) => return Err(StatusCode::Eof),
+            Some(OpenHandle::Dir {
+                bucket,
+                prefix,
+                continuation_token,
+                ..
+            }) => (bucket.clone(), prefix.clone(), continuation_token.clone()),
+            _ => return Err(StatusCode::Failure),
+        };
+
+        // Each READDIR returns one listing page, the client keeps reading until EOF
+        let (files, next_token) = match bucket {
+            None => (self.list_root().await?, None),
+            Some(bucket) => self.list_page(&bucket, prefix.as_deref(), token).await?,
+        };
+
+        if let Some(OpenHandle::Dir {
+            continuation_token,
+            exhausted,
+            ..
+        }) = self.handles.get_mut(&handle)
+        {
+            *exhausted = next_token.is_none();
+            *continuation_token = next_token;
+        }
+
+        Ok(Name { id, files })
+    }
+
+    async fn open(
+        &mut self,
+        id: u32,
+        filename: String,
+        pflags: OpenFlags,
+        _attrs: FileAttributes,
+    ) -> Result<Handle, Self::Error> {
+        let (bucket, key) = self.parse_s3_path(&filename);
+        let key = key.ok_or(StatusCode::Failure)?;
+
+        let handle = if pflags.intersects(OpenFlags::WRITE | OpenFlags::CREATE | OpenFlags::TRUNCATE | OpenFlags::APPEND) {
+            if pflags.contains(OpenFlags::APPEND) {
+                // S3 objects cannot be appended to in place
+                return Err(StatusCode::OpUnsupported);
+            }
+            self.authorize(S3Action::PutObject, &bucket, Some(&key)).await?;
+            OpenHandle::Write(WriteHandle::new(bucket, key))
+        } else {
+            self.authorize(S3Action::GetObject, &bucket, Some(&key)).await?;
+            let output = self
+                .storage
+                .head_object(&bucket, &key, self.access_key(), self.secret_key())
+                .await
+                .map_err(|e| {
+                    debug!("SFTP open '{}' failed: {}", filename, e);
+                    StatusCode::NoSuchFile
+                })?;
+            OpenHandle::Read {
+                bucket,
+                key,
+                size: output.content_length.unwrap_or(0).max(0) as u64,
+            }
+        };
+
+        Ok(Handle {
+            id,
+            handle: self.insert_handle(handle),
+        })
+    }
+
+    async fn read(&mut self, id: u32, handle: String, offset: u64, len: u32) -> Result<Data, Self::Error> {
+        let (bucket, key, size) = match self.handles.get(&handle) {
+            Some(OpenHandle::Read { bucket, key, size }) => (bucket.clone(), key.clone(), *size),
+            _ => return Err(StatusCode::Failure),
+        };
+
+        if offset >= size {
+            return Err(StatusCode::Eof);
+        }
+
+        let length = (len.min(MAX_READ_LEN) as u64).min(size - offset);
+        let output = self
+            .storage
+            .get_object_range(&bucket, &key, self.access_key(), self.secret_key(), offset, length)
+            .await
+            .map_err(|e| {
+                error!("SFTP read '{}/{}' at {} failed: {}", bucket, key, offset, e);
+                StatusCode::Failure
+            })?;
+
+        let mut body = output.body.ok_or(StatusCode::Failure)?;
+        let mut data = Vec::with_capacity(length as usize);
+        while let Some(chunk) = body.next().await {
+            match chunk {
+                Ok(bytes) => data.extend_from_slice(&bytes),
+                Err(e) => {
+                    error!("Error reading stream: {}", e);
+                    return Err(StatusCode::Failure);
+                }
+            }
+        }
+
+        Ok(Data { id, data })
+    }
+
+    async fn write(&mut self, id: u32, handle: String, offset: u64, data: Vec<u8>) -> Result<Status, Self::Error> {
+        let Some(OpenHandle::Write(file)) = self.handles.get_mut(&handle) else {
+            return Err(StatusCode::Failure);
+        };
+        file.write_at(offset, &data)?;
+        if file.buffer.len() < UPLOAD_PART_SIZE {
+            return Ok(ok_status(id));
+        }
+
+        let Some(OpenHandle::Write(mut file)) = self.handles.remove(&handle) else {
+            return Err(StatusCode::Failure);
+        };
+        while let Some(part) = file.take_part() {
+            if let Err(e) = self.upload_part(&mut file, part).await {
+                // The handle is dropped, later writes and the CLOSE fail
+                self.abort_write(&file).await;
+                return Err(e);
+            }
+        }
+        self.handles.insert(handle, OpenHandle::Write(file));
+
+        Ok(ok_status(id))
+    }
+
+    async fn close(&mut self, id: u32, handle: String) -> Result<Status, Self::Error> {
+        match self.handles.remove(&handle) {
+            Some(OpenHandle::Write(file)) => {
+                self.finish_write(file).await?;
+                Ok(ok_status(id))
+            }
+            Some(_) => Ok(ok_status(id)),
+            None => Err(StatusCode::Failure),
+        }
+    }
+
+    async fn remove(&mut self, id: u32, filename: String) -> Result<Status, Self::Error> {
+        let (bucket, key) = self.parse_s3_path(&filename);
+        let key = key.ok_or(StatusCode::Failure)?;
+
+        self.authorize(S3Action::DeleteObject, &bucket, Some(&key)).await?;
+        match self
+            .storage
+            .delete_object(&bucket, &key, self.access_key(), self.secret_key())
+            .await
+        {
+            Ok(_) => Ok(ok_status(id)),
+            Err(e) => {
+                error!("Failed to delete file '{}': {}", filename, e);
+                Err(StatusCode::Failure)
+            }
+        }
+    }
+
+    async fn mkdir(&mut self, id: u32, path: String, _attrs: FileAttributes) -> Result<Status, Self::Error> {
+        let (bucket, key) = self.parse_s3_path(&path);
+        if bucket.is_empty() {
+            return Err(StatusCode::Failure);
+        }
+
+        match key {
+            None => {
+                self.authorize(S3Action::CreateBucket, &bucket, None).await?;
+                self.storage
+                    .create_bucket(&bucket, self.access_key(), self.secret_key())
+                    .await
+                    .map_err(|e| {
+                        error!("Failed to create directory/bucket '{}': {}", path, e);
+                        StatusCode::Failure
+                    })?;
+            }
+            Some(key) => {
+                // Nested directories are represented by an empty "key/" marker object
+                let marker = dir_prefix(&key);
+                self.authorize(S3Action::PutObject, &bucket, Some(&marker)).await?;
+                self.upload(bucket, marker, Vec::new()).await?;
+            }
+        }
+
+        debug!("Successfully created directory '{}'", path);
+        Ok(ok_status(id))
+    }
+
+    async fn rmdir(&mut self, id: u32, path: String) -> Result<Status, Self::Error> {
+        let (bucket, key) = self.parse_s3_path(&path);
+        if bucket.is_empty() {
+            return Err(StatusCode::Failure);
+        }
+
+        match key {
+            None => {
+                self.authorize(S3Action::DeleteBucket, &bucket, None).await?;
+                self.storage
+                    .delete_bucket(&bucket, self.access_key(), self.secret_key())
+                    .await
+                    .map_err(|e| {
+                        error!("Failed to remove directory/bucket '{}': {}", path, e);
+                        StatusCode::Failure
+                    })?;
+            }
+            Some(key) => {
+                let marker = dir_prefix(&key);
+                self.authorize(S3Action::ListBucket, &bucket, Some(&marker)).await?;
+                let (entries, _) = self.list_page(&bucket, Some(&marker), None).await?;
+                if !entries.is_empty() {
+                    debug!("SFTP rmdir '{}' refused: directory not empty", path);
+                    return Err(StatusCode::Failure);
+                }
+
+                self.authorize(S3Action::DeleteObject, &bucket, Some(&marker)).await?;
+                self.storage
+                    .delete_object(&bucket, &marker, self.access_key(), self.secret_key())
+                    .await
+                    .map_err(|e| {
+                        error!("Failed to remove directory '{}': {}", path, e);
+                        StatusCode::Failure
+                    })?;
+            }
+        }
+
+        Ok(ok_status(id))
+    }
+
+    async fn rename(&mut self, _id: u32, oldpath: String, newpath: String) -> Result<Status, Self::Error> {
+        debug!(
+            "SFTP rename request for user '{}' from '{}' to '{}'",
+            self.session_context.access_key(),
+            oldpath,
+            newpath
+        );
+        Err(StatusCode::OpUnsupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dir_prefix() {
+        assert_eq!(dir_prefix("photos"), "photos/");
+        assert_eq!(dir_prefix("photos/"), "photos/");
+        assert_eq!(dir_prefix("a/b"), "a/b/");
+    }
+
+    #[test]
+    fn test_attrs_file_type() {
+        assert!(dir_attrs(None).is_dir());
+        assert!(file_attrs(42, None).is_regular());
+        assert_eq!(file_attrs(42, Some(7)).size, Some(42));
+    }
+
+    #[test]
+    fn test_write_handle_offsets() {
+        let mut file = WriteHandle::new("bucket".to_string(), "key".to_string());
+        file.write_at(0, b"hello").unwrap();
+        file.write_at(3, b"p!").unwrap();
+        assert_eq!(file.buffer, b"help!");
+
+        // A gap is zero filled
+        file.write_at(7, b"x").unwrap();
+        assert_eq!(file.buffer, b"help!\0\0x");
+        assert_eq!(file.size(), 8);
+
+        assert_eq!(file.write_at(u64::MAX, b"x"), Err(StatusCode::Failure));
+        assert_eq!(file.write_at(MAX_FILE_SIZE, b"x"), Err(StatusCode::Failure));
+        assert_eq!(file.write_at(3 * UPLOAD_PART_SIZE as u64, b"x"), Err(StatusCode::OpUnsupported));
+        assert!(file.take_part().is_none());
+    }
+
+    #[test]
+    fn test_write_handle_parts() {
+        let mut file = WriteHandle::new("bucket".to_string(), "key".to_string());
+        file.write_at(0, &vec![1u8; UPLOAD_PART_SIZE + 10]).unwrap();
+
+        let part = file.take_part().unwrap();
+        assert_eq!(part.len(), UPLOAD_PART_SIZE);
+        assert_eq!(file.buffer.len(), 10);
+        assert_eq!(file.uploaded, UPLOAD_PART_SIZE as u64);
+        assert!(file.take_part().is_none());
+
+        // Data already sent as a part cannot be rewritten
+        assert_eq!(file.write_at(0, b"x"), Err(StatusCode::OpUnsupported));
+        file.write_at(UPLOAD_PART_SIZE as u64 + 10, b"tail").unwrap();
+        assert_eq!(file.size(), UPLOAD_PART_SIZE as u64 + 14);
+    }
+}
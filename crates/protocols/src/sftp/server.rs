@@ -0,0 +1,299 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::config::{SftpConfig, SftpInitError};
+use super::driver::SftpDriver;
+use crate::common::client::s3::StorageBackend;
+use crate::common::session::{Protocol, ProtocolPrincipal, SessionContext};
+use crate::constants::network::{AUTH_FAILURE_DELAY_MS, DEFAULT_SOURCE_IP};
+use crate::constants::sftp::SUBSYSTEM_NAME;
+use russh::keys::{PrivateKey, PublicKey};
+use russh::server::{Auth, Msg, Session};
+use russh::{Channel, ChannelId};
+use rustfs_policy::auth::UserIdentity;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tracing::{debug, error, info, warn};
+
+/// SFTP server implementation
+pub struct SftpServer<S> {
+    /// Server configuration
+    config: SftpConfig,
+    /// S3 storage backend
+    storage: S,
+}
+
+impl<S> SftpServer<S>
+where
+    S: StorageBackend + Clone + Send + Sync + 'static + Debug,
+{
+    /// Create a new SFTP server
+    pub async fn new(config: SftpConfig, storage: S) -> Result<Self, SftpInitError> {
+        config.validate().await?;
+        Ok(Self { config, storage })
+    }
+
+    /// Start the SFTP server
+    ///
+    /// This method binds the listener first to ensure the port is available,
+    /// then accepts SSH connections until the shutdown signal is received.
+    pub async fn start(&self, mut shutdown_rx: broadcast::Receiver<()>) -> Result<(), SftpInitError> {
+        info!("Initializing SFTP server on {}", self.config.bind_addr);
+
+        let host_key = self.load_host_key().await?;
+        let ssh_config = Arc::new(russh::server::Config {
+            inactivity_timeout: Some(self.config.idle_timeout),
+            auth_rejection_time: Duration::from_millis(AUTH_FAILURE_DELAY_MS),
+            auth_rejection_time_initial: Some(Duration::ZERO),
+            keys: vec![host_key],
+            ..Default::default()
+        });
+
+        let listener = TcpListener::bind(self.config.bind_addr).await?;
+        info!("SFTP server listening on {}", self.config.bind_addr);
+
+        loop {
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let (socket, peer_addr) = match accepted {
+                        Ok(conn) => conn,
+                        Err(e) => {
+                            warn!("SFTP accept error: {}", e);
+                            continue;
+                        }
+                    };
+
+                    debug!("SFTP connection accepted from {}", peer_addr);
+                    let _ = socket.set_nodelay(true);
+
+                    let handler = SftpSessionHandler::new(self.storage.clone(), self.config.password_auth, peer_addr);
+                    let ssh_config = ssh_config.clone();
+                    tokio::spawn(async move {
+                        let session = match russh::server::run_stream(ssh_config, socket, handler).await {
+                            Ok(session) => session,
+                            Err(e) => {
+                                debug!("SFTP handshake with {} failed: {}", peer_addr, e);
+                                return;
+                            }
+                        };
+
+                        if let Err(e) = session.await {
+                            debug!("SFTP session with {} ended with error: {}", peer_addr, e);
+                        }
+                    });
+                }
+                _ = shutdown_rx.recv() => {
+                    info!("SFTP server received shutdown signal");
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Load the configured host key or generate an ephemeral one
+    async fn load_host_key(&self) -> Result<PrivateKey, SftpInitError> {
+        match &self.config.host_key_file {
+            Some(path) => {
+                debug!("Loading SFTP host key from {}", path);
+                russh::keys::load_secret_key(path, None).map_err(|e| SftpInitError::HostKey(format!("{}: {}", path, e)))
+            }
+            None => {
+                warn!(
+                    "No SFTP host key configured, generating an ephemeral Ed25519 key; clients will see a new fingerprint after restart"
+                );
+                PrivateKey::random(&mut russh::keys::ssh_key::rand_core::OsRng, russh::keys::Algorithm::Ed25519)
+                    .map_err(|e| SftpInitError::HostKey(e.to_string()))
+            }
+        }
+    }
+
+    /// Get server configuration
+    pub fn config(&self) -> &SftpConfig {
+        &self.config
+    }
+
+    /// Get storage backend
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+}
+
+/// Per-connection SSH handler that authenticates the user and runs the SFTP subsystem
+pub struct SftpSessionHandler<S> {
+    /// S3 storage backend
+    storage: S,
+    /// Whether password authentication is accepted
+    password_auth: bool,
+    /// Remote address of the connection
+    peer_addr: SocketAddr,
+    /// Session context, set once authentication succeeded
+    session_context: Option<SessionContext>,
+    /// Open session channels waiting for a subsystem request
+    channels: HashMap<ChannelId, Channel<Msg>>,
+}
+
+impl<S> SftpSessionHandler<S>
+where
+    S: StorageBackend + Clone + Send + Sync + 'static + Debug,
+{
+    fn new(storage: S, password_auth: bool, peer_addr: SocketAddr) -> Self {
+        Self {
+            storage,
+            password_auth,
+            peer_addr,
+            session_context: None,
+            channels: HashMap::new(),
+        }
+    }
+
+    fn accept(&mut self, identity: UserIdentity) -> Auth {
+        let source_ip: IpAddr = if self.peer_addr.ip().is_unspecified() {
+            DEFAULT_SOURCE_IP.parse().unwrap()
+        } else {
+            self.peer_addr.ip()
+        };
+
+        self.session_context = Some(SessionContext::new(ProtocolPrincipal::new(Arc::new(identity)), Protocol::Sftp, source_ip));
+
+        Auth::Accept
+    }
+}
+
+fn reject() -> Auth {
+    Auth::Reject {
+        proceed_with_methods: None,
+        partial_success: false,
+    }
+}
+
+/// Look up a valid, enabled identity for the given access key
+async fn lookup_identity(username: &str) -> Option<UserIdentity> {
+    let iam_sys = match rustfs_iam::get() {
+        Ok(sys) => sys,
+        Err(e) => {
+            error!("IAM system unavailable during SFTP auth: {}", e);
+            return None;
+        }
+    };
+
+    match iam_sys.check_key(username).await {
+        Ok((Some(identity), true)) => Some(identity),
+        Ok(_) => {
+            warn!("SFTP login failed: Invalid access key '{}'", username);
+            None
+        }
+        Err(e) => {
+            error!("IAM check_key failed for {}: {}", username, e);
+            None
+        }
+    }
+}
+
+/// Check whether `public_key` matches one of the SSH keys stored for the identity
+pub fn is_authorized_public_key(identity: &UserIdentity, public_key: &PublicKey) -> bool {
+    identity
+        .get_ssh_public_keys()
+        .iter()
+        .filter_map(|stored| match PublicKey::from_openssh(stored.trim()) {
+            Ok(key) => Some(key),
+            Err(e) => {
+                warn!(
+                    "Ignoring malformed SSH public key stored for '{}': {}",
+                    identity.credentials.access_key, e
+                );
+                None
+            }
+        })
+        .any(|stored| stored.key_data() == public_key.key_data())
+}
+
+impl<S> russh::server::Handler for SftpSessionHandler<S>
+where
+    S: StorageBackend + Clone + Send + Sync + 'static + Debug,
+{
+    type Error = russh::Error;
+
+    /// Authenticate the user with access key / secret key against RustFS IAM system
+    async fn auth_password(&mut self, user: &str, password: &str) -> Result<Auth, Self::Error> {
+        if !self.password_auth {
+            debug!("SFTP password authentication disabled, rejecting '{}'", user);
+            return Ok(reject());
+        }
+
+        let Some(identity) = lookup_identity(user).await else {
+            return Ok(reject());
+        };
+
+        if !identity.credentials.secret_key.eq(password) {
+            warn!("SFTP login failed: Invalid secret key for '{}'", user);
+            return Ok(reject());
+        }
+
+        info!("SFTP user '{}' authenticated with password from {}", user, self.peer_addr);
+        Ok(self.accept(identity))
+    }
+
+    /// Authenticate the user with one of the SSH public keys stored in IAM
+    async fn auth_publickey(&mut self, user: &str, public_key: &PublicKey) -> Result<Auth, Self::Error> {
+        let Some(identity) = lookup_identity(user).await else {
+            return Ok(reject());
+        };
+
+        if !is_authorized_public_key(&identity, public_key) {
+            warn!("SFTP login failed: Unknown public key for '{}'", user);
+            return Ok(reject());
+        }
+
+        info!("SFTP user '{}' authenticated with public key from {}", user, self.peer_addr);
+        Ok(self.accept(identity))
+    }
+
+    async fn channel_open_session(&mut self, channel: Channel<Msg>, _session: &mut Session) -> Result<bool, Self::Error> {
+        if self.session_context.is_none() {
+            return Ok(false);
+        }
+
+        self.channels.insert(channel.id(), channel);
+        Ok(true)
+    }
+
+    async fn channel_close(&mut self, channel: ChannelId, _session: &mut Session) -> Result<(), Self::Error> {
+        self.channels.remove(&channel);
+        Ok(())
+    }
+
+    async fn subsystem_request(&mut self, channel_id: ChannelId, name: &str, session: &mut Session) -> Result<(), Self::Error> {
+        let (Some(session_context), true) = (self.session_context.clone(), name == SUBSYSTEM_NAME) else {
+            debug!("Rejecting SSH subsystem '{}' on channel {:?}", name, channel_id);
+            return session.channel_failure(channel_id);
+        };
+
+        let Some(channel) = self.channels.remove(&channel_id) else {
+            return session.channel_failure(channel_id);
+        };
+
+        session.channel_success(channel_id)?;
+
+        debug!("Starting SFTP subsystem for user '{}'", session_context.access_key());
+        let driver = SftpDriver::new(self.storage.clone(), session_context);
+        russh_sftp::server::run(channel.into_stream(), driver).await;
+
+        Ok(())
+    }
+}
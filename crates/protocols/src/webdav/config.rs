@@ -0,0 +1,77 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::net::SocketAddr;
+use std::time::Duration;
+use thiserror::Error;
+
+/// WebDAV server initialization error
+#[derive(Debug, Error)]
+pub enum WebDavInitError {
+    #[error("failed to bind address {0}")]
+    Bind(#[from] std::io::Error),
+    #[error("invalid WebDAV configuration: {0}")]
+    InvalidConfig(String),
+}
+
+/// WebDAV server configuration
+#[derive(Debug, Clone)]
+pub struct WebDavConfig {
+    /// Server bind address
+    pub bind_addr: SocketAddr,
+    /// Whether TLS is enabled
+    pub tls_enabled: bool,
+    /// Certificate directory path (supports multiple certificates)
+    pub cert_dir: Option<String>,
+    /// Upper bound for the timeout a client may request with LOCK. Locks are
+    /// held in memory by each node, see [`super::lock::WebDavLockSystem`]
+    pub max_lock_timeout: Duration,
+}
+
+impl WebDavConfig {
+    /// Validates the configuration
+    pub async fn validate(&self) -> Result<(), WebDavInitError> {
+        if self.tls_enabled && self.cert_dir.is_none() {
+            return Err(WebDavInitError::InvalidConfig(
+                "TLS is enabled but certificate directory is missing".to_string(),
+            ));
+        }
+
+        if let Some(path) = &self.cert_dir
+            && !tokio::fs::try_exists(path).await.unwrap_or(false)
+        {
+            return Err(WebDavInitError::InvalidConfig(format!("Certificate directory not found: {}", path)));
+        }
+
+        if self.max_lock_timeout.is_zero() {
+            return Err(WebDavInitError::InvalidConfig(
+                "Maximum lock timeout must be greater than zero".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for WebDavConfig {
+    fn default() -> Self {
+        Self {
+            bind_addr: rustfs_config::DEFAULT_WEBDAV_ADDRESS.parse().unwrap(),
+            tls_enabled: false,
+            cert_dir: None,
+            max_lock_timeout: Duration::from_secs(rustfs_config::DEFAULT_WEBDAV_MAX_LOCK_TIMEOUT),
+        }
+    }
+}
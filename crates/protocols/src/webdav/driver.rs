@@ -0,0 +1,853 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::client::s3::StorageBackend as S3StorageBackend;
+use crate::common::gateway::{S3Action, authorize_operation};
+use crate::common::session::SessionContext;
+use crate::constants::paths::PATH_SEPARATOR;
+use crate::constants::webdav::{LIST_PAGE_SIZE, MAX_FILE_SIZE};
+use bytes::{Buf, Bytes};
+use dav_server::davpath::DavPath;
+use dav_server::fs::{
+    DavDirEntry, DavFile, DavMetaData, FsError, FsFuture, FsResult, FsStream, GuardedFileSystem, OpenOptions, ReadDirMeta,
+};
+use futures_util::{StreamExt, stream};
+use rustfs_utils::path;
+use s3s::dto::*;
+use std::fmt::Debug;
+use std::io::SeekFrom;
+use std::time::SystemTime;
+use tracing::{debug, error};
+
+/// Metadata of a WebDAV resource backed by a bucket, prefix or object
+#[derive(Debug, Clone)]
+pub struct WebDavMetadata {
+    size: u64,
+    modified: Option<SystemTime>,
+    is_dir: bool,
+}
+
+impl WebDavMetadata {
+    fn dir(modified: Option<SystemTime>) -> Self {
+        Self {
+            size: 0,
+            modified,
+            is_dir: true,
+        }
+    }
+
+    fn file(size: u64, modified: Option<SystemTime>) -> Self {
+        Self {
+            size,
+            modified,
+            is_dir: false,
+        }
+    }
+}
+
+impl DavMetaData for WebDavMetadata {
+    fn len(&self) -> u64 {
+        self.size
+    }
+
+    fn modified(&self) -> FsResult<SystemTime> {
+        self.modified.ok_or(FsError::NotImplemented)
+    }
+
+    fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+}
+
+/// Directory entry returned by PROPFIND listings
+#[derive(Debug)]
+pub struct WebDavDirEntry {
+    name: String,
+    metadata: WebDavMetadata,
+}
+
+impl DavDirEntry for WebDavDirEntry {
+    fn name(&self) -> Vec<u8> {
+        self.name.as_bytes().to_vec()
+    }
+
+    fn metadata(&self) -> FsFuture<'_, Box<dyn DavMetaData>> {
+        let metadata = self.metadata.clone();
+        Box::pin(async move { Ok(Box::new(metadata) as Box<dyn DavMetaData>) })
+    }
+}
+
+fn to_system_time(ts: Option<Timestamp>) -> Option<SystemTime> {
+    ts.map(|dt| {
+        let offset_dt: time::OffsetDateTime = dt.into();
+        offset_dt.into()
+    })
+}
+
+/// Directory prefix for an object key, always terminated by a separator
+fn dir_prefix(key: &str) -> String {
+    if key.ends_with(PATH_SEPARATOR) {
+        key.to_string()
+    } else {
+        format!("{}{}", key, PATH_SEPARATOR)
+    }
+}
+
+/// Split a WebDAV path into bucket and optional object key
+fn parse_dav_path(dav_path: &DavPath) -> (String, Option<String>) {
+    let raw = String::from_utf8_lossy(dav_path.as_bytes());
+    let cleaned_path = path::clean(&raw);
+    let (bucket, object) = path::path_to_bucket_object(&cleaned_path);
+    let key = if object.is_empty() { None } else { Some(object) };
+
+    (bucket, key)
+}
+
+/// WebDAV filesystem translating DAV requests into S3 operations
+///
+/// Buckets are exposed as top-level collections and common prefixes as
+/// nested collections. Empty collections are kept alive with a `key/`
+/// marker object, the same convention used by the FTPS and SFTP gateways.
+#[derive(Clone)]
+pub struct WebDavDriver<S> {
+    /// Storage backend for S3 operations
+    storage: S,
+}
+
+impl<S> Debug for WebDavDriver<S>
+where
+    S: S3StorageBackend + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebDavDriver").field("storage", &"StorageBackend").finish()
+    }
+}
+
+/// Per-session accessors shared by the driver and open files
+fn access_key(session_context: &SessionContext) -> &str {
+    &session_context.principal.user_identity.credentials.access_key
+}
+
+fn secret_key(session_context: &SessionContext) -> &str {
+    &session_context.principal.user_identity.credentials.secret_key
+}
+
+async fn authorize(session_context: &SessionContext, action: S3Action, bucket: &str, object: Option<&str>) -> FsResult<()> {
+    authorize_operation(session_context, &action, bucket, object)
+        .await
+        .map_err(|_| FsError::Forbidden)
+}
+
+impl<S> WebDavDriver<S>
+where
+    S: S3StorageBackend + Clone + Debug + Send + Sync + 'static,
+{
+    /// Create a new WebDAV driver
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+
+    /// Resolve the metadata of a path: the root, a bucket, an object or a common prefix
+    async fn resolve(&self, dav_path: &DavPath, session: &SessionContext) -> FsResult<WebDavMetadata> {
+        let (bucket, key) = parse_dav_path(dav_path);
+        if bucket.is_empty() {
+            return Ok(WebDavMetadata::dir(Some(SystemTime::now())));
+        }
+
+        let Some(key) = key else {
+            authorize(session, S3Action::HeadBucket, &bucket, None).await?;
+            return match self
+                .storage
+                .head_bucket(&bucket, access_key(session), secret_key(session))
+                .await
+            {
+                Ok(_) => Ok(WebDavMetadata::dir(Some(SystemTime::now()))),
+                Err(e) => {
+                    debug!("WebDAV stat on bucket '{}' failed: {}", bucket, e);
+                    Err(FsError::NotFound)
+                }
+            };
+        };
+
+        authorize(session, S3Action::HeadObject, &bucket, Some(&key)).await?;
+        if !dav_path.is_collection()
+            && let Ok(output) = self
+                .storage
+                .head_object(&bucket, &key, access_key(session), secret_key(session))
+                .await
+        {
+            let size = output.content_length.unwrap_or(0).max(0) as u64;
+            return Ok(WebDavMetadata::file(size, to_system_time(output.last_modified)));
+        }
+
+        // Not an object: treat it as a collection if anything exists below the prefix
+        if self.prefix_has_entries(&bucket, &dir_prefix(&key), session).await? {
+            Ok(WebDavMetadata::dir(Some(SystemTime::now())))
+        } else {
+            Err(FsError::NotFound)
+        }
+    }
+
+    async fn prefix_has_entries(&self, bucket: &str, prefix: &str, session: &SessionContext) -> FsResult<bool> {
+        let input = ListObjectsV2Input::builder()
+            .bucket(bucket.to_string())
+            .prefix(Some(prefix.to_string()))
+            .max_keys(Some(1))
+            .build()
+            .map_err(|_| FsError::GeneralFailure)?;
+
+        match self
+            .storage
+            .list_objects_v2(input, access_key(session), secret_key(session))
+            .await
+        {
+            Ok(output) => Ok(output.contents.is_some_and(|c| !c.is_empty())),
+            Err(e) => {
+                debug!("WebDAV prefix probe '{}/{}' failed: {}", bucket, prefix, e);
+                Ok(false)
+            }
+        }
+    }
+
+    async fn list_root(&self, session: &SessionContext) -> FsResult<Vec<WebDavDirEntry>> {
+        authorize(session, S3Action::ListBuckets, "", None).await?;
+
+        let output = self
+            .storage
+            .list_buckets(access_key(session), secret_key(session))
+            .await
+            .map_err(|e| {
+                error!("WebDAV list buckets failed: {}", e);
+                FsError::GeneralFailure
+            })?;
+
+        Ok(output
+            .buckets
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|bucket| {
+                let name = bucket.name?;
+                Some(WebDavDirEntry {
+                    name,
+                    metadata: WebDavMetadata::dir(to_system_time(bucket.creation_date)),
+                })
+            })
+            .collect())
+    }
+
+    /// List the direct children of a bucket or prefix
+    async fn list_prefix(&self, bucket: &str, prefix: Option<&str>, session: &SessionContext) -> FsResult<Vec<WebDavDirEntry>> {
+        authorize(session, S3Action::ListBucket, bucket, prefix).await?;
+
+        let prefix = prefix.map(dir_prefix);
+        let mut entries = Vec::new();
+        let mut continuation_token: Option<String> = None;
+
+        loop {
+            let input = ListObjectsV2Input::builder()
+                .bucket(bucket.to_string())
+                .prefix(prefix.clone())
+                .delimiter(Some(PATH_SEPARATOR.to_string()))
+                .max_keys(Some(LIST_PAGE_SIZE))
+                .continuation_token(continuation_token.take())
+                .build()
+                .map_err(|_| FsError::GeneralFailure)?;
+
+            let output = self
+                .storage
+                .list_objects_v2(input, access_key(session), secret_key(session))
+                .await
+                .map_err(|e| {
+                    error!("WebDAV list '{}/{}' failed: {}", bucket, prefix.as_deref().unwrap_or_default(), e);
+                    FsError::GeneralFailure
+                })?;
+
+            let strip = |full: &str| -> String {
+                let rel = prefix.as_deref().and_then(|p| full.strip_prefix(p)).unwrap_or(full);
+                rel.trim_end_matches(PATH_SEPARATOR).to_string()
+            };
+
+            for common_prefix in output.common_prefixes.unwrap_or_default() {
+                if let Some(p) = common_prefix.prefix {
+                    let name = strip(&p);
+                    if !name.is_empty() {
+                        entries.push(WebDavDirEntry {
+                            name,
+                            metadata: WebDavMetadata::dir(None),
+                        });
+                    }
+                }
+            }
+
+            for object in output.contents.unwrap_or_default() {
+                if let Some(key) = object.key {
+                    let name = strip(&key);
+                    // Skip the directory marker object of the listed prefix itself
+                    if name.is_empty() || key.ends_with(PATH_SEPARATOR) {
+                        continue;
+                    }
+                    let size = object.size.unwrap_or(0).max(0) as u64;
+                    entries.push(WebDavDirEntry {
+                        name,
+                        metadata: WebDavMetadata::file(size, to_system_time(object.last_modified)),
+                    });
+                }
+            }
+
+            match output.next_continuation_token {
+                Some(token) if output.is_truncated.unwrap_or(false) => continuation_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Every object key below a prefix, including directory markers
+    async fn list_recursive(&self, bucket: &str, prefix: &str, session: &SessionContext) -> FsResult<Vec<String>> {
+        let mut keys = Vec::new();
+        let mut continuation_token: Option<String> = None;
+
+        loop {
+            let input = ListObjectsV2Input::builder()
+                .bucket(bucket.to_string())
+                .prefix(Some(prefix.to_string()))
+                .max_keys(Some(LIST_PAGE_SIZE))
+                .continuation_token(continuation_token.take())
+                .build()
+                .map_err(|_| FsError::GeneralFailure)?;
+
+            let output = self
+                .storage
+                .list_objects_v2(input, access_key(session), secret_key(session))
+                .await
+                .map_err(|e| {
+                    error!("WebDAV recursive list '{}/{}' failed: {}", bucket, prefix, e);
+                    FsError::GeneralFailure
+                })?;
+
+            keys.extend(
+                output
+                    .contents
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|object| object.key),
+            );
+
+            match output.next_continuation_token {
+                Some(token) if output.is_truncated.unwrap_or(false) => continuation_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(keys)
+    }
+
+    async fn copy_key(&self, src: (&str, &str), dst: (&str, &str), session: &SessionContext) -> FsResult<()> {
+        authorize(session, S3Action::GetObject, src.0, Some(src.1)).await?;
+        authorize(session, S3Action::CopyObject, dst.0, Some(dst.1)).await?;
+
+        self.storage
+            .copy_object(src.0, src.1, dst.0, dst.1, access_key(session), secret_key(session))
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                error!("WebDAV copy '{}/{}' -> '{}/{}' failed: {}", src.0, src.1, dst.0, dst.1, e);
+                FsError::GeneralFailure
+            })
+    }
+
+    /// Copy every object below `src_prefix` to `dst_prefix`, returning the source keys. If a copy
+    /// fails the copies already made are removed again.
+    async fn copy_prefix(&self, src: (&str, &str), dst: (&str, &str), session: &SessionContext) -> FsResult<Vec<String>> {
+        let keys = self.list_recursive(src.0, src.1, session).await?;
+        let mut copied = Vec::with_capacity(keys.len());
+        for key in &keys {
+            let target = format!("{}{}", dst.1, &key[src.1.len()..]);
+            if let Err(e) = self.copy_key((src.0, key), (dst.0, &target), session).await {
+                self.remove_copies(dst.0, &copied, session).await;
+                return Err(e);
+            }
+            copied.push(target);
+        }
+        Ok(keys)
+    }
+
+    /// Best effort removal of the copies made by an operation that failed part way
+    async fn remove_copies(&self, bucket: &str, keys: &[String], session: &SessionContext) {
+        for key in keys {
+            if let Err(e) = self.delete_key(bucket, key, session).await {
+                error!("WebDAV rollback of '{}/{}' failed: {:?}", bucket, key, e);
+            }
+        }
+    }
+
+    async fn delete_key(&self, bucket: &str, key: &str, session: &SessionContext) -> FsResult<()> {
+        authorize(session, S3Action::DeleteObject, bucket, Some(key)).await?;
+
+        self.storage
+            .delete_object(bucket, key, access_key(session), secret_key(session))
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                error!("WebDAV delete '{}/{}' failed: {}", bucket, key, e);
+                FsError::GeneralFailure
+            })
+    }
+}
+
+impl<S> GuardedFileSystem<SessionContext> for WebDavDriver<S>
+where
+    S: S3StorageBackend + Clone + Debug + Send + Sync + 'static,
+{
+    fn open<'a>(
+        &'a self,
+        dav_path: &'a DavPath,
+        options: OpenOptions,
+        session: &'a SessionContext,
+    ) -> FsFuture<'a, Box<dyn DavFile>> {
+        Box::pin(async move {
+            let (bucket, Some(key)) = parse_dav_path(dav_path) else {
+                return Err(FsError::Forbidden);
+            };
+
+            let existing = match self.resolve(dav_path, session).await {
+                Ok(meta) if meta.is_dir => return Err(FsError::Forbidden),
+                Ok(meta) => Some(meta),
+                Err(FsError::NotFound) => None,
+                Err(e) => return Err(e),
+            };
+
+            if !options.write {
+                let meta = existing.ok_or(FsError::NotFound)?;
+                authorize(session, S3Action::GetObject, &bucket, Some(&key)).await?;
+                return Ok(
+                    Box::new(WebDavFile::new(self.storage.clone(), session.clone(), bucket, key, meta, None)) as Box<dyn DavFile>
+                );
+            }
+
+            if options.create_new && existing.is_some() {
+                return Err(FsError::Exists);
+            }
+            if existing.is_none() && !options.create && !options.create_new {
+                return Err(FsError::NotFound);
+            }
+            authorize(session, S3Action::PutObject, &bucket, Some(&key)).await?;
+
+            // Partial updates rewrite the whole object, so start from its current content
+            let mut buffer = Vec::new();
+            if let Some(meta) = existing.as_ref()
+                && !options.truncate
+                && meta.size > 0
+            {
+                authorize(session, S3Action::GetObject, &bucket, Some(&key)).await?;
+                buffer = read_range(&self.storage, session, &bucket, &key, 0, meta.size)
+                    .await?
+                    .to_vec();
+            }
+
+            let meta = existing.unwrap_or_else(|| WebDavMetadata::file(0, Some(SystemTime::now())));
+            let mut file = WebDavFile::new(self.storage.clone(), session.clone(), bucket, key, meta, Some(buffer));
+            if options.append {
+                file.pos = file.len();
+            }
+            Ok(Box::new(file) as Box<dyn DavFile>)
+        })
+    }
+
+    fn read_dir<'a>(
+        &'a self,
+        dav_path: &'a DavPath,
+        _meta: ReadDirMeta,
+        session: &'a SessionContext,
+    ) -> FsFuture<'a, FsStream<Box<dyn DavDirEntry>>> {
+        Box::pin(async move {
+            let (bucket, key) = parse_dav_path(dav_path);
+            let entries = if bucket.is_empty() {
+                self.list_root(session).await?
+            } else {
+                self.list_prefix(&bucket, key.as_deref(), session).await?
+            };
+
+            let entries = entries.into_iter().map(|entry| Ok(Box::new(entry) as Box<dyn DavDirEntry>));
+            Ok(Box::pin(stream::iter(entries)) as FsStream<Box<dyn DavDirEntry>>)
+        })
+    }
+
+    fn metadata<'a>(&'a self, dav_path: &'a DavPath, session: &'a SessionContext) -> FsFuture<'a, Box<dyn DavMetaData>> {
+        Box::pin(async move {
+            let meta = self.resolve(dav_path, session).await?;
+            Ok(Box::new(meta) as Box<dyn DavMetaData>)
+        })
+    }
+
+    fn create_dir<'a>(&'a self, dav_path: &'a DavPath, session: &'a SessionContext) -> FsFuture<'a, ()> {
+        Box::pin(async move {
+            let (bucket, key) = parse_dav_path(dav_path);
+            if bucket.is_empty() {
+                return Err(FsError::Forbidden);
+            }
+            if self.resolve(dav_path, session).await.is_ok() {
+                return Err(FsError::Exists);
+            }
+
+            let Some(key) = key else {
+                authorize(session, S3Action::CreateBucket, &bucket, None).await?;
+                return self
+                    .storage
+                    .create_bucket(&bucket, access_key(session), secret_key(session))
+                    .await
+                    .map(|_| debug!("WebDAV created bucket '{}'", bucket))
+                    .map_err(|e| {
+                        error!("WebDAV create bucket '{}' failed: {}", bucket, e);
+                        FsError::GeneralFailure
+                    });
+            };
+
+            let marker = dir_prefix(&key);
+            authorize(session, S3Action::PutObject, &bucket, Some(&marker)).await?;
+            upload(&self.storage, session, &bucket, &marker, Vec::new()).await
+        })
+    }
+
+    fn remove_dir<'a>(&'a self, dav_path: &'a DavPath, session: &'a SessionContext) -> FsFuture<'a, ()> {
+        Box::pin(async move {
+            let (bucket, key) = parse_dav_path(dav_path);
+            if bucket.is_empty() {
+                return Err(FsError::Forbidden);
+            }
+
+            let Some(key) = key else {
+                authorize(session, S3Action::DeleteBucket, &bucket, None).await?;
+                return self
+                    .storage
+                    .delete_bucket(&bucket, access_key(session), secret_key(session))
+                    .await
+                    .map(|_| debug!("WebDAV deleted bucket '{}'", bucket))
+                    .map_err(|e| {
+                        error!("WebDAV delete bucket '{}' failed: {}", bucket, e);
+                        FsError::GeneralFailure
+                    });
+            };
+
+            let marker = dir_prefix(&key);
+            let keys = self.list_recursive(&bucket, &marker, session).await?;
+            if keys.iter().any(|k| *k != marker) {
+                return Err(FsError::Forbidden);
+            }
+            if keys.is_empty() {
+                return Err(FsError::NotFound);
+            }
+            self.delete_key(&bucket, &marker, session).await
+        })
+    }
+
+    fn remove_file<'a>(&'a self, dav_path: &'a DavPath, session: &'a SessionContext) -> FsFuture<'a, ()> {
+        Box::pin(async move {
+            let (bucket, Some(key)) = parse_dav_path(dav_path) else {
+                return Err(FsError::Forbidden);
+            };
+            self.delete_key(&bucket, &key, session).await
+        })
+    }
+
+    fn rename<'a>(&'a self, from: &'a DavPath, to: &'a DavPath, session: &'a SessionContext) -> FsFuture<'a, ()> {
+        Box::pin(async move {
+            let (src_bucket, Some(src_key)) = parse_dav_path(from) else {
+                return Err(FsError::Forbidden);
+            };
+            let (dst_bucket, Some(dst_key)) = parse_dav_path(to) else {
+                return Err(FsError::Forbidden);
+            };
+
+            // S3 has no rename, so a MOVE is not atomic. Every object is copied before any source is
+            // deleted: a failed copy removes the partial copy and leaves the source untouched, a
+            // failed delete leaves the moved objects in both places but never in neither.
+            let src_keys = if self.resolve(from, session).await?.is_dir {
+                let src_prefix = dir_prefix(&src_key);
+                for key in self.list_recursive(&src_bucket, &src_prefix, session).await? {
+                    authorize(session, S3Action::DeleteObject, &src_bucket, Some(&key)).await?;
+                }
+                self.copy_prefix((&src_bucket, &src_prefix), (&dst_bucket, &dir_prefix(&dst_key)), session)
+                    .await?
+            } else {
+                authorize(session, S3Action::DeleteObject, &src_bucket, Some(&src_key)).await?;
+                self.copy_key((&src_bucket, &src_key), (&dst_bucket, &dst_key), session)
+                    .await?;
+                vec![src_key]
+            };
+
+            for key in &src_keys {
+                self.delete_key(&src_bucket, key, session).await?;
+            }
+            Ok(())
+        })
+    }
+
+    fn copy<'a>(&'a self, from: &'a DavPath, to: &'a DavPath, session: &'a SessionContext) -> FsFuture<'a, ()> {
+        Box::pin(async move {
+            let (src_bucket, Some(src_key)) = parse_dav_path(from) else {
+                return Err(FsError::Forbidden);
+            };
+            let (dst_bucket, Some(dst_key)) = parse_dav_path(to) else {
+                return Err(FsError::Forbidden);
+            };
+
+            // A collection has no object of its own, copy everything below its prefix
+            if self.resolve(from, session).await?.is_dir {
+                return self
+                    .copy_prefix((&src_bucket, &dir_prefix(&src_key)), (&dst_bucket, &dir_prefix(&dst_key)), session)
+                    .await
+                    .map(|_| ());
+            }
+            self.copy_key((&src_bucket, &src_key), (&dst_bucket, &dst_key), session).await
+        })
+    }
+}
+
+async fn read_range<S>(
+    storage: &S,
+    session: &SessionContext,
+    bucket: &str,
+    key: &str,
+    offset: u64,
+    length: u64,
+) -> FsResult<Bytes>
+where
+    S: S3StorageBackend,
+{
+    let output = storage
+        .get_object_range(bucket, key, access_key(session), secret_key(session), offset, length)
+        .await
+        .map_err(|e| {
+            error!("WebDAV read '{}/{}' at {} failed: {}", bucket, key, offset, e);
+            FsError::GeneralFailure
+        })?;
+
+    let mut body = output.body.ok_or(FsError::GeneralFailure)?;
+    let mut data = Vec::with_capacity(length as usize);
+    while let Some(chunk) = body.next().await {
+        match chunk {
+            Ok(bytes) => data.extend_from_slice(&bytes),
+            Err(e) => {
+                error!("Error reading stream: {}", e);
+                return Err(FsError::GeneralFailure);
+            }
+        }
+    }
+
+    Ok(Bytes::from(data))
+}
+
+async fn upload<S>(storage: &S, session: &SessionContext, bucket: &str, key: &str, buffer: Vec<u8>) -> FsResult<()>
+where
+    S: S3StorageBackend,
+{
+    let file_size = buffer.len();
+
+    let mut put_builder = PutObjectInput::builder();
+    put_builder.set_bucket(bucket.to_string());
+    put_builder.set_key(key.to_string());
+    put_builder.set_content_length(Some(file_size as i64));
+
+    let data_bytes = Bytes::from(buffer);
+    let body = stream::once(async move { Ok::<Bytes, std::io::Error>(data_bytes) });
+    put_builder.set_body(Some(StreamingBlob::wrap(body)));
+    let put_input = put_builder.build().map_err(|_| FsError::GeneralFailure)?;
+
+    match storage.put_object(put_input, access_key(session), secret_key(session)).await {
+        Ok(_) => {
+            debug!("WebDAV uploaded {} bytes to '{}/{}'", file_size, bucket, key);
+            Ok(())
+        }
+        Err(e) => {
+            error!("WebDAV upload to '{}/{}' failed: {:?}", bucket, key, e);
+            Err(FsError::GeneralFailure)
+        }
+    }
+}
+
+/// An object opened through the WebDAV filesystem
+///
+/// Reads are served with ranged GETs. Writes are buffered in memory and
+/// uploaded as a single object when the file is flushed.
+pub struct WebDavFile<S> {
+    storage: S,
+    session_context: SessionContext,
+    bucket: String,
+    key: String,
+    metadata: WebDavMetadata,
+    /// Write buffer, `None` for read-only files
+    buffer: Option<Vec<u8>>,
+    /// Whether the buffer holds changes not yet uploaded
+    dirty: bool,
+    pos: u64,
+}
+
+impl<S> Debug for WebDavFile<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebDavFile")
+            .field("bucket", &self.bucket)
+            .field("key", &self.key)
+            .field("writable", &self.buffer.is_some())
+            .field("pos", &self.pos)
+            .finish()
+    }
+}
+
+impl<S> WebDavFile<S>
+where
+    S: S3StorageBackend + Debug + Send + Sync + 'static,
+{
+    fn new(
+        storage: S,
+        session_context: SessionContext,
+        bucket: String,
+        key: String,
+        metadata: WebDavMetadata,
+        buffer: Option<Vec<u8>>,
+    ) -> Self {
+        // A file opened for writing is uploaded on flush even if nothing is written
+        let dirty = buffer.is_some();
+        Self {
+            storage,
+            session_context,
+            bucket,
+            key,
+            metadata,
+            buffer,
+            dirty,
+            pos: 0,
+        }
+    }
+
+    fn len(&self) -> u64 {
+        match &self.buffer {
+            Some(buffer) => buffer.len() as u64,
+            None => self.metadata.size,
+        }
+    }
+
+    fn write_at_pos(&mut self, data: &[u8]) -> FsResult<()> {
+        let buffer = self.buffer.as_mut().ok_or(FsError::Forbidden)?;
+        let end = self
+            .pos
+            .checked_add(data.len() as u64)
+            .filter(|end| *end <= MAX_FILE_SIZE)
+            .ok_or(FsError::TooLarge)?;
+        let offset = self.pos as usize;
+        let end = end as usize;
+        if buffer.len() < end {
+            buffer.resize(end, 0);
+        }
+        buffer[offset..end].copy_from_slice(data);
+        self.pos = end as u64;
+        self.dirty = true;
+        Ok(())
+    }
+}
+
+impl<S> DavFile for WebDavFile<S>
+where
+    S: S3StorageBackend + Debug + Send + Sync + 'static,
+{
+    fn metadata(&mut self) -> FsFuture<'_, Box<dyn DavMetaData>> {
+        let mut metadata = self.metadata.clone();
+        metadata.size = self.len();
+        Box::pin(async move { Ok(Box::new(metadata) as Box<dyn DavMetaData>) })
+    }
+
+    fn write_buf(&mut self, mut buf: Box<dyn Buf + Send>) -> FsFuture<'_, ()> {
+        Box::pin(async move {
+            while buf.has_remaining() {
+                let chunk = buf.chunk().to_vec();
+                buf.advance(chunk.len());
+                self.write_at_pos(&chunk)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn write_bytes(&mut self, buf: Bytes) -> FsFuture<'_, ()> {
+        Box::pin(async move { self.write_at_pos(&buf) })
+    }
+
+    fn read_bytes(&mut self, count: usize) -> FsFuture<'_, Bytes> {
+        Box::pin(async move {
+            let remaining = self.len().saturating_sub(self.pos);
+            let length = remaining.min(count as u64);
+            if length == 0 {
+                return Ok(Bytes::new());
+            }
+
+            let data = match &self.buffer {
+                Some(buffer) => Bytes::copy_from_slice(&buffer[self.pos as usize..(self.pos + length) as usize]),
+                None => read_range(&self.storage, &self.session_context, &self.bucket, &self.key, self.pos, length).await?,
+            };
+            self.pos += data.len() as u64;
+            Ok(data)
+        })
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> FsFuture<'_, u64> {
+        Box::pin(async move {
+            let new_pos = match pos {
+                SeekFrom::Start(offset) => Some(offset),
+                SeekFrom::End(offset) => self.len().checked_add_signed(offset),
+                SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+            };
+            self.pos = new_pos.ok_or(FsError::GeneralFailure)?;
+            Ok(self.pos)
+        })
+    }
+
+    fn flush(&mut self) -> FsFuture<'_, ()> {
+        Box::pin(async move {
+            if !self.dirty {
+                return Ok(());
+            }
+            let Some(buffer) = self.buffer.as_ref() else {
+                return Ok(());
+            };
+
+            upload(&self.storage, &self.session_context, &self.bucket, &self.key, buffer.clone()).await?;
+            self.metadata = WebDavMetadata::file(buffer.len() as u64, Some(SystemTime::now()));
+            self.dirty = false;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dav_path() {
+        let root = DavPath::new("/").unwrap();
+        assert_eq!(parse_dav_path(&root), (String::new(), None));
+
+        let bucket = DavPath::new("/bucket/").unwrap();
+        assert_eq!(parse_dav_path(&bucket), ("bucket".to_string(), None));
+
+        let object = DavPath::new("/bucket/dir/file%20name.txt").unwrap();
+        assert_eq!(parse_dav_path(&object), ("bucket".to_string(), Some("dir/file name.txt".to_string())));
+    }
+
+    #[test]
+    fn test_dir_prefix() {
+        assert_eq!(dir_prefix("a/b"), "a/b/");
+        assert_eq!(dir_prefix("a/b/"), "a/b/");
+    }
+}
@@ -0,0 +1,272 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use dav_server::davpath::DavPath;
+use dav_server::ls::{DavLock, DavLockSystem, LsFuture};
+use futures_util::future;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tracing::debug;
+use xmltree::Element;
+
+/// In-memory WebDAV lock table
+///
+/// Locks are advisory and only visible to WebDAV clients served by this node;
+/// they do not block S3 writers. The table is neither shared across the
+/// cluster nor persisted, so clients relying on LOCK must be pinned to one
+/// node (e.g. sticky sessions at the load balancer) and lose their locks
+/// when that node restarts.
+#[derive(Debug, Clone)]
+pub struct WebDavLockSystem {
+    /// Active locks keyed by token
+    locks: Arc<Mutex<HashMap<String, DavLock>>>,
+    /// Maximum timeout granted to a lock
+    max_timeout: Duration,
+}
+
+/// Normalized lock path: decoded, without trailing separator
+fn lock_path(path: &DavPath) -> String {
+    let raw = String::from_utf8_lossy(path.as_bytes());
+    raw.trim_end_matches('/').to_string()
+}
+
+/// Whether `child` equals `parent` or lives below it
+fn is_within(child: &str, parent: &str) -> bool {
+    child == parent || parent.is_empty() || child.strip_prefix(parent).is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Whether `lock` covers `path` (or, for a deep request, anything below `path`)
+fn overlaps(lock: &DavLock, path: &str, deep: bool) -> bool {
+    let locked = lock_path(&lock.path);
+    locked == path || (lock.deep && is_within(path, &locked)) || (deep && is_within(&locked, path))
+}
+
+impl WebDavLockSystem {
+    /// Create a new lock system that caps lock timeouts at `max_timeout`
+    pub fn new(max_timeout: Duration) -> Box<Self> {
+        Box::new(Self {
+            locks: Arc::new(Mutex::new(HashMap::new())),
+            max_timeout,
+        })
+    }
+
+    fn clamp_timeout(&self, timeout: Option<Duration>) -> Duration {
+        timeout.map_or(self.max_timeout, |t| t.min(self.max_timeout))
+    }
+
+    /// Drop expired locks and return the guarded table
+    fn live_locks(&self) -> std::sync::MutexGuard<'_, HashMap<String, DavLock>> {
+        let mut locks = self.locks.lock().unwrap_or_else(|e| e.into_inner());
+        let now = SystemTime::now();
+        locks.retain(|token, lock| {
+            let alive = lock.timeout_at.is_none_or(|at| at > now);
+            if !alive {
+                debug!("WebDAV lock {} on {} expired", token, lock.path);
+            }
+            alive
+        });
+        locks
+    }
+}
+
+impl DavLockSystem for WebDavLockSystem {
+    fn lock(
+        &self,
+        path: &DavPath,
+        principal: Option<&str>,
+        owner: Option<&Element>,
+        timeout: Option<Duration>,
+        shared: bool,
+        deep: bool,
+    ) -> LsFuture<'_, Result<DavLock, DavLock>> {
+        let mut locks = self.live_locks();
+        let target = lock_path(path);
+
+        if let Some(conflict) = locks
+            .values()
+            .find(|existing| overlaps(existing, &target, deep) && !(shared && existing.shared))
+        {
+            debug!("WebDAV lock on {} conflicts with {}", target, conflict.token);
+            return Box::pin(future::ready(Err(conflict.clone())));
+        }
+
+        let timeout = self.clamp_timeout(timeout);
+        let lock = DavLock {
+            token: uuid::Uuid::new_v4().urn().to_string(),
+            path: path.clone(),
+            principal: principal.map(|s| s.to_string()),
+            owner: owner.cloned(),
+            timeout_at: Some(SystemTime::now() + timeout),
+            timeout: Some(timeout),
+            shared,
+            deep,
+        };
+
+        debug!("WebDAV lock {} created on {}", lock.token, target);
+        locks.insert(lock.token.clone(), lock.clone());
+        Box::pin(future::ready(Ok(lock)))
+    }
+
+    fn unlock(&self, path: &DavPath, token: &str) -> LsFuture<'_, Result<(), ()>> {
+        let mut locks = self.live_locks();
+        let target = lock_path(path);
+
+        let result = match locks.get(token) {
+            Some(lock) if is_within(&target, &lock_path(&lock.path)) => {
+                locks.remove(token);
+                Ok(())
+            }
+            _ => Err(()),
+        };
+        Box::pin(future::ready(result))
+    }
+
+    fn refresh(&self, path: &DavPath, token: &str, timeout: Option<Duration>) -> LsFuture<'_, Result<DavLock, ()>> {
+        let mut locks = self.live_locks();
+        let target = lock_path(path);
+        let timeout = self.clamp_timeout(timeout);
+
+        let result = match locks.get_mut(token) {
+            Some(lock) if is_within(&target, &lock_path(&lock.path)) => {
+                lock.timeout = Some(timeout);
+                lock.timeout_at = Some(SystemTime::now() + timeout);
+                Ok(lock.clone())
+            }
+            _ => Err(()),
+        };
+        Box::pin(future::ready(result))
+    }
+
+    fn check(
+        &self,
+        path: &DavPath,
+        principal: Option<&str>,
+        ignore_principal: bool,
+        deep: bool,
+        submitted_tokens: Vec<&str>,
+    ) -> LsFuture<'_, Result<(), DavLock>> {
+        let locks = self.live_locks();
+        let target = lock_path(path);
+
+        let mut holds_lock = false;
+        let mut first_shared: Option<&DavLock> = None;
+        for lock in locks.values().filter(|lock| overlaps(lock, &target, deep)) {
+            let submitted = submitted_tokens.iter().any(|t| *t == lock.token);
+            if submitted && (ignore_principal || principal == lock.principal.as_deref()) {
+                holds_lock = true;
+            } else if !lock.shared {
+                return Box::pin(future::ready(Err(lock.clone())));
+            } else {
+                first_shared.get_or_insert(lock);
+            }
+        }
+
+        let result = match first_shared {
+            Some(lock) if !holds_lock => Err(lock.clone()),
+            _ => Ok(()),
+        };
+        Box::pin(future::ready(result))
+    }
+
+    fn discover(&self, path: &DavPath) -> LsFuture<'_, Vec<DavLock>> {
+        let locks = self.live_locks();
+        let target = lock_path(path);
+
+        let found = locks
+            .values()
+            .filter(|lock| {
+                let locked = lock_path(&lock.path);
+                locked == target || (lock.deep && is_within(&target, &locked))
+            })
+            .cloned()
+            .collect();
+        Box::pin(future::ready(found))
+    }
+
+    fn delete(&self, path: &DavPath) -> LsFuture<'_, Result<(), ()>> {
+        let mut locks = self.live_locks();
+        let target = lock_path(path);
+
+        locks.retain(|_, lock| !is_within(&lock_path(&lock.path), &target));
+        Box::pin(future::ready(Ok(())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dav_path(p: &str) -> DavPath {
+        DavPath::new(p).unwrap()
+    }
+
+    #[test]
+    fn test_is_within() {
+        assert!(is_within("/bucket/a", "/bucket"));
+        assert!(is_within("/bucket", "/bucket"));
+        assert!(is_within("/bucket", ""));
+        assert!(!is_within("/bucket2", "/bucket"));
+    }
+
+    #[tokio::test]
+    async fn test_exclusive_lock_conflicts() {
+        let ls = WebDavLockSystem::new(Duration::from_secs(60));
+        let lock = ls
+            .lock(&dav_path("/bucket/a.txt"), None, None, None, false, false)
+            .await
+            .unwrap();
+
+        assert!(
+            ls.lock(&dav_path("/bucket/a.txt"), None, None, None, true, false)
+                .await
+                .is_err()
+        );
+        assert!(ls.check(&dav_path("/bucket/a.txt"), None, true, false, vec![]).await.is_err());
+        assert!(
+            ls.check(&dav_path("/bucket/a.txt"), None, true, false, vec![&lock.token])
+                .await
+                .is_ok()
+        );
+
+        ls.unlock(&dav_path("/bucket/a.txt"), &lock.token).await.unwrap();
+        assert!(ls.check(&dav_path("/bucket/a.txt"), None, true, false, vec![]).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_deep_lock_covers_children() {
+        let ls = WebDavLockSystem::new(Duration::from_secs(60));
+        ls.lock(&dav_path("/bucket/dir/"), None, None, None, false, true)
+            .await
+            .unwrap();
+
+        assert!(
+            ls.check(&dav_path("/bucket/dir/file"), None, true, false, vec![])
+                .await
+                .is_err()
+        );
+        assert!(ls.check(&dav_path("/bucket/other"), None, true, false, vec![]).await.is_ok());
+        assert!(ls.check(&dav_path("/bucket/"), None, true, true, vec![]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_timeout_is_clamped() {
+        let ls = WebDavLockSystem::new(Duration::from_secs(60));
+        let lock = ls
+            .lock(&dav_path("/bucket/a"), None, None, Some(Duration::from_secs(3600)), true, false)
+            .await
+            .unwrap();
+        assert_eq!(lock.timeout, Some(Duration::from_secs(60)));
+    }
+}
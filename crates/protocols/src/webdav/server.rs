@@ -0,0 +1,247 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::config::{WebDavConfig, WebDavInitError};
+use super::driver::WebDavDriver;
+use super::lock::WebDavLockSystem;
+use crate::common::client::s3::StorageBackend;
+use crate::common::session::{Protocol, ProtocolPrincipal, SessionContext};
+use crate::constants::webdav::{AUTH_REALM, BASIC_AUTH_SCHEME};
+use dav_server::DavHandler;
+use dav_server::body::Body;
+use http::{HeaderValue, Request, Response, StatusCode, header};
+use hyper::body::Incoming;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper_util::rt::TokioIo;
+use std::convert::Infallible;
+use std::fmt::Debug;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, info, warn};
+
+/// WebDAV server implementation
+pub struct WebDavServer<S> {
+    /// Server configuration
+    config: WebDavConfig,
+    /// S3 storage backend
+    storage: S,
+}
+
+impl<S> WebDavServer<S>
+where
+    S: StorageBackend + Clone + Send + Sync + 'static + Debug,
+{
+    /// Create a new WebDAV server
+    pub async fn new(config: WebDavConfig, storage: S) -> Result<Self, WebDavInitError> {
+        config.validate().await?;
+        Ok(Self { config, storage })
+    }
+
+    /// Start the WebDAV server
+    ///
+    /// This method binds the listener first to ensure the port is available,
+    /// then serves HTTP connections until the shutdown signal is received.
+    pub async fn start(&self, mut shutdown_rx: broadcast::Receiver<()>) -> Result<(), WebDavInitError> {
+        info!("Initializing WebDAV server on {}", self.config.bind_addr);
+
+        let tls_acceptor = self.build_tls_acceptor()?;
+        let handler = Arc::new(
+            DavHandler::<SessionContext>::builder()
+                .filesystem(Box::new(WebDavDriver::new(self.storage.clone())))
+                .locksystem(WebDavLockSystem::new(self.config.max_lock_timeout))
+                .build_handler(),
+        );
+
+        let listener = TcpListener::bind(self.config.bind_addr).await?;
+        info!("WebDAV server listening on {}", self.config.bind_addr);
+
+        loop {
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let (socket, peer_addr) = match accepted {
+                        Ok(conn) => conn,
+                        Err(e) => {
+                            warn!("WebDAV accept error: {}", e);
+                            continue;
+                        }
+                    };
+
+                    debug!("WebDAV connection accepted from {}", peer_addr);
+                    let _ = socket.set_nodelay(true);
+
+                    let handler = handler.clone();
+                    let tls_acceptor = tls_acceptor.clone();
+                    tokio::spawn(async move {
+                        match tls_acceptor {
+                            Some(acceptor) => match acceptor.accept(socket).await {
+                                Ok(stream) => serve_connection(stream, handler, peer_addr).await,
+                                Err(e) => debug!("WebDAV TLS handshake with {} failed: {}", peer_addr, e),
+                            },
+                            None => serve_connection(socket, handler, peer_addr).await,
+                        }
+                    });
+                }
+                _ = shutdown_rx.recv() => {
+                    info!("WebDAV server received shutdown signal");
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Build the TLS acceptor when TLS is enabled
+    fn build_tls_acceptor(&self) -> Result<Option<TlsAcceptor>, WebDavInitError> {
+        if !self.config.tls_enabled {
+            info!("TLS disabled, running WebDAV over plain HTTP");
+            return Ok(None);
+        }
+
+        let cert_dir = self
+            .config
+            .cert_dir
+            .as_deref()
+            .ok_or_else(|| WebDavInitError::InvalidConfig("TLS enabled but certificate directory not provided".into()))?;
+        debug!("Enabling WebDAV TLS with multi-certificate support from directory: {}", cert_dir);
+
+        let cert_key_pairs = rustfs_utils::load_all_certs_from_directory(cert_dir)
+            .map_err(|e| WebDavInitError::InvalidConfig(format!("Failed to load certificates: {}", e)))?;
+
+        if cert_key_pairs.is_empty() {
+            return Err(WebDavInitError::InvalidConfig("No valid certificates found in directory".into()));
+        }
+
+        debug!("Loaded {} certificates for WebDAV", cert_key_pairs.len());
+
+        let resolver = rustfs_utils::create_multi_cert_resolver(cert_key_pairs)
+            .map_err(|e| WebDavInitError::InvalidConfig(format!("Failed to create certificate resolver: {}", e)))?;
+
+        let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+
+        let mut server_config = rustls::ServerConfig::builder()
+            .with_no_client_auth()
+            .with_cert_resolver(Arc::new(resolver));
+        server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+        Ok(Some(TlsAcceptor::from(Arc::new(server_config))))
+    }
+
+    /// Get server configuration
+    pub fn config(&self) -> &WebDavConfig {
+        &self.config
+    }
+
+    /// Get storage backend
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+}
+
+/// Serve HTTP/1.1 requests on an accepted connection
+async fn serve_connection<I>(io: I, handler: Arc<DavHandler<SessionContext>>, peer_addr: SocketAddr)
+where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let service = service_fn(move |req: Request<Incoming>| {
+        let handler = handler.clone();
+        async move { Ok::<_, Infallible>(handle_request(req, &handler, peer_addr.ip()).await) }
+    });
+
+    if let Err(e) = http1::Builder::new().serve_connection(TokioIo::new(io), service).await {
+        debug!("WebDAV connection with {} ended with error: {}", peer_addr, e);
+    }
+}
+
+/// Authenticate a request and hand it to the DAV handler
+async fn handle_request(req: Request<Incoming>, handler: &DavHandler<SessionContext>, source_ip: IpAddr) -> Response<Body> {
+    match authenticate(req.headers().get(header::AUTHORIZATION), source_ip).await {
+        Some(session_context) => handler.handle_guarded(req, session_context).await,
+        None => unauthorized(),
+    }
+}
+
+fn unauthorized() -> Response<Body> {
+    let mut response = Response::new(Body::from("Unauthorized"));
+    *response.status_mut() = StatusCode::UNAUTHORIZED;
+    if let Ok(value) = HeaderValue::from_str(&format!("Basic realm=\"{}\"", AUTH_REALM)) {
+        response.headers_mut().insert(header::WWW_AUTHENTICATE, value);
+    }
+    response
+}
+
+/// Decode an HTTP Basic `Authorization` header into access and secret key
+fn parse_basic_auth(value: &HeaderValue) -> Option<(String, String)> {
+    let encoded = value.to_str().ok()?.strip_prefix(BASIC_AUTH_SCHEME)?.trim();
+    let decoded = base64_simd::STANDARD.decode_to_vec(encoded).ok()?;
+    let decoded = String::from_utf8(decoded).ok()?;
+    let (access_key, secret_key) = decoded.split_once(':')?;
+    Some((access_key.to_string(), secret_key.to_string()))
+}
+
+/// Authenticate a WebDAV request against RustFS IAM system
+async fn authenticate(header: Option<&HeaderValue>, source_ip: IpAddr) -> Option<SessionContext> {
+    let (access_key, secret_key) = header.and_then(parse_basic_auth)?;
+
+    let iam_sys = match rustfs_iam::get() {
+        Ok(iam_sys) => iam_sys,
+        Err(e) => {
+            warn!("IAM system unavailable during WebDAV auth: {}", e);
+            return None;
+        }
+    };
+
+    let (user_identity, is_valid) = match iam_sys.check_key(&access_key).await {
+        Ok(result) => result,
+        Err(e) => {
+            warn!("IAM check_key failed for {}: {}", access_key, e);
+            return None;
+        }
+    };
+
+    if !is_valid {
+        warn!("WebDAV login failed: Invalid access key '{}'", access_key);
+        return None;
+    }
+
+    let identity = user_identity?;
+    if !identity.credentials.secret_key.eq(&secret_key) {
+        warn!("WebDAV login failed: Invalid secret key for '{}'", access_key);
+        return None;
+    }
+
+    Some(SessionContext::new(
+        ProtocolPrincipal::new(Arc::new(identity)),
+        Protocol::WebDav,
+        source_ip,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_basic_auth() {
+        let encoded = base64_simd::STANDARD.encode_to_string("user:pa:ss");
+        let value = HeaderValue::from_str(&format!("Basic {}", encoded)).unwrap();
+        assert_eq!(parse_basic_auth(&value), Some(("user".to_string(), "pa:ss".to_string())));
+
+        let bearer = HeaderValue::from_static("Bearer token");
+        assert_eq!(parse_basic_auth(&bearer), None);
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::scanner_inventory::BucketInventory;
 use path_clean::PathClean;
 use s3s::dto::BucketLifecycleConfiguration;
 use serde::{Deserialize, Serialize};
@@ -590,6 +591,9 @@ pub struct DataUsageCacheInfo {
     pub skip_healing: bool,
     pub lifecycle: Option<Arc<BucketLifecycleConfiguration>>,
     pub replication: Option<Arc<ReplicationConfig>>,
+    /// Inventory reports of the bucket that are built during this scan
+    #[serde(skip)]
+    pub inventory: Option<Arc<BucketInventory>>,
 }
 
 /// Data usage cache
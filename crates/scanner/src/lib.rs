@@ -22,10 +22,13 @@
 
 pub mod data_usage_define;
 pub mod error;
+mod inventory_orc;
 pub mod last_minute;
 pub mod scanner;
 pub mod scanner_folder;
+pub mod scanner_inventory;
 pub mod scanner_io;
+pub mod scanner_multipart;
 
 pub use data_usage_define::*;
 pub use error::ScannerError;
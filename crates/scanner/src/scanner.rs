@@ -16,7 +16,9 @@ use std::sync::Arc;
 
 use crate::data_usage_define::{BACKGROUND_HEAL_INFO_PATH, DATA_USAGE_BLOOM_NAME_PATH, DATA_USAGE_OBJ_NAME_PATH};
 use crate::scanner_folder::data_usage_update_dir_cycles;
+use crate::scanner_inventory::InventoryCycle;
 use crate::scanner_io::ScannerIO;
+use crate::scanner_multipart::abort_incomplete_multipart_uploads;
 use crate::{DataUsageInfo, ScannerError};
 use chrono::{DateTime, Utc};
 use rustfs_common::heal_channel::HealScanMode;
@@ -200,14 +202,24 @@ pub async fn run_data_scanner(ctx: CancellationToken, storeapi: Arc<ECStore>) ->
 
                let done_cycle = Metrics::time(Metric::ScanCycle);
                let cycle_start = std::time::Instant::now();
+               let inventory = InventoryCycle::begin(storeapi.clone()).await;
                if let Err(e) = storeapi.clone().nsscanner(ctx.clone(), sender, cycle_info.current, scan_mode).await {
                 error!("Failed to scan namespace: {e}");
                 emit_scan_cycle_complete(false, cycle_start.elapsed());
+                inventory.abort();
                } else {
                 done_cycle();
                 emit_scan_cycle_complete(true, cycle_start.elapsed());
                 info!("Namespace scanned successfully");
 
+                abort_incomplete_multipart_uploads(ctx.clone(), storeapi.clone()).await;
+                // A cancelled scan can stop before every bucket was visited
+                if ctx.is_cancelled() {
+                    inventory.abort();
+                } else {
+                    inventory.finish().await;
+                }
+
                 cycle_info.next +=1;
                 cycle_info.current = 0;
                 cycle_info.cycle_completed.push(Utc::now());
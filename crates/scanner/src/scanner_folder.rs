@@ -20,6 +20,7 @@ use std::time::{Duration, SystemTime};
 use crate::ReplTargetSizeSummary;
 use crate::data_usage_define::{DataUsageCache, DataUsageEntry, DataUsageHash, DataUsageHashMap, SizeSummary, hash_path};
 use crate::error::ScannerError;
+use crate::scanner_inventory::BucketInventory;
 use crate::scanner_io::ScannerIODisk as _;
 use rustfs_common::heal_channel::{HEAL_DELETE_DANGLING, HealChannelRequest, HealOpts, HealScanMode, send_heal_request};
 use rustfs_common::metrics::{IlmAction, Metric, Metrics, UpdateCurrentPathFn, current_path_updater};
@@ -90,6 +91,7 @@ pub struct ScannerItem {
     pub file_type: FileType,
     pub lifecycle: Option<Arc<BucketLifecycleConfiguration>>,
     pub replication: Option<Arc<ReplicationConfig>>,
+    pub inventory: Option<Arc<BucketInventory>>,
     pub heal_enabled: bool,
     pub heal_bitrot: bool,
     pub debug: bool,
@@ -240,7 +242,7 @@ impl ScannerItem {
                         done_ilm(1)();
                     }
 
-                    IlmAction::NoneAction | IlmAction::ActionCount => {
+                    IlmAction::NoneAction | IlmAction::AbortMultipartUploadAction | IlmAction::ActionCount => {
                         size = self.heal_actions(store.clone(), oi, actual_size, size_summary).await;
                     }
                 }
@@ -616,6 +618,7 @@ impl FolderScanner {
                     object_name: file_name,
                     lifecycle: active_life_cycle.clone(),
                     replication: active_replication.clone(),
+                    inventory: self.old_cache.info.inventory.clone(),
                     heal_enabled,
                     heal_bitrot: self.scan_mode == HealScanMode::Deep,
                     debug: self.data_usage_scanner_debug,
@@ -758,7 +761,8 @@ impl FolderScanner {
 
                 let h = hash_path(&folder_item.name);
 
-                if !into.compacted && self.old_cache.is_compacted(&h) {
+                // Folders of buckets with an inventory report due are listed in full
+                if !into.compacted && self.old_cache.is_compacted(&h) && self.old_cache.info.inventory.is_none() {
                     let next_cycle = self.old_cache.info.next_cycle as u32;
                     if !h.mod_(next_cycle, data_usage_update_dir_cycles()) {
                         // Transfer and add as child...
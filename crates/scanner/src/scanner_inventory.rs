@@ -0,0 +1,765 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::{Arc, LazyLock, OnceLock, RwLock};
+
+use crate::ScannerError;
+use crate::inventory_orc::{orc_data_file, orc_type_name};
+use arrow::array::{ArrayRef, BooleanBuilder, Int64Builder, StringBuilder, TimestampMillisecondBuilder};
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use chrono::{DateTime, SecondsFormat, Utc};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use parquet::arrow::ArrowWriter;
+use rustfs_config::{DEFAULT_INVENTORY_MAX_ROWS_PER_FILE, ENV_INVENTORY_MAX_ROWS_PER_FILE};
+use rustfs_ecstore::StorageAPI;
+use rustfs_ecstore::bucket::inventory::{
+    INVENTORY_DESTINATION_ARN_PREFIX, INVENTORY_OPTIONAL_FIELDS, InventoryApi, get_inventory_configs,
+};
+use rustfs_ecstore::config::com::{read_config, save_config};
+use rustfs_ecstore::disk::BUCKET_META_PREFIX;
+use rustfs_ecstore::error::Error as EcstoreError;
+use rustfs_ecstore::store::ECStore;
+use rustfs_ecstore::store_api::{BucketOptions, ObjectInfo};
+use rustfs_utils::path::SLASH_SEPARATOR;
+use s3s::dto::{InventoryConfiguration, InventoryFormat, ServerSideEncryption};
+use s3s::header::{X_AMZ_OBJECT_LOCK_LEGAL_HOLD, X_AMZ_OBJECT_LOCK_MODE, X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+/// Last generation time of every inventory configuration, so schedules survive restarts
+static INVENTORY_STATE_PATH: LazyLock<String> =
+    LazyLock::new(|| format!("{BUCKET_META_PREFIX}{SLASH_SEPARATOR}.inventory-state.json"));
+
+/// Reports filled by the running scanner cycle, keyed by source bucket
+static ACTIVE_INVENTORIES: LazyLock<RwLock<HashMap<String, Arc<BucketInventory>>>> = LazyLock::new(Default::default);
+
+static INVENTORY_WRITER: OnceLock<Arc<dyn InventoryWriter>> = OnceLock::new();
+
+const INVENTORY_MANIFEST_VERSION: &str = "2016-11-30";
+
+/// How a report file is written into the destination bucket
+#[derive(Debug, Clone)]
+pub struct InventoryPutOptions {
+    pub content_type: &'static str,
+    /// Encryption requested by the inventory configuration, the bucket default applies otherwise
+    pub server_side_encryption: Option<ServerSideEncryption>,
+    pub ssekms_key_id: Option<String>,
+}
+
+/// Writes report files into their destination bucket like a PutObject request would, applying the
+/// bucket's default encryption and Object Lock retention. The server installs it with
+/// [`set_inventory_writer`] since the scanner has no access to the SSE key management.
+#[async_trait::async_trait]
+pub trait InventoryWriter: Send + Sync + 'static {
+    async fn put_object(&self, bucket: &str, key: &str, data: Vec<u8>, opts: InventoryPutOptions) -> Result<(), ScannerError>;
+}
+
+/// Installs the writer reports are stored with, reports are not generated until one is set
+pub fn set_inventory_writer(writer: Arc<dyn InventoryWriter>) {
+    if INVENTORY_WRITER.set(writer).is_err() {
+        warn!("set_inventory_writer: inventory writer already set");
+    }
+}
+
+/// The reports the running scanner cycle builds for the bucket, if any are due
+pub fn bucket_inventory(bucket: &str) -> Option<Arc<BucketInventory>> {
+    ACTIVE_INVENTORIES
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(bucket)
+        .cloned()
+}
+
+fn set_active_inventories(inventories: HashMap<String, Arc<BucketInventory>>) {
+    *ACTIVE_INVENTORIES.write().unwrap_or_else(|e| e.into_inner()) = inventories;
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct InventoryState {
+    /// Unix timestamp of the last report, keyed by `bucket/id`
+    last_runs: HashMap<String, i64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InventoryManifest {
+    source_bucket: String,
+    destination_bucket: String,
+    version: &'static str,
+    creation_timestamp: String,
+    file_format: String,
+    file_schema: String,
+    files: Vec<InventoryFile>,
+}
+
+#[derive(Debug, Serialize)]
+struct InventoryFile {
+    key: String,
+    size: usize,
+    #[serde(rename = "MD5checksum")]
+    md5_checksum: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ColumnKind {
+    Str,
+    Int,
+    Bool,
+    Time,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum InventoryValue {
+    Str(String),
+    Int(i64),
+    Bool(bool),
+    Time(OffsetDateTime),
+    Null,
+}
+
+/// The S3 Inventory reports built during one scanner cycle.
+///
+/// Reports that are a day or a week past their previous run are started before the namespace is
+/// scanned. The scanner adds every object version it reads from disk to the reports of its bucket,
+/// so the objects are not listed a second time through the object layer. Data files are written
+/// as gzipped CSV, ORC or Parquet while the scan runs and the `manifest.json` once it completed.
+pub struct InventoryCycle {
+    store: Arc<ECStore>,
+    state: InventoryState,
+    inventories: Vec<Arc<BucketInventory>>,
+}
+
+impl InventoryCycle {
+    /// Starts the reports that are due and makes them visible to the scanner
+    pub async fn begin(store: Arc<ECStore>) -> Self {
+        let mut cycle = Self {
+            store: store.clone(),
+            state: InventoryState::default(),
+            inventories: Vec::new(),
+        };
+
+        let Some(writer) = INVENTORY_WRITER.get() else {
+            return cycle;
+        };
+
+        let buckets = match store.list_bucket(&BucketOptions::default()).await {
+            Ok(buckets) => buckets,
+            Err(err) => {
+                warn!("inventory: failed to list buckets: {err}");
+                return cycle;
+            }
+        };
+
+        cycle.state = read_inventory_state(store.clone()).await;
+        let now = OffsetDateTime::now_utc();
+        let mut active = HashMap::new();
+        for bucket in buckets {
+            let configs = match get_inventory_configs(&bucket.name).await {
+                Ok(configs) => configs,
+                Err(err) => {
+                    warn!("inventory: failed to load inventory configurations of {}: {err}", bucket.name);
+                    continue;
+                }
+            };
+
+            let reports = configs
+                .into_iter()
+                .filter(|config| {
+                    let last_run = cycle
+                        .state
+                        .last_runs
+                        .get(&format!("{}/{}", bucket.name, config.id))
+                        .and_then(|&ts| OffsetDateTime::from_unix_timestamp(ts).ok());
+                    config.is_enabled && config.is_due(last_run, now)
+                })
+                .map(|config| Mutex::new(InventoryReport::new(writer.clone(), &bucket.name, config, now)))
+                .collect::<Vec<_>>();
+            if reports.is_empty() {
+                continue;
+            }
+
+            let inventory = Arc::new(BucketInventory {
+                bucket: bucket.name.clone(),
+                reports,
+            });
+            cycle.inventories.push(inventory.clone());
+            active.insert(bucket.name, inventory);
+        }
+
+        if !active.is_empty() {
+            debug!("inventory: building reports for {} buckets", active.len());
+        }
+        set_active_inventories(active);
+        cycle
+    }
+
+    /// Writes the remaining rows and the manifests once the namespace was scanned completely
+    pub async fn finish(mut self) {
+        set_active_inventories(HashMap::new());
+        if self.inventories.is_empty() {
+            return;
+        }
+
+        for inventory in std::mem::take(&mut self.inventories) {
+            for report in &inventory.reports {
+                let mut report = report.lock().await;
+                if let Some(err) = report.error.take() {
+                    warn!("inventory: inventory {} of bucket {} failed: {err}", report.config.id, inventory.bucket);
+                    continue;
+                }
+
+                let result = match report.flush().await {
+                    Ok(()) => report.write_manifest().await,
+                    Err(err) => Err(err),
+                };
+                match result {
+                    Ok(()) => {
+                        info!("inventory: wrote inventory {} of bucket {}", report.config.id, inventory.bucket);
+                        self.state
+                            .last_runs
+                            .insert(format!("{}/{}", inventory.bucket, report.config.id), report.created.unix_timestamp());
+                    }
+                    Err(err) => warn!("inventory: inventory {} of bucket {} failed: {err}", report.config.id, inventory.bucket),
+                }
+            }
+        }
+
+        save_inventory_state(self.store.clone(), &self.state).await;
+    }
+
+    /// Drops the reports of an incomplete scan, they are started again by the next cycle. Data files
+    /// already written have no manifest and are ignored by inventory consumers.
+    pub fn abort(self) {
+        set_active_inventories(HashMap::new());
+        if !self.inventories.is_empty() {
+            warn!("inventory: scan incomplete, dropping the reports of {} buckets", self.inventories.len());
+        }
+    }
+}
+
+/// The reports of one source bucket that the scanner fills in
+#[derive(Debug)]
+pub struct BucketInventory {
+    bucket: String,
+    reports: Vec<Mutex<InventoryReport>>,
+}
+
+impl BucketInventory {
+    /// Adds the versions of one object, as the scanner read them from its metadata, to every report
+    pub async fn collect(&self, versions: &[ObjectInfo]) {
+        for report in &self.reports {
+            report.lock().await.push(versions).await;
+        }
+    }
+
+    /// Marks the reports incomplete when part of the bucket could not be scanned
+    pub async fn fail(&self, reason: &str) {
+        for report in &self.reports {
+            let mut report = report.lock().await;
+            if report.error.is_none() {
+                report.error = Some(reason.to_string());
+            }
+        }
+    }
+}
+
+async fn read_inventory_state(store: Arc<ECStore>) -> InventoryState {
+    match read_config(store, &INVENTORY_STATE_PATH).await {
+        Ok(buf) => serde_json::from_slice(&buf).unwrap_or_else(|e| {
+            warn!("Failed to unmarshal inventory state from {}: {}", &*INVENTORY_STATE_PATH, e);
+            InventoryState::default()
+        }),
+        Err(e) => {
+            if e != EcstoreError::ConfigNotFound {
+                warn!("Failed to read inventory state from {}: {}", &*INVENTORY_STATE_PATH, e);
+            }
+            InventoryState::default()
+        }
+    }
+}
+
+async fn save_inventory_state(store: Arc<ECStore>, state: &InventoryState) {
+    let data = match serde_json::to_vec(state) {
+        Ok(data) => data,
+        Err(e) => {
+            warn!("Failed to marshal inventory state: {}", e);
+            return;
+        }
+    };
+
+    if let Err(e) = save_config(store, &INVENTORY_STATE_PATH, data).await {
+        warn!("Failed to save inventory state to {}: {}", &*INVENTORY_STATE_PATH, e);
+    }
+}
+
+/// One report of one inventory configuration, flushed to a new data file every `max_rows` objects
+struct InventoryReport {
+    writer: Arc<dyn InventoryWriter>,
+    bucket: String,
+    config: InventoryConfiguration,
+    created: OffsetDateTime,
+    columns: Vec<&'static str>,
+    key_prefix: String,
+    max_rows: usize,
+    rows: Vec<Vec<InventoryValue>>,
+    files: Vec<InventoryFile>,
+    /// Set once a data file could not be written or the scan of the bucket failed
+    error: Option<String>,
+}
+
+impl std::fmt::Debug for InventoryReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InventoryReport")
+            .field("bucket", &self.bucket)
+            .field("id", &self.config.id)
+            .field("rows", &self.rows.len())
+            .field("files", &self.files.len())
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl InventoryReport {
+    fn new(writer: Arc<dyn InventoryWriter>, bucket: &str, config: InventoryConfiguration, created: OffsetDateTime) -> Self {
+        let prefix = config
+            .destination
+            .s3_bucket_destination
+            .prefix
+            .as_deref()
+            .map(|p| p.trim_end_matches('/'))
+            .filter(|p| !p.is_empty());
+        let key_prefix = match prefix {
+            Some(prefix) => format!("{prefix}/{bucket}/{}", config.id),
+            None => format!("{bucket}/{}", config.id),
+        };
+
+        Self {
+            writer,
+            bucket: bucket.to_string(),
+            columns: inventory_columns(&config),
+            config,
+            created,
+            key_prefix,
+            max_rows: rustfs_utils::get_env_usize(ENV_INVENTORY_MAX_ROWS_PER_FILE, DEFAULT_INVENTORY_MAX_ROWS_PER_FILE).max(1),
+            rows: Vec::new(),
+            files: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Adds the versions the report lists to the pending rows and writes a data file once it is full
+    async fn push(&mut self, versions: &[ObjectInfo]) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let prefix = self.config.filter.as_ref().map_or("", |f| f.prefix.as_str());
+        let all_versions = self.config.includes_all_versions();
+        for obj in versions {
+            if obj.is_dir || !obj.name.starts_with(prefix) {
+                continue;
+            }
+            // Current version reports skip objects whose latest version is a delete marker
+            if !all_versions && (!obj.is_latest || obj.delete_marker) {
+                continue;
+            }
+
+            let row = self
+                .columns
+                .iter()
+                .map(|column| column_value(column, &self.bucket, obj))
+                .collect();
+            self.rows.push(row);
+        }
+
+        if self.rows.len() >= self.max_rows
+            && let Err(err) = self.flush().await
+        {
+            self.error = Some(err.to_string());
+        }
+    }
+
+    async fn flush(&mut self) -> Result<(), ScannerError> {
+        if self.rows.is_empty() {
+            return Ok(());
+        }
+
+        let rows = std::mem::take(&mut self.rows);
+        let (data, extension, content_type) = match self.format() {
+            InventoryFormat::PARQUET => (parquet_data_file(&self.columns, &rows)?, "parquet", "application/octet-stream"),
+            InventoryFormat::ORC => (orc_data_file(&self.columns, &rows), "orc", "application/octet-stream"),
+            _ => (csv_data_file(&self.columns, &rows)?, "csv.gz", "application/x-gzip"),
+        };
+
+        let key = format!("{}/data/{}.{extension}", self.key_prefix, Uuid::new_v4());
+        let file = InventoryFile {
+            size: data.len(),
+            md5_checksum: format!("{:x}", md5::compute(&data)),
+            key,
+        };
+        self.put(&file.key, data, content_type).await?;
+
+        debug!(
+            "inventory {} of bucket {}: wrote {} rows to {}",
+            self.config.id,
+            self.bucket,
+            rows.len(),
+            file.key
+        );
+        self.files.push(file);
+        Ok(())
+    }
+
+    async fn write_manifest(&mut self) -> Result<(), ScannerError> {
+        let created = DateTime::<Utc>::from_timestamp_millis(unix_millis(self.created)).unwrap_or_default();
+        let manifest_prefix = format!("{}/{}", self.key_prefix, created.format("%Y-%m-%dT%H-%MZ"));
+
+        let manifest = InventoryManifest {
+            source_bucket: self.bucket.clone(),
+            destination_bucket: format!("{INVENTORY_DESTINATION_ARN_PREFIX}{}", self.config.destination_bucket()),
+            version: INVENTORY_MANIFEST_VERSION,
+            creation_timestamp: unix_millis(self.created).to_string(),
+            file_format: self.format().to_string(),
+            file_schema: match self.format() {
+                InventoryFormat::PARQUET => parquet_file_schema(&self.columns),
+                InventoryFormat::ORC => orc_file_schema(&self.columns),
+                _ => self.columns.join(", "),
+            },
+            files: std::mem::take(&mut self.files),
+        };
+
+        let data = serde_json::to_vec(&manifest)?;
+        let checksum = format!("{:x}", md5::compute(&data));
+        self.put(&format!("{manifest_prefix}/manifest.json"), data, "application/json")
+            .await?;
+        self.put(&format!("{manifest_prefix}/manifest.checksum"), checksum.into_bytes(), "text/plain")
+            .await
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &'static str) -> Result<(), ScannerError> {
+        let encryption = self.config.destination.s3_bucket_destination.encryption.as_ref();
+        let (server_side_encryption, ssekms_key_id) = match encryption {
+            Some(e) if e.ssekms.is_some() => (
+                Some(ServerSideEncryption::from_static(ServerSideEncryption::AWS_KMS)),
+                e.ssekms.as_ref().map(|kms| kms.key_id.clone()),
+            ),
+            Some(e) if e.sses3.is_some() => (Some(ServerSideEncryption::from_static(ServerSideEncryption::AES256)), None),
+            _ => (None, None),
+        };
+
+        let opts = InventoryPutOptions {
+            content_type,
+            server_side_encryption,
+            ssekms_key_id,
+        };
+        self.writer
+            .put_object(self.config.destination_bucket(), key, data, opts)
+            .await
+            .map_err(|e| ScannerError::Other(format!("failed to write {}/{key}: {e}", self.config.destination_bucket())))
+    }
+
+    fn format(&self) -> &str {
+        self.config.destination.s3_bucket_destination.format.as_str()
+    }
+}
+
+/// Report columns in S3 Inventory order: the fixed columns, version columns when all versions are
+/// listed, then the requested optional fields
+fn inventory_columns(config: &InventoryConfiguration) -> Vec<&'static str> {
+    let mut columns = vec!["Bucket", "Key"];
+    if config.includes_all_versions() {
+        columns.extend(["VersionId", "IsLatest", "IsDeleteMarker"]);
+    }
+    columns.extend(INVENTORY_OPTIONAL_FIELDS.into_iter().filter(|f| config.has_optional_field(f)));
+    columns
+}
+
+pub(crate) fn column_kind(column: &str) -> ColumnKind {
+    match column {
+        "Size" => ColumnKind::Int,
+        "IsLatest" | "IsDeleteMarker" | "IsMultipartUploaded" => ColumnKind::Bool,
+        "LastModifiedDate" | "ObjectLockRetainUntilDate" => ColumnKind::Time,
+        _ => ColumnKind::Str,
+    }
+}
+
+fn column_value(column: &str, bucket: &str, obj: &ObjectInfo) -> InventoryValue {
+    match column {
+        "Bucket" => InventoryValue::Str(bucket.to_string()),
+        "Key" => InventoryValue::Str(obj.name.clone()),
+        "VersionId" => string_value(obj.version_id.map(|v| v.to_string()).as_deref()),
+        "IsLatest" => InventoryValue::Bool(obj.is_latest),
+        "IsDeleteMarker" => InventoryValue::Bool(obj.delete_marker),
+        "LastModifiedDate" => obj.mod_time.map_or(InventoryValue::Null, InventoryValue::Time),
+        // Delete markers have no data, size or metadata of their own
+        _ if obj.delete_marker => InventoryValue::Null,
+        "Size" => InventoryValue::Int(obj.size),
+        "ETag" => string_value(obj.etag.as_deref()),
+        "StorageClass" => string_value(Some(obj.storage_class.as_deref().unwrap_or("STANDARD"))),
+        "IsMultipartUploaded" => InventoryValue::Bool(obj.etag.as_deref().is_some_and(|e| e.contains('-'))),
+        "ReplicationStatus" => string_value(Some(obj.replication_status.as_str())),
+        "EncryptionStatus" => InventoryValue::Str(encryption_status(&obj.user_defined).to_string()),
+        "ObjectLockRetainUntilDate" => obj
+            .user_defined
+            .get(X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE.as_str())
+            .and_then(|v| OffsetDateTime::parse(v, &Rfc3339).ok())
+            .map_or(InventoryValue::Null, InventoryValue::Time),
+        "ObjectLockMode" => string_value(obj.user_defined.get(X_AMZ_OBJECT_LOCK_MODE.as_str()).map(String::as_str)),
+        "ObjectLockLegalHoldStatus" => string_value(
+            obj.user_defined
+                .get(X_AMZ_OBJECT_LOCK_LEGAL_HOLD.as_str())
+                .map(String::as_str),
+        ),
+        "Tags" => string_value(Some(obj.user_tags.as_str())),
+        _ => InventoryValue::Null,
+    }
+}
+
+fn string_value(value: Option<&str>) -> InventoryValue {
+    match value.filter(|v| !v.is_empty()) {
+        Some(v) => InventoryValue::Str(v.to_string()),
+        None => InventoryValue::Null,
+    }
+}
+
+fn encryption_status(user_defined: &HashMap<String, String>) -> &'static str {
+    if user_defined.contains_key("x-amz-server-side-encryption-customer-algorithm") {
+        return "SSE-C";
+    }
+    match user_defined.get("x-amz-server-side-encryption").map(String::as_str) {
+        Some("aws:kms") => "SSE-KMS",
+        Some("AES256") => "SSE-S3",
+        _ => "NOT-SSE",
+    }
+}
+
+pub(crate) fn unix_millis(t: OffsetDateTime) -> i64 {
+    (t.unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+fn format_time(t: OffsetDateTime) -> String {
+    DateTime::<Utc>::from_timestamp_millis(unix_millis(t))
+        .map(|d| d.to_rfc3339_opts(SecondsFormat::Millis, true))
+        .unwrap_or_default()
+}
+
+/// Gzipped CSV without a header, every field quoted and object keys URL-encoded
+fn csv_data_file(columns: &[&str], rows: &[Vec<InventoryValue>]) -> Result<Vec<u8>, ScannerError> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    for row in rows {
+        let line = columns
+            .iter()
+            .zip(row)
+            .map(|(&column, value)| {
+                let field = match value {
+                    InventoryValue::Str(s) if column == "Key" => urlencoding::encode(s).into_owned(),
+                    InventoryValue::Str(s) => s.clone(),
+                    InventoryValue::Int(i) => i.to_string(),
+                    InventoryValue::Bool(b) => b.to_string(),
+                    InventoryValue::Time(t) => format_time(*t),
+                    InventoryValue::Null => String::new(),
+                };
+                format!("\"{}\"", field.replace('"', "\"\""))
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        encoder.write_all(line.as_bytes())?;
+        encoder.write_all(b"\n")?;
+    }
+    Ok(encoder.finish()?)
+}
+
+/// Parquet column names are the snake case form of the CSV ones, `LastModifiedDate` becomes `last_modified_date`
+pub(crate) fn parquet_column_name(column: &str) -> String {
+    let mut name = String::with_capacity(column.len() + 4);
+    for (i, c) in column.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            name.push('_');
+        }
+        name.push(c.to_ascii_lowercase());
+    }
+    name
+}
+
+fn parquet_file_schema(columns: &[&str]) -> String {
+    let fields = columns
+        .iter()
+        .map(|&column| {
+            let name = parquet_column_name(column);
+            match column_kind(column) {
+                ColumnKind::Str => format!("optional binary {name} (STRING);"),
+                ColumnKind::Int => format!("optional int64 {name};"),
+                ColumnKind::Bool => format!("optional boolean {name};"),
+                ColumnKind::Time => format!("optional int64 {name} (TIMESTAMP(MILLIS,true));"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("message s3.inventory {{ {fields} }}")
+}
+
+/// ORC files use the Parquet column names with their Hive types
+fn orc_file_schema(columns: &[&str]) -> String {
+    let fields = columns
+        .iter()
+        .map(|&column| format!("{}:{}", parquet_column_name(column), orc_type_name(column_kind(column))))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("struct<{fields}>")
+}
+
+fn parquet_data_file(columns: &[&str], rows: &[Vec<InventoryValue>]) -> Result<Vec<u8>, ScannerError> {
+    let fields = columns
+        .iter()
+        .map(|&column| {
+            let data_type = match column_kind(column) {
+                ColumnKind::Str => DataType::Utf8,
+                ColumnKind::Int => DataType::Int64,
+                ColumnKind::Bool => DataType::Boolean,
+                ColumnKind::Time => DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            };
+            Field::new(parquet_column_name(column), data_type, true)
+        })
+        .collect::<Vec<_>>();
+    let schema = Arc::new(Schema::new(fields));
+
+    let arrays = columns
+        .iter()
+        .enumerate()
+        .map(|(i, &column)| parquet_column(column_kind(column), rows.iter().map(|row| &row[i])))
+        .collect::<Vec<_>>();
+
+    let to_err = |e: &dyn std::fmt::Display| ScannerError::Other(format!("failed to encode parquet inventory: {e}"));
+    let batch = RecordBatch::try_new(schema.clone(), arrays).map_err(|e| to_err(&e))?;
+    let mut writer = ArrowWriter::try_new(Vec::new(), schema, None).map_err(|e| to_err(&e))?;
+    writer.write(&batch).map_err(|e| to_err(&e))?;
+    writer.into_inner().map_err(|e| to_err(&e))
+}
+
+fn parquet_column<'a>(kind: ColumnKind, values: impl Iterator<Item = &'a InventoryValue>) -> ArrayRef {
+    match kind {
+        ColumnKind::Str => {
+            let mut builder = StringBuilder::new();
+            for value in values {
+                match value {
+                    InventoryValue::Str(s) => builder.append_value(s),
+                    _ => builder.append_null(),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ColumnKind::Int => {
+            let mut builder = Int64Builder::new();
+            for value in values {
+                match value {
+                    InventoryValue::Int(i) => builder.append_value(*i),
+                    _ => builder.append_null(),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ColumnKind::Bool => {
+            let mut builder = BooleanBuilder::new();
+            for value in values {
+                match value {
+                    InventoryValue::Bool(b) => builder.append_value(*b),
+                    _ => builder.append_null(),
+                }
+            }
+            Arc::new(builder.finish())
+        }
+        ColumnKind::Time => {
+            let mut builder = TimestampMillisecondBuilder::new();
+            for value in values {
+                match value {
+                    InventoryValue::Time(t) => builder.append_value(unix_millis(*t)),
+                    _ => builder.append_null(),
+                }
+            }
+            Arc::new(builder.finish().with_timezone("UTC"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    fn object(name: &str) -> ObjectInfo {
+        ObjectInfo {
+            name: name.to_string(),
+            size: 42,
+            etag: Some("9b2cf535f27731c974343645a3985328-2".to_string()),
+            mod_time: Some(OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap()),
+            user_defined: HashMap::from([("x-amz-server-side-encryption".to_string(), "aws:kms".to_string())]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_column_values() {
+        let obj = object("photos/a b.jpg");
+        assert_eq!(column_value("Size", "src", &obj), InventoryValue::Int(42));
+        assert_eq!(column_value("IsMultipartUploaded", "src", &obj), InventoryValue::Bool(true));
+        assert_eq!(column_value("EncryptionStatus", "src", &obj), InventoryValue::Str("SSE-KMS".to_string()));
+        assert_eq!(column_value("StorageClass", "src", &obj), InventoryValue::Str("STANDARD".to_string()));
+        assert_eq!(column_value("Tags", "src", &obj), InventoryValue::Null);
+
+        let marker = ObjectInfo {
+            delete_marker: true,
+            ..object("photos/a b.jpg")
+        };
+        assert_eq!(column_value("Size", "src", &marker), InventoryValue::Null);
+        assert_eq!(column_value("IsDeleteMarker", "src", &marker), InventoryValue::Bool(true));
+    }
+
+    #[test]
+    fn test_csv_data_file() {
+        let columns = ["Bucket", "Key", "Size", "LastModifiedDate"];
+        let obj = object("photos/a \"b\".jpg");
+        let rows = vec![columns.iter().map(|c| column_value(c, "src", &obj)).collect()];
+
+        let mut csv = String::new();
+        GzDecoder::new(csv_data_file(&columns, &rows).unwrap().as_slice())
+            .read_to_string(&mut csv)
+            .unwrap();
+        assert_eq!(csv, "\"src\",\"photos%2Fa%20%22b%22.jpg\",\"42\",\"2023-11-14T22:13:20.000Z\"\n");
+    }
+
+    #[test]
+    fn test_parquet_data_file() {
+        assert_eq!(parquet_column_name("ETag"), "e_tag");
+        assert_eq!(parquet_column_name("ObjectLockRetainUntilDate"), "object_lock_retain_until_date");
+        assert_eq!(
+            parquet_file_schema(&["Key", "Size"]),
+            "message s3.inventory { optional binary key (STRING); optional int64 size; }"
+        );
+
+        let columns = ["Bucket", "Key", "IsLatest", "Size", "LastModifiedDate"];
+        let obj = object("a");
+        let rows = vec![columns.iter().map(|c| column_value(c, "src", &obj)).collect()];
+        let data = parquet_data_file(&columns, &rows).unwrap();
+        assert_eq!(&data[..4], b"PAR1");
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::scanner_folder::{ScannerItem, scan_data_folder};
+use crate::scanner_inventory::bucket_inventory;
 use crate::{
     DATA_USAGE_CACHE_NAME, DATA_USAGE_ROOT, DataUsageCache, DataUsageCacheInfo, DataUsageEntry, DataUsageEntryInfo,
     DataUsageInfo, SizeSummary, TierStats,
@@ -21,6 +22,7 @@ use futures::future::join_all;
 use rand::seq::SliceRandom as _;
 use rustfs_common::heal_channel::HealScanMode;
 use rustfs_common::metrics::{Metric, Metrics, emit_scan_bucket_drive_complete};
+use rustfs_common::trace_channel::{is_tracing, publish_trace};
 use rustfs_ecstore::bucket::bucket_target_sys::BucketTargetSys;
 use rustfs_ecstore::bucket::lifecycle::lifecycle::Lifecycle;
 use rustfs_ecstore::bucket::metadata_sys::{get_lifecycle_config, get_object_lock_config, get_replication_config};
@@ -29,6 +31,7 @@ use rustfs_ecstore::bucket::versioning::VersioningApi as _;
 use rustfs_ecstore::bucket::versioning_sys::BucketVersioningSys;
 use rustfs_ecstore::config::storageclass;
 use rustfs_ecstore::disk::STORAGE_FORMAT_FILE;
+use rustfs_ecstore::disk::error::DiskError;
 use rustfs_ecstore::disk::{Disk, DiskAPI};
 use rustfs_ecstore::error::{Error, StorageError};
 use rustfs_ecstore::global::GLOBAL_TierConfigMgr;
@@ -37,6 +40,7 @@ use rustfs_ecstore::set_disk::SetDisks;
 use rustfs_ecstore::store_api::{BucketInfo, BucketOptions, ObjectInfo};
 use rustfs_ecstore::{StorageAPI, error::Result, store::ECStore};
 use rustfs_filemeta::FileMeta;
+use rustfs_madmin::trace::{TraceInfo, TraceType};
 use rustfs_utils::path::{SLASH_SEPARATOR, path_join_buf};
 use s3s::dto::{BucketLifecycleConfiguration, ReplicationConfiguration};
 use std::collections::HashMap;
@@ -417,6 +421,10 @@ impl ScannerIOCache for SetDisks {
                         Err(e) => {
                             error!("Failed to scan disk: {}", e);
 
+                            if let Some(inventory) = bucket_inventory(&bucket.name) {
+                                inventory.fail(&format!("failed to scan bucket: {e}")).await;
+                            }
+
                             if let (Some(last_update), Some(before_update)) = (cache.info.last_update, before)
                                 && last_update > before_update
                                 && let Err(e) = cache.save(store_clone_clone.clone(), cache_name.as_str()).await
@@ -481,10 +489,33 @@ impl ScannerIOCache for SetDisks {
     }
 }
 
+/// Publish a scanner record to `admin trace` subscribers, if any.
+fn publish_scanner_trace(func_name: &str, path: String, duration: Duration, error: Option<String>, disk: Option<String>) {
+    if !is_tracing(TraceType::SCANNER) {
+        return;
+    }
+
+    let mut info = TraceInfo::new(TraceType::SCANNER, func_name, path);
+    info.duration = duration;
+    info.error = error;
+    info.custom = disk.map(|disk| HashMap::from([("disk".to_string(), disk)]));
+    publish_trace(info);
+}
+
+/// Marks the bucket's inventory reports incomplete when an object could not be read
+async fn fail_inventory(item: &ScannerItem, err: &dyn std::fmt::Display) {
+    if let Some(inventory) = &item.inventory {
+        inventory
+            .fail(&format!("failed to read {}/{}: {err}", item.bucket, item.object_path()))
+            .await;
+    }
+}
+
 #[async_trait::async_trait]
 impl ScannerIODisk for Disk {
     async fn get_size(&self, mut item: ScannerItem) -> Result<SizeSummary> {
         let done_object = Metrics::time(Metric::ScanObject);
+        let object_start = std::time::Instant::now();
 
         if !item.path.ends_with(&format!("{SLASH_SEPARATOR}{STORAGE_FORMAT_FILE}")) {
             return Err(StorageError::other("skip file".to_string()));
@@ -499,17 +530,28 @@ impl ScannerIODisk for Disk {
                     &item.object_path()
                 );
 
+                // Objects removed since the folder was listed are simply not part of the inventory
+                if e != DiskError::FileNotFound && e != DiskError::FileVersionNotFound {
+                    fail_inventory(&item, &e).await;
+                }
                 return Err(StorageError::other("skip file".to_string()));
             }
         };
 
         item.transform_meta_dir();
 
-        let meta = FileMeta::load(&data)?;
+        let meta = match FileMeta::load(&data) {
+            Ok(meta) => meta,
+            Err(e) => {
+                fail_inventory(&item, &e).await;
+                return Err(e.into());
+            }
+        };
         let fivs = match meta.get_file_info_versions(item.bucket.as_str(), item.object_path().as_str(), false) {
             Ok(versions) => versions,
             Err(e) => {
                 error!("Failed to get file info versions: {}", e);
+                fail_inventory(&item, &e).await;
                 return Err(StorageError::other("skip file".to_string()));
             }
         };
@@ -525,6 +567,10 @@ impl ScannerIODisk for Disk {
             .map(|v| ObjectInfo::from_file_info(v, item.bucket.as_str(), item.object_path().as_str(), versioned))
             .collect::<Vec<ObjectInfo>>();
 
+        if let Some(inventory) = &item.inventory {
+            inventory.collect(&object_infos).await;
+        }
+
         let mut size_summary = SizeSummary::default();
 
         let tiers = {
@@ -558,6 +604,13 @@ impl ScannerIODisk for Disk {
             .await;
 
         done_object();
+        publish_scanner_trace(
+            "scanner.ScanObject",
+            path_join_buf(&[&item.bucket, &item.object_path()]),
+            object_start.elapsed(),
+            None,
+            None,
+        );
 
         // TODO: enqueueFreeVersion
 
@@ -605,6 +658,8 @@ impl ScannerIODisk for Disk {
 
         // TODO: object lock
 
+        cache.info.inventory = bucket_inventory(&cache.info.name);
+
         let Some(ecstore) = new_object_layer_fn() else {
             error!("ECStore not available");
             return Err(StorageError::other("ECStore not available".to_string()));
@@ -642,11 +697,25 @@ impl ScannerIODisk for Disk {
             Ok(mut data_usage_info) => {
                 done_drive();
                 emit_scan_bucket_drive_complete(true, &bucket, &disk_path, drive_start.elapsed());
+                publish_scanner_trace(
+                    "scanner.ScanBucketDrive",
+                    bucket.clone(),
+                    drive_start.elapsed(),
+                    None,
+                    Some(disk_path.clone()),
+                );
                 data_usage_info.info.last_update = Some(SystemTime::now());
                 Ok(data_usage_info)
             }
             Err(e) => {
                 emit_scan_bucket_drive_complete(false, &bucket, &disk_path, drive_start.elapsed());
+                publish_scanner_trace(
+                    "scanner.ScanBucketDrive",
+                    bucket.clone(),
+                    drive_start.elapsed(),
+                    Some(e.to_string()),
+                    Some(disk_path.clone()),
+                );
                 Err(StorageError::other(format!("Failed to scan data folder: {e}")))
             }
         }
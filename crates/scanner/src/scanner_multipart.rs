@@ -0,0 +1,143 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rustfs_audit::AuditLogger;
+use rustfs_audit::entity::{ApiDetailsBuilder, AuditEntryBuilder};
+use rustfs_config::{DEFAULT_STALE_UPLOADS_EXPIRY_SECS, ENV_STALE_UPLOADS_EXPIRY_SECS};
+use rustfs_ecstore::bucket::lifecycle::bucket_lifecycle_audit::{LcAuditEvent, LcEventSrc};
+use rustfs_ecstore::bucket::lifecycle::bucket_lifecycle_ops::apply_abort_incomplete_multipart_upload;
+use rustfs_ecstore::bucket::lifecycle::lifecycle::{Event, IlmAction, Lifecycle};
+use rustfs_ecstore::bucket::metadata_sys::get_lifecycle_config;
+use rustfs_ecstore::store::ECStore;
+use rustfs_ecstore::store_api::MultipartInfo;
+use rustfs_targets::EventName;
+use rustfs_utils::get_env_u64;
+use serde_json::Value;
+use time::{Duration, OffsetDateTime};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+/// Aborts multipart uploads that have outlived their bucket's AbortIncompleteMultipartUpload rule.
+///
+/// Uploads are not visited by the namespace walk, so they are enumerated once per cycle and
+/// grouped by bucket before each bucket's lifecycle configuration is evaluated. Uploads that
+/// predate the recorded owner carry only their upload path and are removed once stale.
+pub async fn abort_incomplete_multipart_uploads(ctx: CancellationToken, store: Arc<ECStore>) {
+    let uploads = match store.list_all_multipart_uploads().await {
+        Ok(uploads) => uploads,
+        Err(err) => {
+            warn!("abort_incomplete_multipart_uploads: failed to list multipart uploads: {err}");
+            return;
+        }
+    };
+
+    let now = OffsetDateTime::now_utc();
+    let stale_expiry = Duration::seconds(
+        i64::try_from(get_env_u64(ENV_STALE_UPLOADS_EXPIRY_SECS, DEFAULT_STALE_UPLOADS_EXPIRY_SECS)).unwrap_or(i64::MAX),
+    );
+
+    let mut by_bucket: HashMap<String, Vec<MultipartInfo>> = HashMap::new();
+    for upload in uploads {
+        if !upload.bucket.is_empty() {
+            by_bucket.entry(upload.bucket.clone()).or_default().push(upload);
+            continue;
+        }
+        if !is_stale_upload(&upload, now, stale_expiry) {
+            continue;
+        }
+        debug!("abort_incomplete_multipart_uploads: removing stale upload {}", upload.object);
+        if let Err(err) = store.delete_multipart_upload_path(&upload.object).await {
+            warn!(
+                "abort_incomplete_multipart_uploads: failed to remove stale upload {}: {err}",
+                upload.object
+            );
+        }
+    }
+
+    for (bucket, uploads) in by_bucket {
+        if ctx.is_cancelled() {
+            return;
+        }
+
+        let Ok((lifecycle_config, _)) = get_lifecycle_config(&bucket).await else {
+            continue;
+        };
+        if !lifecycle_config.has_abort_incomplete_multipart_upload() {
+            continue;
+        }
+
+        for upload in uploads {
+            let Some(initiated) = upload.initiated else {
+                continue;
+            };
+            let event = lifecycle_config.eval_incomplete_multipart_upload(&upload.object, initiated, now);
+            if event.action != IlmAction::AbortMultipartUploadAction {
+                continue;
+            }
+
+            debug!(
+                "abort_incomplete_multipart_uploads: aborting {}/{} upload {} (rule {})",
+                upload.bucket, upload.object, upload.upload_id, event.rule_id
+            );
+            if apply_abort_incomplete_multipart_upload(store.clone(), &upload, &event).await {
+                audit_abort_multipart_upload(&upload, event).await;
+            }
+        }
+    }
+}
+
+/// Whether an upload without a recorded owner has been pending for longer than `expiry`
+fn is_stale_upload(upload: &MultipartInfo, now: OffsetDateTime, expiry: Duration) -> bool {
+    upload.initiated.is_some_and(|initiated| now - initiated >= expiry)
+}
+
+async fn audit_abort_multipart_upload(upload: &MultipartInfo, event: Event) {
+    let tags = LcAuditEvent::new(event, LcEventSrc::Scanner)
+        .tags()
+        .into_iter()
+        .map(|(k, v)| (k, Value::String(v)))
+        .collect();
+    let api = ApiDetailsBuilder::new()
+        .name("AbortMultipartUpload")
+        .bucket(&upload.bucket)
+        .object(&upload.object)
+        .build();
+    let entry = AuditEntryBuilder::new("1.0", EventName::ObjectRemovedDelete, "ilm:expiry", api)
+        .entry_type("ilm")
+        .tags(tags)
+        .build();
+    AuditLogger::log(entry).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_stale_upload() {
+        let now = OffsetDateTime::now_utc();
+        let upload = |initiated: Option<OffsetDateTime>| MultipartInfo {
+            object: "0a1b/upload".to_string(),
+            initiated,
+            ..Default::default()
+        };
+
+        assert!(is_stale_upload(&upload(Some(now - Duration::days(2))), now, Duration::days(1)));
+        assert!(!is_stale_upload(&upload(Some(now - Duration::hours(1))), now, Duration::days(1)));
+        assert!(!is_stale_upload(&upload(None), now, Duration::days(1)));
+    }
+}
@@ -0,0 +1,788 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    StoreError, Target, TargetLog,
+    arn::TargetID,
+    error::TargetError,
+    store::{Key, QueueStore, Store},
+    target::{ChannelTargetType, EntityTarget, TargetType, parse_optional_bool},
+};
+use async_trait::async_trait;
+use futures::future::join_all;
+use rdkafka::{
+    ClientConfig,
+    error::{KafkaError, RDKafkaErrorCode},
+    producer::{DeliveryFuture, FutureProducer, FutureRecord, Producer},
+};
+use rustfs_config::{
+    DEFAULT_KAFKA_ACKS, DEFAULT_KAFKA_BATCH_COMMIT_TIMEOUT, DEFAULT_KAFKA_BATCH_SIZE, DEFAULT_KAFKA_KEY_TEMPLATE, DEFAULT_LIMIT,
+    KAFKA_ACKS, KAFKA_BATCH_COMMIT_TIMEOUT, KAFKA_BATCH_SIZE, KAFKA_BROKERS, KAFKA_CLIENT_TLS_CERT, KAFKA_CLIENT_TLS_KEY,
+    KAFKA_KEY_TEMPLATE, KAFKA_QUEUE_DIR, KAFKA_QUEUE_LIMIT, KAFKA_SASL, KAFKA_SASL_MECHANISM, KAFKA_SASL_PASSWORD,
+    KAFKA_SASL_USERNAME, KAFKA_TLS, KAFKA_TLS_CA, KAFKA_TLS_SKIP_VERIFY, KAFKA_TOPIC,
+};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+use tracing::{debug, error, info, instrument, warn};
+
+/// How long a message may wait in the producer for delivery before it is reported as failed
+const DEFAULT_MESSAGE_TIMEOUT: Duration = Duration::from_secs(30);
+const METADATA_TIMEOUT: Duration = Duration::from_secs(5);
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Arguments for configuring a Kafka target
+#[derive(Debug, Clone)]
+pub struct KafkaArgs {
+    /// Whether the target is enabled
+    pub enable: bool,
+    /// The bootstrap brokers, as `host:port`
+    pub brokers: Vec<String>,
+    /// The topic to produce to
+    pub topic: String,
+    /// The message key, `{bucket}` and `{object}` are replaced per event
+    pub key_template: String,
+    /// Broker acknowledgements required for a write: `0`, `1` or `all`
+    pub acks: String,
+    /// The maximum number of messages batched in one request
+    pub batch_size: u64,
+    /// How long the producer waits to fill a batch
+    pub batch_commit_timeout: Duration,
+    /// Whether SASL authentication is enabled
+    pub sasl: bool,
+    /// The SASL username
+    pub sasl_username: String,
+    /// The SASL password
+    pub sasl_password: String,
+    /// The SASL mechanism: `plain`, `scram-sha-256` or `scram-sha-512`
+    pub sasl_mechanism: String,
+    /// Whether TLS is enabled
+    pub tls: bool,
+    /// Skip verifying the broker certificate
+    pub tls_skip_verify: bool,
+    /// The CA certificate for the brokers (PEM file)
+    pub tls_ca: String,
+    /// The client certificate for TLS (PEM file)
+    pub client_tls_cert: String,
+    /// The client key for TLS (PEM file)
+    pub client_tls_key: String,
+    /// The directory to store events in case of failure
+    pub queue_dir: String,
+    /// The maximum number of events to store
+    pub queue_limit: u64,
+    /// the target type
+    pub target_type: TargetType,
+}
+
+impl KafkaArgs {
+    /// Builds the arguments from a target configuration, where `lookup` returns the value of a key
+    pub fn from_config(
+        lookup: impl Fn(&str) -> Option<String>,
+        default_queue_dir: &str,
+        target_type: TargetType,
+    ) -> Result<Self, TargetError> {
+        let non_empty = |key: &str| lookup(key).filter(|v| !v.is_empty());
+
+        let brokers = lookup(KAFKA_BROKERS)
+            .ok_or_else(|| TargetError::Configuration("Missing Kafka brokers".to_string()))?
+            .split(',')
+            .map(|b| b.trim().to_string())
+            .filter(|b| !b.is_empty())
+            .collect::<Vec<_>>();
+
+        let topic = lookup(KAFKA_TOPIC).ok_or_else(|| TargetError::Configuration("Missing Kafka topic".to_string()))?;
+
+        let batch_size = match non_empty(KAFKA_BATCH_SIZE) {
+            Some(v) => v
+                .parse::<u64>()
+                .map_err(|_| TargetError::Configuration(format!("Invalid Kafka batch size: {v}")))?,
+            None => DEFAULT_KAFKA_BATCH_SIZE,
+        };
+
+        let batch_commit_timeout =
+            non_empty(KAFKA_BATCH_COMMIT_TIMEOUT).unwrap_or_else(|| DEFAULT_KAFKA_BATCH_COMMIT_TIMEOUT.to_string());
+        let batch_commit_timeout = humantime::parse_duration(&batch_commit_timeout)
+            .map_err(|e| TargetError::Configuration(format!("Invalid Kafka batch commit timeout: {e}")))?;
+
+        Ok(KafkaArgs {
+            enable: true, // Assumed enabled.
+            brokers,
+            topic,
+            key_template: non_empty(KAFKA_KEY_TEMPLATE).unwrap_or_else(|| DEFAULT_KAFKA_KEY_TEMPLATE.to_string()),
+            acks: non_empty(KAFKA_ACKS).unwrap_or_else(|| DEFAULT_KAFKA_ACKS.to_string()),
+            batch_size,
+            batch_commit_timeout,
+            sasl: parse_optional_bool(lookup(KAFKA_SASL))?,
+            sasl_username: lookup(KAFKA_SASL_USERNAME).unwrap_or_default(),
+            sasl_password: lookup(KAFKA_SASL_PASSWORD).unwrap_or_default(),
+            sasl_mechanism: lookup(KAFKA_SASL_MECHANISM).unwrap_or_default(),
+            tls: parse_optional_bool(lookup(KAFKA_TLS))?,
+            tls_skip_verify: parse_optional_bool(lookup(KAFKA_TLS_SKIP_VERIFY))?,
+            tls_ca: lookup(KAFKA_TLS_CA).unwrap_or_default(),
+            client_tls_cert: lookup(KAFKA_CLIENT_TLS_CERT).unwrap_or_default(),
+            client_tls_key: lookup(KAFKA_CLIENT_TLS_KEY).unwrap_or_default(),
+            queue_dir: lookup(KAFKA_QUEUE_DIR).unwrap_or_else(|| default_queue_dir.to_string()),
+            queue_limit: lookup(KAFKA_QUEUE_LIMIT)
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(DEFAULT_LIMIT),
+            target_type,
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), TargetError> {
+        if !self.enable {
+            return Ok(());
+        }
+
+        if self.brokers.is_empty() || self.brokers.iter().any(|b| b.trim().is_empty()) {
+            return Err(TargetError::Configuration("no Kafka broker address found".to_string()));
+        }
+
+        if self.topic.is_empty() {
+            return Err(TargetError::Configuration("Kafka topic cannot be empty".to_string()));
+        }
+
+        if !matches!(self.acks.as_str(), "0" | "1" | "all" | "-1") {
+            return Err(TargetError::Configuration(format!(
+                "invalid Kafka acks '{}', expected 0, 1 or all",
+                self.acks
+            )));
+        }
+
+        if self.batch_size == 0 {
+            return Err(TargetError::Configuration("Kafka batch size must be greater than 0".to_string()));
+        }
+
+        if self.sasl {
+            if self.sasl_username.is_empty() {
+                return Err(TargetError::Configuration("Kafka SASL username cannot be empty".to_string()));
+            }
+
+            sasl_mechanism(&self.sasl_mechanism)?;
+        }
+
+        if self.client_tls_cert.is_empty() != self.client_tls_key.is_empty() {
+            return Err(TargetError::Configuration("cert and key must be specified as a pair".to_string()));
+        }
+
+        if !self.queue_dir.is_empty() {
+            let path = std::path::Path::new(&self.queue_dir);
+            if !path.is_absolute() {
+                return Err(TargetError::Configuration("kafka queueDir path should be absolute".to_string()));
+            }
+
+            if self.acks == "0" {
+                return Err(TargetError::Configuration("acks should be 1 or all if queueDir is set".to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Producer configuration for librdkafka
+    fn client_config(&self) -> Result<ClientConfig, TargetError> {
+        let mut config = ClientConfig::new();
+        config
+            .set("bootstrap.servers", self.brokers.join(","))
+            .set("client.id", "rustfs")
+            .set("acks", &self.acks)
+            .set("batch.num.messages", self.batch_size.to_string())
+            .set("linger.ms", self.batch_commit_timeout.as_millis().to_string())
+            .set("message.timeout.ms", DEFAULT_MESSAGE_TIMEOUT.as_millis().to_string());
+
+        let protocol = match (self.tls, self.sasl) {
+            (false, false) => "plaintext",
+            (true, false) => "ssl",
+            (false, true) => "sasl_plaintext",
+            (true, true) => "sasl_ssl",
+        };
+        config.set("security.protocol", protocol);
+
+        if self.sasl {
+            config
+                .set("sasl.mechanisms", sasl_mechanism(&self.sasl_mechanism)?)
+                .set("sasl.username", &self.sasl_username)
+                .set("sasl.password", &self.sasl_password);
+        }
+
+        if self.tls {
+            if self.tls_skip_verify {
+                config
+                    .set("enable.ssl.certificate.verification", "false")
+                    .set("ssl.endpoint.identification.algorithm", "none");
+            }
+            if !self.tls_ca.is_empty() {
+                config.set("ssl.ca.location", &self.tls_ca);
+            }
+            if !self.client_tls_cert.is_empty() {
+                config
+                    .set("ssl.certificate.location", &self.client_tls_cert)
+                    .set("ssl.key.location", &self.client_tls_key);
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Map a configured SASL mechanism to its librdkafka name
+fn sasl_mechanism(mechanism: &str) -> Result<&'static str, TargetError> {
+    match mechanism.to_lowercase().as_str() {
+        "" | "plain" => Ok("PLAIN"),
+        "scram-sha-256" | "sha256" => Ok("SCRAM-SHA-256"),
+        "scram-sha-512" | "sha512" => Ok("SCRAM-SHA-512"),
+        _ => Err(TargetError::Configuration(format!("unsupported Kafka SASL mechanism '{mechanism}'"))),
+    }
+}
+
+/// Render the message key for an object
+fn render_key(template: &str, bucket: &str, object: &str) -> String {
+    template.replace("{bucket}", bucket).replace("{object}", object)
+}
+
+/// Whether a producer error means the brokers cannot be reached, so the event should stay queued
+fn is_connection_error(err: &KafkaError) -> bool {
+    matches!(
+        err.rdkafka_error_code(),
+        Some(
+            RDKafkaErrorCode::MessageTimedOut
+                | RDKafkaErrorCode::AllBrokersDown
+                | RDKafkaErrorCode::BrokerTransportFailure
+                | RDKafkaErrorCode::BrokerNotAvailable
+                | RDKafkaErrorCode::NetworkException
+                | RDKafkaErrorCode::RequestTimedOut
+                | RDKafkaErrorCode::OperationTimedOut
+                | RDKafkaErrorCode::QueueFull
+        )
+    )
+}
+
+/// A target that produces events to a Kafka topic
+pub struct KafkaTarget<E>
+where
+    E: Send + Sync + 'static + Clone + Serialize + DeserializeOwned,
+{
+    id: TargetID,
+    args: KafkaArgs,
+    producer: FutureProducer,
+    store: Option<Box<dyn Store<EntityTarget<E>, Error = StoreError, Key = Key> + Send + Sync>>,
+    connected: Arc<AtomicBool>,
+}
+
+impl<E> KafkaTarget<E>
+where
+    E: Send + Sync + 'static + Clone + Serialize + DeserializeOwned,
+{
+    /// Creates a new KafkaTarget
+    #[instrument(skip(args), fields(target_id_as_string = %id))]
+    pub fn new(id: String, args: KafkaArgs) -> Result<Self, TargetError> {
+        args.validate()?;
+        let target_id = TargetID::new(id, ChannelTargetType::Kafka.as_str().to_string());
+
+        // Creating the producer does not connect, librdkafka connects to the brokers in the background
+        let producer: FutureProducer = args
+            .client_config()?
+            .create()
+            .map_err(|e| TargetError::Configuration(format!("Failed to create Kafka producer: {e}")))?;
+
+        let queue_store = if !args.queue_dir.is_empty() {
+            let queue_dir = PathBuf::from(&args.queue_dir)
+                .join(format!("rustfs-{}-{}", ChannelTargetType::Kafka.as_str(), target_id.id).replace(":", "_"));
+            debug!(target_id = %target_id, path = %queue_dir.display(), "Initializing queue store for Kafka target");
+            let extension = match args.target_type {
+                TargetType::AuditLog => rustfs_config::audit::AUDIT_STORE_EXTENSION,
+                TargetType::NotifyEvent => rustfs_config::notify::NOTIFY_STORE_EXTENSION,
+            };
+
+            let store = QueueStore::<EntityTarget<E>>::new(queue_dir, args.queue_limit, extension);
+            if let Err(e) = store.open() {
+                error!(target_id = %target_id, error = %e, "Failed to open store for Kafka target");
+                return Err(TargetError::Storage(format!("{e}")));
+            }
+            Some(Box::new(store) as Box<dyn Store<EntityTarget<E>, Error = StoreError, Key = Key> + Send + Sync>)
+        } else {
+            None
+        };
+
+        info!(target_id = %target_id, "Kafka target created");
+        Ok(KafkaTarget {
+            id: target_id,
+            args,
+            producer,
+            store: queue_store,
+            connected: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    #[instrument(skip(self), fields(target_id = %self.id))]
+    async fn init(&self) -> Result<(), TargetError> {
+        if self.connected.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        self.is_active().await?;
+        info!(target_id = %self.id, "Kafka target initialized");
+        Ok(())
+    }
+
+    /// Hand an event to the producer without waiting for its delivery
+    fn enqueue(&self, event: &EntityTarget<E>) -> Result<DeliveryFuture, TargetError> {
+        // Decode form-urlencoded object name
+        let object_name = crate::target::decode_object_name(&event.object_name)?;
+
+        let log = TargetLog {
+            event_name: event.event_name,
+            key: format!("{}/{}", event.bucket_name, object_name),
+            records: vec![event.data.clone()],
+        };
+
+        let data = serde_json::to_vec(&log).map_err(|e| TargetError::Serialization(format!("Failed to serialize event: {e}")))?;
+        let key = render_key(&self.args.key_template, &event.bucket_name, &object_name);
+
+        let record = FutureRecord::to(&self.args.topic).key(&key).payload(&data);
+        self.producer.send_result(record).map_err(|(e, _)| self.produce_error(e))
+    }
+
+    fn produce_error(&self, e: KafkaError) -> TargetError {
+        if is_connection_error(&e) {
+            self.connected.store(false, Ordering::SeqCst);
+            warn!(target_id = %self.id, error = %e, "Produce failed due to connection issue, marking as not connected.");
+            return TargetError::NotConnected;
+        }
+        TargetError::Request(format!("Failed to produce message: {e}"))
+    }
+
+    /// Produce events, queueing all of them before awaiting the deliveries so the producer can batch them.
+    /// Returns one result per event, in order.
+    #[instrument(skip(self, events), fields(target_id = %self.id))]
+    async fn send(&self, events: &[EntityTarget<E>]) -> Vec<Result<(), TargetError>> {
+        let deliveries = events.iter().map(|event| self.enqueue(event)).collect::<Vec<_>>();
+
+        let results = join_all(deliveries.into_iter().map(|delivery| async move {
+            match delivery?.await {
+                Ok(Ok(_)) => Ok(()),
+                Ok(Err((e, _))) => Err(self.produce_error(e)),
+                Err(_) => Err(TargetError::Request("Kafka producer dropped the message".to_string())),
+            }
+        }))
+        .await;
+
+        debug!(target_id = %self.id, topic = %self.args.topic, count = events.len(), "Events produced to Kafka topic");
+        results
+    }
+
+    fn delete_sent(
+        &self,
+        store: &(dyn Store<EntityTarget<E>, Error = StoreError, Key = Key> + Send + Sync),
+        key: &Key,
+    ) -> Result<(), TargetError> {
+        match store.del(key) {
+            Ok(_) | Err(StoreError::NotFound) => {
+                debug!(target_id = %self.id, ?key, "Event sent from store and deleted.");
+                Ok(())
+            }
+            Err(e) => {
+                error!(target_id = %self.id, error = %e, "Failed to delete event from store after send.");
+                Err(TargetError::Storage(format!("Failed to delete event from store: {e}")))
+            }
+        }
+    }
+
+    pub fn clone_target(&self) -> Box<dyn Target<E> + Send + Sync> {
+        Box::new(KafkaTarget {
+            id: self.id.clone(),
+            args: self.args.clone(),
+            producer: self.producer.clone(),
+            store: self.store.as_ref().map(|s| s.boxed_clone()),
+            connected: self.connected.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl<E> Target<E> for KafkaTarget<E>
+where
+    E: Send + Sync + 'static + Clone + Serialize + DeserializeOwned,
+{
+    fn id(&self) -> TargetID {
+        self.id.clone()
+    }
+
+    #[instrument(skip(self), fields(target_id = %self.id))]
+    async fn is_active(&self) -> Result<bool, TargetError> {
+        // Metadata requests block until the brokers answer or the timeout passes
+        let producer = self.producer.clone();
+        let topic = self.args.topic.clone();
+        let metadata = tokio::task::spawn_blocking(move || producer.client().fetch_metadata(Some(&topic), METADATA_TIMEOUT))
+            .await
+            .map_err(|e| TargetError::Unknown(format!("Kafka metadata task failed: {e}")))?;
+
+        match metadata {
+            Ok(_) => {
+                self.connected.store(true, Ordering::SeqCst);
+                debug!(target_id = %self.id, "Kafka brokers are reachable.");
+                Ok(true)
+            }
+            Err(e) => {
+                self.connected.store(false, Ordering::SeqCst);
+                debug!(target_id = %self.id, error = %e, "Kafka brokers are not reachable.");
+                Err(TargetError::NotConnected)
+            }
+        }
+    }
+
+    #[instrument(skip(self, event), fields(target_id = %self.id))]
+    async fn save(&self, event: Arc<EntityTarget<E>>) -> Result<(), TargetError> {
+        if let Some(store) = &self.store {
+            // The event is replayed from the store, so it survives brokers being down
+            store
+                .put(event)
+                .map_err(|e| TargetError::Storage(format!("Failed to save event to store: {e}")))?;
+            debug!(target_id = %self.id, "Event saved to store for Kafka target.");
+            return Ok(());
+        }
+
+        if !self.is_enabled() {
+            return Err(TargetError::Disabled);
+        }
+
+        if let Err(e) = KafkaTarget::init(self).await {
+            error!(target_id = %self.id, error = %e, "Failed to initialize Kafka target.");
+            return Err(TargetError::NotConnected);
+        }
+        self.send(std::slice::from_ref(&*event)).await.pop().unwrap_or(Ok(()))
+    }
+
+    #[instrument(skip(self), fields(target_id = %self.id))]
+    async fn send_from_store(&self, key: Key) -> Result<(), TargetError> {
+        if !self.is_enabled() {
+            return Err(TargetError::Disabled);
+        }
+
+        if let Err(e) = KafkaTarget::init(self).await {
+            warn!(target_id = %self.id, error = %e, "Kafka target not connected; event remains in store.");
+            return Err(TargetError::NotConnected);
+        }
+
+        let store = self
+            .store
+            .as_ref()
+            .ok_or_else(|| TargetError::Configuration("No store configured".to_string()))?;
+
+        let event = match store.get(&key) {
+            Ok(event) => event,
+            Err(StoreError::NotFound) => return Ok(()),
+            Err(e) => {
+                return Err(TargetError::Storage(format!("Failed to get event from store: {e}")));
+            }
+        };
+
+        // Send up to a batch of other queued events along with this one; they are gone
+        // from the store by the time the stream reaches their keys
+        let mut keys = vec![key];
+        let mut events = vec![event];
+        for other in store.list() {
+            if events.len() as u64 >= self.args.batch_size {
+                break;
+            }
+            if other.name == keys[0].name {
+                continue;
+            }
+            if let Ok(event) = store.get(&other) {
+                keys.push(other);
+                events.push(event);
+            }
+        }
+
+        let results = keys
+            .iter()
+            .zip(self.send(&events).await)
+            .map(|(key, result)| result.and_then(|_| self.delete_sent(store, key)))
+            .collect::<Vec<_>>();
+        // Only this key's outcome is reported; the others stay queued on failure and are retried under their own keys
+        results.into_iter().next().unwrap_or(Ok(()))
+    }
+
+    async fn close(&self) -> Result<(), TargetError> {
+        // Deliver whatever is still batched in the producer
+        let producer = self.producer.clone();
+        match tokio::task::spawn_blocking(move || producer.flush(FLUSH_TIMEOUT)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => warn!(target_id = %self.id, error = %e, "Failed to flush Kafka producer on close."),
+            Err(e) => warn!(target_id = %self.id, error = %e, "Kafka flush task failed on close."),
+        }
+
+        self.connected.store(false, Ordering::SeqCst);
+        info!(target_id = %self.id, "Kafka target closed");
+        Ok(())
+    }
+
+    fn store(&self) -> Option<&(dyn Store<EntityTarget<E>, Error = StoreError, Key = Key> + Send + Sync)> {
+        self.store.as_deref()
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Target<E> + Send + Sync> {
+        self.clone_target()
+    }
+
+    async fn init(&self) -> Result<(), TargetError> {
+        if !self.is_enabled() {
+            debug!(target_id = %self.id, "Target is disabled, skipping init.");
+            return Ok(());
+        }
+        KafkaTarget::init(self).await
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.args.enable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EventName;
+    use rdkafka::Message;
+    use rdkafka::consumer::{BaseConsumer, Consumer};
+    use std::collections::HashMap;
+
+    fn args() -> KafkaArgs {
+        KafkaArgs {
+            enable: true,
+            brokers: vec!["localhost:9092".to_string()],
+            topic: "rustfs-events".to_string(),
+            key_template: rustfs_config::DEFAULT_KAFKA_KEY_TEMPLATE.to_string(),
+            acks: "all".to_string(),
+            batch_size: 100,
+            batch_commit_timeout: Duration::from_millis(10),
+            sasl: false,
+            sasl_username: String::new(),
+            sasl_password: String::new(),
+            sasl_mechanism: String::new(),
+            tls: false,
+            tls_skip_verify: false,
+            tls_ca: String::new(),
+            client_tls_cert: String::new(),
+            client_tls_key: String::new(),
+            queue_dir: String::new(),
+            queue_limit: 1000,
+            target_type: TargetType::NotifyEvent,
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(args().validate().is_ok());
+        assert!(
+            KafkaArgs {
+                brokers: vec![],
+                ..args()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            KafkaArgs {
+                topic: String::new(),
+                ..args()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            KafkaArgs {
+                acks: "2".to_string(),
+                ..args()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(KafkaArgs { batch_size: 0, ..args() }.validate().is_err());
+        assert!(KafkaArgs { sasl: true, ..args() }.validate().is_err());
+        assert!(
+            KafkaArgs {
+                sasl: true,
+                sasl_username: "user".to_string(),
+                sasl_mechanism: "gssapi".to_string(),
+                ..args()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            KafkaArgs {
+                client_tls_cert: "/etc/rustfs/client.crt".to_string(),
+                ..args()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            KafkaArgs {
+                queue_dir: "relative".to_string(),
+                ..args()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            KafkaArgs {
+                queue_dir: "/tmp/rustfs".to_string(),
+                acks: "0".to_string(),
+                ..args()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            KafkaArgs {
+                enable: false,
+                brokers: vec![],
+                ..args()
+            }
+            .validate()
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_client_config() {
+        let config = KafkaArgs {
+            brokers: vec!["kafka-1:9093".to_string(), "kafka-2:9093".to_string()],
+            acks: "1".to_string(),
+            sasl: true,
+            sasl_username: "user".to_string(),
+            sasl_password: "secret".to_string(),
+            sasl_mechanism: "SCRAM-SHA-512".to_string(),
+            tls: true,
+            tls_skip_verify: true,
+            ..args()
+        }
+        .client_config()
+        .unwrap();
+
+        assert_eq!(config.get("bootstrap.servers"), Some("kafka-1:9093,kafka-2:9093"));
+        assert_eq!(config.get("acks"), Some("1"));
+        assert_eq!(config.get("batch.num.messages"), Some("100"));
+        assert_eq!(config.get("linger.ms"), Some("10"));
+        assert_eq!(config.get("security.protocol"), Some("sasl_ssl"));
+        assert_eq!(config.get("sasl.mechanisms"), Some("SCRAM-SHA-512"));
+        assert_eq!(config.get("enable.ssl.certificate.verification"), Some("false"));
+
+        let config = args().client_config().unwrap();
+        assert_eq!(config.get("security.protocol"), Some("plaintext"));
+        assert_eq!(config.get("sasl.username"), None);
+    }
+
+    #[test]
+    fn test_from_config() {
+        let config = HashMap::from([
+            (KAFKA_BROKERS, "kafka-1:9092, kafka-2:9092"),
+            (KAFKA_TOPIC, "rustfs-events"),
+            (KAFKA_BATCH_COMMIT_TIMEOUT, "50ms"),
+            (KAFKA_TLS, "on"),
+        ]);
+        let args =
+            KafkaArgs::from_config(|key| config.get(key).map(|v| v.to_string()), "/var/rustfs", TargetType::AuditLog).unwrap();
+        assert_eq!(args.brokers, vec!["kafka-1:9092", "kafka-2:9092"]);
+        assert_eq!(args.batch_size, DEFAULT_KAFKA_BATCH_SIZE);
+        assert_eq!(args.batch_commit_timeout, Duration::from_millis(50));
+        assert_eq!(args.acks, DEFAULT_KAFKA_ACKS);
+        assert!(args.tls);
+        assert!(!args.sasl);
+        assert_eq!(args.queue_dir, "/var/rustfs");
+
+        let missing_topic = HashMap::from([(KAFKA_BROKERS, "kafka-1:9092")]);
+        assert!(
+            KafkaArgs::from_config(|key| missing_topic.get(key).map(|v| v.to_string()), "", TargetType::NotifyEvent).is_err()
+        );
+
+        let bad_bool = HashMap::from([(KAFKA_BROKERS, "kafka-1:9092"), (KAFKA_TOPIC, "t"), (KAFKA_SASL, "maybe")]);
+        assert!(KafkaArgs::from_config(|key| bad_bool.get(key).map(|v| v.to_string()), "", TargetType::NotifyEvent).is_err());
+    }
+
+    /// Runs against a Kafka-compatible broker with topic auto-creation enabled, such as
+    /// `docker run -p 9092:9092 redpandadata/redpanda redpanda start --mode dev-container`.
+    /// The broker is read from RUSTFS_KAFKA_TEST_BROKERS and defaults to localhost:9092.
+    #[tokio::test]
+    #[ignore] // Requires a running Kafka broker
+    async fn test_kafka_target_integration() {
+        let brokers = std::env::var("RUSTFS_KAFKA_TEST_BROKERS").unwrap_or_else(|_| "localhost:9092".to_string());
+        let bucket = format!("bucket-{}", uuid::Uuid::new_v4());
+        let queue_dir = std::env::temp_dir().join(format!("rustfs-kafka-test-{}", uuid::Uuid::new_v4()));
+
+        let target = KafkaTarget::<serde_json::Value>::new(
+            "integration".to_string(),
+            KafkaArgs {
+                brokers: vec![brokers.clone()],
+                queue_dir: queue_dir.display().to_string(),
+                ..args()
+            },
+        )
+        .expect("Failed to create Kafka target");
+        Target::init(&target).await.expect("Kafka broker is not reachable");
+
+        // Queued events are delivered together from the store
+        for i in 0..3 {
+            let event = EntityTarget {
+                object_name: format!("object-{i}"),
+                bucket_name: bucket.clone(),
+                event_name: EventName::ObjectCreatedPut,
+                data: serde_json::json!({ "index": i }),
+            };
+            target.save(Arc::new(event)).await.expect("Failed to queue event");
+        }
+        let store = target.store().expect("Kafka target has a store");
+        let keys = store.list();
+        assert_eq!(keys.len(), 3);
+        target.send_from_store(keys[0].clone()).await.expect("Failed to send batch");
+        assert!(store.is_empty());
+
+        let consumer: BaseConsumer = ClientConfig::new()
+            .set("bootstrap.servers", &brokers)
+            .set("group.id", format!("rustfs-test-{}", uuid::Uuid::new_v4()))
+            .set("auto.offset.reset", "earliest")
+            .create()
+            .expect("Failed to create consumer");
+        consumer.subscribe(&[&args().topic]).expect("Failed to subscribe");
+
+        let mut received = 0;
+        let deadline = std::time::Instant::now() + Duration::from_secs(30);
+        while received < 3 && std::time::Instant::now() < deadline {
+            if let Some(Ok(message)) = consumer.poll(Duration::from_millis(500))
+                && message.key().is_some_and(|key| key.starts_with(bucket.as_bytes()))
+            {
+                received += 1;
+            }
+        }
+
+        target.close().await.expect("Failed to close target");
+        let _ = std::fs::remove_dir_all(&queue_dir);
+        assert_eq!(received, 3);
+    }
+
+    #[test]
+    fn test_render_key() {
+        assert_eq!(render_key("{bucket}/{object}", "photos", "2024/a b.jpg"), "photos/2024/a b.jpg");
+        assert_eq!(render_key("{bucket}", "photos", "a.jpg"), "photos");
+        assert_eq!(render_key("static", "photos", "a.jpg"), "static");
+    }
+}
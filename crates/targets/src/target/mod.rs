@@ -0,0 +1,203 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::arn::TargetID;
+use crate::store::{Key, Store};
+use crate::{EventName, StoreError, TargetError};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+#[cfg(feature = "kafka")]
+pub mod kafka;
+pub mod mqtt;
+pub mod nats;
+pub mod webhook;
+
+/// Trait for notification targets
+#[async_trait]
+pub trait Target<E>: Send + Sync + 'static
+where
+    E: Send + Sync + 'static + Clone + Serialize + DeserializeOwned,
+{
+    /// Returns the ID of the target
+    fn id(&self) -> TargetID;
+
+    /// Returns the name of the target
+    fn name(&self) -> String {
+        self.id().to_string()
+    }
+
+    /// Checks if the target is active and reachable
+    async fn is_active(&self) -> Result<bool, TargetError>;
+
+    /// Saves an event (either sends it immediately or stores it for later)
+    async fn save(&self, event: Arc<EntityTarget<E>>) -> Result<(), TargetError>;
+
+    /// Sends an event from the store
+    async fn send_from_store(&self, key: Key) -> Result<(), TargetError>;
+
+    /// Closes the target and releases resources
+    async fn close(&self) -> Result<(), TargetError>;
+
+    /// Returns the store associated with the target (if any)
+    fn store(&self) -> Option<&(dyn Store<EntityTarget<E>, Error = StoreError, Key = Key> + Send + Sync)>;
+
+    /// Returns the type of the target
+    fn clone_dyn(&self) -> Box<dyn Target<E> + Send + Sync>;
+
+    /// Initialize the target, such as establishing a connection, etc.
+    async fn init(&self) -> Result<(), TargetError> {
+        // The default implementation is empty
+        Ok(())
+    }
+
+    /// Check if the target is enabled
+    fn is_enabled(&self) -> bool;
+}
+
+#[derive(Debug, Serialize, Clone, Deserialize)]
+pub struct EntityTarget<E>
+where
+    E: Send + Sync + 'static + Clone + Serialize,
+{
+    pub object_name: String,
+    pub bucket_name: String,
+    pub event_name: EventName,
+    pub data: E,
+}
+
+/// The `ChannelTargetType` enum represents the different types of channel Target
+/// used in the notification system.
+///
+/// It includes:
+/// - `Webhook`: Represents a webhook target for sending notifications via HTTP requests.
+/// - `Kafka`: Represents a Kafka target for sending notifications to a Kafka topic.
+/// - `Mqtt`: Represents an MQTT target for sending notifications via MQTT protocol.
+/// - `Nats`: Represents a NATS target for publishing notifications to a NATS subject.
+///
+/// Each variant has an associated string representation that can be used for serialization
+/// or logging purposes.
+/// The `as_str` method returns the string representation of the target type,
+/// and the `Display` implementation allows for easy formatting of the target type as a string.
+///
+/// example usage:
+/// ```rust
+/// use rustfs_targets::target::ChannelTargetType;
+///
+/// let target_type = ChannelTargetType::Webhook;
+/// assert_eq!(target_type.as_str(), "webhook");
+/// println!("Target type: {}", target_type);
+/// ```
+///
+/// example output:
+/// Target type: webhook
+pub enum ChannelTargetType {
+    Webhook,
+    Kafka,
+    Mqtt,
+    Nats,
+}
+
+impl ChannelTargetType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChannelTargetType::Webhook => "webhook",
+            ChannelTargetType::Kafka => "kafka",
+            ChannelTargetType::Mqtt => "mqtt",
+            ChannelTargetType::Nats => "nats",
+        }
+    }
+}
+
+impl std::fmt::Display for ChannelTargetType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChannelTargetType::Webhook => write!(f, "webhook"),
+            ChannelTargetType::Kafka => write!(f, "kafka"),
+            ChannelTargetType::Mqtt => write!(f, "mqtt"),
+            ChannelTargetType::Nats => write!(f, "nats"),
+        }
+    }
+}
+
+pub fn parse_bool(value: &str) -> Result<bool, TargetError> {
+    match value.to_lowercase().as_str() {
+        "true" | "on" | "yes" | "1" => Ok(true),
+        "false" | "off" | "no" | "0" => Ok(false),
+        _ => Err(TargetError::ParseError(format!("Unable to parse boolean: {value}"))),
+    }
+}
+
+/// Parses an optional boolean setting, treating a missing or empty value as `false`
+pub fn parse_optional_bool(value: Option<String>) -> Result<bool, TargetError> {
+    match value {
+        Some(v) if !v.is_empty() => parse_bool(&v),
+        _ => Ok(false),
+    }
+}
+
+/// `TargetType` enum represents the type of target in the notification system.
+#[derive(Debug, Clone)]
+pub enum TargetType {
+    AuditLog,
+    NotifyEvent,
+}
+
+impl TargetType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TargetType::AuditLog => "audit_log",
+            TargetType::NotifyEvent => "notify_event",
+        }
+    }
+}
+
+impl std::fmt::Display for TargetType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TargetType::AuditLog => write!(f, "audit_log"),
+            TargetType::NotifyEvent => write!(f, "notify_event"),
+        }
+    }
+}
+
+/// Decodes a form-urlencoded object name to its original form.
+///
+/// This function properly handles form-urlencoded strings where spaces are
+/// represented as `+` symbols. It first replaces `+` with spaces, then
+/// performs standard percent-decoding.
+///
+/// # Arguments
+/// * `encoded` - The form-urlencoded string to decode
+///
+/// # Returns
+/// The decoded string, or an error if decoding fails
+///
+/// # Example
+/// ```
+/// use rustfs_targets::target::decode_object_name;
+///
+/// let encoded = "greeting+file+%282%29.csv";
+/// let decoded = decode_object_name(encoded).unwrap();
+/// assert_eq!(decoded, "greeting file (2).csv");
+/// ```
+pub fn decode_object_name(encoded: &str) -> Result<String, TargetError> {
+    let replaced = encoded.replace("+", " ");
+    urlencoding::decode(&replaced)
+        .map(|s| s.into_owned())
+        .map_err(|e| TargetError::Encoding(format!("Failed to decode object key: {e}")))
+}
@@ -0,0 +1,558 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    StoreError, Target, TargetLog,
+    arn::TargetID,
+    error::TargetError,
+    store::{Key, QueueStore, Store},
+    target::{ChannelTargetType, EntityTarget, TargetType},
+};
+use async_nats::{Client, ConnectOptions, ServerAddr, connection::State};
+use async_trait::async_trait;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::{path::PathBuf, sync::Arc, time::Duration};
+use tokio::sync::Mutex;
+use tracing::{debug, error, info, instrument, warn};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long a publish (and its JetStream acknowledgement) may take before the event is retried
+const PUBLISH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Arguments for configuring a NATS target
+#[derive(Debug, Clone)]
+pub struct NATSArgs {
+    /// Whether the target is enabled
+    pub enable: bool,
+    /// The NATS servers, as `host:port` or `nats://host:port`
+    pub address: Vec<String>,
+    /// The subject to publish to, `{bucket}` is replaced per event with the bucket name,
+    /// dots in the name becoming underscores so the bucket stays a single subject token
+    pub subject: String,
+    /// The username for user/password authentication
+    pub username: String,
+    /// The password for user/password authentication
+    pub password: String,
+    /// The token for token authentication
+    pub token: String,
+    /// The NKey seed for NKey authentication
+    pub nkey_seed: String,
+    /// The credentials file (JWT and NKey seed) for decentralized authentication
+    pub user_credentials: String,
+    /// Whether TLS is required
+    pub tls: bool,
+    /// The CA certificate for the servers (PEM file)
+    pub tls_ca: String,
+    /// The client certificate for TLS (PEM file)
+    pub client_tls_cert: String,
+    /// The client key for TLS (PEM file)
+    pub client_tls_key: String,
+    /// Publish through JetStream and wait for the stream to acknowledge each event
+    pub jetstream: bool,
+    /// The directory to store events in case of failure
+    pub queue_dir: String,
+    /// The maximum number of events to store
+    pub queue_limit: u64,
+    /// the target type
+    pub target_type: TargetType,
+}
+
+impl NATSArgs {
+    pub fn validate(&self) -> Result<(), TargetError> {
+        if !self.enable {
+            return Ok(());
+        }
+
+        if self.address.is_empty() {
+            return Err(TargetError::Configuration("no NATS server address found".to_string()));
+        }
+        for address in &self.address {
+            address
+                .parse::<ServerAddr>()
+                .map_err(|e| TargetError::Configuration(format!("invalid NATS server address '{address}': {e}")))?;
+        }
+
+        validate_subject(&self.subject)?;
+
+        let auth_methods = [
+            !self.username.is_empty() || !self.password.is_empty(),
+            !self.token.is_empty(),
+            !self.nkey_seed.is_empty(),
+            !self.user_credentials.is_empty(),
+        ];
+        if auth_methods.iter().filter(|set| **set).count() > 1 {
+            return Err(TargetError::Configuration(
+                "only one of username/password, token, nkey_seed or user_credentials may be set".to_string(),
+            ));
+        }
+
+        if self.username.is_empty() != self.password.is_empty() {
+            return Err(TargetError::Configuration(
+                "username and password must be specified as a pair".to_string(),
+            ));
+        }
+
+        if self.client_tls_cert.is_empty() != self.client_tls_key.is_empty() {
+            return Err(TargetError::Configuration("cert and key must be specified as a pair".to_string()));
+        }
+
+        if !self.queue_dir.is_empty() {
+            let path = std::path::Path::new(&self.queue_dir);
+            if !path.is_absolute() {
+                return Err(TargetError::Configuration("nats queueDir path should be absolute".to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn connect_options(&self) -> Result<ConnectOptions, TargetError> {
+        let mut options = if !self.user_credentials.is_empty() {
+            ConnectOptions::with_credentials_file(&self.user_credentials)
+                .await
+                .map_err(|e| TargetError::Configuration(format!("Failed to load NATS credentials: {e}")))?
+        } else if !self.nkey_seed.is_empty() {
+            ConnectOptions::with_nkey(self.nkey_seed.clone())
+        } else if !self.token.is_empty() {
+            ConnectOptions::with_token(self.token.clone())
+        } else if !self.username.is_empty() {
+            ConnectOptions::with_user_and_password(self.username.clone(), self.password.clone())
+        } else {
+            ConnectOptions::new()
+        };
+
+        options = options.name("rustfs").connection_timeout(CONNECT_TIMEOUT);
+
+        if self.tls {
+            options = options.require_tls(true);
+        }
+        if !self.tls_ca.is_empty() {
+            options = options.add_root_certificates(PathBuf::from(&self.tls_ca));
+        }
+        if !self.client_tls_cert.is_empty() {
+            options = options.add_client_certificate(PathBuf::from(&self.client_tls_cert), PathBuf::from(&self.client_tls_key));
+        }
+
+        Ok(options)
+    }
+}
+
+/// A subject template must leave literal tokens only, wildcards are for subscribers
+fn validate_subject(subject: &str) -> Result<(), TargetError> {
+    if subject.is_empty() {
+        return Err(TargetError::Configuration("NATS subject cannot be empty".to_string()));
+    }
+
+    let rendered = render_subject(subject, "bucket");
+    if rendered.chars().any(char::is_whitespace) || rendered.split('.').any(|t| t.is_empty() || t == "*" || t == ">") {
+        return Err(TargetError::Configuration(format!("invalid NATS subject '{subject}'")));
+    }
+
+    Ok(())
+}
+
+/// Render the subject for a bucket. Bucket names cannot contain underscores, so mapping dots
+/// to them keeps `my.bucket` a single token without colliding with another bucket's subject.
+fn render_subject(template: &str, bucket: &str) -> String {
+    template.replace("{bucket}", &bucket.replace('.', "_"))
+}
+
+/// A target that publishes events to a NATS subject
+pub struct NATSTarget<E>
+where
+    E: Send + Sync + 'static + Clone + Serialize + DeserializeOwned,
+{
+    id: TargetID,
+    args: NATSArgs,
+    client: Arc<Mutex<Option<Client>>>,
+    store: Option<Box<dyn Store<EntityTarget<E>, Error = StoreError, Key = Key> + Send + Sync>>,
+}
+
+impl<E> NATSTarget<E>
+where
+    E: Send + Sync + 'static + Clone + Serialize + DeserializeOwned,
+{
+    /// Creates a new NATSTarget
+    #[instrument(skip(args), fields(target_id_as_string = %id))]
+    pub fn new(id: String, args: NATSArgs) -> Result<Self, TargetError> {
+        args.validate()?;
+        let target_id = TargetID::new(id, ChannelTargetType::Nats.as_str().to_string());
+
+        let queue_store = if !args.queue_dir.is_empty() {
+            let queue_dir = PathBuf::from(&args.queue_dir)
+                .join(format!("rustfs-{}-{}", ChannelTargetType::Nats.as_str(), target_id.id).replace(":", "_"));
+            debug!(target_id = %target_id, path = %queue_dir.display(), "Initializing queue store for NATS target");
+            let extension = match args.target_type {
+                TargetType::AuditLog => rustfs_config::audit::AUDIT_STORE_EXTENSION,
+                TargetType::NotifyEvent => rustfs_config::notify::NOTIFY_STORE_EXTENSION,
+            };
+
+            let store = QueueStore::<EntityTarget<E>>::new(queue_dir, args.queue_limit, extension);
+            if let Err(e) = store.open() {
+                error!(target_id = %target_id, error = %e, "Failed to open store for NATS target");
+                return Err(TargetError::Storage(format!("{e}")));
+            }
+            Some(Box::new(store) as Box<dyn Store<EntityTarget<E>, Error = StoreError, Key = Key> + Send + Sync>)
+        } else {
+            None
+        };
+
+        info!(target_id = %target_id, "NATS target created");
+        Ok(NATSTarget {
+            id: target_id,
+            args,
+            client: Arc::new(Mutex::new(None)),
+            store: queue_store,
+        })
+    }
+
+    /// Returns the connected client, connecting on first use.
+    /// Once connected, the client reconnects on its own after connection loss.
+    #[instrument(skip(self), fields(target_id = %self.id))]
+    async fn client(&self) -> Result<Client, TargetError> {
+        let mut guard = self.client.lock().await;
+        if let Some(client) = guard.as_ref() {
+            return Ok(client.clone());
+        }
+
+        let servers = self
+            .args
+            .address
+            .iter()
+            .map(|a| a.parse::<ServerAddr>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| TargetError::Configuration(format!("invalid NATS server address: {e}")))?;
+
+        let client = self
+            .args
+            .connect_options()
+            .await?
+            .connect(servers.as_slice())
+            .await
+            .map_err(|e| {
+                warn!(target_id = %self.id, error = %e, "Failed to connect to NATS server");
+                TargetError::NotConnected
+            })?;
+
+        info!(target_id = %self.id, "Connected to NATS server");
+        *guard = Some(client.clone());
+        Ok(client)
+    }
+
+    #[instrument(skip(self, event), fields(target_id = %self.id))]
+    async fn send(&self, event: &EntityTarget<E>) -> Result<(), TargetError> {
+        let client = self.client().await?;
+        if client.connection_state() != State::Connected {
+            return Err(TargetError::NotConnected);
+        }
+
+        // Decode form-urlencoded object name
+        let object_name = crate::target::decode_object_name(&event.object_name)?;
+
+        let log = TargetLog {
+            event_name: event.event_name,
+            key: format!("{}/{}", event.bucket_name, object_name),
+            records: vec![event.data.clone()],
+        };
+
+        let data = serde_json::to_vec(&log).map_err(|e| TargetError::Serialization(format!("Failed to serialize event: {e}")))?;
+        let subject = render_subject(&self.args.subject, &event.bucket_name);
+
+        if self.args.jetstream {
+            let context = async_nats::jetstream::new(client);
+            let ack = tokio::time::timeout(PUBLISH_TIMEOUT, async { context.publish(subject.clone(), data.into()).await?.await })
+                .await
+                .map_err(|_| TargetError::Timeout("Timed out waiting for JetStream acknowledgement".to_string()))?
+                .map_err(|e| TargetError::Request(format!("Failed to publish to JetStream: {e}")))?;
+            debug!(target_id = %self.id, subject = %subject, stream = %ack.stream, sequence = ack.sequence, "Event acknowledged by JetStream");
+        } else {
+            client
+                .publish(subject.clone(), data.into())
+                .await
+                .map_err(|e| TargetError::Request(format!("Failed to publish to NATS: {e}")))?;
+            // Core NATS has no acknowledgements, flushing at least hands the event to the server
+            tokio::time::timeout(PUBLISH_TIMEOUT, client.flush())
+                .await
+                .map_err(|_| TargetError::Timeout("Timed out flushing NATS connection".to_string()))?
+                .map_err(|_| TargetError::NotConnected)?;
+            debug!(target_id = %self.id, subject = %subject, "Event published to NATS subject");
+        }
+
+        Ok(())
+    }
+
+    pub fn clone_target(&self) -> Box<dyn Target<E> + Send + Sync> {
+        Box::new(NATSTarget {
+            id: self.id.clone(),
+            args: self.args.clone(),
+            client: self.client.clone(),
+            store: self.store.as_ref().map(|s| s.boxed_clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl<E> Target<E> for NATSTarget<E>
+where
+    E: Send + Sync + 'static + Clone + Serialize + DeserializeOwned,
+{
+    fn id(&self) -> TargetID {
+        self.id.clone()
+    }
+
+    #[instrument(skip(self), fields(target_id = %self.id))]
+    async fn is_active(&self) -> Result<bool, TargetError> {
+        let client = self.client().await?;
+        match client.connection_state() {
+            State::Connected => {
+                debug!(target_id = %self.id, "NATS server is reachable.");
+                Ok(true)
+            }
+            state => {
+                debug!(target_id = %self.id, ?state, "NATS server is not reachable.");
+                Err(TargetError::NotConnected)
+            }
+        }
+    }
+
+    #[instrument(skip(self, event), fields(target_id = %self.id))]
+    async fn save(&self, event: Arc<EntityTarget<E>>) -> Result<(), TargetError> {
+        if let Some(store) = &self.store {
+            // The event is replayed from the store, so it survives the server being unreachable
+            store
+                .put(event)
+                .map_err(|e| TargetError::Storage(format!("Failed to save event to store: {e}")))?;
+            debug!(target_id = %self.id, "Event saved to store for NATS target.");
+            return Ok(());
+        }
+
+        if !self.is_enabled() {
+            return Err(TargetError::Disabled);
+        }
+
+        self.send(&event).await
+    }
+
+    #[instrument(skip(self), fields(target_id = %self.id))]
+    async fn send_from_store(&self, key: Key) -> Result<(), TargetError> {
+        if !self.is_enabled() {
+            return Err(TargetError::Disabled);
+        }
+
+        let store = self
+            .store
+            .as_ref()
+            .ok_or_else(|| TargetError::Configuration("No store configured".to_string()))?;
+
+        let event = match store.get(&key) {
+            Ok(event) => event,
+            Err(StoreError::NotFound) => return Ok(()),
+            Err(e) => {
+                return Err(TargetError::Storage(format!("Failed to get event from store: {e}")));
+            }
+        };
+
+        if let Err(e) = self.send(&event).await {
+            if matches!(e, TargetError::NotConnected) {
+                warn!(target_id = %self.id, "NATS target not connected; event remains in store.");
+            }
+            return Err(e);
+        }
+
+        match store.del(&key) {
+            Ok(_) | Err(StoreError::NotFound) => {
+                debug!(target_id = %self.id, ?key, "Event sent from store and deleted.");
+                Ok(())
+            }
+            Err(e) => {
+                error!(target_id = %self.id, error = %e, "Failed to delete event from store after send.");
+                Err(TargetError::Storage(format!("Failed to delete event from store: {e}")))
+            }
+        }
+    }
+
+    async fn close(&self) -> Result<(), TargetError> {
+        if let Some(client) = self.client.lock().await.take()
+            && let Err(e) = client.flush().await
+        {
+            warn!(target_id = %self.id, error = %e, "Failed to flush NATS connection on close.");
+        }
+
+        info!(target_id = %self.id, "NATS target closed");
+        Ok(())
+    }
+
+    fn store(&self) -> Option<&(dyn Store<EntityTarget<E>, Error = StoreError, Key = Key> + Send + Sync)> {
+        self.store.as_deref()
+    }
+
+    fn clone_dyn(&self) -> Box<dyn Target<E> + Send + Sync> {
+        self.clone_target()
+    }
+
+    async fn init(&self) -> Result<(), TargetError> {
+        if !self.is_enabled() {
+            debug!(target_id = %self.id, "Target is disabled, skipping init.");
+            return Ok(());
+        }
+
+        // A server that is down at startup is not fatal, events wait in the store until it is reachable
+        if let Err(e) = self.client().await {
+            warn!(target_id = %self.id, error = %e, "NATS server not reachable during init.");
+            if self.store.is_none() {
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.args.enable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args() -> NATSArgs {
+        NATSArgs {
+            enable: true,
+            address: vec!["localhost:4222".to_string()],
+            subject: "rustfs.events.{bucket}".to_string(),
+            username: String::new(),
+            password: String::new(),
+            token: String::new(),
+            nkey_seed: String::new(),
+            user_credentials: String::new(),
+            tls: false,
+            tls_ca: String::new(),
+            client_tls_cert: String::new(),
+            client_tls_key: String::new(),
+            jetstream: false,
+            queue_dir: String::new(),
+            queue_limit: 1000,
+            target_type: TargetType::NotifyEvent,
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let cases = vec![
+            ("defaults", args(), true),
+            (
+                "multiple servers",
+                NATSArgs {
+                    address: vec!["nats://a:4222".to_string(), "tls://b:4222".to_string()],
+                    ..args()
+                },
+                true,
+            ),
+            (
+                "no servers",
+                NATSArgs {
+                    address: vec![],
+                    ..args()
+                },
+                false,
+            ),
+            (
+                "unsupported scheme",
+                NATSArgs {
+                    address: vec!["http://localhost:4222".to_string()],
+                    ..args()
+                },
+                false,
+            ),
+            (
+                "username without password",
+                NATSArgs {
+                    username: "user".to_string(),
+                    ..args()
+                },
+                false,
+            ),
+            (
+                "token and nkey",
+                NATSArgs {
+                    token: "secret".to_string(),
+                    nkey_seed: "SUAKYRHVIOREXV7EUZTBHUHL7NUMHPMAS7QMDU3GTIUWEI5LDNOXD43IZY".to_string(),
+                    ..args()
+                },
+                false,
+            ),
+            (
+                "client key without certificate",
+                NATSArgs {
+                    client_tls_key: "/etc/rustfs/client.key".to_string(),
+                    ..args()
+                },
+                false,
+            ),
+            (
+                "relative queue dir",
+                NATSArgs {
+                    queue_dir: "relative".to_string(),
+                    ..args()
+                },
+                false,
+            ),
+            (
+                "disabled",
+                NATSArgs {
+                    enable: false,
+                    address: vec![],
+                    ..args()
+                },
+                true,
+            ),
+        ];
+
+        for (name, args, valid) in cases {
+            assert_eq!(args.validate().is_ok(), valid, "case: {name}");
+        }
+    }
+
+    #[test]
+    fn test_validate_subject() {
+        let cases = vec![
+            ("rustfs.events", true),
+            ("rustfs.{bucket}.events", true),
+            ("", false),
+            ("rustfs.*", false),
+            ("rustfs.>", false),
+            ("rustfs..events", false),
+            ("rustfs events", false),
+        ];
+
+        for (subject, valid) in cases {
+            assert_eq!(validate_subject(subject).is_ok(), valid, "subject: {subject}");
+        }
+    }
+
+    #[test]
+    fn test_render_subject() {
+        let cases = vec![
+            ("rustfs.events.{bucket}", "photos", "rustfs.events.photos"),
+            ("rustfs.events.{bucket}", "my.photos", "rustfs.events.my_photos"),
+            ("rustfs.{bucket}.put", "a.b.c", "rustfs.a_b_c.put"),
+            ("rustfs.events", "photos", "rustfs.events"),
+        ];
+
+        for (template, bucket, expected) in cases {
+            assert_eq!(render_subject(template, bucket), expected, "template: {template}, bucket: {bucket}");
+        }
+    }
+}
@@ -72,24 +72,36 @@ pub fn load_cert_bundle_der_bytes(path: &str) -> io::Result<Vec<Vec<u8>>> {
     Ok(certs.into_iter().map(|c| c.to_vec()).collect())
 }
 
-/// Builds a WebPkiClientVerifier for mTLS if enabled via environment variable.
+/// Builds a WebPkiClientVerifier for mTLS or certificate identities if enabled via environment variables.
+///
+/// With `RUSTFS_SERVER_MTLS_ENABLE` every client must present a certificate. With only
+/// `RUSTFS_IDENTITY_TLS_ENABLE` a certificate is optional, but one that is presented must be signed
+/// by the client CA so it can be used for STS AssumeRoleWithCertificate.
 ///
 /// # Arguments
 /// * `tls_path` - Directory containing client CA certificates
 ///
 /// # Returns
-/// * `Ok(Some(verifier))` if mTLS is enabled and CA certs are found
-/// * `Ok(None)` if mTLS is disabled
-/// * `Err` if mTLS is enabled but configuration is invalid
+/// * `Ok(Some(verifier))` if mTLS or certificate identities are enabled and CA certs are found
+/// * `Ok(None)` if both are disabled
+/// * `Err` if either is enabled but configuration is invalid
 pub fn build_webpki_client_verifier(tls_path: &str) -> io::Result<Option<Arc<dyn ClientCertVerifier>>> {
-    if !get_env_bool(rustfs_config::ENV_SERVER_MTLS_ENABLE, rustfs_config::DEFAULT_SERVER_MTLS_ENABLE) {
+    let required = get_env_bool(rustfs_config::ENV_SERVER_MTLS_ENABLE, rustfs_config::DEFAULT_SERVER_MTLS_ENABLE);
+    let identity = get_env_bool(rustfs_config::ENV_IDENTITY_TLS_ENABLE, rustfs_config::DEFAULT_IDENTITY_TLS_ENABLE);
+    if !required && !identity {
         return Ok(None);
     }
 
     let ca_path = mtls_ca_bundle_path(tls_path).ok_or_else(|| {
         Error::other(format!(
-            "RUSTFS_SERVER_MTLS_ENABLE=true but missing {}/client_ca.crt (or fallback {}/ca.crt)",
-            tls_path, tls_path
+            "{}=true but missing {}/client_ca.crt (or fallback {}/ca.crt)",
+            if required {
+                rustfs_config::ENV_SERVER_MTLS_ENABLE
+            } else {
+                rustfs_config::ENV_IDENTITY_TLS_ENABLE
+            },
+            tls_path,
+            tls_path
         ))
     })?;
 
@@ -102,7 +114,11 @@ pub fn build_webpki_client_verifier(tls_path: &str) -> io::Result<Option<Arc<dyn
             .map_err(|e| Error::other(format!("Invalid client CA cert: {e}")))?;
     }
 
-    let verifier = WebPkiClientVerifier::builder(Arc::new(store))
+    let mut builder = WebPkiClientVerifier::builder(Arc::new(store));
+    if !required {
+        builder = builder.allow_unauthenticated();
+    }
+    let verifier = builder
         .build()
         .map_err(|e| Error::other(format!("Build client cert verifier failed: {e}")))?;
 
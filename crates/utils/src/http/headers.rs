@@ -87,6 +87,9 @@ pub const AMZ_MP_PARTS_COUNT: &str = "x-amz-mp-parts-count";
 // Object date/time of expiration
 pub const AMZ_EXPIRATION: &str = "x-amz-expiration";
 
+// Object date/time of the next lifecycle transition
+pub const RUSTFS_TRANSITION: &str = "X-Rustfs-Transition";
+
 // Dummy putBucketACL
 pub const AMZ_ACL: &str = "x-amz-acl";
 
@@ -157,6 +160,9 @@ pub const RESERVED_METADATA_PREFIX_LOWER: &str = "x-rustfs-internal-";
 pub const RUSTFS_HEALING: &str = "X-Rustfs-Internal-healing";
 // pub const RUSTFS_DATA_MOVE: &str = "X-Rustfs-Internal-data-mov";
 
+pub const RUSTFS_MULTIPART_BUCKET: &str = "X-Rustfs-Internal-multipart-bucket";
+pub const RUSTFS_MULTIPART_OBJECT: &str = "X-Rustfs-Internal-multipart-object";
+
 // pub const X_RUSTFS_INLINE_DATA: &str = "x-rustfs-inline-data";
 
 pub const VERSION_PURGE_STATUS_KEY: &str = "X-Rustfs-Internal-purgestatus";
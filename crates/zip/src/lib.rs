@@ -19,6 +19,8 @@ use tokio::fs::File;
 use tokio::io::{self, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, BufWriter};
 use tokio_stream::StreamExt;
 use tokio_tar::Archive;
+use zip::write::{SimpleFileOptions, StreamWriter};
+use zip::{CompressionMethod, ZipWriter};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum CompressionFormat {
@@ -206,12 +208,74 @@ pub async fn extract_zip_simple<P: AsRef<Path>>(zip_path: P, extract_to: P) -> i
 
 /// Simplified ZIP file creation
 pub async fn create_zip_simple<P: AsRef<Path>>(
-    _zip_path: P,
-    _files: Vec<(String, Vec<u8>)>, // (filename, file content)
-    _compression_level: CompressionLevel,
+    zip_path: P,
+    files: Vec<(String, Vec<u8>)>, // (filename, file content)
+    compression_level: CompressionLevel,
 ) -> io::Result<()> {
-    // Return unimplemented error for now
-    Err(io::Error::new(io::ErrorKind::Unsupported, "ZIP creation not yet implemented"))
+    let data = create_zip_bytes(files, compression_level)?;
+    tokio::fs::write(zip_path, data).await
+}
+
+fn zip_file_options(compression_level: CompressionLevel) -> SimpleFileOptions {
+    let level = match compression_level {
+        CompressionLevel::Fastest => Some(1),
+        CompressionLevel::Best => Some(9),
+        CompressionLevel::Default => None,
+        CompressionLevel::Level(l) => Some(i64::from(l)),
+    };
+    SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .compression_level(level)
+        .large_file(true)
+}
+
+/// Build a deflate-compressed ZIP archive in memory
+pub fn create_zip_bytes(files: Vec<(String, Vec<u8>)>, compression_level: CompressionLevel) -> io::Result<Vec<u8>> {
+    let options = zip_file_options(compression_level);
+
+    let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, content) in files {
+        writer.start_file(name, options).map_err(io::Error::other)?;
+        std::io::Write::write_all(&mut writer, &content)?;
+    }
+
+    Ok(writer.finish().map_err(io::Error::other)?.into_inner())
+}
+
+/// Deflate-compressed ZIP archive written entry by entry to a writer that cannot seek, such as a
+/// response body, so that no entry has to be held in memory
+pub struct ZipStreamWriter<W: std::io::Write> {
+    inner: ZipWriter<StreamWriter<W>>,
+    options: SimpleFileOptions,
+}
+
+impl<W: std::io::Write> ZipStreamWriter<W> {
+    pub fn new(writer: W, compression_level: CompressionLevel) -> Self {
+        Self {
+            inner: ZipWriter::new_stream(writer),
+            options: zip_file_options(compression_level),
+        }
+    }
+
+    /// Start a new entry, the data written next belongs to it
+    pub fn start_file(&mut self, name: &str) -> io::Result<()> {
+        self.inner.start_file(name, self.options).map_err(io::Error::other)
+    }
+
+    /// Write the central directory and return the underlying writer
+    pub fn finish(self) -> io::Result<W> {
+        Ok(self.inner.finish().map_err(io::Error::other)?.into_inner())
+    }
+}
+
+impl<W: std::io::Write> std::io::Write for ZipStreamWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
 }
 
 /// Compression utility struct
@@ -904,6 +968,47 @@ mod tests {
         assert_eq!(entry.compression_method, "Deflate");
     }
 
+    #[test]
+    fn test_create_zip_bytes_roundtrip() {
+        // Test in-memory ZIP creation can be read back entry by entry
+        let files = vec![
+            ("a/xl.meta".to_string(), b"meta content".to_vec()),
+            ("a/part.1".to_string(), vec![7u8; 4096]),
+        ];
+
+        let data = create_zip_bytes(files, CompressionLevel::Fastest).expect("zip creation should succeed");
+        let mut archive = zip::ZipArchive::new(Cursor::new(data)).expect("archive should open");
+        assert_eq!(archive.len(), 2);
+
+        let mut content = Vec::new();
+        std::io::Read::read_to_end(&mut archive.by_name("a/xl.meta").expect("entry should exist"), &mut content).unwrap();
+        assert_eq!(content, b"meta content");
+
+        content.clear();
+        std::io::Read::read_to_end(&mut archive.by_name("a/part.1").expect("entry should exist"), &mut content).unwrap();
+        assert_eq!(content, vec![7u8; 4096]);
+    }
+
+    #[test]
+    fn test_zip_stream_writer_roundtrip() {
+        // Test a streamed archive written entry by entry opens like a regular one
+        let mut writer = ZipStreamWriter::new(Vec::new(), CompressionLevel::Default);
+        writer.start_file("a/xl.meta").unwrap();
+        std::io::Write::write_all(&mut writer, b"meta").unwrap();
+        writer.start_file("a/part.1").unwrap();
+        for _ in 0..4 {
+            std::io::Write::write_all(&mut writer, &[3u8; 1024]).unwrap();
+        }
+        let data = writer.finish().unwrap();
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(data)).expect("archive should open");
+        assert_eq!(archive.len(), 2);
+
+        let mut content = Vec::new();
+        std::io::Read::read_to_end(&mut archive.by_name("a/part.1").expect("entry should exist"), &mut content).unwrap();
+        assert_eq!(content, vec![3u8; 4096]);
+    }
+
     #[test]
     fn test_compression_level_variants() {
         // Test all compression level variants
@@ -19,7 +19,7 @@ use futures::stream::{FuturesUnordered, StreamExt};
 use http::{HeaderMap, StatusCode};
 use hyper::Method;
 use matchit::Params;
-use rustfs_config::notify::{NOTIFY_MQTT_SUB_SYS, NOTIFY_WEBHOOK_SUB_SYS};
+use rustfs_config::notify::{NOTIFY_KAFKA_SUB_SYS, NOTIFY_MQTT_SUB_SYS, NOTIFY_NATS_SUB_SYS, NOTIFY_WEBHOOK_SUB_SYS};
 use rustfs_config::{ENABLE_KEY, EnableState, MAX_ADMIN_REQUEST_BODY_SIZE};
 use rustfs_targets::check_mqtt_broker_available;
 use s3s::{Body, S3Request, S3Response, S3Result, header::CONTENT_TYPE, s3_error};
@@ -180,6 +180,8 @@ impl Operation for NotificationTarget {
         let allowed_keys: HashSet<&str> = match target_type {
             NOTIFY_WEBHOOK_SUB_SYS => rustfs_config::notify::NOTIFY_WEBHOOK_KEYS.iter().cloned().collect(),
             NOTIFY_MQTT_SUB_SYS => rustfs_config::notify::NOTIFY_MQTT_KEYS.iter().cloned().collect(),
+            NOTIFY_KAFKA_SUB_SYS => rustfs_config::notify::NOTIFY_KAFKA_KEYS.iter().cloned().collect(),
+            NOTIFY_NATS_SUB_SYS => rustfs_config::notify::NOTIFY_NATS_KEYS.iter().cloned().collect(),
             _ => unreachable!(),
         };
 
@@ -239,6 +241,58 @@ impl Operation for NotificationTarget {
                     }
                 }
             }
+        } else if target_type == NOTIFY_KAFKA_SUB_SYS {
+            let brokers = kv_map
+                .get(rustfs_config::KAFKA_BROKERS)
+                .ok_or_else(|| s3_error!(InvalidArgument, "brokers is required"))?;
+            if brokers.split(',').all(|b| b.trim().is_empty()) {
+                return Err(s3_error!(InvalidArgument, "at least one broker address is required"));
+            }
+            if kv_map.get(rustfs_config::KAFKA_TOPIC).is_none_or(|t| t.is_empty()) {
+                return Err(s3_error!(InvalidArgument, "topic is required"));
+            }
+            if let Some(acks) = kv_map.get(rustfs_config::KAFKA_ACKS)
+                && !matches!(*acks, "0" | "1" | "all" | "-1")
+            {
+                return Err(s3_error!(InvalidArgument, "acks must be 0, 1 or all"));
+            }
+            if kv_map.contains_key(rustfs_config::KAFKA_CLIENT_TLS_CERT)
+                != kv_map.contains_key(rustfs_config::KAFKA_CLIENT_TLS_KEY)
+            {
+                return Err(s3_error!(
+                    InvalidArgument,
+                    "client_tls_cert and client_tls_key must be specified as a pair"
+                ));
+            }
+            if let Some(queue_dir) = kv_map.get("queue_dir") {
+                validate_queue_dir(queue_dir).await?;
+                if kv_map.get(rustfs_config::KAFKA_ACKS) == Some(&"0") {
+                    return Err(s3_error!(InvalidArgument, "acks should be 1 or all if queue_dir is set"));
+                }
+            }
+        } else if target_type == NOTIFY_NATS_SUB_SYS {
+            let address = kv_map
+                .get(rustfs_config::NATS_ADDRESS)
+                .ok_or_else(|| s3_error!(InvalidArgument, "address is required"))?;
+            if address.split(',').all(|a| a.trim().is_empty()) {
+                return Err(s3_error!(InvalidArgument, "at least one server address is required"));
+            }
+            if kv_map.get(rustfs_config::NATS_SUBJECT).is_none_or(|s| s.is_empty()) {
+                return Err(s3_error!(InvalidArgument, "subject is required"));
+            }
+            if kv_map.contains_key(rustfs_config::NATS_USERNAME) != kv_map.contains_key(rustfs_config::NATS_PASSWORD) {
+                return Err(s3_error!(InvalidArgument, "username and password must be specified as a pair"));
+            }
+            if kv_map.contains_key(rustfs_config::NATS_CLIENT_TLS_CERT) != kv_map.contains_key(rustfs_config::NATS_CLIENT_TLS_KEY)
+            {
+                return Err(s3_error!(
+                    InvalidArgument,
+                    "client_tls_cert and client_tls_key must be specified as a pair"
+                ));
+            }
+            if let Some(queue_dir) = kv_map.get("queue_dir") {
+                validate_queue_dir(queue_dir).await?;
+            }
         }
 
         let mut kvs_vec: Vec<_> = notification_body
@@ -361,7 +415,10 @@ fn extract_param<'a>(params: &'a Params<'_, '_>, key: &str) -> S3Result<&'a str>
 
 fn extract_target_params<'a>(params: &'a Params<'_, '_>) -> S3Result<(&'a str, &'a str)> {
     let target_type = extract_param(params, "target_type")?;
-    if target_type != NOTIFY_WEBHOOK_SUB_SYS && target_type != NOTIFY_MQTT_SUB_SYS {
+    if !matches!(
+        target_type,
+        NOTIFY_WEBHOOK_SUB_SYS | NOTIFY_MQTT_SUB_SYS | NOTIFY_KAFKA_SUB_SYS | NOTIFY_NATS_SUB_SYS
+    ) {
         return Err(s3_error!(InvalidArgument, "unsupported target type: '{}'", target_type));
     }
     let target_name = extract_param(params, "target_name")?;
@@ -12,19 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::admin::auth::validate_admin_request;
 use crate::admin::router::{AdminOperation, Operation, S3Router};
-use crate::server::ADMIN_PREFIX;
+use crate::auth::{check_key_valid, get_session_token};
+use crate::heal_status::local_background_heal_state;
+use crate::server::{ADMIN_PREFIX, RemoteAddr};
 use bytes::Bytes;
-use http::Uri;
+use http::{HeaderMap, HeaderValue, Uri};
 use hyper::{Method, StatusCode};
 use matchit::Params;
 use rustfs_common::heal_channel::HealOpts;
 use rustfs_config::MAX_HEAL_REQUEST_SIZE;
 use rustfs_ecstore::bucket::utils::is_valid_object_prefix;
 use rustfs_ecstore::error::StorageError;
+use rustfs_ecstore::notification_sys::get_global_notification_sys;
 use rustfs_ecstore::store_utils::is_reserved_or_invalid_bucket;
+use rustfs_madmin::heal_commands::BgHealState;
+use rustfs_policy::policy::action::{Action, AdminAction};
 use rustfs_utils::path::path_join;
-use s3s::{Body, S3Request, S3Response, S3Result, s3_error};
+use s3s::header::CONTENT_TYPE;
+use s3s::{Body, S3Error, S3ErrorCode, S3Request, S3Response, S3Result, s3_error};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tokio::spawn;
@@ -245,14 +252,55 @@ impl Operation for HealHandler {
     }
 }
 
+/// Background heal state merged across this node and all of its peers.
+pub async fn aggregated_background_heal_state() -> S3Result<BgHealState> {
+    let Some(mut state) = local_background_heal_state().await else {
+        return Err(S3Error::with_message(S3ErrorCode::InternalError, "Not init".to_string()));
+    };
+
+    if let Some(sys) = get_global_notification_sys() {
+        for peer_state in sys.background_heal_status().await {
+            state.merge(peer_state);
+        }
+    }
+
+    Ok(state)
+}
+
 pub struct BackgroundHealStatusHandler {}
 
 #[async_trait::async_trait]
 impl Operation for BackgroundHealStatusHandler {
-    async fn call(&self, _req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
+    async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
         warn!("handle BackgroundHealStatusHandler");
 
-        Err(s3_error!(NotImplemented))
+        let Some(input_cred) = req.credentials else {
+            return Err(s3_error!(InvalidRequest, "get cred failed"));
+        };
+
+        let (cred, owner) =
+            check_key_valid(get_session_token(&req.uri, &req.headers).unwrap_or_default(), &input_cred.access_key).await?;
+
+        let remote_addr = req.extensions.get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0));
+        validate_admin_request(
+            &req.headers,
+            &cred,
+            owner,
+            false,
+            vec![Action::AdminAction(AdminAction::HealAdminAction)],
+            remote_addr,
+        )
+        .await?;
+
+        let state = aggregated_background_heal_state().await?;
+
+        let data = serde_json::to_vec(&state)
+            .map_err(|_e| S3Error::with_message(S3ErrorCode::InternalError, "failed to serialize background heal state"))?;
+
+        let mut header = HeaderMap::new();
+        header.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        Ok(S3Response::with_headers((StatusCode::OK, Body::from(data)), header))
     }
 }
 
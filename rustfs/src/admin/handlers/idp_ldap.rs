@@ -0,0 +1,370 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Policy mappings for LDAP user and group DNs
+
+use crate::{
+    admin::{
+        auth::validate_admin_request,
+        router::{AdminOperation, Operation, S3Router},
+    },
+    auth::{check_key_valid, get_session_token},
+    server::{ADMIN_PREFIX, RemoteAddr},
+};
+use http::{HeaderMap, StatusCode};
+use hyper::Method;
+use matchit::Params;
+use rustfs_config::MAX_ADMIN_REQUEST_BODY_SIZE;
+use rustfs_iam::ldap::{is_dn, normalize_dn};
+use rustfs_iam::store::UserType;
+use rustfs_policy::policy::action::{Action, AdminAction};
+use s3s::{Body, S3Error, S3ErrorCode, S3Request, S3Response, S3Result, header::CONTENT_TYPE, s3_error};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use tracing::warn;
+
+pub fn register_idp_ldap_route(r: &mut S3Router<AdminOperation>) -> std::io::Result<()> {
+    r.insert(
+        Method::POST,
+        format!("{}{}", ADMIN_PREFIX, "/v3/idp/ldap/policy/attach").as_str(),
+        AdminOperation(&AttachDetachLdapPolicy { attach: true }),
+    )?;
+
+    r.insert(
+        Method::POST,
+        format!("{}{}", ADMIN_PREFIX, "/v3/idp/ldap/policy/detach").as_str(),
+        AdminOperation(&AttachDetachLdapPolicy { attach: false }),
+    )?;
+
+    r.insert(
+        Method::GET,
+        format!("{}{}", ADMIN_PREFIX, "/v3/idp/ldap/policy-entities").as_str(),
+        AdminOperation(&ListLdapPolicyEntities {}),
+    )?;
+
+    Ok(())
+}
+
+/// Request body of the LDAP policy attach/detach calls; exactly one of `user` and `group` is set
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+pub struct LdapPolicyAssociationReq {
+    pub policies: Vec<String>,
+    pub user: String,
+    pub group: String,
+}
+
+#[derive(Debug, Serialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LdapPolicyAssociationResp {
+    pub policies_attached: Vec<String>,
+    pub policies_detached: Vec<String>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, Default, PartialEq, Eq)]
+pub struct LdapUserPolicies {
+    pub user: String,
+    pub policies: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Default, PartialEq, Eq)]
+pub struct LdapGroupPolicies {
+    pub group: String,
+    pub policies: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Default, PartialEq, Eq)]
+pub struct LdapPolicyEntities {
+    pub policy: String,
+    pub users: Vec<String>,
+    pub groups: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LdapPolicyEntitiesResp {
+    pub user_mappings: Vec<LdapUserPolicies>,
+    pub group_mappings: Vec<LdapGroupPolicies>,
+    pub policy_mappings: Vec<LdapPolicyEntities>,
+}
+
+pub struct AttachDetachLdapPolicy {
+    attach: bool,
+}
+
+#[async_trait::async_trait]
+impl Operation for AttachDetachLdapPolicy {
+    async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
+        warn!("handle AttachDetachLdapPolicy, attach: {}", self.attach);
+
+        let Some(input_cred) = req.credentials else {
+            return Err(s3_error!(InvalidRequest, "get cred failed"));
+        };
+
+        let (cred, owner) =
+            check_key_valid(get_session_token(&req.uri, &req.headers).unwrap_or_default(), &input_cred.access_key).await?;
+
+        validate_admin_request(
+            &req.headers,
+            &cred,
+            owner,
+            false,
+            vec![Action::AdminAction(AdminAction::UpdatePolicyAssociationAction)],
+            req.extensions.get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0)),
+        )
+        .await?;
+
+        let mut input = req.input;
+        let body = match input.store_all_limited(MAX_ADMIN_REQUEST_BODY_SIZE).await {
+            Ok(b) => b,
+            Err(e) => {
+                warn!("get body failed, e: {:?}", e);
+                return Err(s3_error!(InvalidRequest, "request body too large or failed to read"));
+            }
+        };
+
+        let assoc: LdapPolicyAssociationReq =
+            serde_json::from_slice(&body).map_err(|e| s3_error!(InvalidRequest, "invalid request body: {}", e))?;
+
+        let (entity, is_group) = match (assoc.user.is_empty(), assoc.group.is_empty()) {
+            (false, true) => (normalize_dn(&assoc.user), false),
+            (true, false) => (normalize_dn(&assoc.group), true),
+            _ => return Err(s3_error!(InvalidArgument, "exactly one of user and group must be set")),
+        };
+        if !is_dn(&entity) {
+            return Err(s3_error!(InvalidArgument, "{} is not a distinguished name", entity));
+        }
+        if assoc.policies.iter().all(|p| p.trim().is_empty()) {
+            return Err(s3_error!(InvalidArgument, "no policy given"));
+        }
+
+        let Ok(iam_store) = rustfs_iam::get() else { return Err(s3_error!(InternalError, "iam not init")) };
+
+        if !iam_store.ldap().is_enabled() {
+            return Err(s3_error!(InvalidRequest, "LDAP identity is not configured"));
+        }
+
+        if self.attach {
+            match iam_store.ldap().lookup_dn(&entity).await {
+                Ok(true) => {}
+                Ok(false) => return Err(s3_error!(InvalidArgument, "{} does not exist in LDAP", entity)),
+                Err(e) => {
+                    warn!("ldap lookup of {} failed, e: {:?}", entity, e);
+                    return Err(S3Error::with_message(S3ErrorCode::InternalError, e.to_string()));
+                }
+            }
+        }
+
+        let current = iam_store.ldap_mapped_policy(&entity, is_group).await.map_err(|e| {
+            warn!("load ldap policy mapping failed, e: {:?}", e);
+            S3Error::with_message(S3ErrorCode::InternalError, e.to_string())
+        })?;
+
+        let (updated, changed) = update_mapping(&current, &assoc.policies, self.attach);
+        if changed.is_empty() {
+            return Err(s3_error!(
+                InvalidArgument,
+                "{}",
+                if self.attach {
+                    "policies are already attached"
+                } else {
+                    "policies are not attached"
+                }
+            ));
+        }
+
+        let user_type = if is_group { UserType::Reg } else { UserType::Sts };
+        let updated_at = iam_store
+            .policy_db_set(&entity, user_type, is_group, &updated.join(","))
+            .await
+            .map_err(|e| {
+                warn!("policy db set failed, e: {:?}", e);
+                S3Error::with_message(S3ErrorCode::InternalError, e.to_string())
+            })?;
+
+        let resp = LdapPolicyAssociationResp {
+            policies_attached: if self.attach { changed.clone() } else { Vec::new() },
+            policies_detached: if self.attach { Vec::new() } else { changed },
+            updated_at: updated_at
+                .format(&time::format_description::well_known::Rfc3339)
+                .unwrap_or_default(),
+        };
+
+        let data = serde_json::to_vec(&resp).map_err(|e| s3_error!(InternalError, "marshal body failed, e: {:?}", e))?;
+
+        let mut header = HeaderMap::new();
+        header.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        Ok(S3Response::with_headers((StatusCode::OK, Body::from(data)), header))
+    }
+}
+
+/// Apply an attach or detach to a mapping, returning the new mapping and the policies that changed
+fn update_mapping(current: &[String], requested: &[String], attach: bool) -> (Vec<String>, Vec<String>) {
+    let mut updated = current.to_vec();
+    let mut changed = Vec::new();
+
+    for policy in requested.iter().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        let present = updated.iter().any(|p| p == policy);
+        if attach && !present {
+            updated.push(policy.to_string());
+            changed.push(policy.to_string());
+        } else if !attach && present {
+            updated.retain(|p| p != policy);
+            changed.push(policy.to_string());
+        }
+    }
+
+    (updated, changed)
+}
+
+pub struct ListLdapPolicyEntities {}
+
+#[async_trait::async_trait]
+impl Operation for ListLdapPolicyEntities {
+    async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
+        warn!("handle ListLdapPolicyEntities");
+
+        let Some(input_cred) = req.credentials else {
+            return Err(s3_error!(InvalidRequest, "get cred failed"));
+        };
+
+        let (cred, owner) =
+            check_key_valid(get_session_token(&req.uri, &req.headers).unwrap_or_default(), &input_cred.access_key).await?;
+
+        validate_admin_request(
+            &req.headers,
+            &cred,
+            owner,
+            false,
+            vec![Action::AdminAction(AdminAction::ListUserPoliciesAdminAction)],
+            req.extensions.get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0)),
+        )
+        .await?;
+
+        let Ok(iam_store) = rustfs_iam::get() else { return Err(s3_error!(InternalError, "iam not init")) };
+
+        let mut user_map = HashMap::new();
+        let mut group_map = HashMap::new();
+        for (user_type, is_group, m) in [(UserType::Sts, false, &mut user_map), (UserType::Reg, true, &mut group_map)] {
+            iam_store.load_mapped_policies(user_type, is_group, m).await.map_err(|e| {
+                warn!("load mapped policies failed, e: {:?}", e);
+                S3Error::with_message(S3ErrorCode::InternalError, e.to_string())
+            })?;
+        }
+
+        let users = user_map
+            .into_iter()
+            .filter(|(name, _)| is_dn(name))
+            .map(|(name, mp)| (name, mp.to_slice()))
+            .collect();
+        let groups = group_map
+            .into_iter()
+            .filter(|(name, _)| is_dn(name))
+            .map(|(name, mp)| (name, mp.to_slice()))
+            .collect();
+
+        let data = serde_json::to_vec(&build_entities(users, groups))
+            .map_err(|e| s3_error!(InternalError, "marshal body failed, e: {:?}", e))?;
+
+        let mut header = HeaderMap::new();
+        header.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+        Ok(S3Response::with_headers((StatusCode::OK, Body::from(data)), header))
+    }
+}
+
+fn build_entities(users: BTreeMap<String, Vec<String>>, groups: BTreeMap<String, Vec<String>>) -> LdapPolicyEntitiesResp {
+    let mut by_policy: BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> = BTreeMap::new();
+    for (user, policies) in users.iter() {
+        for p in policies {
+            by_policy.entry(p.clone()).or_default().0.insert(user.clone());
+        }
+    }
+    for (group, policies) in groups.iter() {
+        for p in policies {
+            by_policy.entry(p.clone()).or_default().1.insert(group.clone());
+        }
+    }
+
+    LdapPolicyEntitiesResp {
+        user_mappings: users
+            .into_iter()
+            .filter(|(_, policies)| !policies.is_empty())
+            .map(|(user, policies)| LdapUserPolicies { user, policies })
+            .collect(),
+        group_mappings: groups
+            .into_iter()
+            .filter(|(_, policies)| !policies.is_empty())
+            .map(|(group, policies)| LdapGroupPolicies { group, policies })
+            .collect(),
+        policy_mappings: by_policy
+            .into_iter()
+            .map(|(policy, (users, groups))| LdapPolicyEntities {
+                policy,
+                users: users.into_iter().collect(),
+                groups: groups.into_iter().collect(),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_update_mapping() {
+        let current = strings(&["readonly"]);
+
+        let (updated, changed) = update_mapping(&current, &strings(&["readwrite", "readonly", " "]), true);
+        assert_eq!(updated, strings(&["readonly", "readwrite"]));
+        assert_eq!(changed, strings(&["readwrite"]));
+
+        let (updated, changed) = update_mapping(&updated, &strings(&["readonly", "diagnostics"]), false);
+        assert_eq!(updated, strings(&["readwrite"]));
+        assert_eq!(changed, strings(&["readonly"]));
+    }
+
+    #[test]
+    fn test_build_entities() {
+        let users = BTreeMap::from([("uid=alice,dc=example,dc=com".to_string(), strings(&["readwrite"]))]);
+        let groups = BTreeMap::from([
+            ("cn=ops,dc=example,dc=com".to_string(), strings(&["readwrite", "diagnostics"])),
+            ("cn=empty,dc=example,dc=com".to_string(), Vec::new()),
+        ]);
+
+        let resp = build_entities(users, groups);
+        assert_eq!(resp.user_mappings.len(), 1);
+        assert_eq!(resp.group_mappings.len(), 1);
+        assert_eq!(
+            resp.policy_mappings,
+            vec![
+                LdapPolicyEntities {
+                    policy: "diagnostics".to_string(),
+                    users: Vec::new(),
+                    groups: strings(&["cn=ops,dc=example,dc=com"]),
+                },
+                LdapPolicyEntities {
+                    policy: "readwrite".to_string(),
+                    users: strings(&["uid=alice,dc=example,dc=com"]),
+                    groups: strings(&["cn=ops,dc=example,dc=com"]),
+                },
+            ]
+        );
+    }
+}
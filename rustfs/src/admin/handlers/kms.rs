@@ -14,7 +14,7 @@
 
 //! KMS admin handlers for HTTP API
 
-use super::{kms_dynamic, kms_keys, kms_management};
+use super::{kms_dynamic, kms_keys, kms_management, kms_rewrap};
 use crate::admin::auth::validate_admin_request;
 use crate::admin::router::{AdminOperation, Operation, S3Router};
 use crate::auth::{check_key_valid, get_session_token};
@@ -95,7 +95,7 @@ pub struct KmsConfigResponse {
     pub default_key_id: Option<String>,
 }
 
-fn extract_query_params(uri: &hyper::Uri) -> HashMap<String, String> {
+pub(super) fn extract_query_params(uri: &hyper::Uri) -> HashMap<String, String> {
     let mut params = HashMap::new();
     if let Some(query) = uri.query() {
         query.split('&').for_each(|pair| {
@@ -114,6 +114,7 @@ pub fn register_kms_route(r: &mut S3Router<AdminOperation>) -> std::io::Result<(
     kms_management::register_kms_management_route(r)?;
     kms_dynamic::register_kms_dynamic_route(r)?;
     kms_keys::register_kms_key_route(r)?;
+    kms_rewrap::register_kms_rewrap_route(r)?;
 
     Ok(())
 }
@@ -21,11 +21,15 @@ use crate::server::{ADMIN_PREFIX, RemoteAddr};
 use hyper::{Method, StatusCode};
 use matchit::Params;
 use rustfs_config::MAX_ADMIN_REQUEST_BODY_SIZE;
-use rustfs_ecstore::config::com::{read_config, save_config};
+use rustfs_ecstore::config::com::{delete_config, read_config, save_config};
+use rustfs_ecstore::disk::RUSTFS_META_BUCKET;
+use rustfs_ecstore::error::StorageError;
 use rustfs_ecstore::new_object_layer_fn;
+use rustfs_ecstore::store_api::StorageAPI;
+use rustfs_kms::backends::hsm::KeyStateStore;
 use rustfs_kms::{
-    ConfigureKmsRequest, ConfigureKmsResponse, KmsConfig, KmsConfigSummary, KmsServiceStatus, KmsStatusResponse, StartKmsRequest,
-    StartKmsResponse, StopKmsResponse, get_global_kms_service_manager,
+    ConfigureKmsRequest, ConfigureKmsResponse, KmsConfig, KmsConfigSummary, KmsError, KmsServiceStatus, KmsStatusResponse,
+    StartKmsRequest, StartKmsResponse, StopKmsResponse, get_global_kms_service_manager,
 };
 use rustfs_policy::policy::action::{Action, AdminAction};
 use s3s::{Body, S3Request, S3Response, S3Result, s3_error};
@@ -34,6 +38,9 @@ use tracing::{error, info, warn};
 /// Path to store KMS configuration in the cluster metadata
 const KMS_CONFIG_PATH: &str = "config/kms_config.json";
 
+/// Prefix of the key state kept for the KMIP and PKCS#11 backends in the cluster metadata
+const KMS_KEY_STATE_PREFIX: &str = "config/kms/keys/";
+
 /// Save KMS configuration to cluster storage
 async fn save_kms_config(config: &KmsConfig) -> Result<(), String> {
     let Some(store) = new_object_layer_fn() else {
@@ -80,6 +87,93 @@ pub async fn load_kms_config() -> Option<KmsConfig> {
     }
 }
 
+/// Keeps KMIP and PKCS#11 key state in the cluster metadata so every node sees the same keys
+pub struct ClusterKeyStateStore;
+
+impl ClusterKeyStateStore {
+    fn path(key_id: &str) -> String {
+        format!("{KMS_KEY_STATE_PREFIX}{key_id}.json")
+    }
+}
+
+fn key_state_error(op: &str, e: impl std::fmt::Display) -> KmsError {
+    KmsError::backend_error(format!("Failed to {op} KMS key state: {e}"))
+}
+
+#[async_trait::async_trait]
+impl KeyStateStore for ClusterKeyStateStore {
+    async fn load(&self, key_id: &str) -> rustfs_kms::Result<Option<Vec<u8>>> {
+        let Some(store) = new_object_layer_fn() else {
+            return Err(key_state_error("load", "storage layer not initialized"));
+        };
+
+        match read_config(store, &Self::path(key_id)).await {
+            Ok(data) => Ok(Some(data)),
+            Err(StorageError::ConfigNotFound) => Ok(None),
+            Err(e) => Err(key_state_error("load", e)),
+        }
+    }
+
+    async fn store(&self, key_id: &str, data: Vec<u8>) -> rustfs_kms::Result<()> {
+        let Some(store) = new_object_layer_fn() else {
+            return Err(key_state_error("store", "storage layer not initialized"));
+        };
+
+        save_config(store, &Self::path(key_id), data)
+            .await
+            .map_err(|e| key_state_error("store", e))
+    }
+
+    async fn remove(&self, key_id: &str) -> rustfs_kms::Result<()> {
+        let Some(store) = new_object_layer_fn() else {
+            return Err(key_state_error("remove", "storage layer not initialized"));
+        };
+
+        match delete_config(store, &Self::path(key_id)).await {
+            Ok(()) | Err(StorageError::ConfigNotFound) => Ok(()),
+            Err(e) => Err(key_state_error("remove", e)),
+        }
+    }
+
+    async fn list(&self) -> rustfs_kms::Result<Vec<String>> {
+        let Some(store) = new_object_layer_fn() else {
+            return Err(key_state_error("list", "storage layer not initialized"));
+        };
+
+        let mut key_ids = Vec::new();
+        let mut continuation_token = None;
+        loop {
+            let page = store
+                .clone()
+                .list_objects_v2(
+                    RUSTFS_META_BUCKET,
+                    KMS_KEY_STATE_PREFIX,
+                    continuation_token,
+                    None,
+                    1000,
+                    false,
+                    None,
+                    false,
+                )
+                .await
+                .map_err(|e| key_state_error("list", e))?;
+
+            key_ids.extend(page.objects.iter().filter_map(|object| {
+                object
+                    .name
+                    .strip_prefix(KMS_KEY_STATE_PREFIX)
+                    .and_then(|name| name.strip_suffix(".json"))
+                    .map(str::to_string)
+            }));
+
+            if !page.is_truncated || page.next_continuation_token.is_none() {
+                return Ok(key_ids);
+            }
+            continuation_token = page.next_continuation_token;
+        }
+    }
+}
+
 pub fn register_kms_dynamic_route(r: &mut S3Router<AdminOperation>) -> std::io::Result<()> {
     r.insert(
         Method::POST,
@@ -80,6 +80,12 @@ pub fn register_kms_key_route(r: &mut S3Router<AdminOperation>) -> std::io::Resu
         AdminOperation(&CancelKmsKeyDeletionHandler {}),
     )?;
 
+    r.insert(
+        Method::POST,
+        format!("{}{}", ADMIN_PREFIX, "/v3/kms/keys/rotate").as_str(),
+        AdminOperation(&RotateKmsKeyHandler {}),
+    )?;
+
     r.insert(
         Method::GET,
         format!("{}{}", ADMIN_PREFIX, "/v3/kms/keys").as_str(),
@@ -498,6 +504,139 @@ impl Operation for CancelKmsKeyDeletionHandler {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RotateKmsKeyRequest {
+    pub key_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RotateKmsKeyResponse {
+    pub success: bool,
+    pub message: String,
+    pub key_id: String,
+    pub key_version: Option<u32>,
+}
+
+/// Rotate a KMS key to a new version, keeping previous versions for decryption
+pub struct RotateKmsKeyHandler;
+
+#[async_trait::async_trait]
+impl Operation for RotateKmsKeyHandler {
+    async fn call(&self, mut req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
+        let Some(cred) = req.credentials else {
+            return Err(s3_error!(InvalidRequest, "authentication required"));
+        };
+
+        let (cred, owner) =
+            check_key_valid(get_session_token(&req.uri, &req.headers).unwrap_or_default(), &cred.access_key).await?;
+
+        validate_admin_request(
+            &req.headers,
+            &cred,
+            owner,
+            false,
+            vec![Action::AdminAction(AdminAction::ServerInfoAdminAction)],
+            req.extensions.get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0)),
+        )
+        .await?;
+
+        let body = req
+            .input
+            .store_all_limited(MAX_ADMIN_REQUEST_BODY_SIZE)
+            .await
+            .map_err(|e| s3_error!(InvalidRequest, "failed to read request body: {}", e))?;
+
+        let request: RotateKmsKeyRequest = if body.is_empty() {
+            let query_params = extract_query_params(&req.uri);
+            let Some(key_id) = query_params.get("keyId") else {
+                let response = RotateKmsKeyResponse {
+                    success: false,
+                    message: "missing keyId parameter".to_string(),
+                    key_id: "".to_string(),
+                    key_version: None,
+                };
+                let data =
+                    serde_json::to_vec(&response).map_err(|e| s3_error!(InternalError, "failed to serialize response: {}", e))?;
+                let mut headers = HeaderMap::new();
+                headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+                return Ok(S3Response::with_headers((StatusCode::BAD_REQUEST, Body::from(data)), headers));
+            };
+            RotateKmsKeyRequest { key_id: key_id.clone() }
+        } else {
+            serde_json::from_slice(&body).map_err(|e| s3_error!(InvalidRequest, "invalid JSON: {}", e))?
+        };
+
+        let Some(service_manager) = get_global_kms_service_manager() else {
+            let response = RotateKmsKeyResponse {
+                success: false,
+                message: "KMS service manager not initialized".to_string(),
+                key_id: request.key_id,
+                key_version: None,
+            };
+            let data =
+                serde_json::to_vec(&response).map_err(|e| s3_error!(InternalError, "failed to serialize response: {}", e))?;
+            let mut headers = HeaderMap::new();
+            headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+            return Ok(S3Response::with_headers((StatusCode::SERVICE_UNAVAILABLE, Body::from(data)), headers));
+        };
+
+        let Some(manager) = service_manager.get_manager().await else {
+            let response = RotateKmsKeyResponse {
+                success: false,
+                message: "KMS service not running".to_string(),
+                key_id: request.key_id,
+                key_version: None,
+            };
+            let data =
+                serde_json::to_vec(&response).map_err(|e| s3_error!(InternalError, "failed to serialize response: {}", e))?;
+            let mut headers = HeaderMap::new();
+            headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+            return Ok(S3Response::with_headers((StatusCode::SERVICE_UNAVAILABLE, Body::from(data)), headers));
+        };
+
+        let kms_request = RotateKeyRequest {
+            key_id: request.key_id.clone(),
+        };
+
+        match manager.rotate_key(kms_request).await {
+            Ok(kms_response) => {
+                info!("Rotated KMS key {} to version {}", kms_response.key_id, kms_response.key_version);
+                let response = RotateKmsKeyResponse {
+                    success: true,
+                    message: "Key rotated successfully".to_string(),
+                    key_id: kms_response.key_id,
+                    key_version: Some(kms_response.key_version),
+                };
+
+                let data =
+                    serde_json::to_vec(&response).map_err(|e| s3_error!(InternalError, "failed to serialize response: {}", e))?;
+
+                let mut headers = HeaderMap::new();
+                headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+
+                Ok(S3Response::with_headers((StatusCode::OK, Body::from(data)), headers))
+            }
+            Err(e) => {
+                error!("Failed to rotate KMS key {}: {}", request.key_id, e);
+                let response = RotateKmsKeyResponse {
+                    success: false,
+                    message: format!("Failed to rotate key: {e}"),
+                    key_id: request.key_id,
+                    key_version: None,
+                };
+
+                let data =
+                    serde_json::to_vec(&response).map_err(|e| s3_error!(InternalError, "failed to serialize response: {}", e))?;
+
+                let mut headers = HeaderMap::new();
+                headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+
+                Ok(S3Response::with_headers((StatusCode::INTERNAL_SERVER_ERROR, Body::from(data)), headers))
+            }
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ListKmsKeysResponse {
     pub success: bool,
@@ -0,0 +1,389 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! KMS data key re-wrap admin API handlers
+//!
+//! After a master key rotation, objects keep their data keys sealed under the
+//! version that was current when they were written. The re-wrap job walks
+//! SSE-S3/SSE-KMS objects and re-seals their data keys under the latest master
+//! key version, updating only object metadata.
+
+use crate::admin::auth::validate_admin_request;
+use crate::admin::router::{AdminOperation, Operation, S3Router};
+use crate::auth::{check_key_valid, get_session_token};
+use crate::server::{ADMIN_PREFIX, RemoteAddr};
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64_STANDARD};
+use hyper::{HeaderMap, Method, StatusCode};
+use matchit::Params;
+use rustfs_config::MAX_ADMIN_REQUEST_BODY_SIZE;
+use rustfs_ecstore::error::StorageError;
+use rustfs_ecstore::new_object_layer_fn;
+use rustfs_ecstore::store::ECStore;
+use rustfs_ecstore::store_api::{BucketOptions, HTTPPreconditions, ObjectOptions, StorageAPI};
+use rustfs_kms::{ObjectEncryptionService, get_global_encryption_service, types::RewrapDataKeyRequest};
+use rustfs_policy::policy::action::{Action, AdminAction};
+use s3s::header::CONTENT_TYPE;
+use s3s::{Body, S3Request, S3Response, S3Result, s3_error};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+use time::OffsetDateTime;
+use tracing::{info, warn};
+
+const ENCRYPTION_KEY_HEADER: &str = "x-rustfs-encryption-key";
+const ENCRYPTION_KEY_VERSION_HEADER: &str = "x-rustfs-encryption-key-version";
+const KMS_KEY_ID_HEADER: &str = "x-amz-server-side-encryption-aws-kms-key-id";
+const SSEC_ALGORITHM_HEADER: &str = "x-amz-server-side-encryption-customer-algorithm";
+const REWRAP_LIST_PAGE_SIZE: i32 = 1000;
+
+static REWRAP_STATUS: LazyLock<RwLock<RewrapStatus>> = LazyLock::new(|| RwLock::new(RewrapStatus::default()));
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RewrapState {
+    #[default]
+    Idle,
+    Running,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RewrapStatus {
+    pub state: RewrapState,
+    pub bucket: Option<String>,
+    pub key_id: Option<String>,
+    pub scanned: u64,
+    pub rewrapped: u64,
+    pub skipped: u64,
+    pub failed: u64,
+    pub last_error: Option<String>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub started_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub finished_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct StartRewrapRequest {
+    /// Restrict the job to a single bucket
+    pub bucket: Option<String>,
+    /// Restrict the job to objects encrypted under this master key
+    pub key_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StartRewrapResponse {
+    pub success: bool,
+    pub message: String,
+    pub status: RewrapStatus,
+}
+
+pub fn register_kms_rewrap_route(r: &mut S3Router<AdminOperation>) -> std::io::Result<()> {
+    r.insert(
+        Method::POST,
+        format!("{}{}", ADMIN_PREFIX, "/v3/kms/rewrap").as_str(),
+        AdminOperation(&StartRewrapHandler {}),
+    )?;
+
+    r.insert(
+        Method::GET,
+        format!("{}{}", ADMIN_PREFIX, "/v3/kms/rewrap/status").as_str(),
+        AdminOperation(&RewrapStatusHandler {}),
+    )?;
+
+    Ok(())
+}
+
+fn current_status() -> RewrapStatus {
+    REWRAP_STATUS.read().map(|status| status.clone()).unwrap_or_default()
+}
+
+fn update_status(f: impl FnOnce(&mut RewrapStatus)) {
+    if let Ok(mut status) = REWRAP_STATUS.write() {
+        f(&mut status);
+    }
+}
+
+fn json_response<T: Serialize>(status: StatusCode, body: &T) -> S3Result<S3Response<(StatusCode, Body)>> {
+    let data = serde_json::to_vec(body).map_err(|e| s3_error!(InternalError, "failed to serialize response: {}", e))?;
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+    Ok(S3Response::with_headers((status, Body::from(data)), headers))
+}
+
+/// Start a background job re-wrapping object data keys under the latest master key versions
+pub struct StartRewrapHandler;
+
+#[async_trait::async_trait]
+impl Operation for StartRewrapHandler {
+    async fn call(&self, mut req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
+        let Some(cred) = req.credentials else {
+            return Err(s3_error!(InvalidRequest, "authentication required"));
+        };
+
+        let (cred, owner) =
+            check_key_valid(get_session_token(&req.uri, &req.headers).unwrap_or_default(), &cred.access_key).await?;
+
+        validate_admin_request(
+            &req.headers,
+            &cred,
+            owner,
+            false,
+            vec![Action::AdminAction(AdminAction::ServerInfoAdminAction)],
+            req.extensions.get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0)),
+        )
+        .await?;
+
+        let body = req
+            .input
+            .store_all_limited(MAX_ADMIN_REQUEST_BODY_SIZE)
+            .await
+            .map_err(|e| s3_error!(InvalidRequest, "failed to read request body: {}", e))?;
+
+        let request: StartRewrapRequest = if body.is_empty() {
+            let query_params = super::kms::extract_query_params(&req.uri);
+            StartRewrapRequest {
+                bucket: query_params.get("bucket").cloned(),
+                key_id: query_params.get("keyId").cloned(),
+            }
+        } else {
+            serde_json::from_slice(&body).map_err(|e| s3_error!(InvalidRequest, "invalid JSON: {}", e))?
+        };
+
+        let Some(service) = get_global_encryption_service().await else {
+            let response = StartRewrapResponse {
+                success: false,
+                message: "KMS service not running".to_string(),
+                status: current_status(),
+            };
+            return json_response(StatusCode::SERVICE_UNAVAILABLE, &response);
+        };
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(s3_error!(InternalError, "Not init"));
+        };
+
+        let started = {
+            let mut status = REWRAP_STATUS
+                .write()
+                .map_err(|_| s3_error!(InternalError, "re-wrap status lock poisoned"))?;
+            if status.state == RewrapState::Running {
+                false
+            } else {
+                *status = RewrapStatus {
+                    state: RewrapState::Running,
+                    bucket: request.bucket.clone(),
+                    key_id: request.key_id.clone(),
+                    started_at: Some(OffsetDateTime::now_utc()),
+                    ..Default::default()
+                };
+                true
+            }
+        };
+
+        if !started {
+            let response = StartRewrapResponse {
+                success: false,
+                message: "a re-wrap job is already running".to_string(),
+                status: current_status(),
+            };
+            return json_response(StatusCode::CONFLICT, &response);
+        }
+
+        info!(
+            "Starting KMS data key re-wrap job (bucket: {:?}, key: {:?})",
+            request.bucket, request.key_id
+        );
+        tokio::spawn(async move {
+            let result = run_rewrap(store, service, request).await;
+            update_status(|status| {
+                status.finished_at = Some(OffsetDateTime::now_utc());
+                match result {
+                    Ok(()) => status.state = RewrapState::Completed,
+                    Err(e) => {
+                        status.state = RewrapState::Failed;
+                        status.last_error = Some(e);
+                    }
+                }
+            });
+            let status = current_status();
+            info!(
+                "KMS data key re-wrap job finished: scanned {}, rewrapped {}, skipped {}, failed {}",
+                status.scanned, status.rewrapped, status.skipped, status.failed
+            );
+        });
+
+        let response = StartRewrapResponse {
+            success: true,
+            message: "Re-wrap job started".to_string(),
+            status: current_status(),
+        };
+        json_response(StatusCode::OK, &response)
+    }
+}
+
+/// Report the progress of the current or last re-wrap job
+pub struct RewrapStatusHandler;
+
+#[async_trait::async_trait]
+impl Operation for RewrapStatusHandler {
+    async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
+        let Some(cred) = req.credentials else {
+            return Err(s3_error!(InvalidRequest, "authentication required"));
+        };
+
+        let (cred, owner) =
+            check_key_valid(get_session_token(&req.uri, &req.headers).unwrap_or_default(), &cred.access_key).await?;
+
+        validate_admin_request(
+            &req.headers,
+            &cred,
+            owner,
+            false,
+            vec![Action::AdminAction(AdminAction::ServerInfoAdminAction)],
+            req.extensions.get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0)),
+        )
+        .await?;
+
+        json_response(StatusCode::OK, &current_status())
+    }
+}
+
+async fn run_rewrap(
+    store: Arc<ECStore>,
+    service: Arc<ObjectEncryptionService>,
+    request: StartRewrapRequest,
+) -> Result<(), String> {
+    let buckets = match &request.bucket {
+        Some(bucket) => vec![bucket.clone()],
+        None => store
+            .list_bucket(&BucketOptions::default())
+            .await
+            .map_err(|e| format!("failed to list buckets: {e}"))?
+            .into_iter()
+            .map(|bucket| bucket.name)
+            .collect(),
+    };
+
+    for bucket in buckets {
+        let mut marker = None;
+        let mut version_marker = None;
+
+        loop {
+            let page = store
+                .clone()
+                .list_object_versions(&bucket, "", marker.clone(), version_marker.clone(), None, REWRAP_LIST_PAGE_SIZE)
+                .await
+                .map_err(|e| format!("failed to list objects in {bucket}: {e}"))?;
+
+            for object in page.objects {
+                update_status(|status| status.scanned += 1);
+
+                let Some(sealed_key) = object.user_defined.get(ENCRYPTION_KEY_HEADER) else {
+                    update_status(|status| status.skipped += 1);
+                    continue;
+                };
+                if object.delete_marker || object.user_defined.contains_key(SSEC_ALGORITHM_HEADER) {
+                    update_status(|status| status.skipped += 1);
+                    continue;
+                }
+                if let Some(key_id) = &request.key_id
+                    && object.user_defined.get(KMS_KEY_ID_HEADER) != Some(key_id)
+                {
+                    update_status(|status| status.skipped += 1);
+                    continue;
+                }
+
+                let version_id = object.version_id.map(|v| v.to_string());
+                match rewrap_object(
+                    store.as_ref(),
+                    &service,
+                    &bucket,
+                    &object.name,
+                    version_id,
+                    object.etag.clone(),
+                    sealed_key,
+                )
+                .await
+                {
+                    Ok(true) => update_status(|status| status.rewrapped += 1),
+                    Ok(false) => update_status(|status| status.skipped += 1),
+                    Err(e) => {
+                        warn!("Failed to re-wrap data key of {}/{}: {}", bucket, object.name, e);
+                        update_status(|status| {
+                            status.failed += 1;
+                            status.last_error = Some(format!("{bucket}/{}: {e}", object.name));
+                        });
+                    }
+                }
+            }
+
+            if !page.is_truncated {
+                break;
+            }
+            marker = page.next_marker;
+            version_marker = page.next_version_idmarker;
+        }
+    }
+
+    Ok(())
+}
+
+async fn rewrap_object(
+    store: &ECStore,
+    service: &ObjectEncryptionService,
+    bucket: &str,
+    object: &str,
+    version_id: Option<String>,
+    etag: Option<String>,
+    sealed_key: &str,
+) -> Result<bool, String> {
+    // Without an etag the update could not be made conditional on the version we read
+    let Some(etag) = etag else {
+        return Ok(false);
+    };
+
+    let ciphertext_blob = BASE64_STANDARD
+        .decode(sealed_key)
+        .map_err(|e| format!("invalid sealed data key: {e}"))?;
+
+    let response = service
+        .rewrap_data_key(RewrapDataKeyRequest { ciphertext_blob })
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.rewrapped {
+        return Ok(false);
+    }
+
+    let eval_metadata = HashMap::from([
+        (ENCRYPTION_KEY_HEADER.to_string(), BASE64_STANDARD.encode(&response.ciphertext_blob)),
+        (ENCRYPTION_KEY_VERSION_HEADER.to_string(), response.key_version.to_string()),
+    ]);
+    let opts = ObjectOptions {
+        version_id,
+        eval_metadata: Some(eval_metadata),
+        http_preconditions: Some(HTTPPreconditions {
+            if_match: Some(etag),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    match store.put_object_metadata(bucket, object, &opts).await {
+        Ok(_) => Ok(true),
+        // Overwritten since it was listed; the new data key is already sealed under the latest version
+        Err(StorageError::PreconditionFailed) => Ok(false),
+        Err(e) => Err(e.to_string()),
+    }
+}
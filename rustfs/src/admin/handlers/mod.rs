@@ -18,11 +18,13 @@ pub mod event;
 pub mod group;
 pub mod heal;
 pub mod health;
+pub mod idp_ldap;
 pub mod is_admin;
 pub mod kms;
 pub mod kms_dynamic;
 pub mod kms_keys;
 pub mod kms_management;
+pub mod kms_rewrap;
 pub mod metrics;
 pub mod policies;
 pub mod pools;
@@ -12,17 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::admin::auth::validate_admin_request;
 use crate::admin::router::{AdminOperation, Operation, S3Router};
-use crate::server::ADMIN_PREFIX;
+use crate::auth::{check_key_valid, get_session_token};
+use crate::server::{ADMIN_PREFIX, RemoteAddr};
 use http::{HeaderMap, HeaderValue, Uri};
 use hyper::{Method, StatusCode};
 use matchit::Params;
-use s3s::header::CONTENT_TYPE;
-use s3s::{Body, S3Request, S3Response, S3Result};
+use rustfs_common::GLOBAL_LOCAL_NODE_NAME;
+use rustfs_ecstore::notification_sys::get_global_notification_sys;
+use rustfs_policy::policy::action::{Action, AdminAction};
+use rustfs_zip::{CompressionLevel, create_zip_bytes};
+use s3s::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use s3s::{Body, S3Error, S3ErrorCode, S3Request, S3Response, S3Result, s3_error};
 use std::collections::HashMap;
-use tracing::error;
+use std::time::Duration;
+use tracing::{error, warn};
+
+const DEFAULT_CLUSTER_PROFILE_SECONDS: u64 = 10;
+const MAX_CLUSTER_PROFILE_SECONDS: u64 = 300;
 
-#[allow(dead_code)]
 fn extract_query_params(uri: &Uri) -> HashMap<String, String> {
     let mut params = HashMap::new();
 
@@ -48,9 +57,116 @@ pub fn register_profiling_route(r: &mut S3Router<AdminOperation>) -> std::io::Re
         AdminOperation(&ProfileStatusHandler {}),
     )?;
 
+    r.insert(
+        Method::POST,
+        format!("{}{}", ADMIN_PREFIX, "/v3/profile").as_str(),
+        AdminOperation(&ClusterProfileHandler {}),
+    )?;
+
     Ok(())
 }
 
+/// Parse the `duration` query parameter of a cluster profiling request, in seconds
+fn cluster_profile_duration(queries: &HashMap<String, String>) -> S3Result<Duration> {
+    let seconds = match queries.get("duration") {
+        Some(duration) => duration
+            .parse::<u64>()
+            .map_err(|_| s3_error!(InvalidArgument, "invalid profile duration: {}", duration))?,
+        None => DEFAULT_CLUSTER_PROFILE_SECONDS,
+    };
+    if seconds == 0 || seconds > MAX_CLUSTER_PROFILE_SECONDS {
+        return Err(s3_error!(
+            InvalidArgument,
+            "Profile duration must be between 1 and {} seconds",
+            MAX_CLUSTER_PROFILE_SECONDS
+        ));
+    }
+    Ok(Duration::from_secs(seconds))
+}
+
+//awscurl --service s3 --region us-east-1 --access_key rustfsadmin --secret_key rustfsadmin -X POST "http://:9000/rustfs/admin/v3/profile?profilerType=cpu,mem&duration=30"
+/// Profile every node of the cluster for `duration` seconds and return the profiles as a zip archive.
+pub struct ClusterProfileHandler {}
+
+#[async_trait::async_trait]
+impl Operation for ClusterProfileHandler {
+    async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
+        let Some(input_cred) = &req.credentials else {
+            return Err(s3_error!(InvalidRequest, "get cred failed"));
+        };
+
+        let (cred, owner) =
+            check_key_valid(get_session_token(&req.uri, &req.headers).unwrap_or_default(), &input_cred.access_key).await?;
+
+        let remote_addr = req.extensions.get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0));
+        validate_admin_request(
+            &req.headers,
+            &cred,
+            owner,
+            false,
+            vec![Action::AdminAction(AdminAction::ProfilingAdminAction)],
+            remote_addr,
+        )
+        .await?;
+
+        let queries = extract_query_params(&req.uri);
+        let Some(profilers) = queries.get("profilerType").filter(|p| !p.is_empty()) else {
+            return Err(s3_error!(InvalidArgument, "profilerType is required"));
+        };
+        let duration = cluster_profile_duration(&queries)?;
+
+        crate::profiling::start_profiling_session(profilers)
+            .await
+            .map_err(|e| S3Error::with_message(S3ErrorCode::InvalidArgument, e))?;
+
+        let notification_sys = get_global_notification_sys();
+        if let Some(notification_sys) = &notification_sys {
+            for peer_err in notification_sys.start_profiling(profilers).await {
+                if let Some(err) = peer_err.err {
+                    warn!("start profiling on peer {} failed: {}", peer_err.host, err);
+                }
+            }
+        }
+
+        tokio::time::sleep(duration).await;
+
+        let mut profiles = HashMap::new();
+        match crate::profiling::download_profiling_session().await {
+            Ok(data) => {
+                profiles.insert(GLOBAL_LOCAL_NODE_NAME.read().await.clone(), data);
+            }
+            Err(err) => warn!("download local profile data failed: {}", err),
+        }
+        if let Some(notification_sys) = &notification_sys {
+            profiles.extend(notification_sys.download_profile_data().await);
+        }
+
+        let files: Vec<(String, Vec<u8>)> = profiles
+            .into_iter()
+            .flat_map(|(host, data)| {
+                data.into_iter()
+                    .map(move |(profiler, bytes)| (format!("profile-{host}-{profiler}.pprof"), bytes))
+            })
+            .collect();
+        if files.is_empty() {
+            return Err(S3Error::with_message(
+                S3ErrorCode::InternalError,
+                "no profile data was collected".to_string(),
+            ));
+        }
+
+        let archive = tokio::task::spawn_blocking(move || create_zip_bytes(files, CompressionLevel::Default))
+            .await
+            .map_err(|e| S3Error::with_message(S3ErrorCode::InternalError, e.to_string()))?
+            .map_err(|e| S3Error::with_message(S3ErrorCode::InternalError, format!("zip profile data failed: {e}")))?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/zip"));
+        headers.insert(CONTENT_DISPOSITION, HeaderValue::from_static("attachment; filename=profile.zip"));
+        Ok(S3Response::with_headers((StatusCode::OK, Body::from(archive)), headers))
+    }
+}
+
 pub struct ProfileHandler {}
 
 #[async_trait::async_trait]
@@ -204,8 +320,10 @@ impl Operation for ProfileStatusHandler {
 
 #[cfg(test)]
 mod tests {
-    use super::extract_query_params;
+    use super::{MAX_CLUSTER_PROFILE_SECONDS, cluster_profile_duration, extract_query_params};
     use http::Uri;
+    use std::collections::HashMap;
+    use std::time::Duration;
 
     #[test]
     fn test_extract_query_params_decodes_percent_encoded_values() {
@@ -217,4 +335,15 @@ mod tests {
         assert_eq!(params.get("format"), Some(&"flamegraph".to_string()));
         assert_eq!(params.get("note"), Some(&"a+b value".to_string()));
     }
+
+    #[test]
+    fn test_cluster_profile_duration() {
+        let queries = |duration: &str| HashMap::from([("duration".to_string(), duration.to_string())]);
+
+        assert_eq!(cluster_profile_duration(&HashMap::new()).unwrap(), Duration::from_secs(10));
+        assert_eq!(cluster_profile_duration(&queries("60")).unwrap(), Duration::from_secs(60));
+        assert!(cluster_profile_duration(&queries("0")).is_err());
+        assert!(cluster_profile_duration(&queries(&(MAX_CLUSTER_PROFILE_SECONDS + 1).to_string())).is_err());
+        assert!(cluster_profile_duration(&queries("abc")).is_err());
+    }
 }
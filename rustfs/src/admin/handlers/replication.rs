@@ -24,13 +24,16 @@ use rustfs_config::MAX_ADMIN_REQUEST_BODY_SIZE;
 use rustfs_ecstore::bucket::bucket_target_sys::BucketTargetSys;
 use rustfs_ecstore::bucket::metadata::BUCKET_TARGETS_FILE;
 use rustfs_ecstore::bucket::metadata_sys;
+use rustfs_ecstore::bucket::replication::{BucketReplicationStats, BucketStats, GLOBAL_REPLICATION_STATS, SRMetricsSummary};
 use rustfs_ecstore::bucket::target::BucketTarget;
 use rustfs_ecstore::global::global_rustfs_port;
 use rustfs_ecstore::new_object_layer_fn;
+use rustfs_ecstore::notification_sys::get_global_notification_sys;
 use rustfs_ecstore::store_api::{BucketOptions, StorageAPI};
 use rustfs_policy::policy::action::{Action, AdminAction};
 use s3s::header::CONTENT_TYPE;
 use s3s::{Body, S3Error, S3ErrorCode, S3Request, S3Response, S3Result, s3_error};
+use serde::Serialize;
 use std::collections::HashMap;
 use tracing::{debug, error, warn};
 use url::Host;
@@ -93,17 +96,85 @@ fn is_local_host(_host: String) -> bool {
 }
 
 //awscurl --service s3 --region us-east-1 --access_key rustfsadmin --secret_key rustfsadmin "http://:9000/rustfs/admin/v3/replicationmetrics?bucket=1"
+//without a bucket the metrics of every bucket and the site summary of the whole cluster are returned
 pub struct GetReplicationMetricsHandler {}
 
+/// Replication metrics of the whole cluster, aggregated from every node
+#[derive(Debug, Default, Serialize)]
+pub struct ClusterReplicationMetrics {
+    pub buckets: HashMap<String, BucketReplicationStats>,
+    pub site: SRMetricsSummary,
+}
+
 #[async_trait::async_trait]
 impl Operation for GetReplicationMetricsHandler {
     async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
-        error!("GetReplicationMetricsHandler");
+        validate_replication_admin_request(&req, AdminAction::GetBucketTargetAction).await?;
+
         let queries = extract_query_params(&req.uri);
-        if let Some(bucket) = queries.get("bucket") {
-            error!("get bucket:{} metrics", bucket);
+        let bucket = queries.get("bucket").filter(|b| !b.is_empty());
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(S3Error::with_message(S3ErrorCode::InternalError, "Not init".to_string()));
+        };
+
+        if let Some(bucket) = bucket {
+            store
+                .get_bucket_info(bucket, &BucketOptions::default())
+                .await
+                .map_err(ApiError::from)?;
         }
-        Ok(S3Response::new((StatusCode::OK, Body::from("Ok".to_string()))))
+
+        let Some(stats) = GLOBAL_REPLICATION_STATS.get() else {
+            return Err(S3Error::with_message(
+                S3ErrorCode::InternalError,
+                "replication stats not initialized".to_string(),
+            ));
+        };
+
+        let notification_sys = get_global_notification_sys();
+        let data = if let Some(bucket) = bucket {
+            let mut bucket_stats = vec![stats.get_local_bucket_stats(bucket).await];
+            if let Some(notification_sys) = notification_sys {
+                bucket_stats.extend(notification_sys.get_cluster_bucket_stats(bucket).await);
+            }
+            let metrics = stats.calculate_bucket_replication_stats(bucket, bucket_stats).await;
+            serde_json::to_vec(&metrics.replication_stats)
+        } else {
+            let mut node_bucket_stats = vec![stats.get_all_local_bucket_stats().await];
+            let mut site = stats.get_sr_metrics_for_node().await;
+            if let Some(notification_sys) = notification_sys {
+                node_bucket_stats.extend(notification_sys.get_cluster_all_bucket_stats().await);
+                for node in notification_sys.get_cluster_site_metrics().await {
+                    site = site.merge(&node);
+                }
+            }
+
+            let mut per_bucket: HashMap<String, Vec<BucketStats>> = HashMap::new();
+            for (bucket, bucket_stats) in node_bucket_stats.into_iter().flatten() {
+                per_bucket.entry(bucket).or_default().push(bucket_stats);
+            }
+
+            let mut metrics = ClusterReplicationMetrics {
+                site,
+                ..Default::default()
+            };
+            for (bucket, bucket_stats) in per_bucket {
+                let bucket_metrics = stats.calculate_bucket_replication_stats(&bucket, bucket_stats).await;
+                metrics.buckets.insert(bucket, bucket_metrics.replication_stats);
+            }
+            serde_json::to_vec(&metrics)
+        };
+
+        let data = data.map_err(|e| {
+            error!("Serialization error: {}", e);
+            S3Error::with_message(S3ErrorCode::InternalError, "Failed to serialize replication metrics".to_string())
+        })?;
+
+        let mut header = HeaderMap::new();
+        header.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        Ok(S3Response::with_headers((StatusCode::OK, Body::from(data)), header))
     }
 }
 
@@ -16,14 +16,19 @@ use super::is_admin::IsAdminHandler;
 use crate::{
     admin::router::{AdminOperation, Operation, S3Router},
     auth::{check_key_valid, get_session_token},
-    server::ADMIN_PREFIX,
+    server::{ADMIN_PREFIX, TlsClientCert},
 };
-use http::StatusCode;
+use http::header::CONTENT_TYPE;
+use http::{HeaderMap, HeaderValue, StatusCode};
 use hyper::Method;
 use matchit::Params;
 use rustfs_config::MAX_ADMIN_REQUEST_BODY_SIZE;
 use rustfs_ecstore::bucket::utils::serialize;
-use rustfs_iam::{manager::get_token_signing_key, sys::SESSION_POLICY_NAME};
+use rustfs_iam::{
+    ldap::{LDAP_USER_CLAIM, LDAP_USERNAME_CLAIM},
+    manager::get_token_signing_key,
+    sys::{POLICYNAME, SESSION_POLICY_NAME, TLS_PARENT_PREFIX},
+};
 use rustfs_policy::{auth::get_new_credentials_with_metadata, policy::Policy};
 use s3s::{
     Body, S3Error, S3ErrorCode, S3Request, S3Response, S3Result,
@@ -34,12 +39,47 @@ use serde::Deserialize;
 use serde_json::Value;
 use serde_urlencoded::from_bytes;
 use std::collections::HashMap;
-use time::{Duration, OffsetDateTime};
+use time::{Duration, OffsetDateTime, format_description::well_known::Rfc3339};
 use tracing::{error, info, warn};
 
 const ASSUME_ROLE_ACTION: &str = "AssumeRole";
+const ASSUME_ROLE_WITH_WEB_IDENTITY_ACTION: &str = "AssumeRoleWithWebIdentity";
+const ASSUME_ROLE_WITH_CLIENT_GRANTS_ACTION: &str = "AssumeRoleWithClientGrants";
+const ASSUME_ROLE_WITH_LDAP_IDENTITY_ACTION: &str = "AssumeRoleWithLDAPIdentity";
+const ASSUME_ROLE_WITH_CERTIFICATE_ACTION: &str = "AssumeRoleWithCertificate";
 const ASSUME_ROLE_VERSION: &str = "2011-06-15";
 
+const STS_NAMESPACE: &str = "https://sts.amazonaws.com/doc/2011-06-15/";
+const MIN_DURATION_SECONDS: usize = 900;
+const MAX_DURATION_SECONDS: usize = 43200;
+const DEFAULT_DURATION_SECONDS: usize = 3600;
+
+/// Whether an STS action must be signed with existing credentials, or `None` for actions not served here.
+/// The other actions are authenticated by their web identity token, LDAP credentials or client certificate.
+pub(crate) fn sts_action_requires_signature(action: &str) -> Option<bool> {
+    match action {
+        ASSUME_ROLE_ACTION => Some(true),
+        ASSUME_ROLE_WITH_WEB_IDENTITY_ACTION
+        | ASSUME_ROLE_WITH_CLIENT_GRANTS_ACTION
+        | ASSUME_ROLE_WITH_LDAP_IDENTITY_ACTION
+        | ASSUME_ROLE_WITH_CERTIFICATE_ACTION => Some(false),
+        _ => None,
+    }
+}
+
+/// Action of an STS request, from the form body or, when the body is empty, the query string
+pub(crate) fn sts_request_action(body: &[u8], query: Option<&str>) -> Option<String> {
+    let params = if body.is_empty() {
+        query.unwrap_or_default().as_bytes()
+    } else {
+        body
+    };
+    from_bytes::<Vec<(String, String)>>(params)
+        .ok()?
+        .into_iter()
+        .find_map(|(key, value)| (key == "Action").then_some(value))
+}
+
 pub fn register_admin_auth_route(r: &mut S3Router<AdminOperation>) -> std::io::Result<()> {
     r.insert(Method::POST, "/", AdminOperation(&AssumeRoleHandle {}))?;
 
@@ -62,6 +102,12 @@ pub struct AssumeRoleRequest {
     pub role_session_name: String,
     pub policy: String,
     pub external_id: String,
+    pub web_identity_token: String,
+    pub token: String,
+    #[serde(rename = "LDAPUsername")]
+    pub ldap_username: String,
+    #[serde(rename = "LDAPPassword")]
+    pub ldap_password: String,
 }
 
 pub struct AssumeRoleHandle {}
@@ -70,6 +116,37 @@ impl Operation for AssumeRoleHandle {
     async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
         warn!("handle AssumeRoleHandle");
 
+        let mut input = req.input;
+
+        let bytes = match input.store_all_limited(MAX_ADMIN_REQUEST_BODY_SIZE).await {
+            Ok(b) => b,
+            Err(e) => {
+                warn!("get body failed, e: {:?}", e);
+                return Err(s3_error!(InvalidRequest, "STS request body too large or failed to read"));
+            }
+        };
+
+        // Some clients send STS parameters in the query string of an empty POST
+        let body: AssumeRoleRequest = if bytes.is_empty() {
+            from_bytes(req.uri.query().unwrap_or_default().as_bytes())
+        } else {
+            from_bytes(&bytes)
+        }
+        .map_err(|_e| s3_error!(InvalidRequest, "invalid STS request format"))?;
+
+        match body.action.as_str() {
+            ASSUME_ROLE_ACTION => {}
+            ASSUME_ROLE_WITH_WEB_IDENTITY_ACTION | ASSUME_ROLE_WITH_CLIENT_GRANTS_ACTION => {
+                return assume_role_with_openid(&body).await;
+            }
+            ASSUME_ROLE_WITH_LDAP_IDENTITY_ACTION => return assume_role_with_ldap(&body).await,
+            ASSUME_ROLE_WITH_CERTIFICATE_ACTION => {
+                let client_cert = req.extensions.get::<Option<TlsClientCert>>().cloned().flatten();
+                return assume_role_with_certificate(&body, client_cert).await;
+            }
+            _ => return Err(s3_error!(InvalidArgument, "not support action")),
+        }
+
         let Some(user) = req.credentials else { return Err(s3_error!(InvalidRequest, "get cred failed")) };
 
         let session_token = get_session_token(&req.uri, &req.headers);
@@ -85,22 +162,6 @@ impl Operation for AssumeRoleHandle {
             return Err(s3_error!(InvalidRequest, "AccessDenied"));
         }
 
-        let mut input = req.input;
-
-        let bytes = match input.store_all_limited(MAX_ADMIN_REQUEST_BODY_SIZE).await {
-            Ok(b) => b,
-            Err(e) => {
-                warn!("get body failed, e: {:?}", e);
-                return Err(s3_error!(InvalidRequest, "STS request body too large or failed to read"));
-            }
-        };
-
-        let body: AssumeRoleRequest = from_bytes(&bytes).map_err(|_e| s3_error!(InvalidRequest, "invalid STS request format"))?;
-
-        if body.action.as_str() != ASSUME_ROLE_ACTION {
-            return Err(s3_error!(InvalidArgument, "not support action"));
-        }
-
         if body.version.as_str() != ASSUME_ROLE_VERSION {
             return Err(s3_error!(InvalidArgument, "not support version"));
         }
@@ -124,6 +185,10 @@ impl Operation for AssumeRoleHandle {
 
         claims.insert("parent".to_string(), Value::String(cred.access_key.clone()));
 
+        if !body.role_session_name.is_empty() {
+            claims.insert("roleSessionName".to_string(), Value::String(body.role_session_name.clone()));
+        }
+
         // warn!("AssumeRole get cred {:?}", &user);
         // warn!("AssumeRole get body {:?}", &body);
 
@@ -179,6 +244,328 @@ impl Operation for AssumeRoleHandle {
     }
 }
 
+/// AssumeRoleWithWebIdentity / AssumeRoleWithClientGrants: exchange a token of a configured OpenID provider
+/// for temporary credentials
+async fn assume_role_with_openid(body: &AssumeRoleRequest) -> S3Result<S3Response<(StatusCode, Body)>> {
+    let (token, subject_element) = if body.action == ASSUME_ROLE_WITH_WEB_IDENTITY_ACTION {
+        (&body.web_identity_token, "SubjectFromWebIdentityToken")
+    } else {
+        (&body.token, "SubjectFromToken")
+    };
+
+    if body.version.as_str() != ASSUME_ROLE_VERSION {
+        return Err(s3_error!(InvalidArgument, "not support version"));
+    }
+
+    if token.is_empty() {
+        return Err(s3_error!(InvalidArgument, "missing token"));
+    }
+
+    let duration = sts_duration_seconds(body.duration_seconds)?;
+
+    let Ok(iam_store) = rustfs_iam::get() else {
+        return Err(s3_error!(InvalidRequest, "iam not init"));
+    };
+
+    if !iam_store.openid().is_enabled() {
+        return Err(s3_error!(InvalidRequest, "no OpenID provider is configured"));
+    }
+
+    let role_arn = (!body.role_arn.is_empty()).then_some(body.role_arn.as_str());
+    let identity = iam_store.openid().authenticate(token, role_arn).await.map_err(|e| {
+        warn!("{} token validation failed: {}", body.action, e);
+        s3_error!(AccessDenied, "{}", e)
+    })?;
+
+    let parent_user = identity.parent_user();
+
+    let mut claims = HashMap::new();
+    claims.insert(
+        "exp".to_string(),
+        Value::Number(serde_json::Number::from(OffsetDateTime::now_utc().unix_timestamp() + duration as i64)),
+    );
+    claims.insert("parent".to_string(), Value::String(parent_user.clone()));
+    claims.insert("sub".to_string(), Value::String(identity.subject.clone()));
+    claims.insert("iss".to_string(), Value::String(identity.issuer.clone()));
+    claims.insert("aud".to_string(), Value::String(identity.audience.clone()));
+    match &identity.role_arn {
+        Some(role_arn) => {
+            claims.insert("roleArn".to_string(), Value::String(role_arn.clone()));
+        }
+        None => {
+            claims.insert(POLICYNAME.to_string(), Value::String(identity.policies.join(",")));
+        }
+    }
+
+    populate_session_policy(&mut claims, &body.policy)?;
+
+    let Some(secret) = get_token_signing_key() else {
+        return Err(s3_error!(InvalidArgument, "global active sk not init"));
+    };
+
+    let mut new_cred = get_new_credentials_with_metadata(&claims, &secret)
+        .map_err(|e| S3Error::with_message(S3ErrorCode::InternalError, format!("get new cred failed {e}")))?;
+
+    new_cred.parent_user = parent_user;
+
+    info!("{} issued temporary credentials for {}", body.action, new_cred.parent_user);
+
+    if let Err(_err) = iam_store.set_temp_user(&new_cred.access_key, &new_cred, None).await {
+        return Err(s3_error!(InternalError, "set_temp_user failed"));
+    }
+
+    let output = sts_credentials_response(
+        &body.action,
+        &new_cred,
+        &[
+            (subject_element, identity.subject.as_str()),
+            ("Audience", identity.audience.as_str()),
+        ],
+        &[("Provider", identity.issuer.as_str())],
+    );
+
+    let mut header = HeaderMap::new();
+    header.insert(CONTENT_TYPE, HeaderValue::from_static("text/xml"));
+    Ok(S3Response::with_headers((StatusCode::OK, Body::from(output)), header))
+}
+
+/// AssumeRoleWithLDAPIdentity: exchange LDAP credentials for temporary credentials carrying the policies
+/// mapped to the user DN and its group DNs
+async fn assume_role_with_ldap(body: &AssumeRoleRequest) -> S3Result<S3Response<(StatusCode, Body)>> {
+    if body.version.as_str() != ASSUME_ROLE_VERSION {
+        return Err(s3_error!(InvalidArgument, "not support version"));
+    }
+
+    if body.ldap_username.is_empty() || body.ldap_password.is_empty() {
+        return Err(s3_error!(InvalidArgument, "LDAPUsername and LDAPPassword are required"));
+    }
+
+    let duration = sts_duration_seconds(body.duration_seconds)?;
+
+    let Ok(iam_store) = rustfs_iam::get() else {
+        return Err(s3_error!(InvalidRequest, "iam not init"));
+    };
+
+    if !iam_store.ldap().is_enabled() {
+        return Err(s3_error!(InvalidRequest, "LDAP identity is not configured"));
+    }
+
+    let identity = iam_store
+        .ldap()
+        .authenticate(&body.ldap_username, &body.ldap_password)
+        .await
+        .map_err(|e| {
+            warn!("{} failed for {}: {}", body.action, body.ldap_username, e);
+            s3_error!(AccessDenied, "LDAP authentication failed")
+        })?;
+
+    let groups = Some(identity.groups.clone());
+    let policies = iam_store
+        .policy_db_get_ldap(&identity.user_dn, &groups)
+        .await
+        .map_err(|e| S3Error::with_message(S3ErrorCode::InternalError, format!("load LDAP policy mappings failed {e}")))?;
+    if policies.is_empty() {
+        return Err(s3_error!(AccessDenied, "no policy is mapped to the LDAP user or its groups"));
+    }
+
+    let mut claims = HashMap::new();
+    claims.insert(
+        "exp".to_string(),
+        Value::Number(serde_json::Number::from(OffsetDateTime::now_utc().unix_timestamp() + duration as i64)),
+    );
+    claims.insert("parent".to_string(), Value::String(identity.user_dn.clone()));
+    claims.insert(LDAP_USER_CLAIM.to_string(), Value::String(identity.user_dn.clone()));
+    claims.insert(LDAP_USERNAME_CLAIM.to_string(), Value::String(identity.username.clone()));
+
+    populate_session_policy(&mut claims, &body.policy)?;
+
+    let Some(secret) = get_token_signing_key() else {
+        return Err(s3_error!(InvalidArgument, "global active sk not init"));
+    };
+
+    let mut new_cred = get_new_credentials_with_metadata(&claims, &secret)
+        .map_err(|e| S3Error::with_message(S3ErrorCode::InternalError, format!("get new cred failed {e}")))?;
+
+    new_cred.parent_user = identity.user_dn;
+    new_cred.groups = groups;
+
+    info!("{} issued temporary credentials for {}", body.action, new_cred.parent_user);
+
+    if let Err(_err) = iam_store.set_temp_user(&new_cred.access_key, &new_cred, None).await {
+        return Err(s3_error!(InternalError, "set_temp_user failed"));
+    }
+
+    let output = sts_credentials_response(&body.action, &new_cred, &[], &[]);
+
+    let mut header = HeaderMap::new();
+    header.insert(CONTENT_TYPE, HeaderValue::from_static("text/xml"));
+    Ok(S3Response::with_headers((StatusCode::OK, Body::from(output)), header))
+}
+
+/// AssumeRoleWithCertificate: exchange the client certificate verified during the TLS handshake for temporary
+/// credentials carrying the policy named by the certificate's subject CN
+async fn assume_role_with_certificate(
+    body: &AssumeRoleRequest,
+    client_cert: Option<TlsClientCert>,
+) -> S3Result<S3Response<(StatusCode, Body)>> {
+    if body.version.as_str() != ASSUME_ROLE_VERSION {
+        return Err(s3_error!(InvalidArgument, "not support version"));
+    }
+
+    if !rustfs_utils::get_env_bool(rustfs_config::ENV_IDENTITY_TLS_ENABLE, rustfs_config::DEFAULT_IDENTITY_TLS_ENABLE) {
+        return Err(s3_error!(InvalidRequest, "certificate identity is not enabled"));
+    }
+
+    let Some(TlsClientCert(der)) = client_cert else {
+        return Err(s3_error!(AccessDenied, "no client certificate was presented"));
+    };
+
+    let now = OffsetDateTime::now_utc();
+    let identity = CertificateIdentity::parse(&der, now)?;
+
+    // Credentials never outlive the certificate they were issued for
+    let duration = sts_duration_seconds(body.duration_seconds)? as i64;
+    let expires_at = (now.unix_timestamp() + duration).min(identity.not_after);
+
+    let Ok(iam_store) = rustfs_iam::get() else {
+        return Err(s3_error!(InvalidRequest, "iam not init"));
+    };
+
+    if iam_store.info_policy(&identity.common_name).await.is_err() {
+        warn!("{} rejected: no policy named {}", body.action, identity.common_name);
+        return Err(s3_error!(AccessDenied, "no policy matches the certificate common name"));
+    }
+
+    let parent_user = format!("{TLS_PARENT_PREFIX}{}", identity.common_name);
+
+    let mut claims = HashMap::new();
+    claims.insert("exp".to_string(), Value::Number(serde_json::Number::from(expires_at)));
+    claims.insert("parent".to_string(), Value::String(parent_user.clone()));
+    claims.insert(POLICYNAME.to_string(), Value::String(identity.common_name.clone()));
+
+    populate_session_policy(&mut claims, &body.policy)?;
+
+    let Some(secret) = get_token_signing_key() else {
+        return Err(s3_error!(InvalidArgument, "global active sk not init"));
+    };
+
+    let mut new_cred = get_new_credentials_with_metadata(&claims, &secret)
+        .map_err(|e| S3Error::with_message(S3ErrorCode::InternalError, format!("get new cred failed {e}")))?;
+
+    new_cred.parent_user = parent_user;
+
+    info!("{} issued temporary credentials for {}", body.action, new_cred.parent_user);
+
+    if let Err(_err) = iam_store.set_temp_user(&new_cred.access_key, &new_cred, None).await {
+        return Err(s3_error!(InternalError, "set_temp_user failed"));
+    }
+
+    let output = sts_credentials_response(&body.action, &new_cred, &[], &[]);
+
+    let mut header = HeaderMap::new();
+    header.insert(CONTENT_TYPE, HeaderValue::from_static("text/xml"));
+    Ok(S3Response::with_headers((StatusCode::OK, Body::from(output)), header))
+}
+
+/// Identity carried by a client certificate
+#[derive(Debug, PartialEq, Eq)]
+struct CertificateIdentity {
+    common_name: String,
+    /// Unix timestamp after which the certificate is no longer valid
+    not_after: i64,
+}
+
+impl CertificateIdentity {
+    /// The chain was verified during the handshake; only validity and subject are checked here
+    fn parse(der: &[u8], now: OffsetDateTime) -> S3Result<Self> {
+        let (_, cert) =
+            x509_parser::parse_x509_certificate(der).map_err(|e| s3_error!(AccessDenied, "invalid client certificate: {}", e))?;
+
+        let validity = cert.validity();
+        let now = now.unix_timestamp();
+        if now < validity.not_before.timestamp() || now >= validity.not_after.timestamp() {
+            return Err(s3_error!(AccessDenied, "client certificate is expired or not yet valid"));
+        }
+
+        let common_name = cert
+            .subject()
+            .iter_common_name()
+            .next()
+            .and_then(|cn| cn.as_str().ok())
+            .map(str::trim)
+            .filter(|cn| !cn.is_empty())
+            .ok_or_else(|| s3_error!(AccessDenied, "client certificate has no subject common name"))?;
+
+        Ok(Self {
+            common_name: common_name.to_string(),
+            not_after: validity.not_after.timestamp(),
+        })
+    }
+}
+
+/// Validate the requested session duration, defaulting to one hour
+fn sts_duration_seconds(duration_seconds: usize) -> S3Result<usize> {
+    match duration_seconds {
+        0 => Ok(DEFAULT_DURATION_SECONDS),
+        d if (MIN_DURATION_SECONDS..=MAX_DURATION_SECONDS).contains(&d) => Ok(d),
+        _ => Err(s3_error!(
+            InvalidArgument,
+            "DurationSeconds must be between {} and {}",
+            MIN_DURATION_SECONDS,
+            MAX_DURATION_SECONDS
+        )),
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Build the `<{action}Response>` document carrying temporary credentials.
+///
+/// `before` elements precede `<Credentials>` in the result and `after` elements follow it.
+fn sts_credentials_response(
+    action: &str,
+    cred: &rustfs_credentials::Credentials,
+    before: &[(&str, &str)],
+    after: &[(&str, &str)],
+) -> String {
+    let expiration = cred
+        .expiration
+        .unwrap_or(OffsetDateTime::now_utc().saturating_add(Duration::seconds(3600)))
+        .format(&Rfc3339)
+        .unwrap_or_default();
+
+    let element = |name: &str, value: &str| format!("<{name}>{}</{name}>", xml_escape(value));
+
+    let mut result = String::new();
+    for (name, value) in before {
+        result.push_str(&element(name, value));
+    }
+    result.push_str("<Credentials>");
+    result.push_str(&element("AccessKeyId", &cred.access_key));
+    result.push_str(&element("SecretAccessKey", &cred.secret_key));
+    result.push_str(&element("SessionToken", &cred.session_token));
+    result.push_str(&element("Expiration", &expiration));
+    result.push_str("</Credentials>");
+    for (name, value) in after {
+        result.push_str(&element(name, value));
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+         <{action}Response xmlns=\"{STS_NAMESPACE}\">\
+         <{action}Result>{result}</{action}Result>\
+         <ResponseMetadata><RequestId>{}</RequestId></ResponseMetadata>\
+         </{action}Response>",
+        uuid::Uuid::new_v4()
+    )
+}
+
 pub fn populate_session_policy(claims: &mut HashMap<String, Value>, policy: &str) -> S3Result<()> {
     if !policy.is_empty() {
         let session_policy = Policy::parse_config(policy.as_bytes())
@@ -205,3 +592,97 @@ pub fn populate_session_policy(claims: &mut HashMap<String, Value>, policy: &str
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sts_duration_seconds() {
+        assert_eq!(sts_duration_seconds(0).unwrap(), DEFAULT_DURATION_SECONDS);
+        assert_eq!(sts_duration_seconds(900).unwrap(), 900);
+        assert_eq!(sts_duration_seconds(43200).unwrap(), 43200);
+        assert!(sts_duration_seconds(899).is_err());
+        assert!(sts_duration_seconds(43201).is_err());
+    }
+
+    #[test]
+    fn test_web_identity_request_from_form() {
+        let body: AssumeRoleRequest =
+            from_bytes(b"Action=AssumeRoleWithWebIdentity&Version=2011-06-15&WebIdentityToken=a.b.c&DurationSeconds=900")
+                .unwrap();
+        assert_eq!(body.action, ASSUME_ROLE_WITH_WEB_IDENTITY_ACTION);
+        assert_eq!(body.web_identity_token, "a.b.c");
+        assert_eq!(body.duration_seconds, 900);
+    }
+
+    #[test]
+    fn test_sts_request_action() {
+        assert_eq!(
+            sts_request_action(b"Action=AssumeRole&Version=2011-06-15", Some("Action=AssumeRoleWithWebIdentity")).as_deref(),
+            Some(ASSUME_ROLE_ACTION)
+        );
+        assert_eq!(
+            sts_request_action(b"", Some("Version=2011-06-15&Action=AssumeRoleWithWebIdentity")).as_deref(),
+            Some(ASSUME_ROLE_WITH_WEB_IDENTITY_ACTION)
+        );
+        assert_eq!(sts_request_action(b"Version=2011-06-15", None), None);
+
+        assert_eq!(sts_action_requires_signature(ASSUME_ROLE_ACTION), Some(true));
+        assert_eq!(sts_action_requires_signature(ASSUME_ROLE_WITH_CLIENT_GRANTS_ACTION), Some(false));
+        assert_eq!(sts_action_requires_signature(ASSUME_ROLE_WITH_CERTIFICATE_ACTION), Some(false));
+        assert_eq!(sts_action_requires_signature("GetCallerIdentity"), None);
+        assert_eq!(sts_action_requires_signature(""), None);
+    }
+
+    #[test]
+    fn test_ldap_identity_request_from_form() {
+        let body: AssumeRoleRequest =
+            from_bytes(b"Action=AssumeRoleWithLDAPIdentity&Version=2011-06-15&LDAPUsername=alice&LDAPPassword=p%26ss").unwrap();
+        assert_eq!(body.action, ASSUME_ROLE_WITH_LDAP_IDENTITY_ACTION);
+        assert_eq!(body.ldap_username, "alice");
+        assert_eq!(body.ldap_password, "p&ss");
+    }
+
+    #[test]
+    fn test_certificate_identity() {
+        let mut params = rcgen::CertificateParams::new(vec!["svc.example.com".to_string()]).unwrap();
+        params.distinguished_name.push(rcgen::DnType::CommonName, "readwrite");
+        params.not_before = rcgen::date_time_ymd(2024, 1, 1);
+        params.not_after = rcgen::date_time_ymd(2030, 1, 1);
+        let key = rcgen::KeyPair::generate().unwrap();
+        let cert = params.self_signed(&key).unwrap();
+
+        let now = OffsetDateTime::from_unix_timestamp(1_800_000_000).unwrap();
+        let identity = CertificateIdentity::parse(cert.der(), now).unwrap();
+        assert_eq!(identity.common_name, "readwrite");
+        assert_eq!(identity.not_after, 1_893_456_000);
+
+        let expired = OffsetDateTime::from_unix_timestamp(1_900_000_000).unwrap();
+        assert!(CertificateIdentity::parse(cert.der(), expired).is_err());
+        assert!(CertificateIdentity::parse(b"not a certificate", now).is_err());
+    }
+
+    #[test]
+    fn test_sts_credentials_response() {
+        let cred = rustfs_credentials::Credentials {
+            access_key: "AKIA".to_string(),
+            secret_key: "secret".to_string(),
+            session_token: "token".to_string(),
+            expiration: Some(OffsetDateTime::UNIX_EPOCH),
+            ..Default::default()
+        };
+        let xml = sts_credentials_response(
+            ASSUME_ROLE_WITH_WEB_IDENTITY_ACTION,
+            &cred,
+            &[("SubjectFromWebIdentityToken", "alice<&>")],
+            &[("Provider", "https://sso.example.com")],
+        );
+
+        assert!(xml.contains("<AssumeRoleWithWebIdentityResponse xmlns=\"https://sts.amazonaws.com/doc/2011-06-15/\">"));
+        assert!(xml.contains("<SubjectFromWebIdentityToken>alice&lt;&amp;&gt;</SubjectFromWebIdentityToken><Credentials>"));
+        assert!(xml.contains("<AccessKeyId>AKIA</AccessKeyId>"));
+        assert!(xml.contains("<Expiration>1970-01-01T00:00:00Z</Expiration>"));
+        assert!(xml.contains("</Credentials><Provider>https://sso.example.com</Provider></AssumeRoleWithWebIdentityResult>"));
+    }
+}
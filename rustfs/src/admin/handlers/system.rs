@@ -12,22 +12,47 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::metrics;
+use super::{heal, metrics, trace};
 use crate::admin::auth::validate_admin_request;
 use crate::admin::router::{AdminOperation, Operation, S3Router};
 use crate::auth::{check_key_valid, get_session_token};
 use crate::server::{ADMIN_PREFIX, RemoteAddr};
-use http::{HeaderMap, HeaderValue};
+use aes_gcm::{
+    Aes256Gcm, Key, Nonce,
+    aead::{Aead, KeyInit, Payload},
+};
+use bytes::{Bytes, BytesMut};
+use http::{HeaderMap, HeaderValue, Uri};
 use hyper::{Method, StatusCode};
 use matchit::Params;
+use rand::Rng;
+use rsa::{Oaep, RsaPublicKey, pkcs8::DecodePublicKey};
+use rustfs_common::GLOBAL_LOCAL_NODE_NAME;
+use rustfs_common::service_signal::{ServiceSignal, freeze_services, send_service_signal, unfreeze_services};
 use rustfs_ecstore::admin_server_info::get_server_info;
 use rustfs_ecstore::data_usage::load_data_usage_from_backend;
+use rustfs_ecstore::disk::{DiskAPI, DiskStore, FileReader, STORAGE_FORMAT_FILE};
 use rustfs_ecstore::new_object_layer_fn;
+use rustfs_ecstore::notification_sys::get_global_notification_sys;
 use rustfs_ecstore::pools::{get_total_usable_capacity, get_total_usable_capacity_free};
+use rustfs_ecstore::store::ECStore;
 use rustfs_ecstore::store_api::StorageAPI;
+use rustfs_filemeta::{FileMeta, FileMetaVersion, FileMetaVersionHeader};
+use rustfs_madmin::service_commands::{ServiceAction, ServiceActionResult, ServicePeerResult};
 use rustfs_policy::policy::action::{Action, AdminAction, S3Action};
-use s3s::header::CONTENT_TYPE;
+use rustfs_zip::{CompressionLevel, ZipStreamWriter};
+use s3s::dto::StreamingBlob;
+use s3s::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
 use s3s::{Body, S3Error, S3ErrorCode, S3Request, S3Response, S3Result, s3_error};
+use serde::Serialize;
+use sha2::Sha256;
+use std::io::Write;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+use tokio::spawn;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 use tracing::{debug, error, info, warn};
 
 pub fn register_system_route(r: &mut S3Router<AdminOperation>) -> std::io::Result<()> {
@@ -73,17 +98,121 @@ pub fn register_system_route(r: &mut S3Router<AdminOperation>) -> std::io::Resul
         AdminOperation(&metrics::MetricsHandler {}),
     )?;
 
+    r.insert(
+        Method::GET,
+        format!("{}{}", ADMIN_PREFIX, "/v3/trace").as_str(),
+        AdminOperation(&trace::Trace {}),
+    )?;
+
     Ok(())
 }
 
+/// Delay before a local restart or stop so the admin response can be delivered
+const SERVICE_SIGNAL_DELAY: Duration = Duration::from_millis(500);
+
+fn extract_service_params(uri: &Uri) -> S3Result<(ServiceAction, bool)> {
+    let mut action = None;
+    let mut dry_run = false;
+    if let Some(query) = uri.query() {
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "action" => {
+                    action = Some(
+                        value
+                            .parse::<ServiceAction>()
+                            .map_err(|e| s3_error!(InvalidArgument, "{}", e))?,
+                    )
+                }
+                "dry-run" => dry_run = value == "true",
+                _ => {}
+            }
+        }
+    }
+
+    let action = action.ok_or_else(|| s3_error!(InvalidArgument, "service action is missing"))?;
+    Ok((action, dry_run))
+}
+
+/// Apply a service signal to this node.
+///
+/// Restart and stop are deferred so that the admin response reaches the client first.
+fn apply_local_service_signal(signal: ServiceSignal) {
+    match signal {
+        ServiceSignal::Freeze => freeze_services(),
+        ServiceSignal::Unfreeze => unfreeze_services(),
+        ServiceSignal::Restart | ServiceSignal::Stop => {
+            spawn(async move {
+                tokio::time::sleep(SERVICE_SIGNAL_DELAY).await;
+                if !send_service_signal(signal) {
+                    warn!("service signal {:?} dropped, main loop is not listening", signal);
+                }
+            });
+        }
+        ServiceSignal::ReloadDynamic => {}
+    }
+}
+
 pub struct ServiceHandle {}
 
 #[async_trait::async_trait]
 impl Operation for ServiceHandle {
-    async fn call(&self, _req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
-        warn!("handle ServiceHandle");
+    async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
+        let (action, dry_run) = extract_service_params(&req.uri)?;
+        info!("handle ServiceHandle, action: {}, dry_run: {}", action.as_str(), dry_run);
+
+        let Some(input_cred) = req.credentials else {
+            return Err(s3_error!(InvalidRequest, "get cred failed"));
+        };
+
+        let (cred, owner) =
+            check_key_valid(get_session_token(&req.uri, &req.headers).unwrap_or_default(), &input_cred.access_key).await?;
+
+        let (admin_action, signal) = match action {
+            ServiceAction::Restart => (AdminAction::ServiceRestartAdminAction, ServiceSignal::Restart),
+            ServiceAction::Stop => (AdminAction::ServiceStopAdminAction, ServiceSignal::Stop),
+            ServiceAction::Freeze => (AdminAction::ServiceFreezeAdminAction, ServiceSignal::Freeze),
+            ServiceAction::Unfreeze => (AdminAction::ServiceFreezeAdminAction, ServiceSignal::Unfreeze),
+        };
+
+        let remote_addr = req.extensions.get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0));
+        validate_admin_request(&req.headers, &cred, owner, false, vec![Action::AdminAction(admin_action)], remote_addr).await?;
+
+        // Signal peers first: once the local node goes down it can no longer reach them.
+        let mut results = Vec::new();
+        if let Some(notification_sys) = get_global_notification_sys() {
+            for peer_err in notification_sys.signal_service(signal as u64, "", dry_run).await {
+                if let Some(err) = &peer_err.err {
+                    warn!("service {} on peer {} failed: {}", action.as_str(), peer_err.host, err);
+                }
+                results.push(ServicePeerResult {
+                    host: peer_err.host,
+                    err: peer_err.err.map(|e| e.to_string()),
+                });
+            }
+        }
+
+        if !dry_run {
+            apply_local_service_signal(signal);
+        }
+        results.insert(
+            0,
+            ServicePeerResult {
+                host: GLOBAL_LOCAL_NODE_NAME.read().await.clone(),
+                err: None,
+            },
+        );
+
+        let data = serde_json::to_vec(&ServiceActionResult {
+            action,
+            dry_run,
+            results,
+        })
+        .map_err(|_e| S3Error::with_message(S3ErrorCode::InternalError, "failed to serialize service result"))?;
+
+        let mut header = HeaderMap::new();
+        header.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
-        Err(s3_error!(NotImplemented))
+        Ok(S3Response::with_headers((StatusCode::OK, Body::from(data)), header))
     }
 }
 
@@ -122,14 +251,441 @@ impl Operation for ServerInfoHandler {
     }
 }
 
+/// Size of the chunks the inspect archive is streamed, and encrypted, in
+const INSPECT_CHUNK_SIZE: usize = 1 << 20;
+
+/// Leads an obfuscated inspect stream, followed by the 32 byte key and the 8 byte nonce prefix.
+/// The key travels with the data, so this only keeps the archive from being read by accident.
+const INSPECT_STREAM_VERSION: u8 = 1;
+
+/// Leads an inspect stream encrypted for an operator public key, followed by the big endian u16
+/// length of the RSA-OAEP (SHA-256) wrapped 32 byte key, the wrapped key and the 8 byte nonce prefix
+const INSPECT_STREAM_VERSION_PUBLIC_KEY: u8 = 2;
+
+#[derive(Debug, Default, PartialEq)]
+struct InspectDataParams {
+    volume: String,
+    file: String,
+    /// Also collect the part files of every version
+    parts: bool,
+    /// Return the archive unencrypted
+    plain: bool,
+    /// Wrap the archive key for this RSA public key instead of sending it in the clear
+    public_key: Option<RsaPublicKey>,
+}
+
+fn extract_inspect_params(uri: &Uri) -> S3Result<InspectDataParams> {
+    let mut params = InspectDataParams::default();
+    if let Some(query) = uri.query() {
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "volume" => params.volume = value.into_owned(),
+                "file" => params.file = value.trim_matches('/').to_string(),
+                "parts" => params.parts = value == "true",
+                "plain" => params.plain = value == "true",
+                "public-key" => {
+                    // URL safe base64, unpadded, of the DER encoded SubjectPublicKeyInfo
+                    let der = base64_simd::URL_SAFE_NO_PAD
+                        .decode_to_vec(value.as_bytes())
+                        .map_err(|_| s3_error!(InvalidArgument, "invalid public-key encoding"))?;
+                    let public_key =
+                        RsaPublicKey::from_public_key_der(&der).map_err(|_| s3_error!(InvalidArgument, "invalid public-key"))?;
+                    params.public_key = Some(public_key);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if params.volume.is_empty() || params.file.is_empty() {
+        return Err(s3_error!(InvalidArgument, "volume and file are required"));
+    }
+    if params.volume.contains('/') || params.volume == ".." || params.file.split('/').any(|c| c == "..") {
+        return Err(s3_error!(InvalidArgument, "invalid volume or file"));
+    }
+    if params.plain && params.public_key.is_some() {
+        return Err(s3_error!(InvalidArgument, "plain and public-key are exclusive"));
+    }
+
+    Ok(params)
+}
+
+/// Decoded view of one version of an `xl.meta`
+#[derive(Debug, Serialize)]
+struct InspectVersion {
+    header: FileMetaVersionHeader,
+    version: Option<FileMetaVersion>,
+    error: Option<String>,
+}
+
+/// What a single drive of the erasure set holds for the inspected file
+#[derive(Debug, Default, Serialize)]
+struct InspectDrive {
+    endpoint: String,
+    pool_index: Option<usize>,
+    set_index: Option<usize>,
+    disk_index: Option<usize>,
+    meta_version: u8,
+    versions: Vec<InspectVersion>,
+    /// Part files added to the archive
+    parts: Vec<String>,
+    error: Option<String>,
+}
+
+fn inspect_drive_label(drive: &InspectDrive) -> String {
+    match (drive.pool_index, drive.set_index, drive.disk_index) {
+        (Some(pool), Some(set), Some(disk)) => format!("pool{pool}-set{set}-disk{disk}"),
+        _ => drive
+            .endpoint
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect(),
+    }
+}
+
+fn decode_inspect_versions(meta: &FileMeta) -> Vec<InspectVersion> {
+    meta.versions
+        .iter()
+        .map(|v| match FileMetaVersion::try_from(v.meta.as_slice()) {
+            Ok(version) => InspectVersion {
+                header: v.header.clone(),
+                version: Some(version),
+                error: None,
+            },
+            Err(e) => InspectVersion {
+                header: v.header.clone(),
+                version: None,
+                error: Some(e.to_string()),
+            },
+        })
+        .collect()
+}
+
+/// Sent from the drive readers to the archive writer
+enum InspectEntry {
+    /// Start the next file of the archive
+    File(String),
+    /// Append to the current file
+    Data(Bytes),
+}
+
+async fn send_inspect_entry(tx: &mpsc::Sender<InspectEntry>, entry: InspectEntry) -> std::io::Result<()> {
+    tx.send(entry)
+        .await
+        .map_err(|_| std::io::Error::from(std::io::ErrorKind::BrokenPipe))
+}
+
+/// Copy a file from a drive into the archive chunk by chunk
+async fn send_inspect_file(tx: &mpsc::Sender<InspectEntry>, name: String, mut reader: FileReader) -> std::io::Result<()> {
+    send_inspect_entry(tx, InspectEntry::File(name)).await?;
+    loop {
+        let mut buf = BytesMut::with_capacity(INSPECT_CHUNK_SIZE);
+        if reader.read_buf(&mut buf).await? == 0 {
+            return Ok(());
+        }
+        send_inspect_entry(tx, InspectEntry::Data(buf.freeze())).await?;
+    }
+}
+
+/// Stream `xl.meta` and optionally the part files of the inspected file on one drive into the archive.
+async fn inspect_drive(disk: &DiskStore, params: &InspectDataParams, tx: &mpsc::Sender<InspectEntry>) -> InspectDrive {
+    let location = disk.get_disk_location();
+    let mut drive = InspectDrive {
+        endpoint: disk.to_string(),
+        pool_index: location.pool_idx,
+        set_index: location.set_idx,
+        disk_index: location.disk_idx,
+        ..Default::default()
+    };
+    let prefix = format!("{}/{}/{}", inspect_drive_label(&drive), params.volume, params.file);
+
+    let buf = match disk
+        .read_all(&params.volume, &format!("{}/{}", params.file, STORAGE_FORMAT_FILE))
+        .await
+    {
+        Ok(buf) => buf,
+        Err(e) => {
+            drive.error = Some(e.to_string());
+            return drive;
+        }
+    };
+    let meta = FileMeta::load(&buf);
+    let sent = async {
+        send_inspect_entry(tx, InspectEntry::File(format!("{prefix}/{STORAGE_FORMAT_FILE}"))).await?;
+        send_inspect_entry(tx, InspectEntry::Data(buf)).await
+    };
+    if let Err(e) = sent.await {
+        drive.error = Some(e.to_string());
+        return drive;
+    }
+
+    let meta = match meta {
+        Ok(meta) => meta,
+        Err(e) => {
+            drive.error = Some(format!("decode {STORAGE_FORMAT_FILE}: {e}"));
+            return drive;
+        }
+    };
+    drive.meta_version = meta.meta_ver;
+    drive.versions = decode_inspect_versions(&meta);
+
+    if params.parts {
+        // Versions may share a data dir, add each part file once
+        let mut parts: Vec<String> = Vec::new();
+        for object in drive.versions.iter().filter_map(|v| v.version.as_ref()?.object.as_ref()) {
+            let Some(data_dir) = object.data_dir else { continue };
+            for number in object.part_numbers.iter() {
+                let part = format!("{data_dir}/part.{number}");
+                if !parts.contains(&part) {
+                    parts.push(part);
+                }
+            }
+        }
+
+        for part in parts {
+            let reader = match disk.read_file(&params.volume, &format!("{}/{}", params.file, part)).await {
+                Ok(reader) => reader,
+                Err(e) => {
+                    warn!("inspect {} on {}: {}", part, drive.endpoint, e);
+                    continue;
+                }
+            };
+            if let Err(e) = send_inspect_file(tx, format!("{prefix}/{part}"), reader).await {
+                warn!("inspect {} on {}: {}", part, drive.endpoint, e);
+                if tx.is_closed() {
+                    break;
+                }
+                continue;
+            }
+            drive.parts.push(part);
+        }
+    }
+
+    drive
+}
+
+/// Visit every drive of the erasure set the file hashes to in each pool, then add the `inspect.json` report
+async fn send_inspect_archive(store: Arc<ECStore>, params: InspectDataParams, tx: mpsc::Sender<InspectEntry>) {
+    let mut drives = Vec::new();
+    for pool in store.pools.iter() {
+        let disks = match pool.get_disks_by_key(&params.file).get_disks(0, 0).await {
+            Ok(disks) => disks,
+            Err(e) => {
+                warn!(
+                    "inspect {}/{} in pool {}: get disks failed: {}",
+                    params.volume, params.file, pool.pool_idx, e
+                );
+                continue;
+            }
+        };
+
+        for (idx, disk) in disks.iter().enumerate() {
+            let drive = match disk {
+                Some(disk) => inspect_drive(disk, &params, &tx).await,
+                None => InspectDrive {
+                    pool_index: Some(pool.pool_idx),
+                    disk_index: Some(idx),
+                    error: Some("drive offline".to_string()),
+                    ..Default::default()
+                },
+            };
+            if tx.is_closed() {
+                return;
+            }
+            drives.push(drive);
+        }
+    }
+
+    let report = match serde_json::to_vec_pretty(&drives) {
+        Ok(report) => report,
+        Err(e) => {
+            error!("serialize inspect report failed: {}", e);
+            return;
+        }
+    };
+    if send_inspect_entry(&tx, InspectEntry::File("inspect.json".to_string()))
+        .await
+        .is_ok()
+    {
+        let _ = send_inspect_entry(&tx, InspectEntry::Data(report.into())).await;
+    }
+}
+
+/// The response body end of the inspect archive. Cuts the archive into chunks and, unless the
+/// archive is requested plain, seals each as an AES-256-GCM package: a big endian u32 length
+/// followed by the ciphertext, the nonce being the stream's nonce prefix and the package counter.
+/// Only a stream whose key is wrapped for an operator public key is confidential, without one the
+/// key is sent ahead of the packages and the archive is merely obfuscated.
+struct InspectBodyWriter {
+    tx: mpsc::Sender<std::io::Result<Bytes>>,
+    buf: Vec<u8>,
+    cipher: Option<(Aes256Gcm, [u8; 8])>,
+    sequence: u32,
+}
+
+impl InspectBodyWriter {
+    /// Start the stream, a sealed one leads with the version, the (wrapped) key and the nonce prefix
+    fn new(tx: mpsc::Sender<std::io::Result<Bytes>>, plain: bool, public_key: Option<&RsaPublicKey>) -> std::io::Result<Self> {
+        let mut writer = Self {
+            tx,
+            buf: Vec::with_capacity(INSPECT_CHUNK_SIZE),
+            cipher: None,
+            sequence: 0,
+        };
+        if !plain {
+            let mut key = [0u8; 32];
+            let mut nonce_prefix = [0u8; 8];
+            rand::rng().fill_bytes(&mut key);
+            rand::rng().fill_bytes(&mut nonce_prefix);
+
+            let mut header = Vec::with_capacity(3 + key.len() + nonce_prefix.len());
+            match public_key {
+                Some(public_key) => {
+                    let wrapped = public_key
+                        .encrypt(&mut rand::rng(), Oaep::<Sha256>::new(), &key)
+                        .map_err(|e| std::io::Error::other(format!("wrap inspect key failed: {e}")))?;
+                    header.push(INSPECT_STREAM_VERSION_PUBLIC_KEY);
+                    header.extend_from_slice(&(wrapped.len() as u16).to_be_bytes());
+                    header.extend_from_slice(&wrapped);
+                }
+                None => {
+                    header.push(INSPECT_STREAM_VERSION);
+                    header.extend_from_slice(&key);
+                }
+            }
+            header.extend_from_slice(&nonce_prefix);
+            writer.send(header)?;
+            writer.cipher = Some((Aes256Gcm::new(&Key::<Aes256Gcm>::from(key)), nonce_prefix));
+        }
+        Ok(writer)
+    }
+
+    fn send(&self, data: Vec<u8>) -> std::io::Result<()> {
+        self.tx
+            .blocking_send(Ok(Bytes::from(data)))
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::BrokenPipe))
+    }
+
+    /// Send a chunk of the archive, the final package is authenticated as such so truncation is detected
+    fn send_chunk(&mut self, chunk: Vec<u8>, last: bool) -> std::io::Result<()> {
+        let Some((cipher, nonce_prefix)) = &self.cipher else {
+            return if chunk.is_empty() { Ok(()) } else { self.send(chunk) };
+        };
+
+        let mut nonce = [0u8; 12];
+        nonce[..8].copy_from_slice(nonce_prefix);
+        nonce[8..].copy_from_slice(&self.sequence.to_be_bytes());
+        self.sequence = self
+            .sequence
+            .checked_add(1)
+            .ok_or_else(|| std::io::Error::other("inspect archive too large"))?;
+
+        let sealed = cipher
+            .encrypt(
+                &Nonce::from(nonce),
+                Payload {
+                    msg: &chunk,
+                    aad: &[u8::from(last)],
+                },
+            )
+            .map_err(|e| std::io::Error::other(format!("encrypt inspect archive failed: {e}")))?;
+        let mut package = Vec::with_capacity(4 + sealed.len());
+        package.extend_from_slice(&(sealed.len() as u32).to_be_bytes());
+        package.extend_from_slice(&sealed);
+        self.send(package)
+    }
+
+    fn finish(mut self) -> std::io::Result<()> {
+        let chunk = std::mem::take(&mut self.buf);
+        self.send_chunk(chunk, true)
+    }
+}
+
+impl Write for InspectBodyWriter {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.buf.extend_from_slice(data);
+        while self.buf.len() >= INSPECT_CHUNK_SIZE {
+            let rest = self.buf.split_off(INSPECT_CHUNK_SIZE);
+            let chunk = std::mem::replace(&mut self.buf, rest);
+            self.send_chunk(chunk, false)?;
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Zip the entries sent by the drive readers into the response body, runs on a blocking thread
+fn write_inspect_archive(mut entries: mpsc::Receiver<InspectEntry>, body: InspectBodyWriter) -> std::io::Result<()> {
+    let mut zip = ZipStreamWriter::new(body, CompressionLevel::Default);
+    while let Some(entry) = entries.blocking_recv() {
+        match entry {
+            InspectEntry::File(name) => zip.start_file(&name)?,
+            InspectEntry::Data(data) => zip.write_all(&data)?,
+        }
+    }
+    zip.finish()?.finish()
+}
+
 pub struct InspectDataHandler {}
 
 #[async_trait::async_trait]
 impl Operation for InspectDataHandler {
-    async fn call(&self, _req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
+    async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
         warn!("handle InspectDataHandler");
 
-        Err(s3_error!(NotImplemented))
+        let params = extract_inspect_params(&req.uri)?;
+
+        let Some(input_cred) = req.credentials else {
+            return Err(s3_error!(InvalidRequest, "get cred failed"));
+        };
+
+        let (cred, owner) =
+            check_key_valid(get_session_token(&req.uri, &req.headers).unwrap_or_default(), &input_cred.access_key).await?;
+
+        let remote_addr = req.extensions.get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0));
+        validate_admin_request(
+            &req.headers,
+            &cred,
+            owner,
+            false,
+            vec![Action::AdminAction(AdminAction::InspectDataAction)],
+            remote_addr,
+        )
+        .await?;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(S3Error::with_message(S3ErrorCode::InternalError, "Not init".to_string()));
+        };
+
+        let mut header = HeaderMap::new();
+        if params.plain {
+            header.insert(CONTENT_TYPE, HeaderValue::from_static("application/zip"));
+            header.insert(CONTENT_DISPOSITION, HeaderValue::from_static("attachment; filename=inspect-data.zip"));
+        } else {
+            header.insert(CONTENT_TYPE, HeaderValue::from_static("application/octet-stream"));
+            header.insert(CONTENT_DISPOSITION, HeaderValue::from_static("attachment; filename=inspect-data.enc"));
+        }
+
+        // Drive reads and zipping run concurrently, only a few chunks are held in memory at a time
+        let (entry_tx, entry_rx) = mpsc::channel(4);
+        let (body_tx, body_rx) = mpsc::channel(4);
+        let plain = params.plain;
+        let public_key = params.public_key.clone();
+        spawn(send_inspect_archive(store, params, entry_tx));
+        tokio::task::spawn_blocking(move || {
+            let result = InspectBodyWriter::new(body_tx.clone(), plain, public_key.as_ref())
+                .and_then(|body| write_inspect_archive(entry_rx, body));
+            if let Err(e) = result {
+                error!("write inspect archive failed: {}", e);
+                let _ = body_tx.blocking_send(Err(e));
+            }
+        });
+
+        let body = Body::from(StreamingBlob::wrap(ReceiverStream::new(body_rx)));
+        Ok(S3Response::with_headers((StatusCode::OK, body), header))
     }
 }
 
@@ -162,8 +718,18 @@ impl Operation for StorageInfoHandler {
             return Err(S3Error::with_message(S3ErrorCode::InternalError, "Not init".to_string()));
         };
 
-        // TODO:getAggregatedBackgroundHealState
-        let info = store.storage_info().await;
+        let mut info = store.storage_info().await;
+
+        // Flag drives that are being resynced anywhere in the cluster
+        if let Ok(healing) = heal::aggregated_background_heal_state().await {
+            for disk in info.disks.iter_mut() {
+                if !healing.heal_disks.contains(&disk.endpoint) {
+                    continue;
+                }
+                disk.healing = true;
+                disk.heal_info = healing.healing_disks.iter().find(|h| h.endpoint == disk.endpoint).cloned();
+            }
+        }
 
         let data = serde_json::to_vec(&info)
             .map_err(|_e| S3Error::with_message(S3ErrorCode::InternalError, "failed to serialize storage info"))?;
@@ -291,3 +857,172 @@ impl Operation for DataUsageInfoHandler {
         Ok(S3Response::with_headers((StatusCode::OK, Body::from(data)), header))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsa::{RsaPrivateKey, pkcs8::EncodePublicKey};
+
+    #[test]
+    fn test_extract_service_params() {
+        let uri: Uri = "/rustfs/admin/v3/service?action=freeze&dry-run=true".parse().unwrap();
+        let (action, dry_run) = extract_service_params(&uri).unwrap();
+        assert_eq!(action, ServiceAction::Freeze);
+        assert!(dry_run);
+
+        let uri: Uri = "/rustfs/admin/v3/service?action=restart".parse().unwrap();
+        assert_eq!(extract_service_params(&uri).unwrap(), (ServiceAction::Restart, false));
+
+        let uri: Uri = "/rustfs/admin/v3/service?action=reboot".parse().unwrap();
+        assert!(extract_service_params(&uri).is_err());
+
+        let uri: Uri = "/rustfs/admin/v3/service".parse().unwrap();
+        assert!(extract_service_params(&uri).is_err());
+    }
+
+    #[test]
+    fn test_extract_inspect_params() {
+        let uri: Uri = "/rustfs/admin/v3/inspect-data?volume=bucket&file=%2Fdir%2Fobj&parts=true"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            extract_inspect_params(&uri).unwrap(),
+            InspectDataParams {
+                volume: "bucket".to_string(),
+                file: "dir/obj".to_string(),
+                parts: true,
+                plain: false,
+                public_key: None,
+            }
+        );
+
+        let uri: Uri = "/rustfs/admin/v3/inspect-data?volume=bucket".parse().unwrap();
+        assert!(extract_inspect_params(&uri).is_err());
+
+        let uri: Uri = "/rustfs/admin/v3/inspect-data?volume=bucket&file=..%2F..%2Fetc"
+            .parse()
+            .unwrap();
+        assert!(extract_inspect_params(&uri).is_err());
+    }
+
+    #[test]
+    fn test_inspect_drive_label() {
+        let mut drive = InspectDrive {
+            endpoint: "http://node1:9000/data/rustfs0".to_string(),
+            pool_index: Some(0),
+            set_index: Some(1),
+            disk_index: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(inspect_drive_label(&drive), "pool0-set1-disk3");
+
+        drive.set_index = None;
+        assert_eq!(inspect_drive_label(&drive), "http___node1_9000_data_rustfs0");
+    }
+
+    #[test]
+    fn test_inspect_body_writer_encrypted() {
+        let (tx, mut rx) = mpsc::channel(16);
+        let mut body = InspectBodyWriter::new(tx, false, None).unwrap();
+        let archive: Vec<u8> = (0..INSPECT_CHUNK_SIZE + INSPECT_CHUNK_SIZE / 2).map(|i| i as u8).collect();
+        body.write_all(&archive).unwrap();
+        body.finish().unwrap();
+
+        let mut stream = Vec::new();
+        while let Ok(chunk) = rx.try_recv() {
+            stream.extend_from_slice(&chunk.unwrap());
+        }
+
+        assert_eq!(stream[0], INSPECT_STREAM_VERSION);
+        let key = <[u8; 32]>::try_from(&stream[1..33]).unwrap();
+        assert_eq!(open_inspect_packages(&key, &stream[33..]), archive);
+    }
+
+    #[test]
+    fn test_inspect_body_writer_public_key() {
+        let private_key = RsaPrivateKey::new(&mut rand::rng(), 2048).unwrap();
+        let public_key = RsaPublicKey::from(&private_key);
+
+        let (tx, mut rx) = mpsc::channel(16);
+        let mut body = InspectBodyWriter::new(tx, false, Some(&public_key)).unwrap();
+        let archive: Vec<u8> = (0..INSPECT_CHUNK_SIZE + INSPECT_CHUNK_SIZE / 2).map(|i| i as u8).collect();
+        body.write_all(&archive).unwrap();
+        body.finish().unwrap();
+
+        let mut stream = Vec::new();
+        while let Ok(chunk) = rx.try_recv() {
+            stream.extend_from_slice(&chunk.unwrap());
+        }
+
+        // The key must not be recoverable from the stream without the private key
+        assert_eq!(stream[0], INSPECT_STREAM_VERSION_PUBLIC_KEY);
+        let wrapped_len = u16::from_be_bytes([stream[1], stream[2]]) as usize;
+        let wrapped = &stream[3..3 + wrapped_len];
+        let key = private_key.decrypt(Oaep::<Sha256>::new(), wrapped).unwrap();
+        assert!(!stream.windows(key.len()).any(|w| w == key.as_slice()));
+
+        let key = <[u8; 32]>::try_from(key.as_slice()).unwrap();
+        assert_eq!(open_inspect_packages(&key, &stream[3 + wrapped_len..]), archive);
+    }
+
+    #[test]
+    fn test_extract_inspect_public_key() {
+        let private_key = RsaPrivateKey::new(&mut rand::rng(), 2048).unwrap();
+        let public_key = RsaPublicKey::from(&private_key);
+        let der = public_key.to_public_key_der().unwrap();
+        let encoded = base64_simd::URL_SAFE_NO_PAD.encode_to_string(der.as_bytes());
+
+        let uri: Uri = format!("/rustfs/admin/v3/inspect-data?volume=bucket&file=obj&public-key={encoded}")
+            .parse()
+            .unwrap();
+        assert_eq!(extract_inspect_params(&uri).unwrap().public_key, Some(public_key));
+
+        let uri: Uri = format!("/rustfs/admin/v3/inspect-data?volume=bucket&file=obj&plain=true&public-key={encoded}")
+            .parse()
+            .unwrap();
+        assert!(extract_inspect_params(&uri).is_err());
+
+        let uri: Uri = "/rustfs/admin/v3/inspect-data?volume=bucket&file=obj&public-key=AAAA"
+            .parse()
+            .unwrap();
+        assert!(extract_inspect_params(&uri).is_err());
+    }
+
+    /// Decrypt the packages following the stream header, checking the last one is marked final
+    fn open_inspect_packages(key: &[u8; 32], stream: &[u8]) -> Vec<u8> {
+        let cipher = Aes256Gcm::new(&Key::<Aes256Gcm>::from(*key));
+        let nonce_prefix = &stream[..8];
+
+        let mut rest = &stream[8..];
+        let mut decrypted = Vec::new();
+        let mut sequence = 0u32;
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let sealed = &rest[4..4 + len];
+            rest = &rest[4 + len..];
+
+            let mut nonce = [0u8; 12];
+            nonce[..8].copy_from_slice(nonce_prefix);
+            nonce[8..].copy_from_slice(&sequence.to_be_bytes());
+            sequence += 1;
+            let aad = [u8::from(rest.is_empty())];
+            let plain = cipher
+                .decrypt(&Nonce::from(nonce), Payload { msg: sealed, aad: &aad })
+                .expect("package should authenticate");
+            decrypted.extend_from_slice(&plain);
+        }
+        assert_eq!(sequence, 2);
+        decrypted
+    }
+
+    #[test]
+    fn test_inspect_body_writer_plain() {
+        let (tx, mut rx) = mpsc::channel(16);
+        let mut body = InspectBodyWriter::new(tx, true, None).unwrap();
+        body.write_all(b"PK").unwrap();
+        body.finish().unwrap();
+
+        assert_eq!(rx.try_recv().unwrap().unwrap(), Bytes::from_static(b"PK"));
+        assert!(rx.try_recv().is_err());
+    }
+}
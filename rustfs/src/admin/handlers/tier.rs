@@ -28,6 +28,7 @@ use rustfs_config::MAX_ADMIN_REQUEST_BODY_SIZE;
 use rustfs_ecstore::{
     config::storageclass,
     global::GLOBAL_TierConfigMgr,
+    notification_sys::get_global_notification_sys,
     tier::{
         tier::{ERR_TIER_BACKEND_IN_USE, ERR_TIER_BACKEND_NOT_EMPTY, ERR_TIER_MISSING_CREDENTIALS},
         tier_admin::TierCreds,
@@ -74,6 +75,18 @@ pub struct AddTierQuery {
     pub force: Option<String>,
 }
 
+/// Ask every peer to reload the tier configuration that was just saved.
+async fn notify_tier_config_reload() {
+    let Some(notification_sys) = get_global_notification_sys() else {
+        return;
+    };
+    for peer_err in notification_sys.load_transition_tier_config().await {
+        if let Some(err) = peer_err.err {
+            warn!("reload tier config on peer {} failed: {}", peer_err.host, err);
+        }
+    }
+}
+
 pub struct AddTier {}
 
 pub fn register_tier_route(r: &mut S3Router<AdminOperation>) -> std::io::Result<()> {
@@ -243,6 +256,8 @@ impl Operation for AddTier {
             warn!("tier_config_mgr save failed, e: {:?}", e);
             return Err(S3Error::with_message(S3ErrorCode::Custom("TierAddFailed".into()), "tier save failed"));
         }
+        drop(tier_config_mgr);
+        notify_tier_config_reload().await;
 
         let mut header = HeaderMap::new();
         header.insert(CONTENT_TYPE, "application/json".parse().unwrap());
@@ -320,6 +335,8 @@ impl Operation for EditTier {
             warn!("tier_config_mgr save failed, e: {:?}", e);
             return Err(S3Error::with_message(S3ErrorCode::Custom("TierEditFailed".into()), "tier save failed"));
         }
+        drop(tier_config_mgr);
+        notify_tier_config_reload().await;
 
         let mut header = HeaderMap::new();
         header.insert(CONTENT_TYPE, "application/json".parse().unwrap());
@@ -440,6 +457,8 @@ impl Operation for RemoveTier {
             warn!("tier_config_mgr save failed, e: {:?}", e);
             return Err(S3Error::with_message(S3ErrorCode::Custom("TierRemoveFailed".into()), "tier save failed"));
         }
+        drop(tier_config_mgr);
+        notify_tier_config_reload().await;
 
         let mut header = HeaderMap::new();
         header.insert(CONTENT_TYPE, "application/json".parse().unwrap());
@@ -600,6 +619,8 @@ impl Operation for ClearTier {
             warn!("tier_config_mgr save failed, e: {:?}", e);
             return Err(S3Error::with_message(S3ErrorCode::Custom("TierEditFailed".into()), "tier save failed"));
         }
+        drop(tier_config_mgr);
+        notify_tier_config_reload().await;
 
         let mut header = HeaderMap::new();
         header.insert(CONTENT_TYPE, "application/json".parse().unwrap());
@@ -12,16 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::admin::router::Operation;
+use crate::admin::{auth::validate_admin_request, router::Operation};
+use crate::auth::{check_key_valid, get_session_token};
+use crate::server::RemoteAddr;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
 use http::StatusCode;
 use hyper::Uri;
 use matchit::Params;
+use rustfs_common::trace_channel::subscribe_trace;
 use rustfs_ecstore::{GLOBAL_Endpoints, rpc::PeerRestClient};
 use rustfs_madmin::service_commands::ServiceTraceOpts;
-use s3s::{Body, S3Request, S3Response, S3Result, s3_error};
-use tracing::warn;
+use rustfs_madmin::trace::TraceInfo;
+use rustfs_policy::policy::action::{Action, AdminAction};
+use s3s::stream::{ByteStream, DynByteStream};
+use s3s::{Body, S3Request, S3Response, S3Result, StdError, s3_error};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+use tokio::{select, spawn};
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::{debug, warn};
+
+/// Number of trace records buffered between the collectors and the HTTP response
+const TRACE_RESPONSE_BUFFER: usize = 4000;
 
-#[allow(dead_code)]
 fn extract_trace_options(uri: &Uri) -> S3Result<ServiceTraceOpts> {
     let mut st_opts = ServiceTraceOpts::default();
     st_opts
@@ -31,21 +47,135 @@ fn extract_trace_options(uri: &Uri) -> S3Result<ServiceTraceOpts> {
     Ok(st_opts)
 }
 
-#[allow(dead_code)]
+struct TraceStream {
+    inner: ReceiverStream<Result<Bytes, StdError>>,
+}
+
+impl Stream for TraceStream {
+    type Item = Result<Bytes, StdError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = Pin::into_inner(self);
+        this.inner.poll_next_unpin(cx)
+    }
+}
+
+impl ByteStream for TraceStream {}
+
+/// Encode a trace record as a single line of JSON.
+fn encode_trace_line(info: &TraceInfo) -> Option<Bytes> {
+    match serde_json::to_vec(info) {
+        Ok(mut buf) => {
+            buf.push(b'\n');
+            Some(Bytes::from(buf))
+        }
+        Err(err) => {
+            warn!("trace: json encode failed, err: {:?}", err);
+            None
+        }
+    }
+}
+
+/// Forward records from a remote peer until either side goes away.
+async fn forward_peer_trace(peer: PeerRestClient, opts: Arc<ServiceTraceOpts>, tx: mpsc::Sender<Result<Bytes, StdError>>) {
+    let mut stream = match peer.trace(&opts).await {
+        Ok(stream) => stream,
+        Err(err) => {
+            warn!("trace: subscribe to peer {} failed, err: {:?}", peer.host, err);
+            return;
+        }
+    };
+
+    loop {
+        let info = select! {
+            _ = tx.closed() => return,
+            info = stream.next() => info,
+        };
+        match info {
+            Some(Ok(info)) => {
+                if let Some(line) = encode_trace_line(&info)
+                    && tx.send(Ok(line)).await.is_err()
+                {
+                    return;
+                }
+            }
+            Some(Err(err)) => {
+                warn!("trace: peer {} stream failed, err: {:?}", peer.host, err);
+                return;
+            }
+            None => return,
+        }
+    }
+}
+
 pub struct Trace {}
 
 #[async_trait::async_trait]
 impl Operation for Trace {
     async fn call(&self, req: S3Request<Body>, _params: Params<'_, '_>) -> S3Result<S3Response<(StatusCode, Body)>> {
-        warn!("handle Trace");
+        debug!("handle Trace, uri: {:?}", req.uri);
+
+        let Some(input_cred) = req.credentials else {
+            return Err(s3_error!(InvalidRequest, "get cred failed"));
+        };
 
-        let _trace_opts = extract_trace_options(&req.uri)?;
+        let (cred, owner) =
+            check_key_valid(get_session_token(&req.uri, &req.headers).unwrap_or_default(), &input_cred.access_key).await?;
 
-        // let (tx, rx) = mpsc::channel(10000);
-        let _peers = match GLOBAL_Endpoints.get() {
+        validate_admin_request(
+            &req.headers,
+            &cred,
+            owner,
+            false,
+            vec![Action::AdminAction(AdminAction::TraceAdminAction)],
+            req.extensions.get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0)),
+        )
+        .await?;
+
+        let trace_opts = Arc::new(extract_trace_options(&req.uri)?);
+        if trace_opts.trace_types().mask() == 0 {
+            return Err(s3_error!(InvalidRequest, "no trace type selected"));
+        }
+
+        let (tx, rx) = mpsc::channel(TRACE_RESPONSE_BUFFER);
+
+        // Subscribe locally before returning so no record is missed once the client sees the response.
+        let mut subscription = subscribe_trace(trace_opts.trace_types());
+        let local_tx = tx.clone();
+        let local_opts = trace_opts.clone();
+        spawn(async move {
+            loop {
+                let info = select! {
+                    _ = local_tx.closed() => return,
+                    info = subscription.recv() => match info {
+                        Some(info) => info,
+                        None => return,
+                    },
+                };
+                if !local_opts.matches(&info) {
+                    continue;
+                }
+                if let Some(line) = encode_trace_line(&info)
+                    && local_tx.send(Ok(line)).await.is_err()
+                {
+                    return;
+                }
+            }
+        });
+
+        let (remote_peers, _) = match GLOBAL_Endpoints.get() {
             Some(ep) => PeerRestClient::new_clients(ep.clone()).await,
             None => (Vec::new(), Vec::new()),
         };
-        Err(s3_error!(NotImplemented))
+        for peer in remote_peers.into_iter().flatten() {
+            spawn(forward_peer_trace(peer, trace_opts.clone(), tx.clone()));
+        }
+        drop(tx);
+
+        let in_stream: DynByteStream = Box::pin(TraceStream {
+            inner: ReceiverStream::new(rx),
+        });
+
+        Ok(S3Response::new((StatusCode::OK, Body::from(in_stream))))
     }
 }
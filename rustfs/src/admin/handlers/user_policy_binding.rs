@@ -12,11 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{event, policies};
+use super::{event, idp_ldap, policies};
 use crate::admin::router::{AdminOperation, S3Router};
 
 pub fn register_user_policy_binding_route(r: &mut S3Router<AdminOperation>) -> std::io::Result<()> {
     policies::register_iam_policy_route(r)?;
     event::register_notification_target_route(r)?;
+    idp_ldap::register_idp_ldap_route(r)?;
     Ok(())
 }
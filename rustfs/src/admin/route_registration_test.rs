@@ -69,6 +69,9 @@ fn test_register_routes_cover_representative_admin_paths() {
     assert_route(&router, Method::GET, &admin_path("/v3/export-iam"));
     assert_route(&router, Method::PUT, &admin_path("/v3/import-iam"));
     assert_route(&router, Method::GET, &admin_path("/v3/list-canned-policies"));
+    assert_route(&router, Method::POST, &admin_path("/v3/idp/ldap/policy/attach"));
+    assert_route(&router, Method::POST, &admin_path("/v3/idp/ldap/policy/detach"));
+    assert_route(&router, Method::GET, &admin_path("/v3/idp/ldap/policy-entities"));
     assert_route(&router, Method::GET, &admin_path("/v3/target/list"));
     assert_route(&router, Method::GET, &admin_path("/v3/accountinfo"));
 
@@ -76,12 +79,14 @@ fn test_register_routes_cover_representative_admin_paths() {
     assert_route(&router, Method::GET, &admin_path("/v3/info"));
     assert_route(&router, Method::GET, &admin_path("/v3/storageinfo"));
     assert_route(&router, Method::GET, &admin_path("/v3/metrics"));
+    assert_route(&router, Method::GET, &admin_path("/v3/trace"));
 
     assert_route(&router, Method::GET, &admin_path("/v3/pools/list"));
     assert_route(&router, Method::POST, &admin_path("/v3/rebalance/start"));
     assert_route(&router, Method::GET, &admin_path("/v3/rebalance/status"));
     assert_route(&router, Method::POST, &admin_path("/v3/heal/test-bucket"));
     assert_route(&router, Method::POST, &admin_path("/v3/heal/test-bucket/prefix"));
+    assert_route(&router, Method::POST, &admin_path("/v3/background-heal/status"));
 
     assert_route(&router, Method::GET, &admin_path("/v3/tier"));
     assert_route(&router, Method::POST, &admin_path("/v3/tier/clear"));
@@ -93,6 +98,8 @@ fn test_register_routes_cover_representative_admin_paths() {
     assert_route(&router, Method::GET, &admin_path("/v3/list-remote-targets"));
     assert_route(&router, Method::PUT, &admin_path("/v3/set-remote-target"));
     assert_route(&router, Method::GET, &admin_path("/debug/pprof/profile"));
+    assert_route(&router, Method::POST, &admin_path("/v3/profile"));
+    assert_route(&router, Method::GET, &admin_path("/v3/replicationmetrics"));
 
     assert_route(&router, Method::POST, &admin_path("/v3/kms/create-key"));
     assert_route(&router, Method::POST, &admin_path("/v3/kms/configure"));
@@ -14,6 +14,7 @@
 
 use crate::admin::console::is_console_path;
 use crate::admin::console::make_console_server;
+use crate::admin::handlers::sts::{sts_action_requires_signature, sts_request_action};
 use crate::server::{ADMIN_PREFIX, HEALTH_PREFIX, HEALTH_READY_PATH, PROFILE_CPU_PATH, PROFILE_MEMORY_PATH, RPC_PREFIX};
 use hyper::HeaderMap;
 use hyper::Method;
@@ -22,6 +23,7 @@ use hyper::Uri;
 use hyper::http::Extensions;
 use matchit::Params;
 use matchit::Router;
+use rustfs_config::MAX_ADMIN_REQUEST_BODY_SIZE;
 use rustfs_ecstore::rpc::verify_rpc_signature;
 use s3s::Body;
 use s3s::S3Request;
@@ -43,6 +45,22 @@ fn is_public_health_path(path: &str) -> bool {
     path == HEALTH_PREFIX || path == HEALTH_READY_PATH
 }
 
+/// STS requests are form-encoded POSTs to the root path, or carry their parameters in the query string
+fn is_sts_request(method: &Method, uri: &Uri, headers: &HeaderMap) -> bool {
+    if method != Method::POST || uri.path() != "/" {
+        return false;
+    }
+
+    let is_form = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|ct| ct.split(';').next().unwrap_or("").trim().to_lowercase())
+        .map(|ct| ct == "application/x-www-form-urlencoded")
+        .unwrap_or(false);
+
+    is_form || uri.query().is_some_and(|q| q.split('&').any(|kv| kv.starts_with("Action=")))
+}
+
 impl<T: Operation> S3Router<T> {
     pub fn new(console_enabled: bool) -> Self {
         let router = Router::new();
@@ -108,15 +126,7 @@ where
         }
 
         // AssumeRole
-        if method == Method::POST
-            && path == "/"
-            && headers
-                .get(header::CONTENT_TYPE)
-                .and_then(|v| v.to_str().ok())
-                .map(|ct| ct.split(';').next().unwrap_or("").trim().to_lowercase())
-                .map(|ct| ct == "application/x-www-form-urlencoded")
-                .unwrap_or(false)
-        {
+        if is_sts_request(method, uri, headers) {
             return true;
         }
 
@@ -143,6 +153,23 @@ where
             return Ok(());
         }
 
+        // Only AssumeRole needs a signature; the other STS actions carry their own proof of identity
+        if is_sts_request(&req.method, &req.uri, &req.headers) {
+            // The body stays stored in the request for the STS handler
+            let body = req
+                .input
+                .store_all_limited(MAX_ADMIN_REQUEST_BODY_SIZE)
+                .await
+                .map_err(|_| s3_error!(InvalidRequest, "STS request body too large or failed to read"))?;
+            let action = sts_request_action(&body, req.uri.query());
+
+            return match action.as_deref().and_then(sts_action_requires_signature) {
+                None => Err(s3_error!(AccessDenied, "unsupported STS action: {}", action.unwrap_or_default())),
+                Some(true) if req.credentials.is_none() => Err(s3_error!(AccessDenied, "Signature is required")),
+                Some(_) => Ok(()),
+            };
+        }
+
         // Check RPC signature verification
         if req.uri.path().starts_with(RPC_PREFIX) {
             // Skip signature verification for HEAD requests (health checks)
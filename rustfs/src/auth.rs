@@ -18,6 +18,7 @@ use rustfs_credentials::{Credentials, get_global_action_cred};
 use rustfs_iam::error::Error as IamError;
 use rustfs_iam::sys::SESSION_POLICY_NAME;
 use rustfs_iam::sys::get_claims_from_token_with_secret;
+use rustfs_policy::policy::{PRINCIPAL_ARN_KEY, principal_arns};
 use rustfs_utils::http::ip::get_source_ip_raw;
 use s3s::S3Error;
 use s3s::S3ErrorCode;
@@ -345,9 +346,14 @@ pub fn get_condition_values(
 
     // Add user and principal info
     args.insert("userid".to_owned(), vec![username.clone()]);
-    args.insert("username".to_owned(), vec![username]);
+    args.insert("username".to_owned(), vec![username.clone()]);
     args.insert("principaltype".to_owned(), vec![principal_type.to_string()]);
 
+    let principal_arns = principal_arns(cred, !username.is_empty() && constant_time_eq(&sys_cred.access_key, &username));
+    if !principal_arns.is_empty() {
+        args.insert(PRINCIPAL_ARN_KEY.to_owned(), principal_arns);
+    }
+
     // Add version ID
     if !vid.is_empty() {
         args.insert("versionid".to_owned(), vec![vid.to_string()]);
@@ -876,6 +882,10 @@ mod tests {
         assert_eq!(conditions.get("userid"), Some(&vec!["test-access-key".to_string()]));
         assert_eq!(conditions.get("username"), Some(&vec!["test-access-key".to_string()]));
         assert_eq!(conditions.get("principaltype"), Some(&vec!["User".to_string()]));
+        assert_eq!(
+            conditions.get(PRINCIPAL_ARN_KEY),
+            Some(&vec!["arn:aws:iam:::user/test-access-key".to_string()])
+        );
     }
 
     #[test]
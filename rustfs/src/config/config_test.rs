@@ -524,6 +524,25 @@ mod tests {
         assert_eq!(opt.server_domains[2], "localhost");
     }
 
+    #[test]
+    fn test_website_domains_parsing() {
+        let args = vec!["rustfs", "/data/vol1"];
+        let opt = Opt::parse_from(args);
+        assert!(opt.website_domains.is_empty());
+
+        let args = vec![
+            "rustfs",
+            "/data/vol1",
+            "--website-domains",
+            "web.example.com,static.example.com:9000",
+        ];
+        let opt = Opt::parse_from(args);
+
+        assert_eq!(opt.website_domains.len(), 2);
+        assert_eq!(opt.website_domains[0], "web.example.com");
+        assert_eq!(opt.website_domains[1], "static.example.com:9000");
+    }
+
     #[test]
     fn test_access_key_arguments_mutually_exclusive_cli() {
         // Test that CLI args configuration fails on conflict
@@ -77,6 +77,15 @@ pub struct Opt {
     )]
     pub server_domains: Vec<String>,
 
+    /// Domain names serving bucket static websites as `<bucket>.<domain>`.
+    #[arg(
+        long,
+        env = "RUSTFS_WEBSITE_DOMAINS",
+        value_delimiter = ',',
+        value_parser = NonEmptyStringValueParser::new()
+    )]
+    pub website_domains: Vec<String>,
+
     /// Access key used for authentication.
     #[arg(long, env = "RUSTFS_ACCESS_KEY", group = "access-key")]
     pub access_key: Option<String>,
@@ -131,7 +140,7 @@ pub struct Opt {
     #[arg(long, default_value_t = false, env = "RUSTFS_KMS_ENABLE")]
     pub kms_enable: bool,
 
-    /// KMS backend type (local or vault)
+    /// KMS backend type (local, vault, vault-transit, kmip or pkcs11)
     #[arg(long, default_value_t = String::from("local"), env = "RUSTFS_KMS_BACKEND")]
     pub kms_backend: String,
 
@@ -173,6 +182,9 @@ pub struct Config {
     /// Domain name used for virtual-hosted-style requests.
     pub server_domains: Vec<String>,
 
+    /// Domain names serving bucket static websites as `<bucket>.<domain>`.
+    pub website_domains: Vec<String>,
+
     /// Access key used for authentication.
     pub access_key: String,
 
@@ -198,7 +210,7 @@ pub struct Config {
     /// Enable KMS encryption for server-side encryption
     pub kms_enable: bool,
 
-    /// KMS backend type (local or vault)
+    /// KMS backend type (local, vault, vault-transit, kmip or pkcs11)
     pub kms_backend: String,
 
     /// KMS key directory for local backend
@@ -232,6 +244,7 @@ impl Config {
             volumes,
             address,
             server_domains,
+            website_domains,
             access_key,
             access_key_file,
             secret_key,
@@ -284,6 +297,7 @@ impl Config {
             volumes,
             address,
             server_domains,
+            website_domains,
             access_key,
             secret_key,
             console_enable,
@@ -310,6 +324,7 @@ impl std::fmt::Debug for Config {
             .field("volumes", &self.volumes)
             .field("address", &self.address)
             .field("server_domains", &self.server_domains)
+            .field("website_domains", &self.website_domains)
             .field("access_key", &self.access_key)
             .field("secret_key", &rustfs_credentials::Masked(Some(&self.secret_key))) // Hide sensitive values
             .field("console_enable", &self.console_enable)
@@ -0,0 +1,89 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rustfs_common::heal_channel::HealScanMode;
+use rustfs_ecstore::admin_server_info::get_local_background_heal_state;
+use rustfs_ecstore::new_object_layer_fn;
+use rustfs_madmin::HealingDisk;
+use rustfs_madmin::heal_commands::BgHealState;
+use rustfs_scanner::scanner::read_background_heal_info;
+use time::OffsetDateTime;
+
+fn scan_mode_name(mode: HealScanMode) -> &'static str {
+    match mode {
+        HealScanMode::Normal => "normal",
+        HealScanMode::Deep => "deep",
+        HealScanMode::Unknown => "unknown",
+    }
+}
+
+/// Background heal state of this node: drive status, scanner bitrot info and
+/// the resync progress of drives the heal manager is working on.
+pub async fn local_background_heal_state() -> Option<BgHealState> {
+    let mut state = get_local_background_heal_state().await?;
+
+    if let Some(store) = new_object_layer_fn() {
+        let info = read_background_heal_info(store).await;
+        state.bitrot_start_time = info.bitrot_start_time;
+        state.bitrot_start_cycle = info.bitrot_start_cycle;
+        state.current_scan_mode = scan_mode_name(info.current_scan_mode).to_string();
+    }
+
+    if let Some(manager) = rustfs_heal::get_heal_manager() {
+        state.heal_queue_length = manager.get_queue_length().await as u64;
+        state.heal_active_tasks = manager.get_active_task_count().await as u64;
+
+        for status in manager.get_healing_drives().await {
+            let drive = status.drive;
+            let progress = status.progress.unwrap_or_default();
+            state.heal_disks.push(drive.endpoint.clone());
+            state.healing_disks.push(HealingDisk {
+                id: drive.set_disk_id.clone(),
+                heal_id: drive.set_disk_id,
+                pool_index: usize::try_from(drive.pool_index).ok(),
+                set_index: usize::try_from(drive.set_index).ok(),
+                disk_index: usize::try_from(drive.disk_index).ok(),
+                endpoint: drive.endpoint,
+                started: Some(OffsetDateTime::from(drive.started)),
+                last_update: progress.last_update_time,
+                objects_total_count: progress.objects_scanned,
+                items_healed: progress.items_healed,
+                items_failed: progress.items_failed,
+                objects_healed: progress.objects_healed,
+                objects_failed: progress.objects_failed,
+                bytes_done: progress.bytes_processed,
+                object: progress.current_object.clone().unwrap_or_default(),
+                eta: progress.estimate_completion(),
+                ..Default::default()
+            });
+        }
+        state.heal_disks.sort();
+        state.heal_disks.dedup();
+    }
+
+    Some(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scan_mode_name;
+    use rustfs_common::heal_channel::HealScanMode;
+
+    #[test]
+    fn test_scan_mode_name() {
+        assert_eq!(scan_mode_name(HealScanMode::Normal), "normal");
+        assert_eq!(scan_mode_name(HealScanMode::Deep), "deep");
+        assert_eq!(scan_mode_name(HealScanMode::Unknown), "unknown");
+    }
+}
@@ -197,7 +197,7 @@ pub(crate) async fn init_kms_system(config: &config::Config) -> std::io::Result<
                     cache_config: rustfs_kms::config::CacheConfig::default(),
                 }
             }
-            "vault" => {
+            "vault" | "vault-transit" => {
                 let vault_address = config
                     .kms_vault_address
                     .as_ref()
@@ -207,19 +207,62 @@ pub(crate) async fn init_kms_system(config: &config::Config) -> std::io::Result<
                     .as_ref()
                     .ok_or_else(|| Error::other("Vault token is required for vault backend"))?;
 
+                let vault_config = Box::new(rustfs_kms::config::VaultConfig {
+                    address: vault_address.clone(),
+                    auth_method: rustfs_kms::config::VaultAuthMethod::Token {
+                        token: vault_token.clone(),
+                    },
+                    namespace: None,
+                    mount_path: "transit".to_string(),
+                    kv_mount: "secret".to_string(),
+                    key_path_prefix: "rustfs/kms/keys".to_string(),
+                    tls: None,
+                });
+                let (backend, backend_config) = if config.kms_backend == "vault-transit" {
+                    (
+                        rustfs_kms::config::KmsBackend::VaultTransit,
+                        rustfs_kms::config::BackendConfig::VaultTransit(vault_config),
+                    )
+                } else {
+                    (
+                        rustfs_kms::config::KmsBackend::Vault,
+                        rustfs_kms::config::BackendConfig::Vault(vault_config),
+                    )
+                };
+
+                rustfs_kms::config::KmsConfig {
+                    backend,
+                    backend_config,
+                    default_key_id: config.kms_default_key_id.clone(),
+                    timeout: std::time::Duration::from_secs(30),
+                    retry_attempts: 3,
+                    enable_cache: true,
+                    cache_config: rustfs_kms::config::CacheConfig::default(),
+                }
+            }
+            "kmip" => {
+                // KMIP connection settings come from RUSTFS_KMS_KMIP_* environment variables
+                let kmip_config = rustfs_kms::config::KmipConfig::from_env()
+                    .map_err(|e| Error::other(format!("Invalid KMIP configuration: {e}")))?;
+
                 rustfs_kms::config::KmsConfig {
-                    backend: rustfs_kms::config::KmsBackend::Vault,
-                    backend_config: rustfs_kms::config::BackendConfig::Vault(Box::new(rustfs_kms::config::VaultConfig {
-                        address: vault_address.clone(),
-                        auth_method: rustfs_kms::config::VaultAuthMethod::Token {
-                            token: vault_token.clone(),
-                        },
-                        namespace: None,
-                        mount_path: "transit".to_string(),
-                        kv_mount: "secret".to_string(),
-                        key_path_prefix: "rustfs/kms/keys".to_string(),
-                        tls: None,
-                    })),
+                    backend: rustfs_kms::config::KmsBackend::Kmip,
+                    backend_config: rustfs_kms::config::BackendConfig::Kmip(Box::new(kmip_config)),
+                    default_key_id: config.kms_default_key_id.clone(),
+                    timeout: std::time::Duration::from_secs(30),
+                    retry_attempts: 3,
+                    enable_cache: true,
+                    cache_config: rustfs_kms::config::CacheConfig::default(),
+                }
+            }
+            "pkcs11" => {
+                // PKCS#11 module and token settings come from RUSTFS_KMS_PKCS11_* environment variables
+                let pkcs11_config = rustfs_kms::config::Pkcs11Config::from_env()
+                    .map_err(|e| Error::other(format!("Invalid PKCS#11 configuration: {e}")))?;
+
+                rustfs_kms::config::KmsConfig {
+                    backend: rustfs_kms::config::KmsBackend::Pkcs11,
+                    backend_config: rustfs_kms::config::BackendConfig::Pkcs11(Box::new(pkcs11_config)),
                     default_key_id: config.kms_default_key_id.clone(),
                     timeout: std::time::Duration::from_secs(30),
                     retry_attempts: 3,
@@ -474,3 +517,143 @@ pub async fn init_ftps_system() -> Result<Option<tokio::sync::broadcast::Sender<
         Ok(Some(shutdown_tx))
     }
 }
+
+/// Initialize the SFTP system
+///
+/// This function initializes the SFTP server if enabled in the configuration.
+/// Users authenticate with their access key and either their secret key or one
+/// of the SSH public keys stored in IAM.
+#[cfg(feature = "sftp")]
+#[instrument(skip_all)]
+pub async fn init_sftp_system() -> Result<Option<tokio::sync::broadcast::Sender<()>>, Box<dyn std::error::Error + Send + Sync>> {
+    {
+        use crate::protocols::ProtocolStorageClient;
+        use rustfs_config::{
+            DEFAULT_SFTP_ADDRESS, DEFAULT_SFTP_IDLE_TIMEOUT, ENV_SFTP_ADDRESS, ENV_SFTP_ENABLE, ENV_SFTP_HOST_KEY,
+            ENV_SFTP_IDLE_TIMEOUT, ENV_SFTP_PASSWORD_AUTH,
+        };
+        use rustfs_protocols::{SftpConfig, SftpServer};
+        // Check if SFTP is enabled
+        let sftp_enable = rustfs_utils::get_env_bool(ENV_SFTP_ENABLE, false);
+        if !sftp_enable {
+            debug!("SFTP system is disabled");
+            return Ok(None);
+        }
+
+        // Parse SFTP address
+        let sftp_address_str = rustfs_utils::get_env_str(ENV_SFTP_ADDRESS, DEFAULT_SFTP_ADDRESS);
+        let addr = rustfs_utils::net::parse_and_resolve_address(&sftp_address_str)
+            .map_err(|e| format!("Invalid SFTP address '{sftp_address_str}': {e}"))?;
+
+        // Get SFTP configuration from environment variables
+        let host_key_file = rustfs_utils::get_env_opt_str(ENV_SFTP_HOST_KEY);
+        let password_auth = rustfs_utils::get_env_bool(ENV_SFTP_PASSWORD_AUTH, true);
+        let idle_timeout = rustfs_utils::get_env_u64(ENV_SFTP_IDLE_TIMEOUT, DEFAULT_SFTP_IDLE_TIMEOUT);
+
+        // Create SFTP configuration
+        let config = SftpConfig {
+            bind_addr: addr,
+            host_key_file,
+            password_auth,
+            idle_timeout: std::time::Duration::from_secs(idle_timeout),
+        };
+
+        // Validate SFTP configuration
+        config.validate().await?;
+
+        // Create SFTP server with protocol storage client
+        let fs = crate::storage::ecfs::FS::new();
+        let storage_client = ProtocolStorageClient::new(fs);
+        let server: SftpServer<crate::protocols::ProtocolStorageClient> = SftpServer::new(config, storage_client).await?;
+
+        // Log server configuration
+        info!(
+            "SFTP server configured on {} (password auth: {})",
+            server.config().bind_addr,
+            server.config().password_auth
+        );
+
+        // Start SFTP server in background task with proper shutdown support
+        let (shutdown_tx, shutdown_rx) = tokio::sync::broadcast::channel(1);
+
+        tokio::spawn(async move {
+            if let Err(e) = server.start(shutdown_rx).await {
+                error!("SFTP server error: {}", e);
+            }
+            info!("SFTP server shutdown completed");
+        });
+
+        info!("SFTP system initialized successfully");
+        Ok(Some(shutdown_tx))
+    }
+}
+
+/// Initialize the WebDAV system
+///
+/// This function initializes the WebDAV server if enabled in the configuration.
+/// Clients authenticate with HTTP Basic auth using their access and secret key.
+#[cfg(feature = "webdav")]
+#[instrument(skip_all)]
+pub async fn init_webdav_system() -> Result<Option<tokio::sync::broadcast::Sender<()>>, Box<dyn std::error::Error + Send + Sync>>
+{
+    {
+        use crate::protocols::ProtocolStorageClient;
+        use rustfs_config::{
+            DEFAULT_WEBDAV_ADDRESS, DEFAULT_WEBDAV_MAX_LOCK_TIMEOUT, ENV_WEBDAV_ADDRESS, ENV_WEBDAV_CERTS_DIR, ENV_WEBDAV_ENABLE,
+            ENV_WEBDAV_MAX_LOCK_TIMEOUT, ENV_WEBDAV_TLS_ENABLED,
+        };
+        use rustfs_protocols::{WebDavConfig, WebDavServer};
+        // Check if WebDAV is enabled
+        let webdav_enable = rustfs_utils::get_env_bool(ENV_WEBDAV_ENABLE, false);
+        if !webdav_enable {
+            debug!("WebDAV system is disabled");
+            return Ok(None);
+        }
+
+        // Parse WebDAV address
+        let webdav_address_str = rustfs_utils::get_env_str(ENV_WEBDAV_ADDRESS, DEFAULT_WEBDAV_ADDRESS);
+        let addr = rustfs_utils::net::parse_and_resolve_address(&webdav_address_str)
+            .map_err(|e| format!("Invalid WebDAV address '{webdav_address_str}': {e}"))?;
+
+        // Get WebDAV configuration from environment variables
+        let tls_enabled = rustfs_utils::get_env_bool(ENV_WEBDAV_TLS_ENABLED, false);
+        let cert_dir = rustfs_utils::get_env_opt_str(ENV_WEBDAV_CERTS_DIR);
+        let max_lock_timeout = rustfs_utils::get_env_u64(ENV_WEBDAV_MAX_LOCK_TIMEOUT, DEFAULT_WEBDAV_MAX_LOCK_TIMEOUT);
+
+        // Create WebDAV configuration
+        let config = WebDavConfig {
+            bind_addr: addr,
+            tls_enabled,
+            cert_dir,
+            max_lock_timeout: std::time::Duration::from_secs(max_lock_timeout),
+        };
+
+        // Validate WebDAV configuration
+        config.validate().await?;
+
+        // Create WebDAV server with protocol storage client
+        let fs = crate::storage::ecfs::FS::new();
+        let storage_client = ProtocolStorageClient::new(fs);
+        let server: WebDavServer<crate::protocols::ProtocolStorageClient> = WebDavServer::new(config, storage_client).await?;
+
+        // Log server configuration
+        info!(
+            "WebDAV server configured on {} (TLS: {})",
+            server.config().bind_addr,
+            server.config().tls_enabled
+        );
+
+        // Start WebDAV server in background task with proper shutdown support
+        let (shutdown_tx, shutdown_rx) = tokio::sync::broadcast::channel(1);
+
+        tokio::spawn(async move {
+            if let Err(e) = server.start(shutdown_rx).await {
+                error!("WebDAV server error: {}", e);
+            }
+            info!("WebDAV server shutdown completed");
+        });
+
+        info!("WebDAV system initialized successfully");
+        Ok(Some(shutdown_tx))
+    }
+}
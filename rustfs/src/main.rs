@@ -16,10 +16,11 @@ mod admin;
 mod auth;
 mod config;
 mod error;
+mod heal_status;
 mod init;
 mod license;
 mod profiling;
-#[cfg(feature = "ftps")]
+#[cfg(any(feature = "ftps", feature = "sftp", feature = "webdav"))]
 mod protocols;
 mod server;
 mod storage;
@@ -34,9 +35,15 @@ use crate::init::{
 #[cfg(feature = "ftps")]
 use crate::init::{init_ftp_system, init_ftps_system};
 
+#[cfg(feature = "sftp")]
+use crate::init::init_sftp_system;
+
+#[cfg(feature = "webdav")]
+use crate::init::init_webdav_system;
+
 use crate::server::{
-    SHUTDOWN_TIMEOUT, ServiceState, ServiceStateManager, ShutdownSignal, init_cert, init_event_notifier, shutdown_event_notifier,
-    start_audit_system, start_http_server, stop_audit_system, wait_for_shutdown,
+    SHUTDOWN_TIMEOUT, ServiceState, ServiceStateManager, ShutdownSignal, init_cert, init_event_notifier, restart_process,
+    shutdown_event_notifier, start_audit_system, start_http_server, stop_audit_system, wait_for_shutdown,
 };
 use license::init_license;
 use rustfs_common::{GlobalReadiness, SystemStage, set_global_addr};
@@ -61,9 +68,11 @@ use rustfs_heal::{
     create_ahm_services_cancel_token, heal::storage::ECStoreHealStorage, init_heal_manager, shutdown_ahm_services,
 };
 use rustfs_iam::init_iam_sys;
+use rustfs_kms::backends::hsm::set_key_state_store;
 use rustfs_metrics::init_metrics_system;
 use rustfs_obs::{init_obs, set_global_guard};
 use rustfs_scanner::init_data_scanner;
+use rustfs_scanner::scanner_inventory::set_inventory_writer;
 use rustfs_utils::net::parse_and_resolve_address;
 use std::io::{Error, Result};
 use std::sync::Arc;
@@ -288,7 +297,8 @@ async fn run(config: config::Config) -> Result<()> {
     readiness.mark_stage(SystemStage::StorageReady);
     // init replication_pool
     init_background_replication(store.clone()).await;
-    // Initialize KMS system if enabled
+    // Initialize KMS system if enabled; KMIP and PKCS#11 key state lives in the cluster metadata
+    set_key_state_store(Arc::new(admin::handlers::kms_dynamic::ClusterKeyStateStore));
     init_kms_system(&config).await?;
 
     // Initialize FTP system if enabled
@@ -331,6 +341,46 @@ async fn run(config: config::Config) -> Result<()> {
     #[cfg(not(feature = "ftps"))]
     let ftps_shutdown_tx: Option<tokio::sync::broadcast::Sender<()>> = None;
 
+    // Initialize SFTP system if enabled
+    #[cfg(feature = "sftp")]
+    let sftp_shutdown_tx = match init_sftp_system().await {
+        Ok(Some(tx)) => {
+            info!("SFTP system initialized successfully");
+            Some(tx)
+        }
+        Ok(None) => {
+            info!("SFTP system disabled");
+            None
+        }
+        Err(e) => {
+            error!("Failed to initialize SFTP system: {}", e);
+            return Err(Error::other(e));
+        }
+    };
+
+    #[cfg(not(feature = "sftp"))]
+    let sftp_shutdown_tx: Option<tokio::sync::broadcast::Sender<()>> = None;
+
+    // Initialize WebDAV system if enabled
+    #[cfg(feature = "webdav")]
+    let webdav_shutdown_tx = match init_webdav_system().await {
+        Ok(Some(tx)) => {
+            info!("WebDAV system initialized successfully");
+            Some(tx)
+        }
+        Ok(None) => {
+            info!("WebDAV system disabled");
+            None
+        }
+        Err(e) => {
+            error!("Failed to initialize WebDAV system: {}", e);
+            return Err(Error::other(e));
+        }
+    };
+
+    #[cfg(not(feature = "webdav"))]
+    let webdav_shutdown_tx: Option<tokio::sync::broadcast::Sender<()>> = None;
+
     // Initialize buffer profiling system
     init_buffer_profile_system(&config);
 
@@ -359,6 +409,9 @@ async fn run(config: config::Config) -> Result<()> {
 
     init_bucket_metadata_sys(store.clone(), buckets.clone()).await;
 
+    // Periodically write buffered server access log records to their target buckets
+    storage::access_log::init_access_log();
+
     // 3. Initialize IAM System (Blocking load)
     // This ensures data is in memory before moving forward
     init_iam_sys(store.clone()).await.map_err(Error::other)?;
@@ -392,6 +445,7 @@ async fn run(config: config::Config) -> Result<()> {
 
         init_heal_manager(heal_storage, None).await?;
 
+        set_inventory_writer(Arc::new(storage::inventory::ObjectInventoryWriter));
         init_data_scanner(ctx.clone(), store.clone()).await;
     } else {
         info!(target: "rustfs::main::run","Both scanner and heal are disabled, skipping AHM service initialization");
@@ -423,31 +477,24 @@ async fn run(config: config::Config) -> Result<()> {
     // Perform hibernation for 1 second
     tokio::time::sleep(SHUTDOWN_TIMEOUT).await;
     // listen to the shutdown signal
-    match wait_for_shutdown().await {
-        #[cfg(unix)]
-        ShutdownSignal::CtrlC | ShutdownSignal::Sigint | ShutdownSignal::Sigterm => {
-            handle_shutdown(
-                &state_manager,
-                s3_shutdown_tx,
-                console_shutdown_tx,
-                ftp_shutdown_tx,
-                ftps_shutdown_tx,
-                ctx.clone(),
-            )
-            .await;
-        }
-        #[cfg(not(unix))]
-        ShutdownSignal::CtrlC => {
-            handle_shutdown(
-                &state_manager,
-                s3_shutdown_tx,
-                console_shutdown_tx,
-                ftp_shutdown_tx,
-                ftps_shutdown_tx,
-                ctx.clone(),
-            )
-            .await;
-        }
+    let signal = wait_for_shutdown().await;
+    handle_shutdown(
+        &state_manager,
+        s3_shutdown_tx,
+        console_shutdown_tx,
+        ftp_shutdown_tx,
+        ftps_shutdown_tx,
+        sftp_shutdown_tx,
+        webdav_shutdown_tx,
+        ctx.clone(),
+    )
+    .await;
+
+    if let ShutdownSignal::ServiceRestart = signal {
+        info!(target: "rustfs::main::run", "restarting server");
+        let err = restart_process();
+        error!("Failed to restart server: {}", err);
+        return Err(err);
     }
 
     info!(target: "rustfs::main::run","server is stopped state: {:?}", state_manager.current_state());
@@ -461,6 +508,8 @@ async fn handle_shutdown(
     console_shutdown_tx: Option<tokio::sync::broadcast::Sender<()>>,
     ftp_shutdown_tx: Option<tokio::sync::broadcast::Sender<()>>,
     ftps_shutdown_tx: Option<tokio::sync::broadcast::Sender<()>>,
+    sftp_shutdown_tx: Option<tokio::sync::broadcast::Sender<()>>,
+    webdav_shutdown_tx: Option<tokio::sync::broadcast::Sender<()>>,
     ctx: CancellationToken,
 ) {
     ctx.cancel();
@@ -496,7 +545,7 @@ async fn handle_shutdown(
         );
     }
 
-    // Shutdown FTP and FTPS servers
+    // Shutdown FTP, FTPS, SFTP and WebDAV servers
     if let Some(ftp_shutdown_tx) = ftp_shutdown_tx {
         info!(
             target: "rustfs::main::handle_shutdown",
@@ -513,6 +562,22 @@ async fn handle_shutdown(
         let _ = ftps_shutdown_tx.send(());
     }
 
+    if let Some(sftp_shutdown_tx) = sftp_shutdown_tx {
+        info!(
+            target: "rustfs::main::handle_shutdown",
+            "Shutting down SFTP server..."
+        );
+        let _ = sftp_shutdown_tx.send(());
+    }
+
+    if let Some(webdav_shutdown_tx) = webdav_shutdown_tx {
+        info!(
+            target: "rustfs::main::handle_shutdown",
+            "Shutting down WebDAV server..."
+        );
+        let _ = webdav_shutdown_tx.send(());
+    }
+
     // Stop the notification system
     info!(
         target: "rustfs::main::handle_shutdown",
@@ -520,6 +585,13 @@ async fn handle_shutdown(
     );
     shutdown_event_notifier().await;
 
+    // Write out buffered server access log records
+    info!(
+        target: "rustfs::main::handle_shutdown",
+        "Flushing server access logs..."
+    );
+    storage::access_log::flush_access_log().await;
+
     // Stop the audit system
     info!(
         target: "rustfs::main::handle_shutdown",
@@ -40,9 +40,19 @@ mod windows_impl {
     pub async fn dump_memory_pprof_now() -> Result<PathBuf, String> {
         Err("Memory profiling is not supported on Windows platform".to_string())
     }
+
+    pub async fn start_cpu_session() -> Result<(), String> {
+        Err("CPU profiling is not supported on Windows platform".to_string())
+    }
+
+    pub async fn stop_cpu_session() -> Result<Vec<u8>, String> {
+        Err("CPU profiling is not supported on Windows platform".to_string())
+    }
 }
 #[cfg(target_os = "windows")]
-pub use windows_impl::{dump_cpu_pprof_for, dump_memory_pprof_now, init_from_env, shutdown_profiling};
+pub use windows_impl::{
+    dump_cpu_pprof_for, dump_memory_pprof_now, init_from_env, shutdown_profiling, start_cpu_session, stop_cpu_session,
+};
 
 #[cfg(all(
     not(target_os = "windows"),
@@ -138,12 +148,15 @@ mod generic_impl {
         allocator::set_enabled(false);
     }
 
-    pub async fn dump_cpu_pprof_for(_duration: Duration) -> Result<PathBuf, String> {
+    fn cpu_unsupported_msg() -> String {
         let (target_os, target_env, target_arch) = get_platform_info();
-        let msg = format!(
+        format!(
             "CPU profiling is not supported on this platform. target_os={target_os}, target_env={target_env}, target_arch={target_arch}"
-        );
-        Err(msg)
+        )
+    }
+
+    pub async fn dump_cpu_pprof_for(_duration: Duration) -> Result<PathBuf, String> {
+        Err(cpu_unsupported_msg())
     }
 
     pub async fn dump_memory_pprof_now() -> Result<PathBuf, String> {
@@ -153,13 +166,23 @@ mod generic_impl {
             out
         })
     }
+
+    pub async fn start_cpu_session() -> Result<(), String> {
+        Err(cpu_unsupported_msg())
+    }
+
+    pub async fn stop_cpu_session() -> Result<Vec<u8>, String> {
+        Err(cpu_unsupported_msg())
+    }
 }
 
 #[cfg(all(
     not(target_os = "windows"),
     not(all(target_os = "linux", target_env = "gnu", target_arch = "x86_64"))
 ))]
-pub use generic_impl::{dump_cpu_pprof_for, dump_memory_pprof_now, init_from_env, shutdown_profiling};
+pub use generic_impl::{
+    dump_cpu_pprof_for, dump_memory_pprof_now, init_from_env, shutdown_profiling, start_cpu_session, stop_cpu_session,
+};
 
 #[cfg(all(target_os = "linux", target_env = "gnu", target_arch = "x86_64"))]
 mod linux_impl {
@@ -182,6 +205,7 @@ mod linux_impl {
     use tracing::{debug, error, info, warn};
 
     static CPU_CONT_GUARD: OnceLock<Arc<Mutex<Option<pprof::ProfilerGuard<'static>>>>> = OnceLock::new();
+    static CPU_SESSION_GUARD: OnceLock<Arc<Mutex<Option<pprof::ProfilerGuard<'static>>>>> = OnceLock::new();
     static PROFILING_CANCEL_TOKEN: OnceLock<CancellationToken> = OnceLock::new();
 
     /// CPU profiling mode
@@ -217,11 +241,17 @@ mod linux_impl {
         jiff::Zoned::now().strftime("%Y%m%dT%H%M%S").to_string()
     }
 
-    /// Write pprof report to file in protobuf format
-    fn write_pprof_report_pb(report: &pprof::Report, path: &Path) -> Result<(), String> {
+    /// Encode pprof report in protobuf format
+    fn encode_pprof_report(report: &pprof::Report) -> Result<Vec<u8>, String> {
         let profile = report.pprof().map_err(|e| format!("pprof() failed: {e}"))?;
         let mut buf = Vec::with_capacity(512 * 1024);
         profile.write_to_vec(&mut buf).map_err(|e| format!("encode failed: {e}"))?;
+        Ok(buf)
+    }
+
+    /// Write pprof report to file in protobuf format
+    fn write_pprof_report_pb(report: &pprof::Report, path: &Path) -> Result<(), String> {
+        let buf = encode_pprof_report(report)?;
         let mut f = File::create(path).map_err(|e| format!("create file failed: {e}"))?;
         f.write_all(&buf).map_err(|e| format!("write file failed: {e}"))?;
         Ok(())
@@ -273,6 +303,33 @@ mod linux_impl {
         Ok(out)
     }
 
+    // Public API: start an open-ended CPU profiling session, ended by `stop_cpu_session`
+    pub async fn start_cpu_session() -> Result<(), String> {
+        let cell = CPU_SESSION_GUARD.get_or_init(|| Arc::new(Mutex::new(None))).clone();
+        let mut slot = cell.lock().await;
+        if slot.is_some() {
+            return Err("CPU profiling session already running".to_string());
+        }
+
+        let freq = get_env_usize(ENV_CPU_FREQ, DEFAULT_CPU_FREQ) as i32;
+        let guard = pprof::ProfilerGuard::new(freq).map_err(|e| format!("create profiler failed: {e}"))?;
+        *slot = Some(guard);
+        info!(freq, "start CPU profiling session");
+        Ok(())
+    }
+
+    // Public API: stop the running CPU profiling session and return the encoded profile
+    pub async fn stop_cpu_session() -> Result<Vec<u8>, String> {
+        let guard = match CPU_SESSION_GUARD.get() {
+            Some(cell) => cell.lock().await.take(),
+            None => None,
+        };
+        let guard = guard.ok_or_else(|| "no CPU profiling session running".to_string())?;
+
+        let report = guard.report().build().map_err(|e| format!("build report failed: {e}"))?;
+        encode_pprof_report(&report)
+    }
+
     // Jemalloc status check (No forced placement, only status observation)
     pub async fn check_jemalloc_profiling() {
         use tikv_jemalloc_ctl::{config, epoch, stats};
@@ -471,4 +528,60 @@ mod linux_impl {
 }
 
 #[cfg(all(target_os = "linux", target_env = "gnu", target_arch = "x86_64"))]
-pub use linux_impl::{dump_cpu_pprof_for, dump_memory_pprof_now, init_from_env, shutdown_profiling};
+pub use linux_impl::{
+    dump_cpu_pprof_for, dump_memory_pprof_now, init_from_env, shutdown_profiling, start_cpu_session, stop_cpu_session,
+};
+
+pub const PROFILER_CPU: &str = "cpu";
+pub const PROFILER_MEM: &str = "mem";
+
+// Profilers started by `start_profiling_session` and not yet downloaded
+static ACTIVE_PROFILERS: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+/// Start the profilers named in the comma separated `profilers` list.
+///
+/// The session runs until `download_profiling_session` collects it.
+pub async fn start_profiling_session(profilers: &str) -> Result<(), String> {
+    let requested: Vec<String> = profilers
+        .split(',')
+        .map(|p| p.trim().to_lowercase())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if requested.is_empty() {
+        return Err("no profiler type specified".to_string());
+    }
+    if let Some(other) = requested.iter().find(|p| *p != PROFILER_CPU && *p != PROFILER_MEM) {
+        return Err(format!("unsupported profiler type: {other}"));
+    }
+    if !ACTIVE_PROFILERS.lock().unwrap_or_else(|e| e.into_inner()).is_empty() {
+        return Err("a profiling session is already running".to_string());
+    }
+
+    if requested.iter().any(|p| p == PROFILER_CPU) {
+        start_cpu_session().await?;
+    }
+    *ACTIVE_PROFILERS.lock().unwrap_or_else(|e| e.into_inner()) = requested;
+    Ok(())
+}
+
+/// Stop the running profiling session and return each profile keyed by profiler type.
+pub async fn download_profiling_session() -> Result<std::collections::HashMap<String, Vec<u8>>, String> {
+    let active = std::mem::take(&mut *ACTIVE_PROFILERS.lock().unwrap_or_else(|e| e.into_inner()));
+    if active.is_empty() {
+        return Err("no profiling session is running".to_string());
+    }
+
+    let mut data = std::collections::HashMap::with_capacity(active.len());
+    for profiler in active {
+        let bytes = if profiler == PROFILER_CPU {
+            stop_cpu_session().await?
+        } else {
+            let path = dump_memory_pprof_now().await?;
+            tokio::fs::read(&path)
+                .await
+                .map_err(|e| format!("read memory profile failed: {e}"))?
+        };
+        data.insert(profiler, bytes);
+    }
+    Ok(data)
+}
@@ -194,6 +194,54 @@ impl rustfs_protocols::common::client::s3::StorageBackend for ProtocolStorageCli
         }
     }
 
+    async fn copy_object(
+        &self,
+        src_bucket: &str,
+        src_key: &str,
+        dst_bucket: &str,
+        dst_key: &str,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<CopyObjectOutput, Self::Error> {
+        trace!(
+            "Protocol storage client CopyObject request: src={}/{}, dst={}/{}",
+            src_bucket, src_key, dst_bucket, dst_key
+        );
+
+        let input = CopyObjectInput::builder()
+            .bucket(dst_bucket.to_string())
+            .key(dst_key.to_string())
+            .copy_source(CopySource::Bucket {
+                bucket: src_bucket.into(),
+                key: src_key.into(),
+                version_id: None,
+            })
+            .build()
+            .map_err(|e| {
+                s3s::S3Error::with_message(s3s::S3ErrorCode::InvalidRequest, format!("Failed to build CopyObjectInput: {}", e))
+            })?;
+
+        let uri: http::Uri = format!("/{}{}", dst_bucket, dst_key).parse().unwrap_or_default();
+        let req = self
+            .create_request(
+                input,
+                Method::PUT,
+                uri,
+                RequestParams {
+                    bucket: Some(dst_bucket.to_string()),
+                    object: Some(dst_key.to_string()),
+                    access_key,
+                    secret_key,
+                },
+            )
+            .await?;
+
+        match self.fs.copy_object(req).await {
+            Ok(response) => Ok(response.output),
+            Err(e) => Err(e),
+        }
+    }
+
     async fn delete_object(
         &self,
         bucket: &str,
@@ -459,4 +507,178 @@ impl rustfs_protocols::common::client::s3::StorageBackend for ProtocolStorageCli
             Err(e) => Err(e),
         }
     }
+
+    async fn create_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<CreateMultipartUploadOutput, Self::Error> {
+        trace!("Protocol storage client CreateMultipartUpload request: bucket={}, key={}", bucket, key);
+
+        let input = CreateMultipartUploadInput::builder()
+            .bucket(bucket.to_string())
+            .key(key.to_string())
+            .build()
+            .map_err(|e| {
+                s3s::S3Error::with_message(
+                    s3s::S3ErrorCode::InvalidRequest,
+                    format!("Failed to build CreateMultipartUploadInput: {}", e),
+                )
+            })?;
+
+        let uri: http::Uri = format!("/{}/{}?uploads", bucket, key).parse().unwrap_or_default();
+        let req = self
+            .create_request(
+                input,
+                Method::POST,
+                uri,
+                RequestParams {
+                    bucket: Some(bucket.to_string()),
+                    object: Some(key.to_string()),
+                    access_key,
+                    secret_key,
+                },
+            )
+            .await?;
+
+        match self.fs.create_multipart_upload(req).await {
+            Ok(response) => Ok(response.output),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn upload_part(
+        &self,
+        input: UploadPartInput,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<UploadPartOutput, Self::Error> {
+        trace!(
+            "Protocol storage client UploadPart request: bucket={}, key={}, part={}",
+            input.bucket, input.key, input.part_number
+        );
+
+        let bucket = input.bucket.clone();
+        let key = input.key.clone();
+        let uri: http::Uri = format!("/{}/{}?partNumber={}&uploadId={}", bucket, key, input.part_number, input.upload_id)
+            .parse()
+            .unwrap_or_default();
+        let req = self
+            .create_request(
+                input,
+                Method::PUT,
+                uri,
+                RequestParams {
+                    bucket: Some(bucket),
+                    object: Some(key),
+                    access_key,
+                    secret_key,
+                },
+            )
+            .await?;
+
+        match self.fs.upload_part(req).await {
+            Ok(response) => Ok(response.output),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn complete_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        upload_id: &str,
+        parts: Vec<CompletedPart>,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<CompleteMultipartUploadOutput, Self::Error> {
+        trace!(
+            "Protocol storage client CompleteMultipartUpload request: bucket={}, key={}, parts={}",
+            bucket,
+            key,
+            parts.len()
+        );
+
+        let input = CompleteMultipartUploadInput::builder()
+            .bucket(bucket.to_string())
+            .key(key.to_string())
+            .upload_id(upload_id.to_string())
+            .multipart_upload(Some(CompletedMultipartUpload { parts: Some(parts) }))
+            .build()
+            .map_err(|e| {
+                s3s::S3Error::with_message(
+                    s3s::S3ErrorCode::InvalidRequest,
+                    format!("Failed to build CompleteMultipartUploadInput: {}", e),
+                )
+            })?;
+
+        let uri: http::Uri = format!("/{}/{}?uploadId={}", bucket, key, upload_id)
+            .parse()
+            .unwrap_or_default();
+        let req = self
+            .create_request(
+                input,
+                Method::POST,
+                uri,
+                RequestParams {
+                    bucket: Some(bucket.to_string()),
+                    object: Some(key.to_string()),
+                    access_key,
+                    secret_key,
+                },
+            )
+            .await?;
+
+        match self.fs.complete_multipart_upload(req).await {
+            Ok(response) => Ok(response.output),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        upload_id: &str,
+        access_key: &str,
+        secret_key: &str,
+    ) -> Result<AbortMultipartUploadOutput, Self::Error> {
+        trace!("Protocol storage client AbortMultipartUpload request: bucket={}, key={}", bucket, key);
+
+        let input = AbortMultipartUploadInput::builder()
+            .bucket(bucket.to_string())
+            .key(key.to_string())
+            .upload_id(upload_id.to_string())
+            .build()
+            .map_err(|e| {
+                s3s::S3Error::with_message(
+                    s3s::S3ErrorCode::InvalidRequest,
+                    format!("Failed to build AbortMultipartUploadInput: {}", e),
+                )
+            })?;
+
+        let uri: http::Uri = format!("/{}/{}?uploadId={}", bucket, key, upload_id)
+            .parse()
+            .unwrap_or_default();
+        let req = self
+            .create_request(
+                input,
+                Method::DELETE,
+                uri,
+                RequestParams {
+                    bucket: Some(bucket.to_string()),
+                    object: Some(key.to_string()),
+                    access_key,
+                    secret_key,
+                },
+            )
+            .await?;
+
+        match self.fs.abort_multipart_upload(req).await {
+            Ok(response) => Ok(response.output),
+            Err(e) => Err(e),
+        }
+    }
 }
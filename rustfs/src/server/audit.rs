@@ -53,20 +53,24 @@ pub(crate) async fn start_audit_system() -> AuditResult<()> {
     // 2. Check if the notify subsystem exists in the configuration, and skip initialization if it doesn't
     let mqtt_config = server_config.get_value(rustfs_config::audit::AUDIT_MQTT_SUB_SYS, DEFAULT_DELIMITER);
     let webhook_config = server_config.get_value(rustfs_config::audit::AUDIT_WEBHOOK_SUB_SYS, DEFAULT_DELIMITER);
+    let kafka_config = server_config.get_value(rustfs_config::audit::AUDIT_KAFKA_SUB_SYS, DEFAULT_DELIMITER);
+    let nats_config = server_config.get_value(rustfs_config::audit::AUDIT_NATS_SUB_SYS, DEFAULT_DELIMITER);
 
-    if mqtt_config.is_none() && webhook_config.is_none() {
+    if mqtt_config.is_none() && webhook_config.is_none() && kafka_config.is_none() && nats_config.is_none() {
         info!(
             target: "rustfs::main::start_audit_system",
-            "Audit subsystem (MQTT/Webhook) is not configured, and audit system initialization is skipped."
+            "Audit subsystem (MQTT/Webhook/Kafka/NATS) is not configured, and audit system initialization is skipped."
         );
         return Ok(());
     }
 
     info!(
         target: "rustfs::main::start_audit_system",
-        "Audit subsystem configuration detected (MQTT: {}, Webhook: {}) and started initializing the audit system.",
+        "Audit subsystem configuration detected (MQTT: {}, Webhook: {}, Kafka: {}, NATS: {}) and started initializing the audit system.",
         mqtt_config.is_some(),
-        webhook_config.is_some()
+        webhook_config.is_some(),
+        kafka_config.is_some(),
+        nats_config.is_some()
     );
     // 3. Initialize and start the audit system
     let system = init_audit_system();
@@ -18,9 +18,9 @@ use crate::admin;
 use crate::auth::IAMAuth;
 use crate::config;
 use crate::server::{
-    ReadinessGateLayer, RemoteAddr, ServiceState, ServiceStateManager,
+    ReadinessGateLayer, RemoteAddr, ServiceState, ServiceStateManager, TlsClientCert, WebsiteLayer,
     hybrid::hybrid,
-    layer::{ConditionalCorsLayer, RedirectLayer},
+    layer::{AccessLogLayer, ApiTraceLayer, ConditionalCorsLayer, RedirectLayer, ServiceFreezeLayer},
 };
 use crate::storage;
 use crate::storage::tonic_service::make_server;
@@ -51,7 +51,7 @@ use tokio::net::{TcpListener, TcpStream};
 use tokio_rustls::TlsAcceptor;
 use tonic::{Request, Status};
 use tower::ServiceBuilder;
-use tower_http::add_extension::AddExtensionLayer;
+use tower_http::add_extension::{AddExtension, AddExtensionLayer};
 use tower_http::catch_panic::CatchPanicLayer;
 use tower_http::compression::CompressionLayer;
 use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
@@ -257,6 +257,15 @@ pub async fn start_http_server(
     }
 
     let is_console = config.console_enable;
+    let website_layer = WebsiteLayer::new(&config.website_domains);
+    let access_log_layer = if config.console_enable {
+        AccessLogLayer::new(&[])
+    } else {
+        AccessLogLayer::new(&config.server_domains)
+    };
+    if !config.website_domains.is_empty() {
+        info!("bucket static websites are served on domains {:?}", &config.website_domains);
+    }
     tokio::spawn(async move {
         // Note: CORS layer is removed from global middleware stack
         // - S3 API CORS is handled by bucket-level CORS configuration in apply_cors_headers()
@@ -403,6 +412,8 @@ pub async fn start_http_server(
                 compression_config: compression_config.clone(),
                 is_console,
                 readiness: readiness.clone(),
+                website_layer: website_layer.clone(),
+                access_log_layer: access_log_layer.clone(),
             };
 
             process_connection(socket, tls_acceptor.clone(), connection_ctx, graceful.clone());
@@ -524,6 +535,8 @@ struct ConnectionContext {
     compression_config: CompressionConfig,
     is_console: bool,
     readiness: Arc<GlobalReadiness>,
+    website_layer: WebsiteLayer,
+    access_log_layer: AccessLogLayer,
 }
 
 /// Adapter that implements the OpenTelemetry [`Extractor`] trait for Hyper's
@@ -582,6 +595,8 @@ fn process_connection(
             compression_config,
             is_console,
             readiness,
+            website_layer,
+            access_log_layer,
         } = context;
 
         // Build services inside each connected task to avoid passing complex service types across tasks,
@@ -620,6 +635,8 @@ fn process_connection(
             // CRITICAL: Insert ReadinessGateLayer before business logic
             // This stops requests from hitting IAMAuth or Storage if they are not ready.
             .layer(ReadinessGateLayer::new(readiness))
+            // Hold new S3 calls while the cluster is frozen through the admin service API
+            .layer(ServiceFreezeLayer)
             .layer(
                 TraceLayer::new_for_http()
                     .make_span_with(|request: &HttpRequest<_>| {
@@ -692,6 +709,12 @@ fn process_connection(
             // Compress responses based on whitelist configuration
             // Only compresses when enabled and matches configured extensions/MIME types
             .layer(CompressionLayer::new().compress_when(CompressionPredicate::new(compression_config)))
+            // Serve bucket static websites for `<bucket>.<website-domain>` hosts, bypassing S3 authentication
+            .layer(website_layer)
+            // Publish API calls to `admin trace` subscribers; a no-op while nobody is tracing
+            .layer(ApiTraceLayer)
+            // Write S3 server access logs, including requests denied before reaching a handler
+            .layer(access_log_layer)
             // Conditional CORS layer: only applies to S3 API requests (not Admin, not Console)
             // Admin has its own CORS handling in router.rs
             // Console has its own CORS layer in setup_console_middleware_stack()
@@ -701,8 +724,6 @@ fn process_connection(
             .option_layer(if is_console { Some(RedirectLayer) } else { None })
             .service(service);
 
-        let hybrid_service = TowerToHyperService::new(hybrid_service);
-
         // Decide whether to handle HTTPS or HTTP connections based on the existence of TLS Acceptor
         if let Some(acceptor) = tls_acceptor {
             debug!("TLS handshake start");
@@ -713,6 +734,14 @@ fn process_connection(
             match acceptor.accept(socket).await {
                 Ok(tls_socket) => {
                     debug!("TLS handshake successful");
+                    // Expose the verified client certificate to STS AssumeRoleWithCertificate
+                    let client_cert = tls_socket
+                        .get_ref()
+                        .1
+                        .peer_certificates()
+                        .and_then(|certs| certs.first())
+                        .map(|cert| TlsClientCert(cert.to_vec()));
+                    let hybrid_service = TowerToHyperService::new(AddExtension::new(hybrid_service, client_cert));
                     let stream = TokioIo::new(tls_socket);
                     let conn = http_server.serve_connection(stream, hybrid_service);
                     if let Err(err) = graceful.watch(conn).await {
@@ -759,6 +788,7 @@ fn process_connection(
             debug!("TLS handshake success");
         } else {
             debug!("Http handshake start");
+            let hybrid_service = TowerToHyperService::new(hybrid_service);
             let stream = TokioIo::new(socket);
             let conn = http_server.serve_connection(stream, hybrid_service);
             if let Err(err) = graceful.watch(conn).await {
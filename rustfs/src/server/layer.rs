@@ -15,14 +15,22 @@
 use crate::admin::console::is_console_path;
 use crate::server::cors;
 use crate::server::hybrid::HybridBody;
-use crate::server::{ADMIN_PREFIX, RPC_PREFIX};
+use crate::server::{ADMIN_PREFIX, RPC_PREFIX, RemoteAddr, TONIC_PREFIX};
+use crate::storage::access_log::{self, AccessLogContext, AccessLogRequest};
 use crate::storage::apply_cors_headers;
+use chrono::Utc;
 use http::{HeaderMap, HeaderValue, Method, Request as HttpRequest, Response, StatusCode};
 use hyper::body::Incoming;
+use rustfs_common::service_signal::{is_services_frozen, wait_for_unfreeze};
+use rustfs_common::trace_channel::{is_tracing, publish_trace};
+use rustfs_madmin::trace::{TraceCallStats, TraceHTTPStats, TraceInfo, TraceRequestInfo, TraceResponseInfo, TraceType};
+use rustfs_trusted_proxies::ClientInfo;
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::task::{Context, Poll};
+use std::time::Instant;
 use tower::{Layer, Service};
 use tracing::{debug, info};
 
@@ -264,3 +272,387 @@ where
         })
     }
 }
+
+/// Holds new S3 API calls while the service is frozen through the admin service API.
+///
+/// Admin, console and internal RPC traffic is never held so that the cluster can still be unfrozen.
+#[derive(Clone, Default)]
+pub struct ServiceFreezeLayer;
+
+impl<S> Layer<S> for ServiceFreezeLayer {
+    type Service = ServiceFreezeService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ServiceFreezeService { inner }
+    }
+}
+
+/// Service implementation for service freezing
+#[derive(Clone)]
+pub struct ServiceFreezeService<S> {
+    inner: S,
+}
+
+impl<S, ResBody> Service<HttpRequest<Incoming>> for ServiceFreezeService<S>
+where
+    S: Service<HttpRequest<Incoming>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<Box<dyn std::error::Error + Send + Sync>> + Send + 'static,
+    ResBody: Send + 'static,
+{
+    type Response = Response<ResBody>;
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: HttpRequest<Incoming>) -> Self::Future {
+        let mut inner = self.inner.clone();
+        let path = req.uri().path();
+        let should_hold = is_services_frozen() && ConditionalCorsLayer::is_s3_path(path) && !path.starts_with(TONIC_PREFIX);
+
+        Box::pin(async move {
+            if should_hold {
+                debug!("service is frozen, holding request for {}", req.uri().path());
+                wait_for_unfreeze().await;
+            }
+            inner.call(req).await.map_err(Into::into)
+        })
+    }
+}
+
+/// Request extension slot through which the S3 access check reports the
+/// resolved S3 operation name to [`ApiTraceLayer`].
+#[derive(Clone, Debug, Default)]
+pub struct TraceApiName(Arc<OnceLock<&'static str>>);
+
+impl TraceApiName {
+    pub fn set(&self, name: &'static str) {
+        let _ = self.0.set(name);
+    }
+
+    pub fn get(&self) -> Option<&'static str> {
+        self.0.get().copied()
+    }
+}
+
+/// Publishes S3, admin and internal RPC calls to `admin trace` subscribers.
+///
+/// Nothing is recorded while no subscriber is interested in S3 or internal traces.
+#[derive(Clone, Default)]
+pub struct ApiTraceLayer;
+
+impl<S> Layer<S> for ApiTraceLayer {
+    type Service = ApiTraceService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiTraceService { inner }
+    }
+}
+
+/// Service implementation for API call tracing
+#[derive(Clone)]
+pub struct ApiTraceService<S> {
+    inner: S,
+}
+
+/// Trace endpoints are never traced themselves, otherwise every record would produce another one.
+const TRACE_EXCLUDED_PATHS: &[(&str, &str)] = &[(ADMIN_PREFIX, "/v3/trace"), (TONIC_PREFIX, "/Trace")];
+
+/// Replaces the value of credentials and keys in published traces
+const TRACE_REDACTED: &str = "*REDACTED*";
+
+/// Headers carrying credentials or keys, matched by prefix to cover their `-MD5` companions
+const TRACE_REDACTED_HEADERS: &[&str] = &[
+    "x-amz-security-token",
+    "x-amz-server-side-encryption-customer-key",
+    "x-amz-copy-source-server-side-encryption-customer-key",
+    "cookie",
+];
+
+/// Query parameters of presigned requests carrying credentials
+const TRACE_REDACTED_QUERY_PARAMS: &[&str] = &["X-Amz-Signature", "X-Amz-Credential", "X-Amz-Security-Token"];
+
+fn is_trace_endpoint(path: &str) -> bool {
+    TRACE_EXCLUDED_PATHS
+        .iter()
+        .any(|(prefix, endpoint)| path.strip_prefix(prefix) == Some(endpoint))
+}
+
+/// Classify a request path into its trace type and function name.
+///
+/// The function name is left empty for S3 calls; it is resolved from the
+/// S3 operation once the request has been routed.
+fn classify_trace_path(path: &str) -> Option<(TraceType, String)> {
+    if is_trace_endpoint(path) || is_console_path(path) {
+        return None;
+    }
+
+    if let Some(method) = path.strip_prefix(TONIC_PREFIX) {
+        return Some((TraceType::INTERNAL, format!("grpc{}", method.replace('/', "."))));
+    }
+    if let Some(method) = path.strip_prefix(RPC_PREFIX) {
+        return Some((TraceType::INTERNAL, format!("rpc{}", method.replace('/', "."))));
+    }
+    if path.starts_with(ADMIN_PREFIX) {
+        let name = path.trim_end_matches('/').rsplit('/').next().unwrap_or_default();
+        return Some((TraceType::S3, format!("admin.{name}")));
+    }
+
+    Some((TraceType::S3, String::new()))
+}
+
+fn trace_header_map(headers: &HeaderMap) -> HashMap<String, String> {
+    headers
+        .iter()
+        .filter(|(name, _)| *name != http::header::AUTHORIZATION)
+        .filter_map(|(name, value)| {
+            if TRACE_REDACTED_HEADERS.iter().any(|h| name.as_str().starts_with(h)) {
+                return Some((name.to_string(), TRACE_REDACTED.to_string()));
+            }
+            value.to_str().ok().map(|v| (name.to_string(), v.to_string()))
+        })
+        .collect()
+}
+
+fn trace_raw_query(query: &str) -> String {
+    query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if TRACE_REDACTED_QUERY_PARAMS.iter().any(|p| key.eq_ignore_ascii_case(p)) => {
+                format!("{key}={TRACE_REDACTED}")
+            }
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn trace_content_length(headers: &HeaderMap) -> i32 {
+    headers
+        .get(http::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_default()
+}
+
+impl<S, ResBody> Service<HttpRequest<Incoming>> for ApiTraceService<S>
+where
+    S: Service<HttpRequest<Incoming>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<Box<dyn std::error::Error + Send + Sync>> + Send + 'static,
+    ResBody: Send + 'static,
+{
+    type Response = Response<ResBody>;
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, mut req: HttpRequest<Incoming>) -> Self::Future {
+        let mut inner = self.inner.clone();
+
+        let classified = classify_trace_path(req.uri().path()).filter(|(trace_type, _)| is_tracing(*trace_type));
+        let Some((trace_type, func_name)) = classified else {
+            return Box::pin(async move { inner.call(req).await.map_err(Into::into) });
+        };
+
+        let api_name = TraceApiName::default();
+        req.extensions_mut().insert(api_name.clone());
+
+        let client = req
+            .extensions()
+            .get::<ClientInfo>()
+            .map(|info| info.real_ip.to_string())
+            .or_else(|| {
+                req.extensions()
+                    .get::<Option<RemoteAddr>>()
+                    .and_then(|ra| ra.map(|ra| ra.0.to_string()))
+            })
+            .unwrap_or_default();
+        let req_info = TraceRequestInfo {
+            time: Utc::now(),
+            proto: format!("{:?}", req.version()),
+            method: req.method().to_string(),
+            path: Some(req.uri().path().to_string()),
+            raw_query: req.uri().query().map(trace_raw_query),
+            headers: Some(trace_header_map(req.headers())),
+            body: None,
+            client,
+        };
+        let input_bytes = trace_content_length(req.headers());
+        let start = Instant::now();
+
+        Box::pin(async move {
+            let response = inner.call(req).await.map_err(Into::into)?;
+            let latency = start.elapsed();
+
+            let func_name = if func_name.is_empty() {
+                format!("s3.{}", api_name.get().unwrap_or(req_info.method.as_str()))
+            } else {
+                func_name
+            };
+            let status = response.status();
+            let mut info = TraceInfo::new(trace_type, func_name, req_info.path.clone().unwrap_or_default());
+            info.time = req_info.time;
+            info.duration = latency;
+            if status.is_client_error() || status.is_server_error() {
+                info.error = status.canonical_reason().map(str::to_string);
+            }
+            info.http = Some(TraceHTTPStats {
+                resp_info: TraceResponseInfo {
+                    time: Utc::now(),
+                    headers: Some(trace_header_map(response.headers())),
+                    body: None,
+                    status_code: Some(status.as_u16() as i32),
+                },
+                call_stats: TraceCallStats {
+                    input_bytes,
+                    output_bytes: trace_content_length(response.headers()),
+                    latency,
+                    time_to_first_byte: latency,
+                },
+                req_info,
+            });
+            publish_trace(info);
+
+            Ok(response)
+        })
+    }
+}
+
+/// Records S3 server access log lines for every S3 API request, including requests rejected by
+/// authentication or authorization before any handler ran.
+#[derive(Clone)]
+pub struct AccessLogLayer {
+    domains: Arc<Vec<String>>,
+}
+
+impl AccessLogLayer {
+    /// `domains` are the virtual-hosted-style domains, used to name the bucket of requests the S3
+    /// access check never saw
+    pub fn new(domains: &[String]) -> Self {
+        Self {
+            domains: Arc::new(domains.to_vec()),
+        }
+    }
+}
+
+impl<S> Layer<S> for AccessLogLayer {
+    type Service = AccessLogService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AccessLogService {
+            inner,
+            domains: self.domains.clone(),
+        }
+    }
+}
+
+/// Service implementation for S3 server access logging
+#[derive(Clone)]
+pub struct AccessLogService<S> {
+    inner: S,
+    domains: Arc<Vec<String>>,
+}
+
+impl<S, ResBody> Service<HttpRequest<Incoming>> for AccessLogService<S>
+where
+    S: Service<HttpRequest<Incoming>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<Box<dyn std::error::Error + Send + Sync>> + Send + 'static,
+    ResBody: Send + 'static,
+{
+    type Response = Response<ResBody>;
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, mut req: HttpRequest<Incoming>) -> Self::Future {
+        let mut inner = self.inner.clone();
+
+        let path = req.uri().path();
+        if !ConditionalCorsLayer::is_s3_path(path) || path.starts_with(TONIC_PREFIX) {
+            return Box::pin(async move { inner.call(req).await.map_err(Into::into) });
+        }
+
+        let remote_ip = req
+            .extensions()
+            .get::<ClientInfo>()
+            .map(|info| info.real_ip.to_string())
+            .or_else(|| {
+                req.extensions()
+                    .get::<Option<RemoteAddr>>()
+                    .and_then(|ra| ra.map(|ra| ra.0.ip().to_string()))
+            });
+        let context = AccessLogContext::new(AccessLogRequest::new(req.method(), req.uri(), req.headers(), remote_ip));
+        req.extensions_mut().insert(context.clone());
+        let domains = self.domains.clone();
+        let start = Instant::now();
+
+        Box::pin(async move {
+            let response = inner.call(req).await.map_err(Into::into)?;
+
+            let mut record = context.take();
+            record.finish(response.status(), response.headers(), start.elapsed());
+            record.resolve_target(&domains);
+            tokio::spawn(access_log::record(record));
+
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_trace_path_excludes_trace_endpoints() {
+        assert!(classify_trace_path(&format!("{ADMIN_PREFIX}/v3/trace")).is_none());
+        assert!(classify_trace_path(&format!("{TONIC_PREFIX}/Trace")).is_none());
+        assert!(classify_trace_path(&format!("{ADMIN_PREFIX}/v3/info")).is_some());
+    }
+
+    #[test]
+    fn test_trace_header_map_redacts_credentials() {
+        let mut headers = HeaderMap::new();
+        headers.insert(http::header::AUTHORIZATION, "AWS4-HMAC-SHA256 Credential=ak".parse().unwrap());
+        headers.insert("x-amz-security-token", "session-token".parse().unwrap());
+        headers.insert("x-amz-server-side-encryption-customer-key", "c2VjcmV0".parse().unwrap());
+        headers.insert("x-amz-server-side-encryption-customer-key-md5", "bWQ1".parse().unwrap());
+        headers.insert("x-amz-copy-source-server-side-encryption-customer-key", "c2VjcmV0".parse().unwrap());
+        headers.insert(http::header::COOKIE, "token=abc".parse().unwrap());
+        headers.insert(http::header::CONTENT_TYPE, "text/plain".parse().unwrap());
+
+        let map = trace_header_map(&headers);
+        assert!(!map.contains_key("authorization"));
+        for name in [
+            "x-amz-security-token",
+            "x-amz-server-side-encryption-customer-key",
+            "x-amz-server-side-encryption-customer-key-md5",
+            "x-amz-copy-source-server-side-encryption-customer-key",
+            "cookie",
+        ] {
+            assert_eq!(map.get(name).map(String::as_str), Some(TRACE_REDACTED), "{name}");
+        }
+        assert_eq!(map.get("content-type").map(String::as_str), Some("text/plain"));
+    }
+
+    #[test]
+    fn test_trace_raw_query_redacts_presigned_credentials() {
+        let query = "X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=ak%2F20250101&X-Amz-Signature=abc&x-amz-security-token=t&versionId=1";
+        assert_eq!(
+            trace_raw_query(query),
+            "X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=*REDACTED*&X-Amz-Signature=*REDACTED*&x-amz-security-token=*REDACTED*&versionId=1"
+        );
+        assert_eq!(trace_raw_query("list-type=2&prefix=a"), "list-type=2&prefix=a");
+    }
+}
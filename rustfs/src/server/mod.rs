@@ -24,11 +24,13 @@ mod prefix;
 mod readiness;
 mod runtime;
 mod service_state;
+mod website;
 
 pub(crate) use audit::{start_audit_system, stop_audit_system};
 pub(crate) use cert::init_cert;
 pub(crate) use event::{init_event_notifier, shutdown_event_notifier};
 pub(crate) use http::start_http_server;
+pub(crate) use layer::TraceApiName;
 pub(crate) use prefix::*;
 pub(crate) use readiness::ReadinessGateLayer;
 pub(crate) use runtime::get_tokio_runtime_builder;
@@ -36,7 +38,13 @@ pub(crate) use service_state::SHUTDOWN_TIMEOUT;
 pub(crate) use service_state::ServiceState;
 pub(crate) use service_state::ServiceStateManager;
 pub(crate) use service_state::ShutdownSignal;
+pub(crate) use service_state::restart_process;
 pub(crate) use service_state::wait_for_shutdown;
+pub(crate) use website::WebsiteLayer;
 
 #[derive(Clone, Copy, Debug)]
 pub struct RemoteAddr(pub std::net::SocketAddr);
+
+/// DER leaf certificate presented by the client during the TLS handshake, already verified against the client CA
+#[derive(Clone, Debug)]
+pub struct TlsClientCert(pub Vec<u8>);
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use atomic_enum::atomic_enum;
+use rustfs_common::service_signal::{ServiceSignal, subscribe_service_signal};
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
 use tracing::info;
 
 // a configurable shutdown timeout
@@ -54,6 +56,56 @@ pub enum ShutdownSignal {
     Sigterm,
     #[cfg(unix)]
     Sigint,
+    /// Stop requested through the admin service API
+    ServiceStop,
+    /// Restart requested through the admin service API
+    ServiceRestart,
+}
+
+/// Wait for a restart or stop request from the admin service API.
+async fn wait_for_service_signal() -> ShutdownSignal {
+    let mut rx = subscribe_service_signal();
+    loop {
+        match rx.recv().await {
+            Ok(ServiceSignal::Stop) => {
+                info!("RustFS Received service stop request");
+                return ShutdownSignal::ServiceStop;
+            }
+            Ok(ServiceSignal::Restart) => {
+                info!("RustFS Received service restart request");
+                return ShutdownSignal::ServiceRestart;
+            }
+            Ok(_) | Err(RecvError::Lagged(_)) => continue,
+            // The sender lives in a static, so the channel is never closed.
+            Err(RecvError::Closed) => std::future::pending::<()>().await,
+        }
+    }
+}
+
+/// Replace the current process with a fresh instance started with the same arguments.
+///
+/// Only returns if the new process could not be started.
+pub(crate) fn restart_process() -> std::io::Error {
+    let exe = match std::env::current_exe() {
+        Ok(exe) => exe,
+        Err(err) => return err,
+    };
+    let mut cmd = std::process::Command::new(exe);
+    cmd.args(std::env::args_os().skip(1));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.exec()
+    }
+
+    #[cfg(not(unix))]
+    {
+        match cmd.spawn() {
+            Ok(_) => std::process::exit(0),
+            Err(err) => err,
+        }
+    }
 }
 
 #[atomic_enum]
@@ -84,6 +136,7 @@ pub(crate) async fn wait_for_shutdown() -> ShutdownSignal {
             info!("RustFS Received SIGTERM signal");
             ShutdownSignal::Sigterm
         }
+        signal = wait_for_service_signal() => signal,
     }
 }
 
@@ -94,6 +147,7 @@ pub(crate) async fn wait_for_shutdown() -> ShutdownSignal {
             info!("Received Ctrl-C signal");
             ShutdownSignal::CtrlC
         }
+        signal = wait_for_service_signal() => signal,
     }
 }
 
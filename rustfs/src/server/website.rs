@@ -0,0 +1,561 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::auth::get_condition_values;
+use crate::server::RemoteAddr;
+use crate::storage::check_preconditions;
+use crate::storage::sse::{DecryptionRequest, check_encryption_metadata, sse_decryption};
+use bytes::Bytes;
+use futures::TryStreamExt;
+use http::{HeaderMap, HeaderValue, Method, Request as HttpRequest, Response, StatusCode, header};
+use http_body::{Body, Frame};
+use http_body_util::{BodyExt, Empty, Full, StreamBody};
+use rustfs_credentials::Credentials;
+use rustfs_ecstore::bucket::metadata_sys;
+use rustfs_ecstore::bucket::policy_sys::PolicySys;
+use rustfs_ecstore::bucket::website::{WebsiteApi, WebsiteRedirect};
+use rustfs_ecstore::error::{StorageError, is_err_bucket_not_found, is_err_object_not_found, is_err_version_not_found};
+use rustfs_ecstore::new_object_layer_fn;
+use rustfs_ecstore::store_api::{HTTPRangeSpec, ObjectIO, ObjectInfo, ObjectOptions, StorageAPI};
+use rustfs_policy::policy::BucketPolicyArgs;
+use rustfs_policy::policy::action::{Action, S3Action};
+use rustfs_utils::http::SSEC_ALGORITHM_HEADER;
+use s3s::S3ErrorCode;
+use s3s::dto::WebsiteConfiguration;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use time::format_description::FormatItem;
+use time::macros::format_description;
+use tokio_util::io::ReaderStream;
+use tower::{Layer, Service};
+use tracing::{debug, warn};
+
+const RFC1123: &[FormatItem<'_>] =
+    format_description!("[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT");
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+type BoxBody = http_body_util::combinators::UnsyncBoxBody<Bytes, BoxError>;
+
+/// WebsiteLayer serves bucket static websites for requests whose host is `<bucket>.<website-domain>`,
+/// all other requests go to the S3 API unchanged.
+#[derive(Clone)]
+pub struct WebsiteLayer {
+    domains: Arc<Vec<String>>,
+}
+
+impl WebsiteLayer {
+    pub fn new(domains: &[String]) -> Self {
+        let domains = domains
+            .iter()
+            .map(|d| strip_port(d).to_ascii_lowercase())
+            .filter(|d| !d.is_empty())
+            .collect();
+        Self {
+            domains: Arc::new(domains),
+        }
+    }
+}
+
+impl<S> Layer<S> for WebsiteLayer {
+    type Service = WebsiteService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        WebsiteService {
+            inner,
+            domains: self.domains.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct WebsiteService<S> {
+    inner: S,
+    domains: Arc<Vec<String>>,
+}
+
+impl<S, ReqBody, B> Service<HttpRequest<ReqBody>> for WebsiteService<S>
+where
+    S: Service<HttpRequest<ReqBody>, Response = Response<B>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+    ReqBody: Send + 'static,
+    B: Body<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError> + Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: HttpRequest<ReqBody>) -> Self::Future {
+        let host = request_host(&req).map(str::to_string);
+        let bucket = host.as_deref().and_then(|h| website_bucket(&self.domains, h));
+
+        let Some(bucket) = bucket else {
+            let mut inner = self.inner.clone();
+            return Box::pin(async move {
+                let resp = inner.call(req).await?;
+                let (parts, body) = resp.into_parts();
+                let body: BoxBody = body.map_err(Into::into).boxed_unsync();
+                Ok(Response::from_parts(parts, body))
+            });
+        };
+
+        let remote_addr = req.extensions().get::<Option<RemoteAddr>>().and_then(|opt| opt.map(|a| a.0));
+        let request = WebsiteRequest {
+            bucket,
+            host: host.unwrap_or_default(),
+            method: req.method().clone(),
+            path: req.uri().path().to_string(),
+            headers: req.headers().clone(),
+            remote_addr,
+        };
+
+        Box::pin(async move { Ok(serve(request).await) })
+    }
+}
+
+struct WebsiteRequest {
+    bucket: String,
+    host: String,
+    method: Method,
+    path: String,
+    headers: HeaderMap,
+    remote_addr: Option<std::net::SocketAddr>,
+}
+
+async fn serve(req: WebsiteRequest) -> Response<BoxBody> {
+    debug!("website request for bucket {} path {}", req.bucket, req.path);
+
+    if req.method != Method::GET && req.method != Method::HEAD {
+        return error_page(StatusCode::METHOD_NOT_ALLOWED, "MethodNotAllowed", "The specified method is not allowed");
+    }
+
+    let config = match metadata_sys::get_website_config(&req.bucket).await {
+        Ok((config, _)) => config,
+        Err(err) if err == StorageError::ConfigNotFound => {
+            return error_page(
+                StatusCode::NOT_FOUND,
+                "NoSuchWebsiteConfiguration",
+                "The specified bucket does not have a website configuration",
+            );
+        }
+        Err(err) if is_err_bucket_not_found(&err) => {
+            return error_page(StatusCode::NOT_FOUND, "NoSuchBucket", "The specified bucket does not exist");
+        }
+        Err(err) => {
+            warn!("get_website_config err {:?}", &err);
+            return error_page(StatusCode::INTERNAL_SERVER_ERROR, "InternalError", "We encountered an internal error");
+        }
+    };
+
+    let key = match urlencoding::decode(req.path.trim_start_matches('/')) {
+        Ok(key) => key.into_owned(),
+        Err(_) => return error_page(StatusCode::BAD_REQUEST, "InvalidURI", "Couldn't parse the specified URI"),
+    };
+
+    if let Some(redirect) = config.redirect_all() {
+        return redirect_response(&req, &redirect, &key);
+    }
+
+    if let Some(redirect) = config.routing_redirect(&key, None) {
+        return redirect_response(&req, &redirect, &key);
+    }
+
+    let Some(object) = config.object_key(&key) else {
+        return error_page(StatusCode::NOT_FOUND, "NoSuchKey", "The specified key does not exist");
+    };
+
+    let status = match serve_object(&req, &object, StatusCode::OK).await {
+        Ok(resp) => return resp,
+        Err(status) => status,
+    };
+
+    // A key without trailing slash that names a folder with an index document
+    if status == StatusCode::NOT_FOUND
+        && !key.is_empty()
+        && !key.ends_with('/')
+        && let Some(index) = config.object_key(&format!("{key}/"))
+        && object_exists(&req.bucket, &index).await
+    {
+        return found_folder(&req.path);
+    }
+
+    if let Some(redirect) = config.routing_redirect(&key, Some(status.as_u16())) {
+        return redirect_response(&req, &redirect, &key);
+    }
+
+    serve_error(&req, &config, status).await
+}
+
+/// Streams the object when the anonymous caller may read it, otherwise returns the error status.
+/// Range and conditional headers only apply when serving the requested page, not an error document.
+async fn serve_object(req: &WebsiteRequest, object: &str, status: StatusCode) -> Result<Response<BoxBody>, StatusCode> {
+    let Some(store) = new_object_layer_fn() else {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    };
+
+    let region = rustfs_ecstore::global::get_global_region();
+    let conditions = get_condition_values(&req.headers, &Credentials::default(), None, region.as_deref(), req.remote_addr);
+    let allowed = PolicySys::is_allowed(&BucketPolicyArgs {
+        bucket: &req.bucket,
+        action: Action::S3Action(S3Action::GetObjectAction),
+        is_owner: false,
+        account: "",
+        groups: &None,
+        conditions: &conditions,
+        object,
+    })
+    .await;
+    if !allowed {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let info = match store.get_object_info(&req.bucket, object, &ObjectOptions::default()).await {
+        Ok(info) => info,
+        Err(err) => return Err(object_error_status(req, object, err)),
+    };
+
+    // SSE-C objects can only be read with the customer's key, which a website visitor cannot send
+    if info.user_defined.contains_key(SSEC_ALGORITHM_HEADER) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let encrypted = check_encryption_metadata(&info.user_defined);
+
+    let size = info.get_actual_size().map_err(|err| {
+        warn!("website get_actual_size {}/{} err {:?}", req.bucket, object, err);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let requested = status == StatusCode::OK;
+    if requested && let Err(err) = check_preconditions(&req.headers, &info) {
+        return Ok(precondition_failed(&info, err.code()));
+    }
+
+    // Encrypted objects are always decrypted whole, so a range on them is ignored as RFC 9110 allows
+    let range = req
+        .headers
+        .get(header::RANGE)
+        .and_then(|v| v.to_str().ok())
+        .filter(|_| requested && !encrypted)
+        .and_then(parse_range);
+    let (status, content_length, content_range) = match &range {
+        Some(rs) => match rs.get_offset_length(size) {
+            Ok((start, length)) => (
+                StatusCode::PARTIAL_CONTENT,
+                length,
+                Some(format!("bytes {}-{}/{}", start, start as i64 + length - 1, size)),
+            ),
+            Err(_) => return Ok(range_not_satisfiable(size)),
+        },
+        None => (status, size, None),
+    };
+
+    let mut builder = Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, info.content_type.as_deref().unwrap_or("application/octet-stream"))
+        .header(header::CONTENT_LENGTH, content_length);
+    builder = object_headers(builder, &info);
+    if !encrypted {
+        builder = builder.header(header::ACCEPT_RANGES, "bytes");
+    }
+    if let Some(content_range) = content_range {
+        builder = builder.header(header::CONTENT_RANGE, content_range);
+    }
+
+    let body: BoxBody = if req.method == Method::HEAD {
+        Empty::new().map_err(|e| -> BoxError { Box::new(e) }).boxed_unsync()
+    } else {
+        let reader = match store
+            .get_object_reader(&req.bucket, object, range, HeaderMap::new(), &ObjectOptions::default())
+            .await
+        {
+            Ok(reader) => reader,
+            Err(err) => return Err(object_error_status(req, object, err)),
+        };
+
+        let mut stream = reader.stream;
+        let decryption_request = DecryptionRequest {
+            bucket: &req.bucket,
+            key: object,
+            metadata: &info.user_defined,
+            sse_customer_key: None,
+            sse_customer_key_md5: None,
+            part_number: None,
+            parts: &info.parts,
+        };
+        let material = sse_decryption(decryption_request).await.map_err(|err| {
+            warn!("website sse_decryption {}/{} err {:?}", req.bucket, object, err);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+        if let Some(material) = material {
+            let (decrypted, _) = material.wrap_reader(stream, size).await.map_err(|err| {
+                warn!("website decrypt {}/{} err {:?}", req.bucket, object, err);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+            stream = decrypted;
+        }
+
+        let stream = ReaderStream::new(stream)
+            .map_ok(Frame::data)
+            .map_err(|e| -> BoxError { Box::new(e) });
+        StreamBody::new(stream).boxed_unsync()
+    };
+
+    builder.body(body).map_err(|e| {
+        warn!("website response for {}/{} err {:?}", req.bucket, object, e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+fn object_error_status(req: &WebsiteRequest, object: &str, err: StorageError) -> StatusCode {
+    if is_err_object_not_found(&err) || is_err_version_not_found(&err) || is_err_bucket_not_found(&err) {
+        return StatusCode::NOT_FOUND;
+    }
+    warn!("website read {}/{} err {:?}", req.bucket, object, err);
+    StatusCode::INTERNAL_SERVER_ERROR
+}
+
+fn object_headers(mut builder: http::response::Builder, info: &ObjectInfo) -> http::response::Builder {
+    if let Some(etag) = &info.etag {
+        builder = builder.header(header::ETAG, format!("\"{}\"", etag.trim_matches('"')));
+    }
+    if let Some(last_modified) = info.mod_time.and_then(|t| t.format(RFC1123).ok()) {
+        builder = builder.header(header::LAST_MODIFIED, last_modified);
+    }
+    builder
+}
+
+/// 304 for a failed If-None-Match or If-Modified-Since, 412 for a failed If-Match or If-Unmodified-Since
+fn precondition_failed(info: &ObjectInfo, code: &S3ErrorCode) -> Response<BoxBody> {
+    if *code != S3ErrorCode::NotModified {
+        return error_page(
+            StatusCode::PRECONDITION_FAILED,
+            "PreconditionFailed",
+            "At least one of the pre-conditions you specified did not hold",
+        );
+    }
+
+    object_headers(Response::builder().status(StatusCode::NOT_MODIFIED), info)
+        .body(Empty::new().map_err(|e| -> BoxError { Box::new(e) }).boxed_unsync())
+        .expect("failed to build website not modified response")
+}
+
+fn range_not_satisfiable(size: i64) -> Response<BoxBody> {
+    let mut resp = error_page(
+        StatusCode::RANGE_NOT_SATISFIABLE,
+        "InvalidRange",
+        "The requested range is not satisfiable",
+    );
+    if let Ok(value) = HeaderValue::from_str(&format!("bytes */{size}")) {
+        resp.headers_mut().insert(header::CONTENT_RANGE, value);
+    }
+    resp
+}
+
+/// Parses a single `bytes=` range, anything else is ignored and the whole object is served
+fn parse_range(value: &str) -> Option<HTTPRangeSpec> {
+    let spec = value.trim().strip_prefix("bytes=")?;
+    if spec.contains(',') {
+        return None;
+    }
+
+    let (first, last) = spec.split_once('-')?;
+    let (first, last) = (first.trim(), last.trim());
+    if first.is_empty() {
+        let length = last.parse::<i64>().ok().filter(|l| *l > 0)?;
+        return Some(HTTPRangeSpec {
+            is_suffix_length: true,
+            start: length,
+            end: -1,
+        });
+    }
+
+    let start = first.parse::<i64>().ok()?;
+    let end = if last.is_empty() { -1 } else { last.parse::<i64>().ok()? };
+    if end != -1 && end < start {
+        return None;
+    }
+    Some(HTTPRangeSpec {
+        is_suffix_length: false,
+        start,
+        end,
+    })
+}
+
+async fn object_exists(bucket: &str, object: &str) -> bool {
+    let Some(store) = new_object_layer_fn() else {
+        return false;
+    };
+    store.get_object_info(bucket, object, &ObjectOptions::default()).await.is_ok()
+}
+
+/// Serves the ErrorDocument with the original status, falling back to the default error page
+async fn serve_error(req: &WebsiteRequest, config: &WebsiteConfiguration, status: StatusCode) -> Response<BoxBody> {
+    if let Some(document) = &config.error_document
+        && let Ok(resp) = serve_object(req, &document.key, status).await
+    {
+        return resp;
+    }
+
+    match status {
+        StatusCode::NOT_FOUND => error_page(status, "NoSuchKey", "The specified key does not exist"),
+        StatusCode::FORBIDDEN => error_page(status, "AccessDenied", "Access Denied"),
+        StatusCode::SERVICE_UNAVAILABLE => error_page(status, "ServiceUnavailable", "Please reduce your request rate"),
+        _ => error_page(status, "InternalError", "We encountered an internal error"),
+    }
+}
+
+fn redirect_response(req: &WebsiteRequest, redirect: &WebsiteRedirect, key: &str) -> Response<BoxBody> {
+    let key = redirect.key.as_deref().unwrap_or(key);
+    let path = format!("/{}", encode_key(key.trim_start_matches('/')));
+
+    let location = match (&redirect.protocol, &redirect.host) {
+        (None, None) => path,
+        (protocol, host) => {
+            let protocol = protocol.clone().unwrap_or_else(|| request_scheme(&req.headers).to_string());
+            let host = host.as_deref().unwrap_or(&req.host);
+            format!("{protocol}://{host}{path}")
+        }
+    };
+
+    let status = StatusCode::from_u16(redirect.status).unwrap_or(StatusCode::MOVED_PERMANENTLY);
+    redirect_to(status, &location)
+}
+
+fn found_folder(path: &str) -> Response<BoxBody> {
+    redirect_to(StatusCode::FOUND, &format!("{path}/"))
+}
+
+fn redirect_to(status: StatusCode, location: &str) -> Response<BoxBody> {
+    let Ok(location) = HeaderValue::from_str(location) else {
+        return error_page(StatusCode::INTERNAL_SERVER_ERROR, "InternalError", "We encountered an internal error");
+    };
+
+    Response::builder()
+        .status(status)
+        .header(header::LOCATION, location)
+        .body(Empty::new().map_err(|e| -> BoxError { Box::new(e) }).boxed_unsync())
+        .expect("failed to build website redirect response")
+}
+
+fn error_page(status: StatusCode, code: &str, message: &str) -> Response<BoxBody> {
+    let title = format!("{} {}", status.as_u16(), status.canonical_reason().unwrap_or_default());
+    let html = format!(
+        "<html>\n<head><title>{title}</title></head>\n<body>\n<h1>{title}</h1>\n<ul>\n<li>Code: {code}</li>\n<li>Message: {message}</li>\n</ul>\n<hr/>\n</body>\n</html>\n"
+    );
+
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+        .header(header::CACHE_CONTROL, "no-store")
+        .body(
+            Full::new(Bytes::from(html))
+                .map_err(|e| -> BoxError { Box::new(e) })
+                .boxed_unsync(),
+        )
+        .expect("failed to build website error response")
+}
+
+fn request_host<B>(req: &HttpRequest<B>) -> Option<&str> {
+    req.headers()
+        .get(header::HOST)
+        .and_then(|v| v.to_str().ok())
+        .or_else(|| req.uri().host())
+}
+
+fn request_scheme(headers: &HeaderMap) -> &str {
+    headers
+        .get("x-forwarded-proto")
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| *v == "https")
+        .unwrap_or("http")
+}
+
+fn strip_port(host: &str) -> &str {
+    host.rsplit_once(':')
+        .filter(|(_, port)| port.chars().all(|c| c.is_ascii_digit()))
+        .map_or(host, |(host, _)| host)
+}
+
+/// The bucket addressed by a host of the form `<bucket>.<website-domain>`
+fn website_bucket(domains: &[String], host: &str) -> Option<String> {
+    let host = strip_port(host).to_ascii_lowercase();
+    domains.iter().find_map(|domain| {
+        host.strip_suffix(domain.as_str())
+            .and_then(|prefix| prefix.strip_suffix('.'))
+            .filter(|bucket| !bucket.is_empty())
+            .map(str::to_string)
+    })
+}
+
+fn encode_key(key: &str) -> String {
+    key.split('/')
+        .map(|s| urlencoding::encode(s).into_owned())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_website_bucket() {
+        let layer = WebsiteLayer::new(&["web.example.com".to_string(), "static.example.com:9000".to_string()]);
+
+        assert_eq!(website_bucket(&layer.domains, "blog.web.example.com"), Some("blog".to_string()));
+        assert_eq!(website_bucket(&layer.domains, "Blog.Web.Example.com:8080"), Some("blog".to_string()));
+        assert_eq!(
+            website_bucket(&layer.domains, "my.site.static.example.com:9000"),
+            Some("my.site".to_string())
+        );
+        assert_eq!(website_bucket(&layer.domains, "web.example.com"), None);
+        assert_eq!(website_bucket(&layer.domains, "blogweb.example.com"), None);
+        assert_eq!(website_bucket(&layer.domains, "blog.example.com"), None);
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let range = parse_range("bytes=0-99").unwrap();
+        assert_eq!(range.get_offset_length(1000).unwrap(), (0, 100));
+
+        let range = parse_range("bytes=900-").unwrap();
+        assert_eq!(range.get_offset_length(1000).unwrap(), (900, 100));
+
+        let range = parse_range("bytes=-10").unwrap();
+        assert_eq!(range.get_offset_length(1000).unwrap(), (990, 10));
+
+        let range = parse_range("bytes=500-2000").unwrap();
+        assert_eq!(range.get_offset_length(1000).unwrap(), (500, 500));
+
+        assert!(parse_range("bytes=1000-").unwrap().get_offset_length(1000).is_err());
+        assert!(parse_range("bytes=0-1,5-9").is_none());
+        assert!(parse_range("bytes=9-5").is_none());
+        assert!(parse_range("bytes=-0").is_none());
+        assert!(parse_range("items=0-9").is_none());
+        assert!(parse_range("bytes=abc").is_none());
+    }
+
+    #[test]
+    fn test_encode_key() {
+        assert_eq!(encode_key("docs/my page.html"), "docs/my%20page.html");
+        assert_eq!(encode_key(""), "");
+    }
+}
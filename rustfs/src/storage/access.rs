@@ -15,13 +15,20 @@
 use super::ecfs::FS;
 use crate::auth::{check_key_valid, get_condition_values, get_session_token};
 use crate::license::license_check;
-use crate::server::RemoteAddr;
+use crate::server::{RemoteAddr, TraceApiName};
+use crate::storage::access_log::AccessLogContext;
+use crate::storage::s3_api::acl::has_acl_headers;
+use rustfs_ecstore::bucket::acl::{AccessControlList, AclPermission, get_bucket_acl};
 use rustfs_ecstore::bucket::policy_sys::PolicySys;
+use rustfs_ecstore::bucket::public_access::{PublicAccessBlockApi, bucket_acls_disabled, get_public_access_block};
+use rustfs_ecstore::store_api::ObjectOptions;
+use rustfs_ecstore::{StorageAPI, new_object_layer_fn};
 use rustfs_iam::error::Error as IamError;
 use rustfs_policy::policy::action::{Action, S3Action};
 use rustfs_policy::policy::{Args, BucketPolicyArgs};
 use rustfs_utils::http::AMZ_OBJECT_LOCK_BYPASS_GOVERNANCE;
 use s3s::access::{S3Access, S3AccessContext};
+use s3s::path::S3Path;
 use s3s::{S3Error, S3ErrorCode, S3Request, S3Result, dto::*, s3_error};
 use std::collections::HashMap;
 
@@ -146,6 +153,25 @@ pub async fn authorize_request<T>(req: &mut S3Request<T>, action: Action) -> S3R
                 return Ok(());
             }
         }
+
+        // An explicit Deny in the identity's own policies wins over any ACL grant
+        if !iam_store
+            .is_denied(&Args {
+                account: &cred.access_key,
+                groups: &cred.groups,
+                action,
+                bucket: req_info.bucket.as_deref().unwrap_or(""),
+                conditions: &conditions,
+                is_owner: req_info.is_owner,
+                object: req_info.object.as_deref().unwrap_or(""),
+                claims,
+                deny_only: false,
+            })
+            .await
+            && is_allowed_by_acl(req_info, action, Some(acl_account(cred)), &cred.groups, &conditions).await
+        {
+            return Ok(());
+        }
     } else {
         let conditions = get_condition_values(
             &req.headers,
@@ -184,12 +210,101 @@ pub async fn authorize_request<T>(req: &mut S3Request<T>, action: Action) -> S3R
             {
                 return Ok(());
             }
+
+            if is_allowed_by_acl(req_info, action, None, &None, &conditions).await {
+                return Ok(());
+            }
         }
     }
 
     Err(s3_error!(AccessDenied, "Access Denied"))
 }
 
+/// Object writes that set an ACL through headers also need s3:PutObjectAcl on the written object
+async fn authorize_acl_headers<T>(req: &mut S3Request<T>) -> S3Result<()> {
+    if !has_acl_headers(&req.headers) {
+        return Ok(());
+    }
+
+    authorize_request(req, Action::S3Action(S3Action::PutObjectAclAction)).await
+}
+
+/// The canonical user ACL grants are matched against; temporary and service account
+/// credentials act for the user they were issued to
+fn acl_account(cred: &rustfs_credentials::Credentials) -> &str {
+    if (cred.is_temp() || cred.is_service_account()) && !cred.parent_user.is_empty() {
+        &cred.parent_user
+    } else {
+        &cred.access_key
+    }
+}
+
+/// Falls back to the bucket and object ACLs once IAM and the bucket policy did not allow the request.
+/// ACLs are skipped when the bucket enforces `BucketOwnerEnforced` ownership, grants to public groups
+/// are dropped under IgnorePublicAcls, and an explicit Deny in the bucket policy still wins. Callers
+/// check for an explicit IAM Deny first.
+async fn is_allowed_by_acl(
+    req_info: &ReqInfo,
+    action: Action,
+    account: Option<&str>,
+    groups: &Option<Vec<String>>,
+    conditions: &HashMap<String, Vec<String>>,
+) -> bool {
+    let Some(bucket) = req_info.bucket.as_deref().filter(|b| !b.is_empty()) else {
+        return false;
+    };
+    let object = req_info.object.as_deref().unwrap_or("");
+
+    let object_permission = AclPermission::for_object(&action).filter(|_| !object.is_empty());
+    if object_permission.is_none() && AclPermission::for_bucket(&action).is_none() {
+        return false;
+    }
+
+    // Cheap checks first: object ACLs are only read from disk when they could grant the request
+    if bucket_acls_disabled(bucket).await {
+        return false;
+    }
+
+    let (acl, permission) = if let Some(permission) = object_permission {
+        let Some(store) = new_object_layer_fn() else {
+            return false;
+        };
+        let opts = ObjectOptions {
+            version_id: req_info.version_id.clone(),
+            ..Default::default()
+        };
+        let Ok(info) = store.get_object_info(bucket, object, &opts).await else {
+            return false;
+        };
+        (AccessControlList::from_object_metadata(&info.user_defined), permission)
+    } else if let Some(permission) = AclPermission::for_bucket(&action) {
+        (get_bucket_acl(bucket).await, permission)
+    } else {
+        return false;
+    };
+
+    let Some(acl) = acl else {
+        return false;
+    };
+
+    let ignore_public = get_public_access_block(bucket).await.ignores_public_acls();
+    if !acl.is_allowed(account, permission, ignore_public) {
+        return false;
+    }
+
+    // Evaluated as the owner, the bucket policy only rejects the request on a matching Deny statement
+    PolicySys::is_allowed(&BucketPolicyArgs {
+        bucket,
+        action,
+        is_owner: true,
+        account: account.unwrap_or_default(),
+        groups,
+        conditions,
+        object,
+    })
+    .await
+}
+
 /// Check if the request has the x-amz-bypass-governance-retention header set to true
 pub fn has_bypass_governance_header(headers: &http::HeaderMap) -> bool {
     headers
@@ -228,6 +343,15 @@ impl S3Access for FS {
         //     // cx.extensions_mut(),
         // );
 
+        if let Some(access_log) = cx.extensions_mut().get::<AccessLogContext>().cloned() {
+            let (bucket, key) = match cx.s3_path() {
+                S3Path::Root => (None, None),
+                S3Path::Bucket { bucket } => (Some(&**bucket), None),
+                S3Path::Object { bucket, key } => (Some(&**bucket), Some(&**key)),
+            };
+            access_log.set_target(bucket, key, cx.credentials().map(|c| c.access_key.as_str()));
+        }
+
         let (cred, is_owner) = if let Some(input_cred) = cx.credentials() {
             let (cred, is_owner) =
                 check_key_valid(get_session_token(cx.uri(), cx.headers()).unwrap_or_default(), &input_cred.access_key).await?;
@@ -243,7 +367,11 @@ impl S3Access for FS {
             ..Default::default()
         };
 
+        let op_name = cx.s3_op().name();
         let ext = cx.extensions_mut();
+        if let Some(api_name) = ext.get::<TraceApiName>() {
+            api_name.set(op_name);
+        }
         ext.insert(req_info);
 
         // Verify uniformly here? Or verify separately below?
@@ -309,15 +437,22 @@ impl S3Access for FS {
         req_info.object = Some(req.input.key.clone());
         req_info.version_id = req.input.version_id.clone();
 
-        authorize_request(req, Action::S3Action(S3Action::PutObjectAction)).await
+        authorize_request(req, Action::S3Action(S3Action::PutObjectAction)).await?;
+        authorize_acl_headers(req).await
     }
 
     /// Checks whether the CreateMultipartUpload request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn create_multipart_upload(&self, _req: &mut S3Request<CreateMultipartUploadInput>) -> S3Result<()> {
+    async fn create_multipart_upload(&self, req: &mut S3Request<CreateMultipartUploadInput>) -> S3Result<()> {
         license_check().map_err(|er| s3_error!(AccessDenied, "{:?}", er.to_string()))?;
-        Ok(())
+
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+        req_info.object = Some(req.input.key.clone());
+        req_info.version_id = None;
+
+        authorize_acl_headers(req).await
     }
 
     /// Checks whether the DeleteBucket request has accesses to the resources.
@@ -380,9 +515,12 @@ impl S3Access for FS {
     /// This method returns `Ok(())` by default.
     async fn delete_bucket_inventory_configuration(
         &self,
-        _req: &mut S3Request<DeleteBucketInventoryConfigurationInput>,
+        req: &mut S3Request<DeleteBucketInventoryConfigurationInput>,
     ) -> S3Result<()> {
-        Ok(())
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::PutInventoryConfigurationAction)).await
     }
 
     /// Checks whether the DeleteBucketLifecycle request has accesses to the resources.
@@ -408,8 +546,11 @@ impl S3Access for FS {
     /// Checks whether the DeleteBucketOwnershipControls request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn delete_bucket_ownership_controls(&self, _req: &mut S3Request<DeleteBucketOwnershipControlsInput>) -> S3Result<()> {
-        Ok(())
+    async fn delete_bucket_ownership_controls(&self, req: &mut S3Request<DeleteBucketOwnershipControlsInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::PutBucketOwnershipControlsAction)).await
     }
 
     /// Checks whether the DeleteBucketPolicy request has accesses to the resources.
@@ -445,8 +586,11 @@ impl S3Access for FS {
     /// Checks whether the DeleteBucketWebsite request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn delete_bucket_website(&self, _req: &mut S3Request<DeleteBucketWebsiteInput>) -> S3Result<()> {
-        Ok(())
+    async fn delete_bucket_website(&self, req: &mut S3Request<DeleteBucketWebsiteInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::DeleteBucketWebsiteAction)).await
     }
 
     /// Checks whether the DeleteObject request has accesses to the resources.
@@ -502,8 +646,11 @@ impl S3Access for FS {
     /// Checks whether the DeletePublicAccessBlock request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn delete_public_access_block(&self, _req: &mut S3Request<DeletePublicAccessBlockInput>) -> S3Result<()> {
-        Ok(())
+    async fn delete_public_access_block(&self, req: &mut S3Request<DeletePublicAccessBlockInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::PutBucketPublicAccessBlockAction)).await
     }
 
     /// Checks whether the GetBucketAccelerateConfiguration request has accesses to the resources.
@@ -523,7 +670,7 @@ impl S3Access for FS {
         let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
         req_info.bucket = Some(req.input.bucket.clone());
 
-        authorize_request(req, Action::S3Action(S3Action::GetBucketPolicyAction)).await
+        authorize_request(req, Action::S3Action(S3Action::GetBucketAclAction)).await
     }
 
     /// Checks whether the GetBucketAnalyticsConfiguration request has accesses to the resources.
@@ -571,9 +718,12 @@ impl S3Access for FS {
     /// This method returns `Ok(())` by default.
     async fn get_bucket_inventory_configuration(
         &self,
-        _req: &mut S3Request<GetBucketInventoryConfigurationInput>,
+        req: &mut S3Request<GetBucketInventoryConfigurationInput>,
     ) -> S3Result<()> {
-        Ok(())
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::GetInventoryConfigurationAction)).await
     }
 
     /// Checks whether the GetBucketLifecycleConfiguration request has accesses to the resources.
@@ -602,8 +752,11 @@ impl S3Access for FS {
     /// Checks whether the GetBucketLogging request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn get_bucket_logging(&self, _req: &mut S3Request<GetBucketLoggingInput>) -> S3Result<()> {
-        Ok(())
+    async fn get_bucket_logging(&self, req: &mut S3Request<GetBucketLoggingInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::GetBucketLoggingAction)).await
     }
 
     /// Checks whether the GetBucketMetricsConfiguration request has accesses to the resources.
@@ -629,8 +782,11 @@ impl S3Access for FS {
     /// Checks whether the GetBucketOwnershipControls request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn get_bucket_ownership_controls(&self, _req: &mut S3Request<GetBucketOwnershipControlsInput>) -> S3Result<()> {
-        Ok(())
+    async fn get_bucket_ownership_controls(&self, req: &mut S3Request<GetBucketOwnershipControlsInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::GetBucketOwnershipControlsAction)).await
     }
 
     /// Checks whether the GetBucketPolicy request has accesses to the resources.
@@ -693,8 +849,11 @@ impl S3Access for FS {
     /// Checks whether the GetBucketWebsite request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn get_bucket_website(&self, _req: &mut S3Request<GetBucketWebsiteInput>) -> S3Result<()> {
-        Ok(())
+    async fn get_bucket_website(&self, req: &mut S3Request<GetBucketWebsiteInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::GetBucketWebsiteAction)).await
     }
 
     /// Checks whether the GetObject request has accesses to the resources.
@@ -718,7 +877,7 @@ impl S3Access for FS {
         req_info.object = Some(req.input.key.clone());
         req_info.version_id = req.input.version_id.clone();
 
-        authorize_request(req, Action::S3Action(S3Action::GetBucketPolicyAction)).await
+        authorize_request(req, Action::S3Action(S3Action::GetObjectAclAction)).await
     }
 
     /// Checks whether the GetObjectAttributes request has accesses to the resources.
@@ -797,8 +956,11 @@ impl S3Access for FS {
     /// Checks whether the GetPublicAccessBlock request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn get_public_access_block(&self, _req: &mut S3Request<GetPublicAccessBlockInput>) -> S3Result<()> {
-        Ok(())
+    async fn get_public_access_block(&self, req: &mut S3Request<GetPublicAccessBlockInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::GetBucketPublicAccessBlockAction)).await
     }
 
     /// Checks whether the HeadBucket request has accesses to the resources.
@@ -848,9 +1010,12 @@ impl S3Access for FS {
     /// This method returns `Ok(())` by default.
     async fn list_bucket_inventory_configurations(
         &self,
-        _req: &mut S3Request<ListBucketInventoryConfigurationsInput>,
+        req: &mut S3Request<ListBucketInventoryConfigurationsInput>,
     ) -> S3Result<()> {
-        Ok(())
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::GetInventoryConfigurationAction)).await
     }
 
     /// Checks whether the ListBucketMetricsConfigurations request has accesses to the resources.
@@ -935,7 +1100,7 @@ impl S3Access for FS {
         let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
         req_info.bucket = Some(req.input.bucket.clone());
 
-        authorize_request(req, Action::S3Action(S3Action::PutBucketPolicyAction)).await
+        authorize_request(req, Action::S3Action(S3Action::PutBucketAclAction)).await
     }
 
     /// Checks whether the PutBucketAnalyticsConfiguration request has accesses to the resources.
@@ -983,9 +1148,12 @@ impl S3Access for FS {
     /// This method returns `Ok(())` by default.
     async fn put_bucket_inventory_configuration(
         &self,
-        _req: &mut S3Request<PutBucketInventoryConfigurationInput>,
+        req: &mut S3Request<PutBucketInventoryConfigurationInput>,
     ) -> S3Result<()> {
-        Ok(())
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::PutInventoryConfigurationAction)).await
     }
 
     /// Checks whether the PutBucketLifecycleConfiguration request has accesses to the resources.
@@ -1004,8 +1172,30 @@ impl S3Access for FS {
     /// Checks whether the PutBucketLogging request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn put_bucket_logging(&self, _req: &mut S3Request<PutBucketLoggingInput>) -> S3Result<()> {
-        Ok(())
+    async fn put_bucket_logging(&self, req: &mut S3Request<PutBucketLoggingInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::PutBucketLoggingAction)).await?;
+
+        // Log objects are written on the caller's behalf, so the caller must be allowed to write any
+        // object under the target prefix
+        let Some(logging) = &req.input.bucket_logging_status.logging_enabled else {
+            return Ok(());
+        };
+        let (target_bucket, target_prefix) = (logging.target_bucket.clone(), logging.target_prefix.clone());
+
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        let bucket = req_info.bucket.replace(target_bucket);
+        let object = req_info.object.replace(format!("{target_prefix}*"));
+
+        let result = authorize_request(req, Action::S3Action(S3Action::PutObjectAction)).await;
+
+        // Later checks on this request see the bucket being configured again
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = bucket;
+        req_info.object = object;
+        result
     }
 
     /// Checks whether the PutBucketMetricsConfiguration request has accesses to the resources.
@@ -1031,8 +1221,11 @@ impl S3Access for FS {
     /// Checks whether the PutBucketOwnershipControls request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn put_bucket_ownership_controls(&self, _req: &mut S3Request<PutBucketOwnershipControlsInput>) -> S3Result<()> {
-        Ok(())
+    async fn put_bucket_ownership_controls(&self, req: &mut S3Request<PutBucketOwnershipControlsInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::PutBucketOwnershipControlsAction)).await
     }
 
     /// Checks whether the PutBucketPolicy request has accesses to the resources.
@@ -1085,8 +1278,11 @@ impl S3Access for FS {
     /// Checks whether the PutBucketWebsite request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn put_bucket_website(&self, _req: &mut S3Request<PutBucketWebsiteInput>) -> S3Result<()> {
-        Ok(())
+    async fn put_bucket_website(&self, req: &mut S3Request<PutBucketWebsiteInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::PutBucketWebsiteAction)).await
     }
 
     /// Checks whether the PutObject request has accesses to the resources.
@@ -1100,7 +1296,8 @@ impl S3Access for FS {
         req_info.object = Some(req.input.key.clone());
         req_info.version_id = req.input.version_id.clone();
 
-        authorize_request(req, Action::S3Action(S3Action::PutObjectAction)).await
+        authorize_request(req, Action::S3Action(S3Action::PutObjectAction)).await?;
+        authorize_acl_headers(req).await
     }
 
     /// Checks whether the PutObjectAcl request has accesses to the resources.
@@ -1112,7 +1309,7 @@ impl S3Access for FS {
         req_info.object = Some(req.input.key.clone());
         req_info.version_id = req.input.version_id.clone();
 
-        authorize_request(req, Action::S3Action(S3Action::PutBucketPolicyAction)).await
+        authorize_request(req, Action::S3Action(S3Action::PutObjectAclAction)).await
     }
 
     /// Checks whether the PutObjectLegalHold request has accesses to the resources.
@@ -1169,8 +1366,11 @@ impl S3Access for FS {
     /// Checks whether the PutPublicAccessBlock request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
-    async fn put_public_access_block(&self, _req: &mut S3Request<PutPublicAccessBlockInput>) -> S3Result<()> {
-        Ok(())
+    async fn put_public_access_block(&self, req: &mut S3Request<PutPublicAccessBlockInput>) -> S3Result<()> {
+        let req_info = req.extensions.get_mut::<ReqInfo>().expect("ReqInfo not found");
+        req_info.bucket = Some(req.input.bucket.clone());
+
+        authorize_request(req, Action::S3Action(S3Action::PutBucketPublicAccessBlockAction)).await
     }
 
     /// Checks whether the RestoreObject request has accesses to the resources.
@@ -1221,3 +1421,50 @@ impl S3Access for FS {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustfs_credentials::{Credentials, IAM_POLICY_CLAIM_NAME_SA};
+    use rustfs_ecstore::bucket::acl::{AclGrant, AclGrantee};
+
+    #[test]
+    fn test_acl_grants_match_parent_user_of_derived_credentials() {
+        let acl = AccessControlList {
+            grants: vec![AclGrant {
+                grantee: AclGrantee::CanonicalUser("alice".to_string()),
+                permission: AclPermission::Read,
+            }],
+        };
+
+        let user = Credentials {
+            access_key: "alice".to_string(),
+            ..Default::default()
+        };
+        let temp = Credentials {
+            access_key: "TEMPACCESSKEY".to_string(),
+            session_token: "token".to_string(),
+            parent_user: "alice".to_string(),
+            ..Default::default()
+        };
+        let service_account = Credentials {
+            access_key: "SVCACCESSKEY".to_string(),
+            parent_user: "alice".to_string(),
+            claims: Some(HashMap::from([(IAM_POLICY_CLAIM_NAME_SA.to_string(), "inherited-policy".into())])),
+            ..Default::default()
+        };
+        let other = Credentials {
+            access_key: "bob".to_string(),
+            parent_user: "alice".to_string(),
+            ..Default::default()
+        };
+
+        for cred in [&user, &temp, &service_account] {
+            assert_eq!(acl_account(cred), "alice");
+            assert!(acl.is_allowed(Some(acl_account(cred)), AclPermission::Read, false));
+        }
+        // A parent user only counts for temporary and service account credentials
+        assert_eq!(acl_account(&other), "bob");
+        assert!(!acl.is_allowed(Some(acl_account(&other)), AclPermission::Read, false));
+    }
+}
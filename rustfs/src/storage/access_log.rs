@@ -0,0 +1,550 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! S3 server access logging.
+//!
+//! Every S3 request to a bucket with a `BucketLoggingStatus`, including those rejected before reaching
+//! a handler, is turned into an AWS-format access log line, buffered per target bucket and prefix,
+//! and written as log objects by a periodic flush.
+
+use crate::storage::s3_api::common::RUSTFS_OWNER_ID;
+use chrono::{DateTime, Utc};
+use http::{HeaderMap, HeaderName, Method, StatusCode, Uri};
+use rustfs_config::{
+    DEFAULT_ACCESS_LOG_FLUSH_INTERVAL_SECS, DEFAULT_ACCESS_LOG_MAX_BATCH, ENV_ACCESS_LOG_FLUSH_INTERVAL_SECS,
+    ENV_ACCESS_LOG_MAX_BATCH,
+};
+use rustfs_ecstore::bucket::metadata_sys;
+use rustfs_ecstore::new_object_layer_fn;
+use rustfs_ecstore::store_api::{ObjectIO, ObjectOptions, PutObjReader};
+use s3s::S3ErrorCode;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex as StdMutex};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Query parameters that name the bucket or object sub-resource of a request
+const SUB_RESOURCES: &[&str] = &[
+    "accelerate",
+    "acl",
+    "analytics",
+    "cors",
+    "encryption",
+    "intelligent-tiering",
+    "inventory",
+    "legal-hold",
+    "lifecycle",
+    "location",
+    "logging",
+    "metrics",
+    "notification",
+    "object-lock",
+    "ownershipControls",
+    "policy",
+    "policyStatus",
+    "publicAccessBlock",
+    "replication",
+    "requestPayment",
+    "restore",
+    "retention",
+    "select",
+    "tagging",
+    "torrent",
+    "uploadId",
+    "uploads",
+    "versioning",
+    "versions",
+    "website",
+];
+
+/// Query parameters of presigned requests that carry credentials, left out of the logged Request-URI
+const CREDENTIAL_QUERY_PARAMS: &[&str] = &[
+    "X-Amz-Signature",
+    "X-Amz-Credential",
+    "X-Amz-Security-Token",
+    "Signature",
+    "AWSAccessKeyId",
+];
+
+static ACCESS_LOG: LazyLock<AccessLogWriter> = LazyLock::new(|| AccessLogWriter {
+    buffers: Mutex::new(HashMap::new()),
+    max_batch: rustfs_utils::get_env_usize(ENV_ACCESS_LOG_MAX_BATCH, DEFAULT_ACCESS_LOG_MAX_BATCH).max(1),
+});
+
+/// Everything an access log line is built from.
+///
+/// The HTTP layer fills in what the request and response carry, the S3 access check and the
+/// operation handlers add what only they resolve through [`AccessLogContext`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AccessLogRequest {
+    time: DateTime<Utc>,
+    method: String,
+    request_uri: String,
+    remote_ip: Option<String>,
+    referer: Option<String>,
+    user_agent: Option<String>,
+    host: Option<String>,
+    signature_version: Option<&'static str>,
+    auth_type: Option<&'static str>,
+    bucket: Option<String>,
+    key: Option<String>,
+    requester: Option<String>,
+    request_id: Option<String>,
+    status: Option<u16>,
+    bytes_sent: Option<u64>,
+    total_time: Option<Duration>,
+    error_code: Option<String>,
+    version_id: Option<String>,
+    object_size: Option<i64>,
+}
+
+impl AccessLogRequest {
+    pub fn new(method: &Method, uri: &Uri, headers: &HeaderMap, remote_ip: Option<String>) -> Self {
+        let query = uri.query().unwrap_or_default();
+        let header = |name: HeaderName| headers.get(name).and_then(|v| v.to_str().ok()).map(str::to_string);
+        let authorization = header(http::header::AUTHORIZATION).unwrap_or_default();
+
+        let (signature_version, auth_type) = if authorization.starts_with("AWS4-HMAC-SHA256") {
+            (Some("SigV4"), Some("AuthHeader"))
+        } else if authorization.starts_with("AWS ") {
+            (Some("SigV2"), Some("AuthHeader"))
+        } else if query.contains("X-Amz-Algorithm=") {
+            (Some("SigV4"), Some("QueryString"))
+        } else if query.contains("Signature=") {
+            (Some("SigV2"), Some("QueryString"))
+        } else {
+            (None, None)
+        };
+
+        let request_uri = match uri.query() {
+            Some(query) => format!("{}?{}", uri.path(), strip_credentials(query)),
+            None => uri.path().to_string(),
+        };
+
+        Self {
+            time: Utc::now(),
+            method: method.to_string(),
+            request_uri,
+            remote_ip,
+            referer: header(http::header::REFERER),
+            user_agent: header(http::header::USER_AGENT),
+            host: header(http::header::HOST),
+            signature_version,
+            auth_type,
+            ..Default::default()
+        }
+    }
+
+    /// Records the response once the request has been served
+    pub fn finish(&mut self, status: StatusCode, headers: &HeaderMap, total_time: Duration) {
+        self.status = Some(status.as_u16());
+        self.total_time = Some(total_time);
+        self.bytes_sent = headers
+            .get(http::header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok());
+        self.request_id = headers
+            .get("x-amz-request-id")
+            .or_else(|| headers.get("x-request-id"))
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        // Requests rejected before an S3 handler ran carry no error code of their own
+        if self.error_code.is_none() && status == StatusCode::FORBIDDEN {
+            self.error_code = Some(S3ErrorCode::AccessDenied.as_str().to_string());
+        }
+    }
+
+    /// Falls back to the bucket and key named by the request when the S3 access check never ran,
+    /// e.g. for requests whose signature was rejected
+    pub fn resolve_target(&mut self, domains: &[String]) {
+        if self.bucket.is_some() {
+            return;
+        }
+
+        let path = self
+            .request_uri
+            .split_once('?')
+            .map_or(self.request_uri.as_str(), |(path, _)| path);
+        let path = path.trim_start_matches('/');
+        let host = self
+            .host
+            .as_deref()
+            .map(|h| strip_port(h).to_ascii_lowercase())
+            .unwrap_or_default();
+        let virtual_bucket = domains.iter().find_map(|domain| {
+            host.strip_suffix(strip_port(domain))
+                .and_then(|prefix| prefix.strip_suffix('.'))
+                .filter(|bucket| !bucket.is_empty())
+        });
+
+        let (bucket, key) = match virtual_bucket {
+            Some(bucket) => (bucket, path),
+            None => path.split_once('/').unwrap_or((path, "")),
+        };
+        self.bucket = Some(bucket.to_string()).filter(|b| !b.is_empty());
+        self.key = urlencoding::decode(key)
+            .ok()
+            .map(|k| k.into_owned())
+            .filter(|k| !k.is_empty());
+    }
+
+    /// `REST.<METHOD>.<RESOURCE>` as used in the S3 access log operation field
+    fn operation(&self) -> String {
+        let query = self.request_uri.split_once('?').map(|(_, q)| q).unwrap_or_default();
+        let sub_resource = query
+            .split('&')
+            .map(|param| param.split_once('=').map_or(param, |(name, _)| name))
+            .find(|name| SUB_RESOURCES.contains(name));
+
+        let resource = match (sub_resource, self.key.is_some()) {
+            (Some("uploadId" | "uploads"), _) => "UPLOAD".to_string(),
+            (Some(name), _) => name.replace('-', "_").to_ascii_uppercase(),
+            (None, true) => "OBJECT".to_string(),
+            (None, false) => "BUCKET".to_string(),
+        };
+
+        format!("REST.{}.{resource}", self.method)
+    }
+}
+
+/// Request extension through which the S3 access check and handlers report what they resolved
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AccessLogContext(Arc<StdMutex<AccessLogRequest>>);
+
+impl AccessLogContext {
+    pub fn new(req: AccessLogRequest) -> Self {
+        Self(Arc::new(StdMutex::new(req)))
+    }
+
+    fn update(&self, f: impl FnOnce(&mut AccessLogRequest)) {
+        if let Ok(mut req) = self.0.lock() {
+            f(&mut req);
+        }
+    }
+
+    /// The bucket, key and requester resolved by the S3 access check
+    pub fn set_target(&self, bucket: Option<&str>, key: Option<&str>, requester: Option<&str>) {
+        self.update(|req| {
+            req.bucket = bucket.filter(|b| !b.is_empty()).map(str::to_string);
+            req.key = key.filter(|k| !k.is_empty()).map(str::to_string);
+            req.requester = requester.filter(|r| !r.is_empty()).map(str::to_string);
+        });
+    }
+
+    pub fn set_error_code(&self, code: &S3ErrorCode) {
+        self.update(|req| req.error_code = Some(code.as_str().to_string()));
+    }
+
+    pub fn set_version_id(&self, version_id: &str) {
+        self.update(|req| req.version_id = Some(version_id.to_string()).filter(|v| !v.is_empty()));
+    }
+
+    pub fn set_object_size(&self, size: i64) {
+        self.update(|req| req.object_size = Some(size));
+    }
+
+    pub fn take(&self) -> AccessLogRequest {
+        self.0.lock().map(|mut req| std::mem::take(&mut *req)).unwrap_or_default()
+    }
+}
+
+fn strip_port(host: &str) -> &str {
+    host.rsplit_once(':')
+        .filter(|(_, port)| port.chars().all(|c| c.is_ascii_digit()))
+        .map_or(host, |(host, _)| host)
+}
+
+fn strip_credentials(query: &str) -> String {
+    query
+        .split('&')
+        .filter(|pair| {
+            let name = pair.split_once('=').map_or(*pair, |(name, _)| name);
+            !CREDENTIAL_QUERY_PARAMS.iter().any(|p| name.eq_ignore_ascii_case(p))
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Formats one access log line in the S3 server access log format
+fn format_line(req: &AccessLogRequest) -> String {
+    fn field(value: Option<&str>) -> &str {
+        value.filter(|v| !v.is_empty()).unwrap_or("-")
+    }
+
+    fn quoted(value: Option<&str>) -> String {
+        value
+            .filter(|v| !v.is_empty())
+            .map_or_else(|| "-".to_string(), |v| format!("\"{}\"", v.replace('"', "\\\"")))
+    }
+
+    fn number(value: Option<impl ToString>) -> String {
+        value.map_or_else(|| "-".to_string(), |v| v.to_string())
+    }
+
+    [
+        RUSTFS_OWNER_ID.to_string(),
+        field(req.bucket.as_deref()).to_string(),
+        req.time.format("[%d/%b/%Y:%H:%M:%S %z]").to_string(),
+        field(req.remote_ip.as_deref()).to_string(),
+        field(req.requester.as_deref()).to_string(),
+        field(req.request_id.as_deref()).to_string(),
+        req.operation(),
+        req.key.as_deref().map_or_else(
+            || "-".to_string(),
+            |k| {
+                k.split('/')
+                    .map(|s| urlencoding::encode(s).into_owned())
+                    .collect::<Vec<_>>()
+                    .join("/")
+            },
+        ),
+        format!("\"{} {} HTTP/1.1\"", req.method, req.request_uri),
+        number(req.status),
+        field(req.error_code.as_deref()).to_string(),
+        number(req.bytes_sent),
+        number(req.object_size),
+        number(req.total_time.map(|t| t.as_millis())),
+        "-".to_string(),
+        quoted(req.referer.as_deref()),
+        quoted(req.user_agent.as_deref()),
+        field(req.version_id.as_deref()).to_string(),
+        "-".to_string(),
+        field(req.signature_version).to_string(),
+        "-".to_string(),
+        field(req.auth_type).to_string(),
+        field(req.host.as_deref()).to_string(),
+        "-".to_string(),
+        "-".to_string(),
+        "-".to_string(),
+    ]
+    .join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct LogTarget {
+    bucket: String,
+    prefix: String,
+}
+
+struct AccessLogWriter {
+    buffers: Mutex<HashMap<LogTarget, Vec<String>>>,
+    max_batch: usize,
+}
+
+impl AccessLogWriter {
+    async fn push(&self, target: LogTarget, line: String) {
+        let full = {
+            let mut buffers = self.buffers.lock().await;
+            let lines = buffers.entry(target.clone()).or_default();
+            lines.push(line);
+            if lines.len() >= self.max_batch {
+                buffers.remove(&target)
+            } else {
+                None
+            }
+        };
+
+        if let Some(lines) = full {
+            write_log_object(&target, lines).await;
+        }
+    }
+
+    async fn flush(&self) {
+        let buffers = std::mem::take(&mut *self.buffers.lock().await);
+        for (target, lines) in buffers {
+            write_log_object(&target, lines).await;
+        }
+    }
+}
+
+async fn write_log_object(target: &LogTarget, lines: Vec<String>) {
+    let Some(store) = new_object_layer_fn() else {
+        warn!(
+            "access log dropped {} records for {}, object layer not initialized",
+            lines.len(),
+            target.bucket
+        );
+        return;
+    };
+
+    let now = chrono::Utc::now();
+    let unique = uuid::Uuid::new_v4().simple().to_string().to_ascii_uppercase();
+    let object = format!("{}{}-{}", target.prefix, now.format("%Y-%m-%d-%H-%M-%S"), &unique[..16]);
+
+    let mut data = lines.join("\n");
+    data.push('\n');
+
+    let opts = ObjectOptions {
+        user_defined: HashMap::from([("content-type".to_string(), "text/plain".to_string())]),
+        ..Default::default()
+    };
+    match store
+        .put_object(&target.bucket, &object, &mut PutObjReader::from_vec(data.into_bytes()), &opts)
+        .await
+    {
+        Ok(_) => debug!("access log written to {}/{} with {} records", target.bucket, object, lines.len()),
+        Err(err) => warn!("access log write to {}/{} failed: {:?}", target.bucket, object, err),
+    }
+}
+
+/// Buffers an access log line for the request when its bucket has server access logging enabled
+pub(crate) async fn record(req: AccessLogRequest) {
+    let Some(bucket) = req.bucket.as_deref() else {
+        return;
+    };
+    let Ok((config, _)) = metadata_sys::get_logging_config(bucket).await else {
+        return;
+    };
+    let Some(logging) = config.logging_enabled else {
+        return;
+    };
+
+    let target = LogTarget {
+        bucket: logging.target_bucket,
+        prefix: logging.target_prefix,
+    };
+    ACCESS_LOG.push(target, format_line(&req)).await;
+}
+
+/// Starts the periodic flush of buffered access log lines
+pub(crate) fn init_access_log() {
+    let interval = rustfs_utils::get_env_u64(ENV_ACCESS_LOG_FLUSH_INTERVAL_SECS, DEFAULT_ACCESS_LOG_FLUSH_INTERVAL_SECS).max(1);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            ACCESS_LOG.flush().await;
+        }
+    });
+}
+
+/// Writes out all buffered access log lines, used on shutdown
+pub(crate) async fn flush_access_log() {
+    ACCESS_LOG.flush().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn request(method: &str, uri: &str) -> AccessLogRequest {
+        let mut req = AccessLogRequest::new(
+            &Method::from_bytes(method.as_bytes()).unwrap(),
+            &uri.parse::<Uri>().unwrap(),
+            &HeaderMap::new(),
+            None,
+        );
+        req.resolve_target(&[]);
+        req
+    }
+
+    #[test]
+    fn test_operation() {
+        assert_eq!(request("GET", "/bucket/photo.jpg").operation(), "REST.GET.OBJECT");
+        assert_eq!(request("GET", "/bucket?list-type=2").operation(), "REST.GET.BUCKET");
+        assert_eq!(request("PUT", "/bucket?versioning").operation(), "REST.PUT.VERSIONING");
+        assert_eq!(request("PUT", "/bucket/key?tagging=").operation(), "REST.PUT.TAGGING");
+        assert_eq!(request("GET", "/bucket/key?legal-hold").operation(), "REST.GET.LEGAL_HOLD");
+        assert_eq!(request("POST", "/bucket/key?uploadId=abc").operation(), "REST.POST.UPLOAD");
+    }
+
+    #[test]
+    fn test_presigned_credentials_are_stripped() {
+        let req = request(
+            "GET",
+            "/bucket/key?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=AKIA%2F20240206&X-Amz-Date=20240206T000000Z\
+             &X-Amz-Expires=300&X-Amz-SignedHeaders=host&X-Amz-Signature=abcdef&versionId=v1",
+        );
+        assert_eq!(
+            req.request_uri,
+            "/bucket/key?X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Date=20240206T000000Z&X-Amz-Expires=300\
+             &X-Amz-SignedHeaders=host&versionId=v1"
+        );
+        assert_eq!(req.signature_version, Some("SigV4"));
+        assert_eq!(req.auth_type, Some("QueryString"));
+
+        let req = request("GET", "/bucket/key?AWSAccessKeyId=AKIA&Expires=1700000000&Signature=abc%3D");
+        assert_eq!(req.request_uri, "/bucket/key?Expires=1700000000");
+        assert_eq!(req.signature_version, Some("SigV2"));
+    }
+
+    #[test]
+    fn test_resolve_target() {
+        let req = request("GET", "/logs-source/dir/my%20photo.jpg");
+        assert_eq!(req.bucket.as_deref(), Some("logs-source"));
+        assert_eq!(req.key.as_deref(), Some("dir/my photo.jpg"));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(http::header::HOST, "logs-source.s3.example.com:9000".parse().unwrap());
+        let mut req = AccessLogRequest::new(&Method::GET, &"/dir/photo.jpg".parse::<Uri>().unwrap(), &headers, None);
+        req.resolve_target(&["s3.example.com:9000".to_string()]);
+        assert_eq!(req.bucket.as_deref(), Some("logs-source"));
+        assert_eq!(req.key.as_deref(), Some("dir/photo.jpg"));
+
+        // What the S3 access check resolved is kept
+        let context = AccessLogContext::new(req);
+        context.set_target(Some("other"), None, Some("AKIAEXAMPLE"));
+        let mut req = context.take();
+        req.resolve_target(&[]);
+        assert_eq!(req.bucket.as_deref(), Some("other"));
+        assert_eq!(req.key, None);
+    }
+
+    #[test]
+    fn test_format_line() {
+        let mut headers = HeaderMap::new();
+        headers.insert(http::header::AUTHORIZATION, "AWS4-HMAC-SHA256 Credential=AKIAEXAMPLE".parse().unwrap());
+        headers.insert(http::header::USER_AGENT, "aws-cli/2.0".parse().unwrap());
+        headers.insert(http::header::HOST, "s3.example.com".parse().unwrap());
+        let mut req = AccessLogRequest::new(
+            &Method::GET,
+            &"/logs-source/dir/my%20photo.jpg".parse::<Uri>().unwrap(),
+            &headers,
+            Some("192.0.2.3".to_string()),
+        );
+        req.time = Utc.with_ymd_and_hms(2024, 2, 6, 0, 0, 38).unwrap();
+
+        let context = AccessLogContext::new(req);
+        context.set_target(Some("logs-source"), Some("dir/my photo.jpg"), Some("AKIAEXAMPLE"));
+        context.set_object_size(2048);
+        let mut req = context.take();
+
+        let mut resp_headers = HeaderMap::new();
+        resp_headers.insert(http::header::CONTENT_LENGTH, "2048".parse().unwrap());
+        resp_headers.insert("x-amz-request-id", "3E57427F3EXAMPLE".parse().unwrap());
+        req.finish(StatusCode::OK, &resp_headers, Duration::from_micros(12500));
+
+        assert_eq!(
+            format_line(&req),
+            format!(
+                "{RUSTFS_OWNER_ID} logs-source [06/Feb/2024:00:00:38 +0000] 192.0.2.3 AKIAEXAMPLE 3E57427F3EXAMPLE \
+                 REST.GET.OBJECT dir/my%20photo.jpg \"GET /logs-source/dir/my%20photo.jpg HTTP/1.1\" 200 - 2048 2048 12 - - \
+                 \"aws-cli/2.0\" - - SigV4 - AuthHeader s3.example.com - - -"
+            )
+        );
+
+        // A request denied before any handler ran still gets a line with its error code
+        let mut req = AccessLogRequest::new(&Method::PUT, &"/logs-source/key".parse::<Uri>().unwrap(), &headers, None);
+        req.resolve_target(&[]);
+        req.finish(StatusCode::FORBIDDEN, &HeaderMap::new(), Duration::from_millis(1));
+        let line = format_line(&req);
+        assert!(
+            line.contains("REST.PUT.OBJECT key \"PUT /logs-source/key HTTP/1.1\" 403 AccessDenied"),
+            "{line}"
+        );
+    }
+}
@@ -23,6 +23,7 @@ use crate::storage::head_prefix::{head_prefix_not_found_message, probe_prefix_ha
 use crate::storage::helper::OperationHelper;
 use crate::storage::options::{filter_object_metadata, get_content_sha256};
 use crate::storage::readers::InMemoryAsyncReader;
+use crate::storage::s3_api::acl::{acl_from_headers, build_acl, default_acl, missing_acl_error};
 use crate::storage::s3_api::bucket::{
     build_list_object_versions_output, build_list_objects_output, build_list_objects_v2_output,
     parse_list_object_versions_params, parse_list_objects_v2_params,
@@ -47,10 +48,10 @@ use crate::storage::{
     },
 };
 use crate::storage::{
-    check_preconditions, get_buffer_size_opt_in, get_validated_store, has_replication_rules, parse_object_lock_legal_hold,
-    parse_object_lock_retention, process_lambda_configurations, process_queue_configurations, process_topic_configurations,
-    validate_bucket_object_lock_enabled, validate_list_object_unordered_with_delimiter, validate_object_key,
-    wrap_response_with_cors,
+    check_preconditions, get_buffer_size_opt_in, get_validated_store, has_replication_rules, lifecycle_prediction_headers,
+    parse_object_lock_legal_hold, parse_object_lock_retention, process_lambda_configurations, process_queue_configurations,
+    process_topic_configurations, validate_acl_update, validate_bucket_object_lock_enabled,
+    validate_list_object_unordered_with_delimiter, validate_object_key, wrap_response_with_cors,
 };
 use crate::storage::{entity, parse_part_number_i32_to_usize};
 // base64 imports moved to sse module
@@ -64,18 +65,23 @@ use metrics::{counter, histogram};
 use rustfs_ecstore::bucket::quota::checker::QuotaChecker;
 use rustfs_ecstore::{
     bucket::{
+        acl::{AccessControlList, AclPermission, OBJECT_ACL_METADATA_KEY, get_bucket_acl},
+        inventory::{InventoryApi, MAX_INVENTORY_LIST_SIZE, get_inventory_configs, marshal_inventory_configs},
         lifecycle::{
             bucket_lifecycle_ops::{RestoreRequestOps, post_restore_opts, validate_transition_tier},
             lifecycle::{self, Lifecycle, TransitionOptions},
         },
         metadata::{
-            BUCKET_CORS_CONFIG, BUCKET_LIFECYCLE_CONFIG, BUCKET_NOTIFICATION_CONFIG, BUCKET_POLICY_CONFIG,
-            BUCKET_REPLICATION_CONFIG, BUCKET_SSECONFIG, BUCKET_TAGGING_CONFIG, BUCKET_VERSIONING_CONFIG, OBJECT_LOCK_CONFIG,
+            BUCKET_ACL_CONFIG, BUCKET_CORS_CONFIG, BUCKET_INVENTORY_CONFIG, BUCKET_LIFECYCLE_CONFIG, BUCKET_LOGGING_CONFIG,
+            BUCKET_NOTIFICATION_CONFIG, BUCKET_OWNERSHIP_CONTROLS_CONFIG, BUCKET_POLICY_CONFIG,
+            BUCKET_PUBLIC_ACCESS_BLOCK_CONFIG, BUCKET_REPLICATION_CONFIG, BUCKET_SSECONFIG, BUCKET_TAGGING_CONFIG,
+            BUCKET_VERSIONING_CONFIG, BUCKET_WEBSITE_CONFIG, OBJECT_LOCK_CONFIG,
         },
         metadata_sys,
         metadata_sys::get_replication_config,
         object_lock::objectlock_sys::{BucketObjectLockSys, check_object_lock_for_deletion, check_retention_for_modification},
         policy_sys::PolicySys,
+        public_access::{OwnershipControlsApi, PublicAccessBlockApi, get_public_access_block},
         quota::QuotaOperation,
         replication::{
             DeletedObjectReplicationInfo, check_replicate_delete, get_must_replicate_options, must_replicate,
@@ -85,6 +91,7 @@ use rustfs_ecstore::{
         utils::serialize,
         versioning::VersioningApi,
         versioning_sys::BucketVersioningSys,
+        website::WebsiteApi,
     },
     client::object_api_utils::to_s3s_etag,
     compress::{MIN_COMPRESSIBLE_SIZE, is_compressible},
@@ -127,7 +134,7 @@ use rustfs_targets::{
     EventName,
     arn::{ARN, TargetIDError},
 };
-use rustfs_utils::http::RUSTFS_FORCE_DELETE;
+use rustfs_utils::http::{RUSTFS_FORCE_DELETE, RUSTFS_TRANSITION};
 use rustfs_utils::string::parse_bool;
 use rustfs_utils::{
     CompressionAlgorithm, extract_params_header, extract_resp_elements, get_request_host, get_request_port,
@@ -960,6 +967,12 @@ impl S3 for FS {
             }
         }
 
+        // ACLs are not copied, the destination gets the ACL of the request or the default one
+        src_info.user_defined.remove(OBJECT_ACL_METADATA_KEY);
+        if let Some(acl) = acl_from_headers(&bucket, &req.headers).await? {
+            src_info.user_defined.extend(acl.to_object_metadata());
+        }
+
         let mut reader = HashReader::new(reader, length, actual_size, None, None, false).map_err(ApiError::from)?;
 
         // Apply unified SSE encryption for destination object
@@ -1091,10 +1104,28 @@ impl S3 for FS {
         let helper = OperationHelper::new(&req, EventName::BucketCreated, "s3:CreateBucket");
         let CreateBucketInput {
             bucket,
+            acl,
+            grant_full_control,
+            grant_read,
+            grant_read_acp,
+            grant_write,
+            grant_write_acp,
             object_lock_enabled_for_bucket,
             ..
         } = req.input;
 
+        let acl = build_acl(
+            acl.as_ref().map(|a| a.as_str()),
+            None,
+            &[
+                (AclPermission::FullControl, grant_full_control.as_deref()),
+                (AclPermission::Read, grant_read.as_deref()),
+                (AclPermission::ReadAcp, grant_read_acp.as_deref()),
+                (AclPermission::Write, grant_write.as_deref()),
+                (AclPermission::WriteAcp, grant_write_acp.as_deref()),
+            ],
+        )?;
+
         let Some(store) = new_object_layer_fn() else {
             return Err(not_initialized_error());
         };
@@ -1113,6 +1144,14 @@ impl S3 for FS {
             .await
             .map_err(ApiError::from)?;
 
+        // A new bucket has no Public Access Block or Ownership Controls yet, so any ACL is accepted
+        if let Some(acl) = acl {
+            let data = serde_json::to_vec(&acl).map_err(|e| s3_error!(InternalError, "serialize acl failed {:?}", e))?;
+            metadata_sys::update(&bucket, BUCKET_ACL_CONFIG, data)
+                .await
+                .map_err(ApiError::from)?;
+        }
+
         let output = CreateBucketOutput::default();
 
         let result = Ok(s3_response(output));
@@ -1160,6 +1199,10 @@ impl S3 for FS {
             metadata.insert(AMZ_OBJECT_TAGGING.to_owned(), tags);
         }
 
+        if let Some(acl) = acl_from_headers(&bucket, &req.headers).await? {
+            metadata.extend(acl.to_object_metadata());
+        }
+
         // Prepare SSE configuration for multipart upload
         // Apply encryption using unified SSE API
         let encryption_request = PrepareEncryptionRequest {
@@ -1316,6 +1359,46 @@ impl S3 for FS {
         Ok(s3_response(DeleteBucketEncryptionOutput::default()))
     }
 
+    async fn delete_bucket_inventory_configuration(
+        &self,
+        req: S3Request<DeleteBucketInventoryConfigurationInput>,
+    ) -> S3Result<S3Response<DeleteBucketInventoryConfigurationOutput>> {
+        let DeleteBucketInventoryConfigurationInput { bucket, id, .. } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        let mut configs = get_inventory_configs(&bucket).await.map_err(ApiError::from)?;
+        let Some(pos) = configs.iter().position(|c| c.id == id) else {
+            let mut err = S3Error::with_message(
+                S3ErrorCode::Custom("NoSuchConfiguration".into()),
+                "The specified configuration does not exist".to_string(),
+            );
+            err.set_status_code(StatusCode::NOT_FOUND);
+            return Err(err);
+        };
+        configs.remove(pos);
+
+        if configs.is_empty() {
+            metadata_sys::delete(&bucket, BUCKET_INVENTORY_CONFIG)
+                .await
+                .map_err(ApiError::from)?;
+        } else {
+            let data = marshal_inventory_configs(&configs).map_err(ApiError::from)?;
+            metadata_sys::update(&bucket, BUCKET_INVENTORY_CONFIG, data)
+                .await
+                .map_err(ApiError::from)?;
+        }
+
+        Ok(s3_response(DeleteBucketInventoryConfigurationOutput::default()))
+    }
+
     #[instrument(level = "debug", skip(self))]
     async fn delete_bucket_lifecycle(
         &self,
@@ -1339,6 +1422,28 @@ impl S3 for FS {
         Ok(s3_response(DeleteBucketLifecycleOutput::default()))
     }
 
+    async fn delete_bucket_ownership_controls(
+        &self,
+        req: S3Request<DeleteBucketOwnershipControlsInput>,
+    ) -> S3Result<S3Response<DeleteBucketOwnershipControlsOutput>> {
+        let DeleteBucketOwnershipControlsInput { bucket, .. } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        metadata_sys::delete(&bucket, BUCKET_OWNERSHIP_CONTROLS_CONFIG)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(s3_response(DeleteBucketOwnershipControlsOutput {}))
+    }
+
     async fn delete_bucket_policy(
         &self,
         req: S3Request<DeleteBucketPolicyInput>,
@@ -1399,6 +1504,29 @@ impl S3 for FS {
         Ok(s3_response(DeleteBucketTaggingOutput {}))
     }
 
+    #[instrument(level = "debug", skip(self))]
+    async fn delete_bucket_website(
+        &self,
+        req: S3Request<DeleteBucketWebsiteInput>,
+    ) -> S3Result<S3Response<DeleteBucketWebsiteOutput>> {
+        let DeleteBucketWebsiteInput { bucket, .. } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        metadata_sys::delete(&bucket, BUCKET_WEBSITE_CONFIG)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(s3_response(DeleteBucketWebsiteOutput {}))
+    }
+
     /// Delete an object
     #[instrument(level = "debug", skip(self, req))]
     async fn delete_object(&self, mut req: S3Request<DeleteObjectInput>) -> S3Result<S3Response<DeleteObjectOutput>> {
@@ -1945,6 +2073,28 @@ impl S3 for FS {
         result
     }
 
+    async fn delete_public_access_block(
+        &self,
+        req: S3Request<DeletePublicAccessBlockInput>,
+    ) -> S3Result<S3Response<DeletePublicAccessBlockOutput>> {
+        let DeletePublicAccessBlockInput { bucket, .. } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        metadata_sys::delete(&bucket, BUCKET_PUBLIC_ACCESS_BLOCK_CONFIG)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(s3_response(DeletePublicAccessBlockOutput {}))
+    }
+
     async fn get_bucket_acl(&self, req: S3Request<GetBucketAclInput>) -> S3Result<S3Response<GetBucketAclOutput>> {
         let GetBucketAclInput { bucket, .. } = req.input;
 
@@ -1957,19 +2107,10 @@ impl S3 for FS {
             .await
             .map_err(ApiError::from)?;
 
-        let grants = vec![Grant {
-            grantee: Some(Grantee {
-                type_: Type::from_static(Type::CANONICAL_USER),
-                display_name: None,
-                email_address: None,
-                id: None,
-                uri: None,
-            }),
-            permission: Some(Permission::from_static(Permission::FULL_CONTROL)),
-        }];
+        let acl = get_bucket_acl(&bucket).await.unwrap_or_else(default_acl);
 
         Ok(s3_response(GetBucketAclOutput {
-            grants: Some(grants),
+            grants: Some(acl.to_grants()),
             owner: Some(RUSTFS_OWNER.to_owned()),
         }))
     }
@@ -2035,6 +2176,36 @@ impl S3 for FS {
         }))
     }
 
+    async fn get_bucket_inventory_configuration(
+        &self,
+        req: S3Request<GetBucketInventoryConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketInventoryConfigurationOutput>> {
+        let GetBucketInventoryConfigurationInput { bucket, id, .. } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        let configs = get_inventory_configs(&bucket).await.map_err(ApiError::from)?;
+        let Some(config) = configs.into_iter().find(|c| c.id == id) else {
+            let mut err = S3Error::with_message(
+                S3ErrorCode::Custom("NoSuchConfiguration".into()),
+                "The specified configuration does not exist".to_string(),
+            );
+            err.set_status_code(StatusCode::NOT_FOUND);
+            return Err(err);
+        };
+
+        Ok(s3_response(GetBucketInventoryConfigurationOutput {
+            inventory_configuration: Some(config),
+        }))
+    }
+
     #[instrument(level = "debug", skip(self))]
     async fn get_bucket_lifecycle_configuration(
         &self,
@@ -2091,6 +2262,32 @@ impl S3 for FS {
         Ok(s3_response(output))
     }
 
+    #[instrument(level = "debug", skip(self))]
+    async fn get_bucket_logging(&self, req: S3Request<GetBucketLoggingInput>) -> S3Result<S3Response<GetBucketLoggingOutput>> {
+        let GetBucketLoggingInput { bucket, .. } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        // A bucket without logging returns an empty BucketLoggingStatus
+        let logging_enabled = match metadata_sys::get_logging_config(&bucket).await {
+            Ok((config, _)) => config.logging_enabled,
+            Err(StorageError::ConfigNotFound) => None,
+            Err(err) => {
+                warn!("get_logging_config err {:?}", &err);
+                return Err(ApiError::from(err).into());
+            }
+        };
+
+        Ok(s3_response(GetBucketLoggingOutput { logging_enabled }))
+    }
+
     async fn get_bucket_notification_configuration(
         &self,
         req: S3Request<GetBucketNotificationConfigurationInput>,
@@ -2131,6 +2328,42 @@ impl S3 for FS {
         }
     }
 
+    async fn get_bucket_ownership_controls(
+        &self,
+        req: S3Request<GetBucketOwnershipControlsInput>,
+    ) -> S3Result<S3Response<GetBucketOwnershipControlsOutput>> {
+        let GetBucketOwnershipControlsInput { bucket, .. } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        let ownership_controls = match metadata_sys::get_ownership_controls_config(&bucket).await {
+            Ok((config, _)) => config,
+            Err(err) => {
+                if err == StorageError::ConfigNotFound {
+                    let mut err = S3Error::with_message(
+                        S3ErrorCode::Custom("OwnershipControlsNotFoundError".into()),
+                        "The bucket ownership controls were not found".to_string(),
+                    );
+                    err.set_status_code(StatusCode::NOT_FOUND);
+                    return Err(err);
+                }
+                warn!("get_ownership_controls_config err {:?}", &err);
+                return Err(ApiError::from(err).into());
+            }
+        };
+
+        Ok(s3_response(GetBucketOwnershipControlsOutput {
+            ownership_controls: Some(ownership_controls),
+        }))
+    }
+
     async fn get_bucket_policy(&self, req: S3Request<GetBucketPolicyInput>) -> S3Result<S3Response<GetBucketPolicyOutput>> {
         let GetBucketPolicyInput { bucket, .. } = req.input;
 
@@ -2200,6 +2433,7 @@ impl S3 for FS {
         })
         .await;
 
+        // Anonymous checks go through PolicySys, so RestrictPublicBuckets already reports the bucket as not public
         let is_public = read_only && write_only;
 
         let output = GetBucketPolicyStatusOutput {
@@ -2314,6 +2548,46 @@ impl S3 for FS {
         }))
     }
 
+    #[instrument(level = "debug", skip(self))]
+    async fn get_bucket_website(&self, req: S3Request<GetBucketWebsiteInput>) -> S3Result<S3Response<GetBucketWebsiteOutput>> {
+        let GetBucketWebsiteInput { bucket, .. } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        let WebsiteConfiguration {
+            error_document,
+            index_document,
+            redirect_all_requests_to,
+            routing_rules,
+        } = match metadata_sys::get_website_config(&bucket).await {
+            Ok((config, _)) => config,
+            Err(err) => {
+                if err == StorageError::ConfigNotFound {
+                    return Err(S3Error::with_message(
+                        S3ErrorCode::NoSuchWebsiteConfiguration,
+                        "The specified bucket does not have a website configuration".to_string(),
+                    ));
+                }
+                warn!("get_website_config err {:?}", &err);
+                return Err(ApiError::from(err).into());
+            }
+        };
+
+        Ok(s3_response(GetBucketWebsiteOutput {
+            error_document,
+            index_document,
+            redirect_all_requests_to,
+            routing_rules,
+        }))
+    }
+
     /// Get bucket notification
     #[instrument(
         level = "debug",
@@ -2838,6 +3112,13 @@ impl S3 for FS {
             None
         };
 
+        let (expiration, transition) = lifecycle_prediction_headers(&bucket, &info).await;
+        let restore = info
+            .user_defined
+            .get(X_AMZ_RESTORE.as_str())
+            .and_then(|amz_restore| parse_restore_obj_status(amz_restore).ok())
+            .map(|restore_status| restore_status.to_string2());
+
         let output = GetObjectOutput {
             body,
             content_length: Some(response_content_length),
@@ -2859,6 +3140,7 @@ impl S3 for FS {
             checksum_crc64nvme,
             checksum_type,
             version_id: output_version_id,
+            expiration,
             ..Default::default()
         };
 
@@ -2883,36 +3165,42 @@ impl S3 for FS {
             cache_key, response_content_length, total_duration, optimal_buffer_size
         );
 
-        let response = wrap_response_with_cors(&bucket, &req.method, &req.headers, output).await;
+        let mut response = wrap_response_with_cors(&bucket, &req.method, &req.headers, output).await;
+        if let Some(restore) = restore
+            && let Ok(header_value) = HeaderValue::from_str(&restore)
+        {
+            response.headers.insert(X_AMZ_RESTORE, header_value);
+        }
+        if let Some(transition) = transition
+            && let Ok(header_name) = http::HeaderName::from_bytes(RUSTFS_TRANSITION.as_bytes())
+            && let Ok(header_value) = HeaderValue::from_str(&transition)
+        {
+            response.headers.insert(header_name, header_value);
+        }
         let result = Ok(response);
         let _ = helper.complete(&result);
         result
     }
 
     async fn get_object_acl(&self, req: S3Request<GetObjectAclInput>) -> S3Result<S3Response<GetObjectAclOutput>> {
-        let GetObjectAclInput { bucket, key, .. } = req.input;
+        let GetObjectAclInput {
+            bucket, key, version_id, ..
+        } = req.input;
 
         let Some(store) = new_object_layer_fn() else {
             return Err(not_initialized_error());
         };
 
-        if let Err(e) = store.get_object_info(&bucket, &key, &ObjectOptions::default()).await {
-            return Err(S3Error::with_message(S3ErrorCode::InternalError, format!("{e}")));
-        }
+        let opts: ObjectOptions = get_opts(&bucket, &key, version_id, None, &req.headers)
+            .await
+            .map_err(ApiError::from)?;
 
-        let grants = vec![Grant {
-            grantee: Some(Grantee {
-                type_: Type::from_static(Type::CANONICAL_USER),
-                display_name: None,
-                email_address: None,
-                id: None,
-                uri: None,
-            }),
-            permission: Some(Permission::from_static(Permission::FULL_CONTROL)),
-        }];
+        let info = store.get_object_info(&bucket, &key, &opts).await.map_err(ApiError::from)?;
+
+        let acl = AccessControlList::from_object_metadata(&info.user_defined).unwrap_or_else(default_acl);
 
         Ok(s3_response(GetObjectAclOutput {
-            grants: Some(grants),
+            grants: Some(acl.to_grants()),
             owner: Some(RUSTFS_OWNER.to_owned()),
             ..Default::default()
         }))
@@ -3138,6 +3426,42 @@ impl S3 for FS {
         Err(S3Error::new(S3ErrorCode::NoSuchKey))
     }
 
+    async fn get_public_access_block(
+        &self,
+        req: S3Request<GetPublicAccessBlockInput>,
+    ) -> S3Result<S3Response<GetPublicAccessBlockOutput>> {
+        let GetPublicAccessBlockInput { bucket, .. } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        let config = match metadata_sys::get_public_access_block_config(&bucket).await {
+            Ok((config, _)) => config,
+            Err(err) => {
+                if err == StorageError::ConfigNotFound {
+                    let mut err = S3Error::with_message(
+                        S3ErrorCode::Custom("NoSuchPublicAccessBlockConfiguration".into()),
+                        "The public access block configuration was not found".to_string(),
+                    );
+                    err.set_status_code(StatusCode::NOT_FOUND);
+                    return Err(err);
+                }
+                warn!("get_public_access_block_config err {:?}", &err);
+                return Err(ApiError::from(err).into());
+            }
+        };
+
+        Ok(s3_response(GetPublicAccessBlockOutput {
+            public_access_block_configuration: Some(config),
+        }))
+    }
+
     #[instrument(level = "debug", skip(self, req))]
     async fn head_bucket(&self, req: S3Request<HeadBucketInput>) -> S3Result<S3Response<HeadBucketOutput>> {
         let input = req.input;
@@ -3352,6 +3676,7 @@ impl S3 for FS {
         } else {
             0
         };
+        let (expiration, transition) = lifecycle_prediction_headers(&bucket, &info).await;
         let output = HeadObjectOutput {
             content_length: Some(content_length),
             content_type,
@@ -3375,6 +3700,7 @@ impl S3 for FS {
             checksum_crc64nvme,
             checksum_type,
             storage_class,
+            expiration,
             // metadata: object_metadata,
             ..Default::default()
         };
@@ -3482,6 +3808,12 @@ impl S3 for FS {
         {
             response.headers.insert(header_name, header_value);
         }
+        if let Some(transition) = transition
+            && let Ok(header_name) = http::HeaderName::from_bytes(RUSTFS_TRANSITION.as_bytes())
+            && let Ok(header_value) = HeaderValue::from_str(&transition)
+        {
+            response.headers.insert(header_name, header_value);
+        }
 
         let result = Ok(response);
         let _ = helper.complete(&result);
@@ -3489,6 +3821,44 @@ impl S3 for FS {
         result
     }
 
+    async fn list_bucket_inventory_configurations(
+        &self,
+        req: S3Request<ListBucketInventoryConfigurationsInput>,
+    ) -> S3Result<S3Response<ListBucketInventoryConfigurationsOutput>> {
+        let ListBucketInventoryConfigurationsInput {
+            bucket,
+            continuation_token,
+            ..
+        } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        // Configurations are listed by Id, the continuation token being the last Id of the previous page
+        let mut configs = get_inventory_configs(&bucket).await.map_err(ApiError::from)?;
+        configs.sort_by(|a, b| a.id.cmp(&b.id));
+        if let Some(token) = &continuation_token {
+            configs.retain(|c| c.id.as_str() > token.as_str());
+        }
+
+        let is_truncated = configs.len() > MAX_INVENTORY_LIST_SIZE;
+        configs.truncate(MAX_INVENTORY_LIST_SIZE);
+        let next_continuation_token = configs.last().filter(|_| is_truncated).map(|c| c.id.clone());
+
+        Ok(s3_response(ListBucketInventoryConfigurationsOutput {
+            continuation_token,
+            inventory_configuration_list: Some(configs),
+            is_truncated: Some(is_truncated),
+            next_continuation_token,
+        }))
+    }
+
     #[instrument(level = "debug", skip(self))]
     async fn list_buckets(&self, req: S3Request<ListBucketsInput>) -> S3Result<S3Response<ListBucketsOutput>> {
         // mc ls
@@ -3724,6 +4094,11 @@ impl S3 for FS {
             bucket,
             acl,
             access_control_policy,
+            grant_full_control,
+            grant_read,
+            grant_read_acp,
+            grant_write,
+            grant_write_acp,
             ..
         } = req.input;
 
@@ -3738,27 +4113,30 @@ impl S3 for FS {
             .await
             .map_err(ApiError::from)?;
 
-        if let Some(canned_acl) = acl {
-            if canned_acl.as_str() != BucketCannedACL::PRIVATE {
-                return Err(s3_error!(NotImplemented));
-            }
-        } else {
-            let is_full_control = access_control_policy.is_some_and(|v| {
-                v.grants.is_some_and(|gs| {
-                    //
-                    !gs.is_empty()
-                        && gs.first().is_some_and(|g| {
-                            g.to_owned()
-                                .permission
-                                .is_some_and(|p| p.as_str() == Permission::FULL_CONTROL)
-                        })
-                })
-            });
+        let canned_acl = acl.as_ref().map(|a| a.as_str());
+        let Some(acl) = build_acl(
+            canned_acl,
+            access_control_policy.as_ref(),
+            &[
+                (AclPermission::FullControl, grant_full_control.as_deref()),
+                (AclPermission::Read, grant_read.as_deref()),
+                (AclPermission::ReadAcp, grant_read_acp.as_deref()),
+                (AclPermission::Write, grant_write.as_deref()),
+                (AclPermission::WriteAcp, grant_write_acp.as_deref()),
+            ],
+        )?
+        else {
+            return Err(missing_acl_error());
+        };
+
+        validate_acl_update(&bucket, canned_acl, Some(&acl.to_grants())).await?;
+
+        let data = serde_json::to_vec(&acl).map_err(|e| s3_error!(InternalError, "serialize acl failed {:?}", e))?;
+
+        metadata_sys::update(&bucket, BUCKET_ACL_CONFIG, data)
+            .await
+            .map_err(ApiError::from)?;
 
-            if !is_full_control {
-                return Err(s3_error!(NotImplemented));
-            }
-        }
         Ok(s3_response(PutBucketAclOutput::default()))
     }
 
@@ -3818,6 +4196,59 @@ impl S3 for FS {
         Ok(s3_response(PutBucketEncryptionOutput::default()))
     }
 
+    async fn put_bucket_inventory_configuration(
+        &self,
+        req: S3Request<PutBucketInventoryConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketInventoryConfigurationOutput>> {
+        let PutBucketInventoryConfigurationInput {
+            bucket,
+            id,
+            inventory_configuration,
+            ..
+        } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        if inventory_configuration.id != id {
+            return Err(S3Error::with_message(
+                S3ErrorCode::InvalidArgument,
+                "The Id in the request does not match the Id of the inventory configuration".to_string(),
+            ));
+        }
+        inventory_configuration
+            .validate()
+            .map_err(|e| S3Error::with_message(S3ErrorCode::InvalidArgument, e.to_string()))?;
+
+        // The destination has to exist when the configuration is created, not only when reports are written
+        store
+            .get_bucket_info(inventory_configuration.destination_bucket(), &BucketOptions::default())
+            .await
+            .map_err(|_| {
+                S3Error::with_message(
+                    S3ErrorCode::InvalidArgument,
+                    format!("The destination bucket {} does not exist", inventory_configuration.destination_bucket()),
+                )
+            })?;
+
+        let mut configs = get_inventory_configs(&bucket).await.map_err(ApiError::from)?;
+        configs.retain(|c| c.id != id);
+        configs.push(inventory_configuration);
+
+        let data = marshal_inventory_configs(&configs).map_err(ApiError::from)?;
+        metadata_sys::update(&bucket, BUCKET_INVENTORY_CONFIG, data)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(s3_response(PutBucketInventoryConfigurationOutput::default()))
+    }
+
     #[instrument(level = "debug", skip(self))]
     async fn put_bucket_lifecycle_configuration(
         &self,
@@ -3831,10 +4262,11 @@ impl S3 for FS {
 
         let Some(input_cfg) = lifecycle_configuration else { return Err(s3_error!(InvalidArgument)) };
 
-        let rcfg = metadata_sys::get_object_lock_config(&bucket).await;
-        if let Ok(rcfg) = rcfg
-            && let Err(err) = input_cfg.validate(&rcfg.0).await
-        {
+        let rcfg = metadata_sys::get_object_lock_config(&bucket)
+            .await
+            .map(|(cfg, _)| cfg)
+            .unwrap_or_default();
+        if let Err(err) = input_cfg.validate(&rcfg).await {
             //return Err(S3Error::with_message(S3ErrorCode::Custom("BucketLockValidateFailed".into()), err.to_string()));
             return Err(S3Error::with_message(S3ErrorCode::Custom("ValidateFailed".into()), err.to_string()));
         }
@@ -3852,6 +4284,53 @@ impl S3 for FS {
         Ok(s3_response(PutBucketLifecycleConfigurationOutput::default()))
     }
 
+    #[instrument(level = "debug", skip(self))]
+    async fn put_bucket_logging(&self, req: S3Request<PutBucketLoggingInput>) -> S3Result<S3Response<PutBucketLoggingOutput>> {
+        let PutBucketLoggingInput {
+            bucket,
+            bucket_logging_status,
+            ..
+        } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        // An empty BucketLoggingStatus turns logging off
+        let Some(logging_enabled) = &bucket_logging_status.logging_enabled else {
+            metadata_sys::delete(&bucket, BUCKET_LOGGING_CONFIG)
+                .await
+                .map_err(ApiError::from)?;
+            return Ok(s3_response(PutBucketLoggingOutput::default()));
+        };
+
+        if let Err(err) = store
+            .get_bucket_info(&logging_enabled.target_bucket, &BucketOptions::default())
+            .await
+        {
+            if is_err_bucket_not_found(&err) {
+                return Err(S3Error::with_message(
+                    S3ErrorCode::InvalidTargetBucketForLogging,
+                    "The target bucket for logging does not exist".to_string(),
+                ));
+            }
+            return Err(ApiError::from(err).into());
+        }
+
+        let data = try_!(serialize(&bucket_logging_status));
+
+        metadata_sys::update(&bucket, BUCKET_LOGGING_CONFIG, data)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(s3_response(PutBucketLoggingOutput::default()))
+    }
+
     async fn put_bucket_notification_configuration(
         &self,
         req: S3Request<PutBucketNotificationConfigurationInput>,
@@ -3924,6 +4403,38 @@ impl S3 for FS {
         Ok(s3_response(PutBucketNotificationConfigurationOutput {}))
     }
 
+    async fn put_bucket_ownership_controls(
+        &self,
+        req: S3Request<PutBucketOwnershipControlsInput>,
+    ) -> S3Result<S3Response<PutBucketOwnershipControlsOutput>> {
+        let PutBucketOwnershipControlsInput {
+            bucket,
+            ownership_controls,
+            ..
+        } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        ownership_controls
+            .validate()
+            .map_err(|e| S3Error::with_message(S3ErrorCode::MalformedXML, e.to_string()))?;
+
+        let data = try_!(serialize(&ownership_controls));
+
+        metadata_sys::update(&bucket, BUCKET_OWNERSHIP_CONTROLS_CONFIG, data)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(s3_response(PutBucketOwnershipControlsOutput::default()))
+    }
+
     async fn put_bucket_policy(&self, req: S3Request<PutBucketPolicyInput>) -> S3Result<S3Response<PutBucketPolicyOutput>> {
         let PutBucketPolicyInput { bucket, policy, .. } = req.input;
 
@@ -3946,6 +4457,13 @@ impl S3 for FS {
             return Err(s3_error!(MalformedPolicy));
         }
 
+        if cfg.is_public() && get_public_access_block(&bucket).await.blocks_public_policy() {
+            return Err(S3Error::with_message(
+                S3ErrorCode::AccessDenied,
+                "Public policies are blocked by the bucket's PublicAccessBlock configuration".to_string(),
+            ));
+        }
+
         // Preserve the original JSON text so GetBucketPolicy can return byte-for-byte content.
         // s3-tests expects exact string round-trip equality.
         let data = policy.into_bytes();
@@ -4036,6 +4554,36 @@ impl S3 for FS {
         Ok(s3_response(PutBucketVersioningOutput {}))
     }
 
+    #[instrument(level = "debug", skip(self))]
+    async fn put_bucket_website(&self, req: S3Request<PutBucketWebsiteInput>) -> S3Result<S3Response<PutBucketWebsiteOutput>> {
+        let PutBucketWebsiteInput {
+            bucket,
+            website_configuration,
+            ..
+        } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        website_configuration
+            .validate()
+            .map_err(|e| S3Error::with_message(S3ErrorCode::InvalidArgument, e.to_string()))?;
+
+        let data = try_!(serialize(&website_configuration));
+
+        metadata_sys::update(&bucket, BUCKET_WEBSITE_CONFIG, data)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(s3_response(PutBucketWebsiteOutput::default()))
+    }
+
     #[instrument(level = "debug", skip(self, req))]
     async fn put_object(&self, req: S3Request<PutObjectInput>) -> S3Result<S3Response<PutObjectOutput>> {
         crate::storage::objects::GLOBAL_OBJECTS.put_object(req).await
@@ -4047,6 +4595,12 @@ impl S3 for FS {
             key,
             acl,
             access_control_policy,
+            grant_full_control,
+            grant_read,
+            grant_read_acp,
+            grant_write,
+            grant_write_acp,
+            version_id,
             ..
         } = req.input;
 
@@ -4054,31 +4608,43 @@ impl S3 for FS {
             return Err(not_initialized_error());
         };
 
-        if let Err(e) = store.get_object_info(&bucket, &key, &ObjectOptions::default()).await {
-            return Err(S3Error::with_message(S3ErrorCode::InternalError, format!("{e}")));
-        }
+        let opts: ObjectOptions = get_opts(&bucket, &key, version_id, None, &req.headers)
+            .await
+            .map_err(ApiError::from)?;
 
-        if let Some(canned_acl) = acl {
-            if canned_acl.as_str() != BucketCannedACL::PRIVATE {
-                return Err(s3_error!(NotImplemented));
-            }
-        } else {
-            let is_full_control = access_control_policy.is_some_and(|v| {
-                v.grants.is_some_and(|gs| {
-                    //
-                    !gs.is_empty()
-                        && gs.first().is_some_and(|g| {
-                            g.to_owned()
-                                .permission
-                                .is_some_and(|p| p.as_str() == Permission::FULL_CONTROL)
-                        })
-                })
-            });
+        store.get_object_info(&bucket, &key, &opts).await.map_err(ApiError::from)?;
+
+        let canned_acl = acl.as_ref().map(|a| a.as_str());
+        let Some(acl) = build_acl(
+            canned_acl,
+            access_control_policy.as_ref(),
+            &[
+                (AclPermission::FullControl, grant_full_control.as_deref()),
+                (AclPermission::Read, grant_read.as_deref()),
+                (AclPermission::ReadAcp, grant_read_acp.as_deref()),
+                (AclPermission::Write, grant_write.as_deref()),
+                (AclPermission::WriteAcp, grant_write_acp.as_deref()),
+            ],
+        )?
+        else {
+            return Err(missing_acl_error());
+        };
+
+        validate_acl_update(&bucket, canned_acl, Some(&acl.to_grants())).await?;
+
+        let popts = ObjectOptions {
+            version_id: opts.version_id,
+            versioned: opts.versioned,
+            version_suspended: opts.version_suspended,
+            eval_metadata: Some(acl.to_object_metadata()),
+            ..Default::default()
+        };
+
+        store
+            .put_object_metadata(&bucket, &key, &popts)
+            .await
+            .map_err(ApiError::from)?;
 
-            if !is_full_control {
-                return Err(s3_error!(NotImplemented));
-            }
-        }
         Ok(s3_response(PutObjectAclOutput::default()))
     }
 
@@ -4374,6 +4940,34 @@ impl S3 for FS {
         result
     }
 
+    async fn put_public_access_block(
+        &self,
+        req: S3Request<PutPublicAccessBlockInput>,
+    ) -> S3Result<S3Response<PutPublicAccessBlockOutput>> {
+        let PutPublicAccessBlockInput {
+            bucket,
+            public_access_block_configuration,
+            ..
+        } = req.input;
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(not_initialized_error());
+        };
+
+        store
+            .get_bucket_info(&bucket, &BucketOptions::default())
+            .await
+            .map_err(ApiError::from)?;
+
+        let data = try_!(serialize(&public_access_block_configuration));
+
+        metadata_sys::update(&bucket, BUCKET_PUBLIC_ACCESS_BLOCK_CONFIG, data)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(s3_response(PutPublicAccessBlockOutput::default()))
+    }
+
     async fn restore_object(&self, req: S3Request<RestoreObjectInput>) -> S3Result<S3Response<RestoreObjectOutput>> {
         let RestoreObjectInput {
             bucket,
@@ -20,9 +20,14 @@ use crate::server::cors;
 use crate::storage::ecfs::ListObjectUnorderedQuery;
 use http::{HeaderMap, HeaderValue, StatusCode};
 use metrics::counter;
+use rustfs_ecstore::bucket::lifecycle::bucket_lifecycle_ops::LifecycleOps;
+use rustfs_ecstore::bucket::lifecycle::lifecycle::{Event, Lifecycle};
 use rustfs_ecstore::bucket::metadata_sys;
 use rustfs_ecstore::bucket::metadata_sys::get_replication_config;
 use rustfs_ecstore::bucket::object_lock::objectlock_sys;
+use rustfs_ecstore::bucket::public_access::{
+    PublicAccessBlockApi, bucket_acls_disabled, get_public_access_block, is_public_canned_acl, is_public_grant,
+};
 use rustfs_ecstore::bucket::replication::ReplicationConfigurationExt;
 use rustfs_ecstore::error::StorageError;
 use rustfs_ecstore::store_api::{BucketOptions, ObjectInfo, ObjectToDelete};
@@ -34,8 +39,8 @@ use rustfs_utils::http::{
     RESERVED_METADATA_PREFIX_LOWER,
 };
 use s3s::dto::{
-    Delimiter, LambdaFunctionConfiguration, NotificationConfigurationFilter, ObjectLockConfiguration, ObjectLockEnabled,
-    ObjectLockLegalHold, ObjectLockLegalHoldStatus, ObjectLockRetention, ObjectLockRetentionMode, QueueConfiguration,
+    Delimiter, Grant, LambdaFunctionConfiguration, NotificationConfigurationFilter, ObjectLockConfiguration, ObjectLockEnabled,
+    ObjectLockLegalHold, ObjectLockLegalHoldStatus, ObjectLockRetention, ObjectLockRetentionMode, Permission, QueueConfiguration,
     TopicConfiguration,
 };
 use s3s::{S3Error, S3ErrorCode, S3Response, S3Result};
@@ -43,8 +48,8 @@ use serde_urlencoded::from_bytes;
 use std::collections::HashMap;
 use std::ops::Add;
 use std::sync::Arc;
-use time::OffsetDateTime;
 use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
 use time::{format_description::FormatItem, macros::format_description};
 use tracing::{debug, warn};
 
@@ -344,6 +349,36 @@ pub(crate) async fn validate_bucket_object_lock_enabled(bucket: &str) -> S3Resul
     Ok(())
 }
 
+/// Checks an ACL change against the bucket's Object Ownership and Public Access Block settings.
+/// `BucketOwnerEnforced` only accepts ACLs that leave the owner in full control, and
+/// `BlockPublicAcls` rejects canned ACLs and grants that open access to everyone.
+pub(crate) async fn validate_acl_update(bucket: &str, canned_acl: Option<&str>, grants: Option<&[Grant]>) -> S3Result<()> {
+    let owner_only = canned_acl.is_none_or(|acl| acl == "private" || acl == "bucket-owner-full-control")
+        && grants.is_none_or(|gs| {
+            gs.iter()
+                .all(|g| !is_public_grant(g) && g.permission.as_ref().is_some_and(|p| p.as_str() == Permission::FULL_CONTROL))
+        });
+    if !owner_only && bucket_acls_disabled(bucket).await {
+        let mut err = S3Error::with_message(
+            S3ErrorCode::Custom("AccessControlListNotSupported".into()),
+            "The bucket does not allow ACLs".to_string(),
+        );
+        err.set_status_code(StatusCode::BAD_REQUEST);
+        return Err(err);
+    }
+
+    if get_public_access_block(bucket).await.blocks_public_acls()
+        && (canned_acl.is_some_and(is_public_canned_acl) || grants.is_some_and(|gs| gs.iter().any(is_public_grant)))
+    {
+        return Err(S3Error::with_message(
+            S3ErrorCode::AccessDenied,
+            "Public ACLs are blocked by the bucket's PublicAccessBlock configuration".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 /// Validates HTTP conditional request headers for a single object according to
 /// RFC 7232 (HTTP/1.1 conditional requests) and S3 API semantics.
 ///
@@ -853,3 +888,29 @@ pub(crate) fn parse_part_number_i32_to_usize(part_number: Option<i32>, op: &'sta
         Some(n) => Ok(Some(n as usize)),
     }
 }
+
+/// Formats a lifecycle prediction as `<kind>-date="<RFC 1123 date>", rule-id="<id>"`,
+/// the form used by `x-amz-expiration` and the transition prediction header.
+pub(crate) fn format_lifecycle_prediction(kind: &str, event: &Event) -> Option<String> {
+    let due = event.due?.to_offset(UtcOffset::UTC).format(RFC1123).ok()?;
+    Some(format!("{kind}-date=\"{due}\", rule-id=\"{}\"", event.rule_id))
+}
+
+/// Predicts the expiration and transition headers for an object from its bucket's lifecycle rules.
+///
+/// Returns `(x-amz-expiration, transition)` values; either is `None` when no rule applies.
+pub(crate) async fn lifecycle_prediction_headers(bucket: &str, info: &ObjectInfo) -> (Option<String>, Option<String>) {
+    let Ok((lifecycle, _)) = metadata_sys::get_lifecycle_config(bucket).await else {
+        return (None, None);
+    };
+    let opts = info.to_lifecycle_opts();
+    let expiration = lifecycle
+        .predict_expiration(&opts)
+        .await
+        .and_then(|event| format_lifecycle_prediction("expiry", &event));
+    let transition = lifecycle
+        .predict_transition(&opts)
+        .await
+        .and_then(|event| format_lifecycle_prediction("transition", &event));
+    (expiration, transition)
+}
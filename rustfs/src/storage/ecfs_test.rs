@@ -18,13 +18,14 @@ mod tests {
     use crate::storage::ecfs::FS;
     use crate::storage::ecfs::RUSTFS_OWNER;
     use crate::storage::{
-        apply_cors_headers, check_preconditions, get_adaptive_buffer_size_with_profile, get_buffer_size_opt_in, is_etag_equal,
-        matches_origin_pattern, parse_etag, parse_object_lock_legal_hold, parse_object_lock_retention,
-        process_lambda_configurations, process_queue_configurations, process_topic_configurations,
+        apply_cors_headers, check_preconditions, format_lifecycle_prediction, get_adaptive_buffer_size_with_profile,
+        get_buffer_size_opt_in, is_etag_equal, matches_origin_pattern, parse_etag, parse_object_lock_legal_hold,
+        parse_object_lock_retention, process_lambda_configurations, process_queue_configurations, process_topic_configurations,
         validate_bucket_object_lock_enabled, validate_list_object_unordered_with_delimiter,
     };
     use http::{HeaderMap, HeaderValue, StatusCode};
     use rustfs_config::MI_B;
+    use rustfs_ecstore::bucket::lifecycle::lifecycle::{Event, IlmAction};
     use rustfs_ecstore::set_disk::DEFAULT_READ_BUFFER_SIZE;
     use rustfs_ecstore::store_api::ObjectInfo;
     use rustfs_policy::policy::{BucketPolicy, Validator};
@@ -556,6 +557,23 @@ mod tests {
         assert!(!is_etag_equal("\"12345\"", "\"67890\", \"abcde\""));
     }
 
+    #[test]
+    fn test_format_lifecycle_prediction() {
+        let event = Event {
+            action: IlmAction::DeleteAction,
+            rule_id: "expire-logs".to_string(),
+            due: Some(OffsetDateTime::from_unix_timestamp(1700006400).unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            format_lifecycle_prediction("expiry", &event).as_deref(),
+            Some(r#"expiry-date="Wed, 15 Nov 2023 00:00:00 GMT", rule-id="expire-logs""#)
+        );
+
+        let event = Event { due: None, ..event };
+        assert!(format_lifecycle_prediction("transition", &event).is_none());
+    }
+
     #[test]
     fn test_check_preconditions() {
         use time::{format_description::FormatItem, macros::format_description};
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::storage::access_log::AccessLogContext;
 use http::StatusCode;
 use rustfs_audit::{
     entity::{ApiDetails, ApiDetailsBuilder, AuditEntryBuilder},
@@ -49,6 +50,7 @@ pub struct OperationHelper {
     audit_builder: Option<AuditEntryBuilder>,
     api_builder: ApiDetailsBuilder,
     event_builder: Option<EventArgsBuilder>,
+    access_log: Option<AccessLogContext>,
     start_time: std::time::Instant,
 }
 
@@ -104,12 +106,16 @@ impl OperationHelper {
             audit_builder: Some(audit_builder),
             api_builder,
             event_builder: Some(event_builder),
+            access_log: req.extensions.get::<AccessLogContext>().cloned(),
             start_time: std::time::Instant::now(),
         }
     }
 
     /// Sets the ObjectInfo for event notification.
     pub fn object(mut self, object_info: ObjectInfo) -> Self {
+        if let Some(access_log) = &self.access_log {
+            access_log.set_object_size(object_info.size);
+        }
         if let Some(builder) = self.event_builder.take() {
             self.event_builder = Some(builder.object(object_info));
         }
@@ -118,6 +124,10 @@ impl OperationHelper {
 
     /// Set the version ID for event notifications.
     pub fn version_id(mut self, version_id: impl Into<String>) -> Self {
+        let version_id = version_id.into();
+        if let Some(access_log) = &self.access_log {
+            access_log.set_version_id(&version_id);
+        }
         if let Some(builder) = self.event_builder.take() {
             self.event_builder = Some(builder.version_id(version_id));
         }
@@ -152,6 +162,10 @@ impl OperationHelper {
                 ),
             };
 
+            if let (Err(e), Some(access_log)) = (result, &self.access_log) {
+                access_log.set_error_code(e.code());
+            }
+
             let ttr = self.start_time.elapsed();
             let api_details = self
                 .api_builder
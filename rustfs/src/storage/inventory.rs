@@ -0,0 +1,85 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::storage::apply_lock_retention;
+use crate::storage::options::put_opts;
+use crate::storage::sse::{EncryptionRequest, sse_encryption};
+use http::HeaderMap;
+use rustfs_ecstore::bucket::metadata_sys;
+use rustfs_ecstore::error::StorageError;
+use rustfs_ecstore::new_object_layer_fn;
+use rustfs_ecstore::store_api::{ObjectIO, PutObjReader};
+use rustfs_rio::{HashReader, Reader, WarpReader};
+use rustfs_scanner::ScannerError;
+use rustfs_scanner::scanner_inventory::{InventoryPutOptions, InventoryWriter};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+/// Stores inventory report files like a PutObject request, so the destination bucket's default
+/// encryption and Object Lock retention apply to them
+pub(crate) struct ObjectInventoryWriter;
+
+#[async_trait::async_trait]
+impl InventoryWriter for ObjectInventoryWriter {
+    async fn put_object(&self, bucket: &str, key: &str, data: Vec<u8>, opts: InventoryPutOptions) -> Result<(), ScannerError> {
+        let to_err = |e: &dyn std::fmt::Display| ScannerError::Other(e.to_string());
+
+        let Some(store) = new_object_layer_fn() else {
+            return Err(ScannerError::Other("errServerNotInitialized".to_string()));
+        };
+
+        let object_lock_configuration = match metadata_sys::get_object_lock_config(bucket).await {
+            Ok((cfg, _created)) => Some(cfg),
+            Err(StorageError::ConfigNotFound) => None,
+            Err(err) => return Err(to_err(&err)),
+        };
+
+        let mut metadata = HashMap::from([("content-type".to_string(), opts.content_type.to_string())]);
+        apply_lock_retention(object_lock_configuration, &mut metadata);
+
+        let size = data.len() as i64;
+        let reader: Box<dyn Reader> = Box::new(WarpReader::new(Cursor::new(data)));
+        let mut reader = HashReader::new(reader, size, size, None, None, false).map_err(|e| to_err(&e))?;
+
+        let encryption_request = EncryptionRequest {
+            bucket,
+            key,
+            server_side_encryption: opts.server_side_encryption,
+            ssekms_key_id: opts.ssekms_key_id,
+            sse_customer_algorithm: None,
+            sse_customer_key: None,
+            sse_customer_key_md5: None,
+            content_size: size,
+            part_number: None,
+            part_key: None,
+            part_nonce: None,
+        };
+
+        if let Some(material) = sse_encryption(encryption_request).await.map_err(|e| to_err(&e))? {
+            let encrypted_reader = material.wrap_reader(reader);
+            reader = HashReader::new(encrypted_reader, HashReader::SIZE_PRESERVE_LAYER, size, None, None, false)
+                .map_err(|e| to_err(&e))?;
+            metadata.extend(material.metadata);
+        }
+
+        let opts = put_opts(bucket, key, None, &HeaderMap::new(), metadata)
+            .await
+            .map_err(|e| to_err(&e))?;
+        store
+            .put_object(bucket, key, &mut PutObjReader::new(reader), &opts)
+            .await
+            .map_err(|e| to_err(&e))?;
+        Ok(())
+    }
+}
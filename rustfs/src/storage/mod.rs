@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod access;
+pub(crate) mod access_log;
 pub mod concurrency;
 #[cfg(test)]
 mod concurrent_get_object_test;
@@ -20,6 +21,7 @@ pub mod ecfs;
 mod ecfs_extend;
 pub(crate) mod entity;
 pub(crate) mod helper;
+pub(crate) mod inventory;
 pub mod options;
 pub(crate) mod readers;
 pub(crate) mod s3_api;
@@ -29,6 +31,6 @@ pub(crate) use ecfs_extend::*;
 mod ecfs_test;
 pub(crate) mod head_prefix;
 mod objects;
-mod sse;
+pub(crate) mod sse;
 #[cfg(test)]
 mod sse_test;
@@ -17,6 +17,7 @@ use crate::storage::concurrency::get_concurrency_manager;
 use crate::storage::helper::OperationHelper;
 use crate::storage::objects::Objects;
 use crate::storage::options::{extract_metadata_from_mime_with_object_name, get_content_sha256, put_opts};
+use crate::storage::s3_api::acl::acl_from_headers;
 use crate::storage::sse::{EncryptionRequest, sse_encryption};
 use crate::storage::{apply_lock_retention, get_buffer_size_opt_in, get_validated_store, validate_object_key};
 use futures_util::StreamExt;
@@ -227,6 +228,10 @@ impl Objects {
             metadata.insert(AMZ_OBJECT_TAGGING.to_owned(), tags.to_string());
         }
 
+        if let Some(acl) = acl_from_headers(&bucket, &req.headers).await? {
+            metadata.extend(acl.to_object_metadata());
+        }
+
         let mut opts: ObjectOptions = put_opts(&bucket, &key, version_id.clone(), &req.headers, metadata.clone())
             .await
             .map_err(ApiError::from)?;
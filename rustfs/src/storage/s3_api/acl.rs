@@ -0,0 +1,121 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::storage::s3_api::common::RUSTFS_OWNER_ID;
+use crate::storage::validate_acl_update;
+use http::{HeaderMap, StatusCode};
+use rustfs_ecstore::bucket::acl::{AccessControlList, AclPermission};
+use rustfs_utils::http::AMZ_ACL;
+use s3s::dto::AccessControlPolicy;
+use s3s::{S3Error, S3ErrorCode, S3Result};
+
+const GRANT_HEADERS: [(AclPermission, &str); 5] = [
+    (AclPermission::FullControl, "x-amz-grant-full-control"),
+    (AclPermission::Read, "x-amz-grant-read"),
+    (AclPermission::ReadAcp, "x-amz-grant-read-acp"),
+    (AclPermission::Write, "x-amz-grant-write"),
+    (AclPermission::WriteAcp, "x-amz-grant-write-acp"),
+];
+
+/// The ACL a bucket or object has when none was set: FULL_CONTROL for the owner only
+pub(crate) fn default_acl() -> AccessControlList {
+    AccessControlList::from_canned("private", RUSTFS_OWNER_ID).unwrap_or_default()
+}
+
+/// PutBucketAcl and PutObjectAcl need a canned ACL, an `AccessControlPolicy` body or grant headers
+pub(crate) fn missing_acl_error() -> S3Error {
+    let mut err = S3Error::with_message(
+        S3ErrorCode::Custom("MissingSecurityHeader".into()),
+        "Your request was missing a required header".to_string(),
+    );
+    err.set_status_code(StatusCode::BAD_REQUEST);
+    err
+}
+
+/// Whether an object write sets its ACL through the `x-amz-acl` or `x-amz-grant-*` headers
+pub(crate) fn has_acl_headers(headers: &HeaderMap) -> bool {
+    headers.contains_key(AMZ_ACL) || GRANT_HEADERS.iter().any(|(_, name)| headers.contains_key(*name))
+}
+
+/// Builds the ACL of a request from its canned ACL, its `AccessControlPolicy` body or its grant headers,
+/// only one of which may be set. Returns `None` when the request carries no ACL.
+pub(crate) fn build_acl(
+    canned_acl: Option<&str>,
+    access_control_policy: Option<&AccessControlPolicy>,
+    grant_headers: &[(AclPermission, Option<&str>)],
+) -> S3Result<Option<AccessControlList>> {
+    let invalid = |e: std::io::Error| S3Error::with_message(S3ErrorCode::InvalidArgument, e.to_string());
+
+    let header_grants = AccessControlList::from_grant_headers(grant_headers).map_err(invalid)?;
+    let policy_grants = access_control_policy.and_then(|p| p.grants.as_deref());
+
+    match (canned_acl, policy_grants, header_grants) {
+        (None, None, None) => Ok(None),
+        (Some(acl), None, None) => AccessControlList::from_canned(acl, RUSTFS_OWNER_ID)
+            .map(Some)
+            .map_err(invalid),
+        (None, Some(grants), None) => AccessControlList::from_grants(grants).map(Some).map_err(invalid),
+        (None, None, Some(acl)) => Ok(Some(acl)),
+        _ => Err(S3Error::with_message(
+            S3ErrorCode::InvalidRequest,
+            "Specifying both Canned ACLs and Header Grants is not allowed".to_string(),
+        )),
+    }
+}
+
+/// The ACL set by the `x-amz-acl` and `x-amz-grant-*` headers of an object write, checked against the
+/// bucket's Object Ownership and Public Access Block settings
+pub(crate) async fn acl_from_headers(bucket: &str, headers: &HeaderMap) -> S3Result<Option<AccessControlList>> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    let grant_headers = GRANT_HEADERS.map(|(permission, name)| (permission, header(name)));
+
+    let canned_acl = header(AMZ_ACL);
+    let Some(acl) = build_acl(canned_acl, None, &grant_headers)? else {
+        return Ok(None);
+    };
+
+    validate_acl_update(bucket, canned_acl, Some(&acl.to_grants())).await?;
+
+    Ok(Some(acl))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustfs_ecstore::bucket::public_access::ALL_USERS_GROUP;
+
+    #[test]
+    fn test_build_acl() {
+        assert!(build_acl(None, None, &[]).unwrap().is_none());
+
+        let acl = build_acl(Some("public-read"), None, &[]).unwrap().unwrap();
+        assert!(acl.is_public());
+        assert!(acl.is_allowed(None, AclPermission::Read, false));
+
+        let grant = format!(r#"uri="{ALL_USERS_GROUP}""#);
+        let acl = build_acl(None, None, &[(AclPermission::Read, Some(&grant))])
+            .unwrap()
+            .unwrap();
+        assert_eq!(acl.grants.len(), 1);
+
+        let policy = AccessControlPolicy {
+            grants: Some(default_acl().to_grants()),
+            owner: None,
+        };
+        assert_eq!(build_acl(None, Some(&policy), &[]).unwrap(), Some(default_acl()));
+
+        assert!(build_acl(Some("private"), None, &[(AclPermission::Read, Some(&grant))]).is_err());
+        assert!(build_acl(Some("everyone"), None, &[]).is_err());
+    }
+}
@@ -82,7 +82,7 @@ use rand::Rng;
 use rustfs_ecstore::error::StorageError;
 use rustfs_filemeta::ObjectPartInfo;
 use rustfs_kms::{
-    DataKey,
+    DataKey, ObjectEncryptionService,
     service_manager::get_global_encryption_service,
     types::{EncryptionMetadata, ObjectEncryptionContext},
 };
@@ -883,7 +883,7 @@ async fn apply_managed_encryption_material(
     let encryption_metadata = EncryptionMetadata {
         algorithm: algorithm.clone(),
         key_id: kms_key_to_use.clone(),
-        key_version: 1,
+        key_version: ObjectEncryptionService::data_key_version(&encrypted_data_key),
         iv: data_key.nonce.to_vec(),
         tag: None,
         encryption_context: context.encryption_context.clone(),
@@ -1359,12 +1359,13 @@ pub fn is_managed_sse(server_side_encryption: &ServerSideEncryption) -> bool {
 /// Removes all managed SSE-related headers before returning object metadata to client.
 /// This is necessary because encryption is transparent to S3 clients.
 pub fn strip_managed_encryption_metadata(metadata: &mut HashMap<String, String>) {
-    const KEYS: [&str; 7] = [
+    const KEYS: [&str; 8] = [
         "x-amz-server-side-encryption",
         "x-amz-server-side-encryption-aws-kms-key-id",
         "x-rustfs-encryption-iv",
         "x-rustfs-encryption-tag",
         "x-rustfs-encryption-key",
+        "x-rustfs-encryption-key-version",
         "x-rustfs-encryption-context",
         "x-rustfs-encryption-original-size",
     ];
@@ -12,22 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::heal_status::local_background_heal_state;
 use bytes::Bytes;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use futures_util::future::join_all;
 use rmp_serde::{Deserializer, Serializer};
-use rustfs_common::{GLOBAL_LOCAL_NODE_NAME, heal_channel::HealOpts};
+use rustfs_common::{
+    GLOBAL_LOCAL_NODE_NAME,
+    heal_channel::HealOpts,
+    service_signal::{ServiceSignal, freeze_services, send_service_signal, unfreeze_services},
+    trace_channel::subscribe_trace,
+};
 use rustfs_ecstore::{
     admin_server_info::get_local_server_property,
-    bucket::{metadata::load_bucket_metadata, metadata_sys},
+    bucket::{metadata::load_bucket_metadata, metadata_sys, replication::GLOBAL_REPLICATION_STATS},
     disk::{
-        DeleteOptions, DiskAPI, DiskInfoOptions, DiskStore, FileInfoVersions, ReadMultipleReq, ReadOptions, UpdateMetadataOpts,
-        error::DiskError,
+        DeleteOptions, DiskAPI, DiskInfoOptions, DiskStore, FileInfoVersions, FileWriter, ReadMultipleReq, ReadOptions,
+        UpdateMetadataOpts, error::DiskError,
     },
     get_global_lock_client,
+    global::GLOBAL_TierConfigMgr,
     metrics_realtime::{CollectMetricsOpts, MetricType, collect_local_metrics},
     new_object_layer_fn,
-    rpc::{LocalPeerS3Client, PeerS3Client},
+    rpc::{LocalPeerS3Client, PEER_RESTDRY_RUN, PEER_RESTSIGNAL, PEER_RESTSUB_SYS, PeerS3Client},
     store::{all_local_disk_path, find_local_disk},
     store_api::{BucketOptions, DeleteBucketOptions, MakeBucketOptions, StorageAPI},
 };
@@ -38,12 +45,14 @@ use rustfs_madmin::health::{
     get_cpus, get_mem_info, get_os_info, get_partitions, get_proc_info, get_sys_config, get_sys_errors, get_sys_services,
 };
 use rustfs_madmin::net::get_net_info;
+use rustfs_madmin::service_commands::ServiceTraceOpts;
 use rustfs_protos::{
     models::{PingBody, PingBodyBuilder},
     proto_gen::node_service::{node_service_server::NodeService as Node, *},
 };
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, io::Cursor, pin::Pin, sync::Arc};
+use std::{collections::HashMap, io::Cursor, pin::Pin, sync::Arc, time::Duration};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::spawn;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
@@ -52,6 +61,28 @@ use tracing::{debug, error, info, warn};
 
 type ResponseStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
 
+const METACACHE_NOT_SUPPORTED: &str = "metacache listings are not supported";
+
+// Largest range a single read_at call returns, the default gRPC message size limit of the caller
+const MAX_READ_AT_LENGTH: usize = 4 << 20;
+
+/// Read `length` bytes at `offset` from a file on one of this node's disks.
+async fn read_local_range(disk: &DiskStore, req: &ReadAtRequest) -> rustfs_ecstore::disk::error::Result<Vec<u8>> {
+    let (Ok(offset), Ok(length)) = (usize::try_from(req.offset), usize::try_from(req.length)) else {
+        return Err(DiskError::other(format!("invalid range offset={} length={}", req.offset, req.length)));
+    };
+    if length > MAX_READ_AT_LENGTH {
+        return Err(DiskError::other(format!(
+            "read length {length} exceeds the maximum of {MAX_READ_AT_LENGTH} bytes"
+        )));
+    }
+
+    let mut reader = disk.read_file_stream(&req.volume, &req.path, offset, length).await?;
+    let mut data = Vec::with_capacity(length);
+    reader.take(length as u64).read_to_end(&mut data).await?;
+    Ok(data)
+}
+
 // fn match_for_io_error(err_status: &Status) -> Option<&std::io::Error> {
 //     let mut err: &(dyn Error + 'static) = err_status;
 
@@ -540,245 +571,166 @@ impl Node for NodeService {
         }
     }
 
-    async fn write(&self, _request: Request<WriteRequest>) -> Result<Response<WriteResponse>, Status> {
-        unimplemented!("write");
-        // let request = request.into_inner();
-        // if let Some(disk) = self.find_disk(&request.disk).await {
-        //     let file_writer = if request.is_append {
-        //         disk.append_file(&request.volume, &request.path).await
-        //     } else {
-        //         disk.create_file("", &request.volume, &request.path, 0).await
-        //     };
-
-        //     match file_writer {
-        //         Ok(mut file_writer) => match file_writer.write(&request.data).await {
-        //             Ok(_) => Ok(Response::new(WriteResponse {
-        //                 success: true,
-        //                 error: None,
-        //             })),
-        //             Err(err) => Ok(Response::new(WriteResponse {
-        //                 success: false,
-        //                 error: Some(err_to_proto_err(&err, &format!("write failed: {}", err))),
-        //             })),
-        //         },
-        //         Err(err) => Ok(Response::new(WriteResponse {
-        //             success: false,
-        //             error: Some(err_to_proto_err(&err, &format!("get writer failed: {}", err))),
-        //         })),
-        //     }
-        // } else {
-        //     Ok(Response::new(WriteResponse {
-        //         success: false,
-        //         error: Some(err_to_proto_err(
-        //             &EcsError::new(StorageError::InvalidArgument(Default::default(), Default::default(), Default::default())),
-        //             "can not find disk",
-        //         )),
-        //     }))
-        // }
+    async fn write(&self, request: Request<WriteRequest>) -> Result<Response<WriteResponse>, Status> {
+        let request = request.into_inner();
+        if let Some(disk) = self.find_disk(&request.disk).await {
+            let file_writer = if request.is_append {
+                disk.append_file(&request.volume, &request.path).await
+            } else {
+                disk.create_file("", &request.volume, &request.path, request.data.len() as i64)
+                    .await
+            };
+
+            let result = match file_writer {
+                Ok(mut file_writer) => match file_writer.write_all(&request.data).await {
+                    Ok(_) => file_writer.shutdown().await.map_err(DiskError::from),
+                    Err(err) => Err(DiskError::from(err)),
+                },
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(_) => Ok(Response::new(WriteResponse {
+                    success: true,
+                    error: None,
+                })),
+                Err(err) => Ok(Response::new(WriteResponse {
+                    success: false,
+                    error: Some(err.into()),
+                })),
+            }
+        } else {
+            Ok(Response::new(WriteResponse {
+                success: false,
+                error: Some(DiskError::other("can not find disk".to_string()).into()),
+            }))
+        }
     }
 
     type WriteStreamStream = ResponseStream<WriteResponse>;
-    async fn write_stream(
-        &self,
-        _request: Request<Streaming<WriteRequest>>,
-    ) -> Result<Response<Self::WriteStreamStream>, Status> {
-        info!("write_stream");
-
-        unimplemented!("write_stream");
-
-        // let mut in_stream = request.into_inner();
-        // let (tx, rx) = mpsc::channel(128);
-
-        // tokio::spawn(async move {
-        //     let mut file_ref = None;
-        //     while let Some(result) = in_stream.next().await {
-        //         match result {
-        //             // Ok(v) => tx
-        //             //     .send(Ok(EchoResponse { message: v.message }))
-        //             //     .await
-        //             //     .expect("working rx"),
-        //             Ok(v) => {
-        //                 match file_ref.as_ref() {
-        //                     Some(_) => (),
-        //                     None => {
-        //                         if let Some(disk) = find_local_disk(&v.disk).await {
-        //                             let file_writer = if v.is_append {
-        //                                 disk.append_file(&v.volume, &v.path).await
-        //                             } else {
-        //                                 disk.create_file("", &v.volume, &v.path, 0).await
-        //                             };
-
-        //                             match file_writer {
-        //                                 Ok(file_writer) => file_ref = Some(file_writer),
-        //                                 Err(err) => {
-        //                                     tx.send(Ok(WriteResponse {
-        //                                         success: false,
-        //                                         error: Some(err_to_proto_err(
-        //                                             &err,
-        //                                             &format!("get file writer failed: {}", err),
-        //                                         )),
-        //                                     }))
-        //                                     .await
-        //                                     .expect("working rx");
-        //                                     break;
-        //                                 }
-        //                             }
-        //                         } else {
-        //                             tx.send(Ok(WriteResponse {
-        //                                 success: false,
-        //                                 error: Some(err_to_proto_err(
-        //                                     &EcsError::new(StorageError::InvalidArgument(
-        //                                         Default::default(),
-        //                                         Default::default(),
-        //                                         Default::default(),
-        //                                     )),
-        //                                     "can not find disk",
-        //                                 )),
-        //                             }))
-        //                             .await
-        //                             .expect("working rx");
-        //                             break;
-        //                         }
-        //                     }
-        //                 };
-
-        //                 match file_ref.as_mut().unwrap().write(&v.data).await {
-        //                     Ok(_) => tx.send(Ok(WriteResponse {
-        //                         success: true,
-        //                         error: None,
-        //                     })),
-        //                     Err(err) => tx.send(Ok(WriteResponse {
-        //                         success: false,
-        //                         error: Some(err_to_proto_err(&err, &format!("write failed: {}", err))),
-        //                     })),
-        //                 }
-        //                 .await
-        //                 .unwrap();
-        //             }
-        //             Err(err) => {
-        //                 if let Some(io_err) = match_for_io_error(&err) {
-        //                     if io_err.kind() == ErrorKind::BrokenPipe {
-        //                         // here you can handle special case when client
-        //                         // disconnected in unexpected way
-        //                         eprintln!("\tclient disconnected: broken pipe");
-        //                         break;
-        //                     }
-        //                 }
-
-        //                 match tx.send(Err(err)).await {
-        //                     Ok(_) => (),
-        //                     Err(_err) => break, // response was dropped
-        //                 }
-        //             }
-        //         }
-        //     }
-        //     println!("\tstream ended");
-        // });
-
-        // let out_stream = ReceiverStream::new(rx);
-
-        // Ok(Response::new(Box::pin(out_stream)))
+    async fn write_stream(&self, request: Request<Streaming<WriteRequest>>) -> Result<Response<Self::WriteStreamStream>, Status> {
+        let mut in_stream = request.into_inner();
+        let (tx, rx) = mpsc::channel(128);
+
+        // The first message names the file, later ones only carry data.
+        let first = in_stream.next().await.transpose()?;
+        let opened = match &first {
+            Some(req) => match self.find_disk(&req.disk).await {
+                Some(disk) => {
+                    if req.is_append {
+                        disk.append_file(&req.volume, &req.path).await.map(Some)
+                    } else {
+                        disk.create_file("", &req.volume, &req.path, 0).await.map(Some)
+                    }
+                }
+                None => Err(DiskError::other("can not find disk".to_string())),
+            },
+            None => Ok(None),
+        };
+
+        spawn(async move {
+            let mut file_writer: FileWriter = match opened {
+                Ok(Some(writer)) => writer,
+                Ok(None) => return,
+                Err(err) => {
+                    let _ = tx
+                        .send(Ok(WriteResponse {
+                            success: false,
+                            error: Some(err.into()),
+                        }))
+                        .await;
+                    return;
+                }
+            };
+
+            let mut next = first;
+            while let Some(req) = next {
+                let resp = match file_writer.write_all(&req.data).await {
+                    Ok(_) => WriteResponse {
+                        success: true,
+                        error: None,
+                    },
+                    Err(err) => WriteResponse {
+                        success: false,
+                        error: Some(DiskError::from(err).into()),
+                    },
+                };
+                let failed = !resp.success;
+                if tx.send(Ok(resp)).await.is_err() || failed {
+                    break;
+                }
+
+                next = match in_stream.next().await {
+                    Some(Ok(req)) => Some(req),
+                    Some(Err(err)) => {
+                        // Client went away or sent garbage; nothing more to write.
+                        let _ = tx.send(Err(err)).await;
+                        None
+                    }
+                    None => None,
+                };
+            }
+
+            if let Err(err) = file_writer.shutdown().await {
+                warn!("write_stream: close file failed: {}", err);
+            }
+        });
+
+        let out_stream = ReceiverStream::new(rx);
+        Ok(Response::new(Box::pin(out_stream)))
     }
 
     type ReadAtStream = ResponseStream<ReadAtResponse>;
-    async fn read_at(&self, _request: Request<Streaming<ReadAtRequest>>) -> Result<Response<Self::ReadAtStream>, Status> {
-        info!("read_at");
-        unimplemented!("read_at");
-
-        // let mut in_stream = request.into_inner();
-        // let (tx, rx) = mpsc::channel(128);
-
-        // tokio::spawn(async move {
-        //     let mut file_ref = None;
-        //     while let Some(result) = in_stream.next().await {
-        //         match result {
-        //             Ok(v) => {
-        //                 match file_ref.as_ref() {
-        //                     Some(_) => (),
-        //                     None => {
-        //                         if let Some(disk) = find_local_disk(&v.disk).await {
-        //                             match disk.read_file(&v.volume, &v.path).await {
-        //                                 Ok(file_reader) => file_ref = Some(file_reader),
-        //                                 Err(err) => {
-        //                                     tx.send(Ok(ReadAtResponse {
-        //                                         success: false,
-        //                                         data: Vec::new(),
-        //                                         error: Some(err_to_proto_err(&err, &format!("read file failed: {}", err))),
-        //                                         read_size: -1,
-        //                                     }))
-        //                                     .await
-        //                                     .expect("working rx");
-        //                                     break;
-        //                                 }
-        //                             }
-        //                         } else {
-        //                             tx.send(Ok(ReadAtResponse {
-        //                                 success: false,
-        //                                 data: Vec::new(),
-        //                                 error: Some(err_to_proto_err(
-        //                                     &EcsError::new(StorageError::InvalidArgument(
-        //                                         Default::default(),
-        //                                         Default::default(),
-        //                                         Default::default(),
-        //                                     )),
-        //                                     "can not find disk",
-        //                                 )),
-        //                                 read_size: -1,
-        //                             }))
-        //                             .await
-        //                             .expect("working rx");
-        //                             break;
-        //                         }
-        //                     }
-        //                 };
-
-        //                 let mut data = vec![0u8; v.length.try_into().unwrap()];
-
-        //                 match file_ref
-        //                     .as_mut()
-        //                     .unwrap()
-        //                     .read_at(v.offset.try_into().unwrap(), &mut data)
-        //                     .await
-        //                 {
-        //                     Ok(read_size) => tx.send(Ok(ReadAtResponse {
-        //                         success: true,
-        //                         data,
-        //                         read_size: read_size.try_into().unwrap(),
-        //                         error: None,
-        //                     })),
-        //                     Err(err) => tx.send(Ok(ReadAtResponse {
-        //                         success: false,
-        //                         data: Vec::new(),
-        //                         error: Some(err_to_proto_err(&err, &format!("read at failed: {}", err))),
-        //                         read_size: -1,
-        //                     })),
-        //                 }
-        //                 .await
-        //                 .unwrap();
-        //             }
-        //             Err(err) => {
-        //                 if let Some(io_err) = match_for_io_error(&err) {
-        //                     if io_err.kind() == ErrorKind::BrokenPipe {
-        //                         // here you can handle special case when client
-        //                         // disconnected in unexpected way
-        //                         eprintln!("\tclient disconnected: broken pipe");
-        //                         break;
-        //                     }
-        //                 }
-
-        //                 match tx.send(Err(err)).await {
-        //                     Ok(_) => (),
-        //                     Err(_err) => break, // response was dropped
-        //                 }
-        //             }
-        //         }
-        //     }
-        //     println!("\tstream ended");
-        // });
-
-        // let out_stream = ReceiverStream::new(rx);
-
-        // Ok(Response::new(Box::pin(out_stream)))
+    async fn read_at(&self, request: Request<Streaming<ReadAtRequest>>) -> Result<Response<Self::ReadAtStream>, Status> {
+        let mut in_stream = request.into_inner();
+        let (tx, rx) = mpsc::channel(128);
+
+        // Every request of a stream reads from the disk the first one names.
+        let first = in_stream.next().await.transpose()?;
+        let (disk_path, disk) = match &first {
+            Some(req) => (req.disk.clone(), self.find_disk(&req.disk).await),
+            None => (String::new(), None),
+        };
+
+        spawn(async move {
+            let mut next = first.map(Ok);
+            while let Some(result) = next {
+                let req = match result {
+                    Ok(req) => req,
+                    Err(err) => {
+                        let _ = tx.send(Err(err)).await;
+                        break;
+                    }
+                };
+
+                let result = match &disk {
+                    Some(disk) if req.disk == disk_path => read_local_range(disk, &req).await,
+                    _ => Err(DiskError::other("can not find disk".to_string())),
+                };
+                let resp = match result {
+                    Ok(data) => ReadAtResponse {
+                        success: true,
+                        read_size: data.len() as i64,
+                        data: data.into(),
+                        error: None,
+                    },
+                    Err(err) => ReadAtResponse {
+                        success: false,
+                        data: Bytes::new(),
+                        read_size: -1,
+                        error: Some(err.into()),
+                    },
+                };
+                if tx.send(Ok(resp)).await.is_err() {
+                    break;
+                }
+
+                next = in_stream.next().await;
+            }
+        });
+
+        let out_stream = ReceiverStream::new(rx);
+        Ok(Response::new(Box::pin(out_stream)))
     }
 
     async fn list_dir(&self, request: Request<ListDirRequest>) -> Result<Response<ListDirResponse>, Status> {
@@ -1827,39 +1779,121 @@ impl Node for NodeService {
         }))
     }
 
-    async fn start_profiling(
-        &self,
-        _request: Request<StartProfilingRequest>,
-    ) -> Result<Response<StartProfilingResponse>, Status> {
-        todo!()
+    async fn start_profiling(&self, request: Request<StartProfilingRequest>) -> Result<Response<StartProfilingResponse>, Status> {
+        let request = request.into_inner();
+        match crate::profiling::start_profiling_session(&request.profiler).await {
+            Ok(_) => Ok(Response::new(StartProfilingResponse {
+                success: true,
+                error_info: None,
+            })),
+            Err(err) => Ok(Response::new(StartProfilingResponse {
+                success: false,
+                error_info: Some(err),
+            })),
+        }
     }
 
     async fn download_profile_data(
         &self,
         _request: Request<DownloadProfileDataRequest>,
     ) -> Result<Response<DownloadProfileDataResponse>, Status> {
-        todo!()
+        match crate::profiling::download_profiling_session().await {
+            Ok(data) => Ok(Response::new(DownloadProfileDataResponse {
+                success: true,
+                data: data.into_iter().map(|(k, v)| (k, v.into())).collect(),
+                error_info: None,
+            })),
+            Err(err) => Ok(Response::new(DownloadProfileDataResponse {
+                success: false,
+                data: HashMap::new(),
+                error_info: Some(err),
+            })),
+        }
     }
 
     async fn get_bucket_stats(
         &self,
-        _request: Request<GetBucketStatsDataRequest>,
+        request: Request<GetBucketStatsDataRequest>,
     ) -> Result<Response<GetBucketStatsDataResponse>, Status> {
-        todo!()
+        let request = request.into_inner();
+        let Some(stats) = GLOBAL_REPLICATION_STATS.get() else {
+            return Ok(Response::new(GetBucketStatsDataResponse {
+                success: false,
+                bucket_stats: Bytes::new(),
+                error_info: Some("replication stats not initialized".to_string()),
+            }));
+        };
+
+        let bucket_stats = stats.get_local_bucket_stats(&request.bucket).await;
+        let mut buf = Vec::new();
+        if let Err(err) = bucket_stats.serialize(&mut Serializer::new(&mut buf)) {
+            return Ok(Response::new(GetBucketStatsDataResponse {
+                success: false,
+                bucket_stats: Bytes::new(),
+                error_info: Some(err.to_string()),
+            }));
+        }
+        Ok(Response::new(GetBucketStatsDataResponse {
+            success: true,
+            bucket_stats: buf.into(),
+            error_info: None,
+        }))
     }
 
     async fn get_sr_metrics(
         &self,
         _request: Request<GetSrMetricsDataRequest>,
     ) -> Result<Response<GetSrMetricsDataResponse>, Status> {
-        todo!()
+        let Some(stats) = GLOBAL_REPLICATION_STATS.get() else {
+            return Ok(Response::new(GetSrMetricsDataResponse {
+                success: false,
+                sr_metrics_summary: Bytes::new(),
+                error_info: Some("replication stats not initialized".to_string()),
+            }));
+        };
+
+        let sr_metrics = stats.get_sr_metrics_for_node().await;
+        let mut buf = Vec::new();
+        if let Err(err) = sr_metrics.serialize(&mut Serializer::new(&mut buf)) {
+            return Ok(Response::new(GetSrMetricsDataResponse {
+                success: false,
+                sr_metrics_summary: Bytes::new(),
+                error_info: Some(err.to_string()),
+            }));
+        }
+        Ok(Response::new(GetSrMetricsDataResponse {
+            success: true,
+            sr_metrics_summary: buf.into(),
+            error_info: None,
+        }))
     }
 
     async fn get_all_bucket_stats(
         &self,
         _request: Request<GetAllBucketStatsRequest>,
     ) -> Result<Response<GetAllBucketStatsResponse>, Status> {
-        todo!()
+        let Some(stats) = GLOBAL_REPLICATION_STATS.get() else {
+            return Ok(Response::new(GetAllBucketStatsResponse {
+                success: false,
+                bucket_stats_map: Bytes::new(),
+                error_info: Some("replication stats not initialized".to_string()),
+            }));
+        };
+
+        let bucket_stats_map = stats.get_all_local_bucket_stats().await;
+        let mut buf = Vec::new();
+        if let Err(err) = bucket_stats_map.serialize(&mut Serializer::new(&mut buf)) {
+            return Ok(Response::new(GetAllBucketStatsResponse {
+                success: false,
+                bucket_stats_map: Bytes::new(),
+                error_info: Some(err.to_string()),
+            }));
+        }
+        Ok(Response::new(GetAllBucketStatsResponse {
+            success: true,
+            bucket_stats_map: buf.into(),
+            error_info: None,
+        }))
     }
 
     async fn load_bucket_metadata(
@@ -2186,32 +2220,114 @@ impl Node for NodeService {
 
     async fn signal_service(&self, request: Request<SignalServiceRequest>) -> Result<Response<SignalServiceResponse>, Status> {
         let request = request.into_inner();
-        let _vars = match request.vars {
+        let vars = match request.vars {
             Some(vars) => vars.value,
             None => HashMap::new(),
         };
-        todo!()
+
+        let signal = match vars
+            .get(PEER_RESTSIGNAL)
+            .ok_or_else(|| "signal name not found".to_string())
+            .and_then(|sig| sig.parse::<u64>().map_err(|err| format!("invalid signal {sig}: {err}")))
+            .and_then(ServiceSignal::try_from)
+        {
+            Ok(signal) => signal,
+            Err(err) => {
+                return Ok(Response::new(SignalServiceResponse {
+                    success: false,
+                    error_info: Some(err),
+                }));
+            }
+        };
+        let dry_run = vars.get(PEER_RESTDRY_RUN).is_some_and(|v| v == "true");
+
+        match signal {
+            ServiceSignal::Restart | ServiceSignal::Stop => {
+                if !dry_run {
+                    // Let the response reach the caller before the node goes down.
+                    spawn(async move {
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                        if !send_service_signal(signal) {
+                            warn!("service signal {:?} dropped, main loop is not listening", signal);
+                        }
+                    });
+                }
+            }
+            ServiceSignal::Freeze => {
+                if !dry_run {
+                    freeze_services();
+                }
+            }
+            ServiceSignal::Unfreeze => {
+                if !dry_run {
+                    unfreeze_services();
+                }
+            }
+            ServiceSignal::ReloadDynamic => {
+                return Ok(Response::new(SignalServiceResponse {
+                    success: false,
+                    error_info: Some(format!(
+                        "reloading sub-system {} is not supported",
+                        vars.get(PEER_RESTSUB_SYS).map(String::as_str).unwrap_or_default()
+                    )),
+                }));
+            }
+        }
+
+        Ok(Response::new(SignalServiceResponse {
+            success: true,
+            error_info: None,
+        }))
     }
 
     async fn background_heal_status(
         &self,
         _request: Request<BackgroundHealStatusRequest>,
     ) -> Result<Response<BackgroundHealStatusResponse>, Status> {
-        todo!()
+        let Some(state) = local_background_heal_state().await else {
+            return Ok(Response::new(BackgroundHealStatusResponse {
+                success: false,
+                bg_heal_state: Bytes::new(),
+                error_info: Some("errServerNotInitialized".to_string()),
+            }));
+        };
+
+        let mut buf = Vec::new();
+        if let Err(err) = state.serialize(&mut Serializer::new(&mut buf)) {
+            return Ok(Response::new(BackgroundHealStatusResponse {
+                success: false,
+                bg_heal_state: Bytes::new(),
+                error_info: Some(err.to_string()),
+            }));
+        }
+        Ok(Response::new(BackgroundHealStatusResponse {
+            success: true,
+            bg_heal_state: buf.into(),
+            error_info: None,
+        }))
     }
 
+    // Listings are not cached or shared between nodes, every node walks the disks itself
     async fn get_metacache_listing(
         &self,
         _request: Request<GetMetacacheListingRequest>,
     ) -> Result<Response<GetMetacacheListingResponse>, Status> {
-        todo!()
+        Ok(Response::new(GetMetacacheListingResponse {
+            success: false,
+            metacache: Bytes::new(),
+            error_info: Some(METACACHE_NOT_SUPPORTED.to_string()),
+        }))
     }
 
     async fn update_metacache_listing(
         &self,
         _request: Request<UpdateMetacacheListingRequest>,
     ) -> Result<Response<UpdateMetacacheListingResponse>, Status> {
-        todo!()
+        Ok(Response::new(UpdateMetacacheListingResponse {
+            success: false,
+            metacache: Bytes::new(),
+            error_info: Some(METACACHE_NOT_SUPPORTED.to_string()),
+        }))
     }
 
     async fn reload_pool_meta(
@@ -2292,7 +2408,68 @@ impl Node for NodeService {
         &self,
         _request: Request<LoadTransitionTierConfigRequest>,
     ) -> Result<Response<LoadTransitionTierConfigResponse>, Status> {
-        todo!()
+        let Some(store) = new_object_layer_fn() else {
+            return Ok(Response::new(LoadTransitionTierConfigResponse {
+                success: false,
+                error_info: Some("errServerNotInitialized".to_string()),
+            }));
+        };
+
+        let mut tier_config_mgr = GLOBAL_TierConfigMgr.write().await;
+        match tier_config_mgr.reload(store).await {
+            Ok(_) => Ok(Response::new(LoadTransitionTierConfigResponse {
+                success: true,
+                error_info: None,
+            })),
+            Err(err) => Ok(Response::new(LoadTransitionTierConfigResponse {
+                success: false,
+                error_info: Some(err.to_string()),
+            })),
+        }
+    }
+
+    type TraceStream = ResponseStream<TraceResponse>;
+    async fn trace(&self, request: Request<TraceRequest>) -> Result<Response<Self::TraceStream>, Status> {
+        let request = request.into_inner();
+        let opts = serde_json::from_slice::<ServiceTraceOpts>(&request.opts)
+            .map_err(|err| Status::invalid_argument(format!("invalid ServiceTraceOpts: {err}")))?;
+
+        let mut subscription = subscribe_trace(opts.trace_types());
+        let (tx, rx) = mpsc::channel(1000);
+        spawn(async move {
+            loop {
+                let info = tokio::select! {
+                    _ = tx.closed() => break,
+                    info = subscription.recv() => match info {
+                        Some(info) => info,
+                        None => break,
+                    },
+                };
+                if !opts.matches(&info) {
+                    continue;
+                }
+
+                let resp = match serde_json::to_vec(info.as_ref()) {
+                    Ok(buf) => TraceResponse {
+                        success: true,
+                        trace_info: buf.into(),
+                        error_info: None,
+                    },
+                    Err(err) => TraceResponse {
+                        success: false,
+                        trace_info: Bytes::new(),
+                        error_info: Some(err.to_string()),
+                    },
+                };
+                if tx.send(Ok(resp)).await.is_err() {
+                    break;
+                }
+            }
+            debug!("trace stream closed");
+        });
+
+        let out_stream = ReceiverStream::new(rx);
+        Ok(Response::new(Box::pin(out_stream)))
     }
 }
 
@@ -2491,6 +2668,23 @@ mod tests {
         assert!(write_response.error.is_some());
     }
 
+    #[tokio::test]
+    async fn test_write_invalid_disk() {
+        let service = create_test_node_service();
+
+        let request = Request::new(WriteRequest {
+            disk: "invalid-disk-path".to_string(),
+            volume: "test-volume".to_string(),
+            path: "test-path".to_string(),
+            is_append: false,
+            data: vec![1, 2, 3, 4].into(),
+        });
+
+        let write_response = service.write(request).await.unwrap().into_inner();
+        assert!(!write_response.success);
+        assert!(write_response.error.is_some());
+    }
+
     #[tokio::test]
     async fn test_delete_invalid_disk() {
         let service = create_test_node_service();
@@ -3602,7 +3796,99 @@ mod tests {
         assert!(reload_response.error_info.is_some());
     }
 
-    // Note: signal_service test is skipped because it contains todo!() and would panic
+    #[tokio::test]
+    async fn test_signal_service_dry_run() {
+        let service = create_test_node_service();
+
+        let mut vars = HashMap::new();
+        vars.insert(PEER_RESTSIGNAL.to_string(), (ServiceSignal::Stop as u64).to_string());
+        vars.insert(PEER_RESTDRY_RUN.to_string(), "true".to_string());
+        let request = Request::new(SignalServiceRequest {
+            vars: Some(Mss { value: vars }),
+        });
+
+        let response = service.signal_service(request).await.unwrap().into_inner();
+        assert!(response.success);
+        assert!(response.error_info.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_signal_service_unknown_signal() {
+        let service = create_test_node_service();
+
+        let mut vars = HashMap::new();
+        vars.insert(PEER_RESTSIGNAL.to_string(), "42".to_string());
+        let request = Request::new(SignalServiceRequest {
+            vars: Some(Mss { value: vars }),
+        });
+
+        let response = service.signal_service(request).await.unwrap().into_inner();
+        assert!(!response.success);
+        assert!(response.error_info.unwrap().contains("unknown service signal"));
+    }
+
+    #[tokio::test]
+    async fn test_start_profiling_unsupported_type() {
+        let service = create_test_node_service();
+
+        let request = Request::new(StartProfilingRequest {
+            profiler: "goroutines".to_string(),
+        });
+
+        let response = service.start_profiling(request).await.unwrap().into_inner();
+        assert!(!response.success);
+        assert!(response.error_info.unwrap().contains("unsupported profiler type"));
+    }
+
+    #[tokio::test]
+    async fn test_download_profile_data_without_session() {
+        let service = create_test_node_service();
+
+        let request = Request::new(DownloadProfileDataRequest {});
+
+        let response = service.download_profile_data(request).await.unwrap().into_inner();
+        assert!(!response.success);
+        assert!(response.data.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_background_heal_status_no_object_layer() {
+        let service = create_test_node_service();
+
+        let request = Request::new(BackgroundHealStatusRequest {});
+
+        let response = service.background_heal_status(request).await.unwrap().into_inner();
+        // Should fail because object layer is not initialized in test
+        assert!(!response.success);
+        assert!(response.error_info.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_metacache_listing_not_supported() {
+        let service = create_test_node_service();
+
+        let request = Request::new(GetMetacacheListingRequest { opts: Bytes::new() });
+        let response = service.get_metacache_listing(request).await.unwrap().into_inner();
+        assert!(!response.success);
+        assert_eq!(response.error_info.as_deref(), Some(METACACHE_NOT_SUPPORTED));
+
+        let request = Request::new(UpdateMetacacheListingRequest { metacache: Bytes::new() });
+        let response = service.update_metacache_listing(request).await.unwrap().into_inner();
+        assert!(!response.success);
+        assert_eq!(response.error_info.as_deref(), Some(METACACHE_NOT_SUPPORTED));
+    }
+
+    #[tokio::test]
+    async fn test_load_transition_tier_config_no_object_layer() {
+        let service = create_test_node_service();
+
+        let request = Request::new(LoadTransitionTierConfigRequest {});
+
+        let response = service.load_transition_tier_config(request).await.unwrap().into_inner();
+        // Should fail because object layer is not initialized in test
+        assert!(!response.success);
+        assert!(response.error_info.is_some());
+    }
 
     #[tokio::test]
     async fn test_node_service_debug() {